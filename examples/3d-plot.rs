@@ -0,0 +1,44 @@
+use plotters::prelude::*;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let root = BitMapBackend::gif("plotters-doc-data/3d-plot.gif", (600, 600), 100)?
+        .into_drawing_area();
+
+    // A helix, which is drawn as a line through the points and a marker at each of them
+    let helix: Vec<_> = (0..=200)
+        .map(|i| {
+            let t = i as f64 / 200.0;
+            let angle = t * 6.0 * std::f64::consts::PI;
+            (angle.cos(), t * 2.0 - 1.0, angle.sin())
+        })
+        .collect();
+
+    for frame in 0..60 {
+        root.fill(&WHITE)?;
+
+        let mut chart = ChartBuilder::on(&root)
+            .caption("3D Helix", ("sans-serif", 30))
+            .margin(10)
+            .build_cartesian_3d(-1.2..1.2, -1.2..1.2, -1.2..1.2)?;
+        chart.with_projection(|mut p| {
+            p.yaw = frame as f64 / 60.0 * 2.0 * std::f64::consts::PI;
+            p.pitch = 0.3;
+            p.perspective = Some(2.5);
+            p
+        });
+
+        chart.draw_series(
+            LineSeries3d::new(&chart, helix.iter().cloned(), BLUE.stroke_width(2))
+                .alpha_attenuation(true),
+        )?;
+        chart.draw_series(
+            ScatterSeries3d::new(&chart, helix.iter().step_by(10).cloned(), 4, RED.filled())
+                .size_attenuation(true)
+                .alpha_attenuation(true),
+        )?;
+
+        root.present()?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use plotters::coord::Shift;
+use plotters::prelude::*;
+
+fn animate<DB: AnimatedBackend>(
+    root: DrawingArea<DB, Shift>,
+) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+    let frames = 40;
+    for frame in 0..frames {
+        let phase = 2.0 * std::f64::consts::PI * f64::from(frame) / f64::from(frames);
+
+        root.fill(&WHITE)?;
+
+        let mut chart = ChartBuilder::on(&root)
+            .caption(format!("y = sin(x + {:.2})", phase), ("sans-serif", 30))
+            .margin(10)
+            .x_label_area_size(30)
+            .y_label_area_size(40)
+            .build_ranged(0.0..10.0, -1.2..1.2)?;
+
+        chart.configure_mesh().draw()?;
+
+        chart.draw_series(LineSeries::new(
+            (0..=200)
+                .map(|x| f64::from(x) / 20.0)
+                .map(|x| (x, (x + phase).sin())),
+            &RED,
+        ))?;
+
+        // Each call to present finishes a frame of the animation, and the last frame is
+        // displayed for longer
+        if frame + 1 == frames {
+            root.present_frame(1_000)?;
+        } else {
+            root.present()?;
+        }
+    }
+
+    Ok(())
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // The same animation is drawn as a GIF or an APNG by the backend it's given
+    animate(
+        GifBackend::new("plotters-doc-data/sine-animation.gif", (640, 360), 50)?
+            .palette(GifPalette::Global)
+            .into_drawing_area(),
+    )?;
+    animate(
+        ApngBackend::create_animation("plotters-doc-data/sine-animation.png", (640, 360), 50)?
+            .into_drawing_area(),
+    )?;
+
+    Ok(())
+}
@@ -1,9 +1,43 @@
+use std::error::Error;
+
 use super::context::ChartContext;
 
-use crate::coord::{AsRangedCoord, RangedCoord, Shift};
+use crate::coord::{AsRangedCoord, Cartesian3d, Ranged, RangedCoord, Shift};
 use crate::drawing::backend::DrawingBackend;
-use crate::drawing::{DrawingArea, DrawingAreaErrorKind};
-use crate::style::{IntoTextStyle, SizeDesc, TextStyle};
+use crate::drawing::{ChartLayoutError, DrawingArea, DrawingAreaErrorKind, OutOfRange};
+use crate::element::Text;
+use crate::style::{
+    AsRelative, FontDesc, FontFamily, FontStyle, HPos, IntoTextStyle, Pos, SizeDesc, TextStyle,
+};
+
+/// The space in pixels an automatically sized label area leaves beyond its labels
+const AUTO_LABEL_AREA_PADDING: u32 = 5;
+
+/// The plotting area of a chart, followed by the label areas of its X and Y axes and the areas
+/// of its series label on the right and at the bottom
+type Layout<DB> = (
+    DrawingArea<DB, Shift>,
+    [Option<DrawingArea<DB, Shift>>; 2],
+    [Option<DrawingArea<DB, Shift>>; 2],
+    [Option<DrawingArea<DB, Shift>>; 2],
+    [Option<DrawingArea<DB, Shift>>; 2],
+);
+
+/// Check if the range of an axis maps its values onto more than one pixel, which the empty
+/// ranges, such as `0..0`, and the ranges of NaN don't
+pub(super) fn check_axis_range<R: Ranged, E: Error + Send + Sync>(
+    range: &R,
+    axis: &'static str,
+) -> Result<(), DrawingAreaErrorKind<E>> {
+    let values = range.range();
+    let pixels = (0, 1 << 16);
+    if range.map(&values.start, pixels) == range.map(&values.end, pixels) {
+        return Err(DrawingAreaErrorKind::LayoutError(
+            ChartLayoutError::EmptyRange(axis),
+        ));
+    }
+    Ok(())
+}
 
 /// The enum used to specify the position of label area.
 /// This is used when we configure the label area size with the API `set_label_area_size`
@@ -21,8 +55,16 @@ pub enum LabelAreaPosition {
 pub struct ChartBuilder<'a, 'b, DB: DrawingBackend> {
     label_area_size: [u32; 4], // [upper, lower, left, right]
     overlap_plotting_area: [bool; 4],
+    auto_label_area: [bool; 4],
+    auto_label_style: [Option<TextStyle<'b>>; 2], // [x, y]
+    auto_label_count: [usize; 2],                 // [x, y]
+    series_label_area_size: [u32; 2],             // [right, bottom]
+    colorbar_area_size: [u32; 2],                 // [right, bottom]
     root_area: &'a DrawingArea<DB, Shift>,
     title: Option<(String, TextStyle<'b>)>,
+    subtitles: Vec<(String, TextStyle<'b>)>,
+    caption_alignment: HPos,
+    caption_spacing: u32,
     margin: [u32; 4],
 }
 
@@ -33,10 +75,18 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
     pub fn on(root: &'a DrawingArea<DB, Shift>) -> Self {
         Self {
             label_area_size: [0; 4],
+            series_label_area_size: [0; 2],
+            colorbar_area_size: [0; 2],
             root_area: root,
             title: None,
+            subtitles: vec![],
+            caption_alignment: HPos::Center,
+            caption_spacing: 4,
             margin: [0; 4],
             overlap_plotting_area: [false; 4],
+            auto_label_area: [false; 4],
+            auto_label_style: [None, None],
+            auto_label_count: [10, 10],
         }
     }
 
@@ -131,10 +181,120 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
         let size = size.in_pixels(self.root_area);
         self.label_area_size[pos as usize] = size.abs() as u32;
         self.overlap_plotting_area[pos as usize] = size < 0;
+        self.auto_label_area[pos as usize] = false;
+        self
+    }
+
+    /// Size the X label area below the chart automatically, see `set_label_area_auto`
+    pub fn x_label_area_auto(&mut self) -> &mut Self {
+        self.set_label_area_auto(LabelAreaPosition::Bottom)
+    }
+
+    /// Size the Y label area on the left of the chart automatically, see `set_label_area_auto`
+    pub fn y_label_area_auto(&mut self) -> &mut Self {
+        self.set_label_area_auto(LabelAreaPosition::Left)
+    }
+
+    /// Size a label area automatically when the chart is built with `build_ranged`: the area is
+    /// as large as the ticks, the tallest label of the X axis or the widest label of the Y axis,
+    /// and a small padding. The labels measured are the key points of the axis formatted with
+    /// `Ranged::format_label`, so a custom formatter should be given to the range with
+    /// `with_label_formatter` rather than to the mesh. An axis whose range doesn't format its
+    /// labels isn't measured, and its label areas only fit the ticks.
+    /// - `pos`: The position of the label area
+    pub fn set_label_area_auto(&mut self, pos: LabelAreaPosition) -> &mut Self {
+        self.auto_label_area[pos as usize] = true;
+        self.overlap_plotting_area[pos as usize] = false;
+        self
+    }
+
+    /// Set the style the labels of the X axis are measured with for the automatically sized
+    /// label areas, which should be the label style of the mesh. A rotated label is measured
+    /// with the transform of the style, such as `FontTransform::Rotate90`.
+    pub fn x_auto_label_style<T: IntoTextStyle<'b>>(&mut self, style: T) -> &mut Self {
+        self.auto_label_style[0] = Some(style.into_text_style(self.root_area));
+        self
+    }
+
+    /// Set the style the labels of the Y axis are measured with for the automatically sized
+    /// label areas, see `x_auto_label_style`
+    pub fn y_auto_label_style<T: IntoTextStyle<'b>>(&mut self, style: T) -> &mut Self {
+        self.auto_label_style[1] = Some(style.into_text_style(self.root_area));
+        self
+    }
+
+    /// Set the maximum numbers of the labels measured for the automatically sized label areas,
+    /// which should be the numbers of the labels of the mesh, 10 of each axis by default
+    pub fn auto_labels(&mut self, x_labels: usize, y_labels: usize) -> &mut Self {
+        self.auto_label_count = [x_labels, y_labels];
+        self
+    }
+
+    /// Get the size of an automatically sized label area of an axis
+    /// - `range`: The range of the axis
+    /// - `axis`: 0 for the X axis, whose labels are measured by the height, or 1 for the Y axis,
+    ///   whose labels are measured by the width
+    fn auto_label_area_size<R: Ranged>(
+        &self,
+        range: &R,
+        axis: usize,
+    ) -> Result<u32, DrawingAreaErrorKind<DB::ErrorType>> {
+        /* The defaults of the mesh, which are bounded by the size of the plotting area */
+        let tick_size = (5u32).percent().max(5).in_pixels(self.root_area).max(0) as u32;
+        let font = match &self.auto_label_style[axis] {
+            Some(style) => style.font.clone(),
+            None => FontDesc::new(
+                FontFamily::SansSerif,
+                f64::from((12i32).percent().max(12).in_pixels(self.root_area)),
+                FontStyle::Normal,
+            ),
+        };
+        let mut extent = 0;
+        for value in range.key_points(self.auto_label_count[axis]) {
+            let text = match range.format_label(&value) {
+                Some(text) => text,
+                None => break,
+            };
+            let (w, h) = self.root_area.estimate_text_size(&text, &font)?;
+            extent = extent.max(if axis == 0 { h } else { w });
+        }
+        // The labels are a tick length away from the outer ends of the ticks
+        Ok(tick_size * 2 + extent + AUTO_LABEL_AREA_PADDING)
+    }
+
+    /// Reserve an area on the right of the chart for the series label, beyond the label areas,
+    /// which the series label is drawn in with `SeriesLabelPosition::OutsideRight`
+    /// - `size`: The width of the area, if it's 0, the chart doesn't have the area
+    pub fn right_series_label_area_size<S: SizeDesc>(&mut self, size: S) -> &mut Self {
+        self.series_label_area_size[0] = size.in_pixels(self.root_area).max(0) as u32;
+        self
+    }
+
+    /// Reserve an area at the bottom of the chart for the series label, beyond the label areas,
+    /// which the series label is drawn in with `SeriesLabelPosition::OutsideBottom`
+    /// - `size`: The height of the area, if it's 0, the chart doesn't have the area
+    pub fn bottom_series_label_area_size<S: SizeDesc>(&mut self, size: S) -> &mut Self {
+        self.series_label_area_size[1] = size.in_pixels(self.root_area).max(0) as u32;
         self
     }
 
-    /// Set the caption of the chart
+    /// Reserve an area on the right of the chart for a vertical colorbar, between the label
+    /// area and the area of the series label, see `ChartContext::draw_colorbar`
+    /// - `size`: The width of the area, if it's 0, the chart doesn't have the area
+    pub fn right_colorbar_area_size<S: SizeDesc>(&mut self, size: S) -> &mut Self {
+        self.colorbar_area_size[0] = size.in_pixels(self.root_area).max(0) as u32;
+        self
+    }
+
+    /// Reserve an area at the bottom of the chart for a horizontal colorbar, between the label
+    /// area and the area of the series label, see `ChartContext::draw_colorbar`
+    /// - `size`: The height of the area, if it's 0, the chart doesn't have the area
+    pub fn bottom_colorbar_area_size<S: SizeDesc>(&mut self, size: S) -> &mut Self {
+        self.colorbar_area_size[1] = size.in_pixels(self.root_area).max(0) as u32;
+        self
+    }
+
+    /// Set the caption of the chart, which is split into lines at the line breaks
     /// - `caption`: The caption of the chart
     /// - `style`: The text style
     /// - Note: If the caption is set, the margin option will be ignored
@@ -150,11 +310,54 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
         self
     }
 
+    /// Add a subtitle below the caption of the chart, or below the subtitles added before it.
+    /// An empty subtitle takes no space.
+    /// - `subtitle`: The subtitle, which is split into lines at the line breaks
+    /// - `style`: The text style, which is usually smaller than the one of the caption
+    pub fn subtitle<S: AsRef<str>, Style: IntoTextStyle<'b>>(
+        &mut self,
+        subtitle: S,
+        style: Style,
+    ) -> &mut Self {
+        self.subtitles.push((
+            subtitle.as_ref().to_string(),
+            style.into_text_style(self.root_area),
+        ));
+        self
+    }
+
+    /// Set the alignment of the lines of the caption over the plotting area, which is centered
+    /// by default
+    /// - `alignment`: The alignment of the lines
+    pub fn caption_alignment(&mut self, alignment: HPos) -> &mut Self {
+        self.caption_alignment = alignment;
+        self
+    }
+
+    /// Set the space between the lines of the caption
+    /// - `spacing`: The space in pixels
+    pub fn caption_spacing<S: SizeDesc>(&mut self, spacing: S) -> &mut Self {
+        self.caption_spacing = spacing.in_pixels(self.root_area).max(0) as u32;
+        self
+    }
+
+    /// Get the lines of the caption and the subtitles, leaving out the empty ones
+    fn caption_lines(&self) -> Vec<(&str, &TextStyle<'b>)> {
+        self.title
+            .iter()
+            .chain(self.subtitles.iter())
+            .flat_map(|(text, style)| text.split('\n').map(move |line| (line, style)))
+            .filter(|(line, _)| !line.is_empty())
+            .collect()
+    }
+
     /// Build the chart with a 2D Cartesian coordinate system. The function will returns a chart
     /// context, where data series can be rendered on.
     /// - `x_spec`: The specification of X axis
     /// - `y_spec`: The specification of Y axis
-    /// - Returns: A chart context
+    /// - Returns: A chart context, or `DrawingAreaErrorKind::LayoutError` if the drawing area
+    ///   doesn't leave any pixel for the plotting area or the range of an axis is empty, which
+    ///   is the usual failure of a headless render with a misconfigured size
     #[allow(clippy::type_complexity)]
     pub fn build_ranged<X: AsRangedCoord, Y: AsRangedCoord>(
         &mut self,
@@ -164,8 +367,111 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
         ChartContext<'a, DB, RangedCoord<X::CoordDescType, Y::CoordDescType>>,
         DrawingAreaErrorKind<DB::ErrorType>,
     > {
+        x_spec
+            .check_range()
+            .and(y_spec.check_range())
+            .map_err(DrawingAreaErrorKind::InvalidRange)?;
+        let (x_spec, y_spec): (X::CoordDescType, Y::CoordDescType) = (x_spec.into(), y_spec.into());
+        check_axis_range(&x_spec, "X")?;
+        check_axis_range(&y_spec, "Y")?;
+        for (idx, &auto) in self.auto_label_area.iter().enumerate() {
+            if auto {
+                self.label_area_size[idx] = if idx < 2 {
+                    self.auto_label_area_size(&x_spec, 0)?
+                } else {
+                    self.auto_label_area_size(&y_spec, 1)?
+                };
+            }
+        }
+        let (drawing_area, x_label_area, y_label_area, series_label_area, colorbar_area) =
+            self.layout()?;
+
+        let mut pixel_range = drawing_area.get_pixel_range();
+        pixel_range.1 = pixel_range.1.end..pixel_range.1.start;
+
+        Ok(ChartContext {
+            x_label_area,
+            y_label_area,
+            drawing_area: drawing_area.apply_coord_spec(RangedCoord::new(
+                x_spec,
+                y_spec,
+                pixel_range,
+            )),
+            series_anno: vec![],
+            series_label_area,
+            colorbar_area,
+            out_of_range: OutOfRange::Clamp,
+            clip_text: false,
+            drawn_labels: Default::default(),
+            deferred: vec![],
+        })
+    }
+
+    /// Build the chart with a 3D Cartesian coordinate system, which is projected onto the
+    /// plotting area. The projection can be changed with `ChartContext::with_projection`.
+    /// - `x_spec`: The specification of X axis
+    /// - `y_spec`: The specification of Y axis, which is the vertical one
+    /// - `z_spec`: The specification of Z axis
+    /// - Returns: A chart context, or `DrawingAreaErrorKind::LayoutError` if the drawing area
+    ///   doesn't leave any pixel for the plotting area or the range of an axis is empty, which
+    ///   is the usual failure of a headless render with a misconfigured size
+    #[allow(clippy::type_complexity)]
+    pub fn build_cartesian_3d<X: AsRangedCoord, Y: AsRangedCoord, Z: AsRangedCoord>(
+        &mut self,
+        x_spec: X,
+        y_spec: Y,
+        z_spec: Z,
+    ) -> Result<
+        ChartContext<'a, DB, Cartesian3d<X::CoordDescType, Y::CoordDescType, Z::CoordDescType>>,
+        DrawingAreaErrorKind<DB::ErrorType>,
+    > {
+        x_spec
+            .check_range()
+            .and(y_spec.check_range())
+            .and(z_spec.check_range())
+            .map_err(DrawingAreaErrorKind::InvalidRange)?;
+        let (x_spec, y_spec, z_spec): (X::CoordDescType, Y::CoordDescType, Z::CoordDescType) =
+            (x_spec.into(), y_spec.into(), z_spec.into());
+        check_axis_range(&x_spec, "X")?;
+        check_axis_range(&y_spec, "Y")?;
+        check_axis_range(&z_spec, "Z")?;
+        let (drawing_area, x_label_area, y_label_area, series_label_area, colorbar_area) =
+            self.layout()?;
+
+        let mut pixel_range = drawing_area.get_pixel_range();
+        pixel_range.1 = pixel_range.1.end..pixel_range.1.start;
+
+        Ok(ChartContext {
+            x_label_area,
+            y_label_area,
+            drawing_area: drawing_area.apply_coord_spec(Cartesian3d::new(
+                x_spec,
+                y_spec,
+                z_spec,
+                pixel_range,
+            )),
+            series_anno: vec![],
+            series_label_area,
+            colorbar_area,
+            out_of_range: OutOfRange::Clamp,
+            clip_text: false,
+            drawn_labels: Default::default(),
+            deferred: vec![],
+        })
+    }
+
+    /// Split the root area into the plotting area, the label areas of the X and Y axes and the
+    /// areas of the series label
+    fn layout(&mut self) -> Result<Layout<DB>, DrawingAreaErrorKind<DB::ErrorType>> {
         let mut label_areas = [None, None, None, None];
 
+        let (root_w, root_h) = self.root_area.dim_in_pixel();
+        if root_w == 0 || root_h == 0 {
+            return Err(DrawingAreaErrorKind::LayoutError(
+                ChartLayoutError::DegenerateArea,
+            ));
+        }
+
         let mut drawing_area = DrawingArea::clone(self.root_area);
 
         if *self.margin.iter().max().unwrap_or(&0) > 0 {
@@ -177,8 +483,91 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
             );
         }
 
-        if let Some((ref title, ref style)) = self.title {
-            drawing_area = drawing_area.titled(title, style.clone())?;
+        // The caption is drawn once the plotting area, which it's aligned over, is laid out
+        let caption_lines = self.caption_lines();
+        let mut caption = None;
+        let mut caption_h = 0;
+        if !caption_lines.is_empty() {
+            let mut sizes = vec![];
+            for (text, style) in caption_lines.iter() {
+                sizes.push(drawing_area.estimate_text_size(text, &style.font)?);
+            }
+            let text_h = sizes.iter().map(|size| size.1).sum::<u32>()
+                + self.caption_spacing * (sizes.len() as u32 - 1);
+            let padding = (text_h / 2).min(5);
+            caption_h = text_h + padding * 2;
+            let (area, rest) = drawing_area.split_vertically(text_h + padding * 2);
+            drawing_area = rest;
+            caption = Some((area, sizes, padding));
+        }
+
+        // Everything but the plotting area has a fixed size, so it must leave a pixel for it
+        let label_area_size = |idx: usize| {
+            if self.overlap_plotting_area[idx] {
+                0
+            } else {
+                self.label_area_size[idx]
+            }
+        };
+        let needed = (
+            self.margin[2]
+                + self.margin[3]
+                + label_area_size(2)
+                + label_area_size(3)
+                + self.colorbar_area_size[0]
+                + self.series_label_area_size[0]
+                + 1,
+            self.margin[0]
+                + self.margin[1]
+                + caption_h
+                + label_area_size(0)
+                + label_area_size(1)
+                + self.colorbar_area_size[1]
+                + self.series_label_area_size[1]
+                + 1,
+        );
+        if needed.0 > root_w || needed.1 > root_h {
+            return Err(DrawingAreaErrorKind::LayoutError(
+                ChartLayoutError::InsufficientSpace {
+                    needed,
+                    available: (root_w, root_h),
+                },
+            ));
+        }
+
+        // The areas of the series label are outside of the label areas, and the one at the
+        // bottom is as wide as the rest of the chart
+        let mut series_label_area = [None, None];
+        if self.series_label_area_size[0] > 0 {
+            let (w, _) = drawing_area.dim_in_pixel();
+            let size = self.series_label_area_size[0].min(w);
+            let (rest, area) = drawing_area.split_horizontally(w - size);
+            drawing_area = rest;
+            series_label_area[0] = Some(area);
+        }
+        if self.series_label_area_size[1] > 0 {
+            let (_, h) = drawing_area.dim_in_pixel();
+            let size = self.series_label_area_size[1].min(h);
+            let (rest, area) = drawing_area.split_vertically(h - size);
+            drawing_area = rest;
+            series_label_area[1] = Some(area);
+        }
+
+        // The areas of the colorbars are between the label areas and the series label areas
+        let mut colorbar_area = [None, None];
+        if self.colorbar_area_size[0] > 0 {
+            let (w, _) = drawing_area.dim_in_pixel();
+            let size = self.colorbar_area_size[0].min(w);
+            let (rest, area) = drawing_area.split_horizontally(w - size);
+            drawing_area = rest;
+            colorbar_area[0] = Some(area);
+        }
+        if self.colorbar_area_size[1] > 0 {
+            let (_, h) = drawing_area.dim_in_pixel();
+            let size = self.colorbar_area_size[1].min(h);
+            let (rest, area) = drawing_area.split_vertically(h - size);
+            drawing_area = rest;
+            colorbar_area[1] = Some(area);
         }
 
         let (w, h) = drawing_area.dim_in_pixel();
@@ -240,9 +629,6 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
             }
         }
 
-        let mut pixel_range = drawing_area.get_pixel_range();
-        pixel_range.1 = pixel_range.1.end..pixel_range.1.start;
-
         let mut x_label_area = [None, None];
         let mut y_label_area = [None, None];
 
@@ -251,16 +637,31 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
         std::mem::swap(&mut y_label_area[0], &mut label_areas[2]);
         std::mem::swap(&mut y_label_area[1], &mut label_areas[3]);
 
-        Ok(ChartContext {
+        if let Some((area, sizes, padding)) = caption {
+            let plot_x0 = drawing_area.get_base_pixel().0 - area.get_base_pixel().0;
+            let (plot_w, _) = drawing_area.dim_in_pixel();
+            let (area_w, _) = area.dim_in_pixel();
+            let mut y = padding as i32;
+            for ((text, style), (w, h)) in caption_lines.into_iter().zip(sizes) {
+                let x = match self.caption_alignment {
+                    HPos::Left => plot_x0,
+                    HPos::Center => plot_x0 + (plot_w as i32 - w as i32) / 2,
+                    HPos::Right => plot_x0 + plot_w as i32 - w as i32,
+                };
+                // The lines wider than the plotting area are kept inside of the chart
+                let x = x.min(area_w as i32 - w as i32).max(0);
+                area.draw(&Text::new(text, (x, y), style.pos(Pos::default())))?;
+                y += (h + self.caption_spacing) as i32;
+            }
+        }
+
+        Ok((
+            drawing_area,
             x_label_area,
             y_label_area,
-            drawing_area: drawing_area.apply_coord_spec(RangedCoord::new(
-                x_spec,
-                y_spec,
-                pixel_range,
-            )),
-            series_anno: vec![],
-        })
+            series_label_area,
+            colorbar_area,
+        ))
     }
 }
 
@@ -333,4 +734,195 @@ mod test {
         chart.caption("This is a test case", ("serif", 10));
         assert_eq!(chart.title.as_ref().unwrap().1.font.get_name(), "serif");
     }
+
+    #[test]
+    fn test_caption_lines() {
+        let drawing_area = create_mocked_drawing_area(300, 200, |m| {
+            // The lines are right aligned over the plotting area, which starts after the label
+            // area, and the empty subtitle takes no space
+            m.check_draw_text(|_, _, size, pos, text| {
+                assert_eq!((text, size), ("Title", 20.0));
+                assert!(pos.0 > 200);
+            });
+            m.check_draw_text(|_, _, size, pos, text| {
+                assert_eq!((text, size), ("Subtitle", 10.0));
+                assert!(pos.0 > 200);
+            });
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_eq!(text, "Second line");
+                assert!(pos.0 > 100);
+            });
+            m.drop_check(|b| assert_eq!(b.num_draw_text_call, 3));
+        });
+        let (_, title_h) = drawing_area
+            .estimate_text_size("Title", &("serif", 20).into_font())
+            .unwrap();
+        let (_, subtitle_h) = drawing_area
+            .estimate_text_size("Subtitle", &("serif", 10).into_font())
+            .unwrap();
+        let (_, second_h) = drawing_area
+            .estimate_text_size("Second line", &("serif", 10).into_font())
+            .unwrap();
+
+        let mut builder = ChartBuilder::on(&drawing_area);
+        builder
+            .y_label_area_size(50)
+            .caption("Title", ("serif", 20))
+            .subtitle("", ("serif", 30))
+            .subtitle("Subtitle\nSecond line", ("serif", 10))
+            .caption_alignment(HPos::Right)
+            .caption_spacing(2);
+        let (plotting_area, _, y_label_area, _, _) = builder.layout().unwrap();
+
+        let caption_h = title_h + subtitle_h + second_h + 2 * 2;
+        let padding = (caption_h / 2).min(5);
+        assert_eq!(
+            plotting_area.get_base_pixel(),
+            (50, (caption_h + padding * 2) as i32)
+        );
+        assert_eq!(y_label_area[0].as_ref().unwrap().dim_in_pixel().0, 50);
+    }
+
+    #[test]
+    fn test_layout_errors() {
+        let layout_error = |e| match e {
+            DrawingAreaErrorKind::LayoutError(e) => e,
+            e => panic!("Unexpected error: {}", e),
+        };
+        let drawing_area = create_mocked_drawing_area(40, 30, |_| {});
+        let error = ChartBuilder::on(&drawing_area)
+            .margin(5)
+            .x_label_area_size(20)
+            .y_label_area_size(30)
+            .build_ranged(0..10, 0..10)
+            .err()
+            .map(layout_error);
+        assert_eq!(
+            error,
+            Some(ChartLayoutError::InsufficientSpace {
+                needed: (41, 31),
+                available: (40, 30),
+            })
+        );
+        // The label areas overlapping the plotting area take no space of their own
+        assert!(ChartBuilder::on(&drawing_area)
+            .set_label_area_size(LabelAreaPosition::Left, -30)
+            .set_label_area_size(LabelAreaPosition::Bottom, -20)
+            .build_ranged(0..10, 0..10)
+            .is_ok());
+
+        let empty_area = drawing_area.clone().shrink((0, 0), (0, 30));
+        let error = ChartBuilder::on(&empty_area)
+            .build_ranged(0..10, 0..10)
+            .err()
+            .map(layout_error);
+        assert_eq!(error, Some(ChartLayoutError::DegenerateArea));
+
+        let error = ChartBuilder::on(&drawing_area)
+            .build_ranged(0..10, 1.0..1.0)
+            .err()
+            .map(layout_error);
+        assert_eq!(error, Some(ChartLayoutError::EmptyRange("Y")));
+        let error = ChartBuilder::on(&drawing_area)
+            .build_ranged(std::f64::NAN..1.0, 0..10)
+            .err()
+            .map(layout_error);
+        assert_eq!(error, Some(ChartLayoutError::EmptyRange("X")));
+        let error = ChartBuilder::on(&drawing_area)
+            .build_cartesian_3d(0..10, 0..10, 5..5)
+            .err()
+            .map(layout_error);
+        assert_eq!(error, Some(ChartLayoutError::EmptyRange("Z")));
+
+        // A range emptied after the chart is built fails the mesh
+        let mut state = ChartBuilder::on(&drawing_area)
+            .build_ranged(0..10, 0..10)
+            .unwrap()
+            .into_chart_state();
+        state.set_x_range(3..3);
+        let mut chart = state.restore(&drawing_area);
+        let error = chart.configure_mesh().draw().err().map(layout_error);
+        assert_eq!(error, Some(ChartLayoutError::EmptyRange("X")));
+    }
+
+    #[test]
+    fn test_auto_label_area_size() {
+        let drawing_area = create_mocked_drawing_area(400, 300, |m| {
+            // The labels of the Y axis fit in the label area on the left
+            m.check_draw_text(|_, _, _, pos, _| assert!(pos.0 >= 0));
+        });
+        let font = ("sans-serif", 12).into_font();
+        let (label_w, _) = drawing_area.estimate_text_size("100000", &font).unwrap();
+        let (_, label_h) = drawing_area.estimate_text_size("0", &font).unwrap();
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .x_label_area_auto()
+            .y_label_area_auto()
+            .build_ranged(0..10, 0..100_000)
+            .expect("Create chart");
+        assert_eq!(
+            chart.plotting_area().get_base_pixel(),
+            ((10 + label_w + AUTO_LABEL_AREA_PADDING) as i32, 0)
+        );
+        assert_eq!(
+            chart.plotting_area().dim_in_pixel().1,
+            300 - (10 + label_h + AUTO_LABEL_AREA_PADDING)
+        );
+        chart.configure_mesh().draw().expect("Drawing error");
+
+        // A rotated label is measured across, and a fixed size overrides the automatic one
+        let (_, rotated_h) = drawing_area
+            .estimate_text_size("10", &font.transform(FontTransform::Rotate90))
+            .unwrap();
+        let chart = ChartBuilder::on(&drawing_area)
+            .x_label_area_auto()
+            .x_auto_label_style(
+                ("sans-serif", 12)
+                    .into_font()
+                    .transform(FontTransform::Rotate90),
+            )
+            .y_label_area_auto()
+            .y_label_area_size(30)
+            .build_ranged(0..10, 0..100_000)
+            .expect("Create chart");
+        assert_eq!(chart.plotting_area().get_base_pixel(), (30, 0));
+        assert_eq!(
+            chart.plotting_area().dim_in_pixel().1,
+            300 - (10 + rotated_h + AUTO_LABEL_AREA_PADDING)
+        );
+    }
+
+    #[test]
+    fn test_auto_label_area_formatter() {
+        let drawing_area = create_mocked_drawing_area(400, 300, |m| {
+            m.check_draw_text(|_, _, _, _, text| assert!(!text.contains("000")));
+        });
+        let font = ("sans-serif", 12).into_font();
+        let (label_w, _) = drawing_area.estimate_text_size("100k", &font).unwrap();
+
+        // The labels are measured with the formatter of the range, which the mesh uses too
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .y_label_area_auto()
+            .build_ranged(
+                0..10,
+                (0..100_000).with_label_formatter(|v| format!("{}k", v / 1000)),
+            )
+            .expect("Create chart");
+        assert_eq!(
+            chart.plotting_area().get_base_pixel(),
+            ((10 + label_w + AUTO_LABEL_AREA_PADDING) as i32, 0)
+        );
+        chart.configure_mesh().draw().expect("Drawing error");
+
+        // The labels of the logarithmic axis aren't formatted by the range, so only the ticks
+        // are fit
+        let chart = ChartBuilder::on(&drawing_area)
+            .y_label_area_auto()
+            .build_ranged(0..10, LogRange(1.0..1000.0))
+            .expect("Create chart");
+        assert_eq!(
+            chart.plotting_area().get_base_pixel(),
+            ((10 + AUTO_LABEL_AREA_PADDING) as i32, 0)
+        );
+    }
 }
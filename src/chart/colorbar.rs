@@ -0,0 +1,270 @@
+use std::fmt::Debug;
+
+use super::ChartContext;
+use crate::coord::{AsRangedCoord, CoordTranslate, Ranged};
+use crate::drawing::{ChartLayoutError, DrawingAreaErrorKind, DrawingBackend};
+use crate::element::{PathElement, Rectangle};
+use crate::style::{Color, ColorMap, FontTransform, ShapeStyle, TextStyle, BLACK};
+
+/// Where a colorbar is drawn, which decides its orientation
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ColorbarPosition {
+    /// A vertical colorbar on the right of the chart, in the area reserved with
+    /// `ChartBuilder::right_colorbar_area_size`
+    Right,
+    /// A horizontal colorbar below the chart, in the area reserved with
+    /// `ChartBuilder::bottom_colorbar_area_size`
+    Bottom,
+}
+
+/// The configuration of a colorbar, see `ChartContext::draw_colorbar`. The strip of the colors
+/// is as long as the plotting area, and it's followed by the ticks, the labels and the title.
+pub struct ColorbarConfig<'b> {
+    position: ColorbarPosition,
+    strip_width: u32,
+    gap: u32,
+    n_labels: usize,
+    tick_size: u32,
+    label_style: TextStyle<'b>,
+    title: Option<(String, TextStyle<'b>)>,
+    border_style: Option<ShapeStyle>,
+}
+
+impl<'b> ColorbarConfig<'b> {
+    /// Create the configuration of a colorbar, whose strip is 15 pixels wide, 10 pixels away
+    /// from the plotting side of its area, with about 5 labels and a black border
+    /// - `position`: Where the colorbar is drawn
+    pub fn new(position: ColorbarPosition) -> Self {
+        Self {
+            position,
+            strip_width: 15,
+            gap: 10,
+            n_labels: 5,
+            tick_size: 4,
+            label_style: ("sans-serif", 12).into(),
+            title: None,
+            border_style: Some((&BLACK).into()),
+        }
+    }
+
+    /// Set the width of the strip of the colors in pixels
+    pub fn strip_width(mut self, width: u32) -> Self {
+        self.strip_width = width.max(1);
+        self
+    }
+
+    /// Set the distance in pixels from the side of the area next to the plotting area to the strip
+    pub fn gap(mut self, gap: u32) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Set the maximum number of the labels, which are the key points of the value range
+    pub fn labels(mut self, n: usize) -> Self {
+        self.n_labels = n;
+        self
+    }
+
+    /// Set the length of the ticks in pixels, 0 means there isn't any tick
+    pub fn tick_size(mut self, size: u32) -> Self {
+        self.tick_size = size;
+        self
+    }
+
+    /// Set the style of the labels
+    pub fn label_style<T: Into<TextStyle<'b>>>(mut self, style: T) -> Self {
+        self.label_style = style.into();
+        self
+    }
+
+    /// Set the title of the colorbar, which is beyond the labels. The title of a vertical
+    /// colorbar reads from the bottom up.
+    pub fn title<S: Into<String>, T: Into<TextStyle<'b>>>(mut self, title: S, style: T) -> Self {
+        self.title = Some((title.into(), style.into()));
+        self
+    }
+
+    /// Set the style of the border around the strip, `None` for no border
+    pub fn border_style<T: Into<ShapeStyle>>(mut self, style: Option<T>) -> Self {
+        self.border_style = style.map(Into::into);
+        self
+    }
+}
+
+impl<'a, DB: DrawingBackend, CT: CoordTranslate> ChartContext<'a, DB, CT> {
+    /// Draw a colorbar for the series colored by a color map, such as a heatmap, in the area
+    /// the chart reserves for it. The strip samples the color map from 0 at the start of the
+    /// value range to 1 at its end, and the ticks and the labels are the key points of the range
+    /// as for an axis. The chart must reserve the area of the colorbar, otherwise it fails with
+    /// `ChartLayoutError::MissingArea`.
+    /// - `colormap`: The color map of the series
+    /// - `value_range`: The range of the values the color map is normalized over, which can be
+    ///   any axis specification, such as a log range
+    /// - `config`: The configuration of the colorbar
+    pub fn draw_colorbar<C: ColorMap, R: AsRangedCoord>(
+        &self,
+        colormap: &C,
+        value_range: R,
+        config: &ColorbarConfig,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        <R::CoordDescType as Ranged>::ValueType: Debug,
+    {
+        let vertical = config.position == ColorbarPosition::Right;
+        let area = self.colorbar_area[if vertical { 0 } else { 1 }]
+            .as_ref()
+            .ok_or(DrawingAreaErrorKind::LayoutError(
+                ChartLayoutError::MissingArea("colorbar"),
+            ))?;
+        let range: R::CoordDescType = value_range.into();
+
+        /* The strip spans the plotting area, whose pixels are relative to the colorbar area */
+        let (base, plot_base) = (area.get_base_pixel(), self.drawing_area.get_base_pixel());
+        let (plot_w, plot_h) = self.drawing_area.dim_in_pixel();
+        let (start, end) = if vertical {
+            let top = plot_base.1 - base.1;
+            (top + plot_h as i32 - 1, top)
+        } else {
+            let left = plot_base.0 - base.0;
+            (left, left + plot_w as i32 - 1)
+        };
+        let (strip0, strip1) = (
+            config.gap as i32,
+            (config.gap + config.strip_width) as i32 - 1,
+        );
+        let strip_rect = |from: i32, to: i32| {
+            if vertical {
+                [(strip0, from), (strip1, to)]
+            } else {
+                [(from, strip0), (to, strip1)]
+            }
+        };
+
+        let len = (end - start).abs().max(1);
+        for pos in start.min(end)..=start.max(end) {
+            let value = f64::from((pos - start).abs()) / f64::from(len);
+            let color = colormap.eval(value);
+            area.draw(&Rectangle::new(strip_rect(pos, pos), color.filled()))?;
+        }
+        if let Some(style) = &config.border_style {
+            area.draw(&Rectangle::new(
+                strip_rect(start.min(end), start.max(end)),
+                style.clone(),
+            ))?;
+        }
+
+        /* Then the ticks and the labels of the key points, beyond the strip */
+        let tick0 = strip1 + 1;
+        let label0 = tick0 + config.tick_size as i32 + 2;
+        let mut label_extent = 0;
+        for value in range.key_points(config.n_labels) {
+            let pos = range.map(&value, (start, end));
+            let text = range
+                .format_label(&value)
+                .unwrap_or_else(|| format!("{:?}", value));
+            let (w, h) = area.estimate_text_size(&text, &config.label_style.font)?;
+            let (w, h) = (w as i32, h as i32);
+            if config.tick_size > 0 {
+                let tick1 = tick0 + config.tick_size as i32;
+                let points = if vertical {
+                    vec![(tick0, pos), (tick1, pos)]
+                } else {
+                    vec![(pos, tick0), (pos, tick1)]
+                };
+                let style = config
+                    .border_style
+                    .clone()
+                    .unwrap_or_else(|| (&BLACK).into());
+                area.draw(&PathElement::new(points, style))?;
+            }
+            let text_pos = if vertical {
+                (label0, pos - h / 2)
+            } else {
+                (pos - w / 2, label0)
+            };
+            area.draw_text(&text, &config.label_style, text_pos)?;
+            label_extent = label_extent.max(if vertical { w } else { h });
+        }
+
+        if let Some((title, style)) = &config.title {
+            let style = if vertical {
+                style.transform(FontTransform::Rotate270)
+            } else {
+                style.clone()
+            };
+            let (w, h) = area.estimate_text_size(title, &style.font)?;
+            let (w, h) = (w as i32, h as i32);
+            let offset = label0 + label_extent + 4;
+            let middle = (start + end) / 2;
+            let pos = if vertical {
+                (offset, middle - h / 2)
+            } else {
+                (middle - w / 2, offset)
+            };
+            area.draw_text(title, &style, pos)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_colorbar() {
+        let texts = Rc::new(RefCell::new(vec![]));
+        let drawn = texts.clone();
+        let drawing_area = create_mocked_drawing_area(300, 200, move |m| {
+            // The strip goes from the last color of the map at the top to the first one at
+            // the bottom, one row of pixels each, and the first row is drawn first
+            m.check_draw_rect(|c, _, filled, ul, br| {
+                assert_eq!(c, WHITE.to_rgba());
+                assert!(filled);
+                assert_eq!((ul, br), ((240, 0), (254, 0)));
+            });
+            // The rest of the rows and the border are within the columns of the strip
+            m.check_draw_rect(|_, _, _, ul, br| {
+                assert_eq!((ul.0, br.0), (240, 254));
+            });
+            m.check_draw_text(move |_, _, _, pos, text| {
+                drawn.borrow_mut().push((pos, text.to_string()))
+            });
+            m.drop_check(|b| assert_eq!(b.num_draw_rect_call, 201));
+        });
+
+        let chart = ChartBuilder::on(&drawing_area)
+            .right_colorbar_area_size(70)
+            .build_ranged(0..10, 0..10)
+            .expect("Create chart");
+        let colormap = |v: f64| {
+            let level = (v * 255.0).round() as u8;
+            RGBColor(level, level, level)
+        };
+        chart
+            .draw_colorbar(
+                &colormap,
+                0.0..1.0,
+                &ColorbarConfig::new(ColorbarPosition::Right).title("Level", ("sans-serif", 10)),
+            )
+            .expect("Drawing error");
+
+        let texts = texts.borrow();
+        // The labels are on the right of the strip, at the key points of the range
+        assert!(texts.iter().any(|(_, t)| t == "0.0"));
+        assert!(texts.iter().any(|(_, t)| t == "1.0"));
+        assert!(texts.iter().all(|(pos, _)| pos.0 >= 261));
+        assert_eq!(texts.last().unwrap().1, "Level");
+
+        // The chart without the area of the colorbar can't draw it
+        let drawing_area = create_mocked_drawing_area(300, 200, |_| {});
+        let chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0..10, 0..10)
+            .expect("Create chart");
+        let config = ColorbarConfig::new(ColorbarPosition::Bottom);
+        assert!(chart.draw_colorbar(&VIRIDIS, 0.0..1.0, &config).is_err());
+    }
+}
@@ -3,17 +3,30 @@ use std::fmt::Debug;
 use std::marker::PhantomData;
 use std::ops::Range;
 
+use super::builder::{check_axis_range, LabelAreaPosition};
 use super::dual_coord::DualCoordChartContext;
-use super::mesh::MeshStyle;
+use super::mesh::{AxisDescPosition, MeshStyle, TickMarkDirection};
 use super::series::SeriesLabelStyle;
+use super::z_order::DeferredDraw;
 
 use crate::coord::{
-    AsRangedCoord, CoordTranslate, MeshLine, Ranged, RangedCoord, ReverseCoordTranslate, Shift,
+    AsRangedCoord, Cartesian3d, CoordTranslate, MeshLine, ProjectionMatrixBuilder, Ranged,
+    RangedCoord, ReverseCoordTranslate, Shift,
 };
 use crate::drawing::backend::{BackendCoord, DrawingBackend};
-use crate::drawing::{DrawingArea, DrawingAreaErrorKind};
-use crate::element::{Drawable, DynElement, IntoDynElement, PathElement, PointCollection};
-use crate::style::{AsRelative, FontTransform, ShapeStyle, SizeDesc, TextStyle};
+use crate::drawing::{ChartLayoutError, DrawingArea, DrawingAreaErrorKind, OutOfRange};
+use crate::element::{
+    Drawable, DynElement, IntoDynElement, PathElement, PointCollection, Rectangle,
+};
+use crate::style::{
+    AsRelative, FontTransform, Palette, PaletteColor, ShapeStyle, SizeDesc, TextStyle,
+};
+
+/// The half size in pixels of the zig-zag glyph that marks a break on an axis
+const AXIS_BREAK_SIZE: i32 = 4;
+
+/// The gap in pixels between the labels of the key points and the labels of their groups
+const GROUP_LABEL_GAP: i32 = 5;
 
 /// The annotations (such as the label of the series, the legend element, etc)
 /// When a series is drawn onto a drawing area, an series annotation object
@@ -72,6 +85,14 @@ pub struct ChartContext<'a, DB: DrawingBackend, CT: CoordTranslate> {
     pub(super) y_label_area: [Option<DrawingArea<DB, Shift>>; 2],
     pub(super) drawing_area: DrawingArea<DB, CT>,
     pub(super) series_anno: Vec<SeriesAnno<'a, DB>>,
+    pub(super) series_label_area: [Option<DrawingArea<DB, Shift>>; 2],
+    pub(super) colorbar_area: [Option<DrawingArea<DB, Shift>>; 2],
+    pub(super) out_of_range: OutOfRange,
+    pub(super) clip_text: bool,
+    /// The tick labels drawn by the last mesh on each side, indexed by `LabelAreaPosition`
+    pub(super) drawn_labels: [Vec<(i32, String)>; 4],
+    /// The draws kept by `draw_with_z_order` with their z-indexes
+    pub(super) deferred: Vec<(i32, DeferredDraw<'a, DB, CT>)>,
 }
 
 impl<
@@ -133,17 +154,32 @@ impl<
             n_y_labels: 10,
             line_style_1: None,
             line_style_2: None,
+            x_line_styles: [None, None],
+            y_line_styles: [None, None],
+            x_bold_line_predicate: None,
+            y_bold_line_predicate: None,
             x_label_style: None,
             y_label_style: None,
-            format_x: &|x| format!("{:?}", x),
-            format_y: &|y| format!("{:?}", y),
+            format_x: None,
+            format_y: None,
             target: Some(self),
             _phantom_data: PhantomData,
             x_desc: None,
             y_desc: None,
             axis_desc_style: None,
+            axis_desc_gap: None,
+            axis_desc_position: AxisDescPosition::Center,
+            tick_direction: TickMarkDirection::Outside,
+            tick_sides: None,
+            frame_style: None,
+            thin_labels: true,
             x_tick_size,
             y_tick_size,
+            n_x_minor: 0,
+            n_y_minor: 0,
+            minor_line_style: None,
+            minor_tick_size: base_tick_size / 2,
+            x_label_rotation: None,
         }
     }
 }
@@ -160,47 +196,48 @@ impl<'a, DB: DrawingBackend + 'a, CT: CoordTranslate> ChartContext<'a, DB, CT> {
     }
 }
 
-impl<'a, DB: DrawingBackend, CT: ReverseCoordTranslate> ChartContext<'a, DB, CT> {
-    /// Convert the chart context into an closure that can be used for coordinate translation
-    pub fn into_coord_trans(self) -> impl Fn(BackendCoord) -> Option<CT::From> {
-        let coord_spec = self.drawing_area.into_coord_spec();
-        move |coord| coord_spec.reverse_translate(coord)
-    }
-}
-
-impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, RangedCoord<X, Y>> {
-    /// Get the range of X axis
-    pub fn x_range(&self) -> Range<X::ValueType> {
-        self.drawing_area.get_x_range()
-    }
-
-    /// Get range of the Y axis
-    pub fn y_range(&self) -> Range<Y::ValueType> {
-        self.drawing_area.get_y_range()
-    }
-
-    /// Maps the coordinate to the backend coordinate. This is typically used
-    /// with an interactive chart.
-    pub fn backend_coord(&self, coord: &(X::ValueType, Y::ValueType)) -> BackendCoord {
-        self.drawing_area.map_coordinate(coord)
-    }
-
+impl<'a, DB: DrawingBackend, CT: CoordTranslate> ChartContext<'a, DB, CT> {
     pub(super) fn draw_series_impl<E, R, S>(
         &mut self,
         series: S,
     ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
     where
-        for<'b> &'b E: PointCollection<'b, (X::ValueType, Y::ValueType)>,
+        for<'b> &'b E: PointCollection<'b, CT::From>,
         E: Drawable<DB>,
         R: Borrow<E>,
         S: IntoIterator<Item = R>,
     {
+        let (w, h) = self.drawing_area.dim_in_pixel();
+        if w == 0 || h == 0 {
+            return Err(DrawingAreaErrorKind::LayoutError(
+                ChartLayoutError::DegenerateArea,
+            ));
+        }
         for element in series {
-            self.drawing_area.draw(element.borrow())?;
+            self.drawing_area
+                .draw_with_policy(element.borrow(), self.out_of_range)?;
         }
         Ok(())
     }
 
+    /// Set how the parts of the series outside of the plotting area are drawn. By default,
+    /// they are pinned to the edges of the plotting area. With `OutOfRange::Clip`, the lines
+    /// crossing the edges are cut there, which suits the live charts whose data may exceed
+    /// their range for a moment.
+    /// - `policy`: How the parts outside of the plotting area are drawn
+    pub fn set_out_of_range(&mut self, policy: OutOfRange) -> &mut Self {
+        self.out_of_range = policy;
+        self
+    }
+
+    /// Set if the texts of the series drawn by `draw_series_clipped` are clipped to the
+    /// plotting area as well. By default, they aren't, so the labels near the edges can be read.
+    /// - `clip`: If the texts are clipped
+    pub fn set_clip_text(&mut self, clip: bool) -> &mut Self {
+        self.clip_text = clip;
+        self
+    }
+
     pub(super) fn alloc_series_anno(&mut self) -> &mut SeriesAnno<'a, DB> {
         let idx = self.series_anno.len();
         self.series_anno.push(SeriesAnno::new());
@@ -213,7 +250,7 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, RangedCo
         series: S,
     ) -> Result<&mut SeriesAnno<'a, DB>, DrawingAreaErrorKind<DB::ErrorType>>
     where
-        for<'b> &'b E: PointCollection<'b, (X::ValueType, Y::ValueType)>,
+        for<'b> &'b E: PointCollection<'b, CT::From>,
         E: Drawable<DB>,
         R: Borrow<E>,
         S: IntoIterator<Item = R>,
@@ -222,36 +259,231 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, RangedCo
         Ok(self.alloc_series_anno())
     }
 
+    /// Draw a data series clipped to the plotting area. Unlike `OutOfRange::Clip`, which cuts
+    /// the elements at the edges, this clips the pixels on the backend, so the markers and the
+    /// wide lines near the edges don't spill out of it. The backends which can't clip draw the
+    /// series as `draw_series` does.
+    pub fn draw_series_clipped<E, R, S>(
+        &mut self,
+        series: S,
+    ) -> Result<&mut SeriesAnno<'a, DB>, DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        for<'b> &'b E: PointCollection<'b, CT::From>,
+        E: Drawable<DB>,
+        R: Borrow<E>,
+        S: IntoIterator<Item = R>,
+    {
+        self.drawing_area.clip_to_area(self.clip_text)?;
+        let result = self.draw_series_impl(series);
+        // The clip is lifted even if the drawing failed
+        self.drawing_area.reset_clip()?;
+        result?;
+        Ok(self.alloc_series_anno())
+    }
+
+    /// Get the color of the next series from the palette. Each series drawn on the chart moves
+    /// to the next color, so the series get the colors of the palette in turn without picking
+    /// them by hand, and the colors wrap around after the last one.
+    ///
+    /// - **returns**: The color of the palette for the series that is going to be drawn
+    pub fn next_series_color<P: Palette>(&self) -> PaletteColor<P> {
+        PaletteColor::pick(self.series_anno.len())
+    }
+}
+
+impl<'a, DB: DrawingBackend, CT: ReverseCoordTranslate> ChartContext<'a, DB, CT> {
+    /// Get a closure that maps a pixel of the drawing area back to the guest coordinate. It
+    /// returns `None` for the pixels outside of the plotting area, so it can be used to find
+    /// the data value under the mouse cursor of an interactive frontend.
+    pub fn coord_trans(&self) -> impl Fn(BackendCoord) -> Option<CT::From> + '_ {
+        let coord_spec = self.drawing_area.as_coord_spec();
+        move |coord| coord_spec.reverse_translate(coord)
+    }
+
+    /// Convert the chart context into an closure that can be used for coordinate translation
+    pub fn into_coord_trans(self) -> impl Fn(BackendCoord) -> Option<CT::From> {
+        let coord_spec = self.into_drawing_area().into_coord_spec();
+        move |coord| coord_spec.reverse_translate(coord)
+    }
+}
+
+impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, RangedCoord<X, Y>> {
+    /// Get the range of X axis
+    pub fn x_range(&self) -> Range<X::ValueType> {
+        self.drawing_area.get_x_range()
+    }
+
+    /// Get range of the Y axis
+    pub fn y_range(&self) -> Range<Y::ValueType> {
+        self.drawing_area.get_y_range()
+    }
+
+    /// Maps the coordinate to the backend coordinate. This is typically used
+    /// with an interactive chart.
+    pub fn backend_coord(&self, coord: &(X::ValueType, Y::ValueType)) -> BackendCoord {
+        self.drawing_area.map_coordinate(coord)
+    }
+
+    /// Get the pixel of a value of the X axis, which is relative to the plotting area
+    fn x_pixel(&self, x: X::ValueType) -> i32 {
+        let y = self.y_range().start;
+        self.backend_coord(&(x, y)).0 - self.drawing_area.get_base_pixel().0
+    }
+
+    /// Get the pixel of a value of the Y axis, which is relative to the plotting area
+    fn y_pixel(&self, y: Y::ValueType) -> i32 {
+        let x = self.x_range().start;
+        self.backend_coord(&(x, y)).1 - self.drawing_area.get_base_pixel().1
+    }
+
+    /// Draw a horizontal reference line across the plotting area, such as a threshold. The line
+    /// isn't drawn if the value is out of the range of the Y axis.
+    ///
+    /// The line is drawn right away, across the ranges the chart is built with. Like any other
+    /// draw, it can be kept for later with `draw_with_z_order`.
+    /// - `y`: The value of the Y axis of the line
+    /// - `style`: The style of the line, which can be dashed
+    pub fn draw_horizontal_line<S: Into<ShapeStyle>>(
+        &self,
+        y: Y::ValueType,
+        style: S,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let area = self.drawing_area.strip_coord_spec();
+        let (w, h) = area.dim_in_pixel();
+        let y = self.y_pixel(y);
+        if y < 0 || y >= h as i32 {
+            return Ok(());
+        }
+        area.draw(&PathElement::new(vec![(0, y), (w as i32 - 1, y)], style))
+    }
+
+    /// Draw a vertical reference line across the plotting area. The line isn't drawn if the
+    /// value is out of the range of the X axis. It's drawn right away, see
+    /// `draw_horizontal_line`.
+    /// - `x`: The value of the X axis of the line
+    /// - `style`: The style of the line, which can be dashed
+    pub fn draw_vertical_line<S: Into<ShapeStyle>>(
+        &self,
+        x: X::ValueType,
+        style: S,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let area = self.drawing_area.strip_coord_spec();
+        let (w, h) = area.dim_in_pixel();
+        let x = self.x_pixel(x);
+        if x < 0 || x >= w as i32 {
+            return Ok(());
+        }
+        area.draw(&PathElement::new(vec![(x, 0), (x, h as i32 - 1)], style))
+    }
+
+    /// Shade a horizontal band between two values of the Y axis across the plotting area. The
+    /// band is drawn right away, so the series drawn after it are on top of it, see
+    /// `draw_horizontal_line`.
+    /// - `y0`, `y1`: The values of the Y axis the band is between
+    /// - `style`: The style of the band, which is usually a filled translucent color
+    pub fn draw_horizontal_span<S: Into<ShapeStyle>>(
+        &self,
+        y0: Y::ValueType,
+        y1: Y::ValueType,
+        style: S,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let area = self.drawing_area.strip_coord_spec();
+        let (w, _) = area.dim_in_pixel();
+        let (y0, y1) = (self.y_pixel(y0), self.y_pixel(y1));
+        area.draw(&Rectangle::new([(0, y0), (w as i32 - 1, y1)], style))
+    }
+
+    /// Shade a vertical band between two values of the X axis across the plotting area. The
+    /// band is drawn right away, so the series drawn after it are on top of it, see
+    /// `draw_horizontal_line`.
+    /// - `x0`, `x1`: The values of the X axis the band is between
+    /// - `style`: The style of the band, which is usually a filled translucent color
+    pub fn draw_vertical_span<S: Into<ShapeStyle>>(
+        &self,
+        x0: X::ValueType,
+        x1: X::ValueType,
+        style: S,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let area = self.drawing_area.strip_coord_spec();
+        let (_, h) = area.dim_in_pixel();
+        let (x0, x1) = (self.x_pixel(x0), self.x_pixel(x1));
+        area.draw(&Rectangle::new([(x0, 0), (x1, h as i32 - 1)], style))
+    }
+
+    /// Get the tick labels drawn by the last mesh on a side of the chart, which are the positions
+    /// of their key points in pixels and their texts. The labels left out as they would overlap
+    /// the others aren't included.
+    /// - `pos`: The label area of the labels
+    pub fn drawn_labels(&self, pos: LabelAreaPosition) -> &[(i32, String)] {
+        &self.drawn_labels[pos as usize]
+    }
+
     /// The actual function that draws the mesh lines.
     /// It also returns the label that suppose to be there.
     #[allow(clippy::type_complexity)]
-    fn draw_mesh_lines<FmtLabel>(
+    fn draw_mesh_lines<FmtLabel, LineStyle>(
         &mut self,
         (r, c): (usize, usize),
-        (x_mesh, y_mesh): (bool, bool),
-        mesh_line_style: &ShapeStyle,
+        mut line_style: LineStyle,
         mut fmt_label: FmtLabel,
     ) -> Result<(Vec<(i32, String)>, Vec<(i32, String)>), DrawingAreaErrorKind<DB::ErrorType>>
     where
-        FmtLabel: FnMut(&MeshLine<X, Y>) -> Option<String>,
+        FmtLabel: FnMut(&RangedCoord<X, Y>, &MeshLine<X, Y>) -> Option<String>,
+        LineStyle: FnMut(&MeshLine<X, Y>) -> Option<ShapeStyle>,
     {
         let mut x_labels = vec![];
         let mut y_labels = vec![];
+        let coord = self.drawing_area.as_coord_spec();
         self.drawing_area.draw_mesh(
             |b, l| {
-                let draw;
                 match l {
                     MeshLine::XMesh((x, _), _, _) => {
-                        if let Some(label_text) = fmt_label(&l) {
+                        if let Some(label_text) = fmt_label(coord, &l) {
                             x_labels.push((x, label_text));
                         }
-                        draw = x_mesh;
                     }
                     MeshLine::YMesh((_, y), _, _) => {
-                        if let Some(label_text) = fmt_label(&l) {
+                        if let Some(label_text) = fmt_label(coord, &l) {
                             y_labels.push((y, label_text));
                         }
-                        draw = y_mesh;
+                    }
+                };
+                match line_style(&l) {
+                    Some(style) => l.draw(b, &style),
+                    None => Ok(()),
+                }
+            },
+            r,
+            c,
+        )?;
+        Ok((x_labels, y_labels))
+    }
+
+    /// Draw the minor grid lines of the axes with a minor count, and their ticks of
+    /// `tick_size` pixels on the label areas of the axes that are drawn
+    #[allow(clippy::too_many_arguments)]
+    pub(super) fn draw_minor_mesh(
+        &self,
+        (r, c): (usize, usize),
+        minor_count: (usize, usize),
+        (x_mesh, y_mesh): (bool, bool),
+        (x_axis, y_axis): (bool, bool),
+        mesh_line_style: &ShapeStyle,
+        axis_style: &ShapeStyle,
+        tick_size: i32,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let mut x_ticks = vec![];
+        let mut y_ticks = vec![];
+        self.drawing_area.draw_minor_mesh(
+            |b, l| {
+                let draw = match l {
+                    MeshLine::XMesh((x, _), _, _) => {
+                        x_ticks.push(x);
+                        x_mesh
+                    }
+                    MeshLine::YMesh((_, y), _, _) => {
+                        y_ticks.push(y);
+                        y_mesh
                     }
                 };
                 if draw {
@@ -262,8 +494,48 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, RangedCo
             },
             r,
             c,
+            minor_count,
         )?;
-        Ok((x_labels, y_labels))
+
+        if tick_size <= 0 {
+            return Ok(());
+        }
+
+        let (x0, y0) = self.drawing_area.get_base_pixel();
+        for (idx, area) in self.x_label_area.iter().enumerate() {
+            if let (Some(area), true) = (area, x_axis) {
+                let (_, th) = area.dim_in_pixel();
+                let (from, to) = if idx == 0 {
+                    (th as i32 - tick_size, th as i32)
+                } else {
+                    (0, tick_size)
+                };
+                for x in x_ticks.iter().map(|x| x - x0) {
+                    area.draw(&PathElement::new(
+                        vec![(x, from), (x, to)],
+                        axis_style.clone(),
+                    ))?;
+                }
+            }
+        }
+        for (idx, area) in self.y_label_area.iter().enumerate() {
+            if let (Some(area), true) = (area, y_axis) {
+                let (tw, _) = area.dim_in_pixel();
+                let (from, to) = if idx == 0 {
+                    (tw as i32 - tick_size, tw as i32)
+                } else {
+                    (0, tick_size)
+                };
+                for y in y_ticks.iter().map(|y| y - y0) {
+                    area.draw(&PathElement::new(
+                        vec![(from, y), (to, y)],
+                        axis_style.clone(),
+                    ))?;
+                }
+            }
+        }
+
+        Ok(())
     }
 
     fn draw_axis(
@@ -276,10 +548,17 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, RangedCo
         let (x0, y0) = self.drawing_area.get_base_pixel();
         let (tw, th) = area.dim_in_pixel();
 
-        let mut axis_range = if orientation.0 == 0 {
-            self.drawing_area.get_x_axis_pixel_range()
+        let coord = self.drawing_area.as_coord_spec();
+        let (mut axis_range, axis_breaks) = if orientation.0 == 0 {
+            (
+                self.drawing_area.get_x_axis_pixel_range(),
+                coord.get_x_axis_breaks(),
+            )
         } else {
-            self.drawing_area.get_y_axis_pixel_range()
+            (
+                self.drawing_area.get_y_axis_pixel_range(),
+                coord.get_y_axis_breaks(),
+            )
         };
 
         /* At this point, the coordinate system tells us the pixel range
@@ -329,6 +608,26 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, RangedCo
                 vec![(x0, y0), (x1, y1)],
                 axis_style.clone(),
             ))?;
+
+            /* Mark the breaks of the axis with a zig-zag on the axis line, which goes into
+             * the label area, since the drawing is truncated to the area */
+            let size = AXIS_BREAK_SIZE;
+            for p in axis_breaks {
+                let glyph: Vec<_> = if orientation.0 == 0 {
+                    let p = p - self.drawing_area.get_base_pixel().0;
+                    let dir = if y0 == 0 { 1 } else { -1 };
+                    (-2i32..=2)
+                        .map(|i| (p + i * size, y0 + (i % 2).abs() * 2 * size * dir))
+                        .collect()
+                } else {
+                    let p = p - self.drawing_area.get_base_pixel().1;
+                    let dir = if x0 == 0 { 1 } else { -1 };
+                    (-2i32..=2)
+                        .map(|i| (x0 + (i % 2).abs() * 2 * size * dir, p + i * size))
+                        .collect()
+                };
+                area.draw(&PathElement::new(glyph, axis_style.clone()))?;
+            }
         }
 
         Ok(axis_range)
@@ -363,21 +662,26 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, RangedCo
         label_offset: i32,
         orientation: (i16, i16),
         axis_desc: Option<(&str, &TextStyle)>,
-        tick_size: i32,
-    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        (desc_gap, desc_pos): (Option<i32>, AxisDescPosition),
+        (tick_size, tick_direction, draw_ticks): (i32, TickMarkDirection, bool),
+        thin_labels: bool,
+    ) -> Result<Vec<(i32, String)>, DrawingAreaErrorKind<DB::ErrorType>> {
         let area = if let Some(target) = area {
             target
         } else {
-            return Ok(());
+            return Ok(vec![]);
         };
 
         let (x0, y0) = self.drawing_area.get_base_pixel();
         let (tw, th) = area.dim_in_pixel();
 
-        /* This is the minimal distance from the axis to the box of the labels */
+        /* This is the minimal distance from the axis to the box of the labels, which is a tick
+         * length from the outer ends of the ticks */
         let label_dist =
             if (tick_size > 0 && orientation.1 > 0) || (tick_size < 0 && orientation.1 < 0) {
                 0
+            } else if tick_size > 0 {
+                tick_direction.extents(tick_size).0 + tick_size
             } else {
                 tick_size.abs() * 2
             };
@@ -395,7 +699,8 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, RangedCo
             0
         };
 
-        /* Then we need to draw the tick mark and the label */
+        /* Then we need to place the labels, with their boxes along the axis */
+        let mut placed = vec![];
         for (p, t) in labels {
             /* Make sure we are actually in the visible range */
             let rp = if orientation.0 == 0 { *p - x0 } else { *p - y0 };
@@ -454,79 +759,244 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, RangedCo
                 } else {
                     (cx, cy - h as i32 / 2 + label_offset)
                 };
+                let span = if orientation.0 == 0 {
+                    (text_x, text_x + w as i32)
+                } else {
+                    (text_y, text_y + h as i32)
+                };
+                placed.push((*p, t, (text_x, text_y), span));
+            }
+        }
+
+        /* Leave out the labels which would overlap the previous one, so that the dense or the
+         * long labels stay readable, but always keep the first and the last label */
+        let mut drawn: Vec<usize> = (0..placed.len()).collect();
+        if thin_labels && placed.len() > 1 {
+            let overlaps = |i: usize, j: usize| {
+                let ((a0, a1), (b0, b1)) = (placed[i].3, placed[j].3);
+                a0 < b1 && b0 < a1
+            };
+            let last = placed.len() - 1;
+            drawn = vec![0];
+            for idx in 1..last {
+                if !overlaps(idx, *drawn.last().unwrap()) {
+                    drawn.push(idx);
+                }
+            }
+            while drawn.len() > 1 && overlaps(last, *drawn.last().unwrap()) {
+                drawn.pop();
+            }
+            if !overlaps(last, drawn[0]) {
+                drawn.push(last);
+            }
+        }
 
-                area.draw_text(&t, label_style, (text_x, text_y))?;
-
-                if let Some(style) = axis_style {
-                    let (kx0, ky0, kx1, ky1) = if tick_size > 0 {
-                        match orientation {
-                            (dx, dy) if dx > 0 && dy == 0 => (0, *p - y0, tick_size, *p - y0),
-                            (dx, dy) if dx < 0 && dy == 0 => {
-                                (tw as i32 - tick_size, *p - y0, tw as i32, *p - y0)
-                            }
-                            (dx, dy) if dx == 0 && dy > 0 => (*p - x0, 0, *p - x0, tick_size),
-                            (dx, dy) if dx == 0 && dy < 0 => {
-                                (*p - x0, th as i32 - tick_size, *p - x0, th as i32)
-                            }
-                            _ => panic!("Bug: Invalid orientation specification"),
+        /* Then we need to draw the labels and the tick marks, the ticks of the labels left out
+         * are still drawn */
+        let mut drawn_labels = vec![];
+        for (idx, (p, t, text_pos, _)) in placed.iter().enumerate() {
+            if drawn.contains(&idx) {
+                area.draw_text(t, label_style, *text_pos)?;
+                drawn_labels.push((*p, t.to_string()));
+            }
+            if let (Some(style), true) = (axis_style, draw_ticks) {
+                let (kx0, ky0, kx1, ky1) = if tick_size > 0 {
+                    let (out, inn) = tick_direction.extents(tick_size);
+                    match orientation {
+                        (dx, dy) if dx > 0 && dy == 0 => (-inn, *p - y0, out, *p - y0),
+                        (dx, dy) if dx < 0 && dy == 0 => {
+                            (tw as i32 - out, *p - y0, tw as i32 + inn, *p - y0)
                         }
-                    } else {
-                        match orientation {
-                            (dx, dy) if dx > 0 && dy == 0 => {
-                                (tw as i32, *p - y0, tw as i32 + tick_size, *p - y0)
-                            }
-                            (dx, dy) if dx < 0 && dy == 0 => (0, *p - y0, -tick_size, *p - y0),
-                            (dx, dy) if dx == 0 && dy > 0 => {
-                                (*p - x0, th as i32, *p - x0, th as i32 + tick_size)
-                            }
-                            (dx, dy) if dx == 0 && dy < 0 => (*p - x0, 0, *p - x0, -tick_size),
-                            _ => panic!("Bug: Invalid orientation specification"),
+                        (dx, dy) if dx == 0 && dy > 0 => (*p - x0, -inn, *p - x0, out),
+                        (dx, dy) if dx == 0 && dy < 0 => {
+                            (*p - x0, th as i32 - out, *p - x0, th as i32 + inn)
                         }
-                    };
-                    let line = PathElement::new(vec![(kx0, ky0), (kx1, ky1)], style.clone());
-                    area.draw(&line)?;
-                }
+                        _ => panic!("Bug: Invalid orientation specification"),
+                    }
+                } else {
+                    match orientation {
+                        (dx, dy) if dx > 0 && dy == 0 => {
+                            (tw as i32, *p - y0, tw as i32 + tick_size, *p - y0)
+                        }
+                        (dx, dy) if dx < 0 && dy == 0 => (0, *p - y0, -tick_size, *p - y0),
+                        (dx, dy) if dx == 0 && dy > 0 => {
+                            (*p - x0, th as i32, *p - x0, th as i32 + tick_size)
+                        }
+                        (dx, dy) if dx == 0 && dy < 0 => (*p - x0, 0, *p - x0, -tick_size),
+                        _ => panic!("Bug: Invalid orientation specification"),
+                    }
+                };
+                /* The ticks going inward are drawn over the plotting area */
+                let line = PathElement::new(vec![(kx0, ky0), (kx1, ky1)], style.clone());
+                area.draw_with_policy(&line, OutOfRange::Draw)?;
+            }
+        }
+
+        /* Then the second row of labels for the groups of the key points, with a separator
+         * between two groups. It's only drawn with the labels of the key points, when they
+         * are outside of the plotting area. */
+        let groups = if labels.is_empty() || tick_size < 0 {
+            vec![]
+        } else if orientation.0 == 0 {
+            self.drawing_area.as_coord_spec().get_x_axis_groups()
+        } else {
+            self.drawing_area.as_coord_spec().get_y_axis_groups()
+        };
+        let text_size = |text: &str| {
+            let (w, h) = self
+                .drawing_area
+                .estimate_text_size(text, &label_style.font)
+                .unwrap_or((0, 0));
+            (w as i32, h as i32)
+        };
+        let (inner_w, inner_h) = labels
+            .iter()
+            .map(|(_, t)| text_size(t))
+            .fold((0, 0), |(w, h), (tw, th)| (w.max(tw), h.max(th)));
+        for (idx, (range, text)) in groups.iter().enumerate() {
+            let base = if orientation.0 == 0 { x0 } else { y0 };
+            let start = range.start.min(range.end) - base;
+            let mid = (range.start + range.end) / 2 - base;
+            let (w, h) = text_size(text);
+            let gap = GROUP_LABEL_GAP;
+
+            let (pos, separator) = match orientation {
+                // Right
+                (dx, dy) if dx > 0 && dy == 0 => (
+                    (label_dist + right_alignment + gap, mid - h / 2),
+                    [(0, start), (tw as i32, start)],
+                ),
+                // Left
+                (dx, dy) if dx < 0 && dy == 0 => (
+                    (tw as i32 - label_dist - inner_w - gap - w, mid - h / 2),
+                    [(0, start), (tw as i32, start)],
+                ),
+                // Bottom
+                (dx, dy) if dx == 0 && dy > 0 => (
+                    (mid - w / 2, label_dist + inner_h * 2 + gap),
+                    [(start, 0), (start, th as i32)],
+                ),
+                // Top
+                (dx, dy) if dx == 0 && dy < 0 => (
+                    (mid - w / 2, th as i32 - label_dist - inner_h - gap - h),
+                    [(start, 0), (start, th as i32)],
+                ),
+                _ => panic!("Bug: Invalid orientation specification"),
+            };
+
+            area.draw_text(text, label_style, pos)?;
+            if let (true, Some(style)) = (idx > 0, axis_style) {
+                area.draw(&PathElement::new(separator.to_vec(), style.clone()))?;
             }
         }
 
         if let Some((text, style)) = axis_desc {
+            /* The descriptions of both of the y axes read from the bottom up */
             let actual_style = if orientation.0 == 0 {
                 style.clone()
-            } else if orientation.0 == -1 {
-                style.transform(FontTransform::Rotate270)
             } else {
-                style.transform(FontTransform::Rotate90)
+                style.transform(FontTransform::Rotate270)
             };
 
             let (w, h) = self
                 .drawing_area
                 .estimate_text_size(text, &actual_style.font)
                 .unwrap_or((0, 0));
+            let (w, h, tw, th) = (w as i32, h as i32, tw as i32, th as i32);
+
+            /* The distance from the axis to the outer edge of the tick labels, including the
+             * row of the group labels, if the description should keep a gap from them */
+            let dist = desc_gap.map(|gap| {
+                let group_sizes = groups.iter().map(|(_, text)| text_size(text));
+                let group_w = group_sizes.clone().map(|(w, _)| w).max();
+                let group_h = group_sizes.map(|(_, h)| h).max();
+                let extent = match orientation {
+                    _ if labels.is_empty() || tick_size < 0 => 0,
+                    (dx, _) if dx > 0 => label_dist + right_alignment,
+                    (dx, _) if dx < 0 => label_dist + inner_w,
+                    (_, dy) if dy > 0 => label_dist + inner_h * 2,
+                    _ => label_dist + inner_h,
+                };
+                let group_extent = if orientation.0 == 0 { group_h } else { group_w };
+                extent + group_extent.map_or(0, |size| GROUP_LABEL_GAP + size) + gap
+            });
+
+            let along_x = match desc_pos {
+                AxisDescPosition::Center => (tw - w) / 2,
+                AxisDescPosition::End => tw - w,
+            };
+            let along_y = match desc_pos {
+                AxisDescPosition::Center => (th - h) / 2,
+                AxisDescPosition::End => 0,
+            };
 
             let (x0, y0) = match orientation {
-                (dx, dy) if dx > 0 && dy == 0 => (tw - w, (th - h) / 2),
-                (dx, dy) if dx < 0 && dy == 0 => (0, (th - h) / 2),
-                (dx, dy) if dx == 0 && dy > 0 => ((tw - w) / 2, th - h),
-                (dx, dy) if dx == 0 && dy < 0 => ((tw - w) / 2, 0),
+                (dx, dy) if dx > 0 && dy == 0 => (dist.unwrap_or(tw - w), along_y),
+                (dx, dy) if dx < 0 && dy == 0 => (dist.map_or(0, |d| tw - d - w), along_y),
+                (dx, dy) if dx == 0 && dy > 0 => (along_x, dist.unwrap_or(th - h)),
+                (dx, dy) if dx == 0 && dy < 0 => (along_x, dist.map_or(0, |d| th - d - h)),
                 _ => panic!("Bug: Invalid orientation specification"),
             };
 
-            area.draw_text(&text, &actual_style, (x0 as i32, y0 as i32))?;
+            /* Keep the description inside of the label area, so that it isn't cut off when the
+             * area is too small for the labels and the description */
+            let x0 = x0.min(tw - w).max(0);
+            let y0 = y0.min(th - h).max(0);
+
+            area.draw_text(&text, &actual_style, (x0, y0))?;
         }
 
+        Ok(drawn_labels)
+    }
+
+    /// Draw the ticks of a side of the plotting area without a label area, the sides are the
+    /// indices of `LabelAreaPosition`
+    fn draw_frame_ticks(
+        &self,
+        side: usize,
+        labels: &[(i32, String)],
+        tick_size: i32,
+        direction: TickMarkDirection,
+        style: &ShapeStyle,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let area = self.drawing_area.strip_coord_spec();
+        let (x0, y0) = area.get_base_pixel();
+        let (w, h) = area.dim_in_pixel();
+        let (w, h) = (w as i32, h as i32);
+        let (out, inn) = direction.extents(tick_size);
+        for (p, _) in labels {
+            let (from, to) = match side {
+                0 => ((*p - x0, -out), (*p - x0, inn)),
+                1 => ((*p - x0, h - inn), (*p - x0, h + out)),
+                2 => ((-out, *p - y0), (inn, *p - y0)),
+                _ => ((w - inn, *p - y0), (w + out, *p - y0)),
+            };
+            let line = PathElement::new(vec![from, to], style.clone());
+            area.draw_with_policy(&line, OutOfRange::Draw)?;
+        }
         Ok(())
     }
 
+    /// Draw the frame around the plotting area, which is the axis lines on all of its sides.
+    /// Like the axis lines, the bottom and the right sides are just outside of the area.
+    pub(super) fn draw_plot_frame(
+        &self,
+        style: &ShapeStyle,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let area = self.drawing_area.strip_coord_spec();
+        let (w, h) = area.dim_in_pixel();
+        let frame = Rectangle::new([(0, 0), (w as i32, h as i32)], style.clone());
+        area.draw_with_policy(&frame, OutOfRange::Draw)
+    }
+
     #[allow(clippy::too_many_arguments)]
-    pub(super) fn draw_mesh<FmtLabel>(
+    pub(super) fn draw_mesh<FmtLabel, LineStyle>(
         &mut self,
         (r, c): (usize, usize),
-        mesh_line_style: &ShapeStyle,
+        mesh_line_style: LineStyle,
         x_label_style: &TextStyle,
         y_label_style: &TextStyle,
         fmt_label: FmtLabel,
-        x_mesh: bool,
-        y_mesh: bool,
         x_label_offset: i32,
         y_label_offset: i32,
         x_axis: bool,
@@ -535,17 +1005,25 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, RangedCo
         axis_desc_style: &TextStyle,
         x_desc: Option<String>,
         y_desc: Option<String>,
+        axis_desc_placement: (Option<i32>, AxisDescPosition),
         x_tick_size: [i32; 2],
         y_tick_size: [i32; 2],
+        (tick_direction, tick_sides): (TickMarkDirection, Option<[bool; 4]>),
+        thin_labels: bool,
     ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
     where
-        FmtLabel: FnMut(&MeshLine<X, Y>) -> Option<String>,
+        FmtLabel: FnMut(&RangedCoord<X, Y>, &MeshLine<X, Y>) -> Option<String>,
+        LineStyle: FnMut(&MeshLine<X, Y>) -> Option<ShapeStyle>,
     {
-        let (x_labels, y_labels) =
-            self.draw_mesh_lines((r, c), (x_mesh, y_mesh), mesh_line_style, fmt_label)?;
+        // The ranges may be changed after the chart is built, such as by `ChartState`
+        let coord = self.drawing_area.as_coord_spec();
+        check_axis_range(coord.x_spec(), "X")?;
+        check_axis_range(coord.y_spec(), "Y")?;
+
+        let (x_labels, y_labels) = self.draw_mesh_lines((r, c), mesh_line_style, fmt_label)?;
 
         for idx in 0..2 {
-            self.draw_axis_and_labels(
+            self.drawn_labels[idx] = self.draw_axis_and_labels(
                 self.x_label_area[idx].as_ref(),
                 if x_axis { Some(axis_style) } else { None },
                 &x_labels[..],
@@ -553,10 +1031,16 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, RangedCo
                 x_label_offset,
                 (0, -1 + idx as i16 * 2),
                 x_desc.as_ref().map(|desc| (&desc[..], axis_desc_style)),
-                x_tick_size[idx],
+                axis_desc_placement,
+                (
+                    x_tick_size[idx],
+                    tick_direction,
+                    tick_sides.unwrap_or([true; 4])[idx],
+                ),
+                thin_labels,
             )?;
 
-            self.draw_axis_and_labels(
+            self.drawn_labels[2 + idx] = self.draw_axis_and_labels(
                 self.y_label_area[idx].as_ref(),
                 if y_axis { Some(axis_style) } else { None },
                 &y_labels[..],
@@ -564,10 +1048,39 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, RangedCo
                 y_label_offset,
                 (-1 + idx as i16 * 2, 0),
                 y_desc.as_ref().map(|desc| (&desc[..], axis_desc_style)),
-                y_tick_size[idx],
+                axis_desc_placement,
+                (
+                    y_tick_size[idx],
+                    tick_direction,
+                    tick_sides.unwrap_or([true; 4])[2 + idx],
+                ),
+                thin_labels,
             )?;
         }
 
+        /* The sides without a label area have their ticks drawn on the edges of the plotting
+         * area, if they are asked for */
+        for (side, enabled) in tick_sides.iter().flat_map(|sides| sides.iter().enumerate()) {
+            let (labels, area, size, draw) = if side < 2 {
+                (
+                    &x_labels,
+                    &self.x_label_area[side],
+                    x_tick_size[side],
+                    x_axis,
+                )
+            } else {
+                (
+                    &y_labels,
+                    &self.y_label_area[side - 2],
+                    y_tick_size[side - 2],
+                    y_axis,
+                )
+            };
+            if *enabled && draw && area.is_none() {
+                self.draw_frame_ticks(side, labels, size.abs(), tick_direction, axis_style)?;
+            }
+        }
+
         Ok(())
     }
 
@@ -592,11 +1105,111 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, RangedCo
 
         DualCoordChartContext::new(self, RangedCoord::new(x_coord, y_coord, pixel_range))
     }
+
+    /// Convert this chart context into a dual axis chart context that only has a secondary
+    /// Y axis, such as a chart of the temperature and the rainfall. The secondary coordinate
+    /// system shares the X axis, so only the right label area is used for the secondary axis.
+    ///
+    /// - `y_coord`: The coordinate spec for the secondary Y axis
+    /// - **returns** The newly created dual spec chart context
+    #[allow(clippy::type_complexity)]
+    pub fn set_secondary_y_coord<SY: AsRangedCoord>(
+        self,
+        y_coord: SY,
+    ) -> DualCoordChartContext<'a, DB, RangedCoord<X, Y>, RangedCoord<X, SY::CoordDescType>>
+    where
+        Range<X::ValueType>: AsRangedCoord<CoordDescType = X>,
+    {
+        let x_coord = self.x_range();
+        let mut chart = self.set_secondary_coord(x_coord, y_coord);
+        std::mem::swap(
+            &mut chart.primary.x_label_area[0],
+            &mut chart.secondary.x_label_area[0],
+        );
+        chart
+    }
+
+    /// Convert this chart context into a dual axis chart context that only has a secondary
+    /// X axis, such as a chart of the wavelength with the frequency on top. The secondary
+    /// coordinate system shares the Y axis, so only the top label area is used for the
+    /// secondary axis, which is reserved by `ChartBuilder::top_x_label_area_size`.
+    ///
+    /// - `x_coord`: The coordinate spec for the secondary X axis
+    /// - **returns** The newly created dual spec chart context
+    #[allow(clippy::type_complexity)]
+    pub fn set_secondary_x_coord<SX: AsRangedCoord>(
+        self,
+        x_coord: SX,
+    ) -> DualCoordChartContext<'a, DB, RangedCoord<X, Y>, RangedCoord<SX::CoordDescType, Y>>
+    where
+        Range<Y::ValueType>: AsRangedCoord<CoordDescType = Y>,
+    {
+        let y_coord = self.y_range();
+        let mut chart = self.set_secondary_coord(x_coord, y_coord);
+        std::mem::swap(
+            &mut chart.primary.y_label_area[1],
+            &mut chart.secondary.y_label_area[1],
+        );
+        chart
+    }
+}
+
+impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged, Z: Ranged>
+    ChartContext<'a, DB, Cartesian3d<X, Y, Z>>
+{
+    /// Change the projection of the 3D coordinate system. The function receives the
+    /// parameters of the default projection, which can be modified and returned.
+    /// - `func`: The function that sets the yaw, the pitch, the scale and the perspective
+    pub fn with_projection<F: FnOnce(ProjectionMatrixBuilder) -> ProjectionMatrixBuilder>(
+        &mut self,
+        func: F,
+    ) -> &mut Self {
+        let projection = func(ProjectionMatrixBuilder::default()).into_matrix();
+        self.drawing_area
+            .as_coord_spec_mut()
+            .set_projection(projection);
+        self
+    }
+
+    /// Get the range of X axis
+    pub fn x_range(&self) -> Range<X::ValueType> {
+        self.drawing_area.as_coord_spec().get_x_range()
+    }
+
+    /// Get the range of Y axis
+    pub fn y_range(&self) -> Range<Y::ValueType> {
+        self.drawing_area.as_coord_spec().get_y_range()
+    }
+
+    /// Get the range of Z axis
+    pub fn z_range(&self) -> Range<Z::ValueType> {
+        self.drawing_area.as_coord_spec().get_z_range()
+    }
+
+    /// Get the depth of the coordinate after the projection, the farther the point is from the
+    /// viewer, the larger it is. This is used to draw the elements back to front.
+    pub fn projected_depth(&self, coord: &(X::ValueType, Y::ValueType, Z::ValueType)) -> f64 {
+        self.drawing_area.as_coord_spec().projected_depth(coord)
+    }
+
+    /// Get how near the coordinate is to the viewer after the projection, from 0 for the
+    /// farthest possible point of the chart to 1 for the nearest one
+    pub fn projected_nearness(&self, coord: &(X::ValueType, Y::ValueType, Z::ValueType)) -> f64 {
+        self.drawing_area.as_coord_spec().projected_nearness(coord)
+    }
+
+    /// Check if the coordinate is in front of the camera, which is false for the points behind
+    /// the camera of a perspective projection
+    pub fn is_visible(&self, coord: &(X::ValueType, Y::ValueType, Z::ValueType)) -> bool {
+        self.drawing_area.as_coord_spec().is_visible(coord)
+    }
 }
 
 #[cfg(test)]
 mod test {
     use crate::prelude::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
 
     #[test]
     fn test_chart_context() {
@@ -642,4 +1255,559 @@ mod test {
             .draw()
             .expect("Drawing error");
     }
+
+    #[test]
+    fn test_series_labels() {
+        let drawing_area = create_mocked_drawing_area(300, 200, |m| {
+            // The labeled series are listed in the alphabetical order on the right of the
+            // plotting area, and the series without a label is left out
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_eq!(text, "alpha");
+                assert!(pos.0 > 200);
+            });
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_eq!(text, "beta");
+                assert!(pos.0 > 200);
+            });
+            m.check_draw_rect(|_, _, _, upper_left, bottom_right| {
+                assert!(upper_left.0 >= 205 && bottom_right.0 > upper_left.0);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_text_call, 2);
+                assert_eq!(b.num_draw_rect_call, 4);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .margin_right(100)
+            .build_ranged(0..10, 0..10)
+            .expect("Create chart");
+        let legend = |(x, y)| Rectangle::new([(x, y - 5), (x + 10, y + 5)], RED.filled());
+        chart
+            .draw_series(std::iter::once(Circle::new((5, 5), 5, &RED)))
+            .expect("Drawing error")
+            .label("beta")
+            .legend(legend);
+        chart
+            .draw_series(std::iter::once(Circle::new((6, 6), 5, &RED)))
+            .expect("Drawing error")
+            .legend(legend);
+        chart
+            .draw_series(std::iter::once(Circle::new((7, 7), 5, &RED)))
+            .expect("Drawing error")
+            .label("alpha")
+            .legend(legend);
+
+        chart
+            .configure_series_labels()
+            .position(SeriesLabelPosition::OutsideRight)
+            .order(SeriesLabelOrder::Alphabetical)
+            .border_style(&BLACK)
+            .draw()
+            .expect("Drawing error");
+    }
+
+    #[test]
+    fn test_mesh_line_styles() {
+        let lines = Rc::new(RefCell::new(vec![]));
+        let drawn = lines.clone();
+        let texts = Rc::new(RefCell::new(vec![]));
+        let labels = texts.clone();
+        let drawing_area = create_mocked_drawing_area(200, 200, move |m| {
+            m.check_draw_line(move |c, _, from, to| drawn.borrow_mut().push((c, from, to)));
+            m.check_draw_text(move |_, _, _, _, text| labels.borrow_mut().push(text.to_string()));
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .x_label_area_size(20)
+            .y_label_area_size(20)
+            .build_ranged(0..100, 0..100)
+            .expect("Create chart");
+        chart
+            .configure_mesh()
+            .disable_x_mesh()
+            .x_labels(5)
+            .y_labels(5)
+            .y_bold_line_predicate(&|v| v % 50 == 0)
+            .y_bold_line_style(&RED)
+            .y_light_line_style(&BLUE)
+            .draw()
+            .expect("Drawing error");
+
+        // Only the horizontal lines are drawn, each of them once, and the ones of the multiples
+        // of 50 are bold
+        let lines = lines.borrow();
+        assert_eq!(lines.len(), 21);
+        let mut positions: Vec<_> = lines.iter().map(|(_, from, _)| from.1).collect();
+        positions.dedup();
+        assert_eq!(positions.len(), 21);
+        for (c, from, to) in lines.iter() {
+            assert_eq!(from.1, to.1);
+            let bold = [0, 90, 180].contains(&from.1);
+            assert_eq!(*c, if bold { RED.to_rgba() } else { BLUE.to_rgba() });
+        }
+        // The labels of the X axis are still drawn without its grid lines
+        assert_eq!(texts.borrow().iter().filter(|t| *t == "50").count(), 2);
+    }
+
+    #[test]
+    fn test_tick_marks_on_all_sides() {
+        let ticks = Rc::new(RefCell::new(vec![]));
+        let drawn = ticks.clone();
+        let drawing_area = create_mocked_drawing_area(200, 200, move |m| {
+            m.check_draw_path(move |c, _, path| {
+                if c == BLUE.to_rgba() {
+                    drawn.borrow_mut().push(path)
+                }
+            });
+            m.check_draw_rect(|c, _, filled, ul, br| {
+                assert_eq!(c, RED.to_rgba());
+                assert!(!filled);
+                assert_eq!((ul, br), ((20, 0), (200, 180)));
+            });
+            m.drop_check(|b| assert_eq!(b.num_draw_rect_call, 1));
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .x_label_area_size(20)
+            .y_label_area_size(20)
+            .build_ranged(0..10, 0..10)
+            .expect("Create chart");
+        chart
+            .configure_mesh()
+            .disable_mesh()
+            .x_labels(3)
+            .y_labels(3)
+            .axis_style(&BLUE)
+            .set_all_tick_mark_size(4)
+            .tick_mark_direction(TickMarkDirection::Inside)
+            .tick_mark_sides(&[
+                LabelAreaPosition::Bottom,
+                LabelAreaPosition::Left,
+                LabelAreaPosition::Top,
+                LabelAreaPosition::Right,
+            ])
+            .frame_style(&RED)
+            .draw()
+            .expect("Drawing error");
+
+        // The ticks of the label areas and the mirrored ones go inward from the axis lines
+        let ticks = ticks.borrow();
+        let ticks: Vec<_> = ticks.iter().filter(|path| path.len() == 2).collect();
+        let has_tick = |from: (i32, i32), to: (i32, i32)| ticks.contains(&&vec![from, to]);
+        assert!(has_tick((20, 90), (24, 90)));
+        assert!(has_tick((110, 176), (110, 180)));
+        assert!(has_tick((110, 0), (110, 4)));
+        assert!(has_tick((196, 90), (200, 90)));
+        assert!(ticks.iter().all(|path| {
+            let (dx, dy) = (path[1].0 - path[0].0, path[1].1 - path[0].1);
+            dx.abs() + dy.abs() <= 4 || path[0] == (20, 180)
+        }));
+    }
+
+    #[test]
+    fn test_label_thinning() {
+        let drawn = |thinning: bool| {
+            let drawing_area = create_mocked_drawing_area(200, 100, |_| {});
+            let mut chart = ChartBuilder::on(&drawing_area)
+                .x_label_area_size(20)
+                .build_ranged(0..100, 0..10)
+                .expect("Create chart");
+            chart
+                .configure_mesh()
+                .x_labels(50)
+                .x_label_formatter(&|v| format!("{}.000", v))
+                .label_thinning(thinning)
+                .draw()
+                .expect("Drawing error");
+            chart.drawn_labels(LabelAreaPosition::Bottom).to_vec()
+        };
+
+        let all = drawn(false);
+        let thinned = drawn(true);
+        assert!(all.len() >= 15);
+        assert!(thinned.len() > 1 && thinned.len() < all.len());
+        // The first and the last labels are kept, and the labels are far enough from each other
+        assert_eq!(thinned.first(), all.first());
+        assert_eq!(thinned.last(), all.last());
+        // Each label of 5 characters is more than 20 pixels wide
+        assert!(thinned.windows(2).all(|pair| pair[1].0 - pair[0].0 > 20));
+    }
+
+    #[test]
+    fn test_reference_lines_and_spans() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_rect(|c, _, filled, ul, br| {
+                assert_eq!(c, BLUE.mix(0.2).to_rgba());
+                assert!(filled);
+                assert_eq!((ul, br), ((40, 0), (80, 199)));
+            });
+            m.check_draw_path(|c, _, path| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!(path, vec![(0, 100), (199, 100)]);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 1);
+                // The line out of the range of the axis isn't drawn
+                assert_eq!(b.num_draw_path_call, 1);
+            });
+        });
+
+        let chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0..10, 0..10)
+            .expect("Create chart");
+        chart
+            .draw_vertical_span(4, 2, BLUE.mix(0.2).filled())
+            .expect("Drawing error");
+        chart.draw_horizontal_line(5, &RED).expect("Drawing error");
+        chart.draw_vertical_line(20, &RED).expect("Drawing error");
+    }
+
+    #[test]
+    fn test_axis_desc_placement() {
+        let texts = Rc::new(RefCell::new(vec![]));
+        let drawn = texts.clone();
+        let drawing_area = create_mocked_drawing_area(200, 200, move |m| {
+            m.check_draw_text(move |_, _, _, pos, text| {
+                drawn.borrow_mut().push((pos, text.to_string()))
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .x_label_area_size(40)
+            .y_label_area_size(60)
+            .right_y_label_area_size(8)
+            .build_ranged(0..10, 0..10)
+            .expect("Create chart");
+        chart
+            .configure_mesh()
+            .x_desc("X")
+            .y_desc("Y")
+            .axis_desc_gap(5)
+            .axis_desc_position(AxisDescPosition::End)
+            .draw()
+            .expect("Drawing error");
+
+        let texts = texts.borrow();
+        let desc: Vec<_> = texts.iter().filter(|(_, t)| t == "X" || t == "Y").collect();
+        let label_pos = |text: &str| texts.iter().find(|(_, t)| t == text).unwrap().0;
+        // The left description is beyond the widest label, at the top end of the axis
+        assert_eq!(desc[0].1, "Y");
+        assert!((desc[0].0).0 < label_pos("10").0 - 5);
+        assert_eq!((desc[0].0).1, 0);
+        // The x description is below the labels, at the right end of the axis
+        assert_eq!(desc[1].1, "X");
+        assert!((desc[1].0).0 > 180 && (desc[1].0).0 < 192);
+        assert!((desc[1].0).1 > label_pos("0").1 + 5);
+        // The right label area is too small, so the description is kept inside of it
+        assert_eq!(desc[2].0, (192, 0));
+    }
+
+    #[test]
+    fn test_series_label_area() {
+        let texts = Rc::new(RefCell::new(vec![]));
+        let drawn = texts.clone();
+        let drawing_area = create_mocked_drawing_area(400, 200, move |m| {
+            m.check_draw_text(move |_, _, _, pos, text| {
+                drawn.borrow_mut().push((pos, text.to_string()))
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .right_series_label_area_size(200)
+            .build_ranged(0..10, 0..10)
+            .expect("Create chart");
+        assert_eq!(chart.plotting_area().dim_in_pixel(), (200, 200));
+        for idx in 0..15 {
+            chart
+                .draw_series(std::iter::once(Circle::new((idx % 10, 5), 5, &RED)))
+                .expect("Drawing error")
+                .label(if idx == 0 {
+                    "the first series, whose name is long".to_string()
+                } else {
+                    format!("s{}", idx)
+                })
+                .legend(|(x, y)| Rectangle::new([(x, y - 5), (x + 20, y + 5)], RED.filled()));
+        }
+        chart
+            .configure_series_labels()
+            .position(SeriesLabelPosition::OutsideRight)
+            .draw()
+            .expect("Drawing error");
+        drop(chart);
+        drop(drawing_area);
+
+        // Only 12 rows fit in the area, so the series are split into two columns, and the long
+        // label is cut to the width of its column
+        let texts = texts.borrow();
+        assert_eq!(texts.len(), 15);
+        assert!(texts[..8].iter().all(|((x, _), _)| *x == 240));
+        assert!(texts[8..].iter().all(|((x, _), _)| *x == 330));
+        assert_eq!(texts[1].0, (240, 25));
+        assert!(texts[0].1.starts_with("the") && texts[0].1.ends_with("..."));
+        assert_eq!(texts[14].1, "s14");
+
+        // The area at the bottom is reserved below the label area
+        let drawing_area = create_mocked_drawing_area(400, 200, |_| {});
+        let chart = ChartBuilder::on(&drawing_area)
+            .x_label_area_size(20)
+            .bottom_series_label_area_size(50)
+            .build_ranged(0..10, 0..10)
+            .expect("Create chart");
+        assert_eq!(chart.plotting_area().dim_in_pixel(), (400, 130));
+    }
+
+    #[test]
+    fn test_custom_key_points() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            // Only the given key points inside the range have a mesh line and a label, and the
+            // Y axis has none of them
+            m.check_draw_line(|_, _, from, to| {
+                assert!(from.0 == to.0 && [80, 140].contains(&from.0))
+            });
+            m.check_draw_text(|_, _, _, _, text| assert!(["1.0%", "2.5%"].contains(&text)));
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_line_call, 4);
+                assert_eq!(b.num_draw_text_call, 2);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .x_label_area_size(20)
+            .y_label_area_size(40)
+            .build_ranged(
+                (0.0..4.0).with_key_points(vec![1.0, 2.5, 20.0]),
+                (0..10).with_key_points(vec![]),
+            )
+            .expect("Create chart");
+
+        chart
+            .configure_mesh()
+            .x_label_formatter(&|v| format!("{:.1}%", v))
+            .draw()
+            .expect("Draw mesh");
+    }
+
+    #[test]
+    fn test_minor_mesh() {
+        let lines = Rc::new(RefCell::new(vec![]));
+        let ticks = Rc::new(RefCell::new(0));
+        {
+            let (lines, ticks) = (lines.clone(), ticks.clone());
+            let drawing_area = create_mocked_drawing_area(200, 200, move |m| {
+                m.check_draw_line(move |c, _, from, to| {
+                    lines
+                        .borrow_mut()
+                        .push((c.rgb() == (0, 0, 255), from.0 == to.0))
+                });
+                m.check_draw_path(move |_, _, path| {
+                    // The minor ticks are half as long as the ticks of the labels
+                    if path.len() == 2 && path[0].0 == path[1].0 && path[1].1 - path[0].1 == 2 {
+                        *ticks.borrow_mut() += 1;
+                    }
+                });
+            });
+
+            let mut chart = ChartBuilder::on(&drawing_area)
+                .x_label_area_size(20)
+                .y_label_area_size(20)
+                .build_ranged(0.0..1.0, 0.0..1.0)
+                .expect("Create chart");
+
+            chart
+                .configure_mesh()
+                .x_minor_count(4)
+                .minor_grid_style(&BLUE)
+                .draw()
+                .expect("Draw mesh");
+        }
+
+        // The fine grid of the Y axis is drawn first, then the minor lines of the X axis, which
+        // are below the bold lines
+        let lines = lines.borrow();
+        let start = lines
+            .iter()
+            .position(|(blue, _)| *blue)
+            .expect("No minor line");
+        let minor = lines[start..].iter().take_while(|(blue, _)| *blue).count();
+        assert!(lines[..start].iter().all(|(_, vertical)| !*vertical));
+        assert!(lines[start..start + minor]
+            .iter()
+            .all(|(_, vertical)| *vertical));
+        assert!(lines[start + minor..].iter().all(|(blue, _)| !*blue));
+        assert_eq!(minor, 20);
+        // The X axis has a tick for each minor line
+        assert_eq!(*ticks.borrow(), minor);
+    }
+
+    #[test]
+    fn test_out_of_range_policy() {
+        let draw = |policy: OutOfRange, expected: Vec<(i32, i32)>, circles: u32| {
+            let drawing_area = create_mocked_drawing_area(200, 200, move |m| {
+                m.check_draw_path(move |_, _, path| assert_eq!(path, expected));
+                m.drop_check(move |b| {
+                    assert_eq!(b.num_draw_path_call, 1);
+                    assert_eq!(b.num_draw_circle_call, circles);
+                });
+            });
+            let mut chart = ChartBuilder::on(&drawing_area)
+                .build_ranged(0.0..10.0, 0.0..10.0)
+                .expect("Build chart error");
+            chart.set_out_of_range(policy);
+            let data = vec![(5.0, 5.0), (15.0, 10.0)];
+            chart
+                .draw_series(LineSeries::new(data.clone(), &RED))
+                .expect("Drawing Error");
+            chart
+                .draw_series(data.into_iter().map(|p| Circle::new(p, 3, RED.filled())))
+                .expect("Drawing Error");
+        };
+        // The segment is cut where it crosses the right edge, and the marker outside is left out
+        draw(OutOfRange::Clip, vec![(100, 100), (200, 50)], 1);
+        draw(OutOfRange::Clamp, vec![(100, 100), (200, 0)], 2);
+        draw(OutOfRange::Draw, vec![(100, 100), (300, 0)], 2);
+    }
+
+    #[test]
+    fn test_draw_series_clipped() {
+        use crate::drawing::backend::BackendClip;
+        let clips = Rc::new(RefCell::new(vec![]));
+        {
+            let clips = clips.clone();
+            let drawing_area = create_mocked_drawing_area(200, 200, move |m| {
+                m.check_set_clip(move |clip| clips.borrow_mut().push(clip));
+                m.drop_check(|b| assert_eq!(b.num_draw_path_call, 3));
+            });
+            let mut chart = ChartBuilder::on(&drawing_area)
+                .x_label_area_size(20)
+                .y_label_area_size(20)
+                .build_ranged(0.0..10.0, 0.0..10.0)
+                .expect("Build chart error");
+            let data = vec![(5.0, 5.0), (15.0, 10.0)];
+            chart
+                .draw_series(LineSeries::new(data.clone(), &RED))
+                .expect("Drawing Error");
+            chart
+                .draw_series_clipped(LineSeries::new(data.clone(), &RED))
+                .expect("Drawing Error");
+            chart
+                .set_clip_text(true)
+                .draw_series_clipped(LineSeries::new(data, &RED))
+                .expect("Drawing Error");
+        }
+        // The clip is the plotting area, and it's lifted after each series
+        let clip = |clip_text| BackendClip {
+            upper_left: (20, 0),
+            bottom_right: (200, 180),
+            clip_text,
+        };
+        assert_eq!(
+            *clips.borrow(),
+            vec![Some(clip(false)), None, Some(clip(true)), None]
+        );
+    }
+
+    #[test]
+    fn test_next_series_color() {
+        let colors = Rc::new(RefCell::new(vec![]));
+        {
+            let colors = colors.clone();
+            let drawing_area = create_mocked_drawing_area(200, 200, move |m| {
+                m.check_draw_path(move |c, _, _| colors.borrow_mut().push(c.rgb()));
+            });
+            let mut chart = ChartBuilder::on(&drawing_area)
+                .build_ranged(0..10, 0..10)
+                .expect("Build chart error");
+            for idx in 0..11 {
+                let color = chart.next_series_color::<PaletteTab10>();
+                chart
+                    .draw_series(LineSeries::new(vec![(0, idx), (10, idx)], &color))
+                    .expect("Drawing Error");
+            }
+        }
+        let colors = colors.borrow();
+        assert_eq!(colors.len(), 11);
+        assert_eq!(colors[1], PaletteTab10::COLORS[1]);
+        // The palette has 10 colors, so the 11th series gets the first color again
+        assert_eq!(colors[10], colors[0]);
+        assert_ne!(colors[9], colors[0]);
+    }
+
+    #[test]
+    fn test_coord_trans() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |_| {});
+        let chart = ChartBuilder::on(&drawing_area)
+            .x_label_area_size(20)
+            .build_ranged(0.0..10.0, 0..10)
+            .expect("Build chart error");
+        let coord_trans = chart.coord_trans();
+        // The labels are at the bottom, so the plotting area is 180 pixels high
+        assert_eq!(coord_trans((100, 90)), Some((5.0, 5)));
+        assert_eq!(coord_trans((0, 179)), Some((0.0, 0)));
+        assert_eq!(coord_trans((100, 190)), None);
+        assert_eq!(coord_trans((201, 90)), None);
+
+        for v in 0..10 {
+            let pixel = chart.backend_coord(&(f64::from(v), v));
+            let (x, y) = coord_trans(pixel).unwrap();
+            assert!((chart.backend_coord(&(x, y)).0 - pixel.0).abs() <= 1);
+            assert_eq!(y, v);
+        }
+    }
+
+    #[test]
+    fn test_secondary_y_coord() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_circle(|_, _, _, center, _| assert_eq!(center, (90, 65)));
+            // The secondary axis only has its labels on the right, without any mesh line
+            m.check_draw_text(|_, _, _, pos, _| assert!(pos.0 >= 180));
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_line_call, 0);
+                assert!(b.num_draw_text_call > 0);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .set_label_area_size(LabelAreaPosition::Top, 20)
+            .set_label_area_size(LabelAreaPosition::Right, 20)
+            .build_ranged(0..2, 0..2)
+            .expect("Create chart")
+            .set_secondary_y_coord(0..20);
+
+        chart
+            .draw_secondary_series(std::iter::once(Circle::new((1, 15), 5, &BLUE)))
+            .expect("Drawing error");
+        chart
+            .configure_secondary_axes()
+            .draw()
+            .expect("Draw secondary axes");
+    }
+
+    #[test]
+    fn test_secondary_x_coord() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_circle(|_, _, _, center, _| assert_eq!(center, (135, 110)));
+            // The secondary axis only has its labels on the top, without any mesh line
+            m.check_draw_text(|_, _, _, pos, _| assert!(pos.1 < 20));
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_line_call, 0);
+                assert!(b.num_draw_text_call > 0);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .top_x_label_area_size(20)
+            .right_y_label_area_size(20)
+            .build_ranged(0..2, 0..2)
+            .expect("Create chart")
+            .set_secondary_x_coord(0..20);
+
+        chart
+            .draw_secondary_series(std::iter::once(Circle::new((15, 1), 5, &BLUE)))
+            .expect("Drawing error");
+        chart
+            .configure_secondary_axes()
+            .draw()
+            .expect("Draw secondary axes");
+    }
 }
@@ -32,6 +32,7 @@ impl<'a, DB: DrawingBackend, CT1: CoordTranslate, CT2: CoordTranslate>
         std::mem::swap(&mut primary.x_label_area[0], &mut secondary_x_label_area[0]);
         std::mem::swap(&mut primary.y_label_area[1], &mut secondary_y_label_area[1]);
 
+        let (out_of_range, clip_text) = (primary.out_of_range, primary.clip_text);
         Self {
             primary,
             secondary: ChartContext {
@@ -39,6 +40,12 @@ impl<'a, DB: DrawingBackend, CT1: CoordTranslate, CT2: CoordTranslate>
                 y_label_area: secondary_y_label_area,
                 drawing_area: secondary_drawing_area,
                 series_anno: vec![],
+                series_label_area: [None, None],
+                colorbar_area: [None, None],
+                out_of_range,
+                clip_text,
+                drawn_labels: Default::default(),
+                deferred: vec![],
             },
         }
     }
@@ -60,7 +67,7 @@ impl<'a, DB: DrawingBackend, CT1: CoordTranslate, CT2: ReverseCoordTranslate>
 {
     /// Convert the chart context into the secondary coordinate translation function
     pub fn into_secondary_coord_trans(self) -> impl Fn(BackendCoord) -> Option<CT2::From> {
-        let coord_spec = self.secondary.drawing_area.into_coord_spec();
+        let coord_spec = self.secondary.into_drawing_area().into_coord_spec();
         move |coord| coord_spec.reverse_translate(coord)
     }
 }
@@ -76,8 +83,8 @@ impl<'a, DB: DrawingBackend, CT1: ReverseCoordTranslate, CT2: ReverseCoordTransl
         impl Fn(BackendCoord) -> Option<CT1::From>,
         impl Fn(BackendCoord) -> Option<CT2::From>,
     ) {
-        let coord_spec_1 = self.primary.drawing_area.into_coord_spec();
-        let coord_spec_2 = self.secondary.drawing_area.into_coord_spec();
+        let coord_spec_1 = self.primary.into_drawing_area().into_coord_spec();
+        let coord_spec_2 = self.secondary.into_drawing_area().into_coord_spec();
         (
             move |coord| coord_spec_1.reverse_translate(coord),
             move |coord| coord_spec_2.reverse_translate(coord),
@@ -0,0 +1,241 @@
+use std::fmt::Debug;
+
+use super::ChartContext;
+use crate::coord::{Ranged, RangedCoord, Shift};
+use crate::drawing::backend::BackendCoord;
+use crate::drawing::{DrawingArea, DrawingAreaErrorKind, DrawingBackend};
+use crate::element::{PathElement, Rectangle};
+use crate::style::{Color, ShapeStyle, BLACK, WHITE};
+
+/// Where an inset is placed on the plotting area of a chart
+#[derive(Clone, Debug, PartialEq)]
+pub enum InsetRect<X, Y> {
+    /// The upper left and the bottom right corners in the fractions of the plotting area from
+    /// its upper left corner, such as `((0.6, 0.05), (0.95, 0.4))` in the upper right corner
+    Relative((f64, f64), (f64, f64)),
+    /// The rectangle between two points of the data
+    Data((X, Y), (X, Y)),
+}
+
+/// The configuration of an inset chart, see `ChartContext::inset`
+pub struct InsetConfig<X, Y> {
+    rect: InsetRect<X, Y>,
+    background_style: Option<ShapeStyle>,
+    border_style: Option<ShapeStyle>,
+    zoom_lens: Option<((X, Y), (X, Y))>,
+    zoom_style: ShapeStyle,
+}
+
+impl<X, Y> InsetConfig<X, Y> {
+    /// Create the configuration of an inset, which is a panel with a white background and a
+    /// black border
+    /// - `rect`: Where the inset is placed
+    pub fn new(rect: InsetRect<X, Y>) -> Self {
+        Self {
+            rect,
+            background_style: Some(WHITE.filled()),
+            border_style: Some((&BLACK).into()),
+            zoom_lens: None,
+            zoom_style: (&BLACK).into(),
+        }
+    }
+
+    /// Create the configuration of an inset placed in the fractions of the plotting area
+    /// - `upper_left`, `bottom_right`: The corners of the inset, from `(0.0, 0.0)` at the upper
+    ///   left corner of the plotting area to `(1.0, 1.0)` at its bottom right corner
+    pub fn relative(upper_left: (f64, f64), bottom_right: (f64, f64)) -> Self {
+        Self::new(InsetRect::Relative(upper_left, bottom_right))
+    }
+
+    /// Create the configuration of an inset placed between two points of the data
+    pub fn data(from: (X, Y), to: (X, Y)) -> Self {
+        Self::new(InsetRect::Data(from, to))
+    }
+
+    /// Set the style the background of the inset is filled with, `None` to leave the chart
+    /// below visible
+    pub fn background_style<T: Into<ShapeStyle>>(mut self, style: Option<T>) -> Self {
+        self.background_style = style.map(|style| {
+            let style = style.into();
+            ShapeStyle {
+                filled: true,
+                ..style
+            }
+        });
+        self
+    }
+
+    /// Set the style of the border around the inset, `None` for no border
+    pub fn border_style<T: Into<ShapeStyle>>(mut self, style: Option<T>) -> Self {
+        self.border_style = style.map(Into::into);
+        self
+    }
+
+    /// Highlight the rectangle of the data the inset zooms into, and connect it to the inset
+    /// with the two lines along the outside of both of them
+    /// - `from`, `to`: The points of the data the highlighted rectangle is between
+    /// - `style`: The style of the rectangle and the lines
+    pub fn zoom_lens<S: Into<ShapeStyle>>(mut self, from: (X, Y), to: (X, Y), style: S) -> Self {
+        self.zoom_lens = Some((from, to));
+        self.zoom_style = style.into();
+        self
+    }
+}
+
+/// Get the lines between the corresponding corners of two rectangles which have both of the
+/// rectangles on the same side, which are the ones along the outside of the rectangles
+fn outer_connectors(
+    from: &[BackendCoord; 4],
+    to: &[BackendCoord; 4],
+) -> Vec<(BackendCoord, BackendCoord)> {
+    let side = |(p, q): (BackendCoord, BackendCoord), r: &BackendCoord| {
+        let (dx, dy) = (i64::from(q.0 - p.0), i64::from(q.1 - p.1));
+        (dx * i64::from(r.1 - p.1) - dy * i64::from(r.0 - p.0)).signum()
+    };
+    from.iter()
+        .zip(to.iter())
+        .map(|(p, q)| (*p, *q))
+        .filter(|(p, q)| p != q)
+        .filter(|&line| {
+            let sides: Vec<_> = from
+                .iter()
+                .chain(to.iter())
+                .map(|r| side(line, r))
+                .collect();
+            sides.iter().all(|&s| s >= 0) || sides.iter().all(|&s| s <= 0)
+        })
+        .collect()
+}
+
+/// Get the corners of a rectangle in the order of upper left, upper right, bottom left and
+/// bottom right
+fn corners((x0, y0): BackendCoord, (x1, y1): BackendCoord) -> [BackendCoord; 4] {
+    let (l, r) = (x0.min(x1), x0.max(x1));
+    let (t, b) = (y0.min(y1), y0.max(y1));
+    [(l, t), (r, t), (l, b), (r, b)]
+}
+
+impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, RangedCoord<X, Y>>
+where
+    X::ValueType: Debug,
+    Y::ValueType: Debug,
+{
+    /// Get the pixel of a point of the data, which is relative to the plotting area
+    fn plot_pixel(&self, point: &(X::ValueType, Y::ValueType)) -> BackendCoord {
+        let (x, y) = self.backend_coord(point);
+        let base = self.drawing_area.get_base_pixel();
+        (x - base.0, y - base.1)
+    }
+
+    /// Draw the panel of an inset chart, a small chart within the plotting area such as a
+    /// zoomed-in view of the data, and get the drawing area inside of its border. The inset is
+    /// a drawing area as any other, so a fresh `ChartBuilder` builds the chart on it, and the
+    /// elements drawn on it are kept inside of it as on the plotting area.
+    /// - `config`: Where the inset is placed and how its panel is drawn
+    /// - **returns**: The drawing area of the inset
+    pub fn inset(
+        &self,
+        config: &InsetConfig<X::ValueType, Y::ValueType>,
+    ) -> Result<DrawingArea<DB, Shift>, DrawingAreaErrorKind<DB::ErrorType>> {
+        let area = self.drawing_area.strip_coord_spec();
+        let (w, h) = area.dim_in_pixel();
+        let (w, h) = (w as i32, h as i32);
+
+        /* The edges of the inset, which are kept within the plotting area */
+        let ((x0, y0), (x1, y1)) = match &config.rect {
+            InsetRect::Relative((fx0, fy0), (fx1, fy1)) => {
+                let px = |f: f64| (f * f64::from(w)).round() as i32;
+                let py = |f: f64| (f * f64::from(h)).round() as i32;
+                ((px(*fx0), py(*fy0)), (px(*fx1), py(*fy1)))
+            }
+            InsetRect::Data(from, to) => {
+                let [ul, _, _, br] = corners(self.plot_pixel(from), self.plot_pixel(to));
+                (ul, (br.0 + 1, br.1 + 1))
+            }
+        };
+        let (x0, x1) = (x0.min(x1).max(0).min(w), x0.max(x1).max(0).min(w));
+        let (y0, y1) = (y0.min(y1).max(0).min(h), y0.max(y1).max(0).min(h));
+        let panel = [(x0, y0), ((x1 - 1).max(x0), (y1 - 1).max(y0))];
+
+        if let Some((from, to)) = &config.zoom_lens {
+            let style = &config.zoom_style;
+            let lens = corners(self.plot_pixel(from), self.plot_pixel(to));
+            area.draw(&Rectangle::new([lens[0], lens[3]], style.clone()))?;
+            for (p, q) in outer_connectors(&lens, &corners(panel[0], panel[1])) {
+                area.draw(&PathElement::new(vec![p, q], style.clone()))?;
+            }
+        }
+        if let Some(style) = &config.background_style {
+            area.draw(&Rectangle::new(panel, style.clone()))?;
+        }
+        let border = if let Some(style) = &config.border_style {
+            area.draw(&Rectangle::new(panel, style.clone()))?;
+            style.stroke_width.max(1) as i32
+        } else {
+            0
+        };
+
+        let (inner_w, inner_h) = ((x1 - x0 - 2 * border).max(0), (y1 - y0 - 2 * border).max(0));
+        Ok(area.shrink((x0 + border, y0 + border), (inner_w, inner_h)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::outer_connectors;
+    use crate::prelude::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_outer_connectors() {
+        let small = [(10, 60), (20, 60), (10, 70), (20, 70)];
+        // The inset in the upper right is connected by the upper left and the bottom right
+        // corners
+        let upper_right = [(50, 0), (90, 0), (50, 30), (90, 30)];
+        assert_eq!(
+            outer_connectors(&small, &upper_right),
+            vec![((10, 60), (50, 0)), ((20, 70), (90, 30))]
+        );
+        // The wider inset right above it is connected by both of the bottom corners
+        let above = [(0, 0), (30, 0), (0, 30), (30, 30)];
+        assert_eq!(
+            outer_connectors(&small, &above),
+            vec![((10, 70), (0, 30)), ((20, 70), (30, 30))]
+        );
+    }
+
+    #[test]
+    fn test_inset() {
+        let paths = Rc::new(RefCell::new(vec![]));
+        let drawn = paths.clone();
+        let drawing_area = create_mocked_drawing_area(300, 200, move |m| {
+            m.check_draw_path(move |_, _, path| drawn.borrow_mut().push(path));
+            m.drop_check(|b| assert_eq!(b.num_draw_rect_call, 3));
+        });
+        let chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0..100, 0..100)
+            .expect("Create chart");
+        let config =
+            InsetConfig::relative((0.5, 0.0), (1.0, 0.5)).zoom_lens((10, 10), (20, 20), &RED);
+        let inset = chart.inset(&config).expect("Drawing error");
+        // The drawing area is inside of the border of the panel
+        assert_eq!(inset.get_base_pixel(), (151, 1));
+        assert_eq!(inset.dim_in_pixel(), (148, 98));
+
+        // The lens in the lower left is connected to the inset in the upper right
+        let (ul, br) = (
+            chart.backend_coord(&(10, 20)),
+            chart.backend_coord(&(20, 10)),
+        );
+        assert_eq!(
+            *paths.borrow(),
+            vec![vec![ul, (150, 0)], vec![br, (299, 99)]]
+        );
+
+        // The inset is usable for a chart of its own
+        ChartBuilder::on(&inset)
+            .build_ranged(10..20, 10..20)
+            .expect("Create chart");
+    }
+}
@@ -7,10 +7,42 @@ use crate::coord::{MeshLine, Ranged, RangedCoord};
 use crate::drawing::backend::DrawingBackend;
 use crate::drawing::DrawingAreaErrorKind;
 use crate::style::{
-    AsRelative, Color, FontDesc, FontFamily, FontStyle, IntoTextStyle, RGBColor, ShapeStyle,
-    SizeDesc, TextStyle,
+    AsRelative, Color, FontDesc, FontFamily, FontStyle, FontTransform, IntoTextStyle, RGBColor,
+    ShapeStyle, SizeDesc, TextStyle,
 };
 
+/// Where the description of an axis is placed along the axis
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AxisDescPosition {
+    /// The description is centered on the axis
+    Center,
+    /// The description is at the end of the axis, which is the right end of an x axis and the
+    /// top end of a y axis
+    End,
+}
+
+/// Where the tick marks are drawn from the axis line
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TickMarkDirection {
+    /// The ticks go outward from the plotting area
+    Outside,
+    /// The ticks go inward over the plotting area
+    Inside,
+    /// The ticks are centered on the axis line
+    Centered,
+}
+
+impl TickMarkDirection {
+    /// Get how far the ticks of the length go outward and inward from the axis line
+    pub(super) fn extents(self, size: i32) -> (i32, i32) {
+        match self {
+            TickMarkDirection::Outside => (size, 0),
+            TickMarkDirection::Inside => (0, size),
+            TickMarkDirection::Centered => (size / 2, size - size / 2),
+        }
+    }
+}
+
 /// The style used to describe the mesh and axis for a secondary coordinate system.
 pub struct SecondaryMeshStyle<'a, 'b, X: Ranged, Y: Ranged, DB: DrawingBackend> {
     style: MeshStyle<'a, 'b, X, Y, DB>,
@@ -43,6 +75,12 @@ where
         self
     }
 
+    /// Rotate the x labels, see `MeshStyle::x_label_rotation`
+    pub fn x_label_rotation(&mut self, angle: f64) -> &mut Self {
+        self.style.x_label_rotation(angle);
+        self
+    }
+
     /// The offset of y labels. This is used when we want to place the label in the middle of
     /// the grid. This is useful if we are drawing a histogram
     /// - `value`: The offset in pixel
@@ -101,6 +139,20 @@ where
         self
     }
 
+    /// Set the distance between the tick labels and the axis descriptions
+    /// - `gap`: The distance in pixels
+    pub fn axis_desc_gap<S: SizeDesc>(&mut self, gap: S) -> &mut Self {
+        self.style.axis_desc_gap(gap);
+        self
+    }
+
+    /// Set where the axis descriptions are placed along the axes
+    /// - `pos`: The position of the descriptions
+    pub fn axis_desc_position(&mut self, pos: AxisDescPosition) -> &mut Self {
+        self.style.axis_desc_position(pos);
+        self
+    }
+
     /// Draw the axes for the secondary coordinate system
     pub fn draw(&mut self) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
         self.style.draw()
@@ -134,8 +186,29 @@ where
         } = value.in_pixels(&self.style.parent_size);
         self
     }
+
+    /// Set if the tick labels of the secondary axes which would overlap the previous one are
+    /// left out, which is the default
+    /// - `value`: If the overlapping labels are left out
+    pub fn label_thinning(&mut self, value: bool) -> &mut Self {
+        self.style.label_thinning(value);
+        self
+    }
+
+    /// Set where the tick marks of the secondary axes are drawn from the axis lines
+    /// - `direction`: The direction of the ticks
+    pub fn tick_mark_direction(&mut self, direction: TickMarkDirection) -> &mut Self {
+        self.style.tick_mark_direction(direction);
+        self
+    }
 }
 
+/// The function that formats the label of a key point
+type LabelFormatter<'b, V> = &'b dyn Fn(&V) -> String;
+
+/// The function that decides if the grid line of a key point is a bold one
+type BoldLinePredicate<'b, V> = &'b dyn Fn(&V) -> bool;
+
 /// The struct that is used for tracking the configuration of a mesh of any chart
 pub struct MeshStyle<'a, 'b, X: Ranged, Y: Ranged, DB>
 where
@@ -148,22 +221,40 @@ where
     pub(super) draw_y_axis: bool,
     pub(super) x_label_offset: i32,
     pub(super) y_label_offset: i32,
+    pub(super) x_label_rotation: Option<f64>,
     pub(super) n_x_labels: usize,
     pub(super) n_y_labels: usize,
     pub(super) axis_desc_style: Option<TextStyle<'b>>,
     pub(super) x_desc: Option<String>,
     pub(super) y_desc: Option<String>,
+    pub(super) axis_desc_gap: Option<i32>,
+    pub(super) axis_desc_position: AxisDescPosition,
+    pub(super) tick_direction: TickMarkDirection,
+    /// The sides with the ticks, indexed by `LabelAreaPosition`, or `None` for the sides with
+    /// a label area
+    pub(super) tick_sides: Option<[bool; 4]>,
+    pub(super) frame_style: Option<ShapeStyle>,
+    pub(super) thin_labels: bool,
     pub(super) line_style_1: Option<ShapeStyle>,
     pub(super) line_style_2: Option<ShapeStyle>,
+    /// The bold and the light line styles of each axis, which override the styles of both axes
+    pub(super) x_line_styles: [Option<ShapeStyle>; 2],
+    pub(super) y_line_styles: [Option<ShapeStyle>; 2],
+    pub(super) x_bold_line_predicate: Option<BoldLinePredicate<'b, X::ValueType>>,
+    pub(super) y_bold_line_predicate: Option<BoldLinePredicate<'b, Y::ValueType>>,
     pub(super) axis_style: Option<ShapeStyle>,
     pub(super) x_label_style: Option<TextStyle<'b>>,
     pub(super) y_label_style: Option<TextStyle<'b>>,
-    pub(super) format_x: &'b dyn Fn(&X::ValueType) -> String,
-    pub(super) format_y: &'b dyn Fn(&Y::ValueType) -> String,
+    pub(super) format_x: Option<LabelFormatter<'b, X::ValueType>>,
+    pub(super) format_y: Option<LabelFormatter<'b, Y::ValueType>>,
     pub(super) target: Option<&'b mut ChartContext<'a, DB, RangedCoord<X, Y>>>,
     pub(super) _phantom_data: PhantomData<(X, Y)>,
     pub(super) x_tick_size: [i32; 2],
     pub(super) y_tick_size: [i32; 2],
+    pub(super) n_x_minor: usize,
+    pub(super) n_y_minor: usize,
+    pub(super) minor_line_style: Option<ShapeStyle>,
+    pub(super) minor_tick_size: i32,
 }
 
 impl<'a, 'b, X, Y, DB> MeshStyle<'a, 'b, X, Y, DB>
@@ -200,6 +291,42 @@ where
         self
     }
 
+    /// Set where the tick marks are drawn from the axis lines, the labels stay outside of the
+    /// plotting area. This applies to the tick marks with a positive size only.
+    /// - `direction`: The direction of the ticks
+    pub fn tick_mark_direction(&mut self, direction: TickMarkDirection) -> &mut Self {
+        self.tick_direction = direction;
+        self
+    }
+
+    /// Set the sides of the plotting area with the tick marks, which are the sides with a label
+    /// area by default. The sides without a label area have the ticks on the edges of the
+    /// plotting area, without any label, such as the mirrored ticks of a journal figure.
+    /// - `sides`: The sides with the tick marks
+    pub fn tick_mark_sides(&mut self, sides: &[LabelAreaPosition]) -> &mut Self {
+        let mut enabled = [false; 4];
+        for side in sides {
+            enabled[*side as usize] = true;
+        }
+        self.tick_sides = Some(enabled);
+        self
+    }
+
+    /// Set if the tick labels which would overlap the previous one are left out, which is the
+    /// default. The first and the last labels of each axis are always kept.
+    /// - `value`: If the overlapping labels are left out
+    pub fn label_thinning(&mut self, value: bool) -> &mut Self {
+        self.thin_labels = value;
+        self
+    }
+
+    /// Draw the frame around the plotting area, which is the axis lines on all four sides
+    /// - `style`: The style of the frame
+    pub fn frame_style<T: Into<ShapeStyle>>(&mut self, style: T) -> &mut Self {
+        self.frame_style = Some(style.into());
+        self
+    }
+
     /// The offset of x labels. This is used when we want to place the label in the middle of
     /// the grid. This is useful if we are drawing a histogram
     /// - `value`: The offset in pixel
@@ -208,6 +335,16 @@ where
         self
     }
 
+    /// Rotate the x labels, which keeps the long labels from overlapping each other. The box of
+    /// each rotated label is centered on its tick, so the label area should be large enough for
+    /// the rotated labels.
+    /// - `angle`: The angle in degrees clockwise, for example 90 for the labels read from the
+    ///   top down, or 45 for the diagonal ones
+    pub fn x_label_rotation(&mut self, angle: f64) -> &mut Self {
+        self.x_label_rotation = Some(angle);
+        self
+    }
+
     /// The offset of y labels. This is used when we want to place the label in the middle of
     /// the grid. This is useful if we are drawing a histogram
     /// - `value`: The offset in pixel
@@ -216,13 +353,13 @@ where
         self
     }
 
-    /// Disable the mesh for the x axis.
+    /// Disable the grid lines of the x axis, the tick marks and the labels are still drawn
     pub fn disable_x_mesh(&mut self) -> &mut Self {
         self.draw_x_mesh = false;
         self
     }
 
-    /// Disable the mesh for the y axis
+    /// Disable the grid lines of the y axis, the tick marks and the labels are still drawn
     pub fn disable_y_mesh(&mut self) -> &mut Self {
         self.draw_y_mesh = false;
         self
@@ -270,6 +407,39 @@ where
         self
     }
 
+    /// Set how many minor key points there are between two labels of the X axis, which have
+    /// a minor grid line and a minor tick. On a log scaled axis, the minor key points are the
+    /// multiples of each power instead. By default, there isn't any minor key point.
+    /// - `value`: The number of minor key points between two labels
+    pub fn x_minor_count(&mut self, value: usize) -> &mut Self {
+        self.n_x_minor = value;
+        self
+    }
+
+    /// Set how many minor key points there are between two labels of the Y axis, which have
+    /// a minor grid line and a minor tick. On a log scaled axis, the minor key points are the
+    /// multiples of each power instead. By default, there isn't any minor key point.
+    /// - `value`: The number of minor key points between two labels
+    pub fn y_minor_count(&mut self, value: usize) -> &mut Self {
+        self.n_y_minor = value;
+        self
+    }
+
+    /// Set the style for the minor grid, which replaces the fine grind grid of the axes with
+    /// minor key points
+    /// - `style`: The minor grid style
+    pub fn minor_grid_style<T: Into<ShapeStyle>>(&mut self, style: T) -> &mut Self {
+        self.minor_line_style = Some(style.into());
+        self
+    }
+
+    /// Set the length of the minor ticks, 0 means there isn't any minor tick
+    /// - `value`: The length of the minor ticks
+    pub fn minor_tick_length<S: SizeDesc>(&mut self, value: S) -> &mut Self {
+        self.minor_tick_size = value.in_pixels(&self.parent_size).max(0);
+        self
+    }
+
     /// Set the style for the coarse grind grid
     /// - `style`: This is the coarse grind grid style
     pub fn line_style_1<T: Into<ShapeStyle>>(&mut self, style: T) -> &mut Self {
@@ -284,6 +454,56 @@ where
         self
     }
 
+    /// Set the style for the bold grid lines of the X axis, which are the vertical ones
+    /// - `style`: The bold line style, which overrides `line_style_1` for the X axis
+    pub fn x_bold_line_style<T: Into<ShapeStyle>>(&mut self, style: T) -> &mut Self {
+        self.x_line_styles[0] = Some(style.into());
+        self
+    }
+
+    /// Set the style for the light grid lines of the X axis, which are the vertical ones
+    /// - `style`: The light line style, which overrides `line_style_2` for the X axis
+    pub fn x_light_line_style<T: Into<ShapeStyle>>(&mut self, style: T) -> &mut Self {
+        self.x_line_styles[1] = Some(style.into());
+        self
+    }
+
+    /// Set the style for the bold grid lines of the Y axis, which are the horizontal ones
+    /// - `style`: The bold line style, which overrides `line_style_1` for the Y axis
+    pub fn y_bold_line_style<T: Into<ShapeStyle>>(&mut self, style: T) -> &mut Self {
+        self.y_line_styles[0] = Some(style.into());
+        self
+    }
+
+    /// Set the style for the light grid lines of the Y axis, which are the horizontal ones
+    /// - `style`: The light line style, which overrides `line_style_2` for the Y axis
+    pub fn y_light_line_style<T: Into<ShapeStyle>>(&mut self, style: T) -> &mut Self {
+        self.y_line_styles[1] = Some(style.into());
+        self
+    }
+
+    /// Decide which grid lines of the X axis are bold by their values, for example every
+    /// multiple of 10, instead of the lines of the labels. The other grid lines are light.
+    /// - `predicate`: The function that tells if the line of a key point is bold
+    pub fn x_bold_line_predicate(
+        &mut self,
+        predicate: &'b dyn Fn(&X::ValueType) -> bool,
+    ) -> &mut Self {
+        self.x_bold_line_predicate = Some(predicate);
+        self
+    }
+
+    /// Decide which grid lines of the Y axis are bold by their values, for example every
+    /// multiple of 10, instead of the lines of the labels. The other grid lines are light.
+    /// - `predicate`: The function that tells if the line of a key point is bold
+    pub fn y_bold_line_predicate(
+        &mut self,
+        predicate: &'b dyn Fn(&Y::ValueType) -> bool,
+    ) -> &mut Self {
+        self.y_bold_line_predicate = Some(predicate);
+        self
+    }
+
     /// Set the style of the label text
     /// - `style`: The text style that would be applied to the labels
     pub fn label_style<T: IntoTextStyle<'b>>(&mut self, style: T) -> &mut Self {
@@ -310,14 +530,14 @@ where
     /// Set the formatter function for the X label text
     /// - `fmt`: The formatter function
     pub fn x_label_formatter(&mut self, fmt: &'b dyn Fn(&X::ValueType) -> String) -> &mut Self {
-        self.format_x = fmt;
+        self.format_x = Some(fmt);
         self
     }
 
     /// Set the formatter function for the Y label text
     /// - `fmt`: The formatter function
     pub fn y_label_formatter(&mut self, fmt: &'b dyn Fn(&Y::ValueType) -> String) -> &mut Self {
-        self.format_y = fmt;
+        self.format_y = Some(fmt);
         self
     }
 
@@ -342,8 +562,28 @@ where
         self
     }
 
+    /// Set the distance between the tick labels and the axis descriptions. If not given, the
+    /// descriptions are placed at the outer edges of the label areas.
+    /// - `gap`: The distance in pixels
+    pub fn axis_desc_gap<S: SizeDesc>(&mut self, gap: S) -> &mut Self {
+        self.axis_desc_gap = Some(gap.in_pixels(&self.parent_size).max(0));
+        self
+    }
+
+    /// Set where the axis descriptions are placed along the axes, which is the center of the
+    /// axes by default
+    /// - `pos`: The position of the descriptions
+    pub fn axis_desc_position(&mut self, pos: AxisDescPosition) -> &mut Self {
+        self.axis_desc_position = pos;
+        self
+    }
+
     /// Draw the configured mesh on the target plot
-    pub fn draw(&mut self) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+    pub fn draw(&mut self) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        X::ValueType: Debug,
+        Y::ValueType: Debug,
+    {
         let mut target = None;
         std::mem::swap(&mut target, &mut self.target);
         let target = target.unwrap();
@@ -385,47 +625,137 @@ where
             .clone()
             .unwrap_or_else(|| x_label_style.clone());
 
+        let x_label_style = match self.x_label_rotation {
+            Some(angle) => x_label_style.transform(FontTransform::from_angle(angle)),
+            None => x_label_style,
+        };
+
+        let x_styles = [
+            self.x_line_styles[0]
+                .clone()
+                .unwrap_or_else(|| mesh_style_1.clone()),
+            self.x_line_styles[1]
+                .clone()
+                .unwrap_or_else(|| mesh_style_2.clone()),
+        ];
+        let y_styles = [
+            self.y_line_styles[0]
+                .clone()
+                .unwrap_or_else(|| mesh_style_1.clone()),
+            self.y_line_styles[1]
+                .clone()
+                .unwrap_or_else(|| mesh_style_2.clone()),
+        ];
+        let (x_fine_mesh, y_fine_mesh) = (
+            self.draw_x_mesh && self.n_x_minor == 0,
+            self.draw_y_mesh && self.n_y_minor == 0,
+        );
+
+        /* The fine grid has the bold lines an axis has a predicate for, so they aren't drawn
+         * again with the coarse grid */
+        let (x_predicate, y_predicate) = (&self.x_bold_line_predicate, &self.y_bold_line_predicate);
         target.draw_mesh(
             (self.n_y_labels * 10, self.n_x_labels * 10),
-            &mesh_style_2,
+            |m: &MeshLine<X, Y>| match m {
+                MeshLine::XMesh(_, _, v) if x_fine_mesh => Some(match x_predicate {
+                    Some(is_bold) if is_bold(v) => x_styles[0].clone(),
+                    _ => x_styles[1].clone(),
+                }),
+                MeshLine::YMesh(_, _, v) if y_fine_mesh => Some(match y_predicate {
+                    Some(is_bold) if is_bold(v) => y_styles[0].clone(),
+                    _ => y_styles[1].clone(),
+                }),
+                _ => None,
+            },
             &x_label_style,
             &y_label_style,
-            |_| None,
-            self.draw_x_mesh,
-            self.draw_y_mesh,
+            |_, _| None,
             self.x_label_offset,
             self.y_label_offset,
             false,
             false,
             &axis_style,
             &axis_desc_style,
-            self.x_desc.clone(),
-            self.y_desc.clone(),
+            None,
+            None,
+            (self.axis_desc_gap, self.axis_desc_position),
             self.x_tick_size,
             self.y_tick_size,
+            (self.tick_direction, self.tick_sides),
+            self.thin_labels,
+        )?;
+
+        let minor_style = self
+            .minor_line_style
+            .clone()
+            .unwrap_or_else(|| mesh_style_2.clone());
+        target.draw_minor_mesh(
+            (self.n_y_labels, self.n_x_labels),
+            (self.n_x_minor, self.n_y_minor),
+            (self.draw_x_mesh, self.draw_y_mesh),
+            (self.draw_x_axis, self.draw_y_axis),
+            &minor_style,
+            &axis_style,
+            self.minor_tick_size,
         )?;
 
         target.draw_mesh(
             (self.n_y_labels, self.n_x_labels),
-            &mesh_style_1,
+            |m: &MeshLine<X, Y>| match (m, x_predicate, y_predicate) {
+                (MeshLine::XMesh(_, _, v), Some(is_bold), _)
+                    if self.draw_x_mesh && !x_fine_mesh =>
+                {
+                    Some(x_styles[usize::from(!is_bold(v))].clone())
+                }
+                (MeshLine::XMesh(_, _, _), None, _) if self.draw_x_mesh => {
+                    Some(x_styles[0].clone())
+                }
+                (MeshLine::YMesh(_, _, v), _, Some(is_bold))
+                    if self.draw_y_mesh && !y_fine_mesh =>
+                {
+                    Some(y_styles[usize::from(!is_bold(v))].clone())
+                }
+                (MeshLine::YMesh(_, _, _), _, None) if self.draw_y_mesh => {
+                    Some(y_styles[0].clone())
+                }
+                _ => None,
+            },
             &x_label_style,
             &y_label_style,
-            |m| match m {
-                MeshLine::XMesh(_, _, v) => Some((self.format_x)(v)),
-                MeshLine::YMesh(_, _, v) => Some((self.format_y)(v)),
+            |coord, m| match m {
+                MeshLine::XMesh(_, _, v) => Some(match self.format_x {
+                    Some(fmt) => fmt(v),
+                    None => coord
+                        .x_spec()
+                        .format_label(v)
+                        .unwrap_or_else(|| format!("{:?}", v)),
+                }),
+                MeshLine::YMesh(_, _, v) => Some(match self.format_y {
+                    Some(fmt) => fmt(v),
+                    None => coord
+                        .y_spec()
+                        .format_label(v)
+                        .unwrap_or_else(|| format!("{:?}", v)),
+                }),
             },
-            self.draw_x_mesh,
-            self.draw_y_mesh,
             self.x_label_offset,
             self.y_label_offset,
             self.draw_x_axis,
             self.draw_y_axis,
             &axis_style,
             &axis_desc_style,
-            None,
-            None,
+            self.x_desc.clone(),
+            self.y_desc.clone(),
+            (self.axis_desc_gap, self.axis_desc_position),
             self.x_tick_size,
             self.y_tick_size,
-        )
+            (self.tick_direction, self.tick_sides),
+            self.thin_labels,
+        )?;
+
+        if let Some(style) = &self.frame_style {
+            target.draw_plot_frame(style)?;
+        }
+        Ok(())
     }
 }
@@ -10,16 +10,32 @@ In Plotters, a series is abstracted as an iterator of elements.
 
 `ChartBuilder` is used to construct a chart. To learn more detailed information, check the
 detailed description for each struct.
+
+An interactive chart can keep its layout in a `ChartState`, which is restored onto the drawing
+area with new axis ranges for each frame instead of building the chart again.
+
+The small multiples of a plot are built with `SubplotGridBuilder`, which lays out a grid of
+charts with the same size of the plotting area and can share the axes over the rows and the
+columns.
 */
 
 mod builder;
+mod colorbar;
 mod context;
 mod dual_coord;
+mod inset;
 mod mesh;
 mod series;
+mod state;
+mod subplot;
+mod z_order;
 
 pub use builder::{ChartBuilder, LabelAreaPosition};
+pub use colorbar::{ColorbarConfig, ColorbarPosition};
 pub use context::{ChartContext, SeriesAnno};
 pub use dual_coord::DualCoordChartContext;
-pub use mesh::MeshStyle;
-pub use series::{SeriesLabelPosition, SeriesLabelStyle};
+pub use inset::{InsetConfig, InsetRect};
+pub use mesh::{AxisDescPosition, MeshStyle, TickMarkDirection};
+pub use series::{SeriesLabelOrder, SeriesLabelPosition, SeriesLabelStyle};
+pub use state::ChartState;
+pub use subplot::{SubplotGrid, SubplotGridBuilder};
@@ -1,9 +1,11 @@
 use super::ChartContext;
-use crate::coord::CoordTranslate;
+use crate::coord::{CoordTranslate, Shift};
 use crate::drawing::backend::{BackendCoord, DrawingErrorKind};
-use crate::drawing::{DrawingAreaErrorKind, DrawingBackend};
-use crate::element::{EmptyElement, IntoDynElement, MultiLineText, Rectangle};
-use crate::style::{IntoFont, IntoTextStyle, ShapeStyle, SizeDesc, TextStyle, TRANSPARENT};
+use crate::drawing::{DrawingArea, DrawingAreaErrorKind, DrawingBackend, OutOfRange};
+use crate::element::{DynElement, EmptyElement, IntoDynElement, MultiLineText, Rectangle, Text};
+use crate::style::{
+    FontDesc, IntoFont, IntoTextStyle, Pos, ShapeStyle, SizeDesc, TextStyle, TRANSPARENT,
+};
 
 /// Describes where we want to put the series label
 pub enum SeriesLabelPosition {
@@ -18,6 +20,26 @@ pub enum SeriesLabelPosition {
     LowerRight,
     /// Force the series label drawn at the specific location
     Coordinate(i32, i32),
+    /// Put the series label on the right of the plotting area. If the chart reserves an area
+    /// for it with `ChartBuilder::right_series_label_area_size`, the series are laid out in as
+    /// many columns as the area needs. Otherwise, the label is drawn at the top, over the right
+    /// label area or the margin of the chart, which must be large enough.
+    OutsideRight,
+    /// Put the series label below the plotting area. If the chart reserves an area for it with
+    /// `ChartBuilder::bottom_series_label_area_size`, the series are laid out in as many columns
+    /// as the area fits. Otherwise, the label is drawn at the left, over the bottom label area
+    /// or the margin of the chart, which must be large enough.
+    OutsideBottom,
+}
+
+/// The order the series are listed in the series label
+pub enum SeriesLabelOrder {
+    /// The order the series are drawn in, which is the default
+    Drawing,
+    /// The reverse of the order the series are drawn in, which matches the stacked series
+    Reversed,
+    /// The alphabetical order of the labels
+    Alphabetical,
 }
 
 impl SeriesLabelPosition {
@@ -31,19 +53,45 @@ impl SeriesLabelPosition {
                 }
                 UpperRight | MiddleRight | LowerRight => area_dim.0 as i32 - label_dim.0 as i32,
                 Coordinate(x, _) => *x,
+                OutsideRight => area_dim.0 as i32 + 10,
+                OutsideBottom => 5,
             } - 5,
             match self {
-                UpperLeft | UpperMiddle | UpperRight => 0,
+                UpperLeft | UpperMiddle | UpperRight | OutsideRight => 0,
                 MiddleLeft | MiddleMiddle | MiddleRight => {
                     (area_dim.1 as i32 - label_dim.1 as i32) / 2
                 }
                 LowerLeft | LowerMiddle | LowerRight => area_dim.1 as i32 - label_dim.1 as i32,
                 Coordinate(_, y) => *y,
+                OutsideBottom => area_dim.1 as i32 + 10,
             } - 5,
         )
     }
 }
 
+/// The function which makes the legend element of a series
+type LegendFunc<'a, 'c, DB> = &'c dyn Fn(BackendCoord) -> DynElement<'a, DB, BackendCoord>;
+
+/// Cut the end of a label and put an ellipsis there, so it's at most as wide as `max_width`
+fn fit_label<DB: DrawingBackend>(
+    area: &DrawingArea<DB, Shift>,
+    label: &str,
+    font: &FontDesc,
+    max_width: i32,
+) -> Result<String, DrawingAreaErrorKind<DB::ErrorType>> {
+    if area.estimate_text_size(label, font)?.0 as i32 <= max_width {
+        return Ok(label.to_string());
+    }
+    let mut chars: Vec<char> = label.chars().collect();
+    while chars.pop().is_some() {
+        let text = format!("{}...", chars.iter().collect::<String>().trim_end());
+        if area.estimate_text_size(&text, font)?.0 as i32 <= max_width {
+            return Ok(text);
+        }
+    }
+    Ok("...".to_string())
+}
+
 /// The struct to specify the series label of a target chart context
 pub struct SeriesLabelStyle<'a, 'b, DB: DrawingBackend, CT: CoordTranslate> {
     target: &'b mut ChartContext<'a, DB, CT>,
@@ -53,6 +101,7 @@ pub struct SeriesLabelStyle<'a, 'b, DB: DrawingBackend, CT: CoordTranslate> {
     background: ShapeStyle,
     label_font: Option<TextStyle<'b>>,
     margin: u32,
+    order: SeriesLabelOrder,
 }
 
 impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, 'b, DB, CT> {
@@ -65,6 +114,7 @@ impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, '
             background: (&TRANSPARENT).into(),
             label_font: None,
             margin: 10,
+            order: SeriesLabelOrder::Drawing,
         }
     }
 
@@ -109,6 +159,13 @@ impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, '
         self
     }
 
+    /// Set the order the series are listed in
+    /// `order` - The order of the series
+    pub fn order(&mut self, order: SeriesLabelOrder) -> &mut Self {
+        self.order = order;
+        self
+    }
+
     /// Set the series label font
     /// `font` - The font
     pub fn label_font<F: IntoTextStyle<'b>>(&mut self, font: F) -> &mut Self {
@@ -116,7 +173,8 @@ impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, '
         self
     }
 
-    /// Draw the series label area
+    /// Draw the series label area, which lists the series with a label. The area is sized to fit
+    /// the labels, and nothing is drawn if no series has a label.
     pub fn draw(&mut self) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
         let drawing_area = self.target.plotting_area().strip_coord_spec();
 
@@ -131,21 +189,44 @@ impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, '
             temp.unwrap_or(default_style)
         };
 
-        let mut label_element = MultiLineText::<_, &str>::new((0, 0), &font);
-        let mut funcs = vec![];
+        let mut annos: Vec<_> = self
+            .target
+            .series_anno
+            .iter()
+            .filter(|anno| anno.get_label() != "")
+            .collect();
+        if annos.is_empty() {
+            return Ok(());
+        }
+        match self.order {
+            SeriesLabelOrder::Drawing => {}
+            SeriesLabelOrder::Reversed => annos.reverse(),
+            SeriesLabelOrder::Alphabetical => {
+                annos.sort_by(|a, b| a.get_label().cmp(b.get_label()))
+            }
+        }
 
-        for anno in self.target.series_anno.iter() {
-            let label_text = anno.get_label();
-            let draw_func = anno.get_draw_func();
+        let labels: Vec<_> = annos.iter().map(|anno| anno.get_label()).collect();
+        let funcs: Vec<_> = annos
+            .iter()
+            .map(|anno| {
+                anno.get_draw_func()
+                    .unwrap_or_else(|| &|p: BackendCoord| EmptyElement::at(p).into_dyn())
+            })
+            .collect();
 
-            if label_text == "" && draw_func.is_none() {
-                continue;
-            }
+        let reserved_area = match self.position {
+            SeriesLabelPosition::OutsideRight => self.target.series_label_area[0].as_ref(),
+            SeriesLabelPosition::OutsideBottom => self.target.series_label_area[1].as_ref(),
+            _ => None,
+        };
+        if let Some(area) = reserved_area {
+            return self.draw_in_area(area, &labels, &funcs, &font);
+        }
 
-            funcs.push(
-                draw_func.unwrap_or_else(|| &|p: BackendCoord| EmptyElement::at(p).into_dyn()),
-            );
-            label_element.push_line(label_text);
+        let mut label_element = MultiLineText::<_, &str>::new((0, 0), &font);
+        for label in labels {
+            label_element.push_line(label);
         }
 
         let (mut w, mut h) = label_element
@@ -166,15 +247,29 @@ impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, '
             label_y + margin,
         ));
 
-        drawing_area.draw(&Rectangle::new(
-            [(label_x, label_y), (label_x + w, label_y + h)],
-            self.background.filled(),
-        ))?;
-        drawing_area.draw(&Rectangle::new(
-            [(label_x, label_y), (label_x + w, label_y + h)],
-            self.border_style.clone(),
-        ))?;
-        drawing_area.draw(&label_element)?;
+        // The label outside of the plotting area isn't pinned to its edges
+        let policy = match self.position {
+            SeriesLabelPosition::OutsideRight | SeriesLabelPosition::OutsideBottom => {
+                OutOfRange::Draw
+            }
+            _ => OutOfRange::Clamp,
+        };
+
+        drawing_area.draw_with_policy(
+            &Rectangle::new(
+                [(label_x, label_y), (label_x + w, label_y + h)],
+                self.background.filled(),
+            ),
+            policy,
+        )?;
+        drawing_area.draw_with_policy(
+            &Rectangle::new(
+                [(label_x, label_y), (label_x + w, label_y + h)],
+                self.border_style.clone(),
+            ),
+            policy,
+        )?;
+        drawing_area.draw_with_policy(&label_element, policy)?;
 
         for (((_, y0), (_, y1)), make_elem) in label_element
             .compute_line_layout()
@@ -183,7 +278,69 @@ impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, '
             .zip(funcs.into_iter())
         {
             let legend_element = make_elem((label_x + margin, (y0 + y1) / 2));
-            drawing_area.draw(&legend_element)?;
+            drawing_area.draw_with_policy(&legend_element, policy)?;
+        }
+
+        Ok(())
+    }
+
+    /// Draw the series label in the area the chart reserves for it. The series go down the
+    /// columns in the area on the right, and across the rows in the area at the bottom, in as
+    /// many columns as the rows need to fit the height of the area, and the labels too wide for
+    /// their column are cut with an ellipsis.
+    fn draw_in_area(
+        &self,
+        area: &DrawingArea<DB, Shift>,
+        labels: &[&str],
+        funcs: &[LegendFunc<'a, '_, DB>],
+        font: &TextStyle,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let font = font.pos(Pos::default());
+        let margin = self.margin as i32;
+        let legend_size = self.legend_area_size as i32;
+        let (w, h) = area.dim_in_pixel();
+        let (inner_w, inner_h) = (w as i32 - 2 * margin, h as i32 - 2 * margin);
+        let line_height = ((font.font.get_size() * 1.25).round() as i32).max(1);
+
+        let mut text_width = 0;
+        for label in labels {
+            text_width = text_width.max(area.estimate_text_size(label, &font.font)?.0 as i32);
+        }
+        let entry_width = legend_size + text_width + margin;
+
+        // The columns needed for the rows to fit the height of the area, and the area at the
+        // bottom has as many more as fit its width
+        let count = labels.len() as i32;
+        let max_rows = (inner_h / line_height).clamp(1, count);
+        let columns = match self.position {
+            SeriesLabelPosition::OutsideBottom => (inner_w / entry_width).clamp(1, count),
+            _ => 1,
+        }
+        .max((count + max_rows - 1) / max_rows);
+        let rows = (count + columns - 1) / columns;
+        let column_width = (inner_w / columns).min(entry_width);
+
+        let corners = [
+            (0, 0),
+            (
+                columns * column_width + 2 * margin,
+                rows * line_height + 2 * margin,
+            ),
+        ];
+        area.draw(&Rectangle::new(corners, self.background.filled()))?;
+        area.draw(&Rectangle::new(corners, self.border_style.clone()))?;
+
+        for (idx, (label, make_elem)) in labels.iter().zip(funcs.iter()).enumerate() {
+            let idx = idx as i32;
+            let (column, row) = match self.position {
+                SeriesLabelPosition::OutsideBottom => (idx % columns, idx / columns),
+                _ => (idx / rows, idx % rows),
+            };
+            let (x, y) = (margin + column * column_width, margin + row * line_height);
+            let text = fit_label(area, label, &font.font, column_width - legend_size - margin)?;
+            let (_, text_height) = area.estimate_text_size(&text, &font.font)?;
+            area.draw(&Text::new(text, (x + legend_size, y), font.clone()))?;
+            area.draw(&make_elem((x, y + text_height as i32 / 2)))?;
         }
 
         Ok(())
@@ -0,0 +1,182 @@
+use super::ChartContext;
+
+use crate::coord::{CoordTranslate, Ranged, RangedCoord, Shift};
+use crate::drawing::backend::{BackendCoord, DrawingBackend};
+use crate::drawing::{DrawingArea, OutOfRange};
+
+/// The position and the size of an area of the chart, in backend pixels
+type AreaRect = (BackendCoord, (u32, u32));
+
+fn area_rect<DB: DrawingBackend, CT: CoordTranslate>(area: &DrawingArea<DB, CT>) -> AreaRect {
+    (area.get_base_pixel(), area.dim_in_pixel())
+}
+
+/// The layout and the coordinate system of a chart, which is detached from the drawing area.
+/// It's useful for an interactive chart or an animation, which redraws the same chart with
+/// different axis ranges: the chart is built once with `ChartBuilder`, turned into a state with
+/// `ChartContext::into_chart_state`, then restored onto the drawing area for each frame with
+/// `ChartState::restore`, without going through the layout again.
+///
+/// The state keeps the plotting area, the label areas, the areas of the series label and the
+/// colorbars, the out-of-range policy and the text clipping flag. The mesh, the series and the
+/// caption aren't kept, so they are drawn again on the restored chart. The draws kept by
+/// `ChartContext::draw_with_z_order` are flushed when the chart is turned into a state.
+pub struct ChartState<CT: CoordTranslate> {
+    x_label_area: [Option<AreaRect>; 2],
+    y_label_area: [Option<AreaRect>; 2],
+    series_label_area: [Option<AreaRect>; 2],
+    colorbar_area: [Option<AreaRect>; 2],
+    drawing_area: AreaRect,
+    coord: CT,
+    out_of_range: OutOfRange,
+    clip_text: bool,
+}
+
+impl<'a, DB: DrawingBackend, CT: CoordTranslate> ChartContext<'a, DB, CT> {
+    /// Convert the chart context into a chart state, which can be restored onto the drawing
+    /// area later, see `ChartState` for details
+    pub fn into_chart_state(self) -> ChartState<CT> {
+        let label_rect = |area: &Option<DrawingArea<DB, Shift>>| area.as_ref().map(area_rect);
+        ChartState {
+            x_label_area: [
+                label_rect(&self.x_label_area[0]),
+                label_rect(&self.x_label_area[1]),
+            ],
+            y_label_area: [
+                label_rect(&self.y_label_area[0]),
+                label_rect(&self.y_label_area[1]),
+            ],
+            series_label_area: [
+                label_rect(&self.series_label_area[0]),
+                label_rect(&self.series_label_area[1]),
+            ],
+            colorbar_area: [
+                label_rect(&self.colorbar_area[0]),
+                label_rect(&self.colorbar_area[1]),
+            ],
+            drawing_area: area_rect(&self.drawing_area),
+            out_of_range: self.out_of_range,
+            clip_text: self.clip_text,
+            coord: self.into_drawing_area().into_coord_spec(),
+        }
+    }
+}
+
+impl<CT: CoordTranslate> ChartState<CT> {
+    /// Get the coordinate system of the chart
+    pub fn coord_spec(&self) -> &CT {
+        &self.coord
+    }
+
+    /// Restore the chart onto the drawing area, which should be the one the chart was built
+    /// on. The drawing area isn't cleared, and nothing is drawn on it.
+    ///
+    /// - `area`: The drawing area to draw the chart on
+    /// - **returns**: The chart context, which is the same as the one the state was made of
+    pub fn restore<'a, DB: DrawingBackend>(
+        self,
+        area: &DrawingArea<DB, Shift>,
+    ) -> ChartContext<'a, DB, CT> {
+        let base = area.get_base_pixel();
+        let shrink =
+            |(pos, size): AreaRect| area.clone().shrink((pos.0 - base.0, pos.1 - base.1), size);
+        ChartContext {
+            x_label_area: [
+                self.x_label_area[0].map(shrink),
+                self.x_label_area[1].map(shrink),
+            ],
+            y_label_area: [
+                self.y_label_area[0].map(shrink),
+                self.y_label_area[1].map(shrink),
+            ],
+            drawing_area: shrink(self.drawing_area).apply_coord_spec(self.coord),
+            series_anno: vec![],
+            series_label_area: [
+                self.series_label_area[0].map(shrink),
+                self.series_label_area[1].map(shrink),
+            ],
+            colorbar_area: [
+                self.colorbar_area[0].map(shrink),
+                self.colorbar_area[1].map(shrink),
+            ],
+            out_of_range: self.out_of_range,
+            clip_text: self.clip_text,
+            drawn_labels: Default::default(),
+            deferred: vec![],
+        }
+    }
+}
+
+impl<X: Ranged, Y: Ranged> ChartState<RangedCoord<X, Y>> {
+    /// Change the range of the X axis, which takes effect when the chart is restored
+    pub fn set_x_range<R: Into<X>>(&mut self, x_spec: R) -> &mut Self {
+        self.coord.set_x_spec(x_spec.into());
+        self
+    }
+
+    /// Change the range of the Y axis, which takes effect when the chart is restored
+    pub fn set_y_range<R: Into<Y>>(&mut self, y_spec: R) -> &mut Self {
+        self.coord.set_y_spec(y_spec.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_chart_state_restore() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_circle(|_, _, _, center, _| assert_eq!(center, (110, 18)));
+            m.drop_check(|b| assert_eq!(b.num_draw_circle_call, 1));
+        });
+        let chart = ChartBuilder::on(&drawing_area)
+            .x_label_area_size(20)
+            .y_label_area_size(20)
+            .build_ranged(0.0..10.0, 0.0..10.0)
+            .expect("Build chart error");
+        let expected = chart.backend_coord(&(5.0, 9.0));
+
+        let mut state = chart.into_chart_state();
+        state.set_x_range(0.0..20.0).set_y_range(0.0..10.0);
+
+        let mut chart = state.restore(&drawing_area);
+        assert_eq!(chart.x_range(), 0.0..20.0);
+        // The plotting area is the same, so only the X axis changes
+        assert_eq!(chart.backend_coord(&(10.0, 9.0)), expected);
+        chart
+            .draw_series(std::iter::once(Circle::new((10.0, 9.0), 3, RED.filled())))
+            .expect("Drawing Error");
+    }
+
+    #[test]
+    fn test_chart_state_labels() {
+        let texts = std::rc::Rc::new(std::cell::RefCell::new(vec![]));
+        let draw = |restore: bool| {
+            let texts = texts.clone();
+            let drawing_area = create_mocked_drawing_area(300, 200, move |m| {
+                m.check_draw_text(move |_, _, _, pos, text| {
+                    texts.borrow_mut().push((pos, text.to_string()));
+                });
+            });
+            let chart = ChartBuilder::on(&drawing_area)
+                .x_label_area_size(20)
+                .y_label_area_size(30)
+                .build_ranged(0..10, 0..10)
+                .expect("Build chart error");
+            let mut chart = if restore {
+                chart.into_chart_state().restore(&drawing_area)
+            } else {
+                chart
+            };
+            chart.configure_mesh().draw().expect("Drawing Error");
+        };
+        draw(false);
+        let direct = texts.replace(vec![]);
+        draw(true);
+        // The labels of the restored chart are drawn at the same places
+        assert!(!direct.is_empty());
+        assert_eq!(direct, *texts.borrow());
+    }
+}
@@ -0,0 +1,253 @@
+use super::{ChartBuilder, ChartContext};
+use crate::coord::{AsRangedCoord, CoordTranslate, RangedCoord, Shift};
+use crate::drawing::{DrawingArea, DrawingAreaErrorKind, DrawingBackend};
+use crate::style::SizeDesc;
+
+/// The builder of a grid of charts on a drawing area, the small multiples of the same kind of
+/// plot. All the cells of the grid are laid out with the same size of the plotting area.
+pub struct SubplotGridBuilder<'a, DB: DrawingBackend> {
+    root_area: &'a DrawingArea<DB, Shift>,
+    shape: (usize, usize),
+    margin: u32,
+    x_label_area_size: u32,
+    y_label_area_size: u32,
+    share_x: bool,
+    share_y: bool,
+}
+
+impl<'a, DB: DrawingBackend> SubplotGridBuilder<'a, DB> {
+    /// Create the builder of a grid of charts on the given drawing area
+    /// - `root`: The drawing area the grid is laid out on
+    /// - `shape`: The number of the rows and the columns of the grid
+    pub fn on(root: &'a DrawingArea<DB, Shift>, (rows, cols): (usize, usize)) -> Self {
+        Self {
+            root_area: root,
+            shape: (rows.max(1), cols.max(1)),
+            margin: 0,
+            x_label_area_size: 0,
+            y_label_area_size: 0,
+            share_x: false,
+            share_y: false,
+        }
+    }
+
+    /// Set the margin around each chart of the grid
+    pub fn margin<S: SizeDesc>(&mut self, size: S) -> &mut Self {
+        self.margin = size.in_pixels(self.root_area).max(0) as u32;
+        self
+    }
+
+    /// Set the size of the label area of the X axis, which is below the plotting area
+    pub fn x_label_area_size<S: SizeDesc>(&mut self, size: S) -> &mut Self {
+        self.x_label_area_size = size.in_pixels(self.root_area).max(0) as u32;
+        self
+    }
+
+    /// Set the size of the label area of the Y axis, which is on the left of the plotting area
+    pub fn y_label_area_size<S: SizeDesc>(&mut self, size: S) -> &mut Self {
+        self.y_label_area_size = size.in_pixels(self.root_area).max(0) as u32;
+        self
+    }
+
+    /// Share the X axis over each column of the grid: the charts of a column have the X range
+    /// of the one in the first row, and only the charts in the last row have the label area of
+    /// the X axis
+    pub fn share_x(&mut self, share: bool) -> &mut Self {
+        self.share_x = share;
+        self
+    }
+
+    /// Share the Y axis over each row of the grid: the charts of a row have the Y range of the
+    /// one in the first column, and only the charts in the first column have the label area of
+    /// the Y axis
+    pub fn share_y(&mut self, share: bool) -> &mut Self {
+        self.share_y = share;
+        self
+    }
+
+    /// Split the drawing area into the cells of the grid, where the ones having a label area
+    /// are larger by the size of it, and get the margins each cell is padded with on the right
+    /// and at the bottom, which take the pixels left over by the split
+    fn layout(&self) -> (Vec<DrawingArea<DB, Shift>>, (u32, u32)) {
+        let (rows, cols) = self.shape;
+        let (w, h) = self.root_area.dim_in_pixel();
+        let lead = if self.share_y {
+            self.y_label_area_size.min(w)
+        } else {
+            0
+        };
+        let trail = if self.share_x {
+            self.x_label_area_size.min(h)
+        } else {
+            0
+        };
+        let cell_w = (w - lead) / cols as u32;
+        let cell_h = (h - trail) / rows as u32;
+        let xs: Vec<_> = (1..cols)
+            .map(|k| (lead + cell_w * k as u32) as i32)
+            .collect();
+        let ys: Vec<_> = (1..rows).map(|k| (cell_h * k as u32) as i32).collect();
+        let cells = self.root_area.split_by_breakpoints(xs, ys);
+        (
+            cells,
+            (
+                w - lead - cell_w * cols as u32,
+                h - trail - cell_h * rows as u32,
+            ),
+        )
+    }
+
+    /// Build the charts of the grid with a 2D Cartesian coordinate system each
+    /// - `ranges`: The function which gives the X and the Y specifications of the chart at a
+    ///   row and a column. With a shared axis, the specification of that axis is taken from the
+    ///   first chart of the column or the row only.
+    /// - Returns: The grid of the charts
+    #[allow(clippy::type_complexity)]
+    pub fn build_ranged<X, Y, F>(
+        &self,
+        mut ranges: F,
+    ) -> Result<
+        SubplotGrid<'a, DB, RangedCoord<X::CoordDescType, Y::CoordDescType>>,
+        DrawingAreaErrorKind<DB::ErrorType>,
+    >
+    where
+        X: AsRangedCoord + Clone,
+        Y: AsRangedCoord + Clone,
+        F: FnMut(usize, usize) -> (X, Y),
+    {
+        let (rows, cols) = self.shape;
+        let (cells, (extra_w, extra_h)) = self.layout();
+        let mut x_specs: Vec<X> = vec![];
+        let mut y_specs: Vec<Y> = vec![];
+        let mut charts = vec![];
+        for (idx, cell) in cells.iter().enumerate() {
+            let (row, col) = (idx / cols, idx % cols);
+            let (mut x_spec, mut y_spec) = ranges(row, col);
+            if self.share_x && row > 0 {
+                x_spec = x_specs[col].clone();
+            }
+            if self.share_y && col > 0 {
+                y_spec = y_specs[row * cols].clone();
+            }
+            x_specs.push(x_spec.clone());
+            y_specs.push(y_spec.clone());
+
+            let last_row = row + 1 == rows;
+            let last_col = col + 1 == cols;
+            let mut builder = ChartBuilder::on(cell);
+            builder
+                .margin_top(self.margin)
+                .margin_left(self.margin)
+                .margin_right(self.margin + if last_col { extra_w } else { 0 })
+                .margin_bottom(self.margin + if last_row { extra_h } else { 0 });
+            if !self.share_x || last_row {
+                builder.x_label_area_size(self.x_label_area_size);
+            }
+            if !self.share_y || col == 0 {
+                builder.y_label_area_size(self.y_label_area_size);
+            }
+            // The chart is kept as a state, so it doesn't borrow the cell it's built on
+            let chart = builder.build_ranged(x_spec, y_spec)?;
+            charts.push(chart.into_chart_state().restore(cell));
+        }
+        Ok(SubplotGrid { charts, cols })
+    }
+}
+
+/// A grid of charts built by `SubplotGridBuilder`, whose charts are in the row-major order
+pub struct SubplotGrid<'a, DB: DrawingBackend, CT: CoordTranslate> {
+    charts: Vec<ChartContext<'a, DB, CT>>,
+    cols: usize,
+}
+
+impl<'a, DB: DrawingBackend, CT: CoordTranslate> SubplotGrid<'a, DB, CT> {
+    /// Get the number of the rows and the columns of the grid
+    pub fn shape(&self) -> (usize, usize) {
+        (self.charts.len() / self.cols, self.cols)
+    }
+
+    /// Get the chart at a row and a column, if it's in the grid
+    pub fn cell(&self, row: usize, col: usize) -> Option<&ChartContext<'a, DB, CT>> {
+        if col >= self.cols {
+            return None;
+        }
+        self.charts.get(row * self.cols + col)
+    }
+
+    /// Get the mutable chart at a row and a column, if it's in the grid
+    pub fn cell_mut(&mut self, row: usize, col: usize) -> Option<&mut ChartContext<'a, DB, CT>> {
+        if col >= self.cols {
+            return None;
+        }
+        self.charts.get_mut(row * self.cols + col)
+    }
+
+    /// Iterate over the charts of the grid with their rows and columns, in the row-major order
+    pub fn iter_mut(
+        &mut self,
+    ) -> impl Iterator<Item = (usize, usize, &mut ChartContext<'a, DB, CT>)> {
+        let cols = self.cols;
+        self.charts
+            .iter_mut()
+            .enumerate()
+            .map(move |(idx, chart)| (idx / cols, idx % cols, chart))
+    }
+
+    /// Take the charts of the grid, in the row-major order
+    pub fn into_charts(self) -> Vec<ChartContext<'a, DB, CT>> {
+        self.charts
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_subplot_grid_shared_axes() {
+        let drawing_area = create_mocked_drawing_area(403, 302, |_| {});
+        let mut grid = SubplotGridBuilder::on(&drawing_area, (2, 3))
+            .margin(5)
+            .x_label_area_size(20)
+            .y_label_area_size(30)
+            .share_x(true)
+            .share_y(true)
+            .build_ranged(|row, col| {
+                let (row, col) = (row as i32, col as i32);
+                (0..(col + 1) * 10 + row, 0..(row + 1) * 100 + col)
+            })
+            .expect("Create grid");
+        assert_eq!(grid.shape(), (2, 3));
+        assert!(grid.cell(0, 3).is_none());
+
+        let mut sizes = vec![];
+        for (row, col, chart) in grid.iter_mut() {
+            chart.configure_mesh().draw().expect("Drawing error");
+            sizes.push(chart.plotting_area().dim_in_pixel());
+            // Only the outer edges of the grid have the tick labels
+            let bottom = chart.drawn_labels(LabelAreaPosition::Bottom);
+            let left = chart.drawn_labels(LabelAreaPosition::Left);
+            assert_eq!(bottom.is_empty(), row == 0);
+            assert_eq!(left.is_empty(), col > 0);
+            // The ranges are taken from the first chart of the column and the row
+            assert_eq!(chart.x_range(), 0..(col as i32 + 1) * 10);
+            assert_eq!(chart.y_range(), 0..(row as i32 + 1) * 100);
+        }
+        assert!(sizes.iter().all(|&size| size == sizes[0]));
+        assert_eq!(sizes[0], ((403 - 30) / 3 - 10, (302 - 20) / 2 - 10));
+
+        // Without the shared axes each chart has its own labels and ranges
+        let grid = SubplotGridBuilder::on(&drawing_area, (2, 2))
+            .x_label_area_size(20)
+            .y_label_area_size(30)
+            .build_ranged(|row, col| {
+                let (row, col) = (row as i32, col as i32);
+                (0..(col + 1) * 10 + row, 0..(row + 1) * 100 + col)
+            })
+            .expect("Create grid");
+        let chart = grid.cell(1, 1).unwrap();
+        assert_eq!(chart.x_range(), 0..21);
+        assert_eq!(chart.y_range(), 0..201);
+        assert_eq!(chart.plotting_area().dim_in_pixel(), (201 - 30, 151 - 20));
+    }
+}
@@ -0,0 +1,203 @@
+use std::borrow::Borrow;
+use std::mem::ManuallyDrop;
+
+use super::{ChartContext, SeriesAnno};
+use crate::coord::CoordTranslate;
+use crate::drawing::{DrawingArea, DrawingAreaErrorKind, DrawingBackend};
+use crate::element::{Drawable, PointCollection};
+
+/// A draw kept by `ChartContext::draw_with_z_order` until the chart is flushed
+pub(super) type DeferredDraw<'a, DB, CT> = Box<
+    dyn FnOnce(
+            &mut ChartContext<'a, DB, CT>,
+        ) -> Result<(), DrawingAreaErrorKind<<DB as DrawingBackend>::ErrorType>>
+        + 'a,
+>;
+
+impl<'a, DB: DrawingBackend, CT: CoordTranslate> ChartContext<'a, DB, CT> {
+    /// Draw on the chart in the order of a z-index instead of the order of the calls. The draw
+    /// is kept until `flush` or `present` is called or the chart is dropped, which draw the kept
+    /// ones from the lowest z-index to the highest, and in the order of the calls for the same
+    /// z-index. Everything drawn without a z-index is drawn right away, so it's below the draws
+    /// kept for later.
+    ///
+    /// Anything drawn on the chart can be deferred this way, such as the mesh, the series, the
+    /// reference lines and the series labels. For example,
+    /// `chart.draw_with_z_order(1, |chart| chart.configure_mesh().draw())` draws the mesh over
+    /// the series drawn right away.
+    ///
+    /// - `z`: The z-index of the draw
+    /// - `draw`: The function that draws on the chart when it's flushed
+    pub fn draw_with_z_order<F>(&mut self, z: i32, draw: F) -> &mut Self
+    where
+        F: FnOnce(&mut ChartContext<'a, DB, CT>) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+            + 'a,
+    {
+        self.deferred.push((z, Box::new(draw)));
+        self
+    }
+
+    /// Draw a data series in the order of a z-index, see `draw_with_z_order`. The elements are
+    /// collected right away, and the series gets its annotation in the order of the call, so
+    /// its label is in the legend even if the legend is drawn before the chart is flushed.
+    pub fn draw_series_with_z_order<E, R, S>(
+        &mut self,
+        z: i32,
+        series: S,
+    ) -> &mut SeriesAnno<'a, DB>
+    where
+        for<'b> &'b E: PointCollection<'b, CT::From>,
+        E: Drawable<DB> + 'a,
+        R: Borrow<E> + 'a,
+        S: IntoIterator<Item = R>,
+    {
+        let elements: Vec<R> = series.into_iter().collect();
+        self.draw_with_z_order(z, move |chart| chart.draw_series_impl(elements));
+        self.alloc_series_anno()
+    }
+
+    /// Draw everything kept by `draw_with_z_order` in the order of the z-indexes. The draws
+    /// kept while flushing are drawn after the ones kept before.
+    pub fn flush(&mut self) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        while !self.deferred.is_empty() {
+            let mut deferred = std::mem::take(&mut self.deferred);
+            // The sort is stable, so the draws of the same z-index keep the order of the calls
+            deferred.sort_by_key(|(z, _)| *z);
+            for (_, draw) in deferred {
+                draw(self)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Flush the chart and present the drawing on the backend. The chart is also flushed when
+    /// it's dropped, but the errors of the drawing are only reported by `flush` and `present`.
+    pub fn present(&mut self) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        self.flush()?;
+        self.drawing_area.present()
+    }
+
+    /// Flush the chart and move its drawing area out of it, dropping the rest of the chart. The
+    /// chart can't be moved out of since it flushes itself when it's dropped.
+    pub(super) fn into_drawing_area(self) -> DrawingArea<DB, CT> {
+        let mut chart = ManuallyDrop::new(self);
+        chart.flush().ok();
+        // The pattern lists every field, so that none is left out of here
+        let ChartContext {
+            x_label_area,
+            y_label_area,
+            drawing_area,
+            series_anno,
+            series_label_area,
+            colorbar_area,
+            out_of_range: _,
+            clip_text: _,
+            drawn_labels,
+            deferred,
+        } = &mut *chart;
+        // The chart is never dropped, so each field is moved or dropped exactly once here
+        unsafe {
+            std::ptr::drop_in_place(x_label_area);
+            std::ptr::drop_in_place(y_label_area);
+            std::ptr::drop_in_place(series_anno);
+            std::ptr::drop_in_place(series_label_area);
+            std::ptr::drop_in_place(colorbar_area);
+            std::ptr::drop_in_place(drawn_labels);
+            std::ptr::drop_in_place(deferred);
+            std::ptr::read(drawing_area)
+        }
+    }
+}
+
+/// The draws kept by `draw_with_z_order` are flushed when the chart is dropped, ignoring the
+/// errors of the drawing
+impl<'a, DB: DrawingBackend, CT: CoordTranslate> Drop for ChartContext<'a, DB, CT> {
+    fn drop(&mut self) {
+        self.flush().ok();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_z_order() {
+        let drawn = Rc::new(RefCell::new(vec![]));
+        let (rects, paths, lines) = (drawn.clone(), drawn.clone(), drawn.clone());
+        let drawing_area = create_mocked_drawing_area(200, 100, move |m| {
+            m.check_draw_rect(move |c, _, _, _, _| rects.borrow_mut().push(c));
+            m.check_draw_path(move |c, _, _| paths.borrow_mut().push(c));
+            m.check_draw_line(move |c, _, _, _| lines.borrow_mut().push(c));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0..10, 0..10)
+            .expect("Create chart");
+
+        let rect = |color: &RGBColor| Rectangle::new([(1, 1), (5, 5)], color.filled());
+        chart
+            .draw_series_with_z_order(2, vec![rect(&RED)])
+            .label("red");
+        chart.draw_with_z_order(1, |chart| chart.draw_horizontal_line(5, &BLUE));
+        chart.draw_series_with_z_order(1, vec![rect(&GREEN)]);
+        chart.draw_with_z_order(3, |chart| {
+            chart
+                .configure_mesh()
+                .disable_x_mesh()
+                .disable_axes()
+                .y_labels(1)
+                .draw()
+        });
+        chart
+            .draw_series(vec![rect(&BLACK)])
+            .expect("Drawing error");
+        // The series without a z-index is drawn right away, and the others are kept
+        assert_eq!(*drawn.borrow(), vec![BLACK.to_rgba()]);
+        assert_eq!(chart.series_anno.len(), 3);
+        assert_eq!(chart.series_anno[0].get_label(), "red");
+
+        chart.present().expect("Drawing error");
+        let series_colors: Vec<_> = [BLACK, BLUE, GREEN, RED]
+            .iter()
+            .map(|c| c.to_rgba())
+            .collect();
+        assert_eq!(drawn.borrow()[..4], series_colors[..]);
+        // The mesh is drawn last, over the series
+        assert!(drawn.borrow().len() > 4);
+        assert!(drawn.borrow()[4..]
+            .iter()
+            .all(|c| !series_colors.contains(c)));
+        // Nothing is drawn again
+        let len = drawn.borrow().len();
+        chart.flush().expect("Drawing error");
+        assert_eq!(drawn.borrow().len(), len);
+    }
+
+    #[test]
+    fn test_z_order_flushed_on_drop() {
+        let drawn = Rc::new(RefCell::new(vec![]));
+        let rects = drawn.clone();
+        let drawing_area = create_mocked_drawing_area(200, 100, move |m| {
+            m.check_draw_rect(move |c, _, _, _, _| rects.borrow_mut().push(c));
+        });
+        let rect = |color: &RGBColor| Rectangle::new([(1, 1), (5, 5)], color.filled());
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0..10, 0..10)
+            .expect("Create chart");
+        chart.draw_series_with_z_order(1, vec![rect(&RED)]);
+        drop(chart);
+        assert_eq!(*drawn.borrow(), vec![RED.to_rgba()]);
+
+        // The chart is flushed before it's turned into the coordinate translation
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0..10, 0..10)
+            .expect("Create chart");
+        chart.draw_series_with_z_order(1, vec![rect(&BLUE)]);
+        let trans = chart.into_coord_trans();
+        assert_eq!(*drawn.borrow(), vec![RED.to_rgba(), BLUE.to_rgba()]);
+        assert_eq!(trans((0, 100)), Some((0, 0)));
+    }
+}
@@ -0,0 +1,301 @@
+use super::{AsRangedCoord, Ranged, RangedCoordf64, ReversibleRanged};
+use std::ops::Range;
+
+/// The axis made of several disjoint sub-ranges, which are put next to each other with a break
+/// between them. It's useful when a few outliers would otherwise squash the interesting part of
+/// the data. The pixels of the axis are shared by the sub-ranges in proportion to their
+/// weights, and the breaks are marked on the axis when the mesh is drawn.
+pub struct BrokenRange {
+    segments: Vec<Range<f64>>,
+    weights: Vec<f64>,
+    gap: f64,
+    clip_gaps: bool,
+}
+
+impl BrokenRange {
+    /// Create a new broken axis, where each sub-range gets the same share of the axis
+    ///
+    /// - `segments`: The sub-ranges of the axis, in ascending order and without overlap
+    /// - **returns**: The created axis
+    pub fn new<I: IntoIterator<Item = Range<f64>>>(segments: I) -> Self {
+        let segments: Vec<_> = segments.into_iter().collect();
+        Self {
+            weights: vec![1.0; segments.len()],
+            segments,
+            gap: 0.02,
+            clip_gaps: false,
+        }
+    }
+
+    /// Set the relative share of the axis that each sub-range gets
+    pub fn weights<I: IntoIterator<Item = f64>>(mut self, weights: I) -> Self {
+        self.weights = weights.into_iter().collect();
+        self
+    }
+
+    /// Set the portion of the axis between two sub-ranges, 2% by default
+    pub fn gap(mut self, gap: f64) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Set if the values in the gaps between two sub-ranges are clipped. By default, they are
+    /// mapped to the nearest edge of a sub-range. When they are clipped, they are moved one
+    /// axis length before the start of the axis, which is outside of the plotting area.
+    pub fn clip_gaps(mut self, clip: bool) -> Self {
+        self.clip_gaps = clip;
+        self
+    }
+
+    /// Check if the value is in one of the sub-ranges
+    pub fn contains(&self, value: f64) -> bool {
+        self.segments
+            .iter()
+            .any(|s| s.start <= value && value <= s.end)
+    }
+
+    /// Get the portions of the axis that each sub-range starts and ends at
+    fn spans(&self) -> Vec<(f64, f64)> {
+        let total: f64 = self.weights.iter().sum();
+        let gap = self.gap.max(0.0);
+        let usable = 1.0 - gap * self.segments.len().saturating_sub(1) as f64;
+        let mut start = 0.0;
+        self.weights
+            .iter()
+            .map(|weight| {
+                let span = (start, start + usable * weight / total);
+                start = span.1 + gap;
+                span
+            })
+            .collect()
+    }
+
+    /// Get the portion of the axis that the value is mapped to, if it's not clipped
+    fn portion(&self, value: f64) -> Option<f64> {
+        let spans = self.spans();
+        let last = self.segments.len() - 1;
+        let idx = if value < self.segments[0].start {
+            0
+        } else if value > self.segments[last].end {
+            last
+        } else {
+            let idx = self
+                .segments
+                .iter()
+                .position(|s| value <= s.end)
+                .unwrap_or(last);
+            if value < self.segments[idx].start {
+                if self.clip_gaps {
+                    return None;
+                }
+                // The value is in the gap after the previous sub-range
+                if value - self.segments[idx - 1].end < self.segments[idx].start - value {
+                    return Some(spans[idx - 1].1);
+                }
+                return Some(spans[idx].0);
+            }
+            idx
+        };
+        let (segment, span) = (&self.segments[idx], spans[idx]);
+        let ratio = (value - segment.start) / (segment.end - segment.start);
+        Some(span.0 + (span.1 - span.0) * ratio)
+    }
+}
+
+impl Ranged for BrokenRange {
+    type ValueType = f64;
+
+    fn map(&self, value: &f64, limit: (i32, i32)) -> i32 {
+        let actual_length = limit.1 - limit.0;
+        match self.portion(*value) {
+            Some(portion) => limit.0 + (f64::from(actual_length) * portion + 1e-3).floor() as i32,
+            None => limit.0 - actual_length,
+        }
+    }
+
+    fn key_points(&self, max_points: usize) -> Vec<f64> {
+        let mut ret = vec![];
+        for (segment, span) in self.segments.iter().zip(self.spans()) {
+            let share = span.1 - span.0;
+            let n = ((max_points as f64 * share) as usize)
+                .max(1)
+                .min(max_points);
+            let coord: RangedCoordf64 = segment.clone().into();
+            ret.extend(coord.key_points(n));
+        }
+        ret
+    }
+
+    fn range(&self) -> Range<f64> {
+        self.segments[0].start..self.segments[self.segments.len() - 1].end
+    }
+
+    fn format_label(&self, value: &f64) -> Option<String> {
+        Some(format!("{:?}", value))
+    }
+
+    fn axis_breaks(&self, limit: (i32, i32)) -> Vec<i32> {
+        let actual_length = f64::from(limit.1 - limit.0);
+        let spans = self.spans();
+        spans
+            .windows(2)
+            .map(|w| (w[0].1 + w[1].0) / 2.0)
+            .map(|portion| limit.0 + (actual_length * portion + 1e-3).floor() as i32)
+            .collect()
+    }
+}
+
+impl ReversibleRanged for BrokenRange {
+    fn unmap(&self, input: i32, limit: (i32, i32)) -> Option<f64> {
+        if input < limit.0.min(limit.1) || input > limit.0.max(limit.1) || limit.0 == limit.1 {
+            return None;
+        }
+        let portion = f64::from(input - limit.0) / f64::from(limit.1 - limit.0);
+        let spans = self.spans();
+        let idx = spans.iter().position(|span| portion <= span.1)?;
+        let (segment, span) = (&self.segments[idx], spans[idx]);
+        if portion < span.0 {
+            // The pixel is in a gap, which doesn't have any value
+            return None;
+        }
+        let ratio = (portion - span.0) / (span.1 - span.0);
+        Some(segment.start + (segment.end - segment.start) * ratio)
+    }
+}
+
+impl AsRangedCoord for BrokenRange {
+    type CoordDescType = Self;
+    type Value = f64;
+
+    fn check_range(&self) -> Result<(), String> {
+        if self.segments.is_empty() {
+            return Err("a broken axis needs at least one sub-range".to_string());
+        }
+        if self.weights.len() != self.segments.len() {
+            return Err(format!(
+                "a broken axis with {} sub-ranges needs as many weights, but got {}",
+                self.segments.len(),
+                self.weights.len()
+            ));
+        }
+        if self.weights.iter().any(|w| !(*w > 0.0 && w.is_finite())) {
+            return Err("the weights of a broken axis must be positive".to_string());
+        }
+        if !(self.gap >= 0.0 && self.gap * (self.segments.len() - 1) as f64 <= 0.5) {
+            return Err(format!(
+                "the gaps of a broken axis must take at most half of the axis, but got {}",
+                self.gap
+            ));
+        }
+        for (idx, segment) in self.segments.iter().enumerate() {
+            if !(segment.start.is_finite() && segment.end.is_finite())
+                || segment.start >= segment.end
+            {
+                return Err(format!(
+                    "the sub-ranges of a broken axis must be finite and non-empty, but got {}..{}",
+                    segment.start, segment.end
+                ));
+            }
+            if idx > 0 && self.segments[idx - 1].end >= segment.start {
+                return Err(format!(
+                    "the sub-ranges of a broken axis must be in ascending order without overlap, but {}..{} is after {}..{}",
+                    segment.start,
+                    segment.end,
+                    self.segments[idx - 1].start,
+                    self.segments[idx - 1].end
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_broken_range_map() {
+        let coord = BrokenRange::new(vec![0.0..10.0, 990.0..1000.0]).gap(0.0);
+        assert_eq!(coord.map(&0.0, (0, 200)), 0);
+        assert_eq!(coord.map(&5.0, (0, 200)), 50);
+        assert_eq!(coord.map(&995.0, (0, 200)), 150);
+        assert_eq!(coord.map(&1000.0, (0, 200)), 200);
+        assert_eq!(coord.axis_breaks((0, 200)), [100]);
+
+        let coord = BrokenRange::new(vec![0.0..10.0, 990.0..1000.0]).weights(vec![3.0, 1.0]);
+        assert_eq!(coord.map(&10.0, (0, 1000)), 735);
+        assert_eq!(coord.map(&990.0, (0, 1000)), 755);
+        assert_eq!(coord.axis_breaks((0, 1000)), [745]);
+        // The values in the gap go to the nearest edge, unless they are clipped
+        assert_eq!(coord.map(&20.0, (0, 1000)), 735);
+        assert_eq!(coord.map(&900.0, (0, 1000)), 755);
+        let coord = coord.clip_gaps(true);
+        assert_eq!(coord.map(&20.0, (0, 1000)), -1000);
+        assert_eq!(coord.map(&20.0, (1000, 0)), 2000);
+        assert!(!coord.contains(20.0) && coord.contains(995.0));
+
+        assert_eq!(coord.unmap(0, (0, 1000)), Some(0.0));
+        assert_eq!(coord.unmap(745, (0, 1000)), None);
+        assert!((coord.unmap(1000, (0, 1000)).unwrap() - 1000.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_broken_range_key_points() {
+        let coord = BrokenRange::new(vec![0.0..10.0, 990.0..1000.0]);
+        let kps = coord.key_points(10);
+        assert!(!kps.is_empty() && kps.len() <= 10);
+        assert!(kps.iter().all(|v| coord.contains(*v)));
+        assert!(kps.iter().any(|v| *v < 10.0) && kps.iter().any(|v| *v > 990.0));
+    }
+
+    #[test]
+    fn test_broken_range_check() {
+        let check = |coord: BrokenRange| coord.check_range().is_ok();
+        assert!(check(BrokenRange::new(vec![0.0..10.0, 990.0..1000.0])));
+        assert!(!check(BrokenRange::new(vec![])));
+        assert!(!check(BrokenRange::new(vec![0.0..10.0, 5.0..20.0])));
+        assert!(!check(BrokenRange::new(vec![10.0..0.0])));
+        assert!(!check(
+            BrokenRange::new(vec![0.0..10.0, 990.0..1000.0]).weights(vec![1.0])
+        ));
+    }
+
+    #[test]
+    fn test_broken_range_mesh() {
+        let glyphs = Rc::new(RefCell::new(vec![]));
+        {
+            let glyphs = glyphs.clone();
+            let drawing_area = create_mocked_drawing_area(1000, 1000, move |m| {
+                m.check_draw_path(move |_, _, path| {
+                    if path.len() == 5 {
+                        glyphs.borrow_mut().push(path);
+                    }
+                });
+            });
+
+            let mut chart = ChartBuilder::on(&drawing_area)
+                .x_label_area_size(20)
+                .build_ranged(
+                    BrokenRange::new(vec![0.0..10.0, 990.0..1000.0]).gap(0.0),
+                    0.0..1.0,
+                )
+                .expect("Build chart error");
+            chart.configure_mesh().draw().expect("Drawing Error");
+        }
+        // The zig-zag is drawn on the X axis at the break
+        assert_eq!(
+            *glyphs.borrow(),
+            [vec![
+                (492, 980),
+                (496, 988),
+                (500, 980),
+                (504, 988),
+                (508, 980)
+            ]]
+        );
+    }
+}
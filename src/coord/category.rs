@@ -0,0 +1,368 @@
+use super::{AsRangedCoord, DiscreteRanged, Ranged, ReversibleRanged};
+use std::fmt::Display;
+use std::ops::Range;
+
+/// The discrete axis over a list of labels, such as the months of a year, where each label
+/// takes a slot of the same size in the order of the list. The value on the axis is the index
+/// of the label, and the labels are printed by the mesh.
+#[derive(Clone, Copy)]
+pub struct Categories<'a, T> {
+    labels: &'a [T],
+}
+
+impl<'a, T> Categories<'a, T> {
+    /// Create a new categorical axis
+    ///
+    /// - `labels`: The labels of the axis, in the order they are drawn
+    /// - **returns**: The created axis
+    pub fn new(labels: &'a [T]) -> Self {
+        Self { labels }
+    }
+
+    /// Get the labels of the axis
+    pub fn labels(&self) -> &'a [T] {
+        self.labels
+    }
+
+    /// Get the value on the axis of a label, which is its index in the list
+    pub fn index_of<K>(&self, label: &K) -> Option<usize>
+    where
+        T: PartialEq<K>,
+    {
+        self.labels.iter().position(|l| l == label)
+    }
+
+    /// Turn the data keyed by the labels into the data keyed by the values on the axis, so it
+    /// can be drawn as a histogram. The data with an unknown label is left out.
+    pub fn keyed<K, A, I>(&self, iter: I) -> Vec<(usize, A)>
+    where
+        T: PartialEq<K>,
+        I: IntoIterator<Item = (K, A)>,
+    {
+        iter.into_iter()
+            .filter_map(|(label, value)| Some((self.index_of(&label)?, value)))
+            .collect()
+    }
+}
+
+impl<'a, T> From<&'a [T]> for Categories<'a, T> {
+    fn from(labels: &'a [T]) -> Self {
+        Self::new(labels)
+    }
+}
+
+impl<'a, T> From<&'a Vec<T>> for Categories<'a, T> {
+    fn from(labels: &'a Vec<T>) -> Self {
+        Self::new(labels)
+    }
+}
+
+impl<'a, T: Display> Ranged for Categories<'a, T> {
+    type ValueType = usize;
+
+    fn map(&self, value: &usize, limit: (i32, i32)) -> i32 {
+        let actual_length = limit.1 - limit.0;
+        if self.labels.is_empty() {
+            return limit.1;
+        }
+        let portion = *value as f64 / self.labels.len() as f64;
+        limit.0 + (f64::from(actual_length) * portion + 1e-3).floor() as i32
+    }
+
+    fn key_points(&self, max_points: usize) -> Vec<usize> {
+        if max_points == 0 {
+            return vec![];
+        }
+        let step = self.labels.len().div_ceil(max_points).max(1);
+        (0..self.labels.len()).step_by(step).collect()
+    }
+
+    fn range(&self) -> Range<usize> {
+        0..self.labels.len()
+    }
+
+    fn format_label(&self, value: &usize) -> Option<String> {
+        self.labels.get(*value).map(|label| label.to_string())
+    }
+}
+
+impl<'a, T: Display> ReversibleRanged for Categories<'a, T> {
+    fn unmap(&self, input: i32, limit: (i32, i32)) -> Option<usize> {
+        if input < limit.0.min(limit.1) || input > limit.0.max(limit.1) || limit.0 == limit.1 {
+            return None;
+        }
+        let portion = f64::from(input - limit.0) / f64::from(limit.1 - limit.0);
+        let index = (portion * self.labels.len() as f64) as usize;
+        Some(index.min(self.labels.len().saturating_sub(1)))
+    }
+}
+
+impl<'a, T: Display> DiscreteRanged for Categories<'a, T> {
+    fn next_value(this: &usize) -> usize {
+        this + 1
+    }
+
+    fn previous_value(this: &usize) -> usize {
+        this.saturating_sub(1)
+    }
+}
+
+impl<'a, T: Display> AsRangedCoord for Categories<'a, T> {
+    type CoordDescType = Self;
+    type Value = usize;
+}
+
+/// The discrete axis over groups of labels, such as the quarters of a year containing the
+/// months, where each inner label takes a slot of the same size. The value on the axis is the
+/// index of the group and the index of the label in the group. The mesh prints the inner
+/// labels and a second row of group labels, with a separator between two groups.
+///
+/// The next value of `(group, label)` is `(group, label + 1)`, which is mapped to the start of
+/// the next group when it's past the last label of the group.
+pub struct NestedCategories<O, I> {
+    groups: Vec<(O, Vec<I>)>,
+}
+
+impl<O, I> NestedCategories<O, I> {
+    /// Create a new nested categorical axis
+    ///
+    /// - `groups`: The groups of the axis with their labels, in the order they are drawn
+    /// - **returns**: The created axis
+    pub fn new<G, L>(groups: G) -> Self
+    where
+        G: IntoIterator<Item = (O, L)>,
+        L: IntoIterator<Item = I>,
+    {
+        Self {
+            groups: groups
+                .into_iter()
+                .map(|(group, labels)| (group, labels.into_iter().collect()))
+                .collect(),
+        }
+    }
+
+    /// Get the value on the axis of a group and a label in it
+    pub fn index_of<KO, KI>(&self, group: &KO, label: &KI) -> Option<(usize, usize)>
+    where
+        O: PartialEq<KO>,
+        I: PartialEq<KI>,
+    {
+        let outer = self.groups.iter().position(|(g, _)| g == group)?;
+        let inner = self.groups[outer].1.iter().position(|l| l == label)?;
+        Some((outer, inner))
+    }
+
+    /// Turn the data keyed by the groups and the labels into the data keyed by the values on
+    /// the axis, so it can be drawn as a histogram. The data with an unknown key is left out.
+    pub fn keyed<KO, KI, A, It>(&self, iter: It) -> Vec<((usize, usize), A)>
+    where
+        O: PartialEq<KO>,
+        I: PartialEq<KI>,
+        It: IntoIterator<Item = ((KO, KI), A)>,
+    {
+        iter.into_iter()
+            .filter_map(|((group, label), value)| Some((self.index_of(&group, &label)?, value)))
+            .collect()
+    }
+
+    fn total(&self) -> usize {
+        self.groups.iter().map(|(_, labels)| labels.len()).sum()
+    }
+
+    /// Get the index of the slot of the value among all the labels
+    fn slot(&self, &(outer, inner): &(usize, usize)) -> usize {
+        let before: usize = self.groups[..outer.min(self.groups.len())]
+            .iter()
+            .map(|(_, labels)| labels.len())
+            .sum();
+        let len = self.groups.get(outer).map_or(0, |(_, labels)| labels.len());
+        before + inner.min(len)
+    }
+}
+
+impl<O: Display, I: Display> Ranged for NestedCategories<O, I> {
+    type ValueType = (usize, usize);
+
+    fn map(&self, value: &(usize, usize), limit: (i32, i32)) -> i32 {
+        let total = self.total();
+        if total == 0 {
+            return limit.1;
+        }
+        let portion = self.slot(value) as f64 / total as f64;
+        limit.0 + (f64::from(limit.1 - limit.0) * portion + 1e-3).floor() as i32
+    }
+
+    fn key_points(&self, max_points: usize) -> Vec<(usize, usize)> {
+        if max_points == 0 {
+            return vec![];
+        }
+        let step = self.total().div_ceil(max_points).max(1);
+        self.groups
+            .iter()
+            .enumerate()
+            .flat_map(|(outer, (_, labels))| (0..labels.len()).map(move |inner| (outer, inner)))
+            .step_by(step)
+            .collect()
+    }
+
+    fn range(&self) -> Range<(usize, usize)> {
+        let last = self.groups.len().saturating_sub(1);
+        let len = self.groups.last().map_or(0, |(_, labels)| labels.len());
+        (0, 0)..(last, len)
+    }
+
+    fn format_label(&self, &(outer, inner): &(usize, usize)) -> Option<String> {
+        let (_, labels) = self.groups.get(outer)?;
+        labels.get(inner).map(|label| label.to_string())
+    }
+
+    fn axis_groups(&self, limit: (i32, i32)) -> Vec<(Range<i32>, String)> {
+        self.groups
+            .iter()
+            .enumerate()
+            .map(|(outer, (group, labels))| {
+                let range = self.map(&(outer, 0), limit)..self.map(&(outer, labels.len()), limit);
+                (range, group.to_string())
+            })
+            .collect()
+    }
+}
+
+impl<O: Display, I: Display> ReversibleRanged for NestedCategories<O, I> {
+    fn unmap(&self, input: i32, limit: (i32, i32)) -> Option<(usize, usize)> {
+        if input < limit.0.min(limit.1) || input > limit.0.max(limit.1) || limit.0 == limit.1 {
+            return None;
+        }
+        let portion = f64::from(input - limit.0) / f64::from(limit.1 - limit.0);
+        let total = self.total();
+        let mut slot = ((portion * total as f64) as usize).min(total.checked_sub(1)?);
+        for (outer, (_, labels)) in self.groups.iter().enumerate() {
+            if slot < labels.len() {
+                return Some((outer, slot));
+            }
+            slot -= labels.len();
+        }
+        None
+    }
+}
+
+impl<O: Display, I: Display> DiscreteRanged for NestedCategories<O, I> {
+    fn next_value(&(outer, inner): &(usize, usize)) -> (usize, usize) {
+        (outer, inner + 1)
+    }
+
+    fn previous_value(&(outer, inner): &(usize, usize)) -> (usize, usize) {
+        (outer, inner.saturating_sub(1))
+    }
+}
+
+impl<O: Display, I: Display> AsRangedCoord for NestedCategories<O, I> {
+    type CoordDescType = Self;
+    type Value = (usize, usize);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    const MONTHS: [&str; 4] = ["Jan", "Feb", "Mar", "Apr"];
+
+    #[test]
+    fn test_categories() {
+        let coord = Categories::new(&MONTHS);
+        assert_eq!(coord.map(&1, (0, 200)), 50);
+        assert_eq!(coord.key_points(10), [0, 1, 2, 3]);
+        assert_eq!(coord.key_points(2), [0, 2]);
+        assert_eq!(coord.format_label(&2).unwrap(), "Mar");
+        assert_eq!(coord.index_of(&"Apr"), Some(3));
+        assert_eq!(coord.unmap(199, (0, 200)), Some(3));
+        assert_eq!(
+            coord.keyed(vec![("Feb", 1), ("May", 2), ("Jan", 3)]),
+            [(1, 1), (0, 3)]
+        );
+    }
+
+    #[test]
+    fn test_categories_histogram() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            // The bars follow the order of the labels, whatever the order of the data
+            m.check_draw_rect(|_, _, _, u, d| assert_eq!([u, d], [(0, 100), (50, 200)]));
+            m.check_draw_rect(|_, _, _, u, d| assert_eq!([u, d], [(100, 0), (150, 200)]));
+            m.drop_check(|b| assert_eq!(b.num_draw_rect_call, 2));
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(Categories::new(&MONTHS), 0..10)
+            .expect("Build chart error");
+
+        let data = Categories::new(&MONTHS).keyed(vec![("Mar", 10), ("Jan", 5)]);
+        chart
+            .draw_series(
+                Histogram::vertical(&chart)
+                    .margin(0)
+                    .ordering(BarOrdering::Ascending)
+                    .data(data),
+            )
+            .expect("Drawing Error");
+    }
+
+    fn quarters() -> NestedCategories<&'static str, &'static str> {
+        NestedCategories::new(vec![("Q1", vec!["Jan", "Feb"]), ("Q2", vec!["Apr", "May"])])
+    }
+
+    #[test]
+    fn test_nested_categories() {
+        let coord = quarters();
+        assert_eq!(coord.map(&(1, 0), (0, 200)), 100);
+        assert_eq!(coord.map(&(0, 2), (0, 200)), 100);
+        assert_eq!(coord.key_points(10), [(0, 0), (0, 1), (1, 0), (1, 1)]);
+        assert_eq!(coord.format_label(&(1, 1)).unwrap(), "May");
+        assert_eq!(coord.unmap(160, (0, 200)), Some((1, 1)));
+        assert_eq!(
+            coord.axis_groups((0, 200)),
+            [(0..100, "Q1".to_string()), (100..200, "Q2".to_string())]
+        );
+        assert_eq!(
+            coord.keyed(vec![(("Q2", "Apr"), 1), (("Q2", "Jan"), 2)]),
+            [((1, 0), 1)]
+        );
+    }
+
+    #[test]
+    fn test_nested_categories_mesh() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let texts = Rc::new(RefCell::new(vec![]));
+        let paths = Rc::new(RefCell::new(vec![]));
+        {
+            let (texts, paths) = (texts.clone(), paths.clone());
+            let drawing_area = create_mocked_drawing_area(200, 200, move |m| {
+                m.check_draw_text(move |_, _, _, pos, text| {
+                    texts.borrow_mut().push((text.to_string(), pos))
+                });
+                m.check_draw_path(move |_, _, path| paths.borrow_mut().push(path));
+            });
+
+            let mut chart = ChartBuilder::on(&drawing_area)
+                .x_label_area_size(40)
+                .build_ranged(quarters(), 0..10)
+                .expect("Build chart error");
+            chart
+                .configure_mesh()
+                .disable_mesh()
+                .draw()
+                .expect("Drawing Error");
+        }
+
+        let texts = texts.borrow();
+        let find = |label: &str| texts.iter().find(|(t, _)| t == label).unwrap().1;
+        let (feb, q1, q2) = (find("Feb"), find("Q1"), find("Q2"));
+        // The group labels are centered under their groups, below the labels of the months
+        assert!(q1.1 > feb.1 && q1.1 == q2.1);
+        assert!(q1.0 < 50 && q1.0 > 25 && q2.0 < 150 && q2.0 > 125);
+        // The groups are separated across the label area
+        assert!(paths.borrow().contains(&vec![(100, 160), (100, 200)]));
+    }
+}
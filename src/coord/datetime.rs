@@ -1,22 +1,34 @@
 /// The datetime coordinates
-use chrono::{Date, DateTime, Datelike, Duration, NaiveTime, TimeZone, Timelike};
-use std::ops::Range;
+use chrono::{
+    Date, DateTime, Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Timelike,
+    Utc,
+};
+use std::ops::{Range, RangeInclusive};
 
-use super::{AsRangedCoord, DiscreteRanged, Ranged};
+use super::{AsRangedCoord, DiscreteRanged, Ranged, ReversibleRanged};
 
 /// The trait that describe some time value
 pub trait TimeValue: Eq {
-    type Tz: TimeZone;
+    /// The type of the dates of the time value
+    type DateType: Datelike;
     /// Returns the date that is no later than the time
-    fn date_floor(&self) -> Date<Self::Tz>;
+    fn date_floor(&self) -> Self::DateType;
     /// Returns the date that is no earlier than the time
-    fn date_ceil(&self) -> Date<Self::Tz>;
+    fn date_ceil(&self) -> Self::DateType;
     /// Returns the maximum value that is earlier than the given date
-    fn earliest_after_date(date: Date<Self::Tz>) -> Self;
+    fn earliest_after_date(date: Self::DateType) -> Self;
     /// Returns the duration between two time value
     fn subtract(&self, other: &Self) -> Duration;
-    /// Get the timezone information for current value
-    fn timezone(&self) -> Self::Tz;
+    /// Returns the date in the same timezone as current value, or `None` if the date doesn't exist
+    fn ymd(&self, year: i32, month: u32, day: u32) -> Option<Self::DateType> {
+        self.date_floor()
+            .with_day(1)?
+            .with_year(year)?
+            .with_month(month)?
+            .with_day(day)
+    }
+    /// Returns the time value that is the duration after current value
+    fn add(&self, duration: &Duration) -> Self;
 
     /// Map the coord
     fn map_coord(value: &Self, begin: &Self, end: &Self, limit: (i32, i32)) -> i32 {
@@ -38,10 +50,36 @@ pub trait TimeValue: Eq {
 
         (f64::from(limit.1 - limit.0) * value_days / total_days) as i32 + limit.0
     }
+
+    /// Map the pixel back to the time value, which is `None` if the pixel is out of the limit
+    fn unmap_coord(point: i32, begin: &Self, end: &Self, limit: (i32, i32)) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        let ratio = pixel_ratio(point, limit)?;
+        Some(begin.add(&interpolate_span(end.subtract(begin), ratio)))
+    }
+}
+
+/// Get the portion of the limit the pixel is at, or `None` if it's out of the limit
+fn pixel_ratio(point: i32, limit: (i32, i32)) -> Option<f64> {
+    if point < limit.0.min(limit.1) || point > limit.0.max(limit.1) || limit.0 == limit.1 {
+        return None;
+    }
+    Some(f64::from(point - limit.0) / f64::from(limit.1 - limit.0))
+}
+
+/// Get the given portion of the time span, in nanoseconds if it's possible. The spans that
+/// overflow the nanoseconds are computed in days.
+fn interpolate_span(span: Duration, ratio: f64) -> Duration {
+    if let Some(total_ns) = span.num_nanoseconds() {
+        return Duration::nanoseconds((total_ns as f64 * ratio).round() as i64);
+    }
+    Duration::days((span.num_days() as f64 * ratio + 1e-6).floor() as i64)
 }
 
 impl<Z: TimeZone> TimeValue for Date<Z> {
-    type Tz = Z;
+    type DateType = Date<Z>;
     fn date_floor(&self) -> Date<Z> {
         self.clone()
     }
@@ -54,13 +92,13 @@ impl<Z: TimeZone> TimeValue for Date<Z> {
     fn subtract(&self, other: &Date<Z>) -> Duration {
         self.clone() - other.clone()
     }
-    fn timezone(&self) -> Self::Tz {
-        self.timezone()
+    fn add(&self, duration: &Duration) -> Self {
+        self.clone() + *duration
     }
 }
 
 impl<Z: TimeZone> TimeValue for DateTime<Z> {
-    type Tz = Z;
+    type DateType = Date<Z>;
     fn date_floor(&self) -> Date<Z> {
         self.date()
     }
@@ -78,9 +116,90 @@ impl<Z: TimeZone> TimeValue for DateTime<Z> {
     fn subtract(&self, other: &DateTime<Z>) -> Duration {
         self.clone() - other.clone()
     }
-    fn timezone(&self) -> Self::Tz {
-        self.timezone()
+    fn add(&self, duration: &Duration) -> DateTime<Z> {
+        self.clone() + *duration
+    }
+}
+
+impl TimeValue for NaiveDate {
+    type DateType = NaiveDate;
+    fn date_floor(&self) -> NaiveDate {
+        *self
+    }
+    fn date_ceil(&self) -> NaiveDate {
+        *self
+    }
+    fn earliest_after_date(date: NaiveDate) -> NaiveDate {
+        date
+    }
+    fn subtract(&self, other: &NaiveDate) -> Duration {
+        *self - *other
+    }
+    fn add(&self, duration: &Duration) -> NaiveDate {
+        *self + *duration
+    }
+}
+
+impl TimeValue for NaiveDateTime {
+    type DateType = NaiveDate;
+    fn date_floor(&self) -> NaiveDate {
+        self.date()
+    }
+    fn date_ceil(&self) -> NaiveDate {
+        if self.time().num_seconds_from_midnight() > 0 {
+            self.date() + Duration::days(1)
+        } else {
+            self.date()
+        }
+    }
+    fn earliest_after_date(date: NaiveDate) -> NaiveDateTime {
+        date.and_time(NaiveTime::MIN)
     }
+    fn subtract(&self, other: &NaiveDateTime) -> Duration {
+        *self - *other
+    }
+    fn add(&self, duration: &Duration) -> NaiveDateTime {
+        *self + *duration
+    }
+}
+
+/// Pick the format of the date and time labels, which shows finer units for shorter spans
+fn datetime_label_format(span: Duration) -> &'static str {
+    if span >= Duration::days(30) {
+        "%Y-%m-%d"
+    } else if span >= Duration::days(1) {
+        "%Y-%m-%d %H:%M"
+    } else if span >= Duration::seconds(1) {
+        "%H:%M:%S"
+    } else {
+        "%H:%M:%S%.f"
+    }
+}
+
+/// Pick the key points of a range of dates: every day or every week when they fit, otherwise the
+/// first days of the months or the years
+fn date_key_points<T: TimeValue + Clone>(begin: &T, end: &T, max_points: usize) -> Vec<T> {
+    let mut ret = vec![];
+
+    let total_days = end.subtract(begin).num_days();
+    let total_weeks = end.subtract(begin).num_weeks();
+
+    if total_days > 0 && total_days as usize <= max_points {
+        for day_idx in 0..=total_days {
+            ret.push(begin.add(&Duration::days(day_idx)));
+        }
+        return ret;
+    }
+
+    if total_weeks > 0 && total_weeks as usize <= max_points {
+        for day_idx in 0..=total_weeks {
+            ret.push(begin.add(&Duration::weeks(day_idx)));
+        }
+        return ret;
+    }
+
+    // For the longer spans, the key points are the first days of the months or the years
+    Monthly(begin.clone()..end.clone()).key_points(max_points)
 }
 
 /// The ranged coordinate for date
@@ -92,6 +211,14 @@ impl<Z: TimeZone> From<Range<Date<Z>>> for RangedDate<Z> {
     }
 }
 
+/// The last day of an inclusive range gets a slot of its own
+impl<Z: TimeZone> From<RangeInclusive<Date<Z>>> for RangedDate<Z> {
+    fn from(range: RangeInclusive<Date<Z>>) -> Self {
+        let (start, end) = range.into_inner();
+        Self(start, end + Duration::days(1))
+    }
+}
+
 impl<Z: TimeZone> Ranged for RangedDate<Z> {
     type ValueType = Date<Z>;
 
@@ -104,32 +231,17 @@ impl<Z: TimeZone> Ranged for RangedDate<Z> {
     }
 
     fn key_points(&self, max_points: usize) -> Vec<Self::ValueType> {
-        let mut ret = vec![];
-
-        let total_days = (self.1.clone() - self.0.clone()).num_days();
-        let total_weeks = (self.1.clone() - self.0.clone()).num_weeks();
-
-        if total_days > 0 && total_days as usize <= max_points {
-            for day_idx in 0..=total_days {
-                ret.push(self.0.clone() + Duration::days(day_idx));
-            }
-            return ret;
-        }
-
-        if total_weeks > 0 && total_weeks as usize <= max_points {
-            for day_idx in 0..=total_weeks {
-                ret.push(self.0.clone() + Duration::weeks(day_idx));
-            }
-            return ret;
-        }
-
-        let week_per_point = ((total_weeks as f64) / (max_points as f64)).ceil() as usize;
+        date_key_points(&self.0, &self.1, max_points)
+    }
 
-        for idx in 0..=(total_weeks as usize / week_per_point) {
-            ret.push(self.0.clone() + Duration::weeks((idx * week_per_point) as i64));
-        }
+    fn format_label(&self, value: &Self::ValueType) -> Option<String> {
+        Some(value.naive_local().format("%Y-%m-%d").to_string())
+    }
+}
 
-        ret
+impl<Z: TimeZone> ReversibleRanged for RangedDate<Z> {
+    fn unmap(&self, input: i32, limit: (i32, i32)) -> Option<Self::ValueType> {
+        TimeValue::unmap_coord(input, &self.0, &self.1, limit)
     }
 }
 
@@ -148,6 +260,11 @@ impl<Z: TimeZone> AsRangedCoord for Range<Date<Z>> {
     type Value = Date<Z>;
 }
 
+impl<Z: TimeZone> AsRangedCoord for RangeInclusive<Date<Z>> {
+    type CoordDescType = RangedDate<Z>;
+    type Value = Date<Z>;
+}
+
 /// Indicates the coord has a monthly resolution
 ///
 /// Note: since month doesn't have a constant duration.
@@ -198,15 +315,13 @@ impl<T: TimeValue + Clone> Ranged for Monthly<T> {
             end_year: i32,
             end_month: i32,
             step: u32,
-            tz: T::Tz,
+            value: &T,
         ) -> Vec<T> {
             let mut ret = vec![];
             while end_year > start_year || (end_year == start_year && end_month >= start_month) {
-                ret.push(T::earliest_after_date(tz.ymd(
-                    start_year,
-                    start_month as u32,
-                    1,
-                )));
+                if let Some(date) = value.ymd(start_year, start_month as u32, 1) {
+                    ret.push(T::earliest_after_date(date));
+                }
                 start_month += step as i32;
 
                 if start_month >= 13 {
@@ -226,7 +341,7 @@ impl<T: TimeValue + Clone> Ranged for Monthly<T> {
                 end_year,
                 end_month as i32,
                 1,
-                self.0.start.timezone(),
+                &self.0.start,
             );
         } else if total_month as usize <= max_points * 3 {
             // Quarterly
@@ -236,7 +351,7 @@ impl<T: TimeValue + Clone> Ranged for Monthly<T> {
                 end_year,
                 end_month as i32,
                 3,
-                self.0.start.timezone(),
+                &self.0.start,
             );
         } else if total_month as usize <= max_points * 6 {
             // Biyearly
@@ -246,7 +361,7 @@ impl<T: TimeValue + Clone> Ranged for Monthly<T> {
                 end_year,
                 end_month as i32,
                 6,
-                self.0.start.timezone(),
+                &self.0.start,
             );
         }
 
@@ -257,11 +372,17 @@ impl<T: TimeValue + Clone> Ranged for Monthly<T> {
             start_month,
             end_year,
             end_month,
-            self.0.start.timezone(),
+            &self.0.start,
         )
     }
 }
 
+impl<T: TimeValue + Clone> ReversibleRanged for Monthly<T> {
+    fn unmap(&self, input: i32, limit: (i32, i32)) -> Option<T> {
+        T::unmap_coord(input, &self.0.start, &self.0.end, limit)
+    }
+}
+
 impl<T: TimeValue + Clone> DiscreteRanged for Monthly<T> {
     fn next_value(this: &T) -> T {
         let mut year = this.date_ceil().year();
@@ -271,7 +392,7 @@ impl<T: TimeValue + Clone> DiscreteRanged for Monthly<T> {
             month = 1;
             year += 1;
         }
-        T::earliest_after_date(this.timezone().ymd(year, month, this.date_ceil().day()))
+        T::earliest_after_date(same_day_of_month(this, year, month, this.date_ceil().day()))
     }
 
     fn previous_value(this: &T) -> T {
@@ -282,10 +403,23 @@ impl<T: TimeValue + Clone> DiscreteRanged for Monthly<T> {
             month = 12;
             year -= 1;
         }
-        T::earliest_after_date(this.timezone().ymd(year, month, this.date_floor().day()))
+        T::earliest_after_date(same_day_of_month(
+            this,
+            year,
+            month,
+            this.date_floor().day(),
+        ))
     }
 }
 
+/// Get the given day of the month, or the last day of the month if the month is shorter
+fn same_day_of_month<T: TimeValue>(value: &T, year: i32, month: u32, day: u32) -> T::DateType {
+    (1..=day)
+        .rev()
+        .find_map(|day| value.ymd(year, month, day))
+        .expect("The date is out of the supported range")
+}
+
 /// Indicate the coord has a yearly granularity.
 pub struct Yearly<T: TimeValue>(Range<T>);
 
@@ -300,7 +434,7 @@ fn generate_yearly_keypoints<T: TimeValue>(
     start_month: u32,
     mut end_year: i32,
     end_month: u32,
-    tz: T::Tz,
+    value: &T,
 ) -> Vec<T> {
     if start_month > end_month {
         end_year -= 1;
@@ -324,7 +458,9 @@ fn generate_yearly_keypoints<T: TimeValue>(
     let mut ret = vec![];
 
     while start_year <= end_year {
-        ret.push(T::earliest_after_date(tz.ymd(start_year, start_month, 1)));
+        if let Some(date) = value.ymd(start_year, start_month, 1) {
+            ret.push(T::earliest_after_date(date));
+        }
         start_year += freq as i32;
     }
 
@@ -367,18 +503,24 @@ impl<T: TimeValue + Clone> Ranged for Yearly<T> {
             start_month,
             end_year,
             end_month,
-            self.0.start.timezone(),
+            &self.0.start,
         )
     }
 }
 
+impl<T: TimeValue + Clone> ReversibleRanged for Yearly<T> {
+    fn unmap(&self, input: i32, limit: (i32, i32)) -> Option<T> {
+        T::unmap_coord(input, &self.0.start, &self.0.end, limit)
+    }
+}
+
 impl<T: TimeValue + Clone> DiscreteRanged for Yearly<T> {
     fn next_value(this: &T) -> T {
-        T::earliest_after_date(this.timezone().ymd(this.date_floor().year() + 1, 1, 1))
+        T::earliest_after_date(same_day_of_month(this, this.date_floor().year() + 1, 1, 1))
     }
 
     fn previous_value(this: &T) -> T {
-        T::earliest_after_date(this.timezone().ymd(this.date_ceil().year() - 1, 1, 1))
+        T::earliest_after_date(same_day_of_month(this, this.date_ceil().year() - 1, 1, 1))
     }
 }
 
@@ -418,6 +560,18 @@ impl<Z: TimeZone> From<Range<DateTime<Z>>> for RangedDateTime<Z> {
     }
 }
 
+impl<Z: TimeZone> AsRangedCoord for RangeInclusive<DateTime<Z>> {
+    type CoordDescType = RangedDateTime<Z>;
+    type Value = DateTime<Z>;
+}
+
+impl<Z: TimeZone> From<RangeInclusive<DateTime<Z>>> for RangedDateTime<Z> {
+    fn from(range: RangeInclusive<DateTime<Z>>) -> Self {
+        let (start, end) = range.into_inner();
+        Self(start, end)
+    }
+}
+
 impl<Z: TimeZone> Ranged for RangedDateTime<Z> {
     type ValueType = DateTime<Z>;
 
@@ -474,6 +628,141 @@ impl<Z: TimeZone> Ranged for RangedDateTime<Z> {
             .map(|x| x.and_hms(0, 0, 0))
             .collect()
     }
+
+    fn format_label(&self, value: &DateTime<Z>) -> Option<String> {
+        let format = datetime_label_format(self.1.clone() - self.0.clone());
+        Some(value.naive_local().format(format).to_string())
+    }
+}
+
+impl<Z: TimeZone> ReversibleRanged for RangedDateTime<Z> {
+    fn unmap(&self, input: i32, limit: (i32, i32)) -> Option<DateTime<Z>> {
+        TimeValue::unmap_coord(input, &self.0, &self.1, limit)
+    }
+}
+
+/// The ranged coordinate for the dates without a timezone
+pub struct RangedNaiveDate(NaiveDate, NaiveDate);
+
+impl AsRangedCoord for Range<NaiveDate> {
+    type CoordDescType = RangedNaiveDate;
+    type Value = NaiveDate;
+}
+
+impl From<Range<NaiveDate>> for RangedNaiveDate {
+    fn from(range: Range<NaiveDate>) -> Self {
+        Self(range.start, range.end)
+    }
+}
+
+impl AsRangedCoord for RangeInclusive<NaiveDate> {
+    type CoordDescType = RangedNaiveDate;
+    type Value = NaiveDate;
+}
+
+/// The last day of an inclusive range gets a slot of its own
+impl From<RangeInclusive<NaiveDate>> for RangedNaiveDate {
+    fn from(range: RangeInclusive<NaiveDate>) -> Self {
+        let (start, end) = range.into_inner();
+        (start..end + Duration::days(1)).into()
+    }
+}
+
+impl Ranged for RangedNaiveDate {
+    type ValueType = NaiveDate;
+
+    fn range(&self) -> Range<NaiveDate> {
+        self.0..self.1
+    }
+
+    fn map(&self, value: &NaiveDate, limit: (i32, i32)) -> i32 {
+        TimeValue::map_coord(value, &self.0, &self.1, limit)
+    }
+
+    fn key_points(&self, max_points: usize) -> Vec<NaiveDate> {
+        date_key_points(&self.0, &self.1, max_points)
+    }
+
+    fn format_label(&self, value: &NaiveDate) -> Option<String> {
+        Some(value.format("%Y-%m-%d").to_string())
+    }
+}
+
+impl ReversibleRanged for RangedNaiveDate {
+    fn unmap(&self, input: i32, limit: (i32, i32)) -> Option<NaiveDate> {
+        TimeValue::unmap_coord(input, &self.0, &self.1, limit)
+    }
+}
+
+impl DiscreteRanged for RangedNaiveDate {
+    fn next_value(this: &NaiveDate) -> NaiveDate {
+        *this + Duration::days(1)
+    }
+
+    fn previous_value(this: &NaiveDate) -> NaiveDate {
+        *this - Duration::days(1)
+    }
+}
+
+/// The ranged coordinate for the date and time without a timezone
+pub struct RangedNaiveDateTime(RangedDateTime<Utc>);
+
+impl AsRangedCoord for Range<NaiveDateTime> {
+    type CoordDescType = RangedNaiveDateTime;
+    type Value = NaiveDateTime;
+}
+
+impl From<Range<NaiveDateTime>> for RangedNaiveDateTime {
+    fn from(range: Range<NaiveDateTime>) -> Self {
+        Self(RangedDateTime(
+            DateTime::from_naive_utc_and_offset(range.start, Utc),
+            DateTime::from_naive_utc_and_offset(range.end, Utc),
+        ))
+    }
+}
+
+impl AsRangedCoord for RangeInclusive<NaiveDateTime> {
+    type CoordDescType = RangedNaiveDateTime;
+    type Value = NaiveDateTime;
+}
+
+impl From<RangeInclusive<NaiveDateTime>> for RangedNaiveDateTime {
+    fn from(range: RangeInclusive<NaiveDateTime>) -> Self {
+        let (start, end) = range.into_inner();
+        (start..end).into()
+    }
+}
+
+impl Ranged for RangedNaiveDateTime {
+    type ValueType = NaiveDateTime;
+
+    fn range(&self) -> Range<NaiveDateTime> {
+        (self.0).0.naive_utc()..(self.0).1.naive_utc()
+    }
+
+    fn map(&self, value: &NaiveDateTime, limit: (i32, i32)) -> i32 {
+        self.0
+            .map(&DateTime::from_naive_utc_and_offset(*value, Utc), limit)
+    }
+
+    fn key_points(&self, max_points: usize) -> Vec<NaiveDateTime> {
+        self.0
+            .key_points(max_points)
+            .into_iter()
+            .map(|x| x.naive_utc())
+            .collect()
+    }
+
+    fn format_label(&self, value: &NaiveDateTime) -> Option<String> {
+        let format = datetime_label_format((self.0).1 - (self.0).0);
+        Some(value.format(format).to_string())
+    }
+}
+
+impl ReversibleRanged for RangedNaiveDateTime {
+    fn unmap(&self, input: i32, limit: (i32, i32)) -> Option<NaiveDateTime> {
+        self.0.unmap(input, limit).map(|x| x.naive_utc())
+    }
 }
 
 /// The coordinate that for duration of time
@@ -490,6 +779,18 @@ impl From<Range<Duration>> for RangedDuration {
     }
 }
 
+impl AsRangedCoord for RangeInclusive<Duration> {
+    type CoordDescType = RangedDuration;
+    type Value = Duration;
+}
+
+impl From<RangeInclusive<Duration>> for RangedDuration {
+    fn from(range: RangeInclusive<Duration>) -> Self {
+        let (start, end) = range.into_inner();
+        Self(start, end)
+    }
+}
+
 impl Ranged for RangedDuration {
     type ValueType = Duration;
 
@@ -583,6 +884,13 @@ impl Ranged for RangedDuration {
     }
 }
 
+impl ReversibleRanged for RangedDuration {
+    fn unmap(&self, input: i32, limit: (i32, i32)) -> Option<Duration> {
+        let ratio = pixel_ratio(input, limit)?;
+        Some(self.0 + interpolate_span(self.1 - self.0, ratio))
+    }
+}
+
 #[allow(clippy::inconsistent_digit_grouping)]
 fn compute_period_per_point(total_ns: u64, max_points: usize, sub_daily: bool) -> Option<u64> {
     let min_ns_per_point = total_ns as f64 / max_points as f64;
@@ -669,21 +977,15 @@ mod test {
 
         let kps = ranged_coord.key_points(23);
 
+        // The span is too long for weekly key points, so they fall on evenly spaced years
         assert!(kps.len() <= 23);
-        let max = kps
+        assert!(kps.iter().all(|x| x.month() == 1 && x.day() == 1));
+        let steps: Vec<_> = kps
             .iter()
             .zip(kps.iter().skip(1))
-            .map(|(p, n)| (*n - *p).num_days())
-            .max()
-            .unwrap();
-        let min = kps
-            .iter()
-            .zip(kps.iter().skip(1))
-            .map(|(p, n)| (*n - *p).num_days())
-            .min()
-            .unwrap();
-        assert_eq!(max, min);
-        assert_eq!(max % 7, 0);
+            .map(|(p, n)| n.year() - p.year())
+            .collect();
+        assert!(steps.iter().all(|step| *step == steps[0]));
     }
 
     #[test]
@@ -803,20 +1105,15 @@ mod test {
         let kps = coord.key_points(23);
 
         assert!(kps.len() <= 23);
-        let max = kps
+        assert!(kps
             .iter()
-            .zip(kps.iter().skip(1))
-            .map(|(p, n)| (*n - *p).num_seconds())
-            .max()
-            .unwrap();
-        let min = kps
+            .all(|x| x.month() == 1 && x.day() == 1 && x.num_seconds_from_midnight() == 0));
+        let steps: Vec<_> = kps
             .iter()
             .zip(kps.iter().skip(1))
-            .map(|(p, n)| (*n - *p).num_seconds())
-            .min()
-            .unwrap();
-        assert!(max == min);
-        assert!(max % (24 * 3600 * 7) == 0);
+            .map(|(p, n)| n.year() - p.year())
+            .collect();
+        assert!(steps.iter().all(|step| *step == steps[0]));
     }
 
     #[test]
@@ -940,4 +1237,147 @@ mod test {
         assert!(max == min);
         assert_eq!(max, 3600 * 2);
     }
+
+    #[test]
+    fn test_date_range_monthly_key_points() {
+        let coord: RangedDate<_> = (Utc.ymd(2019, 1, 15)..Utc.ymd(2019, 12, 20)).into();
+        let kps = coord.key_points(12);
+        assert!(kps.iter().all(|x| x.day() == 1));
+        assert_eq!(kps.len(), 11);
+    }
+
+    #[test]
+    fn test_naive_date_range() {
+        let start = NaiveDate::from_ymd_opt(2020, 1, 20).unwrap();
+        let coord: RangedNaiveDate = (start..NaiveDate::from_ymd_opt(2020, 3, 20).unwrap()).into();
+
+        // The days are evenly spaced, whatever the lengths of the months are
+        let steps: Vec<_> = (0..59)
+            .map(|d| {
+                let day = start + Duration::days(d);
+                coord.map(&RangedNaiveDate::next_value(&day), (0, 600)) - coord.map(&day, (0, 600))
+            })
+            .collect();
+        assert!(steps.iter().all(|step| *step == 10));
+
+        assert_eq!(coord.key_points(10).len(), 9);
+        assert_eq!(coord.format_label(&start).unwrap(), "2020-01-20");
+
+        let kps = (start..NaiveDate::from_ymd_opt(2021, 1, 1).unwrap())
+            .monthly()
+            .key_points(12);
+        assert!(kps.iter().all(|x| x.day() == 1));
+        assert_eq!(
+            Monthly::<NaiveDate>::next_value(&NaiveDate::from_ymd_opt(2020, 1, 31).unwrap()),
+            NaiveDate::from_ymd_opt(2020, 2, 29).unwrap()
+        );
+
+        // The last day of an inclusive range has a slot of its own
+        let end = NaiveDate::from_ymd_opt(2020, 1, 29).unwrap();
+        let coord: RangedNaiveDate = (start..=end).into();
+        assert_eq!(
+            coord.range(),
+            start..NaiveDate::from_ymd_opt(2020, 1, 30).unwrap()
+        );
+        assert_eq!(coord.map(&end, (0, 100)), 90);
+        let coord: RangedNaiveDateTime =
+            (start.and_hms_opt(0, 0, 0).unwrap()..=end.and_hms_opt(0, 0, 0).unwrap()).into();
+        assert_eq!(coord.range().end, end.and_hms_opt(0, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn test_naive_datetime_range() {
+        let start = NaiveDate::from_ymd_opt(2020, 1, 1)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap();
+        let coord: RangedNaiveDateTime = (start..start + Duration::hours(2)).into();
+        assert_eq!(coord.map(&(start + Duration::hours(1)), (0, 100)), 50);
+        assert!(coord
+            .key_points(5)
+            .iter()
+            .all(|x| x.minute() % 30 == 0 && x.second() == 0));
+        assert_eq!(
+            coord
+                .format_label(&(start + Duration::minutes(30)))
+                .unwrap(),
+            "00:30:00"
+        );
+
+        let coord: RangedNaiveDateTime = (start..start + Duration::days(2)).into();
+        assert_eq!(coord.format_label(&start).unwrap(), "2020-01-01 00:00");
+        let coord: RangedDateTime<_> =
+            (Utc.ymd(2019, 1, 1).and_hms(0, 0, 0)..Utc.ymd(2020, 1, 1).and_hms(0, 0, 0)).into();
+        assert_eq!(
+            coord
+                .format_label(&Utc.ymd(2019, 6, 1).and_hms(12, 0, 0))
+                .unwrap(),
+            "2019-06-01"
+        );
+    }
+
+    #[test]
+    fn test_datetime_unmap() {
+        let start = NaiveDate::from_ymd_opt(2020, 1, 1).unwrap();
+        let coord: RangedNaiveDate = (start..NaiveDate::from_ymd_opt(2020, 3, 1).unwrap()).into();
+        // Each day has a slot of 10 pixels, and the pixels in it go to the day
+        assert_eq!(coord.unmap(0, (0, 600)), Some(start));
+        assert_eq!(
+            coord.unmap(319, (0, 600)),
+            Some(NaiveDate::from_ymd_opt(2020, 2, 1).unwrap())
+        );
+        assert_eq!(
+            coord.unmap(320, (0, 600)),
+            Some(NaiveDate::from_ymd_opt(2020, 2, 2).unwrap())
+        );
+        assert_eq!(coord.unmap(601, (0, 600)), None);
+        for day in 0..60 {
+            let date = start + Duration::days(day);
+            assert_eq!(
+                coord.unmap(coord.map(&date, (0, 600)), (0, 600)),
+                Some(date)
+            );
+        }
+
+        let coord: RangedDateTime<_> =
+            (Utc.ymd(2020, 1, 1).and_hms(0, 0, 0)..Utc.ymd(2020, 1, 2).and_hms(0, 0, 0)).into();
+        assert_eq!(
+            coord.unmap(50, (0, 100)),
+            Some(Utc.ymd(2020, 1, 1).and_hms(12, 0, 0))
+        );
+        for pixel in 0..=100 {
+            let time = coord.unmap(pixel, (0, 100)).unwrap();
+            assert!((coord.map(&time, (0, 100)) - pixel).abs() <= 1);
+        }
+
+        let coord: RangedDuration = (Duration::seconds(10)..Duration::seconds(20)).into();
+        assert_eq!(coord.unmap(30, (0, 100)), Some(Duration::seconds(13)));
+        let coord = (start..NaiveDate::from_ymd_opt(2021, 1, 1).unwrap()).monthly();
+        assert_eq!(coord.unmap(0, (0, 366)), Some(start));
+    }
+
+    #[test]
+    fn test_date_mesh_labels() {
+        use crate::prelude::*;
+        let drawing_area = create_mocked_drawing_area(400, 200, |m| {
+            m.check_draw_text(|_, _, _, _, text| assert_eq!(text, "2020-01-01"));
+            m.check_draw_text(|_, _, _, _, text| assert!(text.starts_with("2020-01-0")));
+            m.drop_check(|b| assert!(b.num_draw_text_call >= 2));
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .x_label_area_size(20)
+            .build_ranged(
+                NaiveDate::from_ymd_opt(2020, 1, 1).unwrap()
+                    ..NaiveDate::from_ymd_opt(2020, 1, 3).unwrap(),
+                0..1,
+            )
+            .expect("Build chart error");
+
+        chart
+            .configure_mesh()
+            .disable_y_axis()
+            .draw()
+            .expect("Drawing Error");
+    }
 }
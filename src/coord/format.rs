@@ -0,0 +1,194 @@
+use std::fmt::{Result, Write};
+
+/// The SI prefixes from `10^-24` to `10^24`, the unit one is at `SI_UNIT`
+const SI_PREFIXES: [&str; 17] = [
+    "y", "z", "a", "f", "p", "n", "µ", "m", "", "k", "M", "G", "T", "P", "E", "Z", "Y",
+];
+const SI_UNIT: i32 = 8;
+
+/// The binary prefixes of the byte sizes, from a single byte to `1024^8` bytes
+const BYTE_UNITS: [&str; 9] = ["B", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB", "ZiB", "YiB"];
+
+/// Write the number with at most `precision` decimals, leaving out the trailing zeros
+fn write_trimmed<W: Write>(out: &mut W, value: f64, precision: usize) -> Result {
+    let scale = 10f64.powi(precision as i32);
+    let rounded = (value * scale).round() / scale;
+    let mut decimals = 0;
+    while decimals < precision {
+        let scaled = rounded * 10f64.powi(decimals as i32);
+        if (scaled - scaled.round()).abs() <= scaled.abs() * 1e-9 {
+            break;
+        }
+        decimals += 1;
+    }
+    // Avoid printing a negative zero for the tiny negative numbers
+    let rounded = if rounded == 0.0 { 0.0 } else { rounded };
+    write!(out, "{:.*}", decimals, rounded)
+}
+
+/// Split the value into a mantissa and an exponent of `base`, where the mantissa rounded to
+/// `precision` decimals is in `[1, base)`. For example, `999_960` with one decimal and the base
+/// 1000 is split into `0.99996` and `2`, which is written as `1 M`.
+fn split_exponent(value: f64, base: f64, precision: usize) -> (f64, i32) {
+    let exp = (value.abs().ln() / base.ln()).floor() as i32;
+    let mantissa = value / base.powi(exp);
+    let scale = 10f64.powi(precision as i32);
+    if (mantissa.abs() * scale).round() / scale >= base {
+        (mantissa / base, exp + 1)
+    } else {
+        (mantissa, exp)
+    }
+}
+
+/// Write the non-finite and the zero values, which don't have an exponent. Returns `None` if
+/// the value has to be written by the caller.
+fn write_special<W: Write>(out: &mut W, value: f64) -> Option<Result> {
+    if !value.is_finite() {
+        Some(write!(out, "{}", value))
+    } else if value == 0.0 {
+        Some(out.write_char('0'))
+    } else {
+        None
+    }
+}
+
+/// Write the number with a SI prefix, like `1.2 k`, `3.4 M` or `5 µ`, to the writer. The
+/// numbers between 1 and 1000 don't have a prefix and the ones beyond the range of the
+/// prefixes are written in the engineering notation.
+/// - `out`: The writer
+/// - `value`: The number to write
+/// - `precision`: The maximum number of decimals, the trailing zeros are left out
+pub fn write_si<W: Write>(out: &mut W, value: f64, precision: usize) -> Result {
+    if let Some(result) = write_special(out, value) {
+        return result;
+    }
+    let (mantissa, exp) = split_exponent(value, 1000.0, precision);
+    let idx = exp + SI_UNIT;
+    if idx < 0 || idx >= SI_PREFIXES.len() as i32 {
+        return write_engineering(out, value, precision);
+    }
+    write_trimmed(out, mantissa, precision)?;
+    if exp != 0 {
+        write!(out, " {}", SI_PREFIXES[idx as usize])?;
+    }
+    Ok(())
+}
+
+/// Write the number in the engineering notation, whose exponent is a multiple of 3, like
+/// `12.5e3` or `-4e-6`, to the writer. The numbers between 1 and 1000 don't have an exponent.
+/// - `out`: The writer
+/// - `value`: The number to write
+/// - `precision`: The maximum number of decimals, the trailing zeros are left out
+pub fn write_engineering<W: Write>(out: &mut W, value: f64, precision: usize) -> Result {
+    if let Some(result) = write_special(out, value) {
+        return result;
+    }
+    let (mantissa, exp) = split_exponent(value, 1000.0, precision);
+    write_trimmed(out, mantissa, precision)?;
+    if exp != 0 {
+        write!(out, "e{}", exp * 3)?;
+    }
+    Ok(())
+}
+
+/// Write the number of bytes with a binary prefix, like `512 B`, `1.5 KiB` or `2 GiB`, to the
+/// writer. The sizes below a byte are written in bytes.
+/// - `out`: The writer
+/// - `value`: The number of bytes to write
+/// - `precision`: The maximum number of decimals, the trailing zeros are left out
+pub fn write_bytes<W: Write>(out: &mut W, value: f64, precision: usize) -> Result {
+    if !value.is_finite() {
+        return write!(out, "{}", value);
+    }
+    let (mantissa, exp) = if value.abs() < 1.0 {
+        (value, 0)
+    } else {
+        split_exponent(value, 1024.0, precision)
+    };
+    let last = BYTE_UNITS.len() as i32 - 1;
+    let (mantissa, exp) = if exp > last {
+        (value / 1024f64.powi(last), last)
+    } else {
+        (mantissa, exp)
+    };
+    write_trimmed(out, mantissa, precision)?;
+    write!(out, " {}", BYTE_UNITS[exp as usize])
+}
+
+/// Make a label formatter that prints the numbers with a SI prefix, see `write_si`. The
+/// returned function can be passed to `x_label_formatter` or `y_label_formatter` of the mesh.
+/// - `precision`: The maximum number of decimals
+pub fn si_label_formatter(precision: usize) -> impl Fn(&f64) -> String {
+    move |value| {
+        let mut ret = String::new();
+        let _ = write_si(&mut ret, *value, precision);
+        ret
+    }
+}
+
+/// Make a label formatter that prints the numbers in the engineering notation, see
+/// `write_engineering`
+/// - `precision`: The maximum number of decimals
+pub fn engineering_label_formatter(precision: usize) -> impl Fn(&f64) -> String {
+    move |value| {
+        let mut ret = String::new();
+        let _ = write_engineering(&mut ret, *value, precision);
+        ret
+    }
+}
+
+/// Make a label formatter that prints the numbers as byte sizes with a binary prefix, see
+/// `write_bytes`
+/// - `precision`: The maximum number of decimals
+pub fn bytes_label_formatter(precision: usize) -> impl Fn(&f64) -> String {
+    move |value| {
+        let mut ret = String::new();
+        let _ = write_bytes(&mut ret, *value, precision);
+        ret
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_si_format() {
+        let format = si_label_formatter(1);
+        assert_eq!(format(&1234.0), "1.2 k");
+        assert_eq!(format(&3.4e6), "3.4 M");
+        assert_eq!(format(&5e-6), "5 µ");
+        assert_eq!(format(&-0.25), "-250 m");
+        assert_eq!(format(&12.0), "12");
+        assert_eq!(format(&0.0), "0");
+        assert_eq!(format(&999_960.0), "1 M");
+        assert_eq!(format(&2e30), "2e30");
+        assert_eq!(si_label_formatter(3)(&1234.5), "1.235 k");
+        assert_eq!(si_label_formatter(0)(&1234.5), "1 k");
+    }
+
+    #[test]
+    fn test_engineering_and_bytes_format() {
+        let format = engineering_label_formatter(2);
+        assert_eq!(format(&12_500.0), "12.5e3");
+        assert_eq!(format(&-4e-6), "-4e-6");
+        assert_eq!(format(&0.001), "1e-3");
+        assert_eq!(format(&999.0), "999");
+        assert_eq!(format(&f64::NAN), "NaN");
+
+        let format = bytes_label_formatter(1);
+        assert_eq!(format(&512.0), "512 B");
+        assert_eq!(format(&1536.0), "1.5 KiB");
+        assert_eq!(format(&(3.0 * 1024.0 * 1024.0 * 1024.0)), "3 GiB");
+        assert_eq!(format(&-2048.0), "-2 KiB");
+        assert_eq!(format(&0.0), "0 B");
+
+        // The writers don't need any allocation
+        let mut buf = String::with_capacity(16);
+        for &v in &[1.0, 10.0, 100.0] {
+            buf.clear();
+            write_si(&mut buf, v * 1000.0, 1).unwrap();
+            assert!(buf.ends_with(" k"));
+        }
+    }
+}
@@ -1,6 +1,6 @@
-use super::{AsRangedCoord, Ranged, RangedCoordf64};
+use super::{AsRangedCoord, Ranged, RangedCoordf64, ReversibleRanged};
 use std::marker::PhantomData;
-use std::ops::Range;
+use std::ops::{Range, RangeInclusive};
 
 /// The trait for the type that is able to be presented in the log scale
 pub trait LogScalable: Clone {
@@ -47,28 +47,133 @@ impl_log_scalable!(i, u64);
 impl_log_scalable!(f, f32);
 impl_log_scalable!(f, f64);
 
+/// The tolerance used to decide if a value is an exact power of the base
+const POWER_EPSILON: f64 = 1e-9;
+
 /// The decorator type for a range of a log-scaled value
 pub struct LogRange<V: LogScalable>(pub Range<V>);
 
-impl<V: LogScalable> From<LogRange<V>> for LogCoord<V> {
-    fn from(range: LogRange<V>) -> LogCoord<V> {
+/// The range of a log-scaled value with the base of the logarithm and the subdivision of the
+/// key points, which is created with `IntoLogRange::log_scale`
+pub struct LogRangeExt<V: LogScalable> {
+    range: Range<V>,
+    base: f64,
+    subdivisions: bool,
+}
+
+impl<V: LogScalable> LogRangeExt<V> {
+    /// Set the base of the logarithm, the key points are placed at its powers. The mapping
+    /// itself doesn't depend on the base.
+    pub fn base(mut self, base: f64) -> Self {
+        self.base = base;
+        self
+    }
+
+    /// Set if the key points include the values 2 and 5 times each power of the base when
+    /// there's room for them
+    pub fn subdivisions(mut self, subdivisions: bool) -> Self {
+        self.subdivisions = subdivisions;
+        self
+    }
+}
+
+/// The trait for the ranges that can be turned into a log-scaled axis
+pub trait IntoLogRange {
+    type ValueType: LogScalable;
+
+    /// Make the axis logarithmic, with base 10 and the subdivisions by default
+    fn log_scale(self) -> LogRangeExt<Self::ValueType>;
+}
+
+impl<V: LogScalable> IntoLogRange for Range<V> {
+    type ValueType = V;
+
+    fn log_scale(self) -> LogRangeExt<V> {
+        LogRange(self).into()
+    }
+}
+
+impl<V: LogScalable> IntoLogRange for RangeInclusive<V> {
+    type ValueType = V;
+
+    fn log_scale(self) -> LogRangeExt<V> {
+        let (start, end) = self.into_inner();
+        LogRange(start..end).into()
+    }
+}
+
+impl<V: LogScalable> From<LogRange<V>> for LogRangeExt<V> {
+    fn from(range: LogRange<V>) -> LogRangeExt<V> {
+        LogRangeExt {
+            range: range.0,
+            base: 10.0,
+            subdivisions: true,
+        }
+    }
+}
+
+impl<V: LogScalable> From<LogRangeExt<V>> for LogCoord<V> {
+    fn from(range: LogRangeExt<V>) -> LogCoord<V> {
         LogCoord {
-            linear: (range.0.start.as_f64().ln()..range.0.end.as_f64().ln()).into(),
-            logic: range.0,
+            linear: (range.range.start.as_f64().ln()..range.range.end.as_f64().ln()).into(),
+            logic: range.range,
+            base: range.base,
+            subdivisions: range.subdivisions,
             marker: PhantomData,
         }
     }
 }
 
+impl<V: LogScalable> From<LogRange<V>> for LogCoord<V> {
+    fn from(range: LogRange<V>) -> LogCoord<V> {
+        LogRangeExt::from(range).into()
+    }
+}
+
+/// Check that both ends of the range can be mapped through the logarithm
+fn check_log_range<V: LogScalable>(range: &Range<V>) -> Result<(), String> {
+    let (start, end) = (range.start.as_f64(), range.end.as_f64());
+    if start > 0.0 && end > 0.0 && start.is_finite() && end.is_finite() {
+        Ok(())
+    } else {
+        Err(format!(
+            "a log scaled axis needs a positive range, but got {}..{}",
+            start, end
+        ))
+    }
+}
+
 impl<V: LogScalable> AsRangedCoord for LogRange<V> {
     type CoordDescType = LogCoord<V>;
     type Value = V;
+
+    fn check_range(&self) -> Result<(), String> {
+        check_log_range(&self.0)
+    }
+}
+
+impl<V: LogScalable> AsRangedCoord for LogRangeExt<V> {
+    type CoordDescType = LogCoord<V>;
+    type Value = V;
+
+    fn check_range(&self) -> Result<(), String> {
+        if self.base > 1.0 && self.base.is_finite() {
+            check_log_range(&self.range)
+        } else {
+            Err(format!(
+                "the base of a log scaled axis must exceed 1, but got {}",
+                self.base
+            ))
+        }
+    }
 }
 
 /// A log scaled coordinate axis
 pub struct LogCoord<V: LogScalable> {
     linear: RangedCoordf64,
     logic: Range<V>,
+    base: f64,
+    subdivisions: bool,
     marker: PhantomData<V>,
 }
 
@@ -82,45 +187,84 @@ impl<V: LogScalable> Ranged for LogCoord<V> {
     }
 
     fn key_points(&self, max_points: usize) -> Vec<Self::ValueType> {
-        let tier_1 = (self.logic.end.as_f64() / self.logic.start.as_f64())
-            .log10()
-            .abs()
-            .floor() as usize;
-        let tier_2_density = if max_points < tier_1 {
-            0
+        let (start, end) = (self.logic.start.as_f64(), self.logic.end.as_f64());
+        let (low, high) = (start.min(end), start.max(end));
+        if max_points == 0 || low <= 0.0 {
+            return vec![];
+        }
+
+        let log = |v: f64| v.ln() / self.base.ln();
+        let first = (log(low) - POWER_EPSILON).ceil() as i32;
+        let last = (log(high) + POWER_EPSILON).floor() as i32;
+
+        // Without a power of the base in the range, the linear key points are good enough
+        if first > last {
+            return RangedCoordf64::from(low..high)
+                .key_points(max_points)
+                .into_iter()
+                .map(V::from_f64)
+                .collect();
+        }
+
+        let powers = (last - first + 1) as usize;
+        let step = powers.div_ceil(max_points);
+        let multipliers: &[f64] = if self.subdivisions && powers * 3 <= max_points {
+            &[2.0, 5.0]
         } else {
-            let density = 1 + (max_points - tier_1) / tier_1;
-            let mut exp = 1;
-            while exp * 10 <= density {
-                exp *= 10;
-            }
-            exp - 1
+            &[]
         };
+        let in_range =
+            |v: f64| v >= low * (1.0 - POWER_EPSILON) && v <= high * (1.0 + POWER_EPSILON);
 
-        let mut multiplier = 10.0;
-        let mut cnt = 1;
-        while max_points < tier_1 / cnt {
-            multiplier *= 10.0;
-            cnt += 1;
+        let mut ret = vec![];
+        // The subdivisions of the power below the range may still fall in it
+        for exp in (first - 1)..=last {
+            let power = self.base.powi(exp);
+            if exp >= first && ((exp - first) as usize).is_multiple_of(step) {
+                ret.push(V::from_f64(power));
+            }
+            for m in multipliers.iter().filter(|m| **m < self.base) {
+                if in_range(power * m) {
+                    ret.push(V::from_f64(power * m));
+                }
+            }
         }
 
+        ret
+    }
+
+    fn minor_key_points(&self, max_points: usize, _count: usize) -> Vec<V> {
+        let (start, end) = (self.logic.start.as_f64(), self.logic.end.as_f64());
+        let (low, high) = (start.min(end), start.max(end));
+        if low <= 0.0 || self.base < 3.0 {
+            return vec![];
+        }
+
+        let log = |v: f64| v.ln() / self.base.ln();
+        let key_points: Vec<f64> = self
+            .key_points(max_points)
+            .into_iter()
+            .map(|v| v.as_f64())
+            .collect();
+        let is_key_point = |v: f64| {
+            key_points
+                .iter()
+                .any(|k| (k - v).abs() <= v.abs() * POWER_EPSILON)
+        };
+        let in_range =
+            |v: f64| v >= low * (1.0 - POWER_EPSILON) && v <= high * (1.0 + POWER_EPSILON);
+
+        // The multiples of each power between 2 and the base
         let mut ret = vec![];
-        let mut val = (10f64).powf(self.logic.start.as_f64().log10().ceil());
-
-        while val <= self.logic.end.as_f64() {
-            ret.push(V::from_f64(val));
-            for i in 1..=tier_2_density {
-                let v = val
-                    * (1.0
-                        + multiplier / f64::from(tier_2_density as u32 + 1) * f64::from(i as u32));
-                if v > self.logic.end.as_f64() {
-                    break;
+        for exp in (log(low).floor() as i32)..=(log(high).ceil() as i32) {
+            let power = self.base.powi(exp);
+            for m in 2..(self.base.ceil() as i32) {
+                let value = power * f64::from(m);
+                if in_range(value) && !is_key_point(value) {
+                    ret.push(V::from_f64(value));
                 }
-                ret.push(V::from_f64(v));
             }
-            val *= multiplier;
         }
-
         ret
     }
 
@@ -128,3 +272,118 @@ impl<V: LogScalable> Ranged for LogCoord<V> {
         self.logic.clone()
     }
 }
+
+impl<V: LogScalable> ReversibleRanged for LogCoord<V> {
+    fn unmap(&self, input: i32, limit: (i32, i32)) -> Option<V> {
+        self.linear
+            .unmap(input, limit)
+            .map(|v| V::from_f64(v.exp()))
+    }
+}
+
+/// Make a label formatter for a log scaled axis, which prints the powers of `base` as `10^n`
+/// and the other values as plain numbers. The returned function can be passed to
+/// `x_label_formatter` or `y_label_formatter` of the mesh.
+pub fn log_label_formatter<V: LogScalable>(base: f64) -> impl Fn(&V) -> String {
+    move |value| {
+        let value = value.as_f64();
+        let exp = value.ln() / base.ln();
+        if value > 0.0 && (exp - exp.round()).abs() < POWER_EPSILON {
+            format!("{}^{}", base, exp.round() as i32)
+        } else {
+            format!("{}", value)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_log_key_points() {
+        let coord: LogCoord<f64> = (1.0..1000.0).log_scale().into();
+        assert_eq!(coord.key_points(4), [1.0, 10.0, 100.0, 1000.0]);
+        assert_eq!(
+            coord.key_points(12),
+            [1.0, 2.0, 5.0, 10.0, 20.0, 50.0, 100.0, 200.0, 500.0, 1000.0]
+        );
+        assert_eq!(coord.key_points(2), [1.0, 100.0]);
+
+        let coord: LogCoord<f64> = (0.3..20.0).log_scale().into();
+        assert_eq!(coord.key_points(10), [0.5, 1.0, 2.0, 5.0, 10.0, 20.0]);
+        let coord: LogCoord<f64> = (0.3..20.0).log_scale().subdivisions(false).into();
+        assert_eq!(coord.key_points(10), [1.0, 10.0]);
+
+        let coord: LogCoord<u32> = (1..64).log_scale().base(2.0).into();
+        assert_eq!(coord.key_points(10), [1, 2, 4, 8, 16, 32, 64]);
+
+        let coord: LogCoord<f64> = (1.0..100.0).log_scale().into();
+        let value = coord.unmap(100, (0, 200)).unwrap();
+        assert!((value - 10.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_log_label_formatter() {
+        let format = log_label_formatter(10.0);
+        assert_eq!(format(&1000.0), "10^3");
+        assert_eq!(format(&0.01), "10^-2");
+        assert_eq!(format(&50.0), "50");
+        assert_eq!(log_label_formatter(2.0)(&8u32), "2^3");
+    }
+
+    #[test]
+    fn test_log_range_check() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |_| {});
+        let mut builder = ChartBuilder::on(&drawing_area);
+        assert!(builder
+            .build_ranged(0.0..1.0, (0.0..100.0).log_scale())
+            .is_err());
+        assert!(builder
+            .build_ranged(0.0..1.0, LogRange(-1.0..100.0))
+            .is_err());
+        assert!(builder
+            .build_ranged(0.0..1.0, (1.0..100.0).log_scale().base(1.0))
+            .is_err());
+        assert!(builder
+            .build_ranged((1.0..100.0).log_scale(), 0.0..1.0)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_log_minor_key_points() {
+        let coord: LogCoord<f64> = (1.0..100.0).log_scale().into();
+        let minor = coord.minor_key_points(3, 4);
+        assert_eq!(minor.len(), 16);
+        assert!((minor[0] - 2.0).abs() < 1e-9 && (minor[15] - 90.0).abs() < 1e-9);
+
+        // The 2 and 5 subdivisions are key points, so they aren't minor key points
+        let minor = coord.minor_key_points(10, 4);
+        assert_eq!(minor.len(), 12);
+        assert!(minor.iter().all(|v| ![2.0, 5.0, 20.0, 50.0].contains(v)));
+    }
+
+    #[test]
+    fn test_log_coord_drawing() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_path(|_, _, path| {
+                assert_eq!(path, [(0, 200), (100, 100), (200, 0)]);
+            });
+            m.drop_check(|b| assert!(b.num_draw_line_call > 0));
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0..2, (1.0..100.0).log_scale())
+            .expect("Build chart error");
+
+        chart
+            .configure_mesh()
+            .y_label_formatter(&log_label_formatter(10.0))
+            .draw()
+            .expect("Drawing Error");
+        chart
+            .draw_series(LineSeries::new(vec![(0, 1.0), (1, 10.0), (2, 100.0)], &RED))
+            .expect("Drawing Error");
+    }
+}
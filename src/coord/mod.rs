@@ -13,35 +13,69 @@ backend coordinate system.
 When the coordinate type implements `ReverseCoordTranslate`,
 the backward translation is possible, which allows mapping pixel-based coordinate into
 the logic coordinate. It's not usually used for static figure rendering, but may be useful
-for a interactive figure.
+for a interactive figure. `ChartContext::coord_trans` gives the closure that does it for a chart.
 
 `RangedCoord` is the 2D cartesian coordinate system that has two `Ranged` axis.
 A ranged axis can be logarithmic and by applying an logarithmic axis, the figure is logarithmic scale.
+A range is made logarithmic with `IntoLogRange::log_scale`, which also sets the base of the logarithm.
+The symmetric log scale of `IntoSymlogRange::symlog_scale` shows the data that crosses zero.
+A `BrokenRange` puts several disjoint sub-ranges on the same axis, with a break between them.
+The `Categories` axis is a discrete axis over a list of labels, and `NestedCategories` groups them.
+The labels of the numeric axes can be printed with a SI prefix by `si_label_formatter`.
 Also, the ranged axis can be deserted, and this is required by the histogram series.
 
+`Cartesian3d` is the 3D cartesian coordinate system that has three `Ranged` axis, which is
+projected onto the drawing area by a configurable `ProjectionMatrix`.
+
 */
 use crate::drawing::backend::BackendCoord;
 
+mod broken;
+mod category;
 #[cfg(feature = "chrono")]
 mod datetime;
+mod format;
 mod logarithmic;
 mod numeric;
 mod ranged;
+mod ranged3d;
+mod std_duration;
+mod symlog;
 
+pub use broken::BrokenRange;
+pub use category::{Categories, NestedCategories};
 #[cfg(feature = "chrono")]
-pub use datetime::{IntoMonthly, IntoYearly, RangedDate, RangedDateTime, RangedDuration};
+pub use datetime::{
+    IntoMonthly, IntoYearly, RangedDate, RangedDateTime, RangedDuration, RangedNaiveDate,
+    RangedNaiveDateTime,
+};
 pub use numeric::{
-    RangedCoordf32, RangedCoordf64, RangedCoordi128, RangedCoordi32, RangedCoordi64,
-    RangedCoordu128, RangedCoordu32, RangedCoordu64,
+    ExactTicks, IntoExactTicks, RangedCoordf32, RangedCoordf64, RangedCoordi128, RangedCoordi32,
+    RangedCoordi64, RangedCoordisize, RangedCoordu128, RangedCoordu32, RangedCoordu64,
+    RangedCoordusize,
 };
 pub use ranged::{
-    AsRangedCoord, DiscreteRanged, IntoCentric, IntoPartialAxis, MeshLine, Ranged, RangedCoord,
-    ReversibleRanged,
+    AsRangedCoord, DiscreteRanged, IntoCentric, IntoPartialAxis, IntoReversedAxis,
+    IntoWithKeyPoints, IntoWithLabelFormatter, MeshLine, Ranged, RangedCoord, Reversed,
+    ReversibleRanged, WithKeyPoints, WithLabelFormatter,
 };
 
 pub use ranged::make_partial_axis;
 
-pub use logarithmic::{LogCoord, LogRange, LogScalable};
+pub use ranged3d::{Cartesian3d, ProjectionMatrix, ProjectionMatrixBuilder};
+
+pub use std_duration::RangedStdDuration;
+
+pub use format::{
+    bytes_label_formatter, engineering_label_formatter, si_label_formatter, write_bytes,
+    write_engineering, write_si,
+};
+
+pub use symlog::{symlog_label_formatter, IntoSymlogRange, SymlogCoord, SymlogRange};
+
+pub use logarithmic::{
+    log_label_formatter, IntoLogRange, LogCoord, LogRange, LogRangeExt, LogScalable,
+};
 
 pub use numeric::group_integer_by::{GroupBy, ToGroupByRange};
 
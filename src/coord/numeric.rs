@@ -1,4 +1,4 @@
-use std::ops::Range;
+use std::ops::{Range, RangeInclusive};
 
 use super::{AsRangedCoord, DiscreteRanged, Ranged, ReversibleRanged};
 
@@ -21,9 +21,98 @@ macro_rules! impl_ranged_type_trait {
             type CoordDescType = $coord;
             type Value = $value;
         }
+        impl AsRangedCoord for RangeInclusive<$value> {
+            type CoordDescType = $coord;
+            type Value = $value;
+        }
+    };
+}
+
+/// The values of a linear axis, whose distances are computed without overflowing even if the
+/// range spans the whole type
+trait LinearValue: Copy {
+    /// Get the signed distance from `from` to `to`
+    fn distance(from: Self, to: Self) -> f64;
+    /// Get the value that is `distance` after `self`
+    fn offset(self, distance: f64) -> Self;
+    /// Get the exclusive end of the axis for an inclusive range ending at `self`. The integer
+    /// axes are discrete, so the last value gets a slot of its own.
+    fn inclusive_end(self) -> Self;
+}
+
+macro_rules! impl_linear_value {
+    (float, $type:ty) => {
+        impl LinearValue for $type {
+            fn distance(from: Self, to: Self) -> f64 {
+                to as f64 - from as f64
+            }
+            fn offset(self, distance: f64) -> Self {
+                (self as f64 + distance) as $type
+            }
+            fn inclusive_end(self) -> Self {
+                self
+            }
+        }
+    };
+    (integer, $type:ty, $unsigned:ty) => {
+        impl LinearValue for $type {
+            fn distance(from: Self, to: Self) -> f64 {
+                // The difference of two values always fits in the unsigned type of the same size
+                if from <= to {
+                    (to as $unsigned).wrapping_sub(from as $unsigned) as f64
+                } else {
+                    -((from as $unsigned).wrapping_sub(to as $unsigned) as f64)
+                }
+            }
+            fn offset(self, distance: f64) -> Self {
+                // A pixel in the slot of an integer goes to that integer, so the distance is
+                // truncated, within the error of the float computation
+                let delta = (distance.abs() + 1e-6).floor() as $unsigned;
+                if distance >= 0.0 {
+                    (self as $unsigned).wrapping_add(delta) as $type
+                } else {
+                    (self as $unsigned).wrapping_sub(delta) as $type
+                }
+            }
+            fn inclusive_end(self) -> Self {
+                self.saturating_add(1)
+            }
+        }
     };
 }
 
+impl_linear_value!(float, f32);
+impl_linear_value!(float, f64);
+impl_linear_value!(integer, i32, u32);
+impl_linear_value!(integer, u32, u32);
+impl_linear_value!(integer, i64, u64);
+impl_linear_value!(integer, u64, u64);
+impl_linear_value!(integer, i128, u128);
+impl_linear_value!(integer, u128, u128);
+impl_linear_value!(integer, isize, usize);
+impl_linear_value!(integer, usize, usize);
+
+/// Divide each interval between two linear key points into `count + 1` parts, and give the
+/// points between the parts that are in the range, including the ones before the first key
+/// point and after the last one
+fn linear_minor_key_points(key_points: &[f64], range: (f64, f64), count: usize) -> Vec<f64> {
+    if key_points.len() < 2 || count == 0 {
+        return vec![];
+    }
+    let step = (key_points[1] - key_points[0]) / (count + 1) as f64;
+    if !(step.is_finite() && step != 0.0) {
+        return vec![];
+    }
+    let (low, high) = (range.0.min(range.1), range.0.max(range.1));
+    let origin = key_points[0];
+    let first = ((low - origin) / step - 1e-6).ceil() as i64;
+    let last = ((high - origin) / step + 1e-6).floor() as i64;
+    (first..=last)
+        .filter(|k| k.rem_euclid(count as i64 + 1) != 0)
+        .map(|k| origin + step * k as f64)
+        .collect()
+}
+
 macro_rules! make_numeric_coord {
     ($type:ty, $name:ident, $key_points:ident, $doc: expr) => {
         #[doc = $doc]
@@ -33,10 +122,17 @@ macro_rules! make_numeric_coord {
                 return Self(range.start, range.end);
             }
         }
+        impl From<RangeInclusive<$type>> for $name {
+            fn from(range: RangeInclusive<$type>) -> Self {
+                let (start, end) = range.into_inner();
+                return Self(start, end.inclusive_end());
+            }
+        }
         impl Ranged for $name {
             type ValueType = $type;
             fn map(&self, v: &$type, limit: (i32, i32)) -> i32 {
-                let logic_length = (*v - self.0) as f64 / (self.1 - self.0) as f64;
+                let logic_length = <$type as LinearValue>::distance(self.0, *v)
+                    / <$type as LinearValue>::distance(self.0, self.1);
                 let actual_length = limit.1 - limit.0;
 
                 if actual_length == 0 {
@@ -48,26 +144,97 @@ macro_rules! make_numeric_coord {
             fn key_points(&self, max_points: usize) -> Vec<$type> {
                 $key_points((self.0, self.1), max_points)
             }
+            fn minor_key_points(&self, max_points: usize, count: usize) -> Vec<$type> {
+                let key_points: Vec<f64> = self
+                    .key_points(max_points)
+                    .into_iter()
+                    .map(|v| v as f64)
+                    .collect();
+                linear_minor_key_points(&key_points, (self.0 as f64, self.1 as f64), count)
+                    .into_iter()
+                    // The integer axes only have the minor key points that are integers
+                    .filter(|v| ((*v as $type) as f64 - v).abs() <= v.abs() * 1e-6)
+                    .map(|v| v as $type)
+                    .collect()
+            }
             fn range(&self) -> Range<$type> {
                 return self.0..self.1;
             }
+            fn format_label(&self, value: &$type) -> Option<String> {
+                Some(format!("{:?}", value))
+            }
         }
 
         impl ReversibleRanged for $name {
-            fn unmap(&self, p:i32, (min,max): (i32, i32)) -> Option<$type> {
+            fn unmap(&self, p: i32, (min, max): (i32, i32)) -> Option<$type> {
                 if p < min.min(max) || p > max.max(min) {
                     return None;
                 }
 
                 let logical_offset = (p - min) as f64 / (max - min) as f64;
 
-                return Some(((self.1 - self.0) as f64 * logical_offset + self.0 as f64) as $type);
+                let distance = <$type as LinearValue>::distance(self.0, self.1);
+                return Some(self.0.offset(distance * logical_offset));
             }
         }
     };
 }
 
+/// Find the key points of a float range with the nice-number algorithm. The step between the
+/// key points is the smallest of 1, 2 or 5 times a power of ten that gives at most `max_points`
+/// key points, which are the multiples of the step rounded to its decimals.
+fn nice_key_points(range: (f64, f64), max_points: usize) -> Vec<f64> {
+    let (low, high) = (range.0.min(range.1), range.0.max(range.1));
+    if max_points == 0 || !(low.is_finite() && high.is_finite()) {
+        return vec![];
+    }
+    if low == high {
+        return vec![low];
+    }
+
+    let count =
+        |step: f64| ((high / step + 1e-9).floor() - (low / step - 1e-9).ceil() + 1.0).max(0.0);
+    let mut exp = ((high - low) / max_points as f64).log10().floor() as i32 - 1;
+    let step = loop {
+        let scale = 10f64.powi(exp);
+        if let Some(step) = [1.0, 2.0, 5.0]
+            .iter()
+            .map(|m| m * scale)
+            .find(|step| count(*step) <= max_points as f64)
+        {
+            break step;
+        }
+        exp += 1;
+    };
+
+    // Round the key points to the decimals of the step, so they don't carry the float noise
+    let decimals = (-step.log10().floor()).max(0.0) as i32;
+    let size = 10f64.powi(decimals);
+    let (first, last) = (
+        (low / step - 1e-9).ceil() as i64,
+        (high / step + 1e-9).floor() as i64,
+    );
+    (first..=last)
+        .map(|idx| {
+            let value = (idx as f64 * step * size).round() / size;
+            if value == 0.0 {
+                0.0
+            } else {
+                value
+            }
+        })
+        .collect()
+}
+
 macro_rules! gen_key_points_comp {
+    (nice, $name:ident, $type:ty) => {
+        fn $name(range: ($type, $type), max_points: usize) -> Vec<$type> {
+            nice_key_points((range.0 as f64, range.1 as f64), max_points)
+                .into_iter()
+                .map(|v| v as $type)
+                .collect()
+        }
+    };
     (float, $name:ident, $type:ty) => {
         fn $name(range: ($type, $type), max_points: usize) -> Vec<$type> {
             if max_points == 0 {
@@ -128,32 +295,49 @@ macro_rules! gen_key_points_comp {
             return ret;
         }
     };
-    (integer, $name:ident, $type:ty) => {
+    (integer, $name:ident, $type:ty, $unsigned:ty) => {
         fn $name(range: ($type, $type), max_points: usize) -> Vec<$type> {
-            let mut scale: $type = 1;
+            if max_points == 0 {
+                return vec![];
+            }
+
+            // The values are moved into the unsigned type of the same size with the same order,
+            // so that neither the length of the range nor the key points overflow
+            const BIAS: $unsigned = (<$type>::MIN as $unsigned);
             let range = (range.0.min(range.1), range.0.max(range.1));
-            'outer: while (range.1 - range.0 + scale - 1) as usize / (scale as usize) > max_points {
-                let next_scale = scale * 10;
-                for new_scale in [scale * 2, scale * 5, scale * 10].iter() {
+            let (low, high) = ((range.0 as $unsigned) ^ BIAS, (range.1 as $unsigned) ^ BIAS);
+            let len = high - low;
+            let count = |scale: $unsigned| (len / scale + (len % scale != 0) as $unsigned) as u128;
+
+            let mut scale: $unsigned = 1;
+            'outer: while count(scale) > max_points as u128 {
+                let next_scale = match scale.checked_mul(10) {
+                    Some(next_scale) => next_scale,
+                    None => break,
+                };
+                for new_scale in [scale * 2, scale * 5, next_scale].iter() {
                     scale = *new_scale;
-                    if (range.1 - range.0 + *new_scale - 1) as usize / (*new_scale as usize)
-                        < max_points
-                    {
+                    if count(*new_scale) < max_points as u128 {
                         break 'outer;
                     }
                 }
                 scale = next_scale;
             }
 
-            let (mut left, right) = (
-                range.0 + (scale - range.0 % scale) % scale,
-                range.1 - range.1 % scale,
-            );
+            // The key points are the multiples of the scale, which are the ones with the same
+            // remainder as the bias after moving
+            let (start_rem, bias_rem) = (low % scale, BIAS % scale);
+            let delta = if bias_rem >= start_rem {
+                bias_rem - start_rem
+            } else {
+                scale - (start_rem - bias_rem)
+            };
 
             let mut ret = vec![];
-            while left <= right {
-                ret.push(left as $type);
-                left += scale;
+            let mut left = low.checked_add(delta);
+            while let Some(value) = left.filter(|value| *value <= high) {
+                ret.push((value ^ BIAS) as $type);
+                left = value.checked_add(scale);
             }
 
             return ret;
@@ -161,16 +345,18 @@ macro_rules! gen_key_points_comp {
     };
 }
 
-gen_key_points_comp!(float, compute_f32_key_points, f32);
-gen_key_points_comp!(float, compute_f64_key_points, f64);
-gen_key_points_comp!(integer, compute_i32_key_points, i32);
-gen_key_points_comp!(integer, compute_u32_key_points, u32);
-gen_key_points_comp!(integer, compute_i64_key_points, i64);
-gen_key_points_comp!(integer, compute_u64_key_points, u64);
-gen_key_points_comp!(integer, compute_i128_key_points, i128);
-gen_key_points_comp!(integer, compute_u128_key_points, u128);
-gen_key_points_comp!(integer, compute_isize_key_points, isize);
-gen_key_points_comp!(integer, compute_usize_key_points, usize);
+gen_key_points_comp!(float, compute_f32_exact_key_points, f32);
+gen_key_points_comp!(float, compute_f64_exact_key_points, f64);
+gen_key_points_comp!(nice, compute_f32_key_points, f32);
+gen_key_points_comp!(nice, compute_f64_key_points, f64);
+gen_key_points_comp!(integer, compute_i32_key_points, i32, u32);
+gen_key_points_comp!(integer, compute_u32_key_points, u32, u32);
+gen_key_points_comp!(integer, compute_i64_key_points, i64, u64);
+gen_key_points_comp!(integer, compute_u64_key_points, u64, u64);
+gen_key_points_comp!(integer, compute_i128_key_points, i128, u128);
+gen_key_points_comp!(integer, compute_u128_key_points, u128, u128);
+gen_key_points_comp!(integer, compute_isize_key_points, isize, usize);
+gen_key_points_comp!(integer, compute_usize_key_points, usize, usize);
 
 make_numeric_coord!(
     f32,
@@ -253,10 +439,77 @@ impl_ranged_type_trait!(u128, RangedCoordu128);
 impl_ranged_type_trait!(isize, RangedCoordisize);
 impl_ranged_type_trait!(usize, RangedCoordusize);
 
+/// The float axis decorator that places the key points with the algorithm used before the
+/// nice-number one, which divides the range by 2, 5 or 10 until there are enough key points.
+/// It's made by `IntoExactTicks::exact_ticks`.
+pub struct ExactTicks<R: Ranged>(R);
+
+/// The trait for the float ranges that can opt out of the nice-number key points
+pub trait IntoExactTicks: Sized {
+    /// The coordinate of the range
+    type Coord: Ranged;
+
+    /// Make the axis use the exact key points, see `ExactTicks` for details
+    fn exact_ticks(self) -> ExactTicks<Self::Coord>;
+}
+
+macro_rules! impl_exact_ticks {
+    ($type:ty, $coord:ident, $key_points:ident) => {
+        impl IntoExactTicks for Range<$type> {
+            type Coord = $coord;
+            fn exact_ticks(self) -> ExactTicks<$coord> {
+                ExactTicks(self.into())
+            }
+        }
+
+        impl Ranged for ExactTicks<$coord> {
+            type ValueType = $type;
+            fn map(&self, value: &$type, limit: (i32, i32)) -> i32 {
+                self.0.map(value, limit)
+            }
+            fn key_points(&self, max_points: usize) -> Vec<$type> {
+                $key_points(((self.0).0, (self.0).1), max_points)
+            }
+            fn minor_key_points(&self, max_points: usize, count: usize) -> Vec<$type> {
+                let key_points: Vec<f64> = self
+                    .key_points(max_points)
+                    .into_iter()
+                    .map(|v| v as f64)
+                    .collect();
+                let range = ((self.0).0 as f64, (self.0).1 as f64);
+                linear_minor_key_points(&key_points, range, count)
+                    .into_iter()
+                    .map(|v| v as $type)
+                    .collect()
+            }
+            fn range(&self) -> Range<$type> {
+                self.0.range()
+            }
+            fn format_label(&self, value: &$type) -> Option<String> {
+                self.0.format_label(value)
+            }
+        }
+
+        impl ReversibleRanged for ExactTicks<$coord> {
+            fn unmap(&self, input: i32, limit: (i32, i32)) -> Option<$type> {
+                self.0.unmap(input, limit)
+            }
+        }
+
+        impl AsRangedCoord for ExactTicks<$coord> {
+            type CoordDescType = Self;
+            type Value = $type;
+        }
+    };
+}
+
+impl_exact_ticks!(f32, RangedCoordf32, compute_f32_exact_key_points);
+impl_exact_ticks!(f64, RangedCoordf64, compute_f64_exact_key_points);
+
 // TODO: Think about how to re-organize this part
 pub mod group_integer_by {
     use super::Ranged;
-    use super::{AsRangedCoord, DiscreteRanged};
+    use super::{AsRangedCoord, DiscreteRanged, ReversibleRanged};
     use num_traits::{FromPrimitive, PrimInt, ToPrimitive};
     use std::ops::{Mul, Range};
 
@@ -321,6 +574,16 @@ pub mod group_integer_by {
         }
     }
 
+    impl<T> ReversibleRanged for GroupBy<T>
+    where
+        T::ValueType: PrimInt + ToPrimitive + FromPrimitive + Mul,
+        T: ReversibleRanged,
+    {
+        fn unmap(&self, input: i32, limit: (i32, i32)) -> Option<T::ValueType> {
+            self.0.unmap(input, limit)
+        }
+    }
+
     impl<T> Ranged for GroupBy<T>
     where
         T::ValueType: PrimInt + ToPrimitive + FromPrimitive + Mul,
@@ -333,6 +596,9 @@ pub mod group_integer_by {
         fn range(&self) -> Range<T::ValueType> {
             self.0.range()
         }
+        fn format_label(&self, value: &T::ValueType) -> Option<String> {
+            self.0.format_label(value)
+        }
         fn key_points(&self, max_points: usize) -> Vec<T::ValueType> {
             let actual_range = self.0.range();
             let from = ((actual_range.start + self.1 - T::ValueType::from_u8(1).unwrap()) / self.1)
@@ -354,6 +620,7 @@ pub mod group_integer_by {
 mod test {
     use super::*;
     use crate::coord::*;
+    use crate::prelude::*;
     #[test]
     fn test_key_points() {
         let kp = compute_i32_key_points((0, 999), 28);
@@ -368,6 +635,26 @@ mod test {
         assert!(kp.len() == 0);
     }
 
+    #[test]
+    fn test_nice_key_points() {
+        let coord: RangedCoordf64 = (0.0..1.0).into();
+        assert_eq!(coord.key_points(3), [0.0, 0.5, 1.0]);
+        assert_eq!(coord.key_points(10), [0.0, 0.2, 0.4, 0.6, 0.8, 1.0]);
+        // The multiples of the step don't carry the float noise
+        let coord: RangedCoordf64 = (0.1..0.75).into();
+        assert_eq!(coord.key_points(8), [0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7]);
+        let coord: RangedCoordf64 = (-17.3..42.8).into();
+        assert_eq!(coord.key_points(7), [-10.0, 0.0, 10.0, 20.0, 30.0, 40.0]);
+        let coord: RangedCoordf32 = (0.0..0.001).into();
+        assert_eq!(coord.key_points(2), [0.0, 0.001]);
+        assert!(coord.key_points(0).is_empty());
+
+        // The ranges can opt out of the nice numbers
+        let coord = (0.0..1.0).exact_ticks();
+        assert_eq!(coord.key_points(3), [0.5, 1.0]);
+        assert_eq!(coord.map(&0.5, (0, 100)), 50);
+    }
+
     #[test]
     fn test_linear_coord_map() {
         let coord: RangedCoordu32 = (0..20).into();
@@ -380,6 +667,126 @@ mod test {
         assert_eq!(coord.map(&5.0, (0, 100)), 25);
     }
 
+    #[test]
+    fn test_extreme_integer_ranges() {
+        let coord: RangedCoordu64 = (0..std::u64::MAX).into();
+        assert_eq!(coord.map(&0, (0, 1000)), 0);
+        assert_eq!(coord.map(&(std::u64::MAX / 2), (0, 1000)), 500);
+        assert_eq!(coord.map(&std::u64::MAX, (0, 1000)), 1000);
+        assert_eq!(coord.unmap(1000, (0, 1000)), Some(std::u64::MAX));
+        let kp = coord.key_points(10);
+        assert!(!kp.is_empty() && kp.len() <= 10);
+        assert!(kp.windows(2).all(|w| w[0] < w[1]));
+
+        let coord: RangedCoordi64 = (std::i64::MIN..std::i64::MAX).into();
+        assert_eq!(coord.map(&std::i64::MIN, (0, 1000)), 0);
+        assert_eq!(coord.map(&0, (0, 1000)), 500);
+        assert_eq!(coord.unmap(0, (0, 1000)), Some(std::i64::MIN));
+        assert_eq!(coord.unmap(500, (0, 1000)), Some(0));
+        // The key points are the multiples of the same scale, which include zero
+        let kp = coord.key_points(5);
+        assert!(!kp.is_empty() && kp.len() <= 5 && kp.contains(&0));
+
+        let coord: RangedCoordi128 = (std::i128::MIN..std::i128::MAX).into();
+        assert_eq!(coord.map(&0, (0, 1000)), 500);
+        assert!(coord.key_points(10).len() <= 10);
+        let coord: RangedCoordu128 = (0..std::u128::MAX).into();
+        assert!(!coord.key_points(10).is_empty());
+        assert_eq!(coord.key_points(0), []);
+
+        // The nanosecond timestamps are larger than 2^53, but the pixels are still exact
+        let base = 1_600_000_000_000_000_000u64;
+        let coord: RangedCoordu64 = (base..base + 1000).into();
+        assert_eq!(coord.map(&(base + 250), (0, 100)), 25);
+        assert_eq!(coord.unmap(25, (0, 100)), Some(base + 250));
+        assert_eq!(coord.key_points(3), [base, base + 500, base + 1000]);
+
+        let coord: RangedCoordusize = (3..std::usize::MAX).into();
+        assert_eq!(coord.map(&3, (0, 100)), 0);
+        let coord: RangedCoordi32 = (-7..8).into();
+        assert_eq!(coord.key_points(4), [-5, 0, 5]);
+    }
+
+    #[test]
+    fn test_inclusive_ranges() {
+        // The end of a continuous axis is a key point, and the last value of a discrete axis
+        // has a slot of its own
+        let coord: RangedCoordf64 = (0.0..=1.0).into();
+        assert_eq!(coord.range(), 0.0..1.0);
+        assert_eq!(coord.key_points(3).last(), Some(&1.0));
+        let coord: RangedCoordi32 = (0..=9).into();
+        assert_eq!(coord.range(), 0..10);
+        let coord: RangedCoordu64 = (std::u64::MAX - 1..=std::u64::MAX).into();
+        assert_eq!(coord.range(), std::u64::MAX - 1..std::u64::MAX);
+
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            // The bar of the last value is still in the plotting area
+            m.check_draw_rect(|_, _, _, upper_left, bottom_right| {
+                assert!(upper_left.0 >= 0 && bottom_right.0 <= 200);
+            });
+            m.drop_check(|b| assert_eq!(b.num_draw_rect_call, 3));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged((0..=2).into_centric(), 0f64..=1f64)
+            .expect("Build chart error");
+        chart
+            .draw_series(Histogram::vertical(&chart).style(RED.filled()).data(vec![
+                (0, 1.0),
+                (1, 0.5),
+                (2, 0.25),
+            ]))
+            .expect("Drawing Error");
+    }
+
+    #[test]
+    fn test_linear_minor_key_points() {
+        let coord: RangedCoordf64 = (0.0..1.0).into();
+        let minor = coord.minor_key_points(3, 4);
+        assert_eq!(minor.len(), 8);
+        assert!((minor[0] - 0.1).abs() < 1e-9 && (minor[4] - 0.6).abs() < 1e-9);
+
+        // The integer axes leave out the minor key points that aren't integers
+        let coord: RangedCoordi32 = (0..10).into();
+        assert_eq!(coord.key_points(3), [0, 5, 10]);
+        assert_eq!(coord.minor_key_points(3, 4), [1, 2, 3, 4, 6, 7, 8, 9]);
+        assert_eq!(coord.minor_key_points(3, 1), [0; 0]);
+        assert_eq!(coord.minor_key_points(3, 0), [0; 0]);
+    }
+
+    #[test]
+    fn test_unmap_round_trip() {
+        let coord: RangedCoordf64 = (-3.0..7.0).into();
+        for pixel in 0..=300 {
+            let value = coord.unmap(pixel, (0, 300)).unwrap();
+            assert!((coord.map(&value, (0, 300)) - pixel).abs() <= 1);
+        }
+        assert_eq!(coord.unmap(301, (0, 300)), None);
+        assert_eq!(coord.unmap(-1, (300, 0)), None);
+
+        // The pixels go to the integer whose slot contains them
+        let coord: RangedCoordi32 = (0..10).into();
+        assert_eq!(coord.unmap(0, (0, 100)), Some(0));
+        assert_eq!(coord.unmap(9, (0, 100)), Some(0));
+        assert_eq!(coord.unmap(10, (0, 100)), Some(1));
+        assert_eq!(coord.unmap(29, (0, 100)), Some(2));
+        for value in 0..10 {
+            assert_eq!(
+                coord.unmap(coord.map(&value, (0, 70)), (0, 70)),
+                Some(value)
+            );
+        }
+
+        // The slots of a centric axis are centered on the values
+        let coord = (0..10).into_centric();
+        assert_eq!(coord.unmap(4, (0, 100)), Some(0));
+        assert_eq!(coord.unmap(5, (0, 100)), Some(1));
+        assert_eq!(coord.unmap(14, (0, 100)), Some(1));
+        assert_eq!(coord.unmap(15, (0, 100)), Some(2));
+
+        let coord = (0..100).group_by(10);
+        assert_eq!(coord.unmap(55, (0, 100)), Some(55));
+    }
+
     #[test]
     fn test_linear_coord_system() {
         let _coord =
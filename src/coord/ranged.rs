@@ -16,6 +16,14 @@ pub trait Ranged {
     /// This function gives the key points that we can draw a grid based on this
     fn key_points(&self, max_points: usize) -> Vec<Self::ValueType>;
 
+    /// This function gives the minor key points between the key points of
+    /// `key_points(max_points)`, where the mesh draws the minor grid lines and ticks.
+    /// `count` is the number of minor key points between two key points, which a log scaled
+    /// axis ignores, since its minor key points are the multiples of each power.
+    fn minor_key_points(&self, _max_points: usize, _count: usize) -> Vec<Self::ValueType> {
+        vec![]
+    }
+
     /// Get the range of this value
     fn range(&self) -> Range<Self::ValueType>;
 
@@ -23,6 +31,25 @@ pub trait Ranged {
     fn axis_pixel_range(&self, limit: (i32, i32)) -> Range<i32> {
         limit.0..limit.1
     }
+
+    /// Format the value as the label of a key point, which is used by the mesh when it isn't
+    /// given a label formatter. `None` means the `Debug` representation of the value is used,
+    /// and the automatically sized label areas don't measure the labels of the axis.
+    fn format_label(&self, _value: &Self::ValueType) -> Option<String> {
+        None
+    }
+
+    /// Get the pixel positions where the axis is broken, which are marked on the axis when the
+    /// mesh is drawn
+    fn axis_breaks(&self, _limit: (i32, i32)) -> Vec<i32> {
+        vec![]
+    }
+
+    /// Get the pixel ranges and the labels of the groups the key points belong to, which are
+    /// drawn by the mesh as a second row of labels with a separator between two groups
+    fn axis_groups(&self, _limit: (i32, i32)) -> Vec<(Range<i32>, String)> {
+        vec![]
+    }
 }
 
 /// The trait indicates the ranged value can be map reversely, which means
@@ -55,6 +82,26 @@ impl<X: Ranged, Y: Ranged> RangedCoord<X, Y> {
         }
     }
 
+    /// Get the specification of the X axis
+    pub fn x_spec(&self) -> &X {
+        &self.logic_x
+    }
+
+    /// Get the specification of the Y axis
+    pub fn y_spec(&self) -> &Y {
+        &self.logic_y
+    }
+
+    /// Replace the specification of the X axis, the pixel range is kept
+    pub(crate) fn set_x_spec(&mut self, logic_x: X) {
+        self.logic_x = logic_x;
+    }
+
+    /// Replace the specification of the Y axis, the pixel range is kept
+    pub(crate) fn set_y_spec(&mut self, logic_y: Y) {
+        self.logic_y = logic_y;
+    }
+
     /// Draw the mesh for the coordinate system
     pub fn draw_mesh<E, DrawMesh: FnMut(MeshLine<X, Y>) -> Result<(), E>>(
         &self,
@@ -88,6 +135,40 @@ impl<X: Ranged, Y: Ranged> RangedCoord<X, Y> {
         Ok(())
     }
 
+    /// Draw the minor mesh for the coordinate system, with `count` minor key points between
+    /// two key points of each axis. The axis with a count of 0 doesn't have a minor mesh.
+    pub fn draw_minor_mesh<E, DrawMesh: FnMut(MeshLine<X, Y>) -> Result<(), E>>(
+        &self,
+        h_limit: usize,
+        v_limit: usize,
+        (x_count, y_count): (usize, usize),
+        mut draw_mesh: DrawMesh,
+    ) -> Result<(), E> {
+        if x_count > 0 {
+            for logic_x in self.logic_x.minor_key_points(v_limit, x_count) {
+                let x = self.logic_x.map(&logic_x, self.back_x);
+                draw_mesh(MeshLine::XMesh(
+                    (x, self.back_y.0),
+                    (x, self.back_y.1),
+                    &logic_x,
+                ))?;
+            }
+        }
+
+        if y_count > 0 {
+            for logic_y in self.logic_y.minor_key_points(h_limit, y_count) {
+                let y = self.logic_y.map(&logic_y, self.back_y);
+                draw_mesh(MeshLine::YMesh(
+                    (self.back_x.0, y),
+                    (self.back_x.1, y),
+                    &logic_y,
+                ))?;
+            }
+        }
+
+        Ok(())
+    }
+
     /// Get the range of X axis
     pub fn get_x_range(&self) -> Range<X::ValueType> {
         self.logic_x.range()
@@ -105,6 +186,26 @@ impl<X: Ranged, Y: Ranged> RangedCoord<X, Y> {
     pub fn get_y_axis_pixel_range(&self) -> Range<i32> {
         self.logic_y.axis_pixel_range(self.back_y)
     }
+
+    /// Get the pixel positions where the X axis is broken
+    pub fn get_x_axis_breaks(&self) -> Vec<i32> {
+        self.logic_x.axis_breaks(self.back_x)
+    }
+
+    /// Get the pixel positions where the Y axis is broken
+    pub fn get_y_axis_breaks(&self) -> Vec<i32> {
+        self.logic_y.axis_breaks(self.back_y)
+    }
+
+    /// Get the pixel ranges and the labels of the groups on the X axis
+    pub fn get_x_axis_groups(&self) -> Vec<(Range<i32>, String)> {
+        self.logic_x.axis_groups(self.back_x)
+    }
+
+    /// Get the pixel ranges and the labels of the groups on the Y axis
+    pub fn get_y_axis_groups(&self) -> Vec<(Range<i32>, String)> {
+        self.logic_y.axis_groups(self.back_y)
+    }
 }
 
 impl<X: Ranged, Y: Ranged> CoordTranslate for RangedCoord<X, Y> {
@@ -165,6 +266,12 @@ where
 pub trait AsRangedCoord: Sized {
     type CoordDescType: Ranged + From<Self>;
     type Value;
+
+    /// Check if the specification describes a valid axis, the chart builder fails with the
+    /// returned message if it doesn't
+    fn check_range(&self) -> Result<(), String> {
+        Ok(())
+    }
 }
 
 impl<T> AsRangedCoord for T
@@ -218,9 +325,40 @@ where
         self.0.key_points(max_points)
     }
 
+    fn minor_key_points(&self, max_points: usize, count: usize) -> Vec<Self::ValueType> {
+        self.0.minor_key_points(max_points, count)
+    }
+
     fn range(&self) -> Range<Self::ValueType> {
         self.0.range()
     }
+
+    fn format_label(&self, value: &Self::ValueType) -> Option<String> {
+        self.0.format_label(value)
+    }
+
+    fn axis_breaks(&self, limit: (i32, i32)) -> Vec<i32> {
+        self.0.axis_breaks(limit)
+    }
+
+    fn axis_groups(&self, limit: (i32, i32)) -> Vec<(Range<i32>, String)> {
+        self.0.axis_groups(limit)
+    }
+}
+
+/// The pixel goes to the value whose centered slot contains it
+impl<D: DiscreteRanged + ReversibleRanged> ReversibleRanged for CentricDiscreteRange<D>
+where
+    <D as Ranged>::ValueType: Eq,
+{
+    fn unmap(&self, input: i32, limit: (i32, i32)) -> Option<Self::ValueType> {
+        let value = self.0.unmap(input, limit)?;
+        let next = <D as DiscreteRanged>::next_value(&value);
+        if self.map(&next, limit) <= input {
+            return Some(next);
+        }
+        Some(value)
+    }
 }
 
 impl<D: DiscreteRanged> DiscreteRanged for CentricDiscreteRange<D>
@@ -279,6 +417,10 @@ where
         self.0.key_points(max_points)
     }
 
+    fn minor_key_points(&self, max_points: usize, count: usize) -> Vec<Self::ValueType> {
+        self.0.minor_key_points(max_points, count)
+    }
+
     fn range(&self) -> Range<Self::ValueType> {
         self.0.range()
     }
@@ -289,6 +431,27 @@ where
 
         left.min(right)..left.max(right)
     }
+
+    fn format_label(&self, value: &Self::ValueType) -> Option<String> {
+        self.0.format_label(value)
+    }
+
+    fn axis_breaks(&self, limit: (i32, i32)) -> Vec<i32> {
+        self.0.axis_breaks(limit)
+    }
+
+    fn axis_groups(&self, limit: (i32, i32)) -> Vec<(Range<i32>, String)> {
+        self.0.axis_groups(limit)
+    }
+}
+
+impl<R: ReversibleRanged> ReversibleRanged for PartialAxis<R>
+where
+    R::ValueType: Clone,
+{
+    fn unmap(&self, input: i32, limit: (i32, i32)) -> Option<Self::ValueType> {
+        self.0.unmap(input, limit)
+    }
 }
 
 impl<R: DiscreteRanged> DiscreteRanged for PartialAxis<R>
@@ -313,6 +476,268 @@ where
     type Value = <Self as Ranged>::ValueType;
 }
 
+/// This axis decorator flips the direction of the axis, so the larger values are drawn at the
+/// left of the X axis or at the bottom of the Y axis. The key points and the labels are the
+/// same as the decorated axis.
+pub struct Reversed<R: Ranged>(R);
+
+/// The trait for the types that can be converted into a reversed axis
+pub trait IntoReversedAxis: AsRangedCoord {
+    /// Make the reversed axis
+    ///
+    /// - **returns**: The converted range specification
+    fn reversed(self) -> Reversed<Self::CoordDescType> {
+        Reversed(self.into())
+    }
+}
+
+impl<R: AsRangedCoord> IntoReversedAxis for R {}
+
+impl<R: Ranged> Ranged for Reversed<R> {
+    type ValueType = R::ValueType;
+
+    fn map(&self, value: &Self::ValueType, limit: (i32, i32)) -> i32 {
+        self.0.map(value, (limit.1, limit.0))
+    }
+
+    fn key_points(&self, max_points: usize) -> Vec<Self::ValueType> {
+        self.0.key_points(max_points)
+    }
+
+    fn minor_key_points(&self, max_points: usize, count: usize) -> Vec<Self::ValueType> {
+        self.0.minor_key_points(max_points, count)
+    }
+
+    fn range(&self) -> Range<Self::ValueType> {
+        self.0.range()
+    }
+
+    fn axis_pixel_range(&self, limit: (i32, i32)) -> Range<i32> {
+        let range = self.0.axis_pixel_range((limit.1, limit.0));
+        range.end..range.start
+    }
+
+    fn format_label(&self, value: &Self::ValueType) -> Option<String> {
+        self.0.format_label(value)
+    }
+
+    fn axis_breaks(&self, limit: (i32, i32)) -> Vec<i32> {
+        self.0.axis_breaks((limit.1, limit.0))
+    }
+
+    fn axis_groups(&self, limit: (i32, i32)) -> Vec<(Range<i32>, String)> {
+        self.0.axis_groups((limit.1, limit.0))
+    }
+}
+
+impl<R: ReversibleRanged> ReversibleRanged for Reversed<R> {
+    fn unmap(&self, input: i32, limit: (i32, i32)) -> Option<Self::ValueType> {
+        self.0.unmap(input, (limit.1, limit.0))
+    }
+}
+
+impl<R: DiscreteRanged> DiscreteRanged for Reversed<R>
+where
+    <R as Ranged>::ValueType: Eq,
+{
+    fn next_value(this: &Self::ValueType) -> Self::ValueType {
+        <R as DiscreteRanged>::next_value(this)
+    }
+
+    fn previous_value(this: &Self::ValueType) -> Self::ValueType {
+        <R as DiscreteRanged>::previous_value(this)
+    }
+}
+
+impl<R: Ranged> AsRangedCoord for Reversed<R> {
+    type CoordDescType = Self;
+    type Value = <Self as Ranged>::ValueType;
+}
+
+/// This axis decorator replaces the key points of the axis with a given list, so the mesh
+/// lines and the labels are exactly where they are needed. The key points outside of the
+/// range of the axis are left out, and an empty list removes the mesh lines and the labels of
+/// the axis.
+pub struct WithKeyPoints<R: Ranged>(R, Vec<R::ValueType>);
+
+/// The trait for the types that can be given a list of key points
+pub trait IntoWithKeyPoints: AsRangedCoord {
+    /// Use the given key points for the axis
+    ///
+    /// - `key_points`: The key points of the axis
+    /// - **returns**: The converted range specification
+    fn with_key_points(
+        self,
+        key_points: Vec<<Self::CoordDescType as Ranged>::ValueType>,
+    ) -> WithKeyPoints<Self::CoordDescType> {
+        WithKeyPoints(self.into(), key_points)
+    }
+}
+
+impl<R: AsRangedCoord> IntoWithKeyPoints for R {}
+
+impl<R: Ranged> Ranged for WithKeyPoints<R>
+where
+    R::ValueType: PartialOrd + Clone,
+{
+    type ValueType = R::ValueType;
+
+    fn map(&self, value: &Self::ValueType, limit: (i32, i32)) -> i32 {
+        self.0.map(value, limit)
+    }
+
+    fn minor_key_points(&self, max_points: usize, count: usize) -> Vec<Self::ValueType> {
+        self.0.minor_key_points(max_points, count)
+    }
+
+    fn key_points(&self, _max_points: usize) -> Vec<Self::ValueType> {
+        let range = self.0.range();
+        let (low, high) = if range.start <= range.end {
+            (range.start, range.end)
+        } else {
+            (range.end, range.start)
+        };
+        self.1
+            .iter()
+            .filter(|v| low <= **v && **v <= high)
+            .cloned()
+            .collect()
+    }
+
+    fn range(&self) -> Range<Self::ValueType> {
+        self.0.range()
+    }
+
+    fn axis_pixel_range(&self, limit: (i32, i32)) -> Range<i32> {
+        self.0.axis_pixel_range(limit)
+    }
+
+    fn format_label(&self, value: &Self::ValueType) -> Option<String> {
+        self.0.format_label(value)
+    }
+
+    fn axis_breaks(&self, limit: (i32, i32)) -> Vec<i32> {
+        self.0.axis_breaks(limit)
+    }
+
+    fn axis_groups(&self, limit: (i32, i32)) -> Vec<(Range<i32>, String)> {
+        self.0.axis_groups(limit)
+    }
+}
+
+impl<R: ReversibleRanged> ReversibleRanged for WithKeyPoints<R>
+where
+    R::ValueType: PartialOrd + Clone,
+{
+    fn unmap(&self, input: i32, limit: (i32, i32)) -> Option<Self::ValueType> {
+        self.0.unmap(input, limit)
+    }
+}
+
+impl<R: DiscreteRanged> DiscreteRanged for WithKeyPoints<R>
+where
+    <R as Ranged>::ValueType: Eq + PartialOrd + Clone,
+{
+    fn next_value(this: &Self::ValueType) -> Self::ValueType {
+        <R as DiscreteRanged>::next_value(this)
+    }
+
+    fn previous_value(this: &Self::ValueType) -> Self::ValueType {
+        <R as DiscreteRanged>::previous_value(this)
+    }
+}
+
+impl<R: Ranged> AsRangedCoord for WithKeyPoints<R>
+where
+    R::ValueType: PartialOrd + Clone,
+{
+    type CoordDescType = Self;
+    type Value = <Self as Ranged>::ValueType;
+}
+
+/// This axis decorator formats the labels of the axis with the given function, which is used
+/// by the mesh when it isn't given a label formatter, and by the automatically sized label
+/// areas to measure the labels, see `ChartBuilder::set_label_area_auto`.
+pub struct WithLabelFormatter<R: Ranged, F: Fn(&R::ValueType) -> String>(R, F);
+
+/// The trait for the types that can be given a label formatter
+pub trait IntoWithLabelFormatter: AsRangedCoord {
+    /// Format the labels of the axis with the given function
+    ///
+    /// - `formatter`: The function that formats a key point
+    /// - **returns**: The converted range specification
+    fn with_label_formatter<F: Fn(&<Self::CoordDescType as Ranged>::ValueType) -> String>(
+        self,
+        formatter: F,
+    ) -> WithLabelFormatter<Self::CoordDescType, F> {
+        WithLabelFormatter(self.into(), formatter)
+    }
+}
+
+impl<R: AsRangedCoord> IntoWithLabelFormatter for R {}
+
+impl<R: Ranged, F: Fn(&R::ValueType) -> String> Ranged for WithLabelFormatter<R, F> {
+    type ValueType = R::ValueType;
+
+    fn map(&self, value: &Self::ValueType, limit: (i32, i32)) -> i32 {
+        self.0.map(value, limit)
+    }
+
+    fn key_points(&self, max_points: usize) -> Vec<Self::ValueType> {
+        self.0.key_points(max_points)
+    }
+
+    fn minor_key_points(&self, max_points: usize, count: usize) -> Vec<Self::ValueType> {
+        self.0.minor_key_points(max_points, count)
+    }
+
+    fn range(&self) -> Range<Self::ValueType> {
+        self.0.range()
+    }
+
+    fn axis_pixel_range(&self, limit: (i32, i32)) -> Range<i32> {
+        self.0.axis_pixel_range(limit)
+    }
+
+    fn format_label(&self, value: &Self::ValueType) -> Option<String> {
+        Some((self.1)(value))
+    }
+
+    fn axis_breaks(&self, limit: (i32, i32)) -> Vec<i32> {
+        self.0.axis_breaks(limit)
+    }
+
+    fn axis_groups(&self, limit: (i32, i32)) -> Vec<(Range<i32>, String)> {
+        self.0.axis_groups(limit)
+    }
+}
+
+impl<R: ReversibleRanged, F: Fn(&R::ValueType) -> String> ReversibleRanged
+    for WithLabelFormatter<R, F>
+{
+    fn unmap(&self, input: i32, limit: (i32, i32)) -> Option<Self::ValueType> {
+        self.0.unmap(input, limit)
+    }
+}
+
+impl<R: DiscreteRanged, F: Fn(&R::ValueType) -> String> DiscreteRanged for WithLabelFormatter<R, F>
+where
+    <R as Ranged>::ValueType: Eq,
+{
+    fn next_value(this: &Self::ValueType) -> Self::ValueType {
+        <R as DiscreteRanged>::next_value(this)
+    }
+
+    fn previous_value(this: &Self::ValueType) -> Self::ValueType {
+        <R as DiscreteRanged>::previous_value(this)
+    }
+}
+
+impl<R: Ranged, F: Fn(&R::ValueType) -> String> AsRangedCoord for WithLabelFormatter<R, F> {
+    type CoordDescType = Self;
+    type Value = <Self as Ranged>::ValueType;
+}
+
 /// Make a partial axis based on the percentage of visible portion.
 /// We can use `into_partial_axis` to create a partial axis range specification.
 /// But sometimes, we want to directly specify the percentage visible to the user.
@@ -0,0 +1,265 @@
+use super::{CoordTranslate, Ranged};
+use crate::drawing::backend::BackendCoord;
+
+use std::ops::Range;
+
+/// The resolution used to normalize the axis values into the unit cube
+const NORMALIZE_LIMIT: i32 = 1_000_000;
+
+/// The parameters of the projection of a 3D coordinate system, which are turned into a
+/// `ProjectionMatrix` by `into_matrix`
+#[derive(Clone, Debug)]
+pub struct ProjectionMatrixBuilder {
+    /// The rotation around the vertical axis in radians
+    pub yaw: f64,
+    /// The rotation around the horizontal axis in radians, a positive pitch looks down on the
+    /// top of the cube
+    pub pitch: f64,
+    /// The size of the unit cube relative to the smaller dimension of the plotting area
+    pub scale: f64,
+    /// The distance of the camera from the center of the cube, in the size of the cube, for a
+    /// perspective projection. The projection is orthographic if it's `None`.
+    pub perspective: Option<f64>,
+}
+
+impl Default for ProjectionMatrixBuilder {
+    fn default() -> Self {
+        Self {
+            yaw: 0.5,
+            pitch: 0.15,
+            scale: 0.7,
+            perspective: None,
+        }
+    }
+}
+
+impl ProjectionMatrixBuilder {
+    /// Build the projection matrix
+    pub fn into_matrix(self) -> ProjectionMatrix {
+        let (sy, cy) = self.yaw.sin_cos();
+        let (sp, cp) = self.pitch.sin_cos();
+        // The yaw rotates around the Y axis first, then the pitch rotates around the X axis
+        ProjectionMatrix {
+            rows: [
+                [cy, 0.0, -sy],
+                [sy * sp, cp, cy * sp],
+                [sy * cp, -sp, cy * cp],
+            ],
+            scale: self.scale,
+            perspective: self.perspective.map(|d| d.max(f64::EPSILON)),
+        }
+    }
+}
+
+/// The projection of the unit cube onto the screen. The first two rows of the matrix give the
+/// horizontal and vertical screen position and the last one gives the depth, which grows away
+/// from the camera.
+#[derive(Clone, Debug)]
+pub struct ProjectionMatrix {
+    rows: [[f64; 3]; 3],
+    scale: f64,
+    perspective: Option<f64>,
+}
+
+impl Default for ProjectionMatrix {
+    fn default() -> Self {
+        ProjectionMatrixBuilder::default().into_matrix()
+    }
+}
+
+impl ProjectionMatrix {
+    /// Project a point of the unit cube centered at the origin, returning the screen position
+    /// relative to the center of the plotting area in the size of the cube and the depth
+    pub fn project(&self, point: (f64, f64, f64)) -> (f64, f64, f64) {
+        let [x, y, depth] = self.apply(point);
+        match self.perspective {
+            Some(d) => {
+                let factor = d / (d + depth);
+                (x * factor, y * factor, depth)
+            }
+            None => (x, y, depth),
+        }
+    }
+
+    /// Check if the point of the unit cube is in front of the camera, which is always true for
+    /// an orthographic projection
+    pub fn is_visible(&self, point: (f64, f64, f64)) -> bool {
+        match self.perspective {
+            Some(d) => d + self.apply(point)[2] > 0.0,
+            None => true,
+        }
+    }
+
+    fn apply(&self, (x, y, z): (f64, f64, f64)) -> [f64; 3] {
+        let mut ret = [0.0; 3];
+        for (value, row) in ret.iter_mut().zip(self.rows.iter()) {
+            *value = row[0] * x + row[1] * y + row[2] * z;
+        }
+        ret
+    }
+}
+
+/// The 3D cartesian coordinate system described by three ranged axes, which is projected onto
+/// the plotting area. The X axis is horizontal, the Y axis is vertical and the Z axis points
+/// away from the viewer before the projection rotates the cube.
+pub struct Cartesian3d<X: Ranged, Y: Ranged, Z: Ranged> {
+    logic_x: X,
+    logic_y: Y,
+    logic_z: Z,
+    back_x: (i32, i32),
+    back_y: (i32, i32),
+    projection: ProjectionMatrix,
+}
+
+impl<X: Ranged, Y: Ranged, Z: Ranged> Cartesian3d<X, Y, Z> {
+    /// Create a new 3D coordinate system with the default projection
+    pub fn new<IntoX: Into<X>, IntoY: Into<Y>, IntoZ: Into<Z>>(
+        logic_x: IntoX,
+        logic_y: IntoY,
+        logic_z: IntoZ,
+        actual: (Range<i32>, Range<i32>),
+    ) -> Self {
+        Self {
+            logic_x: logic_x.into(),
+            logic_y: logic_y.into(),
+            logic_z: logic_z.into(),
+            back_x: (actual.0.start, actual.0.end),
+            back_y: (actual.1.start, actual.1.end),
+            projection: ProjectionMatrix::default(),
+        }
+    }
+
+    /// Set the projection of the coordinate system
+    pub fn set_projection(&mut self, projection: ProjectionMatrix) {
+        self.projection = projection;
+    }
+
+    /// Get the projection of the coordinate system
+    pub fn projection(&self) -> &ProjectionMatrix {
+        &self.projection
+    }
+
+    /// Get the range of the X axis
+    pub fn get_x_range(&self) -> Range<X::ValueType> {
+        self.logic_x.range()
+    }
+
+    /// Get the range of the Y axis
+    pub fn get_y_range(&self) -> Range<Y::ValueType> {
+        self.logic_y.range()
+    }
+
+    /// Get the range of the Z axis
+    pub fn get_z_range(&self) -> Range<Z::ValueType> {
+        self.logic_z.range()
+    }
+
+    /// Map the coordinate into the unit cube centered at the origin
+    pub fn normalize(&self, from: &(X::ValueType, Y::ValueType, Z::ValueType)) -> (f64, f64, f64) {
+        let limit = (0, NORMALIZE_LIMIT);
+        let unit = |value: i32| f64::from(value) / f64::from(NORMALIZE_LIMIT) - 0.5;
+        (
+            unit(self.logic_x.map(&from.0, limit)),
+            unit(self.logic_y.map(&from.1, limit)),
+            unit(self.logic_z.map(&from.2, limit)),
+        )
+    }
+
+    /// Get the depth of the coordinate after the projection, the larger the depth is, the
+    /// farther the point is from the viewer
+    pub fn projected_depth(&self, from: &(X::ValueType, Y::ValueType, Z::ValueType)) -> f64 {
+        self.projection.project(self.normalize(from)).2
+    }
+
+    /// Get how near the coordinate is to the viewer after the projection, from 0 for the
+    /// farthest possible point of the cube to 1 for the nearest one
+    pub fn projected_nearness(&self, from: &(X::ValueType, Y::ValueType, Z::ValueType)) -> f64 {
+        // The depth of the points in the unit cube is at most half of its diagonal
+        (0.5 - self.projected_depth(from) / 3f64.sqrt()).clamp(0.0, 1.0)
+    }
+
+    /// Check if the coordinate is in front of the camera, the points behind it can't be
+    /// projected with a perspective projection
+    pub fn is_visible(&self, from: &(X::ValueType, Y::ValueType, Z::ValueType)) -> bool {
+        self.projection.is_visible(self.normalize(from))
+    }
+}
+
+impl<X: Ranged, Y: Ranged, Z: Ranged> CoordTranslate for Cartesian3d<X, Y, Z> {
+    type From = (X::ValueType, Y::ValueType, Z::ValueType);
+
+    fn translate(&self, from: &Self::From) -> BackendCoord {
+        let (x, y, _) = self.projection.project(self.normalize(from));
+        let (w, h) = (
+            f64::from((self.back_x.1 - self.back_x.0).abs()),
+            f64::from((self.back_y.1 - self.back_y.0).abs()),
+        );
+        let size = w.min(h) * self.projection.scale;
+        let center = (
+            f64::from(self.back_x.0 + self.back_x.1) / 2.0,
+            f64::from(self.back_y.0 + self.back_y.1) / 2.0,
+        );
+        (
+            (center.0 + x * size).round() as i32,
+            (center.1 - y * size).round() as i32,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::coord::RangedCoordf64;
+
+    #[test]
+    fn test_cartesian3d_projection() {
+        let mut coord: Cartesian3d<RangedCoordf64, RangedCoordf64, RangedCoordf64> =
+            Cartesian3d::new(0.0..1.0, 0.0..1.0, 0.0..1.0, (0..200, 0..200));
+        coord.set_projection(
+            ProjectionMatrixBuilder {
+                yaw: 0.0,
+                pitch: 0.0,
+                scale: 1.0,
+                perspective: None,
+            }
+            .into_matrix(),
+        );
+        assert_eq!(coord.translate(&(0.5, 0.5, 0.0)), (100, 100));
+        assert_eq!(coord.translate(&(1.0, 1.0, 1.0)), (200, 0));
+        assert_eq!(coord.translate(&(0.0, 0.0, 0.0)), (0, 200));
+        assert!(coord.projected_depth(&(0.0, 0.0, 1.0)) > coord.projected_depth(&(0.0, 0.0, 0.0)));
+
+        // The far side of the cube shrinks towards the center with a perspective projection
+        coord.set_projection(
+            ProjectionMatrixBuilder {
+                yaw: 0.0,
+                pitch: 0.0,
+                scale: 1.0,
+                perspective: Some(1.0),
+            }
+            .into_matrix(),
+        );
+        let (near, far) = (
+            coord.translate(&(1.0, 1.0, 0.0)),
+            coord.translate(&(1.0, 1.0, 1.0)),
+        );
+        assert!(near.0 > far.0 && near.1 < far.1);
+        assert!(!coord.projection().is_visible((0.0, 0.0, -2.0)));
+        assert!(coord.is_visible(&(0.0, 0.0, 0.0)));
+        assert!(!coord.is_visible(&(0.5, 0.5, -1.0)));
+        assert_eq!(coord.projected_nearness(&(0.5, 0.5, 0.5)), 0.5);
+        assert!(coord.projected_nearness(&(0.5, 0.5, 0.0)) > 0.5);
+
+        // Looking down on the cube, the top of the near side is closer than the top of the far
+        // side
+        coord.set_projection(
+            ProjectionMatrixBuilder {
+                pitch: 0.5,
+                ..Default::default()
+            }
+            .into_matrix(),
+        );
+        assert!(coord.projected_depth(&(0.5, 1.0, 0.0)) < coord.projected_depth(&(0.5, 1.0, 1.0)));
+        assert!(coord.projected_depth(&(0.5, 1.0, 0.5)) < coord.projected_depth(&(0.5, 0.0, 0.5)));
+    }
+}
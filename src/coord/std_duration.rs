@@ -0,0 +1,157 @@
+use super::{AsRangedCoord, Ranged, RangedCoordf64, ReversibleRanged};
+use std::ops::{Range, RangeInclusive};
+use std::time::Duration;
+
+/// The units of the labels of a duration axis, with their names and their lengths in
+/// nanoseconds, from the smallest to the largest
+const UNITS: [(&str, u64); 5] = [
+    ("ns", 1),
+    ("µs", 1_000),
+    ("ms", 1_000_000),
+    ("s", 1_000_000_000),
+    ("min", 60_000_000_000),
+];
+
+/// The coordinate for `std::time::Duration`, such as the timings of benchmarks. The key points
+/// and the labels use the largest unit from nanoseconds to minutes that the span of the range
+/// covers at least once, so a range of a few milliseconds is labelled in `ms`.
+pub struct RangedStdDuration(Duration, Duration);
+
+impl AsRangedCoord for Range<Duration> {
+    type CoordDescType = RangedStdDuration;
+    type Value = Duration;
+}
+
+impl From<Range<Duration>> for RangedStdDuration {
+    fn from(range: Range<Duration>) -> Self {
+        Self(range.start, range.end)
+    }
+}
+
+impl AsRangedCoord for RangeInclusive<Duration> {
+    type CoordDescType = RangedStdDuration;
+    type Value = Duration;
+}
+
+impl From<RangeInclusive<Duration>> for RangedStdDuration {
+    fn from(range: RangeInclusive<Duration>) -> Self {
+        let (start, end) = range.into_inner();
+        Self(start, end)
+    }
+}
+
+fn as_nanos_f64(value: &Duration) -> f64 {
+    value.as_secs() as f64 * 1e9 + f64::from(value.subsec_nanos())
+}
+
+impl RangedStdDuration {
+    /// Get the name and the length in nanoseconds of the unit used by the labels
+    pub fn unit(&self) -> (&'static str, u64) {
+        let span = (as_nanos_f64(&self.1) - as_nanos_f64(&self.0)).abs();
+        UNITS
+            .iter()
+            .rev()
+            .find(|(_, length)| span >= *length as f64)
+            .cloned()
+            .unwrap_or(UNITS[0])
+    }
+}
+
+impl Ranged for RangedStdDuration {
+    type ValueType = Duration;
+
+    fn map(&self, value: &Duration, limit: (i32, i32)) -> i32 {
+        let (start, end) = (as_nanos_f64(&self.0), as_nanos_f64(&self.1));
+        let actual_length = limit.1 - limit.0;
+        if actual_length == 0 || start == end {
+            return limit.1;
+        }
+        let logic_length = (as_nanos_f64(value) - start) / (end - start);
+        limit.0 + (f64::from(actual_length) * logic_length + 1e-3).floor() as i32
+    }
+
+    fn key_points(&self, max_points: usize) -> Vec<Duration> {
+        let (_, length) = self.unit();
+        let length = length as f64;
+        // The key points are the round numbers of the unit of the labels
+        RangedCoordf64::from(as_nanos_f64(&self.0) / length..as_nanos_f64(&self.1) / length)
+            .key_points(max_points)
+            .into_iter()
+            .filter(|v| *v >= 0.0)
+            .map(|v| Duration::from_nanos((v * length).round() as u64))
+            .collect()
+    }
+
+    fn range(&self) -> Range<Duration> {
+        self.0..self.1
+    }
+
+    fn format_label(&self, value: &Duration) -> Option<String> {
+        let (name, length) = self.unit();
+        let value = as_nanos_f64(value) / length as f64;
+        Some(format!("{}{}", (value * 1e6).round() / 1e6, name))
+    }
+}
+
+impl ReversibleRanged for RangedStdDuration {
+    fn unmap(&self, input: i32, limit: (i32, i32)) -> Option<Duration> {
+        if input < limit.0.min(limit.1) || input > limit.0.max(limit.1) || limit.0 == limit.1 {
+            return None;
+        }
+        let (start, end) = (as_nanos_f64(&self.0), as_nanos_f64(&self.1));
+        let offset = f64::from(input - limit.0) / f64::from(limit.1 - limit.0);
+        Some(Duration::from_nanos(
+            (start + (end - start) * offset).max(0.0).round() as u64,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_std_duration_units() {
+        let coord: RangedStdDuration = (Duration::from_nanos(0)..Duration::from_nanos(800)).into();
+        assert_eq!(coord.unit().0, "ns");
+        let coord: RangedStdDuration = (Duration::from_micros(0)..Duration::from_micros(5)).into();
+        assert_eq!(coord.unit().0, "µs");
+        let coord: RangedStdDuration = (Duration::from_millis(2)..Duration::from_millis(20)).into();
+        assert_eq!(coord.unit().0, "ms");
+        let coord: RangedStdDuration = (Duration::from_secs(0)..Duration::from_secs(59)).into();
+        assert_eq!(coord.unit().0, "s");
+        let coord: RangedStdDuration = (Duration::from_secs(0)..Duration::from_secs(3600)).into();
+        assert_eq!(coord.unit().0, "min");
+    }
+
+    #[test]
+    fn test_std_duration_key_points() {
+        // The range doesn't start at zero
+        let coord: RangedStdDuration =
+            (Duration::from_micros(1500)..Duration::from_micros(4200)).into();
+        let kps = coord.key_points(6);
+        assert_eq!(
+            kps,
+            [1500, 2000, 2500, 3000, 3500, 4000]
+                .iter()
+                .map(|us| Duration::from_micros(*us))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(coord.format_label(&kps[1]).unwrap(), "2ms");
+        assert_eq!(coord.format_label(&kps[2]).unwrap(), "2.5ms");
+
+        assert_eq!(coord.map(&Duration::from_micros(1500), (0, 270)), 0);
+        assert_eq!(coord.map(&Duration::from_micros(2500), (0, 270)), 100);
+        assert_eq!(
+            coord.unmap(100, (0, 270)),
+            Some(Duration::from_micros(2500))
+        );
+
+        let coord: RangedStdDuration = (Duration::from_secs(0)..Duration::from_secs(600)).into();
+        assert_eq!(coord.key_points(6).len(), 6);
+        assert_eq!(
+            coord.format_label(&Duration::from_secs(120)).unwrap(),
+            "2min"
+        );
+    }
+}
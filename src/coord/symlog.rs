@@ -0,0 +1,281 @@
+use super::{AsRangedCoord, Ranged, RangedCoordf64, ReversibleRanged};
+use std::ops::{Range, RangeInclusive};
+
+/// The range of a symmetric log scaled axis, which is created with
+/// `IntoSymlogRange::symlog_scale`
+pub struct SymlogRange {
+    range: Range<f64>,
+    threshold: f64,
+}
+
+/// The trait for the ranges that can be turned into a symmetric log scaled axis
+pub trait IntoSymlogRange {
+    /// Make the axis linear inside `[-threshold, threshold]` and logarithmic outside of it,
+    /// so that it can show the data that crosses zero over several decades
+    fn symlog_scale(self, threshold: f64) -> SymlogRange;
+}
+
+impl IntoSymlogRange for Range<f64> {
+    fn symlog_scale(self, threshold: f64) -> SymlogRange {
+        SymlogRange {
+            range: self,
+            threshold,
+        }
+    }
+}
+
+impl IntoSymlogRange for RangeInclusive<f64> {
+    fn symlog_scale(self, threshold: f64) -> SymlogRange {
+        let (start, end) = self.into_inner();
+        (start..end).symlog_scale(threshold)
+    }
+}
+
+impl AsRangedCoord for SymlogRange {
+    type CoordDescType = SymlogCoord;
+    type Value = f64;
+
+    fn check_range(&self) -> Result<(), String> {
+        if !(self.threshold > 0.0 && self.threshold.is_finite()) {
+            return Err(format!(
+                "the linear threshold of a symlog axis must be positive, but got {}",
+                self.threshold
+            ));
+        }
+        if !(self.range.start.is_finite() && self.range.end.is_finite())
+            || self.range.start == self.range.end
+        {
+            return Err(format!(
+                "a symlog axis needs a finite non-empty range, but got {}..{}",
+                self.range.start, self.range.end
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl From<SymlogRange> for SymlogCoord {
+    fn from(range: SymlogRange) -> SymlogCoord {
+        let threshold = range.threshold;
+        SymlogCoord {
+            linear: (transform(range.range.start, threshold)
+                ..transform(range.range.end, threshold))
+                .into(),
+            logic: range.range,
+            threshold,
+        }
+    }
+}
+
+/// The piecewise mapping of the axis, which is linear from `-1` to `1` for the values inside
+/// the threshold and grows by one for each decade outside of it
+fn transform(value: f64, threshold: f64) -> f64 {
+    let scaled = value.abs() / threshold;
+    let magnitude = if scaled <= 1.0 {
+        scaled
+    } else {
+        1.0 + scaled.log10()
+    };
+    magnitude.copysign(value)
+}
+
+/// The inverse of `transform`
+fn inverse_transform(value: f64, threshold: f64) -> f64 {
+    let magnitude = value.abs();
+    let scaled = if magnitude <= 1.0 {
+        magnitude
+    } else {
+        10f64.powf(magnitude - 1.0)
+    };
+    (scaled * threshold).copysign(value)
+}
+
+/// A symmetric log scaled coordinate axis, which is linear around zero and logarithmic for
+/// the larger positive and negative values
+pub struct SymlogCoord {
+    linear: RangedCoordf64,
+    logic: Range<f64>,
+    threshold: f64,
+}
+
+impl SymlogCoord {
+    /// Get the linear threshold of the axis
+    pub fn threshold(&self) -> f64 {
+        self.threshold
+    }
+}
+
+impl Ranged for SymlogCoord {
+    type ValueType = f64;
+
+    fn map(&self, value: &f64, limit: (i32, i32)) -> i32 {
+        self.linear.map(&transform(*value, self.threshold), limit)
+    }
+
+    fn key_points(&self, max_points: usize) -> Vec<f64> {
+        let (low, high) = (
+            self.logic.start.min(self.logic.end),
+            self.logic.start.max(self.logic.end),
+        );
+        if max_points == 0 {
+            return vec![];
+        }
+
+        // The decades at or beyond the threshold within the range, on each side of zero
+        let first = (self.threshold.log10() - 1e-9).ceil() as i32;
+        let decades = |from: f64, to: f64| {
+            let start = if from > 0.0 {
+                first.max((from.log10() - 1e-9).ceil() as i32)
+            } else {
+                first
+            };
+            if to < 10f64.powi(start) {
+                vec![]
+            } else {
+                (start..=(to.log10() + 1e-9).floor() as i32).collect::<Vec<_>>()
+            }
+        };
+        let (negative, positive) = (decades(-high, -low), decades(low, high));
+        let has_zero = low <= 0.0 && high >= 0.0;
+
+        if negative.is_empty() && positive.is_empty() {
+            return RangedCoordf64::from(low..high).key_points(max_points);
+        }
+
+        // The decades are counted from zero when it's in the range, and from the decade nearest
+        // to zero otherwise, and every `step`-th of them is picked on both sides, so the key
+        // points are symmetric around zero. The smallest step which fits in `max_points` is used.
+        let base = if has_zero {
+            first - 1
+        } else {
+            negative
+                .iter()
+                .chain(positive.iter())
+                .copied()
+                .min()
+                .unwrap_or(first)
+        };
+        let picked = |exps: &[i32], step: usize| {
+            exps.iter()
+                .filter(|exp| ((*exp - base) as usize).is_multiple_of(step))
+                .count()
+        };
+        let mut step = 1;
+        while picked(&negative, step) + picked(&positive, step) + has_zero as usize > max_points {
+            step += 1;
+        }
+        let pick = |exps: Vec<i32>| {
+            exps.into_iter()
+                .filter(|exp| ((exp - base) as usize).is_multiple_of(step))
+                .map(|exp| 10f64.powi(exp))
+                .collect::<Vec<_>>()
+        };
+
+        let mut ret: Vec<f64> = pick(negative).into_iter().rev().map(|v| -v).collect();
+        if has_zero {
+            ret.push(0.0);
+        }
+        ret.extend(pick(positive));
+        ret
+    }
+
+    fn range(&self) -> Range<f64> {
+        self.logic.clone()
+    }
+
+    fn format_label(&self, value: &f64) -> Option<String> {
+        Some(format!("{:?}", value))
+    }
+}
+
+impl ReversibleRanged for SymlogCoord {
+    fn unmap(&self, input: i32, limit: (i32, i32)) -> Option<f64> {
+        self.linear
+            .unmap(input, limit)
+            .map(|v| inverse_transform(v, self.threshold))
+    }
+}
+
+/// Make a label formatter for a symlog axis, which prints the powers of ten as `10^n` or
+/// `-10^n` and the other values as plain numbers
+pub fn symlog_label_formatter() -> impl Fn(&f64) -> String {
+    |value| {
+        let exp = value.abs().log10();
+        if *value != 0.0 && (exp - exp.round()).abs() < 1e-9 {
+            let sign = if *value < 0.0 { "-" } else { "" };
+            format!("{}10^{}", sign, exp.round() as i32)
+        } else {
+            format!("{}", value)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_symlog_mapping() {
+        let coord: SymlogCoord = (-100.0..100.0).symlog_scale(1.0).into();
+        // Each decade takes the same room as the linear part on each side of zero
+        assert_eq!(coord.map(&0.0, (0, 600)), 300);
+        assert_eq!(coord.map(&1.0, (0, 600)), 400);
+        assert_eq!(coord.map(&0.5, (0, 600)), 350);
+        assert_eq!(coord.map(&10.0, (0, 600)), 500);
+        assert_eq!(coord.map(&-100.0, (0, 600)), 0);
+
+        for &value in &[-50.0, -1.0, 0.0, 0.25, 10.0, 100.0] {
+            let pixel = coord.map(&value, (0, 600000));
+            let back = coord.unmap(pixel, (0, 600000)).unwrap();
+            assert!((back - value).abs() <= value.abs() * 1e-3 + 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_symlog_key_points() {
+        let coord: SymlogCoord = (-1000.0..100.0).symlog_scale(1.0).into();
+        assert_eq!(
+            coord.key_points(10),
+            [-1000.0, -100.0, -10.0, -1.0, 0.0, 1.0, 10.0, 100.0]
+        );
+        assert_eq!(coord.key_points(4), [-1000.0, -10.0, 0.0, 10.0]);
+
+        let coord: SymlogCoord = (-10000.0..10000.0).symlog_scale(1.0).into();
+        for max_points in 1..=8 {
+            let points = coord.key_points(max_points);
+            assert!(points.len() <= max_points);
+            assert!(points.contains(&0.0));
+            let mirrored: Vec<_> = points.iter().rev().map(|v| -v).collect();
+            assert_eq!(points, mirrored);
+        }
+        // Without zero, the key points start from the decade nearest to zero in the range
+        let coord: SymlogCoord = (10.0..10000.0).symlog_scale(1.0).into();
+        assert_eq!(coord.key_points(10), [10.0, 100.0, 1000.0, 10000.0]);
+        for max_points in 1..=8 {
+            let points = coord.key_points(max_points);
+            assert!(!points.is_empty() && points.len() <= max_points);
+            assert_eq!(points[0], 10.0);
+        }
+
+        let coord: SymlogCoord = (0.0..1000.0).symlog_scale(5.0).into();
+        assert_eq!(coord.key_points(10), [0.0, 10.0, 100.0, 1000.0]);
+
+        let format = symlog_label_formatter();
+        assert_eq!(format(&-100.0), "-10^2");
+        assert_eq!(format(&0.0), "0");
+        assert_eq!(format(&2.5), "2.5");
+    }
+
+    #[test]
+    fn test_symlog_range_check() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |_| {});
+        let mut builder = ChartBuilder::on(&drawing_area);
+        assert!(builder
+            .build_ranged(0.0..1.0, (-10.0..10.0).symlog_scale(0.0))
+            .is_err());
+        assert!(builder
+            .build_ranged((-10.0..10.0).symlog_scale(1.0), 0.0..1.0)
+            .is_ok());
+    }
+}
@@ -1,17 +1,22 @@
 use std::cmp::{Ordering, PartialOrd};
 use std::iter::IntoIterator;
-use std::ops::Range;
+use std::ops::{Add, Range, Sub};
+use std::time::Duration;
 
-use num_traits::{One, Zero};
+use num_traits::{Bounded, NumCast, One, ToPrimitive, Zero};
 
-/// Build a range that fits the data
-pub fn fitting_range<'a, T: 'a, I: IntoIterator<Item = &'a T>>(iter: I) -> Range<T>
-where
-    T: Zero + One + PartialOrd + Clone,
-{
+/// Find the smallest and the largest values, which are `None` if there's no value. The values
+/// that can't be compared with themselves, like NaN, are left out.
+fn find_bounds<'a, T: PartialOrd + Clone + 'a, I: IntoIterator<Item = &'a T>>(
+    iter: I,
+) -> (Option<T>, Option<T>) {
     let (mut lb, mut ub) = (None, None);
 
     for value in iter.into_iter() {
+        if value.partial_cmp(value) != Some(Ordering::Equal) {
+            continue;
+        }
+
         match lb
             .as_ref()
             .map_or(Some(Ordering::Greater), |lbv: &T| lbv.partial_cmp(value))
@@ -29,5 +34,145 @@ where
         }
     }
 
+    (lb, ub)
+}
+
+/// Build a range that fits the data, which is `0..1` if there's no value
+pub fn fitting_range<'a, T: 'a, I: IntoIterator<Item = &'a T>>(iter: I) -> Range<T>
+where
+    T: Zero + One + PartialOrd + Clone,
+{
+    let (lb, ub) = find_bounds(iter);
+
     lb.unwrap_or(Zero::zero())..ub.unwrap_or(One::one())
 }
+
+/// Build a range that fits the data of any comparable type, the NaN values are left out
+///
+/// - `iter`: The data
+/// - **returns**: The range from the smallest to the largest value, or an error message if
+///   there isn't any value to fit
+pub fn try_fitting_range<'a, T: PartialOrd + Clone + 'a, I: IntoIterator<Item = &'a T>>(
+    iter: I,
+) -> Result<Range<T>, String> {
+    match find_bounds(iter) {
+        (Some(lb), Some(ub)) => Ok(lb..ub),
+        _ => Err("can't fit a range on the data without any comparable value".to_string()),
+    }
+}
+
+/// The methods that adjust a range fitted on the data, so that the data isn't drawn right at
+/// the edges of the plotting area
+pub trait PaddedRange<T>: Sized {
+    /// Expand both ends of the range by a fraction of its length. For the integer types, the
+    /// expansion is rounded up, and it stops at the bounds of the type.
+    ///
+    /// - `fraction`: The fraction of the length to add on each side, `0.05` for 5%
+    /// - **returns**: The expanded range
+    fn padded(self, fraction: f64) -> Range<T>
+    where
+        T: ToPrimitive + NumCast + Bounded;
+
+    /// Expand both ends of the range by an amount in the unit of the data
+    ///
+    /// - `amount`: The amount to add on each side
+    /// - **returns**: The expanded range
+    fn padded_by(self, amount: T) -> Range<T>
+    where
+        T: Add<Output = T> + Sub<Output = T> + Clone;
+
+    /// Expand the range so that it includes the value, for example to start the axis at zero
+    ///
+    /// - `value`: The value to include
+    /// - **returns**: The expanded range
+    fn include(self, value: T) -> Range<T>
+    where
+        T: PartialOrd;
+}
+
+impl<T> PaddedRange<T> for Range<T> {
+    fn padded(self, fraction: f64) -> Range<T>
+    where
+        T: ToPrimitive + NumCast + Bounded,
+    {
+        let (start, end) = match (self.start.to_f64(), self.end.to_f64()) {
+            (Some(start), Some(end)) => (start, end),
+            _ => return self,
+        };
+        let mut pad = (end - start) * fraction;
+        // The integer types can't hold a part of the padding
+        let is_integer = <T as NumCast>::from(0.5).and_then(|v| v.to_f64()) == Some(0.0);
+        if is_integer {
+            pad = if pad < 0.0 { pad.floor() } else { pad.ceil() };
+        }
+        let cast = |value: f64, bound: fn() -> T| <T as NumCast>::from(value).unwrap_or_else(bound);
+        let start = cast(start - pad, T::min_value);
+        let end = cast(end + pad, T::max_value);
+        start..end
+    }
+
+    fn padded_by(self, amount: T) -> Range<T>
+    where
+        T: Add<Output = T> + Sub<Output = T> + Clone,
+    {
+        self.start - amount.clone()..self.end + amount
+    }
+
+    fn include(self, value: T) -> Range<T>
+    where
+        T: PartialOrd,
+    {
+        if value < self.start {
+            value..self.end
+        } else if value > self.end {
+            self.start..value
+        } else {
+            self
+        }
+    }
+}
+
+/// Build a range that fits the durations, such as the timings of a benchmark
+pub fn fitting_duration_range<'a, I: IntoIterator<Item = &'a Duration>>(
+    iter: I,
+) -> Range<Duration> {
+    let (lb, ub) = find_bounds(iter);
+
+    lb.unwrap_or_default()..ub.unwrap_or_else(|| Duration::from_secs(1))
+}
+
+#[cfg(test)]
+#[test]
+fn test_try_fitting_range() {
+    let data = [3.0, std::f64::NAN, -1.5, 2.0];
+    assert_eq!(try_fitting_range(&data), Ok(-1.5..3.0));
+    assert_eq!(fitting_range(&[std::f64::NAN, 1.0, 4.0]), 1.0..4.0);
+    assert!(try_fitting_range::<f64, _>(&[]).is_err());
+    assert!(try_fitting_range(&[std::f64::NAN]).is_err());
+    assert_eq!(try_fitting_range(&["b", "a", "c"]), Ok("a".."c"));
+
+    let range = try_fitting_range(&data).unwrap();
+    assert_eq!(range.clone().padded(0.1), -1.95..3.45);
+    assert_eq!(range.clone().padded_by(0.5), -2.0..3.5);
+    assert_eq!(range.include(5.0), -1.5..5.0);
+    assert_eq!((2u32..10).include(0), 0..10);
+    // The integer padding is rounded up and the unsigned ranges stop at zero
+    assert_eq!((0u32..10).padded(0.05), 0..11);
+    assert_eq!((-10i32..10).padded(0.01), -11..11);
+    assert_eq!(
+        (Duration::from_secs(5)..Duration::from_secs(8)).padded_by(Duration::from_secs(1)),
+        Duration::from_secs(4)..Duration::from_secs(9)
+    );
+}
+
+#[cfg(test)]
+#[test]
+fn test_fitting_duration_range() {
+    let timings = [Duration::from_millis(12), Duration::from_millis(3)];
+    let range = fitting_duration_range(timings.iter());
+    assert_eq!(range, Duration::from_millis(3)..Duration::from_millis(12));
+    assert_eq!(
+        fitting_duration_range(&[]),
+        Duration::from_secs(0)..Duration::from_secs(1)
+    );
+}
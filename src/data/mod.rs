@@ -4,5 +4,7 @@ Such as, down-sampling, etc.
 */
 
 mod data_range;
+mod quartiles;
 
-pub use data_range::fitting_range;
+pub use data_range::{fitting_duration_range, fitting_range, try_fitting_range, PaddedRange};
+pub use quartiles::Quartiles;
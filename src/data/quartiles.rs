@@ -0,0 +1,128 @@
+/// The quartiles of a data set, as drawn by a boxplot
+#[derive(Clone, Debug, PartialEq)]
+pub struct Quartiles {
+    values: [f64; 5],
+    outliers: Vec<f64>,
+}
+
+/// Get the value at the given fraction of the sorted samples, using linear interpolation
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    let pos = (sorted.len() - 1) as f64 * p;
+    let (lo, hi) = (pos.floor() as usize, pos.ceil() as usize);
+    sorted[lo] + (sorted[hi] - sorted[lo]) * (pos - lo as f64)
+}
+
+fn sorted_samples(samples: &[f64]) -> Vec<f64> {
+    let mut sorted: Vec<f64> = samples.iter().cloned().filter(|x| !x.is_nan()).collect();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    sorted
+}
+
+impl Quartiles {
+    /// Compute the five-number summary of the samples: the minimum, the lower quartile, the
+    /// median, the upper quartile and the maximum. NaN samples are ignored.
+    ///
+    /// If there are no samples, all the values are NaN.
+    pub fn new(samples: &[f64]) -> Self {
+        let sorted = sorted_samples(samples);
+        if sorted.is_empty() {
+            return Self::from_values([f64::NAN; 5]);
+        }
+        Self::from_values([
+            sorted[0],
+            percentile(&sorted, 0.25),
+            percentile(&sorted, 0.5),
+            percentile(&sorted, 0.75),
+            sorted[sorted.len() - 1],
+        ])
+    }
+
+    /// Compute the quartiles of the samples, where the whiskers only extend to the most extreme
+    /// samples within `k` times the interquartile range of the box. The samples beyond that are
+    /// the outliers. Tukey's original definition uses `k = 1.5`, and a negative or NaN `k` is
+    /// taken as zero. The whiskers end at the box if there is no sample between the fences.
+    pub fn with_fences(samples: &[f64], k: f64) -> Self {
+        let mut ret = Self::new(samples);
+        let [_, q1, _, q3, _] = ret.values;
+        if q1.is_nan() {
+            return ret;
+        }
+        let k = if k > 0.0 { k } else { 0.0 };
+        let (lower, upper) = (q1 - k * (q3 - q1), q3 + k * (q3 - q1));
+
+        let sorted = sorted_samples(samples);
+        let inside: Vec<_> = sorted
+            .iter()
+            .filter(|&&x| x >= lower && x <= upper)
+            .collect();
+        ret.values[0] = inside.first().map_or(q1, |x| **x);
+        ret.values[4] = inside.last().map_or(q3, |x| **x);
+        ret.outliers = sorted
+            .iter()
+            .cloned()
+            .filter(|&x| x < lower || x > upper)
+            .collect();
+        ret
+    }
+
+    /// Create the quartiles from precomputed values, which are the minimum, the lower quartile,
+    /// the median, the upper quartile and the maximum
+    pub fn from_values(values: [f64; 5]) -> Self {
+        Self {
+            values,
+            outliers: vec![],
+        }
+    }
+
+    /// Set the outliers, which are drawn beyond the whiskers
+    pub fn with_outliers(mut self, outliers: Vec<f64>) -> Self {
+        self.outliers = outliers;
+        self
+    }
+
+    /// Get the minimum, the lower quartile, the median, the upper quartile and the maximum
+    pub fn values(&self) -> [f64; 5] {
+        self.values
+    }
+
+    /// Get the outliers
+    pub fn outliers(&self) -> &[f64] {
+        &self.outliers
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_quartiles() {
+        let q = Quartiles::new(&[7.0, 1.0, 3.0, 5.0, f64::NAN, 9.0]);
+        assert_eq!(q.values(), [1.0, 3.0, 5.0, 7.0, 9.0]);
+        assert!(q.outliers().is_empty());
+
+        let q = Quartiles::new(&[1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(q.values(), [1.0, 1.75, 2.5, 3.25, 4.0]);
+
+        let q = Quartiles::with_fences(&[1.0, 2.0, 3.0, 4.0, 5.0, 100.0], 1.5);
+        assert_eq!(q.values()[4], 5.0);
+        assert_eq!(q.outliers(), [100.0]);
+
+        assert!(Quartiles::new(&[]).values()[2].is_nan());
+    }
+
+    #[test]
+    fn test_quartiles_fences() {
+        let samples = [1.0, 2.0, 3.0, 4.0];
+        for &k in [-1.0, f64::NAN, 0.0].iter() {
+            let q = Quartiles::with_fences(&samples, k);
+            assert_eq!(q.values(), [2.0, 1.75, 2.5, 3.25, 3.0]);
+            assert_eq!(q.outliers(), [1.0, 4.0]);
+        }
+
+        // The fences of the interpolated quartiles have no sample between them
+        let q = Quartiles::with_fences(&[1.0, 4.0], 0.0);
+        assert_eq!(q.values(), [1.75, 1.75, 2.5, 3.25, 3.25]);
+        assert_eq!(q.outliers(), [1.0, 4.0]);
+    }
+}
@@ -1,8 +1,10 @@
 /// The abstraction of a drawing area
-use super::backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+use super::backend::{
+    AnimatedBackend, BackendClip, BackendCoord, DrawingBackend, DrawingErrorKind,
+};
 use crate::coord::{CoordTranslate, MeshLine, Ranged, RangedCoord, Shift};
-use crate::element::{Drawable, PointCollection};
-use crate::style::{Color, FontDesc, SizeDesc, TextStyle};
+use crate::element::{Drawable, MultiLineText, PointCollection};
+use crate::style::{Color, FontDesc, HPos, SizeDesc, TextStyle};
 
 use std::borrow::Borrow;
 use std::cell::RefCell;
@@ -105,6 +107,18 @@ impl Rect {
     }
 }
 
+/// How the elements that fall outside of a drawing area are drawn
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OutOfRange {
+    /// Leave out the parts outside of the area. The paths, the polygons and the rectangles are
+    /// cut at the edges, and the other elements are left out unless they are completely inside.
+    Clip,
+    /// Pin the points outside of the area to its edges, which is the default
+    Clamp,
+    /// Draw the elements as they are, even over the outside of the area
+    Draw,
+}
+
 /// The abstraction of a drawing area. Plotters uses drawing area as the fundamental abstraction for the
 /// high level drawing API. The major functionality provided by the drawing area is
 ///     1. Layout specification - Split the parent drawing area into sub-drawing-areas
@@ -126,6 +140,45 @@ impl<DB: DrawingBackend, CT: CoordTranslate + Clone> Clone for DrawingArea<DB, C
     }
 }
 
+/// The reason why a chart can't be laid out or drawn. The failures of the backend while a chart
+/// is drawn are `DrawingAreaErrorKind::BackendError` instead.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ChartLayoutError {
+    /// The margins, the caption and the label areas of the chart need more space than the
+    /// drawing area has, which leaves no pixel for the plotting area. Both sizes are the width
+    /// and the height in pixels.
+    InsufficientSpace {
+        needed: (u32, u32),
+        available: (u32, u32),
+    },
+    /// The range of the named axis maps all of its values onto the same pixel, such as `0..0`
+    EmptyRange(&'static str),
+    /// The drawing area the chart is on doesn't have any pixel
+    DegenerateArea,
+    /// The area an element of the chart is drawn in isn't reserved by the chart, such as the
+    /// area of a colorbar
+    MissingArea(&'static str),
+}
+
+impl std::fmt::Display for ChartLayoutError {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+        match self {
+            ChartLayoutError::InsufficientSpace { needed, available } => write!(
+                fmt,
+                "the chart needs at least {}x{} pixels, but the drawing area is {}x{}",
+                needed.0, needed.1, available.0, available.1
+            ),
+            ChartLayoutError::EmptyRange(axis) => {
+                write!(fmt, "the range of the {} axis is empty", axis)
+            }
+            ChartLayoutError::DegenerateArea => write!(fmt, "the drawing area has no pixel"),
+            ChartLayoutError::MissingArea(area) => {
+                write!(fmt, "the chart doesn't reserve the area of the {}", area)
+            }
+        }
+    }
+}
+
 /// The error description of any drawing area API
 #[derive(Debug)]
 pub enum DrawingAreaErrorKind<E: Error + Send + Sync> {
@@ -136,7 +189,9 @@ pub enum DrawingAreaErrorKind<E: Error + Send + Sync> {
     /// drawing operation
     SharingError,
     /// The error caused by invalid layout
-    LayoutError,
+    LayoutError(ChartLayoutError),
+    /// The error caused by the range of an axis that can't be mapped
+    InvalidRange(String),
 }
 
 impl<E: Error + Send + Sync> std::fmt::Display for DrawingAreaErrorKind<E> {
@@ -146,7 +201,8 @@ impl<E: Error + Send + Sync> std::fmt::Display for DrawingAreaErrorKind<E> {
             DrawingAreaErrorKind::SharingError => {
                 write!(fmt, "Multiple backend operation in progress")
             }
-            DrawingAreaErrorKind::LayoutError => write!(fmt, "Bad layout"),
+            DrawingAreaErrorKind::LayoutError(e) => write!(fmt, "Bad layout: {}", e),
+            DrawingAreaErrorKind::InvalidRange(msg) => write!(fmt, "Invalid range: {}", msg),
         }
     }
 }
@@ -197,6 +253,26 @@ impl<DB: DrawingBackend, X: Ranged, Y: Ranged> DrawingArea<DB, RangedCoord<X, Y>
         })
     }
 
+    /// Draw the minor mesh on a area, with the given number of minor key points between two
+    /// key points of the X and the Y axis
+    pub fn draw_minor_mesh<DrawFunc>(
+        &self,
+        mut draw_func: DrawFunc,
+        y_count_max: usize,
+        x_count_max: usize,
+        minor_count: (usize, usize),
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        DrawFunc: FnMut(&mut DB, MeshLine<X, Y>) -> Result<(), DrawingErrorKind<DB::ErrorType>>,
+    {
+        self.backend_ops(move |b| {
+            self.coord
+                .draw_minor_mesh(y_count_max, x_count_max, minor_count, |line| {
+                    draw_func(b, line)
+                })
+        })
+    }
+
     /// Get the range of X of the guest coordinate for current drawing area
     pub fn get_x_range(&self) -> Range<X::ValueType> {
         self.coord.get_x_range()
@@ -285,6 +361,26 @@ impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<DB, CT> {
         })
     }
 
+    /// Clip everything drawn on the backend to the drawing area, until `reset_clip` is called.
+    /// Unlike `OutOfRange::Clip`, which cuts the elements at the area, this cuts the pixels, so
+    /// the markers and the wide lines are cut exactly at the edges. The backends which can't
+    /// clip draw everything.
+    ///
+    /// - `clip_text`: If the texts are clipped as well, otherwise they may overflow the area
+    pub fn clip_to_area(&self, clip_text: bool) -> Result<(), DrawingAreaError<DB>> {
+        let clip = BackendClip {
+            upper_left: (self.rect.x0, self.rect.y0),
+            bottom_right: (self.rect.x1, self.rect.y1),
+            clip_text,
+        };
+        self.backend_ops(|backend| backend.set_clip(Some(clip)))
+    }
+
+    /// Stop clipping the drawing on the backend, which was started by `clip_to_area`
+    pub fn reset_clip(&self) -> Result<(), DrawingAreaError<DB>> {
+        self.backend_ops(|backend| backend.set_clip(None))
+    }
+
     /// Draw a single pixel
     pub fn draw_pixel<ColorType: Color>(
         &self,
@@ -300,17 +396,50 @@ impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<DB, CT> {
         self.backend_ops(|b| b.present())
     }
 
+    /// Present the pending changes as a frame of an animation, which is displayed for the given
+    /// time instead of the frame delay of the backend
+    /// - `delay`: How long the frame is displayed in milliseconds
+    pub fn present_frame(&self, delay: u32) -> Result<(), DrawingAreaError<DB>>
+    where
+        DB: AnimatedBackend,
+    {
+        self.backend_ops(|b| b.present_frame(delay))
+    }
+
     /// Draw an high-level element
     pub fn draw<'a, E>(&self, element: &'a E) -> Result<(), DrawingAreaError<DB>>
     where
         &'a E: PointCollection<'a, CT::From>,
         E: Drawable<DB>,
     {
-        let backend_coords = element.point_iter().into_iter().map(|p| {
-            let b = p.borrow();
-            self.rect.truncate(self.coord.translate(b))
+        self.draw_with_policy(element, OutOfRange::Clamp)
+    }
+
+    /// Draw an high-level element, with the given handling of its parts outside of the area
+    /// - `element`: The element to draw
+    /// - `policy`: How the parts outside of the area are drawn
+    pub fn draw_with_policy<'a, E>(
+        &self,
+        element: &'a E,
+        policy: OutOfRange,
+    ) -> Result<(), DrawingAreaError<DB>>
+    where
+        &'a E: PointCollection<'a, CT::From>,
+        E: Drawable<DB>,
+    {
+        let backend_coords = element.point_iter().into_iter().map(move |p| {
+            let b = self.coord.translate(p.borrow());
+            match policy {
+                OutOfRange::Clamp => self.rect.truncate(b),
+                _ => b,
+            }
         });
-        self.backend_ops(move |b| element.draw(backend_coords, b, self.dim_in_pixel()))
+        let dim = self.dim_in_pixel();
+        let clip = ((self.rect.x0, self.rect.y0), (self.rect.x1, self.rect.y1));
+        self.backend_ops(move |b| match policy {
+            OutOfRange::Clip => element.draw_clipped(backend_coords, b, dim, clip),
+            _ => element.draw(backend_coords, b, dim),
+        })
     }
 
     /// Map coordinate to the backend coordinate
@@ -325,12 +454,20 @@ impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<DB, CT> {
     ///
     /// - `text`: The text we want to estimate
     /// - `font`: The font spec in which we want to draw the text
-    /// - **return**: The size of the text if drawn on this area
+    /// - **return**: The size of the text if drawn on this area, which is the size of the
+    ///   whole block for the text of several lines, drawn as a `MultiLineText`
     pub fn estimate_text_size(
         &self,
         text: &str,
         font: &FontDesc,
     ) -> Result<(u32, u32), DrawingAreaError<DB>> {
+        if text.contains('\n') {
+            let lines = MultiLineText::<_, &str>::from_str(text, (0, 0), font.clone(), 0);
+            let (w, h) = lines
+                .estimate_dimension()
+                .map_err(|e| DrawingAreaErrorKind::BackendError(DrawingErrorKind::FontError(e)))?;
+            return Ok((w as u32, h as u32));
+        }
         self.backend_ops(move |b| b.estimate_text_size(text, font))
     }
 }
@@ -472,6 +609,15 @@ impl<DB: DrawingBackend> DrawingArea<DB, Shift> {
     ) -> Result<Self, DrawingAreaError<DB>> {
         let style = style.into();
 
+        // The caption of several lines, such as a title and a subtitle, is centered line by line
+        let lines = if text.contains('\n') {
+            let mut lines = MultiLineText::from_str(text, (0, 0), &style, 0);
+            lines.set_alignment(HPos::Center);
+            Some(lines)
+        } else {
+            None
+        };
+
         let (text_w, text_h) = self.estimate_text_size(text, &style.font)?;
 
         let x_padding = if self.rect.x1 - self.rect.x0 > text_w as i32 {
@@ -482,14 +628,19 @@ impl<DB: DrawingBackend> DrawingArea<DB, Shift> {
 
         let y_padding = (text_h / 2).min(5) as i32;
 
-        self.backend_ops(|b| {
-            b.draw_text(
-                text,
-                &style.font,
-                (self.rect.x0 + x_padding, self.rect.y0 + y_padding),
-                &style.color,
-            )
-        })?;
+        if let Some(mut lines) = lines {
+            lines.relocate((x_padding, y_padding));
+            self.draw(&lines)?;
+        } else {
+            self.backend_ops(|b| {
+                b.draw_text(
+                    text,
+                    &style.font,
+                    (self.rect.x0 + x_padding, self.rect.y0 + y_padding),
+                    &style.color,
+                )
+            })?;
+        }
 
         Ok(Self {
             rect: Rect {
@@ -525,6 +676,15 @@ impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<DB, CT> {
     pub fn into_coord_spec(self) -> CT {
         self.coord
     }
+
+    /// Get the coordinate system attached to the drawing area
+    pub fn as_coord_spec(&self) -> &CT {
+        &self.coord
+    }
+
+    pub(crate) fn as_coord_spec_mut(&mut self) -> &mut CT {
+        &mut self.coord
+    }
 }
 
 #[cfg(test)]
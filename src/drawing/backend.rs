@@ -1,4 +1,4 @@
-use crate::style::{Color, FontDesc, FontError, RGBAColor, ShapeStyle};
+use crate::style::{Color, FontDesc, FontError, Gradient, Pattern, RGBAColor, ShapeStyle};
 use std::error::Error;
 
 /// A coordinate in the image
@@ -27,6 +27,92 @@ impl<E: Error + Send + Sync> std::fmt::Display for DrawingErrorKind<E> {
 
 impl<E: Error + Send + Sync> Error for DrawingErrorKind<E> {}
 
+/// The rectangle a backend clips the drawing to
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BackendClip {
+    /// The upper left corner of the rectangle, which is inside of it
+    pub upper_left: BackendCoord,
+    /// The bottom right corner of the rectangle, which is just outside of it like the end of
+    /// a range
+    pub bottom_right: BackendCoord,
+    /// If the texts are clipped as well, otherwise they may overflow the rectangle
+    pub clip_text: bool,
+}
+
+impl BackendClip {
+    /// Check if the pixel is inside of the clipping rectangle
+    pub fn contains(&self, (x, y): BackendCoord) -> bool {
+        self.upper_left.0 <= x
+            && x < self.bottom_right.0
+            && self.upper_left.1 <= y
+            && y < self.bottom_right.1
+    }
+}
+
+/// The drop shadow drawn beneath an element
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BackendShadow {
+    /// The offset of the shadow from the element
+    pub offset: BackendCoord,
+    /// The distance in pixels the edges of the shadow are blurred over
+    pub blur_radius: u32,
+    /// The color of the shadow, whose alpha is the opacity of the solid parts of the shadow
+    pub color: RGBAColor,
+}
+
+/// The layout of the pixels of a bitmap
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PixelFormat {
+    /// Three bytes per pixel, which are the red, the green and the blue
+    Rgb,
+    /// Four bytes per pixel, which are the red, the green, the blue and the alpha
+    Rgba,
+    /// Four bytes per pixel, which are the blue, the green, the red and the alpha
+    Bgra,
+    /// Four bytes per pixel, which are the blue, the green, the red and an unused byte, as the
+    /// 32 bits `0RGB` pixels of many window systems are laid out on the little endian machines
+    Bgrx,
+}
+
+impl PixelFormat {
+    /// The number of the bytes each pixel takes
+    pub fn bytes_per_pixel(self) -> usize {
+        match self {
+            PixelFormat::Rgb => 3,
+            _ => 4,
+        }
+    }
+
+    /// The offsets of the red, the green and the blue within a pixel
+    pub(crate) fn rgb_offsets(self) -> [usize; 3] {
+        match self {
+            PixelFormat::Rgb | PixelFormat::Rgba => [0, 1, 2],
+            PixelFormat::Bgra | PixelFormat::Bgrx => [2, 1, 0],
+        }
+    }
+
+    /// The offset of the alpha within a pixel, if the pixels have an alpha
+    pub(crate) fn alpha_offset(self) -> Option<usize> {
+        match self {
+            PixelFormat::Rgba | PixelFormat::Bgra => Some(3),
+            PixelFormat::Rgb | PixelFormat::Bgrx => None,
+        }
+    }
+}
+
+/// How a bitmap is resampled when it's drawn at another size than its own
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ImageScaling {
+    /// Take the closest pixel, which keeps the pixels sharp
+    Nearest,
+    /// Interpolate between the four closest pixels, which is smoother
+    Bilinear,
+}
+
 /// The style data for the backend drawing API
 pub trait BackendStyle {
     /// The underlying type represents the color for this style
@@ -39,6 +125,26 @@ pub trait BackendStyle {
     fn stroke_width(&self) -> u32 {
         1
     }
+
+    /// The lengths in pixels of the dashes and the gaps of the stroke, `None` for a solid one
+    fn dash_pattern(&self) -> Option<&[u32]> {
+        None
+    }
+
+    /// If the style fills the shapes, which is how a recorded style replays the same way
+    fn is_filled(&self) -> bool {
+        false
+    }
+
+    /// The gradient the filled shapes are filled with instead of the color, if there's one
+    fn gradient(&self) -> Option<&Gradient> {
+        None
+    }
+
+    /// The pattern the filled shapes are filled with instead of the color, if there's one
+    fn pattern(&self) -> Option<&Pattern> {
+        None
+    }
 }
 
 impl<T: Color> BackendStyle for T {
@@ -56,6 +162,26 @@ impl BackendStyle for ShapeStyle {
     fn stroke_width(&self) -> u32 {
         self.stroke_width
     }
+    fn dash_pattern(&self) -> Option<&[u32]> {
+        self.dash_pattern.as_deref()
+    }
+    fn is_filled(&self) -> bool {
+        self.filled
+    }
+    fn gradient(&self) -> Option<&Gradient> {
+        if self.filled {
+            self.gradient.as_ref()
+        } else {
+            None
+        }
+    }
+    fn pattern(&self) -> Option<&Pattern> {
+        if self.filled {
+            self.pattern.as_ref()
+        } else {
+            None
+        }
+    }
 }
 
 ///  The drawing backend trait, which implements the low-level drawing APIs.
@@ -101,6 +227,9 @@ pub trait DrawingBackend: Sized {
         to: BackendCoord,
         style: &S,
     ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if style.dash_pattern().is_some() {
+            return self.draw_path(vec![from, to], style);
+        }
         super::rasterizer::draw_line(self, from, to, style)
     }
 
@@ -119,6 +248,24 @@ pub trait DrawingBackend: Sized {
         super::rasterizer::draw_rect(self, upper_left, bottom_right, style, fill)
     }
 
+    /// Draw a rectangle with the rounded corners on the drawing backend
+    /// - `upper_left`: The coordinate of the upper-left corner of the rect
+    /// - `bottom_right`: The coordinate of the bottom-right corner of the rect
+    /// - `radii`: The radii of the upper left, the upper right, the bottom right and the bottom
+    ///   left corner, each of which is at most half of the shorter side of the rect
+    /// - `style`: The style
+    /// - `fill`: If the rectangle should be filled
+    fn draw_rounded_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        radii: [u32; 4],
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        super::rasterizer::draw_rounded_rect(self, upper_left, bottom_right, radii, style, fill)
+    }
+
     /// Draw a path on the drawing backend
     /// - `path`: The iterator of key points of the path
     /// - `style`: The style of the path
@@ -131,6 +278,18 @@ pub trait DrawingBackend: Sized {
             return Ok(());
         }
 
+        if let Some(pattern) = style.dash_pattern() {
+            let p: Vec<_> = path.into_iter().collect();
+            let solid = ShapeStyle {
+                stroke_width: style.stroke_width(),
+                ..ShapeStyle::from(&style.as_color())
+            };
+            for dash in super::rasterizer::dash_path(&p, pattern) {
+                self.draw_path(dash, &solid)?;
+            }
+            return Ok(());
+        }
+
         if style.stroke_width() == 1 {
             let mut begin: Option<BackendCoord> = None;
             for end in path.into_iter() {
@@ -150,6 +309,22 @@ pub trait DrawingBackend: Sized {
         Ok(())
     }
 
+    /// Draw a path of cubic Bézier curves on the drawing backend
+    /// - `start`: The point the path starts at
+    /// - `segments`: The two control points and the end point of each curve, which starts
+    ///   where the previous one ends
+    /// - `style`: The style of the path
+    fn draw_bezier<S: BackendStyle>(
+        &mut self,
+        start: BackendCoord,
+        segments: &[[BackendCoord; 3]],
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        // The curves are flattened into the line segments within a quarter of a pixel of them
+        let path = super::rasterizer::flatten_bezier(start, segments, 0.25);
+        self.draw_path(path, style)
+    }
+
     /// Draw a circle on the drawing backend
     /// - `center`: The center coordinate of the circle
     /// - `radius`: The radius of the circle
@@ -165,6 +340,38 @@ pub trait DrawingBackend: Sized {
         super::rasterizer::draw_circle(self, center, radius, style, fill)
     }
 
+    /// Draw an arc of a circle on the drawing backend
+    /// - `center`: The center coordinate of the circle
+    /// - `radius`: The radius of the circle
+    /// - `angles`: The angles the arc goes between, in radians counterclockwise from the
+    ///   3 o'clock position, which cover a full circle if they're a full turn or more apart
+    /// - `style`: The style of the arc
+    fn draw_arc<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radius: u32,
+        angles: (f64, f64),
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        super::rasterizer::draw_arc(self, center, radius, angles, style)
+    }
+
+    /// Fill a circular sector on the drawing backend
+    /// - `center`: The center coordinate of the circle
+    /// - `radii`: The inner and the outer radius, the sector is an annular sector if the inner
+    ///   radius isn't zero
+    /// - `angles`: The angles the sector goes between, the same as the angles of `draw_arc`
+    /// - `style`: The style of the sector
+    fn fill_sector<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radii: (u32, u32),
+        angles: (f64, f64),
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        super::rasterizer::fill_sector(self, center, radii, angles, style)
+    }
+
     fn fill_polygon<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
         &mut self,
         vert: I,
@@ -175,6 +382,23 @@ pub trait DrawingBackend: Sized {
         super::rasterizer::fill_polygon(self, &vert_buf[..], style)
     }
 
+    /// Fill a polygon with a vertical linear gradient
+    /// - `vert`: The iterator of the vertices of the polygon
+    /// - `top`: The row where the gradient starts and the color at it
+    /// - `bottom`: The row where the gradient ends and the color at it
+    ///
+    /// The rows above the top stop and below the bottom stop take the color of the stop.
+    fn fill_polygon_gradient<I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        vert: I,
+        top: (i32, &RGBAColor),
+        bottom: (i32, &RGBAColor),
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let vert_buf: Vec<_> = vert.into_iter().collect();
+
+        super::rasterizer::fill_polygon_gradient(self, &vert_buf[..], top, bottom)
+    }
+
     /// Draw a text on the drawing backend
     /// - `text`: The text to draw
     /// - `font`: The description of the font
@@ -187,16 +411,7 @@ pub trait DrawingBackend: Sized {
         pos: BackendCoord,
         color: &RGBAColor,
     ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
-        if color.alpha() == 0.0 {
-            return Ok(());
-        }
-
-        match font.draw(text, (pos.0, pos.1), |x, y, v| {
-            self.draw_pixel((x as i32, y as i32), &color.mix(f64::from(v)))
-        }) {
-            Ok(drawing_result) => drawing_result,
-            Err(font_error) => Err(DrawingErrorKind::FontError(font_error)),
-        }
+        super::rasterizer::draw_text(self, text, font, pos, color)
     }
 
     /// Estimate the size of the text if rendered on this backend.
@@ -215,6 +430,37 @@ pub trait DrawingBackend: Sized {
         Ok(font.box_size(text).map_err(DrawingErrorKind::FontError)?)
     }
 
+    /// Clip the drawing on the backend to a rectangle, until the clip is set to `None`. The
+    /// backends which don't support clipping draw everything, which is the default.
+    ///
+    /// - `clip`: The clipping rectangle, or `None` to draw everywhere again
+    fn set_clip(
+        &mut self,
+        clip: Option<BackendClip>,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let _ = clip;
+        Ok(())
+    }
+
+    /// Start drawing the elements with a drop shadow beneath them, until `end_shadow` is called.
+    /// The backends which can draw the shadows themselves return `true`, otherwise the shadow
+    /// is rasterized by the caller, which is the default.
+    ///
+    /// - `shadow`: The shadow to draw
+    /// - *Returns* If the backend draws the shadow, in which case `end_shadow` must be called
+    fn begin_shadow(
+        &mut self,
+        shadow: &BackendShadow,
+    ) -> Result<bool, DrawingErrorKind<Self::ErrorType>> {
+        let _ = shadow;
+        Ok(false)
+    }
+
+    /// Stop drawing the drop shadow started by `begin_shadow`
+    fn end_shadow(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        Ok(())
+    }
+
     /// Blit a bitmap on to the backend.
     ///
     /// - `text`: pos the left upper conner of the bitmap to blit
@@ -231,13 +477,19 @@ pub trait DrawingBackend: Sized {
             if pos.0 + dx as i32 >= w as i32 {
                 break;
             }
+            if pos.0 + (dx as i32) < 0 {
+                continue;
+            }
             for dy in 0..ih {
                 if pos.1 + dy as i32 >= h as i32 {
                     break;
                 }
-                let r = src[(dx + dy * w) as usize * 3 + 0];
-                let g = src[(dx + dy * w) as usize * 3 + 1];
-                let b = src[(dx + dy * w) as usize * 3 + 2];
+                if pos.1 + (dy as i32) < 0 {
+                    continue;
+                }
+                let r = src[(dx + dy * iw) as usize * 3 + 0];
+                let g = src[(dx + dy * iw) as usize * 3 + 1];
+                let b = src[(dx + dy * iw) as usize * 3 + 2];
                 let color = crate::style::RGBColor(r, g, b);
                 let result =
                     self.draw_pixel((pos.0 + dx as i32, pos.1 + dy as i32), &color.to_rgba());
@@ -249,4 +501,45 @@ pub trait DrawingBackend: Sized {
 
         Ok(())
     }
+
+    /// Draw a bitmap scaled to the given size, blending its pixels by their alpha if it has an
+    /// alpha channel. The parts outside of the backend are left out.
+    ///
+    /// - `pos`: The left upper corner of the bitmap
+    /// - `size`: The size the bitmap is drawn at
+    /// - `src_size`: The size of the bitmap itself
+    /// - `src`: The pixels of the bitmap, row by row
+    /// - `format`: The layout of the pixels
+    /// - `scaling`: How the bitmap is resampled if it's drawn at another size
+    fn draw_image(
+        &mut self,
+        pos: BackendCoord,
+        size: (u32, u32),
+        src_size: (u32, u32),
+        src: &[u8],
+        format: PixelFormat,
+        scaling: ImageScaling,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        crate::drawing::rasterizer::draw_image(self, pos, size, src_size, src, format, scaling)
+    }
+}
+
+/// The backend drawing an animation into a file, one frame for each call of `present`. The
+/// backends of the animations share this, so the code drawing an animation can switch between
+/// the formats by the backend it's given.
+pub trait AnimatedBackend: DrawingBackend {
+    /// Create the backend drawing an animation into a file
+    /// - `path`: The path to the file to create
+    /// - `dimension`: The size of the frames
+    /// - `frame_delay`: How long each frame is displayed in milliseconds
+    fn create_animation<T: AsRef<std::path::Path>>(
+        path: T,
+        dimension: (u32, u32),
+        frame_delay: u32,
+    ) -> Result<Self, Self::ErrorType>;
+
+    /// Finish the frame as `present` does, but display it for the given time instead of the
+    /// frame delay of the backend
+    /// - `delay`: How long the frame is displayed in milliseconds
+    fn present_frame(&mut self, delay: u32) -> Result<(), DrawingErrorKind<Self::ErrorType>>;
 }
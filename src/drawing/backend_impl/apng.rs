@@ -0,0 +1,284 @@
+use std::fs::File;
+use std::io::{Seek, SeekFrom, Write};
+use std::path::Path;
+
+/// The offset of the animation control chunk, which follows the signature and the header
+const ACTL_OFFSET: u64 = 8 + 25;
+
+/// The writer of an APNG file, whose frames are the RGB pixels of a bitmap. The number of the
+/// frames is written when the file is dropped, since it's only known then.
+pub(super) struct ApngFile {
+    file: File,
+    size: (u32, u32),
+    /// How long each frame is displayed in milliseconds
+    pub(super) delay: u32,
+    /// How many times the animation is repeated after it's played once, `None` to loop forever
+    pub(super) repeat: Option<u16>,
+    /// If the frames after the first one only have the region changed since the previous frame
+    pub(super) dirty_rect: bool,
+    previous: Option<Vec<u8>>,
+    sequence: u32,
+    frames: u32,
+}
+
+impl ApngFile {
+    pub(super) fn new<T: AsRef<Path>>(
+        path: T,
+        (w, h): (u32, u32),
+        delay: u32,
+    ) -> std::io::Result<Self> {
+        let mut apng = Self {
+            file: File::create(path.as_ref())?,
+            size: (w, h),
+            delay,
+            repeat: None,
+            dirty_rect: true,
+            previous: None,
+            sequence: 0,
+            frames: 0,
+        };
+        apng.file.write_all(b"\x89PNG\r\n\x1a\n")?;
+
+        let mut header = vec![];
+        header.extend_from_slice(&w.to_be_bytes());
+        header.extend_from_slice(&h.to_be_bytes());
+        // 8 bits RGB without interlacing
+        header.extend_from_slice(&[8, 2, 0, 0, 0]);
+        apng.write_chunk(b"IHDR", &header)?;
+        // The number of the frames and the plays are written when the file is finished
+        apng.write_chunk(b"acTL", &[0; 8])?;
+        Ok(apng)
+    }
+
+    fn write_chunk(&mut self, kind: &[u8; 4], data: &[u8]) -> std::io::Result<()> {
+        let mut chunk = Vec::with_capacity(data.len() + 12);
+        chunk.extend_from_slice(&(data.len() as u32).to_be_bytes());
+        chunk.extend_from_slice(kind);
+        chunk.extend_from_slice(data);
+        chunk.extend_from_slice(&crc(kind, data).to_be_bytes());
+        self.file.write_all(&chunk)
+    }
+
+    /// Get the region of the frame which is different from the previous frame, as the upper
+    /// left corner and the size in pixels
+    fn changed_region(&self, buffer: &[u8]) -> ((u32, u32), (u32, u32)) {
+        let (w, h) = self.size;
+        let previous = match &self.previous {
+            Some(previous) if self.dirty_rect => previous,
+            _ => return ((0, 0), (w, h)),
+        };
+        let (mut x0, mut y0, mut x1, mut y1) = (w, h, 0, 0);
+        for y in 0..h {
+            for x in 0..w {
+                let idx = ((y * w + x) * 3) as usize;
+                if buffer[idx..idx + 3] != previous[idx..idx + 3] {
+                    x0 = x0.min(x);
+                    y0 = y0.min(y);
+                    x1 = x1.max(x + 1);
+                    y1 = y1.max(y + 1);
+                }
+            }
+        }
+        if x0 >= x1 {
+            // A frame is at least one pixel, even if nothing is changed
+            return ((0, 0), (1, 1));
+        }
+        ((x0, y0), (x1 - x0, y1 - y0))
+    }
+
+    /// Write a frame of the animation
+    /// - `buffer`: The RGB pixels of the whole frame
+    /// - `delay`: How long the frame is displayed in milliseconds
+    pub(super) fn flush_frame(&mut self, buffer: &[u8], delay: u32) -> std::io::Result<()> {
+        let ((x, y), (w, h)) = self.changed_region(buffer);
+
+        /* The rows of the region are filtered by the row above them, which is zero for the
+         * most of a chart */
+        let stride = (w * 3) as usize;
+        let mut rows = Vec::with_capacity((stride + 1) * h as usize);
+        let mut above = vec![0; stride];
+        for row in y..y + h {
+            let start = ((row * self.size.0 + x) * 3) as usize;
+            let pixels = &buffer[start..start + stride];
+            rows.push(2);
+            rows.extend(
+                pixels
+                    .iter()
+                    .zip(above.iter())
+                    .map(|(p, a)| p.wrapping_sub(*a)),
+            );
+            above.copy_from_slice(pixels);
+        }
+        let data = deflate::deflate_bytes_zlib(&rows);
+
+        let mut control = vec![];
+        for value in [self.sequence, w, h, x, y].iter() {
+            control.extend_from_slice(&value.to_be_bytes());
+        }
+        control.extend_from_slice(&(delay.min(u32::from(u16::MAX)) as u16).to_be_bytes());
+        control.extend_from_slice(&1000u16.to_be_bytes());
+        // The frame replaces the region of the previous one, which is kept as it is
+        control.extend_from_slice(&[0, 0]);
+        self.write_chunk(b"fcTL", &control)?;
+        self.sequence += 1;
+
+        if self.frames == 0 {
+            self.write_chunk(b"IDAT", &data)?;
+        } else {
+            let mut frame_data = self.sequence.to_be_bytes().to_vec();
+            frame_data.extend_from_slice(&data);
+            self.write_chunk(b"fdAT", &frame_data)?;
+            self.sequence += 1;
+        }
+        self.frames += 1;
+        self.previous = Some(buffer.to_vec());
+        Ok(())
+    }
+
+    fn finish(&mut self) -> std::io::Result<()> {
+        self.write_chunk(b"IEND", &[])?;
+
+        let plays = self.repeat.map_or(0, |times| u32::from(times) + 1);
+        let mut control = self.frames.to_be_bytes().to_vec();
+        control.extend_from_slice(&plays.to_be_bytes());
+        self.file.seek(SeekFrom::Start(ACTL_OFFSET + 8))?;
+        self.file.write_all(&control)?;
+        self.file.write_all(&crc(b"acTL", &control).to_be_bytes())?;
+        self.file.flush()
+    }
+}
+
+fn crc(kind: &[u8; 4], data: &[u8]) -> u32 {
+    let mut hasher = crc32fast::Hasher::new();
+    hasher.update(kind);
+    hasher.update(data);
+    hasher.finalize()
+}
+
+impl Drop for ApngFile {
+    fn drop(&mut self) {
+        self.finish().expect("Unable to finish the APNG file");
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::crc;
+    use crate::prelude::*;
+
+    /// Get the chunks of a PNG file after checking their CRCs
+    fn read_chunks(data: &[u8]) -> Vec<([u8; 4], Vec<u8>)> {
+        assert_eq!(&data[..8], b"\x89PNG\r\n\x1a\n");
+        let mut chunks = vec![];
+        let mut pos = 8;
+        while pos < data.len() {
+            let be = |at: usize| {
+                u32::from_be_bytes([data[at], data[at + 1], data[at + 2], data[at + 3]])
+            };
+            let len = be(pos) as usize;
+            let mut kind = [0; 4];
+            kind.copy_from_slice(&data[pos + 4..pos + 8]);
+            let body = data[pos + 8..pos + 8 + len].to_vec();
+            assert_eq!(crc(&kind, &body), be(pos + 8 + len));
+            chunks.push((kind, body));
+            pos += len + 12;
+        }
+        chunks
+    }
+
+    /// Decode the pixels of a frame as a PNG image of its own
+    fn decode_frame((w, h): (u32, u32), data: &[u8]) -> Vec<u8> {
+        let mut png = b"\x89PNG\r\n\x1a\n".to_vec();
+        let mut header = vec![];
+        header.extend_from_slice(&w.to_be_bytes());
+        header.extend_from_slice(&h.to_be_bytes());
+        header.extend_from_slice(&[8, 2, 0, 0, 0]);
+        for (kind, body) in [(b"IHDR", &header[..]), (b"IDAT", data), (b"IEND", &[][..])].iter() {
+            png.extend_from_slice(&(body.len() as u32).to_be_bytes());
+            png.extend_from_slice(&kind[..]);
+            png.extend_from_slice(body);
+            png.extend_from_slice(&crc(kind, body).to_be_bytes());
+        }
+        image::load_from_memory(&png).unwrap().to_rgb().into_raw()
+    }
+
+    #[test]
+    fn test_apng_backend() {
+        let path = std::env::temp_dir().join("plotters-test-apng-backend.png");
+        {
+            let root = ApngBackend::new(&path, (30, 10), 100)
+                .unwrap()
+                .repeat(Some(1))
+                .into_drawing_area();
+            let stripes = root.split_evenly((1, 3));
+            for (stripe, color) in stripes.iter().zip([RED, GREEN, BLUE].iter()) {
+                stripe.fill(color).unwrap();
+            }
+            root.present().unwrap();
+            stripes[1].fill(&RGBColor(1, 2, 3)).unwrap();
+            root.present().unwrap();
+            root.present_frame(250).unwrap();
+            // The frame which isn't presented is kept when the backend is dropped
+            stripes[2].fill(&WHITE).unwrap();
+        }
+        let data = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        let chunks = read_chunks(&data);
+        let kinds: Vec<_> = chunks.iter().map(|(kind, _)| &kind[..]).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                &b"IHDR"[..],
+                b"acTL",
+                b"fcTL",
+                b"IDAT",
+                b"fcTL",
+                b"fdAT",
+                b"fcTL",
+                b"fdAT",
+                b"fcTL",
+                b"fdAT",
+                b"IEND"
+            ]
+        );
+        // 4 frames played twice
+        assert_eq!(chunks[1].1, vec![0, 0, 0, 4, 0, 0, 0, 2]);
+
+        let be = |data: &[u8], at: usize| {
+            u32::from_be_bytes([data[at], data[at + 1], data[at + 2], data[at + 3]])
+        };
+        let frames: Vec<_> = chunks
+            .iter()
+            .filter(|(kind, _)| kind == b"fcTL")
+            .map(|(_, c)| {
+                let delay = u16::from_be_bytes([c[20], c[21]]);
+                ((be(c, 12), be(c, 16)), (be(c, 4), be(c, 8)), delay)
+            })
+            .collect();
+        // The first frame is the whole image, and the rest are the changed regions, where the
+        // fill of a drawing area includes its right edge
+        assert_eq!(
+            frames,
+            vec![
+                ((0, 0), (30, 10), 100),
+                ((10, 0), (11, 10), 100),
+                ((0, 0), (1, 1), 250),
+                ((20, 0), (10, 10), 100),
+            ]
+        );
+
+        // The default image is the first frame with all the colors of the bitmap
+        let first = decode_frame((30, 10), &chunks[3].1);
+        assert_eq!(first[..3], [255, 0, 0]);
+        assert_eq!(first[30..33], [0, 255, 0]);
+        let second = decode_frame((11, 10), &chunks[5].1[4..]);
+        assert!(second.chunks(3).all(|c| c == [1, 2, 3]));
+        // The sequence numbers of the chunks follow each other
+        let sequence: Vec<_> = chunks
+            .iter()
+            .filter(|(kind, _)| kind == b"fcTL" || kind == b"fdAT")
+            .map(|(_, c)| be(c, 0))
+            .collect();
+        assert_eq!(sequence, (0..7).collect::<Vec<_>>());
+    }
+}
@@ -1,4 +1,7 @@
-use crate::drawing::backend::{BackendCoord, BackendStyle, DrawingBackend, DrawingErrorKind};
+use crate::drawing::backend::{
+    BackendClip, BackendCoord, BackendStyle, DrawingBackend, DrawingErrorKind, PixelFormat,
+};
+use crate::style::FontDesc;
 use crate::style::{Color, RGBAColor};
 use std::marker::PhantomData;
 
@@ -15,6 +18,11 @@ use image_encoding_support::*;
 #[derive(Debug)]
 pub enum BitMapBackendError {
     InvalidBuffer,
+    /// The buffer given to the backend doesn't have the length of the image in its pixel format
+    BufferSizeMismatch {
+        expected: usize,
+        actual: usize,
+    },
     IOError(std::io::Error),
     #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
     ImageError(ImageError),
@@ -32,17 +40,184 @@ fn blend(prev: &mut u8, new: u8, a: f64) {
     *prev = ((f64::from(*prev)) * (1.0 - a) + a * f64::from(new)).min(255.0) as u8;
 }
 
+/// Check that a buffer has exactly the length of an image of the size in the pixel format
+fn check_buffer_size(
+    buf: &[u8],
+    (w, h): (u32, u32),
+    format: PixelFormat,
+) -> Result<(), BitMapBackendError> {
+    let expected = w as usize * h as usize * format.bytes_per_pixel();
+    if buf.len() != expected {
+        return Err(BitMapBackendError::BufferSizeMismatch {
+            expected,
+            actual: buf.len(),
+        });
+    }
+    Ok(())
+}
+
+/// Paint a color over a pixel of the given format, blending it by the alpha. The alpha of the
+/// pixel, if it has one, becomes more opaque by the alpha of the color, and the unused byte of
+/// `PixelFormat::Bgrx` is left as it is.
+fn paint(format: PixelFormat, pixel: &mut [u8], (r, g, b): (u8, u8, u8), a: f64) {
+    let [ri, gi, bi] = format.rgb_offsets();
+    if a >= 1.0 {
+        pixel[ri] = r;
+        pixel[gi] = g;
+        pixel[bi] = b;
+        if let Some(ai) = format.alpha_offset() {
+            pixel[ai] = 255;
+        }
+    } else {
+        blend(&mut pixel[ri], r, a);
+        blend(&mut pixel[gi], g, a);
+        blend(&mut pixel[bi], b, a);
+        if let Some(ai) = format.alpha_offset() {
+            blend(&mut pixel[ai], 255, a);
+        }
+    }
+}
+
+/// Implement the drawing of a backend drawing on a `BitMapBackend` in its field `bitmap`. Only the
+/// methods `BitMapBackend` implements are forwarded to it, so the rest are drawn with these the
+/// same way as on the bitmap.
+#[cfg(any(
+    all(feature = "gif", not(target_arch = "wasm32"), feature = "image"),
+    all(
+        feature = "apng_backend",
+        not(target_arch = "wasm32"),
+        feature = "image"
+    )
+))]
+macro_rules! forward_to_bitmap {
+    ($name:ident) => {
+        impl DrawingBackend for $name {
+            type ErrorType = BitMapBackendError;
+
+            fn get_size(&self) -> (u32, u32) {
+                self.bitmap.get_size()
+            }
+
+            fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<BitMapBackendError>> {
+                self.bitmap.ensure_prepared()
+            }
+
+            fn present(&mut self) -> Result<(), DrawingErrorKind<BitMapBackendError>> {
+                self.bitmap.present()
+            }
+
+            fn draw_pixel(
+                &mut self,
+                point: BackendCoord,
+                color: &RGBAColor,
+            ) -> Result<(), DrawingErrorKind<BitMapBackendError>> {
+                self.bitmap.draw_pixel(point, color)
+            }
+
+            fn draw_line<S: BackendStyle>(
+                &mut self,
+                from: BackendCoord,
+                to: BackendCoord,
+                style: &S,
+            ) -> Result<(), DrawingErrorKind<BitMapBackendError>> {
+                self.bitmap.draw_line(from, to, style)
+            }
+
+            fn draw_rect<S: BackendStyle>(
+                &mut self,
+                upper_left: BackendCoord,
+                bottom_right: BackendCoord,
+                style: &S,
+                fill: bool,
+            ) -> Result<(), DrawingErrorKind<BitMapBackendError>> {
+                self.bitmap.draw_rect(upper_left, bottom_right, style, fill)
+            }
+
+            fn blit_bitmap(
+                &mut self,
+                pos: BackendCoord,
+                size: (u32, u32),
+                src: &[u8],
+            ) -> Result<(), DrawingErrorKind<BitMapBackendError>> {
+                self.bitmap.blit_bitmap(pos, size, src)
+            }
+
+            fn draw_text(
+                &mut self,
+                text: &str,
+                font: &FontDesc,
+                pos: BackendCoord,
+                color: &RGBAColor,
+            ) -> Result<(), DrawingErrorKind<BitMapBackendError>> {
+                self.bitmap.draw_text(text, font, pos, color)
+            }
+
+            fn set_clip(
+                &mut self,
+                clip: Option<BackendClip>,
+            ) -> Result<(), DrawingErrorKind<BitMapBackendError>> {
+                self.bitmap.set_clip(clip)
+            }
+        }
+    };
+}
+
 #[cfg(all(feature = "gif", not(target_arch = "wasm32"), feature = "image"))]
 mod gif_support {
     use super::*;
+    use crate::drawing::backend::AnimatedBackend;
     use gif::{Encoder as GifEncoder, Frame as GifFrame, Repeat, SetParameter};
+    use std::collections::HashMap;
     use std::fs::File;
 
+    /// How the colors of the frames of a GIF animation are reduced to the 256 colors of a
+    /// palette
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub enum GifPalette {
+        /// Each frame is quantized to a palette of its own
+        Local,
+        /// The first frame is quantized to a palette shared by all the frames, and the colors
+        /// of the frames are mapped to the nearest ones of it. The colors don't flicker between
+        /// the frames and the file is smaller, which suits the frames drawn with the same
+        /// colors.
+        Global,
+    }
+
+    /// The palette shared by the frames, with the colors already mapped to it
+    struct SharedPalette {
+        colors: Vec<u8>,
+        indexes: HashMap<[u8; 3], u8>,
+    }
+
+    impl SharedPalette {
+        fn index_of(&mut self, color: [u8; 3]) -> u8 {
+            let colors = &self.colors;
+            *self.indexes.entry(color).or_insert_with(|| {
+                let distance = |c: &[u8]| -> i32 {
+                    (0..3)
+                        .map(|i| (i32::from(c[i]) - i32::from(color[i])).pow(2))
+                        .sum()
+                };
+                colors
+                    .chunks(3)
+                    .enumerate()
+                    .min_by_key(|(_, c)| distance(c))
+                    .map_or(0, |(idx, _)| idx as u8)
+            })
+        }
+    }
+
     pub(super) struct GifFile {
-        encoder: GifEncoder<File>,
+        /// The file until the first frame is written, which creates the encoder, since the
+        /// shared palette is taken from the first frame
+        file: Option<File>,
+        encoder: Option<GifEncoder<File>>,
         height: u32,
         width: u32,
-        delay: u32,
+        pub(super) delay: u32,
+        pub(super) palette: GifPalette,
+        pub(super) repeat: Option<u16>,
+        shared_palette: Option<SharedPalette>,
     }
 
     impl GifFile {
@@ -51,47 +226,251 @@ mod gif_support {
             dim: (u32, u32),
             delay: u32,
         ) -> Result<Self, BitMapBackendError> {
-            let mut encoder = GifEncoder::new(
-                File::create(path.as_ref()).map_err(BitMapBackendError::IOError)?,
-                dim.0 as u16,
-                dim.1 as u16,
-                &[],
-            )
-            .map_err(BitMapBackendError::IOError)?;
-
-            encoder
-                .set(Repeat::Infinite)
-                .map_err(BitMapBackendError::IOError)?;
-
             Ok(Self {
-                encoder,
+                file: Some(File::create(path.as_ref()).map_err(BitMapBackendError::IOError)?),
+                encoder: None,
                 width: dim.0,
                 height: dim.1,
                 delay: (delay + 5) / 10,
+                palette: GifPalette::Local,
+                repeat: None,
+                shared_palette: None,
             })
         }
 
         pub(super) fn flush_frame(&mut self, buffer: &[u8]) -> Result<(), BitMapBackendError> {
-            let mut frame =
-                GifFrame::from_rgb_speed(self.width as u16, self.height as u16, buffer, 10);
+            let (w, h) = (self.width as u16, self.height as u16);
+            let mut frame = match self.palette {
+                GifPalette::Local => GifFrame::from_rgb_speed(w, h, buffer, 10),
+                GifPalette::Global => {
+                    if self.shared_palette.is_none() {
+                        let first = GifFrame::from_rgb_speed(w, h, buffer, 10);
+                        self.shared_palette = Some(SharedPalette {
+                            colors: first.palette.unwrap_or_default(),
+                            indexes: HashMap::new(),
+                        });
+                    }
+                    let palette = self.shared_palette.as_mut().unwrap();
+                    let pixels: Vec<_> = buffer
+                        .chunks(3)
+                        .map(|c| palette.index_of([c[0], c[1], c[2]]))
+                        .collect();
+                    GifFrame::from_indexed_pixels(w, h, &pixels, None)
+                }
+            };
 
             frame.delay = self.delay as u16;
 
-            self.encoder
-                .write_frame(&frame)
-                .map_err(BitMapBackendError::IOError)?;
+            if let Some(file) = self.file.take() {
+                let colors = self
+                    .shared_palette
+                    .as_ref()
+                    .map_or(&[][..], |palette| &palette.colors[..]);
+                let mut encoder =
+                    GifEncoder::new(file, w, h, colors).map_err(BitMapBackendError::IOError)?;
+                // An animation played only once doesn't have the loop extension
+                let repeat = match self.repeat {
+                    None => Some(Repeat::Infinite),
+                    Some(0) => None,
+                    Some(n) => Some(Repeat::Finite(n)),
+                };
+                if let Some(repeat) = repeat {
+                    encoder.set(repeat).map_err(BitMapBackendError::IOError)?;
+                }
+                self.encoder = Some(encoder);
+            }
+
+            if let Some(encoder) = self.encoder.as_mut() {
+                encoder
+                    .write_frame(&frame)
+                    .map_err(BitMapBackendError::IOError)?;
+            }
 
             Ok(())
         }
     }
+
+    /// The backend that draws a GIF animation, one frame for each call of `present`. The
+    /// frames are drawn as by a `BitMapBackend`, and the frame drawn without being presented
+    /// is the last one of the animation when the backend is dropped.
+    pub struct GifBackend {
+        bitmap: BitMapBackend<'static>,
+    }
+
+    impl GifBackend {
+        /// Create a backend drawing a GIF animation which loops forever, whose frames have
+        /// the palettes of their own
+        /// - `path`: The path to the GIF file to create
+        /// - `dimension`: The size of the frames
+        /// - `frame_delay`: How long each frame is displayed in milliseconds
+        pub fn new<T: AsRef<Path>>(
+            path: T,
+            dimension: (u32, u32),
+            frame_delay: u32,
+        ) -> Result<Self, BitMapBackendError> {
+            Ok(Self {
+                bitmap: BitMapBackend::gif(path, dimension, frame_delay)?,
+            })
+        }
+
+        fn gif_file(&mut self) -> &mut GifFile {
+            match &mut self.bitmap.target {
+                Target::Gif(file) => file,
+                _ => unreachable!("The bitmap of a GIF backend always targets a GIF file"),
+            }
+        }
+
+        /// Set how the colors of the frames are reduced to a palette, which takes effect if
+        /// no frame is presented yet
+        pub fn palette(mut self, palette: GifPalette) -> Self {
+            self.gif_file().palette = palette;
+            self
+        }
+
+        /// Set how many times the animation is repeated after it's played once, `None` to
+        /// loop forever, which takes effect if no frame is presented yet
+        pub fn repeat(mut self, times: Option<u16>) -> Self {
+            self.gif_file().repeat = times;
+            self
+        }
+    }
+
+    forward_to_bitmap!(GifBackend);
+
+    impl AnimatedBackend for GifBackend {
+        fn create_animation<T: AsRef<Path>>(
+            path: T,
+            dimension: (u32, u32),
+            frame_delay: u32,
+        ) -> Result<Self, BitMapBackendError> {
+            Self::new(path, dimension, frame_delay)
+        }
+
+        fn present_frame(
+            &mut self,
+            delay: u32,
+        ) -> Result<(), DrawingErrorKind<BitMapBackendError>> {
+            let file = self.gif_file();
+            let frame_delay = std::mem::replace(&mut file.delay, (delay + 5) / 10);
+            let result = self.bitmap.present();
+            self.gif_file().delay = frame_delay;
+            result
+        }
+    }
+}
+
+#[cfg(all(feature = "gif", not(target_arch = "wasm32"), feature = "image"))]
+pub use gif_support::{GifBackend, GifPalette};
+
+#[cfg(all(
+    feature = "apng_backend",
+    not(target_arch = "wasm32"),
+    feature = "image"
+))]
+mod apng_support {
+    use super::super::apng::ApngFile;
+    use super::*;
+    use crate::drawing::backend::AnimatedBackend;
+
+    /// The backend that draws an APNG animation, one frame for each call of `present`. Unlike
+    /// a GIF animation, the frames keep all the colors of the bitmap, so the gradients aren't
+    /// banded. The frames are drawn as by a `BitMapBackend`, and the frame drawn without being
+    /// presented is the last one of the animation when the backend is dropped.
+    pub struct ApngBackend {
+        bitmap: BitMapBackend<'static>,
+    }
+
+    impl ApngBackend {
+        /// Create a backend drawing an APNG animation which loops forever, whose frames after
+        /// the first one only have the region changed since the previous frame
+        /// - `path`: The path to the APNG file to create
+        /// - `dimension`: The size of the frames
+        /// - `frame_delay`: How long each frame is displayed in milliseconds
+        pub fn new<T: AsRef<Path>>(
+            path: T,
+            (w, h): (u32, u32),
+            frame_delay: u32,
+        ) -> Result<Self, BitMapBackendError> {
+            let file =
+                ApngFile::new(path, (w, h), frame_delay).map_err(BitMapBackendError::IOError)?;
+            Ok(Self {
+                bitmap: BitMapBackend {
+                    target: Target::Apng(Box::new(file)),
+                    size: (w, h),
+                    buffer: Buffer::Owned(vec![0; (3 * w * h) as usize]),
+                    format: PixelFormat::Rgb,
+                    saved: false,
+                    clip: None,
+                },
+            })
+        }
+
+        fn apng_file(&mut self) -> &mut ApngFile {
+            match &mut self.bitmap.target {
+                Target::Apng(file) => file,
+                _ => unreachable!("The bitmap of an APNG backend always targets an APNG file"),
+            }
+        }
+
+        /// Set how many times the animation is repeated after it's played once, `None` to
+        /// loop forever
+        pub fn repeat(mut self, times: Option<u16>) -> Self {
+            self.apng_file().repeat = times;
+            self
+        }
+
+        /// Set if the frames after the first one only have the region changed since the
+        /// previous frame, which keeps the file small when the most of the chart is the same
+        /// in each frame. It's on by default.
+        pub fn dirty_rect(mut self, enabled: bool) -> Self {
+            self.apng_file().dirty_rect = enabled;
+            self
+        }
+    }
+
+    forward_to_bitmap!(ApngBackend);
+
+    impl AnimatedBackend for ApngBackend {
+        fn create_animation<T: AsRef<Path>>(
+            path: T,
+            dimension: (u32, u32),
+            frame_delay: u32,
+        ) -> Result<Self, BitMapBackendError> {
+            Self::new(path, dimension, frame_delay)
+        }
+
+        fn present_frame(
+            &mut self,
+            delay: u32,
+        ) -> Result<(), DrawingErrorKind<BitMapBackendError>> {
+            let file = self.apng_file();
+            let frame_delay = std::mem::replace(&mut file.delay, delay);
+            let result = self.bitmap.present();
+            self.apng_file().delay = frame_delay;
+            result
+        }
+    }
 }
 
+#[cfg(all(
+    feature = "apng_backend",
+    not(target_arch = "wasm32"),
+    feature = "image"
+))]
+pub use apng_support::ApngBackend;
+
 enum Target<'a> {
     #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
     File(&'a Path),
     Buffer(PhantomData<&'a u32>),
     #[cfg(all(feature = "gif", not(target_arch = "wasm32"), feature = "image"))]
     Gif(Box<gif_support::GifFile>),
+    #[cfg(all(
+        feature = "apng_backend",
+        not(target_arch = "wasm32"),
+        feature = "image"
+    ))]
+    Apng(Box<super::apng::ApngFile>),
 }
 
 enum Buffer<'a> {
@@ -119,8 +498,12 @@ pub struct BitMapBackend<'a> {
     size: (u32, u32),
     /// The data buffer of the image
     buffer: Buffer<'a>,
+    /// The layout of the pixels in the buffer
+    format: PixelFormat,
     /// Flag indicates if the bitmap has been saved
     saved: bool,
+    /// The rectangle the drawing is clipped to
+    clip: Option<BackendClip>,
 }
 
 impl<'a> BitMapBackend<'a> {
@@ -131,7 +514,9 @@ impl<'a> BitMapBackend<'a> {
             target: Target::File(path.as_ref()),
             size: (w, h),
             buffer: Buffer::Owned(vec![0; (3 * w * h) as usize]),
+            format: PixelFormat::Rgb,
             saved: false,
+            clip: None,
         }
     }
 
@@ -158,36 +543,111 @@ impl<'a> BitMapBackend<'a> {
             )?)),
             size: (w, h),
             buffer: Buffer::Owned(vec![0; (3 * w * h) as usize]),
+            format: PixelFormat::Rgb,
             saved: false,
+            clip: None,
         })
     }
 
     /// Create a new bitmap backend which only lives in-memory
     ///
     /// When this is used, the bitmap backend will write to a user provided [u8] array (or Vec<u8>).
-    /// Plotters uses RGB pixel format
+    /// Plotters uses RGB pixel format. This panics if the buffer doesn't have exactly the length
+    /// of the image.
     ///
     /// - `buf`: The buffer to operate
     /// - `dimension`: The size of the image in pixels
-    pub fn with_buffer(buf: &'a mut [u8], (w, h): (u32, u32)) -> Self {
-        if (w * h * 3) as usize > buf.len() {
-            // TODO: This doesn't deserve a panic.
-            panic!(
-                "Wrong image size: H = {}, W = {}, BufSize = {}",
-                w,
-                h,
-                buf.len()
-            );
+    #[deprecated(
+        note = "Use with_buffer_and_format, which returns an error for a buffer of the wrong size"
+    )]
+    pub fn with_buffer(buf: &'a mut [u8], dimension: (u32, u32)) -> Self {
+        match Self::with_buffer_and_format(buf, dimension, PixelFormat::Rgb) {
+            Ok(backend) => backend,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Create a new bitmap backend which only lives in-memory, drawing into a buffer of the
+    /// pixels in the given format, such as `PixelFormat::Rgb` or the framebuffer of a window.
+    /// The drawing goes straight into the buffer, so `present` has nothing to do.
+    ///
+    /// - `buf`: The buffer to operate, row by row
+    /// - `dimension`: The size of the image in pixels
+    /// - `format`: The layout of the pixels in the buffer
+    /// - **returns**: The backend, or `BitMapBackendError::BufferSizeMismatch` if the buffer
+    ///   doesn't have exactly the length of the image
+    pub fn with_buffer_and_format(
+        buf: &'a mut [u8],
+        (w, h): (u32, u32),
+        format: PixelFormat,
+    ) -> Result<Self, BitMapBackendError> {
+        check_buffer_size(buf, (w, h), format)?;
+        Ok(Self::from_buffer(buf, (w, h), format))
+    }
+
+    /// Split a buffer into the bitmap backends of its bands of rows from the top to the bottom,
+    /// so several charts are drawn into the same frame, even from different threads
+    ///
+    /// - `buf`, `dimension`, `format`: The buffer as `with_buffer_and_format` takes it
+    /// - `area_size`: The heights of the bands, where the last band takes the rows left
+    /// - **returns**: The backends, or `BitMapBackendError::BufferSizeMismatch` if the buffer
+    ///   doesn't have exactly the length of the image
+    pub fn split_buffer(
+        buf: &'a mut [u8],
+        (w, h): (u32, u32),
+        format: PixelFormat,
+        area_size: &[u32],
+    ) -> Result<Vec<Self>, BitMapBackendError> {
+        check_buffer_size(buf, (w, h), format)?;
+
+        let mut heights = vec![];
+        let mut top = 0;
+        for size in area_size {
+            if top + size >= h {
+                break;
+            }
+            heights.push(*size);
+            top += size;
         }
+        heights.push(h - top);
+
+        let row = w as usize * format.bytes_per_pixel();
+        let mut rest = buf;
+        Ok(heights
+            .into_iter()
+            .map(|height| {
+                let (band, tail) = std::mem::take(&mut rest).split_at_mut(row * height as usize);
+                rest = tail;
+                Self::from_buffer(band, (w, height), format)
+            })
+            .collect())
+    }
 
+    fn from_buffer(buf: &'a mut [u8], size: (u32, u32), format: PixelFormat) -> Self {
         Self {
             target: Target::Buffer(PhantomData),
-            size: (w, h),
+            size,
             buffer: Buffer::Borrowed(buf),
+            format,
             saved: false,
+            clip: None,
         }
     }
 
+    /// Get the part of the image which may be drawn, which is the clipping rectangle within the
+    /// image. The bottom right bound is excluded.
+    fn drawable_area(&self) -> (i32, i32, i32, i32) {
+        let (w, h) = self.get_size();
+        let (mut x0, mut y0, mut x1, mut y1) = (0, 0, w as i32, h as i32);
+        if let Some(clip) = self.clip {
+            x0 = x0.max(clip.upper_left.0);
+            y0 = y0.max(clip.upper_left.1);
+            x1 = x1.min(clip.bottom_right.0);
+            y1 = y1.min(clip.bottom_right.1);
+        }
+        (x0, y0, x1, y1)
+    }
+
     fn get_raw_pixel_buffer(&mut self) -> &mut [u8] {
         self.buffer.borrow_buffer()
     }
@@ -195,33 +655,11 @@ impl<'a> BitMapBackend<'a> {
     /// Split a bitmap backend vertically into several sub drawing area which allows
     /// multi-threading rendering.
     pub fn split(&mut self, area_size: &[u32]) -> Vec<BitMapBackend> {
-        let (w, h) = self.get_size();
-        let buf = self.get_raw_pixel_buffer();
-
-        let base_addr = &mut buf[0] as *mut u8;
-        let mut split_points = vec![0];
-        for size in area_size {
-            let next = split_points.last().unwrap() + size;
-            if next >= h {
-                break;
-            }
-            split_points.push(next);
-        }
-        split_points.push(h);
-
-        split_points
-            .iter()
-            .zip(split_points.iter().skip(1))
-            .map(|(begin, end)| {
-                let actual_buf = unsafe {
-                    std::slice::from_raw_parts_mut(
-                        base_addr.offset((begin * w * 3) as isize),
-                        ((end - begin) * w * 3) as usize,
-                    )
-                };
-                Self::with_buffer(actual_buf, (w, end - begin))
-            })
-            .collect()
+        let ((w, h), format) = (self.get_size(), self.format);
+        let len = w as usize * h as usize * format.bytes_per_pixel();
+        let buf = &mut self.get_raw_pixel_buffer()[..len];
+        BitMapBackend::split_buffer(buf, (w, h), format, area_size)
+            .expect("The buffer has the size of the image")
     }
 
     fn blend_rect_fast(
@@ -233,19 +671,20 @@ impl<'a> BitMapBackend<'a> {
         b: u8,
         a: f64,
     ) {
-        let (w, h) = self.get_size();
+        let (w, _) = self.get_size();
         let a = a.min(1.0).max(0.0);
         if a == 0.0 {
             return;
         }
 
+        let (left, top, right, bottom) = self.drawable_area();
         let (x0, y0) = (
-            upper_left.0.min(bottom_right.0).max(0),
-            upper_left.1.min(bottom_right.1).max(0),
+            upper_left.0.min(bottom_right.0).max(left),
+            upper_left.1.min(bottom_right.1).max(top),
         );
         let (x1, y1) = (
-            upper_left.0.max(bottom_right.0).min(w as i32 - 1),
-            upper_left.1.max(bottom_right.1).min(h as i32 - 1),
+            upper_left.0.max(bottom_right.0).min(right - 1),
+            upper_left.1.max(bottom_right.1).min(bottom - 1),
         );
 
         // This may happen when the minimal value is larger than the limit.
@@ -254,16 +693,15 @@ impl<'a> BitMapBackend<'a> {
             return;
         }
 
+        let format = self.format;
+        let bpp = format.bytes_per_pixel();
         let dst = self.get_raw_pixel_buffer();
 
         for y in y0..=y1 {
             let start = (y * w as i32 + x0) as usize;
             let count = (x1 - x0 + 1) as usize;
-            let mut iter = dst[(start * 3)..((start + count) * 3)].iter_mut();
-            for _ in 0..(x1 - x0 + 1) {
-                blend(iter.next().unwrap(), r, a);
-                blend(iter.next().unwrap(), g, a);
-                blend(iter.next().unwrap(), b, a);
+            for pixel in dst[(start * bpp)..((start + count) * bpp)].chunks_exact_mut(bpp) {
+                paint(format, pixel, (r, g, b), a);
             }
         }
     }
@@ -276,14 +714,15 @@ impl<'a> BitMapBackend<'a> {
         g: u8,
         b: u8,
     ) {
-        let (w, h) = self.get_size();
+        let (w, _) = self.get_size();
+        let (left, top, right, bottom) = self.drawable_area();
         let (x0, y0) = (
-            upper_left.0.min(bottom_right.0).max(0),
-            upper_left.1.min(bottom_right.1).max(0),
+            upper_left.0.min(bottom_right.0).max(left),
+            upper_left.1.min(bottom_right.1).max(top),
         );
         let (x1, y1) = (
-            upper_left.0.max(bottom_right.0).min(w as i32 - 1),
-            upper_left.1.max(bottom_right.1).min(h as i32 - 1),
+            upper_left.0.max(bottom_right.0).min(right - 1),
+            upper_left.1.max(bottom_right.1).min(bottom - 1),
         );
 
         // This may happen when the minimal value is larger than the limit.
@@ -292,9 +731,19 @@ impl<'a> BitMapBackend<'a> {
             return;
         }
 
+        let format = self.format;
         let dst = self.get_raw_pixel_buffer();
 
-        if r == g && g == b {
+        if format != PixelFormat::Rgb {
+            // The pixels of four bytes are filled one by one
+            let count = (x1 - x0 + 1) as usize;
+            for y in y0..=y1 {
+                let start = (y * w as i32 + x0) as usize;
+                for pixel in dst[(start * 4)..((start + count) * 4)].chunks_exact_mut(4) {
+                    paint(format, pixel, (r, g, b), 1.0);
+                }
+            }
+        } else if r == g && g == b {
             // If r == g == b, then we can use memset
             if x0 != 0 || x1 != w as i32 - 1 {
                 // If it's not the entire row is filled, we can only do
@@ -341,9 +790,10 @@ impl<'a> BitMapBackend<'a> {
                                 b, r, g, b, r, g, b, r, // QW2
                                 g, b, r, g, b, r, g, b, // QW3
                             ]);
-                            *ptr = d1;
-                            *ptr.offset(1) = d2;
-                            *ptr.offset(2) = d3;
+                            // The row start is not necessarily 8-byte aligned
+                            ptr.write_unaligned(d1);
+                            ptr.offset(1).write_unaligned(d2);
+                            ptr.offset(2).write_unaligned(d3);
                         }
                     }
 
@@ -401,6 +851,20 @@ impl<'a> DrawingBackend for BitMapBackend<'a> {
                 self.saved = true;
                 Ok(())
             }
+
+            #[cfg(all(
+                feature = "apng_backend",
+                not(target_arch = "wasm32"),
+                feature = "image"
+            ))]
+            Target::Apng(target) => {
+                let delay = target.delay;
+                target
+                    .flush_frame(self.buffer.borrow_buffer(), delay)
+                    .map_err(|e| DrawingErrorKind::DrawingError(BitMapBackendError::IOError(e)))?;
+                self.saved = true;
+                Ok(())
+            }
         }
     }
 
@@ -409,7 +873,8 @@ impl<'a> DrawingBackend for BitMapBackend<'a> {
         point: BackendCoord,
         color: &RGBAColor,
     ) -> Result<(), DrawingErrorKind<BitMapBackendError>> {
-        if point.0 < 0 || point.1 < 0 {
+        let (x0, y0, x1, y1) = self.drawable_area();
+        if point.0 < x0 || point.0 >= x1 || point.1 < y0 || point.1 >= y1 {
             return Ok(());
         }
 
@@ -417,11 +882,20 @@ impl<'a> DrawingBackend for BitMapBackend<'a> {
         let alpha = color.alpha();
         let rgb = color.rgb();
 
+        let format = self.format;
         let buf = self.get_raw_pixel_buffer();
 
         let (x, y) = (point.0 as usize, point.1 as usize);
         let w = w as usize;
 
+        if format != PixelFormat::Rgb {
+            let base = (y * w + x) * 4;
+            if base < buf.len() {
+                paint(format, &mut buf[base..base + 4], rgb, alpha);
+            }
+            return Ok(());
+        }
+
         let base = (y * w + x) * 3;
 
         if base < buf.len() {
@@ -446,6 +920,10 @@ impl<'a> DrawingBackend for BitMapBackend<'a> {
         to: (i32, i32),
         style: &S,
     ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if style.dash_pattern().is_some() {
+            return self.draw_path(vec![from, to], style);
+        }
+
         let alpha = style.as_color().alpha();
         let (r, g, b) = style.as_color().rgb();
 
@@ -454,28 +932,28 @@ impl<'a> DrawingBackend for BitMapBackend<'a> {
                 if from.1 == to.1 {
                     self.fill_rect_fast(from, to, r, g, b);
                 } else {
-                    let (w, h) = self.get_size();
-                    let w = w as i32;
-                    let h = h as i32;
+                    let w = self.get_size().0 as i32;
+                    let (left, top, right, bottom) = self.drawable_area();
 
                     // Make sure we are in the range
-                    if from.0 < 0 || from.0 >= w {
+                    if from.0 < left || from.0 >= right {
                         return Ok(());
                     }
 
+                    let format = self.format;
+                    let bpp = format.bytes_per_pixel();
                     let dst = self.get_raw_pixel_buffer();
                     let (mut y0, mut y1) = (from.1, to.1);
                     if y0 > y1 {
                         std::mem::swap(&mut y0, &mut y1);
                     }
                     // And check the y axis isn't out of bound
-                    y0 = y0.max(0);
-                    y1 = y1.min(h - 1);
+                    y0 = y0.max(top);
+                    y1 = y1.min(bottom - 1);
                     // This is ok because once y0 > y1, there won't be any iteration anymore
                     for y in y0..=y1 {
-                        dst[(y * w + from.0) as usize * 3] = r;
-                        dst[(y * w + from.0) as usize * 3 + 1] = g;
-                        dst[(y * w + from.0) as usize * 3 + 2] = b;
+                        let base = (y * w + from.0) as usize * bpp;
+                        paint(format, &mut dst[base..base + bpp], (r, g, b), 1.0);
                     }
                 }
             } else {
@@ -496,7 +974,7 @@ impl<'a> DrawingBackend for BitMapBackend<'a> {
     ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
         let alpha = style.as_color().alpha();
         let (r, g, b) = style.as_color().rgb();
-        if fill {
+        if fill && !crate::drawing::rasterizer::is_painted(style) {
             if alpha >= 1.0 {
                 self.fill_rect_fast(upper_left, bottom_right, r, g, b);
             } else {
@@ -513,14 +991,15 @@ impl<'a> DrawingBackend for BitMapBackend<'a> {
         (sw, sh): (u32, u32),
         src: &'b [u8],
     ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
-        let (dw, dh) = self.get_size();
+        let dw = self.get_size().0;
+        let (left, top, right, bottom) = self.drawable_area();
 
         let (x0, y0) = pos;
         let (x1, y1) = (x0 + sw as i32, y0 + sh as i32);
 
-        let (x0, y0, x1, y1) = (x0.max(0), y0.max(0), x1.min(dw as i32), y1.min(dh as i32));
+        let (x0, y0, x1, y1) = (x0.max(left), y0.max(top), x1.min(right), y1.min(bottom));
 
-        if x0 == x1 || y0 == y1 {
+        if x0 >= x1 || y0 >= y1 || src.len() < (sw * sh * 3) as usize {
             return Ok(());
         }
 
@@ -529,11 +1008,13 @@ impl<'a> DrawingBackend for BitMapBackend<'a> {
         let dst_gap = dw as usize - chunk_size;
         let src_gap = sw as usize - chunk_size;
 
-        let dst_start = 3 * (y0 as usize * dw as usize + x0 as usize);
+        let format = self.format;
+        let bpp = format.bytes_per_pixel();
+        let dst_start = bpp * (y0 as usize * dw as usize + x0 as usize);
 
         let mut dst = &mut self.get_raw_pixel_buffer()[dst_start..];
 
-        let src_start = 3 * ((sh as i32 + y0 - y1) * sw as i32 + (sw as i32 + x0 - x1)) as usize;
+        let src_start = 3 * ((y0 - pos.1) * sw as i32 + (x0 - pos.0)) as usize;
         let mut src = &src[src_start..];
 
         if src_gap == 0 && dst_gap == 0 {
@@ -541,15 +1022,48 @@ impl<'a> DrawingBackend for BitMapBackend<'a> {
             num_chunks = 1;
         }
         for i in 0..num_chunks {
-            dst[0..(chunk_size * 3)].copy_from_slice(&src[0..(chunk_size * 3)]);
+            if format == PixelFormat::Rgb {
+                dst[0..(chunk_size * 3)].copy_from_slice(&src[0..(chunk_size * 3)]);
+            } else {
+                // The RGB pixels of the source are reordered into the format of the buffer
+                let pixels = dst[0..(chunk_size * bpp)].chunks_exact_mut(bpp);
+                for (pixel, from) in pixels.zip(src[0..(chunk_size * 3)].chunks_exact(3)) {
+                    paint(format, pixel, (from[0], from[1], from[2]), 1.0);
+                }
+            }
             if i != num_chunks - 1 {
-                dst = &mut dst[((chunk_size + dst_gap) * 3)..];
+                dst = &mut dst[((chunk_size + dst_gap) * bpp)..];
                 src = &src[((chunk_size + src_gap) * 3)..];
             }
         }
 
         Ok(())
     }
+
+    fn draw_text(
+        &mut self,
+        text: &str,
+        font: &FontDesc,
+        pos: BackendCoord,
+        color: &RGBAColor,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        // The texts which aren't clipped are drawn with the clip lifted for a moment
+        let clip = self.clip;
+        if matches!(clip, Some(clip) if !clip.clip_text) {
+            self.clip = None;
+        }
+        let result = crate::drawing::rasterizer::draw_text(self, text, font, pos, color);
+        self.clip = clip;
+        result
+    }
+
+    fn set_clip(
+        &mut self,
+        clip: Option<BackendClip>,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.clip = clip;
+        Ok(())
+    }
 }
 
 impl Drop for BitMapBackend<'_> {
@@ -567,7 +1081,8 @@ fn test_bitmap_backend() {
     let mut buffer = vec![0; 10 * 10 * 3];
 
     {
-        let back = BitMapBackend::with_buffer(&mut buffer, (10, 10));
+        let back =
+            BitMapBackend::with_buffer_and_format(&mut buffer, (10, 10), PixelFormat::Rgb).unwrap();
 
         let area = back.into_drawing_area();
         area.fill(&WHITE).unwrap();
@@ -595,7 +1110,8 @@ fn test_bitmap_backend_fill_half() {
     let mut buffer = vec![0; 10 * 10 * 3];
 
     {
-        let back = BitMapBackend::with_buffer(&mut buffer, (10, 10));
+        let back =
+            BitMapBackend::with_buffer_and_format(&mut buffer, (10, 10), PixelFormat::Rgb).unwrap();
 
         let area = back.into_drawing_area();
         area.draw(&Rectangle::new([(0, 0), (5, 10)], RED.filled()))
@@ -616,7 +1132,8 @@ fn test_bitmap_backend_fill_half() {
     let mut buffer = vec![0; 10 * 10 * 3];
 
     {
-        let back = BitMapBackend::with_buffer(&mut buffer, (10, 10));
+        let back =
+            BitMapBackend::with_buffer_and_format(&mut buffer, (10, 10), PixelFormat::Rgb).unwrap();
 
         let area = back.into_drawing_area();
         area.draw(&Rectangle::new([(0, 0), (10, 5)], RED.filled()))
@@ -642,7 +1159,8 @@ fn test_bitmap_backend_blend() {
     let mut buffer = vec![255; 10 * 10 * 3];
 
     {
-        let back = BitMapBackend::with_buffer(&mut buffer, (10, 10));
+        let back =
+            BitMapBackend::with_buffer_and_format(&mut buffer, (10, 10), PixelFormat::Rgb).unwrap();
 
         let area = back.into_drawing_area();
         area.draw(&Rectangle::new(
@@ -674,7 +1192,8 @@ fn test_bitmap_backend_split_and_fill() {
     let mut buffer = vec![255; 10 * 10 * 3];
 
     {
-        let mut back = BitMapBackend::with_buffer(&mut buffer, (10, 10));
+        let mut back =
+            BitMapBackend::with_buffer_and_format(&mut buffer, (10, 10), PixelFormat::Rgb).unwrap();
 
         for (sub_backend, color) in back.split(&[5]).into_iter().zip([&RED, &GREEN].iter()) {
             sub_backend.into_drawing_area().fill(*color).unwrap();
@@ -691,6 +1210,88 @@ fn test_bitmap_backend_split_and_fill() {
     }
 }
 
+#[cfg(test)]
+#[test]
+fn test_bitmap_backend_pixel_formats() {
+    use crate::prelude::*;
+    let formats = vec![
+        (
+            PixelFormat::Rgb,
+            vec![255, 0, 0],
+            vec![127, 0, 127],
+            vec![0, 255, 0],
+        ),
+        (
+            PixelFormat::Rgba,
+            vec![255, 0, 0, 255],
+            vec![127, 0, 127, 255],
+            vec![0, 255, 0, 255],
+        ),
+        (
+            PixelFormat::Bgra,
+            vec![0, 0, 255, 255],
+            vec![127, 0, 127, 255],
+            vec![0, 255, 0, 255],
+        ),
+        // The unused byte is left as it is
+        (
+            PixelFormat::Bgrx,
+            vec![0, 0, 255, 7],
+            vec![127, 0, 127, 7],
+            vec![0, 255, 0, 7],
+        ),
+    ];
+    for (format, red, blended, green) in formats {
+        let bpp = format.bytes_per_pixel();
+        let mut buffer = vec![7; 4 * 3 * bpp];
+        {
+            let mut back =
+                BitMapBackend::with_buffer_and_format(&mut buffer, (4, 3), format).unwrap();
+            back.draw_rect((0, 0), (3, 2), &RED, true).unwrap();
+            back.draw_pixel((1, 0), &BLUE.mix(0.5)).unwrap();
+            back.draw_line((3, 0), (3, 2), &GREEN).unwrap();
+            back.blit_bitmap((0, 2), (1, 1), &[0, 255, 0]).unwrap();
+        }
+        let pixel = |x: usize, y: usize| buffer[(y * 4 + x) * bpp..(y * 4 + x + 1) * bpp].to_vec();
+        assert_eq!(pixel(0, 0), red);
+        assert_eq!(pixel(1, 0), blended);
+        assert_eq!(pixel(3, 1), green);
+        assert_eq!(pixel(0, 2), green);
+        assert_eq!(pixel(1, 2), red);
+    }
+
+    // The buffer must have exactly the length of the image in the format
+    let mut buffer = vec![0; 4 * 3 * 3];
+    match BitMapBackend::with_buffer_and_format(&mut buffer, (4, 3), PixelFormat::Bgrx) {
+        Err(BitMapBackendError::BufferSizeMismatch { expected, actual }) => {
+            assert_eq!((expected, actual), (48, 36));
+        }
+        _ => panic!("The buffer of RGB pixels isn't a BGRX image"),
+    };
+}
+
+#[cfg(test)]
+#[test]
+fn test_bitmap_backend_split_buffer() {
+    use crate::prelude::*;
+    let mut buffer = vec![0; 2 * 6 * 4];
+    {
+        let backends =
+            BitMapBackend::split_buffer(&mut buffer, (2, 6), PixelFormat::Bgrx, &[1, 3]).unwrap();
+        let sizes: Vec<_> = backends.iter().map(|b| b.get_size()).collect();
+        assert_eq!(sizes, vec![(2, 1), (2, 3), (2, 2)]);
+        for (backend, color) in backends.into_iter().zip([RED, GREEN, BLUE].iter()) {
+            backend.into_drawing_area().fill(color).unwrap();
+        }
+    }
+    let rows: Vec<_> = buffer.chunks(2 * 4).map(|row| row[..3].to_vec()).collect();
+    assert_eq!(rows[0], vec![0, 0, 255]);
+    assert_eq!(rows[1..4], vec![vec![0, 255, 0]; 3][..]);
+    assert_eq!(rows[4..], vec![vec![255, 0, 0]; 2][..]);
+
+    assert!(BitMapBackend::split_buffer(&mut buffer, (2, 5), PixelFormat::Bgrx, &[1]).is_err());
+}
+
 #[cfg(test)]
 #[test]
 fn test_draw_rect_out_of_range() {
@@ -698,7 +1299,12 @@ fn test_draw_rect_out_of_range() {
     let mut buffer = vec![0; 1099 * 1000 * 3];
 
     {
-        let mut back = BitMapBackend::with_buffer(&mut buffer, (1000, 1000));
+        let mut back = BitMapBackend::with_buffer_and_format(
+            &mut buffer[..1000 * 1000 * 3],
+            (1000, 1000),
+            PixelFormat::Rgb,
+        )
+        .unwrap();
 
         back.draw_line((1100, 0), (1100, 999), &RED.to_rgba())
             .unwrap();
@@ -724,7 +1330,9 @@ fn test_draw_line_out_of_range() {
     let mut buffer = vec![0; 1000 * 1000 * 3];
 
     {
-        let mut back = BitMapBackend::with_buffer(&mut buffer, (1000, 1000));
+        let mut back =
+            BitMapBackend::with_buffer_and_format(&mut buffer, (1000, 1000), PixelFormat::Rgb)
+                .unwrap();
 
         back.draw_line((-1000, -1000), (2000, 2000), &WHITE.to_rgba())
             .unwrap();
@@ -742,3 +1350,421 @@ fn test_draw_line_out_of_range() {
         }
     }
 }
+
+#[cfg(test)]
+#[test]
+fn test_bitmap_backend_fill_polygon() {
+    use crate::prelude::*;
+    let is_red = |buffer: &[u8], x: usize, y: usize| buffer[(y * 40 + x) * 3] == 255;
+
+    let mut buffer = vec![0; 40 * 40 * 3];
+    {
+        let back =
+            BitMapBackend::with_buffer_and_format(&mut buffer, (40, 40), PixelFormat::Rgb).unwrap();
+        let area = back.into_drawing_area();
+        // The pentagram, whose center is outside of it by the even-odd rule
+        area.draw(&Polygon::new(
+            vec![(20, 0), (32, 38), (1, 14), (39, 14), (8, 38)],
+            &RED,
+        ))
+        .unwrap();
+    }
+    assert!(is_red(&buffer, 20, 8));
+    assert!(is_red(&buffer, 5, 15));
+    assert!(is_red(&buffer, 30, 35));
+    assert!(!is_red(&buffer, 20, 20));
+
+    let mut buffer = vec![0; 40 * 40 * 3];
+    {
+        let back =
+            BitMapBackend::with_buffer_and_format(&mut buffer, (40, 40), PixelFormat::Rgb).unwrap();
+        let area = back.into_drawing_area();
+        // The concave polygon
+        area.draw(&Polygon::new(
+            vec![
+                (2, 2),
+                (37, 2),
+                (37, 37),
+                (27, 37),
+                (27, 12),
+                (12, 12),
+                (12, 37),
+                (2, 37),
+            ],
+            &RED,
+        ))
+        .unwrap();
+    }
+    assert!(is_red(&buffer, 20, 5));
+    assert!(is_red(&buffer, 5, 30));
+    assert!(is_red(&buffer, 32, 30));
+    assert!(!is_red(&buffer, 20, 25));
+
+    let mut buffer = vec![0; 40 * 40 * 3];
+    {
+        let back =
+            BitMapBackend::with_buffer_and_format(&mut buffer, (40, 40), PixelFormat::Rgb).unwrap();
+        let area = back.into_drawing_area();
+        // The degenerate polygons are drawn as nothing or a line
+        area.draw(&Polygon::new(Vec::<(i32, i32)>::new(), &RED))
+            .unwrap();
+        area.draw(&Polygon::new(vec![(5, 5)], &RED)).unwrap();
+        area.draw(&Polygon::new(vec![(10, 10), (20, 20), (30, 30)], &RED))
+            .unwrap();
+    }
+    for x in 0..40 {
+        for y in 0..40 {
+            let expected = (x, y) == (5, 5) || (x == y && x >= 10 && x <= 30);
+            assert_eq!(is_red(&buffer, x, y), expected);
+        }
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_bitmap_backend_arc_and_sector() {
+    use crate::prelude::*;
+    use std::f64::consts::PI;
+    let is_red = |buffer: &[u8], x: usize, y: usize| buffer[(y * 40 + x) * 3] == 255;
+
+    let mut buffer = vec![0; 40 * 40 * 3];
+    {
+        let mut back =
+            BitMapBackend::with_buffer_and_format(&mut buffer, (40, 40), PixelFormat::Rgb).unwrap();
+        // The sector sweeping 270 degrees leaves out the lower right quarter
+        back.fill_sector((20, 20), (5, 15), (0.0, PI * 1.5), &RED)
+            .unwrap();
+    }
+    assert!(is_red(&buffer, 30, 20));
+    assert!(is_red(&buffer, 20, 10));
+    assert!(is_red(&buffer, 10, 20));
+    assert!(is_red(&buffer, 20, 30));
+    assert!(is_red(&buffer, 12, 28));
+    assert!(!is_red(&buffer, 28, 28));
+    assert!(!is_red(&buffer, 20, 20));
+    assert!(!is_red(&buffer, 20, 3));
+
+    let mut buffer = vec![0; 40 * 40 * 3];
+    {
+        let mut back =
+            BitMapBackend::with_buffer_and_format(&mut buffer, (40, 40), PixelFormat::Rgb).unwrap();
+        back.draw_arc((20, 20), 10, (0.0, PI / 2.0), &RED).unwrap();
+    }
+    // Only the upper right quarter of the circle is drawn
+    let mut count = 0;
+    for x in 0..40 {
+        for y in 0..40 {
+            if is_red(&buffer, x, y) {
+                let (dx, dy) = (x as f64 - 20.0, 20.0 - y as f64);
+                assert!((dx.hypot(dy) - 10.0).abs() < 0.75);
+                assert!(dx >= 0.0 && dy >= 0.0);
+                count += 1;
+            }
+        }
+    }
+    assert_eq!(count, 15);
+    assert!(is_red(&buffer, 30, 20));
+    assert!(is_red(&buffer, 20, 10));
+}
+
+#[cfg(test)]
+#[test]
+fn test_bitmap_backend_draw_image() {
+    use crate::drawing::backend::{ImageScaling, PixelFormat};
+    let pixel = |buffer: &[u8], x: usize, y: usize| {
+        let base = (y * 40 + x) * 3;
+        (buffer[base], buffer[base + 1], buffer[base + 2])
+    };
+
+    // The bitmaps partially or completely outside of the backend are cut
+    let src: Vec<u8> = (0..16).flat_map(|idx| vec![idx as u8, 0, 0]).collect();
+    let mut buffer = vec![0; 40 * 40 * 3];
+    {
+        let mut back =
+            BitMapBackend::with_buffer_and_format(&mut buffer, (40, 40), PixelFormat::Rgb).unwrap();
+        back.blit_bitmap((-2, -1), (4, 4), &src).unwrap();
+        back.blit_bitmap((38, 37), (4, 4), &src).unwrap();
+        back.blit_bitmap((50, 50), (4, 4), &src).unwrap();
+        back.blit_bitmap((-10, 20), (4, 4), &src).unwrap();
+    }
+    assert_eq!(pixel(&buffer, 0, 0), (6, 0, 0));
+    assert_eq!(pixel(&buffer, 1, 2), (15, 0, 0));
+    assert_eq!(pixel(&buffer, 2, 0), (0, 0, 0));
+    assert_eq!(pixel(&buffer, 39, 39), (9, 0, 0));
+    assert_eq!(pixel(&buffer, 0, 20), (0, 0, 0));
+
+    // The red pixel and the half transparent blue one, scaled up ten times
+    let src = [255, 0, 0, 255, 0, 0, 255, 128];
+    for &scaling in [ImageScaling::Nearest, ImageScaling::Bilinear].iter() {
+        let mut buffer = vec![0; 40 * 40 * 3];
+        {
+            let mut back =
+                BitMapBackend::with_buffer_and_format(&mut buffer, (40, 40), PixelFormat::Rgb)
+                    .unwrap();
+            back.draw_image((0, 0), (20, 10), (2, 1), &src, PixelFormat::Rgba, scaling)
+                .unwrap();
+        }
+        assert_eq!(pixel(&buffer, 0, 5), (255, 0, 0));
+        assert_eq!(pixel(&buffer, 19, 5), (0, 0, 128));
+        assert_eq!(pixel(&buffer, 25, 5), (0, 0, 0));
+        let (r, _, b) = pixel(&buffer, 10, 5);
+        match scaling {
+            ImageScaling::Nearest => assert_eq!((r, b), (0, 128)),
+            ImageScaling::Bilinear => assert!(r > 64 && r < 192 && b > 32 && b < 96),
+        }
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_bitmap_backend_rounded_rect() {
+    use crate::prelude::*;
+    let is_red = |buffer: &[u8], x: usize, y: usize| buffer[(y * 40 + x) * 3] == 255;
+
+    let mut filled = vec![0; 40 * 40 * 3];
+    {
+        let mut back =
+            BitMapBackend::with_buffer_and_format(&mut filled, (40, 40), PixelFormat::Rgb).unwrap();
+        back.draw_rounded_rect((5, 5), (35, 30), [10, 0, 10, 3], &RED, true)
+            .unwrap();
+    }
+    assert!(!is_red(&filled, 5, 5));
+    assert!(is_red(&filled, 15, 5));
+    assert!(is_red(&filled, 5, 15));
+    assert!(is_red(&filled, 35, 5));
+    assert!(!is_red(&filled, 35, 30));
+    assert!(!is_red(&filled, 5, 30));
+    assert!(is_red(&filled, 8, 30));
+    assert!(is_red(&filled, 20, 18));
+
+    // The outline covers the edge pixels of the filled rectangle
+    let mut outlined = vec![0; 40 * 40 * 3];
+    {
+        let mut back =
+            BitMapBackend::with_buffer_and_format(&mut outlined, (40, 40), PixelFormat::Rgb)
+                .unwrap();
+        back.draw_rounded_rect((5, 5), (35, 30), [10, 0, 10, 3], &RED, false)
+            .unwrap();
+    }
+    for x in 1..39 {
+        for y in 1..39 {
+            if is_red(&outlined, x, y) {
+                assert!(is_red(&filled, x, y));
+            }
+            let edge = [(0, 1), (1, 0), (0, -1), (-1, 0)].iter().any(|(dx, dy)| {
+                let (x, y) = (x as i32 + dx, y as i32 + dy);
+                !is_red(&filled, x as usize, y as usize)
+            });
+            if is_red(&filled, x, y) && edge {
+                assert!(is_red(&outlined, x, y));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_bitmap_backend_gradient_fill() {
+    use crate::prelude::*;
+    let pixel = |buffer: &[u8], x: usize, y: usize| {
+        let base = (y * 40 + x) * 3;
+        (buffer[base], buffer[base + 1], buffer[base + 2])
+    };
+
+    let mut buffer = vec![0; 40 * 40 * 3];
+    {
+        let root = BitMapBackend::with_buffer_and_format(&mut buffer, (40, 40), PixelFormat::Rgb)
+            .unwrap()
+            .into_drawing_area();
+        let horizontal = LinearGradient::new(0.0, vec![(0.0, RED), (1.0, BLUE)]);
+        root.draw(&Rectangle::new(
+            [(0, 0), (20, 9)],
+            ShapeStyle::gradient(horizontal),
+        ))
+        .unwrap();
+        let vertical = LinearGradient::new(90.0, vec![(0.0, RED), (1.0, BLUE)]);
+        root.draw(&Polygon::new(
+            vec![(0, 20), (10, 20), (10, 30), (0, 30)],
+            ShapeStyle::gradient(vertical),
+        ))
+        .unwrap();
+        let radial = RadialGradient::new(vec![(0.0, WHITE), (1.0, BLUE)]);
+        root.draw(&Circle::new((30, 30), 8, ShapeStyle::gradient(radial)))
+            .unwrap();
+    }
+    // The horizontal gradient goes from the left to the right of the rectangle
+    assert_eq!(pixel(&buffer, 0, 5), (255, 0, 0));
+    assert_eq!(pixel(&buffer, 10, 5), (128, 0, 128));
+    assert_eq!(pixel(&buffer, 20, 5), (0, 0, 255));
+    assert_eq!(pixel(&buffer, 21, 5), (0, 0, 0));
+    // The vertical gradient goes from the top to the bottom of the polygon
+    assert_eq!(pixel(&buffer, 5, 20), (255, 0, 0));
+    assert_eq!(pixel(&buffer, 2, 25), (128, 0, 128));
+    assert!(pixel(&buffer, 8, 29).2 > 200);
+    // The radial gradient goes from the center to the edge of the circle
+    assert_eq!(pixel(&buffer, 30, 30), (255, 255, 255));
+    assert_eq!(pixel(&buffer, 34, 30), (128, 128, 255));
+    assert_eq!(pixel(&buffer, 38, 30), (0, 0, 255));
+    assert_eq!(pixel(&buffer, 39, 30), (0, 0, 0));
+}
+
+#[cfg(test)]
+#[test]
+fn test_bitmap_backend_pattern_fill() {
+    use crate::prelude::*;
+    let is_black = |buffer: &[u8], x: usize, y: usize| buffer[(y * 20 + x) * 3] == 0;
+
+    let mut buffer = vec![255; 20 * 20 * 3];
+    {
+        let root = BitMapBackend::with_buffer_and_format(&mut buffer, (20, 20), PixelFormat::Rgb)
+            .unwrap()
+            .into_drawing_area();
+        let pattern = Pattern::new(PatternFill::DiagonalUp).spacing(4);
+        // The hatches of each bar start at its own corner
+        for x in [0, 7].iter() {
+            root.draw(&Rectangle::new(
+                [(*x, 0), (*x + 5, 9)],
+                BLACK.filled().pattern(pattern.clone()),
+            ))
+            .unwrap();
+        }
+        root.draw(&Circle::new(
+            (10, 15),
+            4,
+            BLACK.filled().pattern(PatternFill::Horizontal),
+        ))
+        .unwrap();
+    }
+    for y in 0..10 {
+        for x in 0..6 {
+            assert_eq!(is_black(&buffer, x, y), (x + y) % 4 == 0);
+            assert_eq!(is_black(&buffer, x + 7, y), (x + y) % 4 == 0);
+        }
+        assert!(!is_black(&buffer, 6, y));
+    }
+    // The pattern of the circle starts at the top of its bounding box
+    assert!(is_black(&buffer, 10, 11));
+    assert!(!is_black(&buffer, 10, 12));
+    assert!(is_black(&buffer, 10, 19));
+    assert!(!is_black(&buffer, 1, 15));
+}
+
+#[cfg(test)]
+#[test]
+fn test_bitmap_backend_clip() {
+    use crate::prelude::*;
+    let mut buffer = vec![0; 20 * 20 * 3];
+    {
+        let mut back =
+            BitMapBackend::with_buffer_and_format(&mut buffer, (20, 20), PixelFormat::Rgb).unwrap();
+        back.set_clip(Some(BackendClip {
+            upper_left: (5, 5),
+            bottom_right: (15, 15),
+            clip_text: true,
+        }))
+        .unwrap();
+        back.draw_rect((0, 0), (19, 19), &RED, true).unwrap();
+        back.draw_rect((0, 0), (19, 19), &BLUE.mix(0.5), true)
+            .unwrap();
+        back.draw_line((10, 0), (10, 19), &GREEN).unwrap();
+        back.draw_line((0, 0), (19, 19), &GREEN).unwrap();
+        back.draw_circle((10, 10), 9, &WHITE, false).unwrap();
+        back.blit_bitmap((0, 0), (20, 20), &[255; 20 * 20 * 3])
+            .unwrap();
+        back.set_clip(None).unwrap();
+        back.draw_pixel((0, 0), &RED.to_rgba()).unwrap();
+    }
+    for y in 0..20 {
+        for x in 0..20 {
+            let pixel = &buffer[(y * 20 + x) * 3..(y * 20 + x + 1) * 3];
+            if (x, y) == (0, 0) {
+                assert_eq!(pixel, [255, 0, 0]);
+            } else if (5..15).contains(&x) && (5..15).contains(&y) {
+                assert_eq!(pixel, [255, 255, 255]);
+            } else {
+                assert_eq!(pixel, [0, 0, 0]);
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "gif", feature = "image"))]
+#[test]
+fn test_gif_backend() {
+    use crate::coord::Shift;
+    use crate::prelude::*;
+    use gif::SetParameter;
+    let colors = [RED, GREEN, BLUE];
+    // Each frame has the stripes of all the colors, shifted by one from the previous frame
+    let draw_frame = |root: &DrawingArea<GifBackend, Shift>, shift: usize| {
+        for (idx, stripe) in root.split_evenly((1, 3)).iter().enumerate() {
+            stripe.fill(&colors[(idx + shift) % 3]).unwrap();
+        }
+    };
+    let read_frames = |path: &std::path::Path| {
+        let mut decoder = gif::Decoder::new(std::fs::File::open(path).unwrap());
+        decoder.set(gif::ColorOutput::RGBA);
+        let mut reader = decoder.read_info().unwrap();
+        let mut frames = vec![];
+        while let Some(frame) = reader.read_next_frame().unwrap() {
+            let stripes: Vec<_> = [0, 10, 20]
+                .iter()
+                .map(|x| {
+                    let c = &frame.buffer[x * 4..x * 4 + 3];
+                    (c[0], c[1], c[2])
+                })
+                .collect();
+            frames.push((frame.delay, frame.palette.is_some(), stripes));
+        }
+        frames
+    };
+    let stripes = |shift: usize| -> Vec<_> {
+        (0..3)
+            .map(|idx| Color::rgb(&colors[(idx + shift) % 3]))
+            .collect()
+    };
+
+    let path = std::env::temp_dir().join("plotters-test-gif-backend.gif");
+    {
+        let root = GifBackend::new(&path, (30, 10), 100)
+            .unwrap()
+            .palette(GifPalette::Global)
+            .repeat(Some(2))
+            .into_drawing_area();
+        for shift in 0..3 {
+            draw_frame(&root, shift);
+            root.present().unwrap();
+        }
+        // The frame which isn't presented is kept when the backend is dropped
+        draw_frame(&root, 0);
+    }
+    // The frames are indexed into the palette of the first frame, which has all the colors
+    let frames = read_frames(&path);
+    let expected: Vec<_> = [0, 1, 2, 0]
+        .iter()
+        .map(|&shift| (10, false, stripes(shift)))
+        .collect();
+    assert_eq!(frames, expected);
+    let data = std::fs::read(&path).unwrap();
+    let netscape = data
+        .windows(11)
+        .position(|w| w == b"NETSCAPE2.0")
+        .expect("Loop extension");
+    assert_eq!(data[netscape + 13..netscape + 15], [2, 0]);
+
+    {
+        let root = GifBackend::new(&path, (30, 10), 50)
+            .unwrap()
+            .repeat(Some(0))
+            .into_drawing_area();
+        for shift in 0..2 {
+            draw_frame(&root, shift);
+            root.present().unwrap();
+        }
+    }
+    let frames = read_frames(&path);
+    assert_eq!(frames, vec![(5, true, stripes(0)), (5, true, stripes(1))]);
+    let data = std::fs::read(&path).unwrap();
+    assert!(data.windows(11).all(|w| w != b"NETSCAPE2.0"));
+    std::fs::remove_file(&path).unwrap();
+}
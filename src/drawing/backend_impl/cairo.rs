@@ -3,7 +3,7 @@ use cairo::{Context as CairoContext, FontSlant, FontWeight, Status as CairoStatu
 #[allow(unused_imports)]
 use crate::drawing::backend::{BackendCoord, BackendStyle, DrawingBackend, DrawingErrorKind};
 #[allow(unused_imports)]
-use crate::style::{Color, FontDesc, FontStyle, FontTransform, RGBAColor};
+use crate::style::{Color, FontDesc, FontStyle, RGBAColor};
 
 /// The drawing backend that is backed with a Cairo context
 pub struct CairoBackend<'a> {
@@ -52,6 +52,19 @@ impl<'a> CairoBackend<'a> {
         Ok(())
     }
 
+    fn set_dash_pattern(
+        &self,
+        pattern: Option<&[u32]>,
+    ) -> Result<(), DrawingErrorKind<CairoError>> {
+        let dashes: Vec<f64> = pattern
+            .unwrap_or(&[])
+            .iter()
+            .map(|x| f64::from(*x))
+            .collect();
+        self.call_cairo(|c| c.set_dash(&dashes, 0.0))?;
+        Ok(())
+    }
+
     pub fn new(context: &'a CairoContext, (w, h): (u32, u32)) -> Result<Self, CairoError> {
         let ret = Self {
             context,
@@ -116,6 +129,7 @@ impl<'a> DrawingBackend for CairoBackend<'a> {
 
         self.set_color(&style.as_color())?;
         self.set_stroke_width(style.stroke_width())?;
+        self.set_dash_pattern(style.dash_pattern())?;
 
         self.call_cairo(|c| c.line_to(f64::from(to.0), f64::from(to.1)))?;
         self.call_cairo(|c| c.stroke())?;
@@ -131,6 +145,7 @@ impl<'a> DrawingBackend for CairoBackend<'a> {
     ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
         self.set_color(&style.as_color())?;
         self.set_stroke_width(style.stroke_width())?;
+        self.set_dash_pattern(style.dash_pattern())?;
 
         self.call_cairo(|c| {
             c.rectangle(
@@ -157,6 +172,7 @@ impl<'a> DrawingBackend for CairoBackend<'a> {
     ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
         self.set_color(&style.as_color())?;
         self.set_stroke_width(style.stroke_width())?;
+        self.set_dash_pattern(style.dash_pattern())?;
 
         let mut path = path.into_iter();
 
@@ -180,6 +196,7 @@ impl<'a> DrawingBackend for CairoBackend<'a> {
     ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
         self.set_color(&style.as_color())?;
         self.set_stroke_width(style.stroke_width())?;
+        self.set_dash_pattern(style.dash_pattern())?;
 
         let mut path = path.into_iter();
 
@@ -208,6 +225,7 @@ impl<'a> DrawingBackend for CairoBackend<'a> {
     ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
         self.set_color(&style.as_color())?;
         self.set_stroke_width(style.stroke_width())?;
+        self.set_dash_pattern(style.dash_pattern())?;
 
         self.call_cairo(|c| {
             c.arc(
@@ -236,13 +254,7 @@ impl<'a> DrawingBackend for CairoBackend<'a> {
     ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
         let (mut x, mut y) = (pos.0, pos.1);
 
-        let degree = match font.get_transform() {
-            FontTransform::None => 0.0,
-            FontTransform::Rotate90 => 90.0,
-            FontTransform::Rotate180 => 180.0,
-            FontTransform::Rotate270 => 270.0,
-        } / 180.0
-            * std::f64::consts::PI;
+        let degree = font.get_transform().angle().to_radians();
 
         let layout = font.layout_box(text).map_err(DrawingErrorKind::FontError)?;
 
@@ -268,6 +280,9 @@ impl<'a> DrawingBackend for CairoBackend<'a> {
             FontStyle::Italic => {
                 c.select_font_face(font.get_name(), FontSlant::Italic, FontWeight::Normal)
             }
+            FontStyle::BoldItalic => {
+                c.select_font_face(font.get_name(), FontSlant::Italic, FontWeight::Bold)
+            }
         })?;
         let actual_size = font.get_size();
         self.call_cairo(|c| c.set_font_size(actual_size))?;
@@ -3,7 +3,7 @@ use wasm_bindgen::{JsCast, JsValue};
 use web_sys::{window, CanvasRenderingContext2d, HtmlCanvasElement};
 
 use crate::drawing::backend::{BackendCoord, BackendStyle, DrawingBackend, DrawingErrorKind};
-use crate::style::{Color, FontDesc, FontTransform, RGBAColor};
+use crate::style::{Color, FontDesc, RGBAColor};
 
 /// The backend that is drawing on the HTML canvas
 /// TODO: Support double buffering
@@ -243,13 +243,7 @@ impl DrawingBackend for CanvasBackend {
 
         let (mut x, mut y) = (pos.0, pos.1);
 
-        let degree = match font.get_transform() {
-            FontTransform::None => 0.0,
-            FontTransform::Rotate90 => 90.0,
-            FontTransform::Rotate180 => 180.0,
-            FontTransform::Rotate270 => 270.0,
-        } / 180.0
-            * std::f64::consts::PI;
+        let degree = font.get_transform().angle().to_radians();
 
         if degree != 0.0 {
             self.context.save();
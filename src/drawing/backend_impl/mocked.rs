@@ -1,6 +1,9 @@
 use crate::coord::Shift;
 use crate::drawing::area::IntoDrawingArea;
-use crate::drawing::backend::{BackendCoord, BackendStyle, DrawingBackend, DrawingErrorKind};
+use crate::drawing::backend::{
+    BackendClip, BackendCoord, BackendStyle, DrawingBackend, DrawingErrorKind, ImageScaling,
+    PixelFormat,
+};
 use crate::drawing::DrawingArea;
 use crate::style::{Color, FontDesc, RGBAColor};
 
@@ -18,6 +21,11 @@ pub struct MockedBackend {
     pub num_draw_text_call: u32,
     pub num_draw_path_call: u32,
     pub num_fill_polygon_call: u32,
+    pub num_draw_arc_call: u32,
+    pub num_fill_sector_call: u32,
+    pub num_draw_image_call: u32,
+    pub num_draw_rounded_rect_call: u32,
+    pub num_set_clip_call: u32,
     check_draw_pixel: VecDeque<Box<dyn FnMut(RGBAColor, BackendCoord)>>,
     check_draw_line: VecDeque<Box<dyn FnMut(RGBAColor, u32, BackendCoord, BackendCoord)>>,
     check_draw_rect: VecDeque<Box<dyn FnMut(RGBAColor, u32, bool, BackendCoord, BackendCoord)>>,
@@ -25,6 +33,13 @@ pub struct MockedBackend {
     check_draw_circle: VecDeque<Box<dyn FnMut(RGBAColor, u32, bool, BackendCoord, u32)>>,
     check_draw_text: VecDeque<Box<dyn FnMut(RGBAColor, &str, f64, BackendCoord, &str)>>,
     check_fill_polygon: VecDeque<Box<dyn FnMut(RGBAColor, Vec<BackendCoord>)>>,
+    check_draw_arc: VecDeque<Box<dyn FnMut(RGBAColor, u32, BackendCoord, u32, (f64, f64))>>,
+    check_fill_sector: VecDeque<Box<dyn FnMut(RGBAColor, BackendCoord, (u32, u32), (f64, f64))>>,
+    check_draw_image: VecDeque<Box<dyn FnMut(BackendCoord, (u32, u32), (u32, u32), &[u8])>>,
+    #[allow(clippy::type_complexity)]
+    check_draw_rounded_rect:
+        VecDeque<Box<dyn FnMut(RGBAColor, u32, bool, BackendCoord, BackendCoord, [u32; 4])>>,
+    check_set_clip: VecDeque<Box<dyn FnMut(Option<BackendClip>)>>,
     drop_check: Option<Box<dyn FnMut(&Self)>>,
 }
 
@@ -57,6 +72,11 @@ impl MockedBackend {
             num_draw_text_call: 0,
             num_draw_path_call: 0,
             num_fill_polygon_call: 0,
+            num_draw_arc_call: 0,
+            num_fill_sector_call: 0,
+            num_draw_image_call: 0,
+            num_draw_rounded_rect_call: 0,
+            num_set_clip_call: 0,
             check_draw_pixel: vec![].into(),
             check_draw_line: vec![].into(),
             check_draw_rect: vec![].into(),
@@ -64,6 +84,11 @@ impl MockedBackend {
             check_draw_circle: vec![].into(),
             check_draw_text: vec![].into(),
             check_fill_polygon: vec![].into(),
+            check_draw_arc: vec![].into(),
+            check_fill_sector: vec![].into(),
+            check_draw_image: vec![].into(),
+            check_draw_rounded_rect: vec![].into(),
+            check_set_clip: vec![].into(),
             drop_check: None,
         }
     }
@@ -83,6 +108,38 @@ impl MockedBackend {
     def_set_checker_func!(check_draw_text, RGBAColor, &str, f64, BackendCoord, &str);
     def_set_checker_func!(drop_check, &Self);
     def_set_checker_func!(check_fill_polygon, RGBAColor, Vec<BackendCoord>);
+    def_set_checker_func!(
+        check_draw_arc,
+        RGBAColor,
+        u32,
+        BackendCoord,
+        u32,
+        (f64, f64)
+    );
+    def_set_checker_func!(
+        check_fill_sector,
+        RGBAColor,
+        BackendCoord,
+        (u32, u32),
+        (f64, f64)
+    );
+    def_set_checker_func!(
+        check_draw_image,
+        BackendCoord,
+        (u32, u32),
+        (u32, u32),
+        &[u8]
+    );
+    def_set_checker_func!(
+        check_draw_rounded_rect,
+        RGBAColor,
+        u32,
+        bool,
+        BackendCoord,
+        BackendCoord,
+        [u32; 4]
+    );
+    def_set_checker_func!(check_set_clip, Option<BackendClip>);
 
     fn check_before_draw(&mut self) {
         self.draw_count += 1;
@@ -176,6 +233,34 @@ impl DrawingBackend for MockedBackend {
         Ok(())
     }
 
+    fn draw_rounded_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        radii: [u32; 4],
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.check_before_draw();
+        self.num_draw_rounded_rect_call += 1;
+        let color = style.as_color().to_rgba();
+        if let Some(mut checker) = self.check_draw_rounded_rect.pop_front() {
+            checker(
+                color,
+                style.stroke_width(),
+                fill,
+                upper_left,
+                bottom_right,
+                radii,
+            );
+
+            if self.check_draw_rounded_rect.is_empty() {
+                self.check_draw_rounded_rect.push_back(checker);
+            }
+        }
+        Ok(())
+    }
+
     fn draw_path<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
         &mut self,
         path: I,
@@ -232,6 +317,67 @@ impl DrawingBackend for MockedBackend {
         Ok(())
     }
 
+    fn draw_arc<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radius: u32,
+        angles: (f64, f64),
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.check_before_draw();
+        self.num_draw_arc_call += 1;
+        let color = style.as_color().to_rgba();
+        if let Some(mut checker) = self.check_draw_arc.pop_front() {
+            checker(color, style.stroke_width(), center, radius, angles);
+
+            if self.check_draw_arc.is_empty() {
+                self.check_draw_arc.push_back(checker);
+            }
+        }
+        Ok(())
+    }
+
+    fn fill_sector<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radii: (u32, u32),
+        angles: (f64, f64),
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.check_before_draw();
+        self.num_fill_sector_call += 1;
+        let color = style.as_color().to_rgba();
+        if let Some(mut checker) = self.check_fill_sector.pop_front() {
+            checker(color, center, radii, angles);
+
+            if self.check_fill_sector.is_empty() {
+                self.check_fill_sector.push_back(checker);
+            }
+        }
+        Ok(())
+    }
+
+    fn draw_image(
+        &mut self,
+        pos: BackendCoord,
+        size: (u32, u32),
+        src_size: (u32, u32),
+        src: &[u8],
+        _format: PixelFormat,
+        _scaling: ImageScaling,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.check_before_draw();
+        self.num_draw_image_call += 1;
+        if let Some(mut checker) = self.check_draw_image.pop_front() {
+            checker(pos, size, src_size, src);
+
+            if self.check_draw_image.is_empty() {
+                self.check_draw_image.push_back(checker);
+            }
+        }
+        Ok(())
+    }
+
     fn draw_text<'a>(
         &mut self,
         text: &str,
@@ -251,6 +397,21 @@ impl DrawingBackend for MockedBackend {
         }
         Ok(())
     }
+
+    fn set_clip(
+        &mut self,
+        clip: Option<BackendClip>,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.num_set_clip_call += 1;
+        if let Some(mut checker) = self.check_set_clip.pop_front() {
+            checker(clip);
+
+            if self.check_set_clip.is_empty() {
+                self.check_set_clip.push_back(checker);
+            }
+        }
+        Ok(())
+    }
 }
 
 impl Drop for MockedBackend {
@@ -4,7 +4,16 @@ mod svg;
 pub use self::svg::{svg_types, SVGBackend};
 
 mod bitmap;
-pub use bitmap::BitMapBackend;
+pub use bitmap::{BitMapBackend, BitMapBackendError};
+#[cfg(all(feature = "gif", not(target_arch = "wasm32"), feature = "image"))]
+pub use bitmap::{GifBackend, GifPalette};
+#[cfg(all(feature = "apng_backend", not(target_arch = "wasm32"), feature = "image"))]
+mod apng;
+#[cfg(all(feature = "apng_backend", not(target_arch = "wasm32"), feature = "image"))]
+pub use bitmap::ApngBackend;
+
+mod recording;
+pub use recording::{replay, DrawCommand, RecordingBackend};
 
 #[cfg(target_arch = "wasm32")]
 mod canvas;
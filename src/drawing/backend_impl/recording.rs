@@ -0,0 +1,600 @@
+use crate::drawing::backend::{
+    BackendClip, BackendCoord, BackendShadow, BackendStyle, DrawingBackend, DrawingErrorKind,
+    ImageScaling, PixelFormat,
+};
+use crate::drawing::rasterizer::{draw_shadow, AlphaMask};
+use crate::style::{FontDesc, FontFamily, FontStyle, FontTransform, RGBAColor, ShapeStyle};
+
+use std::convert::Infallible;
+
+/// A draw command recorded by `RecordingBackend`, which is one call of the drawing backend API
+/// with its arguments. The commands are plain data, so they can be kept, compared with the
+/// ones of the previous frame or replayed with `replay`. With the `serde` feature, they can be
+/// serialized as well, to be saved or sent to another process.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DrawCommand {
+    /// A pixel, see `DrawingBackend::draw_pixel`
+    Pixel { pos: BackendCoord, color: RGBAColor },
+    /// A line, see `DrawingBackend::draw_line`
+    Line {
+        from: BackendCoord,
+        to: BackendCoord,
+        style: ShapeStyle,
+    },
+    /// A rectangle, see `DrawingBackend::draw_rect`
+    Rect {
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        style: ShapeStyle,
+        fill: bool,
+    },
+    /// A rectangle with the rounded corners, see `DrawingBackend::draw_rounded_rect`
+    RoundedRect {
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        radii: [u32; 4],
+        style: ShapeStyle,
+        fill: bool,
+    },
+    /// A path, see `DrawingBackend::draw_path`
+    Path {
+        path: Vec<BackendCoord>,
+        style: ShapeStyle,
+    },
+    /// A path of Bézier curves, see `DrawingBackend::draw_bezier`
+    Bezier {
+        start: BackendCoord,
+        segments: Vec<[BackendCoord; 3]>,
+        style: ShapeStyle,
+    },
+    /// A circle, see `DrawingBackend::draw_circle`
+    Circle {
+        center: BackendCoord,
+        radius: u32,
+        style: ShapeStyle,
+        fill: bool,
+    },
+    /// An arc, see `DrawingBackend::draw_arc`
+    Arc {
+        center: BackendCoord,
+        radius: u32,
+        angles: (f64, f64),
+        style: ShapeStyle,
+    },
+    /// A filled sector, see `DrawingBackend::fill_sector`
+    Sector {
+        center: BackendCoord,
+        radii: (u32, u32),
+        angles: (f64, f64),
+        style: ShapeStyle,
+    },
+    /// A filled polygon, see `DrawingBackend::fill_polygon`
+    Polygon {
+        vertices: Vec<BackendCoord>,
+        style: ShapeStyle,
+    },
+    /// A polygon filled with a gradient, see `DrawingBackend::fill_polygon_gradient`
+    PolygonGradient {
+        vertices: Vec<BackendCoord>,
+        top: (i32, RGBAColor),
+        bottom: (i32, RGBAColor),
+    },
+    /// A text, whose font is kept by its family name, which is looked up again on replay
+    Text {
+        text: String,
+        family: String,
+        size: f64,
+        style: FontStyle,
+        transform: FontTransform,
+        pos: BackendCoord,
+        color: RGBAColor,
+    },
+    /// The change of the clipping rectangle, see `DrawingBackend::set_clip`
+    Clip(Option<BackendClip>),
+    /// The start of the elements drawn with a drop shadow, up to the matching `EndShadow`
+    BeginShadow(BackendShadow),
+    /// The end of the elements drawn with a drop shadow
+    EndShadow,
+    /// A bitmap, see `DrawingBackend::blit_bitmap`
+    Bitmap {
+        pos: BackendCoord,
+        size: (u32, u32),
+        data: Vec<u8>,
+    },
+    /// A scaled bitmap, see `DrawingBackend::draw_image`
+    Image {
+        pos: BackendCoord,
+        size: (u32, u32),
+        src_size: (u32, u32),
+        data: Vec<u8>,
+        format: PixelFormat,
+        scaling: ImageScaling,
+    },
+}
+
+/// Keep the parts of a backend style a recorded command needs to be drawn the same way again
+fn record_style<S: BackendStyle>(style: &S) -> ShapeStyle {
+    ShapeStyle {
+        filled: style.is_filled(),
+        stroke_width: style.stroke_width(),
+        dash_pattern: style.dash_pattern().map(<[u32]>::to_vec),
+        gradient: style.gradient().cloned(),
+        pattern: style.pattern().cloned(),
+        ..ShapeStyle::from(&style.as_color())
+    }
+}
+
+/// The backend that records the draw commands instead of drawing them, so one drawing pass can
+/// be replayed onto the other backends, such as a bitmap and an SVG image of the same figure.
+/// The texts are measured with the fonts of the system as the bitmap backend does.
+pub struct RecordingBackend<'a> {
+    size: (u32, u32),
+    commands: &'a mut Vec<DrawCommand>,
+}
+
+impl<'a> RecordingBackend<'a> {
+    /// Create a new recording backend, which appends its commands to the buffer
+    /// - `buf`: The buffer the commands are recorded in
+    /// - `size`: The size of the figure in pixels
+    pub fn new(buf: &'a mut Vec<DrawCommand>, size: (u32, u32)) -> Self {
+        Self {
+            size,
+            commands: buf,
+        }
+    }
+
+    fn record(&mut self, command: DrawCommand) -> Result<(), DrawingErrorKind<Infallible>> {
+        self.commands.push(command);
+        Ok(())
+    }
+}
+
+impl<'a> DrawingBackend for RecordingBackend<'a> {
+    type ErrorType = Infallible;
+
+    fn get_size(&self) -> (u32, u32) {
+        self.size
+    }
+
+    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<Infallible>> {
+        Ok(())
+    }
+
+    fn present(&mut self) -> Result<(), DrawingErrorKind<Infallible>> {
+        Ok(())
+    }
+
+    fn draw_pixel(
+        &mut self,
+        pos: BackendCoord,
+        color: &RGBAColor,
+    ) -> Result<(), DrawingErrorKind<Infallible>> {
+        self.record(DrawCommand::Pixel {
+            pos,
+            color: color.clone(),
+        })
+    }
+
+    fn draw_line<S: BackendStyle>(
+        &mut self,
+        from: BackendCoord,
+        to: BackendCoord,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Infallible>> {
+        self.record(DrawCommand::Line {
+            from,
+            to,
+            style: record_style(style),
+        })
+    }
+
+    fn draw_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Infallible>> {
+        self.record(DrawCommand::Rect {
+            upper_left,
+            bottom_right,
+            style: record_style(style),
+            fill,
+        })
+    }
+
+    fn draw_rounded_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        radii: [u32; 4],
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Infallible>> {
+        self.record(DrawCommand::RoundedRect {
+            upper_left,
+            bottom_right,
+            radii,
+            style: record_style(style),
+            fill,
+        })
+    }
+
+    fn draw_path<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        path: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Infallible>> {
+        self.record(DrawCommand::Path {
+            path: path.into_iter().collect(),
+            style: record_style(style),
+        })
+    }
+
+    fn draw_bezier<S: BackendStyle>(
+        &mut self,
+        start: BackendCoord,
+        segments: &[[BackendCoord; 3]],
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Infallible>> {
+        self.record(DrawCommand::Bezier {
+            start,
+            segments: segments.to_vec(),
+            style: record_style(style),
+        })
+    }
+
+    fn draw_circle<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radius: u32,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Infallible>> {
+        self.record(DrawCommand::Circle {
+            center,
+            radius,
+            style: record_style(style),
+            fill,
+        })
+    }
+
+    fn draw_arc<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radius: u32,
+        angles: (f64, f64),
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Infallible>> {
+        self.record(DrawCommand::Arc {
+            center,
+            radius,
+            angles,
+            style: record_style(style),
+        })
+    }
+
+    fn fill_sector<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radii: (u32, u32),
+        angles: (f64, f64),
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Infallible>> {
+        self.record(DrawCommand::Sector {
+            center,
+            radii,
+            angles,
+            style: record_style(style),
+        })
+    }
+
+    fn fill_polygon<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        vert: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Infallible>> {
+        self.record(DrawCommand::Polygon {
+            vertices: vert.into_iter().collect(),
+            style: record_style(style),
+        })
+    }
+
+    fn fill_polygon_gradient<I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        vert: I,
+        top: (i32, &RGBAColor),
+        bottom: (i32, &RGBAColor),
+    ) -> Result<(), DrawingErrorKind<Infallible>> {
+        self.record(DrawCommand::PolygonGradient {
+            vertices: vert.into_iter().collect(),
+            top: (top.0, top.1.clone()),
+            bottom: (bottom.0, bottom.1.clone()),
+        })
+    }
+
+    fn draw_text<'b>(
+        &mut self,
+        text: &str,
+        font: &FontDesc<'b>,
+        pos: BackendCoord,
+        color: &RGBAColor,
+    ) -> Result<(), DrawingErrorKind<Infallible>> {
+        self.record(DrawCommand::Text {
+            text: text.to_string(),
+            family: font.get_name().to_string(),
+            size: font.get_size(),
+            style: font.get_style(),
+            transform: font.get_transform(),
+            pos,
+            color: color.clone(),
+        })
+    }
+
+    fn set_clip(&mut self, clip: Option<BackendClip>) -> Result<(), DrawingErrorKind<Infallible>> {
+        self.record(DrawCommand::Clip(clip))
+    }
+
+    /// The shadows are recorded as they are, and they're rasterized on replay only if the
+    /// backend replayed onto can't draw them
+    fn begin_shadow(
+        &mut self,
+        shadow: &BackendShadow,
+    ) -> Result<bool, DrawingErrorKind<Infallible>> {
+        self.record(DrawCommand::BeginShadow(shadow.clone()))?;
+        Ok(true)
+    }
+
+    fn end_shadow(&mut self) -> Result<(), DrawingErrorKind<Infallible>> {
+        self.record(DrawCommand::EndShadow)
+    }
+
+    fn blit_bitmap(
+        &mut self,
+        pos: BackendCoord,
+        size: (u32, u32),
+        src: &[u8],
+    ) -> Result<(), DrawingErrorKind<Infallible>> {
+        self.record(DrawCommand::Bitmap {
+            pos,
+            size,
+            data: src.to_vec(),
+        })
+    }
+
+    fn draw_image(
+        &mut self,
+        pos: BackendCoord,
+        size: (u32, u32),
+        src_size: (u32, u32),
+        src: &[u8],
+        format: PixelFormat,
+        scaling: ImageScaling,
+    ) -> Result<(), DrawingErrorKind<Infallible>> {
+        self.record(DrawCommand::Image {
+            pos,
+            size,
+            src_size,
+            data: src.to_vec(),
+            format,
+            scaling,
+        })
+    }
+}
+
+/// Draw a recorded command on a backend, leaving out the ones of the shadows
+fn replay_command<DB: DrawingBackend>(
+    command: &DrawCommand,
+    backend: &mut DB,
+) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+    match command {
+        DrawCommand::Pixel { pos, color } => backend.draw_pixel(*pos, color),
+        DrawCommand::Line { from, to, style } => backend.draw_line(*from, *to, style),
+        DrawCommand::Rect {
+            upper_left,
+            bottom_right,
+            style,
+            fill,
+        } => backend.draw_rect(*upper_left, *bottom_right, style, *fill),
+        DrawCommand::RoundedRect {
+            upper_left,
+            bottom_right,
+            radii,
+            style,
+            fill,
+        } => backend.draw_rounded_rect(*upper_left, *bottom_right, *radii, style, *fill),
+        DrawCommand::Path { path, style } => backend.draw_path(path.iter().copied(), style),
+        DrawCommand::Bezier {
+            start,
+            segments,
+            style,
+        } => backend.draw_bezier(*start, segments, style),
+        DrawCommand::Circle {
+            center,
+            radius,
+            style,
+            fill,
+        } => backend.draw_circle(*center, *radius, style, *fill),
+        DrawCommand::Arc {
+            center,
+            radius,
+            angles,
+            style,
+        } => backend.draw_arc(*center, *radius, *angles, style),
+        DrawCommand::Sector {
+            center,
+            radii,
+            angles,
+            style,
+        } => backend.fill_sector(*center, *radii, *angles, style),
+        DrawCommand::Polygon { vertices, style } => {
+            backend.fill_polygon(vertices.iter().copied(), style)
+        }
+        DrawCommand::PolygonGradient {
+            vertices,
+            top,
+            bottom,
+        } => backend.fill_polygon_gradient(
+            vertices.iter().copied(),
+            (top.0, &top.1),
+            (bottom.0, &bottom.1),
+        ),
+        DrawCommand::Text {
+            text,
+            family,
+            size,
+            style,
+            transform,
+            pos,
+            color,
+        } => {
+            let font = FontDesc::new(FontFamily::from(family.as_str()), *size, *style)
+                .transform(transform.clone());
+            backend.draw_text(text, &font, *pos, color)
+        }
+        DrawCommand::Clip(clip) => backend.set_clip(*clip),
+        DrawCommand::BeginShadow(_) | DrawCommand::EndShadow => Ok(()),
+        DrawCommand::Bitmap { pos, size, data } => backend.blit_bitmap(*pos, *size, data),
+        DrawCommand::Image {
+            pos,
+            size,
+            src_size,
+            data,
+            format,
+            scaling,
+        } => backend.draw_image(*pos, *size, *src_size, data, *format, *scaling),
+    }
+}
+
+/// Replay the commands recorded by `RecordingBackend` onto another backend, which draws them
+/// as if the figure were drawn on it. The drop shadows the backend can't draw itself are
+/// rasterized and blurred as they are when the elements are drawn on it.
+/// - `commands`: The recorded commands
+/// - `backend`: The backend to draw the commands on
+pub fn replay<DB: DrawingBackend>(
+    commands: &[DrawCommand],
+    backend: &mut DB,
+) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+    let mut idx = 0;
+    while idx < commands.len() {
+        let shadow = match &commands[idx] {
+            DrawCommand::BeginShadow(shadow) => shadow,
+            DrawCommand::EndShadow => {
+                backend.end_shadow()?;
+                idx += 1;
+                continue;
+            }
+            command => {
+                replay_command(command, backend)?;
+                idx += 1;
+                continue;
+            }
+        };
+        idx += 1;
+        if backend.begin_shadow(shadow)? {
+            continue;
+        }
+        // The elements of the shadow are drawn on a mask first, and they're drawn on the
+        // backend after the shadow they cast
+        let end = commands[idx..]
+            .iter()
+            .position(|command| *command == DrawCommand::EndShadow)
+            .map_or(commands.len(), |len| idx + len);
+        let mut mask = AlphaMask::for_shadow(backend.get_size(), shadow);
+        for command in &commands[idx..end] {
+            replay_command(command, &mut mask).map_err(|error| match error {
+                DrawingErrorKind::DrawingError(error) => match error {},
+                DrawingErrorKind::FontError(error) => DrawingErrorKind::FontError(error),
+            })?;
+        }
+        draw_shadow(backend, mask, shadow)?;
+        for command in &commands[idx..end] {
+            replay_command(command, backend)?;
+        }
+        idx = end + 1;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    fn draw_figure<DB: DrawingBackend>(root: DrawingArea<DB, crate::coord::Shift>) {
+        root.fill(&WHITE).unwrap();
+        let mut chart = ChartBuilder::on(&root)
+            .caption("Replay", ("sans-serif", 20))
+            .margin(5)
+            .x_label_area_size(25)
+            .y_label_area_size(30)
+            .build_ranged(0.0..1.0, 0.0..1.0)
+            .unwrap();
+        chart.configure_mesh().draw().unwrap();
+        chart
+            .draw_series(LineSeries::new(
+                (0..=20).map(|i| i as f64 / 20.0).map(|x| (x, x * x)),
+                &RED,
+            ))
+            .unwrap();
+        chart
+            .draw_series(std::iter::once(
+                Circle::new((0.5, 0.5), 8, BLUE.filled()).with_shadow((3, 3), 2, &BLACK.mix(0.5)),
+            ))
+            .unwrap();
+    }
+
+    #[test]
+    fn test_replay_matches_direct_drawing() {
+        let mut commands = vec![];
+        draw_figure(RecordingBackend::new(&mut commands, (200, 150)).into_drawing_area());
+        assert!(commands
+            .iter()
+            .any(|command| matches!(command, DrawCommand::Text { text, .. } if text == "Replay")));
+        assert!(commands.contains(&DrawCommand::EndShadow));
+        assert!(commands.iter().any(|command| matches!(
+            command,
+            DrawCommand::Circle { style, fill: true, .. } if style.filled
+        )));
+
+        // The bitmap backend rasterizes the shadow on replay as it does when drawing directly
+        let mut direct = vec![0; 200 * 150 * 3];
+        draw_figure(
+            BitMapBackend::with_buffer_and_format(&mut direct, (200, 150), PixelFormat::Rgb)
+                .unwrap()
+                .into_drawing_area(),
+        );
+        let mut replayed = vec![0; 200 * 150 * 3];
+        replay(
+            &commands,
+            &mut BitMapBackend::with_buffer_and_format(&mut replayed, (200, 150), PixelFormat::Rgb)
+                .unwrap(),
+        )
+        .unwrap();
+        assert!(direct == replayed);
+
+        #[cfg(feature = "svg")]
+        {
+            let mut direct = vec![];
+            draw_figure(SVGBackend::with_buffer(&mut direct, (200, 150)).into_drawing_area());
+            let mut replayed = vec![];
+            {
+                let mut backend = SVGBackend::with_buffer(&mut replayed, (200, 150));
+                replay(&commands, &mut backend).unwrap();
+                backend.present().unwrap();
+            }
+            assert_eq!(String::from_utf8(direct), String::from_utf8(replayed));
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serialize_commands() {
+        let mut commands = vec![];
+        draw_figure(RecordingBackend::new(&mut commands, (200, 150)).into_drawing_area());
+        let json = serde_json::to_string(&commands).unwrap();
+        let parsed: Vec<DrawCommand> = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, commands);
+    }
+}
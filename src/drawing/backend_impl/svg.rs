@@ -3,12 +3,22 @@ The SVG image drawing backend
 */
 pub use svg as svg_types;
 
-use svg::node::element::{Circle, Line, Polygon, Polyline, Rectangle, Text};
-use svg::Document;
+use svg::node::element::{
+    Circle, ClipPath, Definitions, Element, Filter, Group, Line,
+    LinearGradient as LinearGradientNode, Path as PathNode, Pattern as PatternNode, Polygon,
+    Polyline, RadialGradient as RadialGradientNode, Rectangle, Stop, Text,
+};
+use svg::{Document, Node};
 
-use crate::drawing::backend::{BackendCoord, BackendStyle, DrawingBackend, DrawingErrorKind};
-use crate::style::{Color, FontDesc, FontStyle, FontTransform, RGBAColor};
+use crate::drawing::backend::{
+    BackendClip, BackendCoord, BackendShadow, BackendStyle, DrawingBackend, DrawingErrorKind,
+};
+#[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
+use crate::drawing::backend::{ImageScaling, PixelFormat};
+use crate::drawing::rasterizer::{arc_sweep, clamp_corner_radii};
+use crate::style::{Color, FontDesc, Gradient, Pattern, PatternFill, RGBAColor};
 
+use std::collections::HashMap;
 use std::io::{Cursor, Error};
 use std::path::Path;
 
@@ -21,6 +31,77 @@ fn make_svg_opacity<C: Color>(color: &C) -> String {
     return format!("{}", color.alpha());
 }
 
+fn make_svg_dash_array<S: BackendStyle>(style: &S) -> Option<String> {
+    let pattern = style.dash_pattern()?;
+    let values: Vec<_> = pattern.iter().map(|x| x.to_string()).collect();
+    Some(values.join(","))
+}
+
+fn make_svg_rect<S: BackendStyle>(
+    upper_left: BackendCoord,
+    bottom_right: BackendCoord,
+    style: &S,
+    fill: bool,
+) -> Rectangle {
+    let node = Rectangle::new()
+        .set("x", upper_left.0)
+        .set("y", upper_left.1)
+        .set("width", bottom_right.0 - upper_left.0)
+        .set("height", bottom_right.1 - upper_left.1);
+
+    if !fill {
+        let node = node
+            .set("opacity", make_svg_opacity(&style.as_color()))
+            .set("stroke", make_svg_color(&style.as_color()))
+            .set("fill", "none");
+        match make_svg_dash_array(style) {
+            Some(dash_array) => node.set("stroke-dasharray", dash_array),
+            None => node,
+        }
+    } else {
+        node.set("opacity", make_svg_opacity(&style.as_color()))
+            .set("fill", make_svg_color(&style.as_color()))
+            .set("stroke", "none")
+    }
+}
+
+/// Make the point at the angle on the circle
+fn make_svg_arc_point(center: BackendCoord, radius: u32, angle: f64) -> BackendCoord {
+    let radius = f64::from(radius);
+    (
+        center.0 + (radius * angle.cos()).round() as i32,
+        center.1 - (radius * angle.sin()).round() as i32,
+    )
+}
+
+/// Make the path commands of the arc from the current point at the angle `from`, going the
+/// sweep counterclockwise if `ccw` and clockwise otherwise. A full circle is made of two
+/// halves, since an arc command can't end where it starts.
+fn make_svg_arc(center: BackendCoord, radius: u32, from: f64, sweep: f64, ccw: bool) -> String {
+    let sign = if ccw { 1.0 } else { -1.0 };
+    let ends = if sweep >= std::f64::consts::PI * 2.0 {
+        vec![std::f64::consts::PI, sweep]
+    } else {
+        vec![sweep]
+    };
+    let mut commands = String::new();
+    let mut last = 0.0;
+    for end in ends {
+        let (x, y) = make_svg_arc_point(center, radius, from + sign * end);
+        // The sweep flag of SVG is set for the arcs going clockwise on the screen
+        commands.push_str(&format!(
+            " A {r} {r} 0 {} {} {} {}",
+            (end - last > std::f64::consts::PI) as u8,
+            (!ccw) as u8,
+            x,
+            y,
+            r = radius
+        ));
+        last = end;
+    }
+    commands
+}
+
 enum Target<'a> {
     File(&'a Path),
     Buffer(Cursor<&'a mut Vec<u8>>),
@@ -32,6 +113,22 @@ pub struct SVGBackend<'a> {
     size: (u32, u32),
     document: Option<Document>,
     saved: bool,
+    gradient_count: u32,
+    /// The IDs of the gradients of the styles defined in the document, by their descriptions
+    gradients: HashMap<String, String>,
+    pattern_count: u32,
+    /// The IDs of the patterns defined in the document, by their descriptions
+    patterns: HashMap<String, String>,
+    clip_count: u32,
+    /// The clipping rectangle and the ID of its clip path
+    clip: Option<(BackendClip, String)>,
+    /// The group of the nodes drawn since the clip was set, which isn't in the document yet
+    clip_group: Option<Group>,
+    shadow_count: u32,
+    /// The IDs of the filters of the shadows defined in the document, by their descriptions
+    shadows: HashMap<String, String>,
+    /// The group of the nodes drawn with a shadow, which is added when the shadow ends
+    shadow_group: Option<Group>,
 }
 
 impl<'a> SVGBackend<'a> {
@@ -41,6 +138,239 @@ impl<'a> SVGBackend<'a> {
         self.document = Some(op(temp.unwrap()));
     }
 
+    /// Add a node to the document, or to the clipped group if there's a clipping rectangle, or
+    /// to the group with the shadow if a shadow is being drawn
+    fn add_node<N: Node>(&mut self, node: N) {
+        if let Some(group) = self.shadow_group.take() {
+            self.shadow_group = Some(group.add(node));
+            return;
+        }
+        match &self.clip {
+            Some((_, id)) => {
+                let group = match self.clip_group.take() {
+                    Some(group) => group,
+                    None => Group::new().set("clip-path", format!("url(#{})", id)),
+                };
+                self.clip_group = Some(group.add(node));
+            }
+            None => self.update_document(|d| d.add(node)),
+        }
+    }
+
+    /// Move the clipped group into the document, so the nodes added after it are above it
+    fn flush_clip_group(&mut self) {
+        if let Some(group) = self.clip_group.take() {
+            self.update_document(|d| d.add(group));
+        }
+    }
+
+    /// Get the paint of a gradient, which refers to its definition. The gradient spans the
+    /// bounding box of the shape, so the shapes with the same gradient share the definition.
+    fn make_svg_gradient(&mut self, gradient: &Gradient) -> String {
+        let key = format!("{:?}", gradient);
+        if let Some(id) = self.gradients.get(&key) {
+            return format!("url(#{})", id);
+        }
+        let id = format!("plotters-gradient-{}", self.gradient_count);
+        self.gradient_count += 1;
+
+        let stops: Vec<_> = gradient
+            .stops()
+            .iter()
+            .map(|(offset, color)| {
+                Stop::new()
+                    .set("offset", *offset)
+                    .set("stop-color", make_svg_color(color))
+                    .set("stop-opacity", make_svg_opacity(color))
+            })
+            .collect();
+        let round = |value: f64| (value * 1e4).round() / 1e4;
+        let definitions = match gradient {
+            Gradient::Linear(linear) => {
+                // The ends are on the lines through the corners the gradient goes from and to
+                let (sin, cos) = linear.angle().to_radians().sin_cos();
+                let extent = (cos.abs() + sin.abs()) / 2.0;
+                let node = LinearGradientNode::new()
+                    .set("id", id.as_str())
+                    .set("x1", round(0.5 - cos * extent))
+                    .set("y1", round(0.5 - sin * extent))
+                    .set("x2", round(0.5 + cos * extent))
+                    .set("y2", round(0.5 + sin * extent));
+                let node = stops.into_iter().fold(node, |node, stop| node.add(stop));
+                Definitions::new().add(node)
+            }
+            Gradient::Radial(_) => {
+                let node = RadialGradientNode::new().set("id", id.as_str());
+                let node = stops.into_iter().fold(node, |node, stop| node.add(stop));
+                Definitions::new().add(node)
+            }
+        };
+        self.update_document(|d| d.add(definitions));
+        self.gradients.insert(key, id.clone());
+        format!("url(#{})", id)
+    }
+
+    /// Get the paint of a pattern of the hatches of the color, which refers to its definition.
+    /// The tiles of the pattern start at the origin, so the shapes whose upper left corners are
+    /// a whole number of tiles apart share the definition.
+    fn make_svg_pattern(
+        &mut self,
+        pattern: &Pattern,
+        color: &RGBAColor,
+        origin: BackendCoord,
+    ) -> String {
+        let size = pattern.tile_size() as i32;
+        let offset = (origin.0.rem_euclid(size), origin.1.rem_euclid(size));
+        let key = format!("{:?} {:?} {:?}", pattern, color, offset);
+        if let Some(id) = self.patterns.get(&key) {
+            return format!("url(#{})", id);
+        }
+        let id = format!("plotters-pattern-{}", self.pattern_count);
+        self.pattern_count += 1;
+
+        let (size, width) = (size, pattern.hatch_width() as i32);
+        let mut node = PatternNode::new()
+            .set("id", id.as_str())
+            .set("patternUnits", "userSpaceOnUse")
+            .set("x", offset.0)
+            .set("y", offset.1)
+            .set("width", size)
+            .set("height", size);
+        if let Some(background) = pattern.background_color() {
+            node = node.add(make_svg_rect((0, 0), (size, size), background, true));
+        }
+        // The band of each hatch in the tile, the parts outside of it are cut by the tile
+        let up = |c: i32| format!("M {} 0 H {} L 0 {} V {} Z", c, c + width, c + width, c);
+        let down = |c: i32| {
+            format!(
+                "M {} 0 H {} L {} {} H {} Z",
+                c,
+                c + width,
+                c + width + size,
+                size,
+                c + size
+            )
+        };
+        let bands = match pattern.fill() {
+            PatternFill::DiagonalUp => Some(vec![up(0), up(size)]),
+            PatternFill::DiagonalDown => Some(vec![down(0), down(-size)]),
+            PatternFill::Cross => Some(vec![up(0), up(size), down(0), down(-size)]),
+            PatternFill::Horizontal => Some(vec![format!("M 0 0 H {} V {} H 0 Z", size, width)]),
+            PatternFill::Vertical => Some(vec![format!("M 0 0 H {} V {} H 0 Z", width, size)]),
+            PatternFill::Dots => None,
+        };
+        node = match bands {
+            Some(bands) => node.add(
+                PathNode::new()
+                    .set("d", bands.join(" "))
+                    .set("opacity", make_svg_opacity(color))
+                    .set("fill", make_svg_color(color))
+                    .set("stroke", "none"),
+            ),
+            None => {
+                let radius = f64::from(width) / 2.0;
+                node.add(
+                    Circle::new()
+                        .set("cx", radius)
+                        .set("cy", radius)
+                        .set("r", radius)
+                        .set("opacity", make_svg_opacity(color))
+                        .set("fill", make_svg_color(color))
+                        .set("stroke", "none"),
+                )
+            }
+        };
+        self.update_document(|d| d.add(Definitions::new().add(node)));
+        self.patterns.insert(key, id.clone());
+        format!("url(#{})", id)
+    }
+
+    /// Get the ID of the filter which draws the shadow beneath the nodes it's applied to. The
+    /// silhouette of the nodes is blurred, offset and filled with the color of the shadow.
+    fn make_svg_shadow(&mut self, shadow: &BackendShadow) -> String {
+        let key = format!("{:?}", shadow);
+        if let Some(id) = self.shadows.get(&key) {
+            return id.clone();
+        }
+        let id = format!("plotters-shadow-{}", self.shadow_count);
+        self.shadow_count += 1;
+
+        // The version of the svg crate has no types of the primitives of the filters
+        let primitive = |name: &str, attributes: &[(&str, String)]| {
+            let mut node = Element::new(name);
+            for (name, value) in attributes {
+                node.assign(*name, value.as_str());
+            }
+            node
+        };
+        let mut merge = primitive("feMerge", &[]);
+        merge.append(primitive("feMergeNode", &[("in", "shadow".into())]));
+        merge.append(primitive("feMergeNode", &[("in", "SourceGraphic".into())]));
+        // The filter covers the whole image, so the shadow isn't cut by the bounding box
+        let filter = Filter::new()
+            .set("id", id.as_str())
+            .set("filterUnits", "userSpaceOnUse")
+            .set("x", 0)
+            .set("y", 0)
+            .set("width", self.size.0)
+            .set("height", self.size.1)
+            .add(primitive(
+                "feGaussianBlur",
+                &[
+                    ("in", "SourceAlpha".into()),
+                    // The blur reaches about two standard deviations
+                    (
+                        "stdDeviation",
+                        (f64::from(shadow.blur_radius) / 2.0).to_string(),
+                    ),
+                ],
+            ))
+            .add(primitive(
+                "feOffset",
+                &[
+                    ("dx", shadow.offset.0.to_string()),
+                    ("dy", shadow.offset.1.to_string()),
+                    ("result", "offset".into()),
+                ],
+            ))
+            .add(primitive(
+                "feFlood",
+                &[
+                    ("flood-color", make_svg_color(&shadow.color)),
+                    ("flood-opacity", make_svg_opacity(&shadow.color)),
+                ],
+            ))
+            .add(primitive(
+                "feComposite",
+                &[
+                    ("in2", "offset".into()),
+                    ("operator", "in".into()),
+                    ("result", "shadow".into()),
+                ],
+            ))
+            .add(merge);
+        self.update_document(|d| d.add(Definitions::new().add(filter)));
+        self.shadows.insert(key, id.clone());
+        id
+    }
+
+    /// Fill the node with the pattern or the gradient of the style, if it has one
+    /// - `origin`: The upper left corner of the bounding box of the shape
+    fn set_paint_fill<N: Node, S: BackendStyle>(
+        &mut self,
+        node: &mut N,
+        style: &S,
+        origin: BackendCoord,
+    ) {
+        let paint = match (style.pattern(), style.gradient()) {
+            (Some(pattern), _) => self.make_svg_pattern(pattern, &style.as_color(), origin),
+            (None, Some(gradient)) => self.make_svg_gradient(gradient),
+            (None, None) => return,
+        };
+        node.assign("fill", paint);
+        node.assign("opacity", 1);
+    }
+
     /// Create a new SVG drawing backend
     pub fn new<T: AsRef<Path> + ?Sized>(path: &'a T, size: (u32, u32)) -> Self {
         Self {
@@ -48,6 +378,16 @@ impl<'a> SVGBackend<'a> {
             size,
             document: Some(Document::new().set("viewBox", (0, 0, size.0, size.1))),
             saved: false,
+            gradient_count: 0,
+            gradients: HashMap::new(),
+            pattern_count: 0,
+            patterns: HashMap::new(),
+            clip_count: 0,
+            clip: None,
+            clip_group: None,
+            shadow_count: 0,
+            shadows: HashMap::new(),
+            shadow_group: None,
         }
     }
 
@@ -58,6 +398,16 @@ impl<'a> SVGBackend<'a> {
             size,
             document: Some(Document::new().set("viewBox", (0, 0, size.0, size.1))),
             saved: false,
+            gradient_count: 0,
+            gradients: HashMap::new(),
+            pattern_count: 0,
+            patterns: HashMap::new(),
+            clip_count: 0,
+            clip: None,
+            clip_group: None,
+            shadow_count: 0,
+            shadows: HashMap::new(),
+            shadow_group: None,
         }
     }
 }
@@ -75,6 +425,7 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
 
     fn present(&mut self) -> Result<(), DrawingErrorKind<Error>> {
         if !self.saved {
+            self.flush_clip_group();
             match self.target {
                 Target::File(path) => svg::save(path, self.document.as_ref().unwrap())
                     .map_err(DrawingErrorKind::DrawingError)?,
@@ -102,7 +453,7 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
             .set("stroke", "none")
             .set("opacity", make_svg_opacity(color))
             .set("fill", make_svg_color(color));
-        self.update_document(|d| d.add(node));
+        self.add_node(node);
         Ok(())
     }
 
@@ -115,7 +466,7 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
         if style.as_color().alpha() == 0.0 {
             return Ok(());
         }
-        let node = Line::new()
+        let mut node = Line::new()
             .set("x1", from.0)
             .set("y1", from.1)
             .set("x2", to.0)
@@ -123,7 +474,10 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
             .set("opacity", make_svg_opacity(&style.as_color()))
             .set("stroke", make_svg_color(&style.as_color()))
             .set("stroke-width", style.stroke_width());
-        self.update_document(|d| d.add(node));
+        if let Some(dash_array) = make_svg_dash_array(style) {
+            node = node.set("stroke-dasharray", dash_array);
+        }
+        self.add_node(node);
         Ok(())
     }
 
@@ -137,25 +491,91 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
         if style.as_color().alpha() == 0.0 {
             return Ok(());
         }
-        let mut node = Rectangle::new()
-            .set("x", upper_left.0)
-            .set("y", upper_left.1)
-            .set("width", bottom_right.0 - upper_left.0)
-            .set("height", bottom_right.1 - upper_left.1);
+        let mut node = make_svg_rect(upper_left, bottom_right, style, fill);
+        if fill {
+            let origin = (
+                upper_left.0.min(bottom_right.0),
+                upper_left.1.min(bottom_right.1),
+            );
+            self.set_paint_fill(&mut node, style, origin);
+        }
+        self.add_node(node);
+        Ok(())
+    }
 
-        if !fill {
-            node = node
-                .set("opacity", make_svg_opacity(&style.as_color()))
-                .set("stroke", make_svg_color(&style.as_color()))
-                .set("fill", "none");
-        } else {
+    fn draw_rounded_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        radii: [u32; 4],
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if style.as_color().alpha() == 0.0 {
+            return Ok(());
+        }
+        let (ul, br) = (
+            (
+                upper_left.0.min(bottom_right.0),
+                upper_left.1.min(bottom_right.1),
+            ),
+            (
+                upper_left.0.max(bottom_right.0),
+                upper_left.1.max(bottom_right.1),
+            ),
+        );
+        let [r_ul, r_ur, r_br, r_bl] = clamp_corner_radii(ul, br, radii);
+
+        if r_ul == r_ur && r_ul == r_br && r_ul == r_bl {
+            let mut node = make_svg_rect(ul, br, style, fill)
+                .set("rx", r_ul)
+                .set("ry", r_ul)
+                .set("stroke-width", style.stroke_width());
+            if fill {
+                self.set_paint_fill(&mut node, style, ul);
+            }
+            self.add_node(node);
+            return Ok(());
+        }
+
+        // The outline goes clockwise from the end of the upper left corner
+        let corner = |r: i32, x: i32, y: i32| {
+            if r == 0 {
+                format!(" L {} {}", x, y)
+            } else {
+                format!(" A {} {} 0 0 1 {} {}", r, r, x, y)
+            }
+        };
+        let commands = [
+            format!("M {} {} H {}", ul.0 + r_ul, ul.1, br.0 - r_ur),
+            corner(r_ur, br.0, ul.1 + r_ur),
+            format!(" V {}", br.1 - r_br),
+            corner(r_br, br.0 - r_br, br.1),
+            format!(" H {}", ul.0 + r_bl),
+            corner(r_bl, ul.0, br.1 - r_bl),
+            format!(" V {}", ul.1 + r_ul),
+            corner(r_ul, ul.0 + r_ul, ul.1),
+            " Z".to_string(),
+        ]
+        .concat();
+        let mut node = PathNode::new()
+            .set("d", commands)
+            .set("opacity", make_svg_opacity(&style.as_color()));
+        if fill {
             node = node
-                .set("opacity", make_svg_opacity(&style.as_color()))
                 .set("fill", make_svg_color(&style.as_color()))
                 .set("stroke", "none");
+            self.set_paint_fill(&mut node, style, ul);
+        } else {
+            node = node
+                .set("fill", "none")
+                .set("stroke", make_svg_color(&style.as_color()))
+                .set("stroke-width", style.stroke_width());
+            if let Some(dash_array) = make_svg_dash_array(style) {
+                node = node.set("stroke-dasharray", dash_array);
+            }
         }
-
-        self.update_document(|d| d.add(node));
+        self.add_node(node);
         Ok(())
     }
 
@@ -167,7 +587,7 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
         if style.as_color().alpha() == 0.0 {
             return Ok(());
         }
-        let node = Polyline::new()
+        let mut node = Polyline::new()
             .set("fill", "none")
             .set("opacity", make_svg_opacity(&style.as_color()))
             .set("stroke", make_svg_color(&style.as_color()))
@@ -179,7 +599,39 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
                     s
                 }),
             );
-        self.update_document(|d| d.add(node));
+        if let Some(dash_array) = make_svg_dash_array(style) {
+            node = node.set("stroke-dasharray", dash_array);
+        }
+        self.add_node(node);
+        Ok(())
+    }
+
+    fn draw_bezier<S: BackendStyle>(
+        &mut self,
+        start: BackendCoord,
+        segments: &[[BackendCoord; 3]],
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if style.as_color().alpha() == 0.0 {
+            return Ok(());
+        }
+        let commands = segments.iter().fold(
+            format!("M {} {}", start.0, start.1),
+            |mut s, [(x0, y0), (x1, y1), (x, y)]| {
+                s.push_str(&format!(" C {} {}, {} {}, {} {}", x0, y0, x1, y1, x, y));
+                s
+            },
+        );
+        let mut node = PathNode::new()
+            .set("fill", "none")
+            .set("opacity", make_svg_opacity(&style.as_color()))
+            .set("stroke", make_svg_color(&style.as_color()))
+            .set("stroke-width", style.stroke_width())
+            .set("d", commands);
+        if let Some(dash_array) = make_svg_dash_array(style) {
+            node = node.set("stroke-dasharray", dash_array);
+        }
+        self.add_node(node);
         Ok(())
     }
 
@@ -191,9 +643,55 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
         if style.as_color().alpha() == 0.0 {
             return Ok(());
         }
-        let node = Polygon::new()
+        let path: Vec<_> = path.into_iter().collect();
+        let mut node = Polygon::new()
             .set("opacity", make_svg_opacity(&style.as_color()))
             .set("fill", make_svg_color(&style.as_color()))
+            .set("fill-rule", "evenodd")
+            .set(
+                "points",
+                path.iter().fold(String::new(), |mut s, (x, y)| {
+                    s.push_str(&format!("{},{} ", x, y));
+                    s
+                }),
+            );
+        let origin = path.iter().fold((i32::MAX, i32::MAX), |(x0, y0), &(x, y)| {
+            (x0.min(x), y0.min(y))
+        });
+        self.set_paint_fill(&mut node, style, origin);
+        self.add_node(node);
+        Ok(())
+    }
+
+    fn fill_polygon_gradient<I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        path: I,
+        top: (i32, &RGBAColor),
+        bottom: (i32, &RGBAColor),
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if top.0 == bottom.0 {
+            return self.fill_polygon(path, top.1);
+        }
+        let id = format!("plotters-gradient-{}", self.gradient_count);
+        self.gradient_count += 1;
+        let make_stop = |offset: u32, color: &RGBAColor| {
+            Stop::new()
+                .set("offset", offset)
+                .set("stop-color", make_svg_color(color))
+                .set("stop-opacity", make_svg_opacity(color))
+        };
+        let gradient = LinearGradientNode::new()
+            .set("id", id.as_str())
+            .set("gradientUnits", "userSpaceOnUse")
+            .set("x1", 0)
+            .set("y1", top.0)
+            .set("x2", 0)
+            .set("y2", bottom.0)
+            .add(make_stop(0, top.1))
+            .add(make_stop(1, bottom.1));
+        let node = Polygon::new()
+            .set("fill", format!("url(#{})", id))
+            .set("fill-rule", "evenodd")
             .set(
                 "points",
                 path.into_iter().fold(String::new(), |mut s, (x, y)| {
@@ -201,7 +699,77 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
                     s
                 }),
             );
-        self.update_document(|d| d.add(node));
+        self.update_document(|d| d.add(Definitions::new().add(gradient)));
+        self.add_node(node);
+        Ok(())
+    }
+
+    fn draw_arc<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radius: u32,
+        angles: (f64, f64),
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if style.as_color().alpha() == 0.0 {
+            return Ok(());
+        }
+        let (start, sweep) = arc_sweep(angles);
+        let (x, y) = make_svg_arc_point(center, radius, start);
+        let mut node = PathNode::new()
+            .set("fill", "none")
+            .set("opacity", make_svg_opacity(&style.as_color()))
+            .set("stroke", make_svg_color(&style.as_color()))
+            .set("stroke-width", style.stroke_width())
+            .set(
+                "d",
+                format!(
+                    "M {} {}{}",
+                    x,
+                    y,
+                    make_svg_arc(center, radius, start, sweep, true)
+                ),
+            );
+        if let Some(dash_array) = make_svg_dash_array(style) {
+            node = node.set("stroke-dasharray", dash_array);
+        }
+        self.add_node(node);
+        Ok(())
+    }
+
+    fn fill_sector<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        (inner, outer): (u32, u32),
+        angles: (f64, f64),
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if style.as_color().alpha() == 0.0 {
+            return Ok(());
+        }
+        let (start, sweep) = arc_sweep(angles);
+        let (x, y) = make_svg_arc_point(center, outer, start);
+        let mut commands = format!(
+            "M {} {}{}",
+            x,
+            y,
+            make_svg_arc(center, outer, start, sweep, true)
+        );
+        if inner > 0 {
+            let (x, y) = make_svg_arc_point(center, inner, start + sweep);
+            commands.push_str(&format!(" L {} {}", x, y));
+            commands.push_str(&make_svg_arc(center, inner, start + sweep, sweep, false));
+        } else {
+            commands.push_str(&format!(" L {} {}", center.0, center.1));
+        }
+        commands.push_str(" Z");
+        let node = PathNode::new()
+            .set("opacity", make_svg_opacity(&style.as_color()))
+            .set("fill", make_svg_color(&style.as_color()))
+            .set("fill-rule", "evenodd")
+            .set("stroke", "none")
+            .set("d", commands);
+        self.add_node(node);
         Ok(())
     }
 
@@ -225,14 +793,19 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
                 .set("opacity", make_svg_opacity(&style.as_color()))
                 .set("stroke", make_svg_color(&style.as_color()))
                 .set("fill", "none");
+            if let Some(dash_array) = make_svg_dash_array(style) {
+                node = node.set("stroke-dasharray", dash_array);
+            }
         } else {
             node = node
                 .set("opacity", make_svg_opacity(&style.as_color()))
                 .set("fill", make_svg_color(&style.as_color()))
                 .set("stroke", "none");
+            let r = radius as i32;
+            self.set_paint_fill(&mut node, style, (center.0 - r, center.1 - r));
         }
 
-        self.update_document(|d| d.add(node));
+        self.add_node(node);
         Ok(())
     }
     fn draw_text<'b>(
@@ -261,25 +834,32 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
             .set("opacity", make_svg_opacity(color))
             .set("fill", make_svg_color(color));
 
-        let node = match font.get_style() {
-            FontStyle::Normal => node,
-            FontStyle::Bold => node.set("font-weight", "bold"),
-            other_style => node.set("font-style", other_style.as_str()),
+        let style = font.get_style();
+        let node = if style.is_bold() {
+            node.set("font-weight", "bold")
+        } else {
+            node
+        };
+        let node = match style.slant() {
+            Some(slant) => node.set("font-style", slant),
+            None => node,
         };
 
-        let node = match trans {
-            FontTransform::Rotate90 => node.set("transform", format!("rotate(90, {}, {})", x0, y0)),
-            FontTransform::Rotate180 => {
-                node.set("transform", format!("rotate(180, {}, {})", x0, y0))
-            }
-            FontTransform::Rotate270 => {
-                node.set("transform", format!("rotate(270, {}, {})", x0, y0))
-            }
-            _ => node,
+        let angle = trans.angle();
+        let node = if angle != 0.0 {
+            node.set("transform", format!("rotate({}, {}, {})", angle, x0, y0))
+        } else {
+            node
         }
         .add(context);
 
-        self.update_document(|d| d.add(node));
+        // The texts which aren't clipped are added above the clipped group
+        if self.shadow_group.is_none() && matches!(&self.clip, Some((clip, _)) if !clip.clip_text) {
+            self.flush_clip_group();
+            self.update_document(|d| d.add(node));
+        } else {
+            self.add_node(node);
+        }
 
         Ok(())
     }
@@ -290,10 +870,58 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
         pos: BackendCoord,
         (w, h): (u32, u32),
         src: &'b [u8],
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.draw_image(
+            pos,
+            (w, h),
+            (w, h),
+            src,
+            PixelFormat::Rgb,
+            ImageScaling::Nearest,
+        )
+    }
+
+    #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
+    fn draw_image(
+        &mut self,
+        pos: BackendCoord,
+        size: (u32, u32),
+        (w, h): (u32, u32),
+        src: &[u8],
+        format: PixelFormat,
+        scaling: ImageScaling,
     ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
         use image::png::PNGEncoder;
         use svg::node::element::Image;
 
+        if src.len() < w as usize * h as usize * format.bytes_per_pixel() {
+            return Ok(());
+        }
+
+        // The blue first pixels are reordered, since the image is encoded as RGB or RGBA
+        let reordered: Vec<u8>;
+        let (src, color) = match format {
+            PixelFormat::Rgb => (src, image::ColorType::RGB(8)),
+            PixelFormat::Rgba => (src, image::ColorType::RGBA(8)),
+            PixelFormat::Bgra | PixelFormat::Bgrx => {
+                let alpha = format.alpha_offset();
+                reordered = src
+                    .chunks_exact(4)
+                    .take(w as usize * h as usize)
+                    .flat_map(|pixel| {
+                        let bgr = pixel[..3].iter().rev().copied();
+                        bgr.chain(alpha.map(|a| pixel[a]))
+                    })
+                    .collect();
+                let color = if alpha.is_some() {
+                    image::ColorType::RGBA(8)
+                } else {
+                    image::ColorType::RGB(8)
+                };
+                (&reordered[..], color)
+            }
+        };
+
         let mut data = vec![0; 0];
 
         {
@@ -301,8 +929,6 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
 
             let encoder = PNGEncoder::new(cursor);
 
-            let color = image::ColorType::RGB(8);
-
             encoder.encode(src, w, h, color).map_err(|e| {
                 DrawingErrorKind::DrawingError(Error::new(
                     std::io::ErrorKind::Other,
@@ -335,7 +961,7 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
         }
 
         let mut buf = String::new();
-        buf.push_str("data:png;base64,");
+        buf.push_str("data:image/png;base64,");
 
         for byte in data {
             let value = (rem_bits << (6 - rem_num)) | (byte >> (rem_num + 2));
@@ -355,17 +981,60 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
             buf.push('=');
         }
 
-        let node = Image::new()
+        let mut node = Image::new()
             .set("x", pos.0)
             .set("y", pos.1)
-            .set("width", w)
-            .set("height", h)
+            .set("width", size.0)
+            .set("height", size.1)
+            .set("preserveAspectRatio", "none")
             .set("href", buf.as_str());
+        if scaling == ImageScaling::Nearest && size != (w, h) {
+            node = node.set("image-rendering", "pixelated");
+        }
 
-        self.update_document(|d| d.add(node));
+        self.add_node(node);
 
         Ok(())
     }
+
+    fn begin_shadow(
+        &mut self,
+        shadow: &BackendShadow,
+    ) -> Result<bool, DrawingErrorKind<Self::ErrorType>> {
+        let id = self.make_svg_shadow(shadow);
+        self.shadow_group = Some(Group::new().set("filter", format!("url(#{})", id)));
+        Ok(true)
+    }
+
+    fn end_shadow(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if let Some(group) = self.shadow_group.take() {
+            self.add_node(group);
+        }
+        Ok(())
+    }
+
+    fn set_clip(
+        &mut self,
+        clip: Option<BackendClip>,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.flush_clip_group();
+        self.clip = match clip {
+            Some(clip) => {
+                self.clip_count += 1;
+                let id = format!("plotters-clip-{}", self.clip_count);
+                let rect = Rectangle::new()
+                    .set("x", clip.upper_left.0)
+                    .set("y", clip.upper_left.1)
+                    .set("width", (clip.bottom_right.0 - clip.upper_left.0).max(0))
+                    .set("height", (clip.bottom_right.1 - clip.upper_left.1).max(0));
+                let clip_path = ClipPath::new().set("id", id.clone()).add(rect);
+                self.update_document(|d| d.add(Definitions::new().add(clip_path)));
+                Some((clip, id))
+            }
+            None => None,
+        };
+        Ok(())
+    }
 }
 
 impl Drop for SVGBackend<'_> {
@@ -375,3 +1044,357 @@ impl Drop for SVGBackend<'_> {
         }
     }
 }
+
+#[cfg(test)]
+#[test]
+fn test_svg_gradient_fill() {
+    use crate::prelude::*;
+    let mut buffer = vec![];
+
+    {
+        let root = SVGBackend::with_buffer(&mut buffer, (100, 100)).into_drawing_area();
+        root.draw(&GradientPolygon::new(
+            vec![(10, 10), (90, 10), (90, 90), (10, 90)],
+            ((0, 0), &RED),
+            ((0, 100), &BLUE),
+        ))
+        .unwrap();
+    }
+
+    let content = String::from_utf8(buffer).unwrap();
+    assert!(content.contains("<linearGradient"));
+    assert!(content.contains("gradientUnits=\"userSpaceOnUse\""));
+    assert!(content.contains("y2=\"100\""));
+    assert!(content.contains("fill=\"url(#plotters-gradient-0)\""));
+}
+
+#[cfg(test)]
+#[test]
+fn test_svg_polygon() {
+    use crate::prelude::*;
+    let mut buffer = vec![];
+
+    {
+        let root = SVGBackend::with_buffer(&mut buffer, (100, 100)).into_drawing_area();
+        root.draw(&Polygon::new(
+            vec![(50, 0), (80, 95), (2, 35), (98, 35), (20, 95)],
+            &RED,
+        ))
+        .unwrap();
+    }
+
+    let content = String::from_utf8(buffer).unwrap();
+    assert!(content.contains("<polygon"));
+    assert!(content.contains("fill-rule=\"evenodd\""));
+    assert!(content.contains("points=\"50,0 80,95 2,35 98,35 20,95 \""));
+}
+
+#[cfg(test)]
+#[test]
+fn test_svg_arc_and_sector() {
+    use crate::prelude::*;
+    use std::f64::consts::PI;
+    let mut buffer = vec![];
+
+    {
+        let root = SVGBackend::with_buffer(&mut buffer, (100, 100)).into_drawing_area();
+        root.draw(&Arc::new((50, 50), 40, 0.0, PI / 2.0, &RED))
+            .unwrap();
+        root.draw(&Sector::new((50, 50), 40, 0.0, PI * 1.5, BLUE.filled()).inner_radius(20))
+            .unwrap();
+        root.draw(&Sector::new((50, 50), 40, 0.0, PI * 2.0, GREEN.filled()))
+            .unwrap();
+    }
+
+    let content = String::from_utf8(buffer).unwrap();
+    assert!(content.contains("d=\"M 90 50 A 40 40 0 0 0 50 10\""));
+    // The sweep over 180 degrees takes the large arc, and the inner arc goes back
+    assert!(content.contains("d=\"M 90 50 A 40 40 0 1 0 50 90 L 50 70 A 20 20 0 1 1 70 50 Z\""));
+    // The full circle is made of two halves
+    assert!(content.contains("d=\"M 90 50 A 40 40 0 0 0 10 50 A 40 40 0 0 0 90 50 L 50 50 Z\""));
+}
+
+#[cfg(test)]
+#[test]
+fn test_svg_rounded_rect() {
+    use crate::prelude::*;
+    let mut buffer = vec![];
+
+    {
+        let root = SVGBackend::with_buffer(&mut buffer, (100, 100)).into_drawing_area();
+        root.draw(&Rectangle::new([(10, 10), (50, 30)], RED.filled()).rounded(5))
+            .unwrap();
+        root.draw(&Rectangle::new([(10, 40), (50, 90)], &BLUE).rounded_corners([5, 5, 0, 0]))
+            .unwrap();
+    }
+
+    let content = String::from_utf8(buffer).unwrap();
+    assert!(content.contains("rx=\"5\""));
+    assert!(content.contains(
+        "d=\"M 15 40 H 45 A 5 5 0 0 1 50 45 V 90 L 50 90 H 10 L 10 90 V 45 A 5 5 0 0 1 15 40 Z\""
+    ));
+}
+
+#[cfg(test)]
+#[test]
+fn test_svg_gradient_style() {
+    use crate::prelude::*;
+    let mut buffer = vec![];
+
+    {
+        let root = SVGBackend::with_buffer(&mut buffer, (100, 100)).into_drawing_area();
+        let linear = LinearGradient::new(90.0, vec![(1.0, BLUE), (0.0, RED)]);
+        for x in (0..100).step_by(50) {
+            root.draw(&Rectangle::new(
+                [(x, 0), (x + 40, 40)],
+                ShapeStyle::gradient(linear.clone()),
+            ))
+            .unwrap();
+        }
+        let radial = RadialGradient::new(vec![(0.0, WHITE.to_rgba()), (1.0, BLUE.mix(0.5))]);
+        root.draw(&Circle::new((50, 70), 20, ShapeStyle::gradient(radial)))
+            .unwrap();
+    }
+
+    let content = String::from_utf8(buffer).unwrap();
+    // The rectangles with the same gradient share its definition
+    assert_eq!(content.matches("<linearGradient").count(), 1);
+    assert!(content.contains("x1=\"0.5\" x2=\"0.5\" y1=\"0\" y2=\"1\""));
+    assert_eq!(
+        content
+            .matches("fill=\"url(#plotters-gradient-0)\"")
+            .count(),
+        2
+    );
+    // The stops are sorted by the offset
+    let red = content.find("stop-color=\"#FF0000\"").unwrap();
+    assert!(red < content.find("stop-color=\"#0000FF\"").unwrap());
+    assert!(content.contains("<radialGradient id=\"plotters-gradient-1\">"));
+    assert!(content.contains("stop-opacity=\"0.5\""));
+    assert!(content.contains("fill=\"url(#plotters-gradient-1)\""));
+
+    // The series take the gradients through their styles
+    let mut buffer = vec![];
+    {
+        let root = SVGBackend::with_buffer(&mut buffer, (100, 100)).into_drawing_area();
+        let mut chart = ChartBuilder::on(&root)
+            .build_ranged(0u32..4u32, 0u32..10u32)
+            .unwrap();
+        let style = ShapeStyle::gradient(LinearGradient::new(90.0, vec![(0.0, RED), (1.0, BLUE)]));
+        chart
+            .draw_series(Histogram::vertical(&chart).style(style.clone()).data(vec![
+                (0, 3),
+                (1, 5),
+                (2, 8),
+            ]))
+            .unwrap();
+        chart
+            .draw_series(AreaSeries::new(vec![(0, 1), (3, 2)], 0, style))
+            .unwrap();
+    }
+    let content = String::from_utf8(buffer).unwrap();
+    assert_eq!(content.matches("<linearGradient").count(), 1);
+    assert_eq!(
+        content
+            .matches("fill=\"url(#plotters-gradient-0)\"")
+            .count(),
+        4
+    );
+}
+
+#[cfg(test)]
+#[test]
+fn test_svg_pattern_style() {
+    use crate::prelude::*;
+    let mut buffer = vec![];
+
+    {
+        let root = SVGBackend::with_buffer(&mut buffer, (100, 100)).into_drawing_area();
+        let mut chart = ChartBuilder::on(&root)
+            .build_ranged(0u32..4u32, 0u32..10u32)
+            .unwrap();
+        chart
+            .draw_series(
+                Histogram::vertical(&chart)
+                    .margin(0)
+                    .style_func(|x, _| {
+                        let fill = [PatternFill::Cross, PatternFill::Dots][*x as usize % 2];
+                        BLACK.filled().pattern(Pattern::new(fill).spacing(5))
+                    })
+                    .data(vec![(0, 3), (1, 5), (2, 8), (3, 4)]),
+            )
+            .unwrap();
+    }
+
+    let content = String::from_utf8(buffer).unwrap();
+    // The bars are 25 pixels apart, which is a whole number of tiles, so the bars with the
+    // same pattern share it
+    assert_eq!(content.matches("<pattern").count(), 2);
+    assert!(content.contains("patternUnits=\"userSpaceOnUse\""));
+    assert_eq!(
+        content.matches("fill=\"url(#plotters-pattern-0)\"").count(),
+        2
+    );
+    assert_eq!(
+        content.matches("fill=\"url(#plotters-pattern-1)\"").count(),
+        2
+    );
+    assert!(content.contains("<circle cx=\"0.5\" cy=\"0.5\""));
+    assert!(content.contains("d=\"M 0 0 H 1 L 0 1 V 0 Z M 5 0 H 6 L 0 6 V 5 Z"));
+}
+
+#[cfg(test)]
+#[test]
+fn test_svg_clip() {
+    use crate::prelude::*;
+    let mut buffer = vec![];
+
+    {
+        let root = SVGBackend::with_buffer(&mut buffer, (100, 100)).into_drawing_area();
+        let area = root.margin(10, 10, 20, 20);
+        area.clip_to_area(false).unwrap();
+        area.draw(&Circle::new((0, 0), 10, &RED)).unwrap();
+        area.draw(&Text::new("label", (5, 5), ("sans-serif", 10).into_font()))
+            .unwrap();
+        area.draw(&Circle::new((80, 80), 10, &BLUE)).unwrap();
+        area.reset_clip().unwrap();
+        root.draw(&Circle::new((50, 50), 5, &GREEN)).unwrap();
+    }
+
+    let content = String::from_utf8(buffer).unwrap();
+    assert!(content.contains("<clipPath id=\"plotters-clip-1\">"));
+    assert!(content.contains("height=\"80\" width=\"60\" x=\"20\" y=\"10\""));
+    // The circles drawn with the clip are in the clipped groups, and the text isn't
+    let groups: Vec<_> = content
+        .split("<g clip-path=\"url(#plotters-clip-1)\">")
+        .skip(1)
+        .map(|group| &group[..group.find("</g>").unwrap()])
+        .collect();
+    assert_eq!(groups.len(), 2);
+    assert!(groups[0].contains("#FF0000") && groups[1].contains("#0000FF"));
+    assert!(groups.iter().all(|group| !group.contains("label")));
+    let last_group = content.rfind("</g>").unwrap();
+    assert!(content.find("label").unwrap() < last_group);
+    assert!(content.find("#00FF00").unwrap() > last_group);
+}
+
+#[cfg(test)]
+#[test]
+fn test_svg_shadow() {
+    use crate::prelude::*;
+    let mut buffer = vec![];
+
+    {
+        let root = SVGBackend::with_buffer(&mut buffer, (100, 100)).into_drawing_area();
+        let shadowed = |x: i32| {
+            Rectangle::new([(x, 10), (x + 20, 30)], WHITE.filled()).with_shadow(
+                (3, 4),
+                6,
+                &BLACK.mix(0.5),
+            )
+        };
+        root.draw(&shadowed(10)).unwrap();
+        root.draw(&shadowed(50)).unwrap();
+        root.draw(&Circle::new((50, 50), 5, &GREEN)).unwrap();
+    }
+
+    let content = String::from_utf8(buffer).unwrap();
+    // The shadows share the filter
+    assert_eq!(content.matches("<filter ").count(), 1);
+    assert!(content.contains(
+        "<filter filterUnits=\"userSpaceOnUse\" height=\"100\" id=\"plotters-shadow-0\" width=\"100\" x=\"0\" y=\"0\">"
+    ));
+    assert!(content.contains("<feGaussianBlur in=\"SourceAlpha\" stdDeviation=\"3\"/>"));
+    assert!(content.contains("<feOffset dx=\"3\" dy=\"4\" result=\"offset\"/>"));
+    assert!(content.contains("<feFlood flood-color=\"#000000\" flood-opacity=\"0.5\"/>"));
+    let groups: Vec<_> = content
+        .split("<g filter=\"url(#plotters-shadow-0)\">")
+        .skip(1)
+        .map(|group| &group[..group.find("</g>").unwrap()])
+        .collect();
+    assert_eq!(groups.len(), 2);
+    assert!(groups[0].contains("x=\"10\"") && groups[1].contains("x=\"50\""));
+    assert!(content.find("#00FF00").unwrap() > content.rfind("</g>").unwrap());
+}
+
+#[cfg(test)]
+#[test]
+fn test_svg_bezier() {
+    use crate::prelude::*;
+    let mut buffer = vec![];
+
+    {
+        let root = SVGBackend::with_buffer(&mut buffer, (100, 100)).into_drawing_area();
+        root.draw(&BezierPath::new(
+            vec![
+                (0, 50),
+                (0, 0),
+                (50, 0),
+                (50, 50),
+                (50, 100),
+                (100, 100),
+                (100, 50),
+            ],
+            &RED,
+        ))
+        .unwrap();
+    }
+
+    let content = String::from_utf8(buffer).unwrap();
+    assert!(content.contains("d=\"M 0 50 C 0 0, 50 0, 50 50 C 50 100, 100 100, 100 50\""));
+}
+
+#[cfg(all(test, not(target_arch = "wasm32"), feature = "image"))]
+#[test]
+fn test_svg_image() {
+    use crate::prelude::*;
+    let mut buffer = vec![];
+
+    {
+        let root = SVGBackend::with_buffer(&mut buffer, (100, 100)).into_drawing_area();
+        let pixels = vec![255, 0, 0, 255, 0, 0, 255, 128];
+        let elem = BitMapElement::with_rgba_buffer((10, 20), (2, 1), pixels).unwrap();
+        root.draw(&elem.resize((40, 20))).unwrap();
+    }
+
+    let content = String::from_utf8(buffer).unwrap();
+    assert!(content.contains("<image"));
+    assert!(content.contains("href=\"data:image/png;base64,iVBORw0KGgo"));
+    assert!(content.contains("width=\"40\""));
+    assert!(content.contains("image-rendering=\"pixelated\""));
+}
+
+#[cfg(test)]
+#[test]
+fn test_svg_font_style() {
+    use crate::prelude::*;
+    let mut buffer = vec![];
+
+    {
+        let root = SVGBackend::with_buffer(&mut buffer, (100, 100)).into_drawing_area();
+        let font = ("sans-serif", 20).into_font();
+        root.draw_text("a", &font.bold().into(), (0, 0)).unwrap();
+        root.draw_text("b", &font.italic().bold().into(), (0, 30))
+            .unwrap();
+        root.draw_text("c", &font.style(FontStyle::Oblique).into(), (0, 60))
+            .unwrap();
+        let rotated = font.transform(FontTransform::RotateAngle(45.0));
+        root.draw_text("d", &rotated.into(), (50, 50)).unwrap();
+    }
+
+    let content = String::from_utf8(buffer).unwrap();
+    let text = |value: &str| {
+        content
+            .split("</text>")
+            .find(|node| node.trim_end().ends_with(value))
+            .unwrap()
+            .to_owned()
+    };
+    assert!(text("a").contains("font-weight=\"bold\""));
+    assert!(!text("a").contains("font-style"));
+    assert!(text("b").contains("font-weight=\"bold\""));
+    assert!(text("b").contains("font-style=\"italic\""));
+    assert!(text("c").contains("font-style=\"oblique\""));
+    assert!(!text("c").contains("font-weight"));
+    assert!(text("d").contains("transform=\"rotate(45, "));
+}
@@ -12,9 +12,11 @@ interface, which draws an element.
 Currently we have following backend implemented:
 
 - `BitMapBackend`: The backend that creates bitmap, this is based on `image` crate
+- `GifBackend` and `ApngBackend`: The backends that create GIF and APNG animations with the bitmap drawing code, one frame for each call of `present`.
 - `SVGBackend`: The backend that creates SVG image, based on `svg` crate.
 - `PistonBackend`: The backend that uses Piston Window for real time rendering. Disabled by default, use feature `piston` to turn on.
 - `CanvasBackend`: The backend that operates HTML5 Canvas, this is available when `Plotters` is targeting WASM.
+- `RecordingBackend`: The backend that records the draw commands, which can be replayed onto the other backends with `replay`.
 
 */
 mod area;
@@ -24,8 +26,8 @@ pub mod rasterizer;
 
 pub mod backend;
 
-pub use area::{DrawingArea, DrawingAreaErrorKind, IntoDrawingArea};
+pub use area::{ChartLayoutError, DrawingArea, DrawingAreaErrorKind, IntoDrawingArea, OutOfRange};
 
 pub use backend_impl::*;
 
-pub use backend::DrawingBackend;
+pub use backend::{AnimatedBackend, DrawingBackend, ImageScaling, PixelFormat};
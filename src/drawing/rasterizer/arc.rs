@@ -0,0 +1,142 @@
+use crate::drawing::backend::{BackendCoord, BackendStyle, DrawingErrorKind};
+use crate::drawing::DrawingBackend;
+
+use crate::style::Color;
+
+use std::f64::consts::PI;
+
+/// The angle in radians each line segment of a polygonized arc covers at most
+const ARC_STEP: f64 = PI / 90.0;
+
+/// Normalize the angles of an arc, which may go either way, into the angle it starts at and the
+/// counterclockwise sweep from there, which is at most a full turn
+pub fn arc_sweep(angles: (f64, f64)) -> (f64, f64) {
+    let (start, end) = if angles.0 <= angles.1 {
+        angles
+    } else {
+        (angles.1, angles.0)
+    };
+    (start, (end - start).min(PI * 2.0))
+}
+
+/// Check if the angle is covered by the sweep from `arc_sweep`
+fn in_sweep(angle: f64, (start, sweep): (f64, f64)) -> bool {
+    sweep >= PI * 2.0 || (angle - start).rem_euclid(PI * 2.0) <= sweep
+}
+
+/// Get the points of the arc as a polyline, from the start to the end of the sweep
+fn arc_points(center: BackendCoord, radius: u32, (start, sweep): (f64, f64)) -> Vec<BackendCoord> {
+    let steps = (sweep / ARC_STEP).ceil().max(1.0) as usize;
+    let radius = f64::from(radius);
+    (0..=steps)
+        .map(|i| {
+            let angle = start + sweep * i as f64 / steps as f64;
+            (
+                center.0 + (radius * angle.cos()).round() as i32,
+                center.1 - (radius * angle.sin()).round() as i32,
+            )
+        })
+        .collect()
+}
+
+/// Draw an arc with the midpoint circle algorithm, which only plots the pixels within the
+/// angles. The angles are in radians counterclockwise from the 3 o'clock position. The wide
+/// and the dashed arcs are drawn as a path instead.
+pub fn draw_arc<B: DrawingBackend, S: BackendStyle>(
+    b: &mut B,
+    center: BackendCoord,
+    radius: u32,
+    angles: (f64, f64),
+    style: &S,
+) -> Result<(), DrawingErrorKind<B::ErrorType>> {
+    if style.as_color().alpha() == 0.0 {
+        return Ok(());
+    }
+
+    let sweep = arc_sweep(angles);
+    if style.stroke_width() != 1 || style.dash_pattern().is_some() {
+        return b.draw_path(arc_points(center, radius, sweep), style);
+    }
+
+    let color = style.as_color();
+    let (mut x, mut y) = (0, radius as i32);
+    let mut d = 1 - radius as i32;
+    let mut octants = vec![];
+    while x <= y {
+        octants.clear();
+        octants.extend_from_slice(&[
+            (x, y),
+            (y, x),
+            (-x, y),
+            (-y, x),
+            (x, -y),
+            (y, -x),
+            (-x, -y),
+            (-y, -x),
+        ]);
+        // The pixels on the axes and on the diagonals are shared by two octants
+        octants.sort();
+        octants.dedup();
+        for &(dx, dy) in octants.iter() {
+            if in_sweep(f64::from(-dy).atan2(f64::from(dx)), sweep) {
+                b.draw_pixel((center.0 + dx, center.1 + dy), &color)?;
+            }
+        }
+
+        if d < 0 {
+            d += 2 * x + 3;
+        } else {
+            d += 2 * (x - y) + 5;
+            y -= 1;
+        }
+        x += 1;
+    }
+
+    Ok(())
+}
+
+/// Fill a circular sector, which is an annular sector if the inner radius isn't zero, one row of
+/// pixels at a time. The angles are in radians counterclockwise from the 3 o'clock position.
+pub fn fill_sector<B: DrawingBackend, S: BackendStyle>(
+    b: &mut B,
+    center: BackendCoord,
+    (inner, outer): (u32, u32),
+    angles: (f64, f64),
+    style: &S,
+) -> Result<(), DrawingErrorKind<B::ErrorType>> {
+    if style.as_color().alpha() == 0.0 {
+        return Ok(());
+    }
+
+    let sweep = arc_sweep(angles);
+    let color = style.as_color();
+    let (inner, outer) = (i64::from(inner.min(outer)), i64::from(outer));
+    let covers = |dx: i32, dy: i32| {
+        let dist = i64::from(dx) * i64::from(dx) + i64::from(dy) * i64::from(dy);
+        if dist > outer * outer || dist < inner * inner {
+            return false;
+        }
+        dist == 0 || in_sweep(f64::from(-dy).atan2(f64::from(dx)), sweep)
+    };
+
+    let outer = outer as i32;
+    for dy in -outer..=outer {
+        let mut run_start = None;
+        for dx in -outer..=outer + 1 {
+            match (run_start, dx <= outer && covers(dx, dy)) {
+                (None, true) => run_start = Some(dx),
+                (Some(from), false) => {
+                    b.draw_line(
+                        (center.0 + from, center.1 + dy),
+                        (center.0 + dx - 1, center.1 + dy),
+                        &color,
+                    )?;
+                    run_start = None;
+                }
+                _ => {}
+            }
+        }
+    }
+
+    Ok(())
+}
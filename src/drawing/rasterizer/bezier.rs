@@ -0,0 +1,67 @@
+use crate::drawing::backend::BackendCoord;
+
+/// The deepest the curves are split, which is enough for any curve on a screen
+const MAX_DEPTH: u32 = 16;
+
+type Point = (f64, f64);
+
+fn mid(a: Point, b: Point) -> Point {
+    ((a.0 + b.0) / 2.0, (a.1 + b.1) / 2.0)
+}
+
+/// The distance from the point to the line through `from` and `to`, or to `from` if they're
+/// the same point
+fn distance_to_line(p: Point, from: Point, to: Point) -> f64 {
+    let (dx, dy) = (to.0 - from.0, to.1 - from.1);
+    let len = dx.hypot(dy);
+    if len == 0.0 {
+        return (p.0 - from.0).hypot(p.1 - from.1);
+    }
+    ((p.0 - from.0) * dy - (p.1 - from.1) * dx).abs() / len
+}
+
+/// Split the curve in halves until the control points of each piece are within the tolerance
+/// of its chord, then push the end of each piece
+fn flatten_curve(curve: [Point; 4], tolerance: f64, depth: u32, out: &mut Vec<Point>) {
+    let [p0, c0, c1, p1] = curve;
+    if depth >= MAX_DEPTH
+        || distance_to_line(c0, p0, p1).max(distance_to_line(c1, p0, p1)) <= tolerance
+    {
+        out.push(p1);
+        return;
+    }
+    // The de Casteljau subdivision at the middle of the curve
+    let (a, b, c) = (mid(p0, c0), mid(c0, c1), mid(c1, p1));
+    let (d, e) = (mid(a, b), mid(b, c));
+    let m = mid(d, e);
+    flatten_curve([p0, a, d, m], tolerance, depth + 1, out);
+    flatten_curve([m, e, c, p1], tolerance, depth + 1, out);
+}
+
+/// Flatten a path of cubic Bézier curves into line segments which are at most the tolerance
+/// away from the curves, in pixels. Each segment is the two control points and the end point
+/// of a curve, which starts at the end of the previous one.
+pub fn flatten_bezier(
+    start: BackendCoord,
+    segments: &[[BackendCoord; 3]],
+    tolerance: f64,
+) -> Vec<BackendCoord> {
+    let to_f64 = |(x, y): BackendCoord| (f64::from(x), f64::from(y));
+    let mut points = vec![];
+    let mut from = to_f64(start);
+    for segment in segments {
+        let end = to_f64(segment[2]);
+        let curve = [from, to_f64(segment[0]), to_f64(segment[1]), end];
+        flatten_curve(curve, tolerance.max(0.01), 0, &mut points);
+        from = end;
+    }
+
+    let mut ret = vec![start];
+    for (x, y) in points {
+        let point = (x.round() as i32, y.round() as i32);
+        if ret.last() != Some(&point) {
+            ret.push(point);
+        }
+    }
+    ret
+}
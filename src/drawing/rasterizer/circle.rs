@@ -14,6 +14,16 @@ pub fn draw_circle<B: DrawingBackend, S: BackendStyle>(
         return Ok(());
     }
 
+    if fill && super::is_painted(style) {
+        let r = radius as i32;
+        let spans = (-r..=r).map(|dy| {
+            let half = (f64::from(r * r - dy * dy)).sqrt() as i32;
+            (center.1 + dy, center.0 - half, center.0 + half)
+        });
+        let bounds = ((center.0 - r, center.1 - r), (center.0 + r, center.1 + r));
+        return super::fill_spans_painted(b, style, bounds, spans);
+    }
+
     if !fill && style.stroke_width() != 1 {
         // FIXME: We are currently ignore the stroke width for circles
     }
@@ -0,0 +1,191 @@
+use crate::drawing::backend::BackendCoord;
+
+/// Check if the point is inside the clipping rectangle, including its edges
+pub fn is_inside(point: BackendCoord, (ul, br): (BackendCoord, BackendCoord)) -> bool {
+    ul.0 <= point.0 && point.0 <= br.0 && ul.1 <= point.1 && point.1 <= br.1
+}
+
+/// Clip the line segment to the rectangle with the Liang-Barsky algorithm, which gives the part
+/// of the segment that is inside the rectangle, or `None` if there isn't any
+fn clip_segment(
+    from: BackendCoord,
+    to: BackendCoord,
+    (ul, br): (BackendCoord, BackendCoord),
+) -> Option<(BackendCoord, BackendCoord)> {
+    let (x0, y0) = (f64::from(from.0), f64::from(from.1));
+    let (dx, dy) = (f64::from(to.0) - x0, f64::from(to.1) - y0);
+    let (mut t0, mut t1) = (0.0f64, 1.0f64);
+    let edges = [
+        (-dx, x0 - f64::from(ul.0)),
+        (dx, f64::from(br.0) - x0),
+        (-dy, y0 - f64::from(ul.1)),
+        (dy, f64::from(br.1) - y0),
+    ];
+    for &(p, q) in edges.iter() {
+        if p == 0.0 {
+            if q < 0.0 {
+                return None;
+            }
+        } else {
+            let t = q / p;
+            if p < 0.0 {
+                t0 = t0.max(t);
+            } else {
+                t1 = t1.min(t);
+            }
+        }
+    }
+    if t0 > t1 {
+        return None;
+    }
+    let at = |t: f64| ((x0 + dx * t).round() as i32, (y0 + dy * t).round() as i32);
+    let from = if t0 > 0.0 { at(t0) } else { from };
+    let to = if t1 < 1.0 { at(t1) } else { to };
+    Some((from, to))
+}
+
+/// Clip the polyline to the rectangle. The segments crossing the edges are cut at the edges,
+/// and the polyline is split into several pieces where it leaves the rectangle.
+///
+/// - `path`: The points of the polyline
+/// - `clip`: The upper left and the bottom right corners of the rectangle
+/// - **returns**: The pieces of the polyline inside the rectangle, each of at least two points
+pub fn clip_path(
+    path: &[BackendCoord],
+    clip: (BackendCoord, BackendCoord),
+) -> Vec<Vec<BackendCoord>> {
+    let mut ret: Vec<Vec<BackendCoord>> = vec![];
+    let mut current: Vec<BackendCoord> = vec![];
+    for segment in path.windows(2) {
+        match clip_segment(segment[0], segment[1], clip) {
+            Some((from, to)) => {
+                if current.last() != Some(&from) {
+                    if current.len() > 1 {
+                        ret.push(std::mem::take(&mut current));
+                    }
+                    current = vec![from];
+                }
+                current.push(to);
+                // The polyline leaves the rectangle at the end of the clipped segment
+                if to != segment[1] {
+                    ret.push(std::mem::take(&mut current));
+                }
+            }
+            None => {
+                if current.len() > 1 {
+                    ret.push(std::mem::take(&mut current));
+                }
+                current.clear();
+            }
+        }
+    }
+    if current.len() > 1 {
+        ret.push(current);
+    }
+    if ret.is_empty() && path.len() == 1 && is_inside(path[0], clip) {
+        ret.push(path.to_vec());
+    }
+    ret
+}
+
+/// Clip the polygon to the rectangle with the Sutherland-Hodgman algorithm
+///
+/// - `points`: The vertices of the polygon
+/// - `clip`: The upper left and the bottom right corners of the rectangle
+/// - **returns**: The vertices of the part of the polygon inside the rectangle, which is empty
+///   if the polygon is completely outside of it
+pub fn clip_polygon(
+    points: &[BackendCoord],
+    (ul, br): (BackendCoord, BackendCoord),
+) -> Vec<BackendCoord> {
+    let to_f64 = |p: &BackendCoord| (f64::from(p.0), f64::from(p.1));
+    let mut polygon: Vec<(f64, f64)> = points.iter().map(to_f64).collect();
+
+    // Each edge keeps the points where `axis` is on the side of `limit` given by `keep_less`
+    let edges = [
+        (0, f64::from(ul.0), false),
+        (0, f64::from(br.0), true),
+        (1, f64::from(ul.1), false),
+        (1, f64::from(br.1), true),
+    ];
+    for &(axis, limit, keep_less) in edges.iter() {
+        if polygon.is_empty() {
+            break;
+        }
+        let value = |p: &(f64, f64)| if axis == 0 { p.0 } else { p.1 };
+        let inside = |p: &(f64, f64)| {
+            if keep_less {
+                value(p) <= limit
+            } else {
+                value(p) >= limit
+            }
+        };
+        let cross = |a: &(f64, f64), b: &(f64, f64)| {
+            let t = (limit - value(a)) / (value(b) - value(a));
+            (a.0 + (b.0 - a.0) * t, a.1 + (b.1 - a.1) * t)
+        };
+
+        let mut output = vec![];
+        for (idx, point) in polygon.iter().enumerate() {
+            let prev = &polygon[(idx + polygon.len() - 1) % polygon.len()];
+            match (inside(prev), inside(point)) {
+                (true, true) => output.push(*point),
+                (true, false) => output.push(cross(prev, point)),
+                (false, true) => {
+                    output.push(cross(prev, point));
+                    output.push(*point);
+                }
+                (false, false) => {}
+            }
+        }
+        polygon = output;
+    }
+
+    polygon
+        .into_iter()
+        .map(|(x, y)| (x.round() as i32, y.round() as i32))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_clip_path() {
+        let clip = ((0, 0), (100, 100));
+        // The segments crossing the edges are cut, not dropped
+        assert_eq!(
+            clip_path(&[(50, 50), (150, 50)], clip),
+            [vec![(50, 50), (100, 50)]]
+        );
+        // The polyline is split where it leaves the rectangle and comes back
+        assert_eq!(
+            clip_path(&[(20, 20), (20, 200), (80, 200), (80, 20)], clip),
+            [vec![(20, 20), (20, 100)], vec![(80, 100), (80, 20)]]
+        );
+        assert_eq!(
+            clip_path(&[(-50, 50), (150, 50), (50, 50)], clip),
+            [vec![(0, 50), (100, 50)], vec![(100, 50), (50, 50)]]
+        );
+        assert!(clip_path(&[(200, 0), (200, 100)], clip).is_empty());
+        assert_eq!(
+            clip_path(&[(10, 10), (20, 20)], clip),
+            [vec![(10, 10), (20, 20)]]
+        );
+    }
+
+    #[test]
+    fn test_clip_polygon() {
+        let clip = ((0, 0), (100, 100));
+        assert_eq!(
+            clip_polygon(&[(50, 50), (150, 50), (150, 80), (50, 80)], clip),
+            [(50, 50), (100, 50), (100, 80), (50, 80)]
+        );
+        assert!(clip_polygon(&[(150, 50), (200, 50), (200, 80)], clip).is_empty());
+        assert_eq!(
+            clip_polygon(&[(10, 10), (20, 10), (20, 20)], clip),
+            [(10, 10), (20, 10), (20, 20)]
+        );
+    }
+}
@@ -0,0 +1,112 @@
+use crate::drawing::backend::BackendCoord;
+
+/// Split the polyline into the dashes of the pattern. The phase of the pattern is kept across
+/// the corners of the polyline, so a dash can go around a corner.
+///
+/// - `path`: The points of the polyline
+/// - `pattern`: The lengths of the dashes and the gaps in pixels, in turn. A pattern of an
+///   odd length is repeated twice, like the stroke dash array of SVG.
+/// - **returns**: The dashes, each of at least two points
+pub fn dash_path(path: &[BackendCoord], pattern: &[u32]) -> Vec<Vec<BackendCoord>> {
+    let mut pattern: Vec<f64> = pattern.iter().map(|x| f64::from(*x)).collect();
+    if pattern.len() % 2 == 1 {
+        pattern.extend(pattern.clone());
+    }
+    if path.is_empty() || pattern.iter().sum::<f64>() <= 0.0 {
+        return vec![path.to_vec()];
+    }
+
+    let mut ret = vec![];
+    let (mut idx, mut remaining, mut on) = (0, pattern[0], true);
+    let mut current = vec![path[0]];
+    for segment in path.windows(2) {
+        let (from, to) = (segment[0], segment[1]);
+        let (dx, dy) = (f64::from(to.0 - from.0), f64::from(to.1 - from.1));
+        let length = (dx * dx + dy * dy).sqrt();
+        let mut pos = 0.0;
+        while length - pos > remaining {
+            pos += remaining;
+            let point = (
+                (f64::from(from.0) + dx * pos / length).round() as i32,
+                (f64::from(from.1) + dy * pos / length).round() as i32,
+            );
+            if on {
+                current.push(point);
+                ret.push(std::mem::take(&mut current));
+            } else {
+                current = vec![point];
+            }
+            on = !on;
+            idx = (idx + 1) % pattern.len();
+            remaining = pattern[idx];
+        }
+        remaining -= length - pos;
+        if on {
+            current.push(to);
+        }
+    }
+    if on {
+        ret.push(current);
+    }
+
+    // The dashes of zero length aren't visible
+    ret.retain(|dash| dash.len() > 1 && dash.iter().any(|p| *p != dash[0]));
+    ret
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_dash_path() {
+        assert_eq!(
+            dash_path(&[(0, 0), (20, 0)], &[6, 3]),
+            [
+                vec![(0, 0), (6, 0)],
+                vec![(9, 0), (15, 0)],
+                vec![(18, 0), (20, 0)]
+            ]
+        );
+        // The phase goes on around the corner, and so does the dash
+        assert_eq!(
+            dash_path(&[(0, 0), (4, 0), (4, 10)], &[6, 3]),
+            [vec![(0, 0), (4, 0), (4, 2)], vec![(4, 5), (4, 10)]]
+        );
+        // The pattern of an odd length is repeated
+        assert_eq!(
+            dash_path(&[(0, 0), (0, 10)], &[4]),
+            [vec![(0, 0), (0, 4)], vec![(0, 8), (0, 10)]]
+        );
+        assert_eq!(
+            dash_path(&[(0, 0), (10, 0)], &[0, 0]),
+            [vec![(0, 0), (10, 0)]]
+        );
+        assert_eq!(
+            dash_path(&[(0, 0), (10, 0)], &[0, 5]),
+            Vec::<Vec<BackendCoord>>::new()
+        );
+    }
+
+    #[test]
+    fn test_dashed_bitmap_line() {
+        use crate::prelude::*;
+        let mut buf = vec![0u8; 20 * 3 * 3];
+        {
+            let backend =
+                BitMapBackend::with_buffer_and_format(&mut buf, (20, 3), PixelFormat::Rgb).unwrap();
+            let root = backend.into_drawing_area();
+            root.draw(&PathElement::new(
+                vec![(0, 1), (19, 1)],
+                ShapeStyle::from(&WHITE).dash(&[6, 3]),
+            ))
+            .unwrap();
+        }
+        let row: Vec<_> = (0..20).map(|x| buf[(20 + x) * 3] == 255).collect();
+        // The gaps are left out, and the dashes are drawn including their ends
+        assert!(row[..7].iter().all(|x| *x));
+        assert!(!row[7] && !row[8]);
+        assert!(row[9..16].iter().all(|x| *x));
+        assert!(!row[16] && !row[17] && row[18] && row[19]);
+    }
+}
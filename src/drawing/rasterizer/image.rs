@@ -0,0 +1,143 @@
+use crate::drawing::backend::{BackendCoord, DrawingErrorKind, ImageScaling, PixelFormat};
+use crate::drawing::DrawingBackend;
+
+use crate::style::{Color, RGBColor};
+
+/// Samples the pixels of a bitmap which is drawn at another size than its own
+pub struct ImageSampler<'a> {
+    src: &'a [u8],
+    src_size: (u32, u32),
+    format: PixelFormat,
+    size: (u32, u32),
+    scaling: ImageScaling,
+}
+
+/// Map the center of the pixel of the scaled bitmap to the position in the source bitmap
+fn source_position(pos: u32, size: u32, src_size: u32) -> f64 {
+    (f64::from(pos) + 0.5) * f64::from(src_size) / f64::from(size.max(1))
+}
+
+impl<'a> ImageSampler<'a> {
+    /// Create a sampler of the bitmap, which is `None` if the bitmap is empty or its buffer is
+    /// too short for its size
+    pub fn new(
+        src: &'a [u8],
+        src_size: (u32, u32),
+        format: PixelFormat,
+        size: (u32, u32),
+        scaling: ImageScaling,
+    ) -> Option<Self> {
+        let len = src_size.0 as usize * src_size.1 as usize * format.bytes_per_pixel();
+        if len == 0 || src.len() < len {
+            return None;
+        }
+        Some(Self {
+            src,
+            src_size,
+            format,
+            size,
+            scaling,
+        })
+    }
+
+    /// Get the red, the green, the blue and the alpha of a source pixel, with the color
+    /// premultiplied by the alpha
+    fn source_pixel(&self, x: u32, y: u32) -> [f64; 4] {
+        let bpp = self.format.bytes_per_pixel();
+        let base = (y as usize * self.src_size.0 as usize + x as usize) * bpp;
+        let pixel = &self.src[base..base + bpp];
+        let alpha = self
+            .format
+            .alpha_offset()
+            .map_or(1.0, |offset| f64::from(pixel[offset]) / 255.0);
+        let [r, g, b] = self.format.rgb_offsets();
+        [
+            f64::from(pixel[r]) * alpha,
+            f64::from(pixel[g]) * alpha,
+            f64::from(pixel[b]) * alpha,
+            alpha,
+        ]
+    }
+
+    /// Get the red, the green, the blue and the alpha of the pixel of the scaled bitmap
+    pub fn sample(&self, x: u32, y: u32) -> [u8; 4] {
+        let (sw, sh) = self.src_size;
+        let fx = source_position(x, self.size.0, sw);
+        let fy = source_position(y, self.size.1, sh);
+        let [r, g, b, a] = match self.scaling {
+            ImageScaling::Nearest => {
+                self.source_pixel((fx as u32).min(sw - 1), (fy as u32).min(sh - 1))
+            }
+            ImageScaling::Bilinear => {
+                // The four source pixels around the sample are weighted by the distance
+                let fx = (fx - 0.5).max(0.0).min(f64::from(sw - 1));
+                let fy = (fy - 0.5).max(0.0).min(f64::from(sh - 1));
+                let (x0, y0) = (fx as u32, fy as u32);
+                let (x1, y1) = ((x0 + 1).min(sw - 1), (y0 + 1).min(sh - 1));
+                let (tx, ty) = (fx - f64::from(x0), fy - f64::from(y0));
+                let mut ret = [0.0; 4];
+                for &(px, py, weight) in [
+                    (x0, y0, (1.0 - tx) * (1.0 - ty)),
+                    (x1, y0, tx * (1.0 - ty)),
+                    (x0, y1, (1.0 - tx) * ty),
+                    (x1, y1, tx * ty),
+                ]
+                .iter()
+                {
+                    for (sum, value) in ret.iter_mut().zip(self.source_pixel(px, py).iter()) {
+                        *sum += value * weight;
+                    }
+                }
+                ret
+            }
+        };
+        if a <= 0.0 {
+            return [0; 4];
+        }
+        let channel = |value: f64| (value / a).round().clamp(0.0, 255.0) as u8;
+        [
+            channel(r),
+            channel(g),
+            channel(b),
+            (a * 255.0).round() as u8,
+        ]
+    }
+}
+
+/// Draw a bitmap scaled to the given size pixel by pixel, leaving out the pixels outside of the
+/// backend and blending the others by their alpha
+pub fn draw_image<B: DrawingBackend>(
+    b: &mut B,
+    pos: BackendCoord,
+    size: (u32, u32),
+    src_size: (u32, u32),
+    src: &[u8],
+    format: PixelFormat,
+    scaling: ImageScaling,
+) -> Result<(), DrawingErrorKind<B::ErrorType>> {
+    let sampler = match ImageSampler::new(src, src_size, format, size, scaling) {
+        Some(sampler) => sampler,
+        None => return Ok(()),
+    };
+    if size == src_size && format == PixelFormat::Rgb {
+        return b.blit_bitmap(pos, size, src);
+    }
+
+    let (w, h) = b.get_size();
+    let visible = |from: i32, len: u32, limit: u32| {
+        let start = (-from).max(0).min(len as i32);
+        let end = (limit as i32 - from).max(start).min(len as i32);
+        start as u32..end as u32
+    };
+    for dy in visible(pos.1, size.1, h) {
+        for dx in visible(pos.0, size.0, w) {
+            let [red, green, blue, alpha] = sampler.sample(dx, dy);
+            if alpha == 0 {
+                continue;
+            }
+            let color = RGBColor(red, green, blue).mix(f64::from(alpha) / 255.0);
+            b.draw_pixel((pos.0 + dx as i32, pos.1 + dy as i32), &color)?;
+        }
+    }
+    Ok(())
+}
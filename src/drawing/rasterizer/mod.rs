@@ -12,13 +12,37 @@ mod line;
 pub use line::draw_line;
 
 mod rect;
-pub use rect::draw_rect;
+pub use rect::{clamp_corner_radii, draw_rect, draw_rounded_rect};
+
+mod bezier;
+pub use bezier::flatten_bezier;
 
 mod circle;
 pub use circle::draw_circle;
 
+mod arc;
+pub use arc::{arc_sweep, draw_arc, fill_sector};
+
+mod paint;
+pub use paint::{fill_spans_painted, is_painted};
+
+mod image;
+pub use image::{draw_image, ImageSampler};
+
+mod shadow;
+pub use shadow::{draw_shadow, AlphaMask};
+
 mod polygon;
-pub use polygon::fill_polygon;
+pub use polygon::{fill_polygon, fill_polygon_gradient};
 
 mod path;
 pub use path::polygonize;
+
+mod clip;
+pub use clip::{clip_path, clip_polygon, is_inside};
+
+mod dash;
+pub use dash::dash_path;
+
+mod text;
+pub use text::draw_text;
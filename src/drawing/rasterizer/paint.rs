@@ -0,0 +1,52 @@
+use crate::drawing::backend::{BackendCoord, BackendStyle, DrawingErrorKind};
+use crate::drawing::DrawingBackend;
+
+use crate::style::{Color, RGBAColor};
+
+/// Check if the shapes filled with the style are painted pixel by pixel, which is the case for
+/// the gradients and the patterns
+pub fn is_painted<S: BackendStyle>(style: &S) -> bool {
+    style.pattern().is_some() || style.gradient().is_some()
+}
+
+/// Fill the spans of a shape pixel by pixel with the pattern or the gradient of the style. The
+/// gradient spans the bounding box of the shape and the pattern starts at its upper left
+/// corner. Each span is the row and the first and the last column it covers.
+pub fn fill_spans_painted<B: DrawingBackend, S: BackendStyle, I>(
+    b: &mut B,
+    style: &S,
+    (upper_left, bottom_right): (BackendCoord, BackendCoord),
+    spans: I,
+) -> Result<(), DrawingErrorKind<B::ErrorType>>
+where
+    I: IntoIterator<Item = (i32, i32, i32)>,
+{
+    let color = style.as_color();
+    let relative = |pos: i32, from: i32, to: i32| {
+        if to > from {
+            f64::from(pos - from) / f64::from(to - from)
+        } else {
+            0.5
+        }
+    };
+    let paint = |x: i32, y: i32| -> Option<RGBAColor> {
+        match (style.pattern(), style.gradient()) {
+            (Some(pattern), _) => pattern.color_at((x - upper_left.0, y - upper_left.1), &color),
+            (None, Some(gradient)) => {
+                let u = relative(x, upper_left.0, bottom_right.0);
+                let v = relative(y, upper_left.1, bottom_right.1);
+                Some(gradient.color_at(gradient.offset_in_box((u, v))))
+            }
+            (None, None) => Some(color.clone()),
+        }
+    };
+    for (y, from, to) in spans {
+        for x in from..=to {
+            match paint(x, y) {
+                Some(color) if color.alpha() > 0.0 => b.draw_pixel((x, y), &color)?,
+                _ => {}
+            }
+        }
+    }
+    Ok(())
+}
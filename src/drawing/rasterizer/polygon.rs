@@ -1,7 +1,7 @@
 use crate::drawing::backend::{BackendCoord, BackendStyle, DrawingErrorKind};
 use crate::drawing::DrawingBackend;
 
-use crate::style::Color;
+use crate::style::{Color, RGBAColor, RGBColor};
 
 use std::cmp::{Ord, Ordering, PartialOrd};
 
@@ -77,6 +77,15 @@ pub fn fill_polygon<DB: DrawingBackend, S: BackendStyle>(
     vertices: &[BackendCoord],
     style: &S,
 ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+    if super::is_painted(style) {
+        let (mut ul, mut br) = ((i32::MAX, i32::MAX), (i32::MIN, i32::MIN));
+        for &(x, y) in vertices {
+            ul = (ul.0.min(x), ul.1.min(y));
+            br = (br.0.max(x), br.1.max(y));
+        }
+        return super::fill_spans_painted(back, style, (ul, br), polygon_spans(vertices));
+    }
+
     if let Some((x_span, y_span)) =
         vertices
             .iter()
@@ -98,6 +107,17 @@ pub fn fill_polygon<DB: DrawingBackend, S: BackendStyle>(
             return back.draw_line((x_span.0, y_span.0), (x_span.1, y_span.1), style);
         }
 
+        // The points on a same sloped line, which includes the polygons with only two points,
+        // have no area either, so they are drawn as the line between the two farthest points
+        let from = *vertices.iter().min().unwrap();
+        let to = *vertices.iter().max().unwrap();
+        if vertices.iter().all(|&(x, y)| {
+            i64::from(to.0 - from.0) * i64::from(y - from.1)
+                == i64::from(to.1 - from.1) * i64::from(x - from.0)
+        }) {
+            return back.draw_line(from, to, style);
+        }
+
         let horizontal_sweep = x_span.1 - x_span.0 > y_span.1 - y_span.0;
 
         let mut edges: Vec<_> = vertices
@@ -229,7 +249,7 @@ pub fn fill_polygon<DB: DrawingBackend, S: BackendStyle>(
                             ));
                             check_result!(back.draw_pixel(
                                 (to.ceil() as i32, sweep_line),
-                                &style.as_color().mix(to.floor() - to),
+                                &style.as_color().mix(to - to.floor()),
                             ));
                         }
 
@@ -243,3 +263,68 @@ pub fn fill_polygon<DB: DrawingBackend, S: BackendStyle>(
 
     Ok(())
 }
+
+/// Interpolate the color of a vertical gradient at the row `y`, the color is clamped to the
+/// color of the nearest stop outside of the two stops
+fn gradient_color(y: i32, top: (i32, &RGBAColor), bottom: (i32, &RGBAColor)) -> RGBAColor {
+    let t = if top.0 == bottom.0 {
+        0.0
+    } else {
+        (f64::from(y - top.0) / f64::from(bottom.0 - top.0)).clamp(0.0, 1.0)
+    };
+    let ((r0, g0, b0), (r1, g1, b1)) = (top.1.rgb(), bottom.1.rgb());
+    let lerp = |a: u8, b: u8| (f64::from(a) + (f64::from(b) - f64::from(a)) * t).round() as u8;
+    RGBColor(lerp(r0, r1), lerp(g0, g1), lerp(b0, b1))
+        .mix(top.1.alpha() + (bottom.1.alpha() - top.1.alpha()) * t)
+}
+
+/// Get the spans of the rows the polygon covers with the even-odd rule, each of which is the
+/// row and the first and the last column of the span
+fn polygon_spans(vertices: &[BackendCoord]) -> Vec<(i32, i32, i32)> {
+    let mut spans = vec![];
+    if vertices.len() < 3 {
+        return spans;
+    }
+
+    let min_y = vertices.iter().map(|p| p.1).min().unwrap_or(0);
+    let max_y = vertices.iter().map(|p| p.1).max().unwrap_or(0);
+
+    let mut crossings = vec![];
+    for y in min_y..max_y {
+        crossings.clear();
+        for (idx, a) in vertices.iter().enumerate() {
+            let b = &vertices[(idx + 1) % vertices.len()];
+            // Each edge covers the rows from its upper end to the one above its lower end,
+            // so that a vertex shared by two edges is counted once
+            if (a.1 <= y) != (b.1 <= y) {
+                let t = f64::from(y - a.1) / f64::from(b.1 - a.1);
+                crossings.push(f64::from(a.0) + f64::from(b.0 - a.0) * t);
+            }
+        }
+        crossings.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+
+        for span in crossings.chunks(2) {
+            if let [from, to] = span {
+                spans.push((y, from.round() as i32, to.round() as i32));
+            }
+        }
+    }
+
+    spans
+}
+
+/// Fill the polygon with a vertical linear gradient, one scanline at a time. Each row is
+/// filled with the color interpolated between the `top` and the `bottom` stops, which are
+/// the rows where the gradient starts and ends with the given colors.
+pub fn fill_polygon_gradient<DB: DrawingBackend>(
+    back: &mut DB,
+    vertices: &[BackendCoord],
+    top: (i32, &RGBAColor),
+    bottom: (i32, &RGBAColor),
+) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+    for (y, from, to) in polygon_spans(vertices) {
+        back.draw_line((from, y), (to, y), &gradient_color(y, top, bottom))?;
+    }
+
+    Ok(())
+}
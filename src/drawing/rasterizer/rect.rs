@@ -3,6 +3,8 @@ use crate::drawing::DrawingBackend;
 
 use crate::style::Color;
 
+use std::f64::consts::PI;
+
 pub fn draw_rect<B: DrawingBackend, S: BackendStyle>(
     b: &mut B,
     upper_left: BackendCoord,
@@ -24,6 +26,11 @@ pub fn draw_rect<B: DrawingBackend, S: BackendStyle>(
         ),
     );
 
+    if fill && super::is_painted(style) {
+        let spans = (upper_left.1..=bottom_right.1).map(|y| (y, upper_left.0, bottom_right.0));
+        return super::fill_spans_painted(b, style, (upper_left, bottom_right), spans);
+    }
+
     if fill {
         if bottom_right.0 - upper_left.0 < bottom_right.1 - upper_left.1 {
             for x in upper_left.0..=bottom_right.0 {
@@ -58,3 +65,96 @@ pub fn draw_rect<B: DrawingBackend, S: BackendStyle>(
     }
     Ok(())
 }
+
+/// Limit the radii of the corners, which are in the order of the upper left, the upper right,
+/// the bottom right and the bottom left corner, to half of the shorter side of the rectangle
+pub fn clamp_corner_radii(
+    upper_left: BackendCoord,
+    bottom_right: BackendCoord,
+    radii: [u32; 4],
+) -> [i32; 4] {
+    let max_radius = (bottom_right.0 - upper_left.0)
+        .abs()
+        .min((bottom_right.1 - upper_left.1).abs())
+        / 2;
+    let mut ret = [0; 4];
+    for (clamped, radius) in ret.iter_mut().zip(radii.iter()) {
+        *clamped = (*radius as i32).min(max_radius).max(0);
+    }
+    ret
+}
+
+/// The number of the pixels a rounded corner leaves out of the row `dy` rows away from the
+/// center of the corner, which covers the same pixels as the midpoint circle outline does
+fn corner_inset(radius: i32, dy: i32) -> i32 {
+    if dy <= 0 || radius == 0 {
+        return 0;
+    }
+    let half_width = f64::from(radius * radius + radius - dy * dy)
+        .max(0.0)
+        .sqrt() as i32;
+    (radius - half_width).max(0)
+}
+
+/// Draw a rectangle with the rounded corners. A filled rectangle is filled one row at a time,
+/// and the outline is made of the straight edges and the quarter circles of the corners.
+pub fn draw_rounded_rect<B: DrawingBackend, S: BackendStyle>(
+    b: &mut B,
+    upper_left: BackendCoord,
+    bottom_right: BackendCoord,
+    radii: [u32; 4],
+    style: &S,
+    fill: bool,
+) -> Result<(), DrawingErrorKind<B::ErrorType>> {
+    if style.as_color().alpha() == 0.0 {
+        return Ok(());
+    }
+    let (ul, br) = (
+        (
+            upper_left.0.min(bottom_right.0),
+            upper_left.1.min(bottom_right.1),
+        ),
+        (
+            upper_left.0.max(bottom_right.0),
+            upper_left.1.max(bottom_right.1),
+        ),
+    );
+    let [r_ul, r_ur, r_br, r_bl] = clamp_corner_radii(ul, br, radii);
+    if r_ul == 0 && r_ur == 0 && r_br == 0 && r_bl == 0 {
+        return draw_rect(b, ul, br, style, fill);
+    }
+
+    if fill {
+        let spans = (ul.1..=br.1).map(|y| {
+            let left = corner_inset(r_ul, ul.1 + r_ul - y).max(corner_inset(r_bl, y - br.1 + r_bl));
+            let right =
+                corner_inset(r_ur, ul.1 + r_ur - y).max(corner_inset(r_br, y - br.1 + r_br));
+            (y, ul.0 + left, br.0 - right)
+        });
+        if super::is_painted(style) {
+            return super::fill_spans_painted(b, style, (ul, br), spans);
+        }
+        let color = style.as_color();
+        for (y, from, to) in spans {
+            b.draw_line((from, y), (to, y), &color)?;
+        }
+        return Ok(());
+    }
+
+    b.draw_line((ul.0 + r_ul, ul.1), (br.0 - r_ur, ul.1), style)?;
+    b.draw_line((br.0, ul.1 + r_ur), (br.0, br.1 - r_br), style)?;
+    b.draw_line((br.0 - r_br, br.1), (ul.0 + r_bl, br.1), style)?;
+    b.draw_line((ul.0, br.1 - r_bl), (ul.0, ul.1 + r_ul), style)?;
+    let corners = [
+        ((ul.0 + r_ul, ul.1 + r_ul), r_ul, (PI / 2.0, PI)),
+        ((br.0 - r_ur, ul.1 + r_ur), r_ur, (0.0, PI / 2.0)),
+        ((br.0 - r_br, br.1 - r_br), r_br, (-PI / 2.0, 0.0)),
+        ((ul.0 + r_bl, br.1 - r_bl), r_bl, (PI, PI * 1.5)),
+    ];
+    for &(center, radius, angles) in corners.iter() {
+        if radius > 0 {
+            b.draw_arc(center, radius as u32, angles, style)?;
+        }
+    }
+    Ok(())
+}
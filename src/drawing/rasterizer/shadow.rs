@@ -0,0 +1,214 @@
+use crate::drawing::backend::{BackendCoord, BackendShadow, DrawingErrorKind};
+use crate::drawing::DrawingBackend;
+
+use crate::style::{Color, RGBAColor};
+
+use std::convert::Infallible;
+
+/// The coverage of the pixels an element draws, which is blurred into the silhouette of its
+/// drop shadow. The mask is drawn on in the coordinates of the backend, and it keeps the
+/// pixels in its rectangle only.
+pub struct AlphaMask {
+    upper_left: BackendCoord,
+    size: (u32, u32),
+    alpha: Vec<f64>,
+    /// The upper left and the bottom right corners of the pixels drawn, the latter exclusive
+    bounds: Option<(BackendCoord, BackendCoord)>,
+}
+
+/// Blur a line of the mask with a box of `2 * half + 1` pixels, the pixels beyond the line
+/// being empty
+fn box_blur(values: &[f64], half: usize) -> Vec<f64> {
+    let width = (2 * half + 1) as f64;
+    let mut sum: f64 = values.iter().take(half).sum();
+    (0..values.len())
+        .map(|i| {
+            if let Some(value) = values.get(i + half) {
+                sum += value;
+            }
+            if i > half {
+                sum -= values[i - half - 1];
+            }
+            sum.max(0.0) / width
+        })
+        .collect()
+}
+
+impl AlphaMask {
+    /// Create an empty mask
+    /// - `upper_left`: The upper left corner of the rectangle of the mask
+    /// - `size`: The size of the rectangle of the mask
+    pub fn new(upper_left: BackendCoord, size: (u32, u32)) -> Self {
+        Self {
+            upper_left,
+            size,
+            alpha: vec![0.0; size.0 as usize * size.1 as usize],
+            bounds: None,
+        }
+    }
+
+    /// Create an empty mask of the pixels of an element which may cast the shadow on a backend
+    /// - `backend_size`: The size of the backend the shadow is drawn on
+    /// - `shadow`: The shadow of the element
+    pub fn for_shadow(backend_size: (u32, u32), shadow: &BackendShadow) -> Self {
+        let reach = shadow.blur_radius as i32;
+        Self::new(
+            (-shadow.offset.0 - reach, -shadow.offset.1 - reach),
+            (
+                backend_size.0 + 2 * shadow.blur_radius,
+                backend_size.1 + 2 * shadow.blur_radius,
+            ),
+        )
+    }
+
+    fn index(&self, (x, y): BackendCoord) -> Option<usize> {
+        let (dx, dy) = (x - self.upper_left.0, y - self.upper_left.1);
+        if dx < 0 || dy < 0 || dx >= self.size.0 as i32 || dy >= self.size.1 as i32 {
+            return None;
+        }
+        Some(dy as usize * self.size.0 as usize + dx as usize)
+    }
+
+    /// Get the coverage of a pixel, which is in `[0, 1]`
+    pub fn alpha(&self, pos: BackendCoord) -> f64 {
+        self.index(pos).map_or(0.0, |idx| self.alpha[idx])
+    }
+
+    /// Get the upper left and the bottom right corners of the pixels covered, the latter
+    /// exclusive, or `None` if nothing is drawn on the mask
+    pub fn bounds(&self) -> Option<(BackendCoord, BackendCoord)> {
+        self.bounds
+    }
+
+    /// Blur the mask with two passes of a box blur in each direction, which spread the
+    /// coverage over about `radius` pixels
+    pub fn blur(&mut self, radius: u32) {
+        let (ul, br) = match self.bounds {
+            Some(bounds) if radius > 0 => bounds,
+            _ => return,
+        };
+        let half = (radius as usize).div_ceil(2);
+        let reach = 2 * half as i32;
+        let ul = (
+            (ul.0 - reach).max(self.upper_left.0),
+            (ul.1 - reach).max(self.upper_left.1),
+        );
+        let br = (
+            (br.0 + reach).min(self.upper_left.0 + self.size.0 as i32),
+            (br.1 + reach).min(self.upper_left.1 + self.size.1 as i32),
+        );
+        for _ in 0..2 {
+            for y in ul.1..br.1 {
+                let line: Vec<_> = (ul.0..br.0).map(|x| self.alpha((x, y))).collect();
+                for (x, alpha) in (ul.0..br.0).zip(box_blur(&line, half)) {
+                    let idx = self.index((x, y)).unwrap();
+                    self.alpha[idx] = alpha;
+                }
+            }
+            for x in ul.0..br.0 {
+                let line: Vec<_> = (ul.1..br.1).map(|y| self.alpha((x, y))).collect();
+                for (y, alpha) in (ul.1..br.1).zip(box_blur(&line, half)) {
+                    let idx = self.index((x, y)).unwrap();
+                    self.alpha[idx] = alpha;
+                }
+            }
+        }
+        self.bounds = Some((ul, br));
+    }
+}
+
+impl DrawingBackend for AlphaMask {
+    type ErrorType = Infallible;
+
+    /// Get the size of the part of the backend from its origin to the bottom right corner of
+    /// the mask, which the rasterized bitmaps are cut to
+    fn get_size(&self) -> (u32, u32) {
+        (
+            (self.upper_left.0 + self.size.0 as i32).max(0) as u32,
+            (self.upper_left.1 + self.size.1 as i32).max(0) as u32,
+        )
+    }
+
+    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<Infallible>> {
+        Ok(())
+    }
+
+    fn present(&mut self) -> Result<(), DrawingErrorKind<Infallible>> {
+        Ok(())
+    }
+
+    fn draw_pixel(
+        &mut self,
+        point: BackendCoord,
+        color: &RGBAColor,
+    ) -> Result<(), DrawingErrorKind<Infallible>> {
+        let alpha = color.alpha().clamp(0.0, 1.0);
+        let idx = match self.index(point) {
+            Some(idx) if alpha > 0.0 => idx,
+            _ => return Ok(()),
+        };
+        self.alpha[idx] = self.alpha[idx].max(alpha);
+        let (x, y) = point;
+        self.bounds = Some(match self.bounds {
+            Some((ul, br)) => (
+                (ul.0.min(x), ul.1.min(y)),
+                (br.0.max(x + 1), br.1.max(y + 1)),
+            ),
+            None => ((x, y), (x + 1, y + 1)),
+        });
+        Ok(())
+    }
+}
+
+/// Blur the mask of an element and draw it beneath the element as its drop shadow, blending
+/// each pixel in the color of the shadow by its coverage
+pub fn draw_shadow<B: DrawingBackend>(
+    b: &mut B,
+    mut mask: AlphaMask,
+    shadow: &BackendShadow,
+) -> Result<(), DrawingErrorKind<B::ErrorType>> {
+    mask.blur(shadow.blur_radius);
+    let (ul, br) = match mask.bounds() {
+        Some(bounds) => bounds,
+        None => return Ok(()),
+    };
+    let (w, h) = b.get_size();
+    let (dx, dy) = shadow.offset;
+    for y in ul.1.max(-dy)..br.1.min(h as i32 - dy) {
+        for x in ul.0.max(-dx)..br.0.min(w as i32 - dx) {
+            let alpha = mask.alpha((x, y));
+            // The pixels which would be less than half a level of the color are left out
+            if shadow.color.alpha() * alpha * 255.0 < 0.5 {
+                continue;
+            }
+            b.draw_pixel((x + dx, y + dy), &shadow.color.mix(alpha))?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+#[test]
+fn test_alpha_mask_blur() {
+    let mut mask = AlphaMask::new((-10, -10), (30, 30));
+    let black = crate::style::BLACK.to_rgba();
+    for y in 0..4 {
+        for x in 0..4 {
+            mask.draw_pixel((x, y), &black).unwrap();
+        }
+    }
+    mask.draw_pixel((100, 100), &black).unwrap();
+    assert_eq!(mask.bounds(), Some(((0, 0), (4, 4))));
+    assert_eq!(mask.get_size(), (20, 20));
+
+    mask.blur(2);
+    assert_eq!(mask.bounds(), Some(((-2, -2), (6, 6))));
+    // The coverage is kept, and it fades out from the center of the square
+    let pixels: Vec<_> = (-2..6).flat_map(|y| (-2..6).map(move |x| (x, y))).collect();
+    let total: f64 = pixels.iter().map(|&p| mask.alpha(p)).sum();
+    assert!((total - 16.0).abs() < 1e-9);
+    assert!(mask.alpha((1, 1)) > mask.alpha((0, 0)));
+    assert!(mask.alpha((0, 0)) > mask.alpha((-1, -1)));
+    assert!(mask.alpha((-1, -1)) > 0.0);
+    assert_eq!(mask.alpha((-3, 1)), 0.0);
+}
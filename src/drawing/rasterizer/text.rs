@@ -0,0 +1,24 @@
+use crate::drawing::backend::{BackendCoord, DrawingErrorKind};
+use crate::drawing::DrawingBackend;
+
+use crate::style::{Color, FontDesc, RGBAColor};
+
+/// Draw a text with the font rasterizer, blending each pixel of the glyphs by its coverage
+pub fn draw_text<B: DrawingBackend>(
+    b: &mut B,
+    text: &str,
+    font: &FontDesc,
+    pos: BackendCoord,
+    color: &RGBAColor,
+) -> Result<(), DrawingErrorKind<B::ErrorType>> {
+    if color.alpha() == 0.0 {
+        return Ok(());
+    }
+
+    match font.draw(text, (pos.0, pos.1), |x, y, v| {
+        b.draw_pixel((x, y), &color.mix(f64::from(v)))
+    }) {
+        Ok(drawing_result) => drawing_result,
+        Err(font_error) => Err(DrawingErrorKind::FontError(font_error)),
+    }
+}
@@ -0,0 +1,286 @@
+/*!
+  The arc and the circular sector elements, with the angles in a configurable convention
+*/
+
+use crate::drawing::backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+use crate::element::{Drawable, PointCollection};
+use crate::style::ShapeStyle;
+
+use std::f64::consts::PI;
+
+/// How the angles of an arc or a sector are measured. By default the angles are in radians and
+/// go counterclockwise from the 3 o'clock position, which is the positive x direction.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AngleConvention {
+    /// The direction of the angle zero, in radians counterclockwise from the 3 o'clock position
+    pub zero: f64,
+    /// If the angles go clockwise instead of counterclockwise
+    pub clockwise: bool,
+    /// If the angles are in degrees instead of radians
+    pub degrees: bool,
+}
+
+impl Default for AngleConvention {
+    fn default() -> Self {
+        Self {
+            zero: 0.0,
+            clockwise: false,
+            degrees: false,
+        }
+    }
+}
+
+impl AngleConvention {
+    /// The angles in degrees going clockwise from the 12 o'clock position like on a compass,
+    /// which suits the gauges and the polar plots of the headings
+    pub fn compass() -> Self {
+        Self {
+            zero: PI / 2.0,
+            clockwise: true,
+            degrees: true,
+        }
+    }
+
+    /// Convert the angle into radians counterclockwise from the 3 o'clock position
+    pub fn to_radians(&self, angle: f64) -> f64 {
+        let angle = if self.degrees {
+            angle.to_radians()
+        } else {
+            angle
+        };
+        if self.clockwise {
+            self.zero - angle
+        } else {
+            self.zero + angle
+        }
+    }
+}
+
+/// An arc of a circle around a center point, with the radius given in pixels
+pub struct Arc<Coord> {
+    center: Coord,
+    radius: u32,
+    angles: (f64, f64),
+    convention: AngleConvention,
+    style: ShapeStyle,
+}
+
+impl<Coord> Arc<Coord> {
+    /// Create a new arc element
+    /// - `center`: The center of the circle
+    /// - `radius`: The radius in pixels
+    /// - `start`: The angle the arc starts at
+    /// - `end`: The angle the arc ends at, the arc is a full circle if it's a full turn or more
+    ///   away from the start
+    /// - `style`: The style of the arc
+    pub fn new<S: Into<ShapeStyle>>(
+        center: Coord,
+        radius: u32,
+        start: f64,
+        end: f64,
+        style: S,
+    ) -> Self {
+        Self {
+            center,
+            radius,
+            angles: (start, end),
+            convention: AngleConvention::default(),
+            style: style.into(),
+        }
+    }
+
+    /// Set how the angles are measured
+    pub fn convention(mut self, convention: AngleConvention) -> Self {
+        self.convention = convention;
+        self
+    }
+}
+
+impl<'a, Coord> PointCollection<'a, Coord> for &'a Arc<Coord> {
+    type Borrow = &'a Coord;
+    type IntoIter = std::iter::Once<&'a Coord>;
+    fn point_iter(self) -> Self::IntoIter {
+        std::iter::once(&self.center)
+    }
+}
+
+impl<Coord, DB: DrawingBackend> Drawable<DB> for Arc<Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if let Some(center) = points.next() {
+            let angles = (
+                self.convention.to_radians(self.angles.0),
+                self.convention.to_radians(self.angles.1),
+            );
+            backend.draw_arc(center, self.radius, angles, &self.style)?;
+        }
+        Ok(())
+    }
+}
+
+/// A circular sector around a center point, which is an annular sector if it has an inner
+/// radius. The radii are given in pixels, and the sector may sweep any angle up to a full turn.
+pub struct Sector<Coord> {
+    center: Coord,
+    radius: u32,
+    inner_radius: u32,
+    angles: (f64, f64),
+    convention: AngleConvention,
+    style: ShapeStyle,
+}
+
+impl<Coord> Sector<Coord> {
+    /// Create a new sector element
+    /// - `center`: The center of the circle
+    /// - `radius`: The radius in pixels
+    /// - `start`: The angle the sector starts at
+    /// - `end`: The angle the sector ends at
+    /// - `style`: The style of the sector, which is only outlined if it's not filled
+    pub fn new<S: Into<ShapeStyle>>(
+        center: Coord,
+        radius: u32,
+        start: f64,
+        end: f64,
+        style: S,
+    ) -> Self {
+        Self {
+            center,
+            radius,
+            inner_radius: 0,
+            angles: (start, end),
+            convention: AngleConvention::default(),
+            style: style.into(),
+        }
+    }
+
+    /// Set the radius of the hole in the middle in pixels, which makes an annular sector
+    pub fn inner_radius(mut self, radius: u32) -> Self {
+        self.inner_radius = radius.min(self.radius);
+        self
+    }
+
+    /// Set how the angles are measured
+    pub fn convention(mut self, convention: AngleConvention) -> Self {
+        self.convention = convention;
+        self
+    }
+
+    /// Draw the arcs and the radial edges of the sector
+    fn draw_outline<DB: DrawingBackend>(
+        &self,
+        center: BackendCoord,
+        angles: (f64, f64),
+        style: &ShapeStyle,
+        backend: &mut DB,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        backend.draw_arc(center, self.radius, angles, style)?;
+        if self.inner_radius > 0 {
+            backend.draw_arc(center, self.inner_radius, angles, style)?;
+        }
+        if (angles.1 - angles.0).abs() >= PI * 2.0 {
+            return Ok(());
+        }
+        let point = |radius: u32, angle: f64| {
+            (
+                center.0 + (f64::from(radius) * angle.cos()).round() as i32,
+                center.1 - (f64::from(radius) * angle.sin()).round() as i32,
+            )
+        };
+        for &angle in [angles.0, angles.1].iter() {
+            backend.draw_path(
+                vec![point(self.inner_radius, angle), point(self.radius, angle)],
+                style,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a, Coord> PointCollection<'a, Coord> for &'a Sector<Coord> {
+    type Borrow = &'a Coord;
+    type IntoIter = std::iter::Once<&'a Coord>;
+    fn point_iter(self) -> Self::IntoIter {
+        std::iter::once(&self.center)
+    }
+}
+
+impl<Coord, DB: DrawingBackend> Drawable<DB> for Sector<Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let center = match points.next() {
+            Some(center) => center,
+            None => return Ok(()),
+        };
+        let angles = (
+            self.convention.to_radians(self.angles.0),
+            self.convention.to_radians(self.angles.1),
+        );
+        if !self.style.filled {
+            return self.draw_outline(center, angles, &self.style, backend);
+        }
+        backend.fill_sector(
+            center,
+            (self.inner_radius, self.radius),
+            angles,
+            &self.style.color,
+        )?;
+        if let Some(outline) = self.style.outline() {
+            self.draw_outline(center, angles, &outline, backend)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_arc_and_sector_elements() {
+    use crate::prelude::*;
+
+    let da = crate::create_mocked_drawing_area(300, 300, |m| {
+        m.check_draw_arc(|c, w, center, r, angles| {
+            assert_eq!((c, w), (BLUE.to_rgba(), 2));
+            assert_eq!((center, r), ((150, 150), 50));
+            assert_eq!(angles, (0.0, PI));
+        });
+        // The compass angles go clockwise from the 12 o'clock position
+        m.check_fill_sector(|c, center, radii, (start, end)| {
+            assert_eq!((c, center, radii), (RED.to_rgba(), (150, 150), (20, 50)));
+            assert!((start - PI / 2.0).abs() < 1e-9);
+            assert!((end + PI).abs() < 1e-9);
+        });
+        m.drop_check(|b| {
+            assert_eq!(b.num_draw_arc_call, 1);
+            assert_eq!(b.num_fill_sector_call, 1);
+            assert_eq!(b.num_draw_path_call, 0);
+        });
+    });
+    da.draw(&Arc::new((150, 150), 50, 0.0, PI, BLUE.stroke_width(2)))
+        .expect("Drawing Failure");
+    da.draw(
+        &Sector::new((150, 150), 50, 0.0, 270.0, RED.filled())
+            .inner_radius(20)
+            .convention(AngleConvention::compass()),
+    )
+    .expect("Drawing Failure");
+
+    // The outlined sector has two arcs and two radial edges
+    let da = crate::create_mocked_drawing_area(300, 300, |m| {
+        m.check_draw_path(|_, _, path| assert_eq!(path, [(170, 150), (200, 150)]));
+        m.check_draw_path(|_, _, path| assert_eq!(path, [(150, 130), (150, 100)]));
+        m.drop_check(|b| {
+            assert_eq!(b.num_draw_arc_call, 2);
+            assert_eq!(b.num_draw_path_call, 2);
+            assert_eq!(b.num_fill_sector_call, 0);
+        });
+    });
+    da.draw(&Sector::new((150, 150), 50, 0.0, PI / 2.0, &BLUE).inner_radius(20))
+        .expect("Drawing Failure");
+}
@@ -0,0 +1,301 @@
+/*!
+  The arrow element, which is a line segment with a triangular head at its end
+*/
+
+use crate::drawing::backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+use crate::element::{Drawable, PointCollection};
+use crate::style::ShapeStyle;
+
+type Point = (f64, f64);
+
+/// The unit vector from one point to another, or `None` if they're the same point
+fn direction(from: Point, to: Point) -> Option<Point> {
+    let (dx, dy) = (to.0 - from.0, to.1 - from.1);
+    let length = dx.hypot(dy);
+    if length == 0.0 {
+        return None;
+    }
+    Some((dx / length, dy / length))
+}
+
+fn distance(a: Point, b: Point) -> f64 {
+    (b.0 - a.0).hypot(b.1 - a.1)
+}
+
+fn round((x, y): Point) -> BackendCoord {
+    (x.round() as i32, y.round() as i32)
+}
+
+/// The blossom of the quadratic curve, which is the point at `a` on the curve if `b` equals
+/// `a`, and the control point of the part of the curve between `a` and `b` otherwise
+fn blossom((p0, q, p1): (Point, Point, Point), a: f64, b: f64) -> Point {
+    let (w0, w1, w2) = ((1.0 - a) * (1.0 - b), (1.0 - a) * b + a * (1.0 - b), a * b);
+    (
+        w0 * p0.0 + w1 * q.0 + w2 * p1.0,
+        w0 * p0.1 + w1 * q.1 + w2 * p1.1,
+    )
+}
+
+/// An arrow pointing from one point to another, with the head size given in pixels. The body
+/// is either a straight line or a quadratic curve bent by a control point.
+pub struct Arrow<Coord> {
+    points: [Coord; 2],
+    head_size: u32,
+    head_width: Option<u32>,
+    double_headed: bool,
+    curve: Option<(i32, i32)>,
+    style: ShapeStyle,
+}
+
+impl<Coord> Arrow<Coord> {
+    /// Create a new arrow element
+    /// - `from`: The point the arrow starts at
+    /// - `to`: The point the head of the arrow is at
+    /// - `style`: The style of the arrow, the head is always filled with its color
+    pub fn new<S: Into<ShapeStyle>>(from: Coord, to: Coord, style: S) -> Self {
+        Self {
+            points: [from, to],
+            head_size: 8,
+            head_width: None,
+            double_headed: false,
+            curve: None,
+            style: style.into(),
+        }
+    }
+
+    /// Set the length of the head in pixels. The head never gets longer than the arrow.
+    pub fn head_size(mut self, size: u32) -> Self {
+        self.head_size = size;
+        self
+    }
+
+    /// Set the width of the head in pixels, which is the length of the head by default. The
+    /// width shrinks along with the head when the head is shortened to fit the arrow.
+    pub fn head_width(mut self, width: u32) -> Self {
+        self.head_width = Some(width);
+        self
+    }
+
+    /// Draw a head at the start of the arrow as well
+    pub fn double_headed(mut self, double_headed: bool) -> Self {
+        self.double_headed = double_headed;
+        self
+    }
+
+    /// Bend the body into a quadratic curve, whose control point is at the offset in pixels
+    /// from the middle of the arrow. The heads point along the curve at its ends.
+    pub fn curve(mut self, offset: (i32, i32)) -> Self {
+        self.curve = Some(offset);
+        self
+    }
+
+    /// Fill the head with the tip at `tip`, pointing at the direction
+    fn draw_head<DB: DrawingBackend>(
+        &self,
+        tip: Point,
+        (ux, uy): Point,
+        (length, half_width): (f64, f64),
+        backend: &mut DB,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let base = (tip.0 - ux * length, tip.1 - uy * length);
+        let corner = |side: f64| {
+            round((
+                base.0 - uy * half_width * side,
+                base.1 + ux * half_width * side,
+            ))
+        };
+        backend.fill_polygon(
+            vec![round(tip), corner(1.0), corner(-1.0)],
+            &self.style.color,
+        )
+    }
+}
+
+impl<'a, Coord> PointCollection<'a, Coord> for &'a Arrow<Coord> {
+    type Borrow = &'a Coord;
+    type IntoIter = &'a [Coord];
+    fn point_iter(self) -> &'a [Coord] {
+        &self.points
+    }
+}
+
+impl<Coord, DB: DrawingBackend> Drawable<DB> for Arrow<Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (from, to) = match (points.next(), points.next()) {
+            (Some(from), Some(to)) => (from, to),
+            _ => return Ok(()),
+        };
+        let (from, to) = (
+            (f64::from(from.0), f64::from(from.1)),
+            (f64::from(to.0), f64::from(to.1)),
+        );
+        let control = self.curve.map(|(dx, dy)| {
+            (
+                (from.0 + to.0) / 2.0 + f64::from(dx),
+                (from.1 + to.1) / 2.0 + f64::from(dy),
+            )
+        });
+
+        // The directions of the body at its ends, going out of the arrow
+        let end_dir = match control
+            .and_then(|control| direction(control, to))
+            .or_else(|| direction(from, to))
+        {
+            Some(dir) => dir,
+            None => return Ok(()),
+        };
+        let start_dir = control
+            .and_then(|control| direction(control, from))
+            .unwrap_or((-end_dir.0, -end_dir.1));
+        // The length of a curve is between its chord and its control polygon
+        let length = match control {
+            Some(control) => {
+                (distance(from, control) + distance(control, to) + distance(from, to)) / 2.0
+            }
+            None => distance(from, to),
+        };
+
+        let heads = if self.double_headed { 2.0 } else { 1.0 };
+        let head = f64::from(self.head_size).min(length / heads);
+        let half_width = if self.head_size > 0 {
+            f64::from(self.head_width.unwrap_or(self.head_size)) * head
+                / f64::from(self.head_size)
+                / 2.0
+        } else {
+            0.0
+        };
+        let start_trim = if self.double_headed { head } else { 0.0 };
+
+        match control {
+            Some(control) => {
+                // The part of the curve between the heads
+                let curve = (from, control, to);
+                let (t0, t1) = (start_trim / length, 1.0 - head / length);
+                if t1 > t0 {
+                    let (p0, q, p1) = (
+                        blossom(curve, t0, t0),
+                        blossom(curve, t0, t1),
+                        blossom(curve, t1, t1),
+                    );
+                    // The quadratic curve is drawn as the cubic curve of the same shape
+                    let lerp = |a: Point, b: Point| {
+                        (a.0 + (b.0 - a.0) * 2.0 / 3.0, a.1 + (b.1 - a.1) * 2.0 / 3.0)
+                    };
+                    let (c0, c1) = (lerp(p0, q), lerp(p1, q));
+                    backend.draw_bezier(
+                        round(p0),
+                        &[[round(c0), round(c1), round(p1)]],
+                        &self.style,
+                    )?;
+                }
+            }
+            None => {
+                let base = round((to.0 - end_dir.0 * head, to.1 - end_dir.1 * head));
+                let start = round((
+                    from.0 - start_dir.0 * start_trim,
+                    from.1 - start_dir.1 * start_trim,
+                ));
+                if base != start {
+                    backend.draw_line(start, base, &self.style)?;
+                }
+            }
+        }
+
+        if self.head_size > 0 {
+            self.draw_head(to, end_dir, (head, half_width), backend)?;
+            if self.double_headed {
+                self.draw_head(from, start_dir, (head, half_width), backend)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_arrow_element() {
+    use crate::prelude::*;
+
+    let da = crate::create_mocked_drawing_area(300, 300, |m| {
+        m.check_draw_line(|c, _, from, to| {
+            assert_eq!(c, RED.to_rgba());
+            assert_eq!(from, (100, 100));
+            assert_eq!(to, (190, 100));
+        });
+        m.check_fill_polygon(|c, path| {
+            assert_eq!(c, RED.to_rgba());
+            assert_eq!(path, [(200, 100), (190, 105), (190, 95)]);
+        });
+        m.drop_check(|b| {
+            assert_eq!(b.num_draw_line_call, 1);
+            assert_eq!(b.num_fill_polygon_call, 1);
+        });
+    });
+
+    da.draw(&Arrow::new((100, 100), (200, 100), &RED).head_size(10))
+        .expect("Drawing Failure");
+    da.draw(&Arrow::new((50, 50), (50, 50), &RED))
+        .expect("Drawing Failure");
+}
+
+#[cfg(test)]
+#[test]
+fn test_arrow_heads_and_curve() {
+    use crate::prelude::*;
+
+    let da = crate::create_mocked_drawing_area(300, 300, |m| {
+        m.check_draw_line(|_, _, from, to| assert_eq!((from, to), ((100, 110), (100, 190))));
+        m.check_fill_polygon(|_, path| assert_eq!(path, [(100, 200), (90, 190), (110, 190)]));
+        m.check_fill_polygon(|_, path| assert_eq!(path, [(100, 100), (110, 110), (90, 110)]));
+        m.drop_check(|b| {
+            assert_eq!(b.num_draw_line_call, 1);
+            assert_eq!(b.num_fill_polygon_call, 2);
+        });
+    });
+    da.draw(
+        &Arrow::new((100, 100), (100, 200), &RED)
+            .head_size(10)
+            .head_width(20)
+            .double_headed(true),
+    )
+    .expect("Drawing Failure");
+
+    // The curved arrow bends towards the control point, and the head points along the curve
+    let da = crate::create_mocked_drawing_area(300, 300, |m| {
+        m.check_draw_path(|_, _, path| {
+            assert_eq!(path.first(), Some(&(100, 100)));
+            assert!(path.iter().all(|&(_, y)| y <= 100));
+            assert!(path.iter().any(|&(_, y)| y < 75));
+        });
+        m.check_fill_polygon(|_, path| {
+            assert_eq!(path[0], (200, 100));
+            // The curve comes down to the tip, so the head points down and right
+            assert!(path[1].1 < 100 && path[2].1 < 100);
+        });
+        m.drop_check(|b| {
+            assert_eq!(b.num_draw_path_call, 1);
+            assert_eq!(b.num_fill_polygon_call, 1);
+        });
+    });
+    da.draw(&Arrow::new((100, 100), (200, 100), &RED).curve((0, -60)))
+        .expect("Drawing Failure");
+
+    // The arrow shorter than its heads is only the heads
+    let da = crate::create_mocked_drawing_area(300, 300, |m| {
+        m.drop_check(|b| {
+            assert_eq!(b.num_draw_line_call, 0);
+            assert_eq!(b.num_fill_polygon_call, 2);
+        });
+    });
+    da.draw(
+        &Arrow::new((100, 100), (104, 100), &RED)
+            .head_size(10)
+            .double_headed(true),
+    )
+    .expect("Drawing Failure");
+}
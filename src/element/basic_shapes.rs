@@ -1,6 +1,7 @@
 use super::{Drawable, PointCollection};
 use crate::drawing::backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
-use crate::style::{ShapeStyle, SizeDesc};
+use crate::drawing::rasterizer::{clip_path, clip_polygon};
+use crate::style::{Color, RGBAColor, ShapeStyle, SizeDesc};
 
 /// An element of a single pixel
 pub struct Pixel<Coord> {
@@ -97,6 +98,20 @@ impl<Coord, DB: DrawingBackend> Drawable<DB> for PathElement<Coord> {
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
         backend.draw_path(points, &self.style)
     }
+
+    fn draw_clipped<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+        clip: (BackendCoord, BackendCoord),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let points: Vec<_> = points.collect();
+        for piece in clip_path(&points, clip) {
+            backend.draw_path(piece, &self.style)?;
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -126,6 +141,7 @@ pub struct Rectangle<Coord> {
     points: [Coord; 2],
     style: ShapeStyle,
     margin: (u32, u32, u32, u32),
+    radii: [u32; 4],
 }
 
 impl<Coord> Rectangle<Coord> {
@@ -138,9 +154,24 @@ impl<Coord> Rectangle<Coord> {
             points,
             style: style.into(),
             margin: (0, 0, 0, 0),
+            radii: [0; 4],
         }
     }
 
+    /// Round all the corners of the rectangle
+    /// - `radius`: The radius of the corners in pixels, which is at most half of the shorter side
+    pub fn rounded(self, radius: u32) -> Self {
+        self.rounded_corners([radius; 4])
+    }
+
+    /// Round each corner of the rectangle by its own radius
+    /// - `radii`: The radii in pixels of the upper left, the upper right, the bottom right and
+    ///   the bottom left corner on the screen
+    pub fn rounded_corners(mut self, radii: [u32; 4]) -> Self {
+        self.radii = radii;
+        self
+    }
+
     /// Set the margin of the rectangle
     /// - `t`: The top margin
     /// - `b`: The bottom margin
@@ -160,21 +191,89 @@ impl<'a, Coord> PointCollection<'a, Coord> for &'a Rectangle<Coord> {
     }
 }
 
+impl<Coord> Rectangle<Coord> {
+    /// Get the upper left and the bottom right corners in the backend, after the margin
+    fn corners<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+    ) -> Option<(BackendCoord, BackendCoord)> {
+        let (a, b) = (points.next()?, points.next()?);
+        let (mut a, mut b) = ((a.0.min(b.0), a.1.min(b.1)), (a.0.max(b.0), a.1.max(b.1)));
+        a.1 += self.margin.0 as i32;
+        b.1 -= self.margin.1 as i32;
+        a.0 += self.margin.2 as i32;
+        b.0 -= self.margin.3 as i32;
+        Some((a, b))
+    }
+
+    /// Draw the rectangle between the corners, then its outline if it has one
+    fn draw_corners<DB: DrawingBackend>(
+        &self,
+        a: BackendCoord,
+        b: BackendCoord,
+        radii: [u32; 4],
+        backend: &mut DB,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let mut draw = |style: &ShapeStyle, fill: bool| {
+            if radii == [0; 4] {
+                backend.draw_rect(a, b, style, fill)
+            } else {
+                backend.draw_rounded_rect(a, b, radii, style, fill)
+            }
+        };
+        draw(&self.style, self.style.filled)?;
+        if let Some(outline) = self.style.outline() {
+            draw(&outline, false)?;
+        }
+        Ok(())
+    }
+
+    /// Draw the rectangle with the given radii of the corners instead of its own ones
+    pub(crate) fn draw_rounded<DB: DrawingBackend, I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        radii: [u32; 4],
+        backend: &mut DB,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        match self.corners(points) {
+            Some((a, b)) => self.draw_corners(a, b, radii, backend),
+            None => Ok(()),
+        }
+    }
+}
+
 impl<Coord, DB: DrawingBackend> Drawable<DB> for Rectangle<Coord> {
     fn draw<I: Iterator<Item = BackendCoord>>(
         &self,
-        mut points: I,
+        points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.draw_rounded(points, self.radii, backend)
+    }
+
+    fn draw_clipped<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
         backend: &mut DB,
         _: (u32, u32),
+        (ul, br): (BackendCoord, BackendCoord),
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
-        match (points.next(), points.next()) {
-            (Some(a), Some(b)) => {
-                let (mut a, mut b) = ((a.0.min(b.0), a.1.min(b.1)), (a.0.max(b.0), a.1.max(b.1)));
-                a.1 += self.margin.0 as i32;
-                b.1 -= self.margin.1 as i32;
-                a.0 += self.margin.2 as i32;
-                b.0 -= self.margin.3 as i32;
-                backend.draw_rect(a, b, &self.style, self.style.filled)
+        match self.corners(points) {
+            Some((a, b)) if a.0 <= br.0 && b.0 >= ul.0 && a.1 <= br.1 && b.1 >= ul.1 => {
+                // The corners on the edges which are cut off aren't rounded
+                let (left, top) = (a.0 < ul.0, a.1 < ul.1);
+                let (right, bottom) = (b.0 > br.0, b.1 > br.1);
+                let [r_ul, r_ur, r_br, r_bl] = self.radii;
+                let radii = [
+                    if left || top { 0 } else { r_ul },
+                    if right || top { 0 } else { r_ur },
+                    if right || bottom { 0 } else { r_br },
+                    if left || bottom { 0 } else { r_bl },
+                ];
+                let a = (a.0.max(ul.0), a.1.max(ul.1));
+                let b = (b.0.min(br.0), b.1.min(br.1));
+                self.draw_corners(a, b, radii, backend)
             }
             _ => Ok(()),
         }
@@ -220,6 +319,55 @@ fn test_rect_element() {
         da.draw(&Rectangle::new([(100, 101), (105, 107)], BLUE.filled()))
             .expect("Drawing Failure");
     }
+
+    {
+        // The outline is drawn over the fill in its own color and width
+        let da = crate::create_mocked_drawing_area(300, 300, |m| {
+            m.check_draw_rect(|c, s, f, u, d| {
+                if f {
+                    assert_eq!(c, WHITE.to_rgba());
+                } else {
+                    assert_eq!((c, s), (RED.to_rgba(), 2));
+                }
+                assert_eq!([u, d], [(100, 101), (105, 107)]);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 2);
+            });
+        });
+        let style = WHITE.filled().stroke_color(&RED).stroke_width(2);
+        da.draw(&Rectangle::new([(100, 101), (105, 107)], style))
+            .expect("Drawing Failure");
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_rounded_rect_element() {
+    use crate::prelude::*;
+    let da = crate::create_mocked_drawing_area(300, 300, |m| {
+        m.check_draw_rounded_rect(|c, _, f, u, d, radii| {
+            assert_eq!((c, f), (BLUE.to_rgba(), true));
+            assert_eq!([u, d], [(100, 100), (200, 150)]);
+            assert_eq!(radii, [10; 4]);
+        });
+        // The corners at the edges the rectangle is cut at aren't rounded
+        m.check_draw_rounded_rect(|_, _, _, u, d, radii| {
+            assert_eq!([u, d], [(200, 100), (300, 150)]);
+            assert_eq!(radii, [5, 0, 0, 7]);
+        });
+        m.drop_check(|b| {
+            assert_eq!(b.num_draw_rounded_rect_call, 2);
+            assert_eq!(b.num_draw_rect_call, 0);
+        });
+    });
+    da.draw(&Rectangle::new([(100, 100), (200, 150)], BLUE.filled()).rounded(10))
+        .expect("Drawing Failure");
+    da.draw_with_policy(
+        &Rectangle::new([(200, 100), (400, 150)], BLUE.filled()).rounded_corners([5, 6, 8, 7]),
+        OutOfRange::Clip,
+    )
+    .expect("Drawing Failure");
 }
 
 /// A circle element
@@ -261,7 +409,10 @@ impl<Coord, DB: DrawingBackend, Size: SizeDesc> Drawable<DB> for Circle<Coord, S
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
         if let Some((x, y)) = points.next() {
             let size = self.size.in_pixels(&ps).max(0) as u32;
-            return backend.draw_circle((x, y), size, &self.style, self.style.filled);
+            backend.draw_circle((x, y), size, &self.style, self.style.filled)?;
+            if let Some(outline) = self.style.outline() {
+                backend.draw_circle((x, y), size, &outline, false)?;
+            }
         }
         Ok(())
     }
@@ -320,8 +471,40 @@ impl<Coord, DB: DrawingBackend> Drawable<DB> for Polygon<Coord> {
         backend: &mut DB,
         _: (u32, u32),
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
-        backend.fill_polygon(points, &self.style.color)
+        draw_polygon(&points.collect::<Vec<_>>(), &self.style, backend)
+    }
+
+    fn draw_clipped<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+        clip: (BackendCoord, BackendCoord),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let points: Vec<_> = points.collect();
+        let points = clip_polygon(&points, clip);
+        if points.len() < 3 {
+            return Ok(());
+        }
+        draw_polygon(&points, &self.style, backend)
+    }
+}
+
+/// Fill the polygon with the color or the gradient of the style, then draw its outline if the
+/// style has one
+pub(crate) fn draw_polygon<DB: DrawingBackend>(
+    points: &[BackendCoord],
+    style: &ShapeStyle,
+    backend: &mut DB,
+) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+    backend.fill_polygon(points.iter().cloned(), style)?;
+    if let (Some(outline), Some(first)) = (style.outline(), points.first()) {
+        backend.draw_path(
+            points.iter().chain(std::iter::once(first)).cloned(),
+            &outline,
+        )?;
     }
+    Ok(())
 }
 
 #[cfg(test)]
@@ -345,4 +528,101 @@ fn test_polygon_element() {
 
     da.draw(&Polygon::new(points.clone(), &BLUE))
         .expect("Drawing Failure");
+
+    let da = crate::create_mocked_drawing_area(800, 800, |m| {
+        m.check_fill_polygon(|c, _| assert_eq!(c, WHITE.to_rgba()));
+        m.check_draw_path(|c, s, p| {
+            assert_eq!((c, s), (BLUE.to_rgba(), 3));
+            // The outline is closed
+            assert_eq!(p.len(), 4);
+            assert_eq!(p.first(), p.last());
+        });
+        m.drop_check(|b| {
+            assert_eq!(b.num_fill_polygon_call, 1);
+            assert_eq!(b.num_draw_path_call, 1);
+        });
+    });
+    let style = WHITE.filled().stroke_color(&BLUE).stroke_width(3);
+    da.draw(&Polygon::new(vec![(10, 10), (50, 10), (30, 40)], style))
+        .expect("Drawing Failure");
+}
+
+/// An element of a polygon filled with a vertical linear gradient. The stops of the gradient
+/// are guest coordinates, so the gradient is defined in the coordinate system rather than by
+/// the size of the polygon on the drawing area. Only the Y part of the stops is used.
+pub struct GradientPolygon<Coord> {
+    /// The vertices of the polygon followed by the top and the bottom stops
+    points: Vec<Coord>,
+    colors: (RGBAColor, RGBAColor),
+}
+
+impl<Coord> GradientPolygon<Coord> {
+    /// Create a new gradient filled polygon
+    /// - `points`: The iterator of the points
+    /// - `top`: The coordinate where the gradient starts and the color at it
+    /// - `bottom`: The coordinate where the gradient ends and the color at it
+    /// - returns the created element
+    pub fn new<P: Into<Vec<Coord>>, T: Color, B: Color>(
+        points: P,
+        top: (Coord, &T),
+        bottom: (Coord, &B),
+    ) -> Self {
+        let mut points = points.into();
+        points.push(top.0);
+        points.push(bottom.0);
+        Self {
+            points,
+            colors: (top.1.to_rgba(), bottom.1.to_rgba()),
+        }
+    }
+}
+
+impl<'a, Coord> PointCollection<'a, Coord> for &'a GradientPolygon<Coord> {
+    type Borrow = &'a Coord;
+    type IntoIter = &'a [Coord];
+    fn point_iter(self) -> &'a [Coord] {
+        &self.points
+    }
+}
+
+impl<Coord, DB: DrawingBackend> Drawable<DB> for GradientPolygon<Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let mut points: Vec<_> = points.collect();
+        if let (Some(bottom), Some(top)) = (points.pop(), points.pop()) {
+            backend.fill_polygon_gradient(
+                points,
+                (top.1, &self.colors.0),
+                (bottom.1, &self.colors.1),
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_gradient_polygon_element() {
+    use crate::prelude::*;
+    let da = crate::create_mocked_drawing_area(100, 100, |m| {
+        m.check_draw_line(|c, _, from, to| {
+            assert_eq!([from.0, to.0, from.1], [0, 50, to.1]);
+            let t = f64::from(from.1 - 20) / 80.0;
+            let (red, blue) = ((255.0 * (1.0 - t)).round(), (255.0 * t).round());
+            assert_eq!(c.rgb(), (red as u8, 0, blue as u8));
+        });
+        m.drop_check(|b| assert_eq!(b.num_draw_line_call, 70));
+    });
+
+    // The gradient spans from the row 20 to the row 100, beyond the bottom of the polygon
+    da.draw(&GradientPolygon::new(
+        vec![(0, 20), (50, 20), (50, 90), (0, 90)],
+        ((0, 20), &RED),
+        ((0, 100), &BLUE),
+    ))
+    .expect("Drawing Failure");
 }
@@ -0,0 +1,173 @@
+/*!
+  The cubic Bézier curve elements, for the smooth connectors and the custom shapes
+*/
+
+use super::basic_shapes::draw_polygon;
+use crate::drawing::backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+use crate::drawing::rasterizer::flatten_bezier;
+use crate::element::{Drawable, PointCollection};
+use crate::style::ShapeStyle;
+
+/// Draw the path of the curves, which is the start point followed by the two control points
+/// and the end point of each curve. A filled path is closed and filled as a polygon.
+fn draw_bezier_path<DB: DrawingBackend>(
+    points: &[BackendCoord],
+    style: &ShapeStyle,
+    backend: &mut DB,
+) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+    let start = match points.first() {
+        Some(start) => *start,
+        None => return Ok(()),
+    };
+    let segments: Vec<_> = points[1..]
+        .chunks_exact(3)
+        .map(|curve| [curve[0], curve[1], curve[2]])
+        .collect();
+    if style.filled {
+        draw_polygon(&flatten_bezier(start, &segments, 0.25), style, backend)
+    } else {
+        backend.draw_bezier(start, &segments, style)
+    }
+}
+
+/// A cubic Bézier curve from one point to another, shaped by two control points
+pub struct CubicBezier<Coord> {
+    points: [Coord; 4],
+    style: ShapeStyle,
+}
+
+impl<Coord> CubicBezier<Coord> {
+    /// Create a new cubic Bézier curve element
+    /// - `points`: The start point, the two control points and the end point of the curve
+    /// - `style`: The style of the curve, which is closed and filled if the style is filled
+    pub fn new<S: Into<ShapeStyle>>(points: [Coord; 4], style: S) -> Self {
+        Self {
+            points,
+            style: style.into(),
+        }
+    }
+}
+
+impl<'a, Coord> PointCollection<'a, Coord> for &'a CubicBezier<Coord> {
+    type Borrow = &'a Coord;
+    type IntoIter = &'a [Coord];
+    fn point_iter(self) -> &'a [Coord] {
+        &self.points
+    }
+}
+
+impl<Coord, DB: DrawingBackend> Drawable<DB> for CubicBezier<Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        draw_bezier_path(&points.collect::<Vec<_>>(), &self.style, backend)
+    }
+}
+
+/// A path of cubic Bézier curves, each of which starts where the previous one ends
+pub struct BezierPath<Coord> {
+    points: Vec<Coord>,
+    style: ShapeStyle,
+}
+
+impl<Coord> BezierPath<Coord> {
+    /// Create a new Bézier path element
+    /// - `points`: The point the path starts at, followed by the two control points and the end
+    ///   point of each curve. The points after the last complete curve are ignored.
+    /// - `style`: The style of the path, which is closed and filled if the style is filled
+    pub fn new<P: Into<Vec<Coord>>, S: Into<ShapeStyle>>(points: P, style: S) -> Self {
+        Self {
+            points: points.into(),
+            style: style.into(),
+        }
+    }
+}
+
+impl<'a, Coord> PointCollection<'a, Coord> for &'a BezierPath<Coord> {
+    type Borrow = &'a Coord;
+    type IntoIter = &'a [Coord];
+    fn point_iter(self) -> &'a [Coord] {
+        &self.points
+    }
+}
+
+impl<Coord, DB: DrawingBackend> Drawable<DB> for BezierPath<Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        draw_bezier_path(&points.collect::<Vec<_>>(), &self.style, backend)
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_bezier_elements() {
+    use crate::prelude::*;
+
+    let da = crate::create_mocked_drawing_area(300, 300, |m| {
+        // The points of the flattened curve are on the curve
+        m.check_draw_path(|c, _, path| {
+            assert_eq!(c, BLUE.to_rgba());
+            assert_eq!(path.first(), Some(&(0, 0)));
+            assert_eq!(path.last(), Some(&(300, 0)));
+            assert!(path.len() > 8);
+            let curve: Vec<_> = (0..=1000)
+                .map(|i| {
+                    let t = f64::from(i) / 1000.0;
+                    let s = 1.0 - t;
+                    (300.0 * (3.0 * s * t * t + t * t * t), 900.0 * s * t)
+                })
+                .collect();
+            for &(x, y) in path.iter() {
+                let distance = curve
+                    .iter()
+                    .map(|&(cx, cy)| (cx - f64::from(x)).hypot(cy - f64::from(y)))
+                    .fold(f64::MAX, f64::min);
+                assert!(distance < 1.0);
+            }
+        });
+        // The curve whose control points are on the chord is a line
+        m.check_draw_path(|_, _, path| assert_eq!(path, [(0, 0), (90, 90)]));
+        m.drop_check(|b| assert_eq!(b.num_draw_path_call, 2));
+    });
+    da.draw(&CubicBezier::new(
+        [(0, 0), (0, 300), (300, 300), (300, 0)],
+        &BLUE,
+    ))
+    .expect("Drawing Failure");
+    da.draw(&BezierPath::new(
+        vec![(0, 0), (30, 30), (60, 60), (90, 90), (100, 100)],
+        &BLUE,
+    ))
+    .expect("Drawing Failure");
+
+    // The filled path is filled as a polygon
+    let da = crate::create_mocked_drawing_area(300, 300, |m| {
+        m.check_fill_polygon(|c, path| {
+            assert_eq!(c, RED.to_rgba());
+            assert_eq!(path.first(), Some(&(0, 100)));
+            assert!(path.contains(&(100, 100)));
+            assert_eq!(path.last(), Some(&(0, 100)));
+        });
+        m.drop_check(|b| assert_eq!(b.num_fill_polygon_call, 1));
+    });
+    da.draw(&BezierPath::new(
+        vec![
+            (0, 100),
+            (0, 0),
+            (100, 0),
+            (100, 100),
+            (100, 150),
+            (0, 150),
+            (0, 100),
+        ],
+        RED.filled(),
+    ))
+    .expect("Drawing Failure");
+}
@@ -0,0 +1,257 @@
+/*!
+  The boxed text element, which is a text on a background box with an optional border and a
+  tail pointing at the annotated point, for the callout labels over a busy plot
+*/
+
+use crate::drawing::backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+use crate::element::{Drawable, MultiLineText, PointCollection};
+use crate::style::{Pos, ShapeStyle, TextStyle};
+
+/// The number of segments of each rounded corner of the box
+const CORNER_SEGMENTS: u32 = 6;
+
+/// The half of the width of the tail where it joins the box, in pixels
+const TAIL_HALF_WIDTH: i32 = 5;
+
+/// A text on a background box, anchored at a point. The box is as large as the text plus the
+/// padding, and it's placed at an offset from the anchor point. The text can have several
+/// lines, and the `pos` of the text style selects the point of the box placed at the offset,
+/// for example `Pos::new(HPos::Center, VPos::Bottom)` puts the box above the anchor point.
+pub struct BoxedText<'a, Coord> {
+    anchor: Coord,
+    text: MultiLineText<'a, BackendCoord, String>,
+    pos: Pos,
+    background: ShapeStyle,
+    padding: u32,
+    offset: (i32, i32),
+    corner_radius: u32,
+    border: Option<ShapeStyle>,
+    tail: bool,
+}
+
+impl<'a, Coord> BoxedText<'a, Coord> {
+    /// Create a new boxed text element
+    /// - `text`: The text, which is split into lines at the line breaks
+    /// - `anchor`: The point the text is anchored at
+    /// - `style`: The style of the text
+    /// - `background`: The style of the box, which is filled with its color
+    /// - `padding`: The distance in pixels between the text and the edges of the box
+    pub fn new<T: Into<String>, S: Into<TextStyle<'a>>, B: Into<ShapeStyle>>(
+        text: T,
+        anchor: Coord,
+        style: S,
+        background: B,
+        padding: u32,
+    ) -> Self {
+        let style = style.into();
+        let pos = style.pos;
+        Self {
+            anchor,
+            text: MultiLineText::from_string(text.into(), (0, 0), style.pos(Pos::default()), 0),
+            pos,
+            background: background.into(),
+            padding,
+            offset: (0, 0),
+            corner_radius: 0,
+            border: None,
+            tail: false,
+        }
+    }
+
+    /// Set the offset in pixels of the box from the anchor point
+    pub fn offset(mut self, offset: (i32, i32)) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Set the radius in pixels of the rounded corners of the box
+    pub fn corner_radius(mut self, radius: u32) -> Self {
+        self.corner_radius = radius;
+        self
+    }
+
+    /// Draw a border around the box, and the tail if there's one
+    pub fn border<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.border = Some(style.into());
+        self
+    }
+
+    /// Draw a tail from the box to the anchor point, which makes sense if the box is offset from
+    /// the point
+    pub fn tail(mut self, tail: bool) -> Self {
+        self.tail = tail;
+        self
+    }
+
+    /// The outline of the box with the rounded corners
+    fn outline(&self, (ul, br): (BackendCoord, BackendCoord)) -> Vec<BackendCoord> {
+        let max_radius = (br.0 - ul.0).min(br.1 - ul.1) / 2;
+        let radius = (self.corner_radius as i32).min(max_radius);
+        if radius == 0 {
+            return vec![ul, (br.0, ul.1), br, (ul.0, br.1)];
+        }
+        // The centers of the corners clockwise from the upper right one, with the angle the arc
+        // of each corner starts at
+        let corners = [
+            ((br.0 - radius, ul.1 + radius), -90.0),
+            ((br.0 - radius, br.1 - radius), 0.0),
+            ((ul.0 + radius, br.1 - radius), 90.0),
+            ((ul.0 + radius, ul.1 + radius), 180.0),
+        ];
+        let mut points = vec![];
+        for &((cx, cy), start) in corners.iter() {
+            for idx in 0..=CORNER_SEGMENTS {
+                let angle =
+                    (start + 90.0 * f64::from(idx) / f64::from(CORNER_SEGMENTS)).to_radians();
+                points.push((
+                    cx + (f64::from(radius) * angle.cos()).round() as i32,
+                    cy + (f64::from(radius) * angle.sin()).round() as i32,
+                ));
+            }
+        }
+        points.dedup();
+        points
+    }
+
+    /// The two points where the tail pointing at the anchor joins the box, or `None` if the
+    /// anchor is inside of the box
+    fn tail_base(
+        &self,
+        anchor: BackendCoord,
+        (ul, br): (BackendCoord, BackendCoord),
+    ) -> Option<(BackendCoord, BackendCoord)> {
+        let inset = self.corner_radius as i32 + TAIL_HALF_WIDTH;
+        let along = |value: i32, from: i32, to: i32| {
+            if to - from <= 2 * inset {
+                (from + to) / 2
+            } else {
+                value.max(from + inset).min(to - inset)
+            }
+        };
+        let half = TAIL_HALF_WIDTH;
+        if anchor.1 > br.1 || anchor.1 < ul.1 {
+            let x = along(anchor.0, ul.0, br.0);
+            let y = if anchor.1 > br.1 { br.1 } else { ul.1 };
+            Some(((x - half, y), (x + half, y)))
+        } else if anchor.0 > br.0 || anchor.0 < ul.0 {
+            let y = along(anchor.1, ul.1, br.1);
+            let x = if anchor.0 > br.0 { br.0 } else { ul.0 };
+            Some(((x, y - half), (x, y + half)))
+        } else {
+            None
+        }
+    }
+}
+
+impl<'b, 'a, Coord: 'a> PointCollection<'a, Coord> for &'a BoxedText<'b, Coord> {
+    type Borrow = &'a Coord;
+    type IntoIter = std::iter::Once<&'a Coord>;
+    fn point_iter(self) -> Self::IntoIter {
+        std::iter::once(&self.anchor)
+    }
+}
+
+impl<'a, Coord: 'a, DB: DrawingBackend> Drawable<DB> for BoxedText<'a, Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        parent_dim: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let anchor = match points.next() {
+            Some(anchor) => anchor,
+            None => return Ok(()),
+        };
+        let (w, h) = self
+            .text
+            .estimate_dimension()
+            .map_err(DrawingErrorKind::FontError)?;
+        let padding = self.padding as i32;
+        let size = (w + padding * 2, h + padding * 2);
+        let (dx, dy) = self.pos.upper_left_offset((size.0 as u32, size.1 as u32));
+        let ul = (anchor.0 + self.offset.0 + dx, anchor.1 + self.offset.1 + dy);
+        let rect = (ul, (ul.0 + size.0, ul.1 + size.1));
+
+        let outline = self.outline(rect);
+        let tail = if self.tail {
+            self.tail_base(anchor, rect)
+        } else {
+            None
+        };
+
+        backend.fill_polygon(outline.iter().cloned(), &self.background.color)?;
+        if let Some(border) = &self.border {
+            let closed = outline.iter().chain(outline.first()).cloned();
+            backend.draw_path(closed, border)?;
+        }
+        if let Some((a, b)) = tail {
+            // The tail covers the border where it joins the box
+            backend.fill_polygon(vec![a, anchor, b], &self.background.color)?;
+            if let Some(border) = &self.border {
+                backend.draw_path(vec![a, anchor, b], border)?;
+            }
+        }
+
+        self.text.draw(
+            std::iter::once((ul.0 + padding, ul.1 + padding)),
+            backend,
+            parent_dim,
+        )
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_boxed_text() {
+    use crate::prelude::*;
+    let font = ("sans-serif", 20).into_font();
+    let (w, h) = font.box_size("Peak").unwrap();
+    let (w, h) = (w as i32, h as i32);
+
+    let da = crate::create_mocked_drawing_area(300, 300, move |m| {
+        // The box, then the tail
+        m.check_fill_polygon(move |c, path| {
+            assert_eq!(c, WHITE.to_rgba());
+            assert_eq!(
+                path,
+                [(120, 80), (130 + w, 80), (130 + w, 90 + h), (120, 90 + h)]
+            );
+        });
+        m.check_fill_polygon(move |_, path| {
+            assert_eq!(path[1], (100, 100));
+            assert_eq!((path[0].0, path[2].0), (120, 120));
+        });
+        m.check_draw_text(|_, _, _, pos, text| {
+            assert_eq!(text, "Peak");
+            assert_eq!(pos, (125, 85));
+        });
+        m.drop_check(|b| {
+            assert_eq!(b.num_fill_polygon_call, 2);
+            assert_eq!(b.num_draw_path_call, 2);
+            assert_eq!(b.num_draw_text_call, 1);
+        });
+    });
+    da.draw(
+        &BoxedText::new("Peak", (100, 100), font.clone(), WHITE.filled(), 5)
+            .offset((20, -20))
+            .border(&BLACK)
+            .tail(true),
+    )
+    .expect("Drawing Failure");
+
+    // The rounded box is anchored by the bottom center of it
+    let da = crate::create_mocked_drawing_area(300, 300, move |m| {
+        m.check_fill_polygon(move |_, path| {
+            let (min_y, max_y) = path
+                .iter()
+                .fold((i32::MAX, i32::MIN), |(a, b), p| (a.min(p.1), b.max(p.1)));
+            assert_eq!(max_y, 100);
+            assert_eq!(min_y, 100 - h - 8);
+            assert!(path.len() > 4);
+        });
+        m.drop_check(|b| assert_eq!(b.num_fill_polygon_call, 1));
+    });
+    let style = font.color(&BLACK).pos(Pos::new(HPos::Center, VPos::Bottom));
+    da.draw(&BoxedText::new("Peak", (100, 100), style, &YELLOW, 4).corner_radius(4))
+        .expect("Drawing Failure");
+}
@@ -0,0 +1,262 @@
+/*!
+  The boxplot element, which shows the quartiles of a data set
+*/
+
+use std::marker::PhantomData;
+
+use crate::data::Quartiles;
+use crate::drawing::backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+use crate::element::{Drawable, PointCollection};
+use crate::style::{ShapeStyle, BLACK};
+
+/// Describes the orientation of a boxplot
+pub trait BoxplotOrient<K> {
+    type XType;
+    type YType;
+
+    fn make_coord(key: K, val: f64) -> (Self::XType, Self::YType);
+    /// Move the coordinate perpendicular to the value axis
+    fn with_offset(coord: BackendCoord, offset: i32) -> BackendCoord;
+    /// Get the distance between two coordinates perpendicular to the value axis
+    fn distance(a: BackendCoord, b: BackendCoord) -> i32;
+}
+
+pub struct BoxplotOrientV<K>(PhantomData<K>);
+
+pub struct BoxplotOrientH<K>(PhantomData<K>);
+
+impl<K> BoxplotOrient<K> for BoxplotOrientV<K> {
+    type XType = K;
+    type YType = f64;
+
+    fn make_coord(key: K, val: f64) -> (K, f64) {
+        (key, val)
+    }
+
+    fn with_offset(coord: BackendCoord, offset: i32) -> BackendCoord {
+        (coord.0 + offset, coord.1)
+    }
+
+    fn distance(a: BackendCoord, b: BackendCoord) -> i32 {
+        (a.0 - b.0).abs()
+    }
+}
+
+impl<K> BoxplotOrient<K> for BoxplotOrientH<K> {
+    type XType = f64;
+    type YType = K;
+
+    fn make_coord(key: K, val: f64) -> (f64, K) {
+        (val, key)
+    }
+
+    fn with_offset(coord: BackendCoord, offset: i32) -> BackendCoord {
+        (coord.0, coord.1 + offset)
+    }
+
+    fn distance(a: BackendCoord, b: BackendCoord) -> i32 {
+        (a.1 - b.1).abs()
+    }
+}
+
+/// The boxplot element, which draws the box between the lower and upper quartile, the median
+/// line, the whiskers to the minimum and maximum and the outliers.
+pub struct Boxplot<K, O: BoxplotOrient<K>> {
+    key: K,
+    values: [f64; 5],
+    outliers: Vec<f64>,
+    width: u32,
+    slot: Option<(K, K, f64)>,
+    box_style: ShapeStyle,
+    whisker_style: ShapeStyle,
+    outlier_style: ShapeStyle,
+    outlier_size: u32,
+    _p: PhantomData<O>,
+}
+
+impl<K, O: BoxplotOrient<K>> Boxplot<K, O> {
+    fn new(key: K, quartiles: &Quartiles) -> Self {
+        Self {
+            key,
+            values: quartiles.values(),
+            outliers: quartiles.outliers().to_vec(),
+            width: 10,
+            slot: None,
+            box_style: (&BLACK).into(),
+            whisker_style: (&BLACK).into(),
+            outlier_style: (&BLACK).into(),
+            outlier_size: 2,
+            _p: PhantomData,
+        }
+    }
+
+    /// Set the width of the box in pixels
+    pub fn width(mut self, width: u32) -> Self {
+        self.width = width;
+        self.slot = None;
+        self
+    }
+
+    /// Set the width of the box as a fraction of the slot of its key. The slot width is the
+    /// larger distance from the key to `prev_key` or `next_key`, since one of them may be
+    /// outside of the plotting area.
+    pub fn width_fraction(mut self, prev_key: K, next_key: K, fraction: f64) -> Self {
+        self.slot = Some((prev_key, next_key, fraction));
+        self
+    }
+
+    /// Set the style of the box
+    pub fn box_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.box_style = style.into();
+        self
+    }
+
+    /// Set the style of the whiskers and the median line
+    pub fn whisker_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.whisker_style = style.into();
+        self
+    }
+
+    /// Set the style of the outliers
+    pub fn outlier_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.outlier_style = style.into();
+        self
+    }
+
+    /// Set the radius of the outlier circles in pixels
+    pub fn outlier_size(mut self, size: u32) -> Self {
+        self.outlier_size = size;
+        self
+    }
+}
+
+impl<K> Boxplot<K, BoxplotOrientV<K>> {
+    /// Create a new vertical boxplot element
+    /// - `key`: The key on the X axis
+    /// - `quartiles`: The quartiles to draw on the Y axis
+    pub fn new_vertical(key: K, quartiles: &Quartiles) -> Self {
+        Self::new(key, quartiles)
+    }
+}
+
+impl<K> Boxplot<K, BoxplotOrientH<K>> {
+    /// Create a new horizontal boxplot element
+    /// - `key`: The key on the Y axis
+    /// - `quartiles`: The quartiles to draw on the X axis
+    pub fn new_horizontal(key: K, quartiles: &Quartiles) -> Self {
+        Self::new(key, quartiles)
+    }
+}
+
+impl<'a, K: 'a + Clone, O: BoxplotOrient<K>> PointCollection<'a, (O::XType, O::YType)>
+    for &'a Boxplot<K, O>
+{
+    type Borrow = (O::XType, O::YType);
+    type IntoIter = Vec<Self::Borrow>;
+    fn point_iter(self) -> Self::IntoIter {
+        let mut points: Vec<_> = self
+            .values
+            .iter()
+            .map(|v| O::make_coord(self.key.clone(), *v))
+            .collect();
+        if let Some((prev_key, next_key, _)) = &self.slot {
+            points.push(O::make_coord(prev_key.clone(), self.values[2]));
+            points.push(O::make_coord(next_key.clone(), self.values[2]));
+        }
+        points.extend(
+            self.outliers
+                .iter()
+                .map(|v| O::make_coord(self.key.clone(), *v)),
+        );
+        points
+    }
+}
+
+impl<K, O: BoxplotOrient<K>, DB: DrawingBackend> Drawable<DB> for Boxplot<K, O> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let points: Vec<_> = points.collect();
+        if points.len() < 5 {
+            return Ok(());
+        }
+        let (outliers, half) = match self.slot {
+            Some((_, _, fraction)) if points.len() > 6 => {
+                let slot = O::distance(points[2], points[5]).max(O::distance(points[2], points[6]));
+                (
+                    &points[7..],
+                    (f64::from(slot) * fraction / 2.0).round() as i32,
+                )
+            }
+            _ => (&points[5..], self.width as i32 / 2),
+        };
+
+        backend.draw_line(points[0], points[1], &self.whisker_style)?;
+        backend.draw_line(points[3], points[4], &self.whisker_style)?;
+        for p in [points[0], points[4]].iter() {
+            let (from, to) = (O::with_offset(*p, -half / 2), O::with_offset(*p, half / 2));
+            backend.draw_line(from, to, &self.whisker_style)?;
+        }
+
+        let (a, b) = (
+            O::with_offset(points[1], -half),
+            O::with_offset(points[3], half),
+        );
+        backend.draw_rect(
+            (a.0.min(b.0), a.1.min(b.1)),
+            (a.0.max(b.0), a.1.max(b.1)),
+            &self.box_style,
+            self.box_style.filled,
+        )?;
+
+        let (from, to) = (
+            O::with_offset(points[2], -half),
+            O::with_offset(points[2], half),
+        );
+        backend.draw_line(from, to, &self.whisker_style)?;
+
+        for p in outliers {
+            backend.draw_circle(
+                *p,
+                self.outlier_size,
+                &self.outlier_style,
+                self.outlier_style.filled,
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_boxplot_element() {
+    use crate::prelude::*;
+
+    let da = crate::create_mocked_drawing_area(300, 300, |m| {
+        m.check_draw_rect(|_, _, _, u, d| {
+            assert_eq!([u, d], [(95, 170), (105, 230)]);
+        });
+        m.check_draw_circle(|_, _, _, c, r| {
+            assert_eq!(c, (100, 10));
+            assert_eq!(r, 2);
+        });
+        m.drop_check(|b| {
+            assert_eq!(b.num_draw_line_call, 5);
+            assert_eq!(b.num_draw_rect_call, 1);
+            assert_eq!(b.num_draw_circle_call, 1);
+        });
+    });
+    let chart = ChartBuilder::on(&da)
+        .build_ranged(0.0..300.0, 0.0..300.0)
+        .expect("Build chart error");
+    let quartiles =
+        Quartiles::from_values([50.0, 70.0, 100.0, 130.0, 200.0]).with_outliers(vec![290.0]);
+    chart
+        .plotting_area()
+        .draw(&Boxplot::new_vertical(100.0, &quartiles).width(10))
+        .expect("Drawing Failure");
+}
@@ -61,7 +61,6 @@ impl<X, Y: PartialOrd, DB: DrawingBackend> Drawable<DB> for CandleStick<X, Y> {
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
         let mut points: Vec<_> = points.take(4).collect();
         if points.len() == 4 {
-            let fill = false;
             if points[0].1 > points[3].1 {
                 points.swap(0, 3);
             }
@@ -76,8 +75,54 @@ impl<X, Y: PartialOrd, DB: DrawingBackend> Drawable<DB> for CandleStick<X, Y> {
             points[0].0 -= l;
             points[3].0 += r;
 
-            backend.draw_rect(points[0], points[3], &self.style.color, fill)?;
+            backend.draw_rect(points[0], points[3], &self.style, self.style.filled)?;
         }
         Ok(())
     }
 }
+
+#[cfg(test)]
+#[test]
+fn test_candlestick_element() {
+    use crate::prelude::*;
+
+    let da = crate::create_mocked_drawing_area(300, 300, |m| {
+        m.check_draw_rect(|c, _, f, u, d| {
+            assert_eq!(c, GREEN.to_rgba());
+            assert!(f);
+            assert_eq!([u, d], [(93, 100), (107, 200)]);
+        });
+        m.check_draw_rect(|c, _, f, u, d| {
+            assert_eq!(c, RED.to_rgba());
+            assert!(!f);
+            assert_eq!([u, d], [(195, 150), (205, 150)]);
+        });
+        m.drop_check(|b| {
+            assert_eq!(b.num_draw_line_call, 4);
+            assert_eq!(b.num_draw_rect_call, 2);
+        });
+    });
+
+    da.draw(&CandleStick::new(
+        100,
+        100,
+        50,
+        250,
+        200,
+        GREEN.filled(),
+        &RED,
+        14,
+    ))
+    .expect("Drawing Failure");
+    da.draw(&CandleStick::new(
+        200,
+        150,
+        100,
+        200,
+        150,
+        GREEN.filled(),
+        &RED,
+        10,
+    ))
+    .expect("Drawing Failure");
+}
@@ -35,6 +35,15 @@ where
     }
 }
 
+impl<Coord, DB: DrawingBackend> EmptyElement<Coord, DB> {
+    /// Turn the empty element into a composed element of any number of components, which keeps
+    /// them in a vector rather than nesting their types. Each component added with `+` or
+    /// `push` is drawn after the ones before it.
+    pub fn into_multi<'a>(self) -> MultiComposed<'a, Coord, DB> {
+        MultiComposed::at(self.coord)
+    }
+}
+
 impl<'a, Coord, DB: DrawingBackend> PointCollection<'a, Coord> for &'a EmptyElement<Coord, DB> {
     type Borrow = &'a Coord;
     type IntoIter = Once<&'a Coord>;
@@ -198,3 +207,108 @@ where
         }
     }
 }
+
+/// The composed element with any number of components, which are dynamically dispatched and
+/// drawn in the order they are added. Unlike nesting `ComposedElement`, its type doesn't change
+/// with the components, so it's easy to name in the item type of a series.
+pub struct MultiComposed<'a, Coord, DB: DrawingBackend> {
+    offset: Coord,
+    components: Vec<DynElement<'a, DB, BackendCoord>>,
+}
+
+impl<'a, Coord, DB: DrawingBackend> MultiComposed<'a, Coord, DB> {
+    /// Create an empty composed element anchored at the coordinate
+    pub fn at(coord: Coord) -> Self {
+        Self {
+            offset: coord,
+            components: vec![],
+        }
+    }
+
+    /// Add a component, whose points are relative to the anchor in pixels
+    pub fn push<E: IntoDynElement<'a, DB, BackendCoord>>(mut self, component: E) -> Self {
+        self.components.push(component.into_dyn());
+        self
+    }
+}
+
+impl<'a, Coord, DB: DrawingBackend, E> Add<E> for MultiComposed<'a, Coord, DB>
+where
+    E: IntoDynElement<'a, DB, BackendCoord>,
+{
+    type Output = Self;
+    fn add(self, component: E) -> Self {
+        self.push(component)
+    }
+}
+
+impl<'b, 'a, Coord, DB: DrawingBackend> PointCollection<'b, Coord>
+    for &'b MultiComposed<'a, Coord, DB>
+{
+    type Borrow = &'b Coord;
+    type IntoIter = Once<&'b Coord>;
+    fn point_iter(self) -> Self::IntoIter {
+        once(&self.offset)
+    }
+}
+
+impl<'a, Coord, DB: DrawingBackend> Drawable<DB> for MultiComposed<'a, Coord, DB> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut pos: I,
+        backend: &mut DB,
+        ps: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if let Some((x0, y0)) = pos.next() {
+            for component in self.components.iter() {
+                component.draw(
+                    component.point_iter().iter().map(|p| (p.0 + x0, p.1 + y0)),
+                    backend,
+                    ps,
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_multi_composed_element() {
+    use crate::prelude::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    let order = Rc::new(RefCell::new(vec![]));
+    let (rect_order, circle_order, text_order) = (order.clone(), order.clone(), order.clone());
+    let da = crate::create_mocked_drawing_area(300, 300, move |m| {
+        m.check_draw_rect(move |_, _, _, u, d| {
+            assert_eq!([u, d], [(95, 90), (105, 110)]);
+            rect_order.borrow_mut().push("rect");
+        });
+        m.check_draw_circle(move |_, _, _, c, _| {
+            assert_eq!(c, (100, 100));
+            circle_order.borrow_mut().push("circle");
+        });
+        m.check_draw_text(move |_, _, _, pos, text| {
+            assert_eq!((pos, text), ((110, 80), "label"));
+            text_order.borrow_mut().push("text");
+        });
+        m.drop_check(|b| assert_eq!(b.draw_count, 4));
+    });
+
+    let element = EmptyElement::at((100, 100)).into_multi()
+        + Rectangle::new([(-5, -10), (5, 10)], RED.filled())
+        + Circle::new((0, 0), 3, &BLUE);
+    let element = element
+        .push(Text::new(
+            "label",
+            (10, -20),
+            ("sans-serif", 12).into_font(),
+        ))
+        .push(Circle::new((0, 0), 5, &BLUE));
+    da.draw(&element).expect("Drawing Failure");
+    drop(da);
+
+    assert_eq!(*RefCell::borrow(&order), ["rect", "circle", "text", "circle"]);
+}
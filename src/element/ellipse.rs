@@ -0,0 +1,242 @@
+/*!
+  The ellipse element, with the radii either in pixels or in data units, and the ellipse of a
+  covariance matrix at a confidence level
+*/
+
+use super::basic_shapes::draw_polygon;
+use crate::drawing::backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+use crate::element::{Drawable, PointCollection};
+use crate::style::{ShapeStyle, SizeDesc};
+
+/// The number of the segments the outline of an ellipse is made of
+const ELLIPSE_SEGMENTS: u32 = 64;
+
+/// An ellipse element. The radii are either given in pixels, then the ellipse has the same shape
+/// wherever it's drawn, or in data units, then the ellipse is stretched along with the chart,
+/// for example a covariance ellipse around a cluster of points.
+pub struct Ellipse<Coord, Size: SizeDesc> {
+    /// The center, followed by the ends of the two semi-axes for the radii in data units
+    points: Vec<Coord>,
+    radii: Option<(Size, Size)>,
+    angle: f64,
+    style: ShapeStyle,
+}
+
+impl<Coord, Size: SizeDesc> Ellipse<Coord, Size> {
+    /// Create a new ellipse element with the radii in pixels
+    /// - `center`: The center of the ellipse
+    /// - `radii`: The radius along the x axis and the radius along the y axis
+    /// - `style`: The style of the ellipse, which is filled if the style is filled
+    pub fn new<S: Into<ShapeStyle>>(center: Coord, radii: (Size, Size), style: S) -> Self {
+        Self {
+            points: vec![center],
+            radii: Some(radii),
+            angle: 0.0,
+            style: style.into(),
+        }
+    }
+
+    /// Rotate the ellipse with the radii in pixels clockwise by the angle in degrees. The
+    /// ellipse in data units is rotated in the data space when it's created instead.
+    pub fn rotate(mut self, angle: f64) -> Self {
+        self.angle = angle;
+        self
+    }
+}
+
+impl Ellipse<(f64, f64), u32> {
+    /// Create a new ellipse element with the radii in data units
+    /// - `center`: The center of the ellipse
+    /// - `radii`: The two radii, which are along the x axis and the y axis before the rotation
+    /// - `angle`: The rotation of the ellipse in degrees, from the x axis towards the y axis
+    /// - `style`: The style of the ellipse, which is filled if the style is filled
+    pub fn in_data_units<S: Into<ShapeStyle>>(
+        center: (f64, f64),
+        radii: (f64, f64),
+        angle: f64,
+        style: S,
+    ) -> Self {
+        let (sin, cos) = angle.to_radians().sin_cos();
+        let (x, y) = center;
+        Self {
+            points: vec![
+                center,
+                (x + radii.0 * cos, y + radii.0 * sin),
+                (x - radii.1 * sin, y + radii.1 * cos),
+            ],
+            radii: None,
+            angle: 0.0,
+            style: style.into(),
+        }
+    }
+
+    /// Create the ellipse which covers the given share of a normal distribution in data units
+    /// - `center`: The mean of the distribution
+    /// - `covariance`: The 2×2 covariance matrix of the distribution
+    /// - `confidence`: The probability of a point being inside of the ellipse, within `[0, 1)`,
+    ///   for example 0.95 makes the radii about 2.45 standard deviations along each axis
+    /// - `style`: The style of the ellipse
+    pub fn from_covariance<S: Into<ShapeStyle>>(
+        center: (f64, f64),
+        covariance: [[f64; 2]; 2],
+        confidence: f64,
+        style: S,
+    ) -> Self {
+        let (a, d) = (covariance[0][0], covariance[1][1]);
+        let b = (covariance[0][1] + covariance[1][0]) / 2.0;
+        // The eigenvalues of the matrix are the variances along the axes of the ellipse
+        let mean = (a + d) / 2.0;
+        let delta = ((a - d) / 2.0).hypot(b);
+        let angle = (2.0 * b).atan2(a - d).to_degrees() / 2.0;
+        // The quantile of the chi-squared distribution with two degrees of freedom
+        let scale = -2.0 * (1.0 - confidence.max(0.0)).ln();
+        Self::in_data_units(
+            center,
+            (
+                ((mean + delta).max(0.0) * scale).sqrt(),
+                ((mean - delta).max(0.0) * scale).sqrt(),
+            ),
+            angle,
+            style,
+        )
+    }
+}
+
+impl<'a, Coord, Size: SizeDesc> PointCollection<'a, Coord> for &'a Ellipse<Coord, Size> {
+    type Borrow = &'a Coord;
+    type IntoIter = &'a [Coord];
+    fn point_iter(self) -> &'a [Coord] {
+        &self.points
+    }
+}
+
+impl<Coord, DB: DrawingBackend, Size: SizeDesc> Drawable<DB> for Ellipse<Coord, Size> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        ps: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let center = match points.next() {
+            Some(center) => center,
+            None => return Ok(()),
+        };
+        // The two semi-axes of the ellipse from the center in pixels
+        let (u, v) = match &self.radii {
+            Some((rx, ry)) => {
+                let (sin, cos) = self.angle.to_radians().sin_cos();
+                let (rx, ry) = (f64::from(rx.in_pixels(&ps)), f64::from(ry.in_pixels(&ps)));
+                ((rx * cos, rx * sin), (-ry * sin, ry * cos))
+            }
+            None => match (points.next(), points.next()) {
+                (Some(u), Some(v)) => {
+                    let axis =
+                        |(x, y): BackendCoord| (f64::from(x - center.0), f64::from(y - center.1));
+                    (axis(u), axis(v))
+                }
+                _ => return Ok(()),
+            },
+        };
+
+        let mut outline: Vec<_> = (0..ELLIPSE_SEGMENTS)
+            .map(|idx| {
+                let t = std::f64::consts::PI * 2.0 * f64::from(idx) / f64::from(ELLIPSE_SEGMENTS);
+                let (sin, cos) = t.sin_cos();
+                (
+                    center.0 + (u.0 * cos + v.0 * sin).round() as i32,
+                    center.1 + (u.1 * cos + v.1 * sin).round() as i32,
+                )
+            })
+            .collect();
+        outline.dedup();
+
+        if self.style.filled {
+            draw_polygon(&outline, &self.style, backend)
+        } else {
+            let first = outline[0];
+            backend.draw_path(
+                outline.into_iter().chain(std::iter::once(first)),
+                &self.style,
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_ellipse_element() {
+    use crate::prelude::*;
+    let bounds = |path: &[BackendCoord]| {
+        path.iter().fold(
+            ((i32::MAX, i32::MIN), (i32::MAX, i32::MIN)),
+            |((x0, x1), (y0, y1)), &(x, y)| ((x0.min(x), x1.max(x)), (y0.min(y), y1.max(y))),
+        )
+    };
+
+    let da = crate::create_mocked_drawing_area(300, 300, move |m| {
+        m.check_draw_path(move |c, _, path| {
+            assert_eq!(c, BLUE.to_rgba());
+            assert_eq!(path.first(), path.last());
+            assert_eq!(bounds(&path), ((120, 180), (130, 170)));
+        });
+        m.drop_check(|b| assert_eq!(b.num_draw_path_call, 1));
+    });
+    da.draw(&Ellipse::new((150, 150), (30, 20), &BLUE))
+        .expect("Drawing Failure");
+
+    // The rotation by the right angle swaps the radii
+    let da = crate::create_mocked_drawing_area(300, 300, move |m| {
+        m.check_fill_polygon(move |c, path| {
+            assert_eq!(c, RED.to_rgba());
+            assert_eq!(bounds(&path), ((130, 170), (120, 180)));
+        });
+        m.drop_check(|b| {
+            assert_eq!(b.num_fill_polygon_call, 1);
+            assert_eq!(b.num_draw_path_call, 0);
+        });
+    });
+    da.draw(&Ellipse::new((150, 150), (30, 20), RED.filled()).rotate(90.0))
+        .expect("Drawing Failure");
+}
+
+#[cfg(test)]
+#[test]
+fn test_ellipse_in_data_units() {
+    use crate::prelude::*;
+    let bounds = |path: &[BackendCoord]| {
+        path.iter().fold(
+            ((i32::MAX, i32::MIN), (i32::MAX, i32::MIN)),
+            |((x0, x1), (y0, y1)), &(x, y)| ((x0.min(x), x1.max(x)), (y0.min(y), y1.max(y))),
+        )
+    };
+
+    // Each data unit is 10 pixels wide and 20 pixels high
+    let da = crate::create_mocked_drawing_area(100, 200, move |m| {
+        m.check_draw_path(move |_, _, path| {
+            assert_eq!(bounds(&path), ((30, 70), (60, 140)));
+        });
+        m.drop_check(|b| assert_eq!(b.num_draw_path_call, 1));
+    });
+    let da = da.apply_coord_spec(RangedCoord::<RangedCoordf64, RangedCoordf64>::new(
+        0f64..10f64,
+        10f64..0f64,
+        (0..100, 0..200),
+    ));
+    da.draw(&Ellipse::in_data_units((5.0, 5.0), (2.0, 2.0), 0.0, &BLUE))
+        .expect("Drawing Failure");
+
+    // The covariance of the independent variables gives the ellipse along the axes, and the
+    // correlated ones give a tilted ellipse
+    let ellipse = Ellipse::from_covariance((0.0, 0.0), [[4.0, 0.0], [0.0, 1.0]], 0.95, &BLUE);
+    let scale = (-2.0 * 0.05f64.ln()).sqrt();
+    assert!((ellipse.points[1].0 - 2.0 * scale).abs() < 1e-9);
+    assert!(ellipse.points[1].1.abs() < 1e-9);
+    assert!((ellipse.points[2].1 - scale).abs() < 1e-9);
+
+    let ellipse = Ellipse::from_covariance((0.0, 0.0), [[2.0, 1.0], [1.0, 2.0]], 0.95, &BLUE);
+    let (major, minor) = (ellipse.points[1], ellipse.points[2]);
+    assert!((major.0 - major.1).abs() < 1e-9);
+    assert!((major.0.hypot(major.1) - 3f64.sqrt() * scale).abs() < 1e-9);
+    assert!((minor.0 + minor.1).abs() < 1e-9);
+    assert!((minor.0.hypot(minor.1) - scale).abs() < 1e-9);
+}
@@ -53,9 +53,25 @@ pub struct ErrorBar<K, V, O: ErrorBarOrient<K, V>> {
     width: u32,
     key: K,
     values: [V; 3],
+    marker: bool,
+    caps: (bool, bool),
     _p: PhantomData<O>,
 }
 
+impl<K, V, O: ErrorBarOrient<K, V>> ErrorBar<K, V, O> {
+    /// Set if the marker at the average value is drawn
+    pub fn marker(mut self, marker: bool) -> Self {
+        self.marker = marker;
+        self
+    }
+
+    /// Set if the end caps at the minimum and the maximum value are drawn
+    pub fn caps(mut self, min: bool, max: bool) -> Self {
+        self.caps = (min, max);
+        self
+    }
+}
+
 impl<K, V> ErrorBar<K, V, ErrorBarOrientV<K, V>> {
     pub fn new_vertical<S: Into<ShapeStyle>>(
         key: K,
@@ -70,6 +86,8 @@ impl<K, V> ErrorBar<K, V, ErrorBarOrientV<K, V>> {
             width,
             key,
             values: [min, avg, max],
+            marker: true,
+            caps: (true, true),
             _p: PhantomData,
         }
     }
@@ -89,6 +107,8 @@ impl<K, V> ErrorBar<K, V, ErrorBarOrientH<K, V>> {
             width,
             key,
             values: [min, avg, max],
+            marker: true,
+            caps: (true, true),
             _p: PhantomData,
         }
     }
@@ -116,20 +136,26 @@ impl<K, V, O: ErrorBarOrient<K, V>, DB: DrawingBackend> Drawable<DB> for ErrorBa
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
         let points: Vec<_> = points.take(3).collect();
 
-        let (from, to) = O::ending_coord(points[0], self.width);
-        backend.draw_line(from, to, &self.style.color)?;
+        if self.caps.0 {
+            let (from, to) = O::ending_coord(points[0], self.width);
+            backend.draw_line(from, to, &self.style.color)?;
+        }
 
-        let (from, to) = O::ending_coord(points[2], self.width);
-        backend.draw_line(from, to, &self.style.color)?;
+        if self.caps.1 {
+            let (from, to) = O::ending_coord(points[2], self.width);
+            backend.draw_line(from, to, &self.style.color)?;
+        }
 
         backend.draw_line(points[0], points[2], &self.style.color)?;
 
-        backend.draw_circle(
-            points[1],
-            self.width / 2,
-            &self.style.color,
-            self.style.filled,
-        )?;
+        if self.marker {
+            backend.draw_circle(
+                points[1],
+                self.width / 2,
+                &self.style.color,
+                self.style.filled,
+            )?;
+        }
 
         Ok(())
     }
@@ -1,17 +1,26 @@
 #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
-use image::{DynamicImage, GenericImageView};
+use image::{ColorType, DynamicImage, GenericImageView};
 
 use super::{Drawable, PointCollection};
-use crate::drawing::backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+use crate::drawing::backend::{
+    BackendCoord, DrawingBackend, DrawingErrorKind, ImageScaling, PixelFormat,
+};
+use crate::drawing::rasterizer::ImageSampler;
 
 use crate::drawing::BitMapBackend;
 use std::borrow::{Borrow, Cow};
 
-/// The element that contains a bitmap on it
+/// The element that contains a bitmap on it. The bitmap is drawn at its own size by default,
+/// and it can be scaled to another size in pixels or stretched over a rectangle in the guest
+/// coordinate.
 pub struct BitMapElement<'a, Coord> {
-    image: Cow<'a, Vec<u8>>,
+    image: Cow<'a, [u8]>,
     size: (u32, u32),
+    format: PixelFormat,
     pos: Coord,
+    corner: Option<Coord>,
+    draw_size: Option<(u32, u32)>,
+    scaling: ImageScaling,
 }
 
 impl<'a, Coord> BitMapElement<'a, Coord> {
@@ -21,25 +30,117 @@ impl<'a, Coord> BitMapElement<'a, Coord> {
     /// - `pos`: The left upper coordinate for the element
     /// - `size`: The size of the bitmap
     pub fn new(pos: Coord, size: (u32, u32)) -> Self {
+        Self::from_buffer(
+            pos,
+            size,
+            vec![0; (size.0 * size.1 * 3) as usize].into(),
+            PixelFormat::Rgb,
+        )
+    }
+
+    fn from_buffer(
+        pos: Coord,
+        size: (u32, u32),
+        image: Cow<'a, [u8]>,
+        format: PixelFormat,
+    ) -> Self {
         Self {
-            image: Cow::Owned(vec![0; (size.0 * size.1 * 3) as usize]),
+            image,
             size,
+            format,
             pos,
+            corner: None,
+            draw_size: None,
+            scaling: ImageScaling::Nearest,
+        }
+    }
+
+    /// Create a bitmap element from the RGB pixels, row by row. Either an owned or a borrowed
+    /// buffer can be used.
+    ///
+    /// - `pos`: The left upper coordinate for the element
+    /// - `size`: The size of the bitmap
+    /// - `buf`: The pixels of the bitmap
+    /// - *Returns* The element, or `None` if the buffer doesn't have the size of the bitmap
+    pub fn with_buffer<B: Into<Cow<'a, [u8]>>>(
+        pos: Coord,
+        size: (u32, u32),
+        buf: B,
+    ) -> Option<Self> {
+        Self::with_format(pos, size, buf.into(), PixelFormat::Rgb)
+    }
+
+    /// Create a bitmap element from the RGBA pixels, row by row, whose alpha is blended with
+    /// what is under the bitmap. Either an owned or a borrowed buffer can be used.
+    ///
+    /// - `pos`: The left upper coordinate for the element
+    /// - `size`: The size of the bitmap
+    /// - `buf`: The pixels of the bitmap
+    /// - *Returns* The element, or `None` if the buffer doesn't have the size of the bitmap
+    pub fn with_rgba_buffer<B: Into<Cow<'a, [u8]>>>(
+        pos: Coord,
+        size: (u32, u32),
+        buf: B,
+    ) -> Option<Self> {
+        Self::with_format(pos, size, buf.into(), PixelFormat::Rgba)
+    }
+
+    fn with_format(
+        pos: Coord,
+        size: (u32, u32),
+        buf: Cow<'a, [u8]>,
+        format: PixelFormat,
+    ) -> Option<Self> {
+        if buf.len() != size.0 as usize * size.1 as usize * format.bytes_per_pixel() {
+            return None;
         }
+        Some(Self::from_buffer(pos, size, buf, format))
     }
 
-    /// Copy the existing bitmap element to another location
+    /// Draw the bitmap at the given size in pixels instead of its own size
+    ///
+    /// - `size`: The width and the height in pixels
+    pub fn resize(mut self, size: (u32, u32)) -> Self {
+        self.draw_size = Some(size);
+        self.corner = None;
+        self
+    }
+
+    /// Stretch the bitmap over the rectangle between its position and the opposite corner in
+    /// the guest coordinate, so that it's scaled along with the chart
+    ///
+    /// - `corner`: The corner opposite to the position of the element
+    pub fn stretch_to(mut self, corner: Coord) -> Self {
+        self.corner = Some(corner);
+        self.draw_size = None;
+        self
+    }
+
+    /// Set how the bitmap is resampled when it's drawn at another size, which is
+    /// `ImageScaling::Nearest` by default
+    pub fn scaling(mut self, scaling: ImageScaling) -> Self {
+        self.scaling = scaling;
+        self
+    }
+
+    /// Copy the existing bitmap element to another location. The copy has the size in pixels
+    /// of the element, but it isn't stretched to a corner.
     ///
     /// - `pos`: The new location to copy
     pub fn copy_to<Coord2>(&self, pos: Coord2) -> BitMapElement<Coord2> {
         BitMapElement {
             image: Cow::Borrowed(self.image.borrow()),
             size: self.size,
+            format: self.format,
             pos,
+            corner: None,
+            draw_size: self.draw_size,
+            scaling: self.scaling,
         }
     }
 
-    /// Move the existing bitmap element to a new position
+    /// Move the existing bitmap element to a new position. The corner a stretched element is
+    /// stretched to stays where it is.
     ///
     /// - `pos`: The new position
     pub fn move_to(&mut self, pos: Coord) {
@@ -47,43 +148,214 @@ impl<'a, Coord> BitMapElement<'a, Coord> {
     }
 
     /// Make the bitmap element as a bitmap backend, so that we can use
-    /// plotters drawing functionality on the bitmap element
+    /// plotters drawing functionality on the bitmap element. The pixels are drawn in the
+    /// format of the bitmap, which makes the drawn pixels of an RGBA bitmap opaque.
     pub fn as_bitmap_backend(&mut self) -> BitMapBackend {
-        BitMapBackend::with_buffer(self.image.to_mut(), self.size)
+        BitMapBackend::with_buffer_and_format(self.image.to_mut(), self.size, self.format)
+            .expect("The buffer has the size of the bitmap")
+    }
+
+    /// Get the upper left corner and the size of the bitmap in pixels
+    fn placement<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+    ) -> Option<(BackendCoord, (u32, u32))> {
+        let pos = points.next()?;
+        if self.corner.is_none() {
+            return Some((pos, self.draw_size.unwrap_or(self.size)));
+        }
+        let corner = points.next()?;
+        Some((
+            (pos.0.min(corner.0), pos.1.min(corner.1)),
+            (
+                (pos.0 - corner.0).unsigned_abs(),
+                (pos.1 - corner.1).unsigned_abs(),
+            ),
+        ))
+    }
+
+    fn draw_placed<DB: DrawingBackend>(
+        &self,
+        pos: BackendCoord,
+        size: (u32, u32),
+        backend: &mut DB,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        backend.draw_image(
+            pos,
+            size,
+            self.size,
+            self.image.as_ref(),
+            self.format,
+            self.scaling,
+        )
+    }
+}
+
+#[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
+impl<'a, Coord> BitMapElement<'a, Coord> {
+    /// Create a bitmap element from an image, which keeps the alpha channel of the image if
+    /// it has one
+    ///
+    /// - `pos`: The left upper coordinate for the element
+    /// - `image`: The image
+    pub fn from_image(pos: Coord, image: DynamicImage) -> Self {
+        let size = image.dimensions();
+        match image.color() {
+            ColorType::GrayA(_) | ColorType::RGBA(_) | ColorType::BGRA(_) => Self::from_buffer(
+                pos,
+                size,
+                image.to_rgba().into_raw().into(),
+                PixelFormat::Rgba,
+            ),
+            _ => Self::from_buffer(
+                pos,
+                size,
+                image.to_rgb().into_raw().into(),
+                PixelFormat::Rgb,
+            ),
+        }
     }
 }
 
 #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
 impl<'a, Coord> From<(Coord, DynamicImage)> for BitMapElement<'a, Coord> {
     fn from((pos, image): (Coord, DynamicImage)) -> Self {
-        let (w, h) = image.dimensions();
-        let rgb_image = image.to_rgb().into_raw();
-        Self {
-            pos,
-            image: Cow::Owned(rgb_image),
-            size: (w, h),
-        }
+        Self::from_image(pos, image)
     }
 }
 
 impl<'a, 'b, Coord> PointCollection<'a, Coord> for &'a BitMapElement<'b, Coord> {
     type Borrow = &'a Coord;
-    type IntoIter = std::iter::Once<&'a Coord>;
+    type IntoIter = std::iter::Chain<std::iter::Once<&'a Coord>, std::option::Iter<'a, Coord>>;
     fn point_iter(self) -> Self::IntoIter {
-        std::iter::once(&self.pos)
+        std::iter::once(&self.pos).chain(self.corner.iter())
     }
 }
 
 impl<'a, Coord, DB: DrawingBackend> Drawable<DB> for BitMapElement<'a, Coord> {
     fn draw<I: Iterator<Item = BackendCoord>>(
         &self,
-        mut points: I,
+        points: I,
         backend: &mut DB,
         _: (u32, u32),
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
-        if let Some((x, y)) = points.next() {
-            return backend.blit_bitmap((x, y), self.size, self.image.as_ref());
+        match self.placement(points) {
+            Some((pos, size)) => self.draw_placed(pos, size, backend),
+            None => Ok(()),
         }
-        Ok(())
     }
+
+    fn draw_clipped<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+        (ul, br): (BackendCoord, BackendCoord),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (pos, size) = match self.placement(points) {
+            Some(placement) => placement,
+            None => return Ok(()),
+        };
+        // The bottom right corner of the area is the end of its pixels, like the size is
+        let (x0, y0) = (pos.0.max(ul.0), pos.1.max(ul.1));
+        let x1 = (pos.0 + size.0 as i32).min(br.0);
+        let y1 = (pos.1 + size.1 as i32).min(br.1);
+        if x0 >= x1 || y0 >= y1 {
+            return Ok(());
+        }
+        let visible = ((x1 - x0) as u32, (y1 - y0) as u32);
+        if (x0, y0) == pos && visible == size {
+            return self.draw_placed(pos, size, backend);
+        }
+
+        // The visible part is resampled, since it may not start at a pixel of the bitmap
+        let sampler = match ImageSampler::new(
+            self.image.as_ref(),
+            self.size,
+            self.format,
+            size,
+            self.scaling,
+        ) {
+            Some(sampler) => sampler,
+            None => return Ok(()),
+        };
+        let mut buf = Vec::with_capacity(visible.0 as usize * visible.1 as usize * 4);
+        for y in y0..y1 {
+            for x in x0..x1 {
+                buf.extend_from_slice(&sampler.sample((x - pos.0) as u32, (y - pos.1) as u32));
+            }
+        }
+        backend.draw_image(
+            (x0, y0),
+            visible,
+            visible,
+            &buf,
+            PixelFormat::Rgba,
+            ImageScaling::Nearest,
+        )
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_bitmap_element() {
+    use crate::prelude::*;
+
+    let pixels: Vec<u8> = (0..4 * 2 * 3).map(|x| x as u8).collect();
+    assert!(BitMapElement::with_buffer((0, 0), (4, 3), &pixels[..]).is_none());
+
+    let da = crate::create_mocked_drawing_area(100, 100, |m| {
+        m.check_draw_image(|pos, size, src_size, src| {
+            assert_eq!((pos, size, src_size), ((10, 20), (4, 2), (4, 2)));
+            assert_eq!(src.len(), 24);
+        });
+        m.check_draw_image(|pos, size, src_size, _| {
+            assert_eq!((pos, size, src_size), ((10, 20), (40, 20), (4, 2)));
+        });
+        // The corners are in any order
+        m.check_draw_image(|pos, size, _, _| assert_eq!((pos, size), ((5, 10), (25, 40))));
+        m.drop_check(|b| assert_eq!(b.num_draw_image_call, 3));
+    });
+    let elem = BitMapElement::with_buffer((10, 20), (4, 2), &pixels[..]).unwrap();
+    da.draw(&elem).expect("Drawing Failure");
+    let elem = elem.resize((40, 20));
+    da.draw(&elem).expect("Drawing Failure");
+    da.draw(&elem.copy_to((30, 10)).stretch_to((5, 50)))
+        .expect("Drawing Failure");
+}
+
+#[cfg(test)]
+#[test]
+fn test_bitmap_element_clipped() {
+    use crate::prelude::*;
+
+    // The bitmap is half red and half transparent, and it's cut at the edge of the area
+    let pixels: Vec<u8> = (0..4)
+        .flat_map(|x| {
+            if x < 2 {
+                vec![255, 0, 0, 255]
+            } else {
+                vec![0; 4]
+            }
+        })
+        .collect();
+    let da = crate::create_mocked_drawing_area(100, 100, |m| {
+        m.check_draw_image(|pos, size, src_size, src| {
+            assert_eq!((pos, size, src_size), ((70, 10), (30, 5), (30, 5)));
+            for (idx, pixel) in src.chunks(4).enumerate() {
+                let expected = if idx % 30 < 20 {
+                    [255, 0, 0, 255]
+                } else {
+                    [0; 4]
+                };
+                assert_eq!(pixel, expected);
+            }
+        });
+        m.drop_check(|b| assert_eq!(b.num_draw_image_call, 1));
+    });
+    let elem = BitMapElement::with_rgba_buffer((70, 10), (4, 1), pixels)
+        .unwrap()
+        .resize((40, 5));
+    da.draw_with_policy(&elem, OutOfRange::Clip)
+        .expect("Drawing Failure");
 }
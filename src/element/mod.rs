@@ -86,6 +86,11 @@
     ```
     ![](https://plotters-rs.github.io/plotters-doc-data/element-1.png)
 
+    Each `+` nests the type of the group one level deeper. When a group has many components, or its
+    type needs to be named, such as the item type of a series, `EmptyElement::into_multi` turns it
+    into a `MultiComposed` element, which keeps the components in a vector and draws them in the
+    order they are added.
+
     ## Dynamic Elements
     By default, Plotters uses static dispatch for all the elements and series. For example,
     the `ChartContext::draw_series` method accepts an iterator of `T` where type `T` implements
@@ -153,6 +158,7 @@
     ![](https://plotters-rs.github.io/plotters-doc-data/element-3.png)
 */
 use crate::drawing::backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+use crate::drawing::rasterizer::is_inside;
 use std::borrow::Borrow;
 
 mod basic_shapes;
@@ -165,7 +171,7 @@ mod points;
 pub use points::*;
 
 mod composable;
-pub use composable::{ComposedElement, EmptyElement};
+pub use composable::{ComposedElement, EmptyElement, MultiComposed};
 
 mod candlestick;
 pub use candlestick::CandleStick;
@@ -173,9 +179,33 @@ pub use candlestick::CandleStick;
 mod errorbar;
 pub use errorbar::{ErrorBar, ErrorBarOrientH, ErrorBarOrientV};
 
+mod boxplot;
+pub use boxplot::{Boxplot, BoxplotOrient, BoxplotOrientH, BoxplotOrientV};
+
+mod pie;
+pub use pie::Pie;
+
+mod arc;
+pub use arc::{AngleConvention, Arc, Sector};
+
+mod arrow;
+pub use arrow::Arrow;
+
+mod bezier;
+pub use bezier::{BezierPath, CubicBezier};
+
+mod boxed_text;
+pub use boxed_text::BoxedText;
+
+mod ellipse;
+pub use ellipse::Ellipse;
+
 mod image;
 pub use self::image::BitMapElement;
 
+mod shadow;
+pub use shadow::{Shadowed, WithShadow};
+
 mod dynelem;
 pub use dynelem::{DynElement, IntoDynElement};
 
@@ -201,4 +231,23 @@ pub trait Drawable<DB: DrawingBackend> {
         backend: &mut DB,
         parent_dim: (u32, u32),
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>>;
+
+    /// Draws the part of the element inside the clipping rectangle, which is given by its upper
+    /// left and bottom right corners. By default, the element is left out unless all of its
+    /// points are inside the rectangle, and the elements that can be cut geometrically, such as
+    /// paths, polygons and rectangles, override it.
+    fn draw_clipped<I: Iterator<Item = BackendCoord>>(
+        &self,
+        pos: I,
+        backend: &mut DB,
+        parent_dim: (u32, u32),
+        clip: (BackendCoord, BackendCoord),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let points: Vec<_> = pos.collect();
+        if points.iter().all(|p| is_inside(*p, clip)) {
+            self.draw(points.into_iter(), backend, parent_dim)
+        } else {
+            Ok(())
+        }
+    }
 }
@@ -0,0 +1,167 @@
+/*!
+  The pie element, which is a circular sector that optionally has a hole in the middle
+*/
+
+use crate::drawing::backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+use crate::element::{Drawable, PointCollection};
+use crate::style::{ShapeStyle, TextStyle};
+
+/// The angle in radians each line segment of an arc covers at most
+const ARC_STEP: f64 = std::f64::consts::PI / 90.0;
+
+/// Get the points of the arc around `center`, going from `start` to `end` in radians
+fn arc(center: BackendCoord, radius: u32, start: f64, end: f64) -> Vec<BackendCoord> {
+    let steps = ((end - start).abs() / ARC_STEP).ceil().max(1.0) as usize;
+    let radius = f64::from(radius);
+    (0..=steps)
+        .map(|i| {
+            let angle = start + (end - start) * i as f64 / steps as f64;
+            (
+                center.0 + (radius * angle.cos()).round() as i32,
+                center.1 - (radius * angle.sin()).round() as i32,
+            )
+        })
+        .collect()
+}
+
+/// A circular sector around a center point, with the radius given in pixels. The angles are
+/// in radians and go counterclockwise from the 3 o'clock position.
+pub struct Pie<'a, Coord> {
+    center: Coord,
+    radius: u32,
+    inner_radius: u32,
+    angles: (f64, f64),
+    style: ShapeStyle,
+    label: Option<(String, TextStyle<'a>)>,
+}
+
+impl<'a, Coord> Pie<'a, Coord> {
+    /// Create a new pie element
+    /// - `center`: The center of the circle
+    /// - `radius`: The radius in pixels
+    /// - `start`: The angle the sector starts at
+    /// - `end`: The angle the sector ends at
+    /// - `style`: The style of the sector, which is only outlined if it's not filled
+    pub fn new<S: Into<ShapeStyle>>(
+        center: Coord,
+        radius: u32,
+        start: f64,
+        end: f64,
+        style: S,
+    ) -> Self {
+        Self {
+            center,
+            radius,
+            inner_radius: 0,
+            angles: (start, end),
+            style: style.into(),
+            label: None,
+        }
+    }
+
+    /// Set the radius of the hole in the middle in pixels, which makes a donut sector
+    pub fn inner_radius(mut self, radius: u32) -> Self {
+        self.inner_radius = radius.min(self.radius);
+        self
+    }
+
+    /// Set the label, which is drawn centered in the middle of the sector
+    pub fn label<T: Into<String>, S: Into<TextStyle<'a>>>(mut self, text: T, style: S) -> Self {
+        self.label = Some((text.into(), style.into()));
+        self
+    }
+
+    /// Get the position in the middle of the sector, relative to the center
+    fn middle(&self) -> BackendCoord {
+        let angle = (self.angles.0 + self.angles.1) / 2.0;
+        let radius = f64::from(self.radius + self.inner_radius) / 2.0;
+        (
+            (radius * angle.cos()).round() as i32,
+            -(radius * angle.sin()).round() as i32,
+        )
+    }
+}
+
+impl<'b, 'a, Coord: 'a> PointCollection<'a, Coord> for &'a Pie<'b, Coord> {
+    type Borrow = &'a Coord;
+    type IntoIter = std::iter::Once<&'a Coord>;
+    fn point_iter(self) -> Self::IntoIter {
+        std::iter::once(&self.center)
+    }
+}
+
+impl<'a, Coord, DB: DrawingBackend> Drawable<DB> for Pie<'a, Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let center = match points.next() {
+            Some(center) => center,
+            None => return Ok(()),
+        };
+        let (start, end) = self.angles;
+
+        let mut outline = arc(center, self.radius, start, end);
+        if self.inner_radius > 0 {
+            outline.extend(arc(center, self.inner_radius, end, start));
+        } else {
+            outline.push(center);
+        }
+
+        if self.style.filled {
+            backend.fill_polygon(outline, &self.style.color)?;
+        } else {
+            outline.push(outline[0]);
+            backend.draw_path(outline, &self.style)?;
+        }
+
+        if let Some((text, style)) = &self.label {
+            let (w, h) = backend.estimate_text_size(text, &style.font)?;
+            let middle = self.middle();
+            let pos = (
+                center.0 + middle.0 - w as i32 / 2,
+                center.1 + middle.1 - h as i32 / 2,
+            );
+            backend.draw_text(text, &style.font, pos, &style.color)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_pie_element() {
+    use crate::prelude::*;
+    use std::f64::consts::PI;
+
+    let da = crate::create_mocked_drawing_area(300, 300, |m| {
+        m.check_fill_polygon(|c, path| {
+            assert_eq!(c, RED.to_rgba());
+            assert_eq!(path[0], (200, 150));
+            assert_eq!(path.len(), 47);
+            assert_eq!(path[45], (150, 100));
+            assert_eq!(path[path.len() - 1], (150, 150));
+        });
+        m.check_fill_polygon(|_, path| {
+            assert_eq!(path[0], (150, 100));
+            assert_eq!(path.len(), 46 * 2);
+            assert_eq!(path[path.len() - 1], (150, 130));
+        });
+        m.check_draw_text(|_, _, _, _, text| assert_eq!(text, "A"));
+        m.drop_check(|b| {
+            assert_eq!(b.num_fill_polygon_call, 2);
+            assert_eq!(b.num_draw_text_call, 1);
+        });
+    });
+
+    da.draw(&Pie::new((150, 150), 50, 0.0, PI / 2.0, RED.filled()))
+        .expect("Drawing Failure");
+    da.draw(
+        &Pie::new((150, 150), 50, PI / 2.0, PI, BLUE.filled())
+            .inner_radius(20)
+            .label("A", ("sans-serif", 10)),
+    )
+    .expect("Drawing Failure");
+}
@@ -94,12 +94,210 @@ impl<Coord, DB: DrawingBackend, Size: SizeDesc> Drawable<DB> for TriangleMarker<
                         (rad.sin() * f64::from(size) + f64::from(y)).ceil() as i32,
                     )
                 });
-            backend.fill_polygon(points, &self.style.color)?;
+            super::basic_shapes::draw_polygon(&points.collect::<Vec<_>>(), &self.style, backend)?;
         }
         Ok(())
     }
 }
 
+/// The shape of a marker, see `Marker`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MarkerShape {
+    Circle,
+    Square,
+    TriangleUp,
+    TriangleDown,
+    Diamond,
+    /// A star with the number of points, which is at least 3
+    Star(u8),
+    Plus,
+    X,
+}
+
+/// The shapes `MarkerShape::cycle` goes through, the most distinct ones first
+const MARKER_CYCLE: [MarkerShape; 8] = [
+    MarkerShape::Circle,
+    MarkerShape::Square,
+    MarkerShape::TriangleUp,
+    MarkerShape::Diamond,
+    MarkerShape::TriangleDown,
+    MarkerShape::Star(5),
+    MarkerShape::Plus,
+    MarkerShape::X,
+];
+
+impl MarkerShape {
+    /// Pick a shape by its index, which goes around all the shapes. This pairs with
+    /// `Palette::pick`, so the series drawn with the same index get a distinct shape and color.
+    pub fn cycle(idx: usize) -> Self {
+        MARKER_CYCLE[idx % MARKER_CYCLE.len()]
+    }
+
+    /// The outline of the shape around the origin, scaled to the same area as the circle of
+    /// the radius. The plus and the X are the outlines of the thick crosses.
+    fn outline(self, radius: f64) -> Vec<(f64, f64)> {
+        use std::f64::consts::PI;
+        let area = PI * radius * radius;
+        // The points around the origin at the distances, starting at 12 o'clock
+        let around = |distances: &[f64], start: f64| -> Vec<(f64, f64)> {
+            let step = PI * 2.0 / distances.len() as f64;
+            distances
+                .iter()
+                .enumerate()
+                .map(|(i, r)| {
+                    let angle = start + step * i as f64;
+                    (r * angle.cos(), r * angle.sin())
+                })
+                .collect()
+        };
+        match self {
+            MarkerShape::Circle => around(&[radius; 64], 0.0),
+            MarkerShape::Square => {
+                let half = area.sqrt() / 2.0;
+                vec![(-half, -half), (half, -half), (half, half), (-half, half)]
+            }
+            MarkerShape::Diamond => around(&[(area / 2.0).sqrt(); 4], -PI / 2.0),
+            MarkerShape::TriangleUp | MarkerShape::TriangleDown => {
+                // The area of a regular polygon with the circumradius `r` is n·r²·sin(2π/n)/2
+                let r = (area * 4.0 / (3.0 * 3f64.sqrt())).sqrt();
+                let start = if self == MarkerShape::TriangleUp {
+                    -PI / 2.0
+                } else {
+                    PI / 2.0
+                };
+                around(&[r; 3], start)
+            }
+            MarkerShape::Star(points) => {
+                let n = f64::from(points.max(3));
+                // The inner points are at half of the outer radius
+                let r = (area / (n * 0.5 * (PI / n).sin())).sqrt();
+                let distances: Vec<_> = (0..points.max(3) as usize * 2)
+                    .map(|i| if i % 2 == 0 { r } else { r / 2.0 })
+                    .collect();
+                around(&distances, -PI / 2.0)
+            }
+            MarkerShape::Plus | MarkerShape::X => {
+                // The arms are a half of the arm length wide, so the area is 1.75·l²
+                let l = (area / 1.75).sqrt();
+                let w = l / 4.0;
+                let arm = [(w, -w), (w, -l), (-w, -l), (-w, -w)];
+                let (sin, cos) = if self == MarkerShape::X {
+                    (PI / 4.0).sin_cos()
+                } else {
+                    (0.0, 1.0)
+                };
+                // Each arm is a quarter turn from the previous one, then the cross is rotated
+                (0..4)
+                    .flat_map(|k| {
+                        arm[..3].iter().map(move |&(x, y)| match k {
+                            0 => (x, y),
+                            1 => (y, -x),
+                            2 => (-x, -y),
+                            _ => (-y, x),
+                        })
+                    })
+                    .map(|(x, y)| (x * cos - y * sin, x * sin + y * cos))
+                    .collect()
+            }
+        }
+    }
+}
+
+/// A marker of one of the shapes. All the shapes cover the same area as the circle whose
+/// radius is the size of the marker, so the markers of the same size look alike in weight.
+pub struct Marker<Coord, Size: SizeDesc> {
+    center: Coord,
+    size: Size,
+    shape: MarkerShape,
+    style: ShapeStyle,
+}
+
+impl<Coord, Size: SizeDesc> Marker<Coord, Size> {
+    /// Create a new marker element
+    /// - `coord`: The center of the marker
+    /// - `size`: The radius of the circle of the same area as the marker
+    /// - `shape`: The shape of the marker
+    /// - `style`: The style of the marker, which is only outlined if it's not filled. The plus
+    ///   and the X are drawn as two lines when they're not filled.
+    pub fn new<T: Into<ShapeStyle>>(
+        coord: Coord,
+        size: Size,
+        shape: MarkerShape,
+        style: T,
+    ) -> Self {
+        Self {
+            center: coord,
+            size,
+            shape,
+            style: style.into(),
+        }
+    }
+}
+
+impl<'a, Coord: 'a, Size: SizeDesc> PointCollection<'a, Coord> for &'a Marker<Coord, Size> {
+    type Borrow = &'a Coord;
+    type IntoIter = std::iter::Once<&'a Coord>;
+    fn point_iter(self) -> std::iter::Once<&'a Coord> {
+        std::iter::once(&self.center)
+    }
+}
+
+impl<Coord, DB: DrawingBackend, Size: SizeDesc> Drawable<DB> for Marker<Coord, Size> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        ps: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (x, y) = match points.next() {
+            Some(point) => point,
+            None => return Ok(()),
+        };
+        let size = self.size.in_pixels(&ps).max(0);
+        let to_backend = |(dx, dy): (f64, f64)| (x + dx.round() as i32, y + dy.round() as i32);
+
+        match self.shape {
+            MarkerShape::Circle => {
+                return Circle::new((x, y), size, self.style.clone()).draw(
+                    std::iter::once((x, y)),
+                    backend,
+                    ps,
+                );
+            }
+            MarkerShape::Plus | MarkerShape::X if !self.style.filled => {
+                // The lines are drawn along the middle of the arms of the thick cross
+                let outline = self.shape.outline(f64::from(size));
+                for &(a, b) in [(1, 7), (4, 10)].iter() {
+                    let mid = |i: usize, j: usize| {
+                        let (p, q) = (outline[i], outline[j]);
+                        ((p.0 + q.0) / 2.0, (p.1 + q.1) / 2.0)
+                    };
+                    let (from, to) = (mid(a, a + 1), mid(b, b + 1));
+                    backend.draw_path(vec![to_backend(from), to_backend(to)], &self.style)?;
+                }
+                return Ok(());
+            }
+            _ => {}
+        }
+
+        let points: Vec<_> = self
+            .shape
+            .outline(f64::from(size))
+            .into_iter()
+            .map(to_backend)
+            .collect();
+        if self.style.filled {
+            super::basic_shapes::draw_polygon(&points, &self.style, backend)
+        } else {
+            let first = points[0];
+            backend.draw_path(
+                points.into_iter().chain(std::iter::once(first)),
+                &self.style,
+            )
+        }
+    }
+}
+
 impl<Coord, Size: SizeDesc> PointElement<Coord, Size> for Cross<Coord, Size> {
     fn make_point(pos: Coord, size: Size, style: ShapeStyle) -> Self {
         Self::new(pos, size, style)
@@ -112,6 +310,12 @@ impl<Coord, Size: SizeDesc> PointElement<Coord, Size> for TriangleMarker<Coord,
     }
 }
 
+impl<Coord, Size: SizeDesc> PointElement<Coord, Size> for Marker<Coord, Size> {
+    fn make_point(pos: Coord, size: Size, style: ShapeStyle) -> Self {
+        Self::new(pos, size, MarkerShape::Circle, style)
+    }
+}
+
 impl<Coord, Size: SizeDesc> PointElement<Coord, Size> for Circle<Coord, Size> {
     fn make_point(pos: Coord, size: Size, style: ShapeStyle) -> Self {
         Self::new(pos, size, style)
@@ -123,3 +327,54 @@ impl<Coord, Size: SizeDesc> PointElement<Coord, Size> for Pixel<Coord> {
         Self::new(pos, style)
     }
 }
+
+#[cfg(test)]
+#[test]
+fn test_marker_shapes() {
+    use crate::prelude::*;
+
+    // All the shapes have the area of the circle of the size
+    let shapes = (0..8)
+        .map(MarkerShape::cycle)
+        .chain(vec![MarkerShape::Star(7)]);
+    for shape in shapes {
+        let outline = shape.outline(10.0);
+        let area = outline
+            .iter()
+            .zip(outline.iter().cycle().skip(1))
+            .map(|(a, b)| a.0 * b.1 - a.1 * b.0)
+            .sum::<f64>()
+            .abs()
+            / 2.0;
+        let expected = if shape == MarkerShape::Circle {
+            // The circle is approximated by a polygon here
+            area
+        } else {
+            std::f64::consts::PI * 100.0
+        };
+        assert!((area - expected).abs() < 1e-6, "{:?} {}", shape, area);
+    }
+    assert_eq!(MarkerShape::cycle(9), MarkerShape::Square);
+
+    let da = crate::create_mocked_drawing_area(300, 300, |m| {
+        m.check_fill_polygon(|c, path| {
+            assert_eq!(c, RED.to_rgba());
+            assert_eq!(path, [(141, 141), (159, 141), (159, 159), (141, 159)]);
+        });
+        m.check_draw_path(|_, _, path| assert_eq!(path, [(150, 137), (150, 163)]));
+        m.check_draw_path(|_, _, path| assert_eq!(path, [(137, 150), (163, 150)]));
+        m.drop_check(|b| {
+            assert_eq!(b.num_fill_polygon_call, 1);
+            assert_eq!(b.num_draw_path_call, 2);
+        });
+    });
+    da.draw(&Marker::new(
+        (150, 150),
+        10,
+        MarkerShape::Square,
+        RED.filled(),
+    ))
+    .expect("Drawing Failure");
+    da.draw(&Marker::new((150, 150), 10, MarkerShape::Plus, &RED))
+        .expect("Drawing Failure");
+}
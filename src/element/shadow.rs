@@ -0,0 +1,175 @@
+/*!
+  The drop shadow decorator, which draws a blurred and offset silhouette of an element beneath
+  it, for the annotation boxes and the pie slices standing out from a busy plot
+*/
+
+use crate::drawing::backend::{BackendCoord, BackendShadow, DrawingBackend, DrawingErrorKind};
+use crate::drawing::rasterizer::{draw_shadow, AlphaMask};
+use crate::element::{Drawable, PointCollection};
+use crate::style::Color;
+
+use std::borrow::Borrow;
+use std::convert::Infallible;
+
+/// An element drawn with a drop shadow beneath it, see `WithShadow::with_shadow`.
+///
+/// The backends which can't draw the shadows themselves, such as the bitmap backend, rasterize
+/// the element into a mask of the size of the backend and blur it, which is fine for a handful
+/// of elements but expensive for thousands of them.
+pub struct Shadowed<E, Coord> {
+    element: E,
+    points: Vec<Coord>,
+    shadow: BackendShadow,
+}
+
+impl<E, Coord> Shadowed<E, Coord> {
+    /// Get the element the shadow is cast by
+    pub fn element(&self) -> &E {
+        &self.element
+    }
+}
+
+/// The trait that decorates an element with a drop shadow. The element must be drawable on any
+/// backend, so the ad-hoc composed elements, which are tied to a backend, can't have a shadow.
+pub trait WithShadow<Coord>: Sized {
+    /// Draw the element with a drop shadow beneath it
+    /// - `offset`: The offset of the shadow from the element in pixels
+    /// - `blur_radius`: The distance in pixels the edges of the shadow are blurred over
+    /// - `color`: The color of the shadow, whose alpha is the opacity of the shadow
+    fn with_shadow<C: Color>(
+        self,
+        offset: BackendCoord,
+        blur_radius: u32,
+        color: &C,
+    ) -> Shadowed<Self, Coord>;
+}
+
+impl<E, Coord> WithShadow<Coord> for E
+where
+    E: Drawable<AlphaMask>,
+    for<'a> &'a E: PointCollection<'a, Coord>,
+    Coord: Clone,
+{
+    fn with_shadow<C: Color>(
+        self,
+        offset: BackendCoord,
+        blur_radius: u32,
+        color: &C,
+    ) -> Shadowed<Self, Coord> {
+        Shadowed {
+            points: self
+                .point_iter()
+                .into_iter()
+                .map(|point| point.borrow().clone())
+                .collect(),
+            element: self,
+            shadow: BackendShadow {
+                offset,
+                blur_radius,
+                color: color.to_rgba(),
+            },
+        }
+    }
+}
+
+impl<'a, E, Coord> PointCollection<'a, Coord> for &'a Shadowed<E, Coord> {
+    type Borrow = &'a Coord;
+    type IntoIter = &'a [Coord];
+    fn point_iter(self) -> &'a [Coord] {
+        &self.points
+    }
+}
+
+/// Turn the error of drawing on the mask into the error of the backend, which is a font error
+/// as the mask can't fail itself
+fn mask_error<E: std::error::Error + Send + Sync>(
+    error: DrawingErrorKind<Infallible>,
+) -> DrawingErrorKind<E> {
+    match error {
+        DrawingErrorKind::DrawingError(error) => match error {},
+        DrawingErrorKind::FontError(error) => DrawingErrorKind::FontError(error),
+    }
+}
+
+impl<E, Coord, DB> Drawable<DB> for Shadowed<E, Coord>
+where
+    E: Drawable<DB> + Drawable<AlphaMask>,
+    DB: DrawingBackend,
+{
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        pos: I,
+        backend: &mut DB,
+        parent_dim: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let points: Vec<_> = pos.collect();
+        if backend.begin_shadow(&self.shadow)? {
+            Drawable::<DB>::draw(&self.element, points.into_iter(), backend, parent_dim)?;
+            return backend.end_shadow();
+        }
+        let mut mask = AlphaMask::for_shadow(backend.get_size(), &self.shadow);
+        Drawable::<AlphaMask>::draw(&self.element, points.iter().copied(), &mut mask, parent_dim)
+            .map_err(mask_error)?;
+        draw_shadow(backend, mask, &self.shadow)?;
+        Drawable::<DB>::draw(&self.element, points.into_iter(), backend, parent_dim)
+    }
+
+    fn draw_clipped<I: Iterator<Item = BackendCoord>>(
+        &self,
+        pos: I,
+        backend: &mut DB,
+        parent_dim: (u32, u32),
+        clip: (BackendCoord, BackendCoord),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let points: Vec<_> = pos.collect();
+        if backend.begin_shadow(&self.shadow)? {
+            Drawable::<DB>::draw_clipped(
+                &self.element,
+                points.into_iter(),
+                backend,
+                parent_dim,
+                clip,
+            )?;
+            return backend.end_shadow();
+        }
+        let mut mask = AlphaMask::for_shadow(backend.get_size(), &self.shadow);
+        Drawable::<AlphaMask>::draw_clipped(
+            &self.element,
+            points.iter().copied(),
+            &mut mask,
+            parent_dim,
+            clip,
+        )
+        .map_err(mask_error)?;
+        draw_shadow(backend, mask, &self.shadow)?;
+        Drawable::<DB>::draw_clipped(&self.element, points.into_iter(), backend, parent_dim, clip)
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_shadowed_element() {
+    use crate::prelude::*;
+
+    let da = crate::create_mocked_drawing_area(100, 100, |m| {
+        // The shadow is drawn first, as the blurred pixels around the offset square
+        m.check_draw_pixel(|c, pos| {
+            assert_eq!(c.rgb(), (0, 0, 0));
+            assert!(c.alpha() > 0.0 && c.alpha() <= 0.5);
+            assert!((10..=40).contains(&pos.0) && (10..=40).contains(&pos.1));
+        });
+        m.check_draw_rect(|c, _, filled, ul, br| {
+            assert_eq!(c, RED.to_rgba());
+            assert!(filled);
+            assert_eq!((ul, br), ((10, 10), (30, 30)));
+        });
+        m.drop_check(|b| {
+            assert_eq!(b.num_draw_rect_call, 1);
+            // The square of 21 by 21 pixels spreads over two pixels around it when blurred
+            assert_eq!(b.num_draw_pixel_call, 25 * 25);
+        });
+    });
+    let rect = Rectangle::new([(10, 10), (30, 30)], RED.filled());
+    da.draw(&rect.with_shadow((6, 6), 2, &BLACK.mix(0.5)))
+        .expect("Drawing Failure");
+}
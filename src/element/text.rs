@@ -3,7 +3,7 @@ use std::i32;
 
 use super::{Drawable, PointCollection};
 use crate::drawing::backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
-use crate::style::{FontDesc, FontResult, LayoutBox, TextStyle};
+use crate::style::{FontDesc, FontResult, HPos, LayoutBox, Pos, TextStyle};
 
 /// A single line text element. This can be owned or borrowed string, dependents on
 /// `String` or `str` moved into.
@@ -16,7 +16,8 @@ pub struct Text<'a, Coord, T: Borrow<str>> {
 impl<'a, Coord, T: Borrow<str>> Text<'a, Coord, T> {
     /// Create a new text element
     /// - `text`: The text for the element
-    /// - `points`: The upper left conner for the text element
+    /// - `points`: The position of the text element, which is the upper left corner unless
+    ///   the style anchors another point of the text to it with `TextStyle::pos`
     /// - `style`: The text style
     /// - Return the newly created text element
     pub fn new<S: Into<TextStyle<'a>>>(text: T, points: Coord, style: S) -> Self {
@@ -44,7 +45,19 @@ impl<'a, Coord: 'a, DB: DrawingBackend, T: Borrow<str>> Drawable<DB> for Text<'a
         _: (u32, u32),
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
         if let Some(a) = points.next() {
-            return backend.draw_text(self.text.borrow(), &self.style.font, a, &self.style.color);
+            let text = self.text.borrow();
+            let (dx, dy) = if self.style.pos == Pos::default() {
+                (0, 0)
+            } else {
+                let size = backend.estimate_text_size(text, &self.style.font)?;
+                self.style.pos.upper_left_offset(size)
+            };
+            return backend.draw_text(
+                text,
+                &self.style.font,
+                (a.0 + dx, a.1 + dy),
+                &self.style.color,
+            );
         }
         Ok(())
     }
@@ -57,6 +70,7 @@ pub struct MultiLineText<'a, Coord, T: Borrow<str>> {
     coord: Coord,
     style: TextStyle<'a>,
     line_height: f64,
+    alignment: HPos,
 }
 
 impl<'a, Coord, T: Borrow<str>> MultiLineText<'a, Coord, T> {
@@ -71,6 +85,7 @@ impl<'a, Coord, T: Borrow<str>> MultiLineText<'a, Coord, T> {
             coord: pos,
             style: style.into(),
             line_height: 1.25,
+            alignment: HPos::Left,
         }
     }
 
@@ -80,6 +95,13 @@ impl<'a, Coord, T: Borrow<str>> MultiLineText<'a, Coord, T> {
         self
     }
 
+    /// Set the horizontal alignment of the lines in the block of the text, which is as wide as
+    /// the widest line
+    pub fn set_alignment(&mut self, alignment: HPos) -> &mut Self {
+        self.alignment = alignment;
+        self
+    }
+
     /// Push a new line into the given multi-line text
     /// `line`: The line to be pushed
     pub fn push_line<L: Into<T>>(&mut self, line: L) {
@@ -90,8 +112,7 @@ impl<'a, Coord, T: Borrow<str>> MultiLineText<'a, Coord, T> {
     pub fn estimate_dimension(&self) -> FontResult<(i32, i32)> {
         let (mut mx, mut my) = (0, 0);
 
-        for ((x, y), t) in self.layout_lines((0, 0)).zip(self.lines.iter()) {
-            let (dx, dy) = self.style.font.box_size(t.borrow())?;
+        for ((x, y), (dx, dy)) in self.layout_lines((0, 0))? {
             mx = mx.max(x + dx as i32);
             my = my.max(y + dy as i32);
         }
@@ -104,54 +125,74 @@ impl<'a, Coord, T: Borrow<str>> MultiLineText<'a, Coord, T> {
         self.coord = coord
     }
 
-    fn layout_lines(&self, (x0, y0): BackendCoord) -> impl Iterator<Item = BackendCoord> {
+    /// Get the upper left corner and the size of each line
+    fn layout_lines(&self, (x0, y0): BackendCoord) -> FontResult<Vec<(BackendCoord, (u32, u32))>> {
         let font_height = self.style.font.get_size();
         let actual_line_height = font_height * self.line_height;
-        (0..self.lines.len() as u32).map(move |idx| {
-            let y = f64::from(y0) + f64::from(idx) * actual_line_height;
-            // TODO: Support text alignment as well, currently everything is left aligned
-            let x = f64::from(x0);
-            (x.round() as i32, y.round() as i32)
-        })
+        let sizes = self
+            .lines
+            .iter()
+            .map(|line| self.style.font.box_size(line.borrow()))
+            .collect::<FontResult<Vec<_>>>()?;
+        let block_width = sizes.iter().map(|size| size.0).max().unwrap_or(0) as i32;
+        Ok(sizes
+            .into_iter()
+            .enumerate()
+            .map(|(idx, (w, h))| {
+                let y = f64::from(y0) + idx as f64 * actual_line_height;
+                let x = match self.alignment {
+                    HPos::Left => x0,
+                    HPos::Center => x0 + (block_width - w as i32) / 2,
+                    HPos::Right => x0 + block_width - w as i32,
+                };
+                ((x, y.round() as i32), (w, h))
+            })
+            .collect())
     }
 }
 
+/// Split the text into lines at the line breaks, and wrap the lines wider than `max_width` at
+/// the spaces. A word wider than `max_width` is broken between its characters.
 fn layout_multiline_text<'a, F: FnMut(&'a str)>(
     text: &'a str,
     max_width: u32,
     font: FontDesc<'a>,
     mut func: F,
 ) {
+    let fits = |text: &str| font.box_size(text).map_or(true, |(w, _)| w <= max_width);
     for line in text.lines() {
         if max_width == 0 || line.is_empty() {
             func(line);
-        } else {
-            let mut remaining = &line[0..];
-
-            while remaining.is_empty() {
-                let mut width = 0;
-                let mut left = 0;
-                while left < remaining.len() {
-                    let char_width =
-                        font.box_size(&remaining[left..=left]).unwrap_or((0, 0)).0 as i32;
-
-                    width += char_width;
-
-                    if width > max_width as i32 {
-                        break;
-                    }
-                    left += 1;
+            continue;
+        }
+        let mut remaining = line;
+        while !remaining.is_empty() {
+            if fits(remaining) {
+                func(remaining);
+                break;
+            }
+            // The longest prefix ending before a space that fits, or else the longest one that
+            // fits, which is at least one character
+            let breaks = remaining
+                .char_indices()
+                .skip(1)
+                .map(|(idx, _)| idx)
+                .chain(std::iter::once(remaining.len()));
+            let mut word_end = None;
+            let mut char_end = None;
+            for end in breaks {
+                if !fits(&remaining[..end]) {
+                    break;
                 }
-
-                if left == 0 {
-                    left += 1;
+                if remaining[end..].starts_with(char::is_whitespace) {
+                    word_end = Some(end);
                 }
-
-                let cur_line = &remaining[..left];
-                remaining = &remaining[left..];
-
-                func(cur_line);
+                char_end = Some(end);
             }
+            let first_char = remaining.chars().next().map_or(0, char::len_utf8);
+            let end = word_end.or(char_end).unwrap_or(first_char);
+            func(remaining[..end].trim_end());
+            remaining = remaining[end..].trim_start();
         }
     }
 }
@@ -160,8 +201,7 @@ impl<'a, T: Borrow<str>> MultiLineText<'a, BackendCoord, T> {
     /// Compute the line layout
     pub fn compute_line_layout(&self) -> FontResult<Vec<LayoutBox>> {
         let mut ret = vec![];
-        for ((x, y), t) in self.layout_lines(self.coord).zip(self.lines.iter()) {
-            let (dx, dy) = self.style.font.box_size(t.borrow())?;
+        for ((x, y), (dx, dy)) in self.layout_lines(self.coord)? {
             ret.push(((x, y), (x + dx as i32, y + dy as i32)));
         }
         Ok(ret)
@@ -237,10 +277,102 @@ impl<'a, Coord: 'a, DB: DrawingBackend, T: Borrow<str>> Drawable<DB>
         _: (u32, u32),
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
         if let Some(a) = points.next() {
-            for (point, text) in self.layout_lines(a).zip(self.lines.iter()) {
+            let (dx, dy) = if self.style.pos == Pos::default() {
+                (0, 0)
+            } else {
+                let (w, h) = self
+                    .estimate_dimension()
+                    .map_err(DrawingErrorKind::FontError)?;
+                self.style.pos.upper_left_offset((w as u32, h as u32))
+            };
+            let layout = self
+                .layout_lines((a.0 + dx, a.1 + dy))
+                .map_err(DrawingErrorKind::FontError)?;
+            for ((point, _), text) in layout.into_iter().zip(self.lines.iter()) {
                 backend.draw_text(text.borrow(), &self.style.font, point, &self.style.color)?;
             }
         }
         Ok(())
     }
 }
+
+#[cfg(test)]
+#[test]
+fn test_text_anchor() {
+    use crate::prelude::*;
+    let style = ("sans-serif", 20).into_font().color(&BLACK);
+    let drawing_area = crate::create_mocked_drawing_area(200, 200, |m| {
+        m.check_draw_text(|_, _, _, pos, text| match text {
+            "a" => assert_eq!(pos, (100, 100)),
+            _ => assert!(pos.0 < 100 && pos.1 < 100),
+        });
+        m.drop_check(|b| assert_eq!(b.num_draw_text_call, 2));
+    });
+    drawing_area
+        .draw(&Text::new("a", (100, 100), style.clone()))
+        .expect("Drawing Error");
+    // The bottom center of the text is at the position
+    let anchored = style.pos(Pos::new(HPos::Center, VPos::Bottom));
+    drawing_area
+        .draw(&Text::new("Hello", (100, 100), anchored))
+        .expect("Drawing Error");
+}
+
+#[cfg(test)]
+#[test]
+fn test_multi_line_text() {
+    use crate::prelude::*;
+    let font = ("sans-serif", 20).into_font();
+    let text = "The quick brown fox jumps over the lazy dog\nsecond line";
+    let (width, _) = font.box_size("The quick brown fox").unwrap();
+    let wrapped = MultiLineText::<_, &str>::from_str(text, (0, 0), font.clone(), width);
+    // The lines are wrapped between the words, and the line breaks are kept
+    assert!(wrapped.lines.len() >= 3);
+    assert_eq!(wrapped.lines[0], "The quick brown fox");
+    assert_eq!(*wrapped.lines.last().unwrap(), "second line");
+    assert!(wrapped.lines.iter().all(|line| !line.starts_with(' ')));
+    assert_eq!(
+        wrapped.lines.concat().replace(' ', ""),
+        text.replace(' ', "").replace('\n', "")
+    );
+
+    // A word wider than the block is broken between the characters, which can be multi-byte
+    let broken = MultiLineText::<_, &str>::from_str("ééééééééééé", (0, 0), font.clone(), 30);
+    assert!(broken.lines.len() > 1);
+    assert_eq!(broken.lines.concat(), "ééééééééééé");
+
+    let mut block = MultiLineText::<_, &str>::new((10, 10), font.clone());
+    block.push_line("a");
+    block.push_line("a much longer line");
+    block.set_alignment(HPos::Right);
+    let layout = block.compute_line_layout().unwrap();
+    assert_eq!((layout[0].1).0, (layout[1].1).0);
+    block.set_alignment(HPos::Center);
+    let layout = block.compute_line_layout().unwrap();
+    assert!((layout[0].0).0 > 10);
+    assert_eq!((layout[1].0).0, 10);
+}
+
+#[cfg(test)]
+#[test]
+fn test_multi_line_caption() {
+    use crate::prelude::*;
+    let drawing_area = crate::create_mocked_drawing_area(300, 200, |m| {
+        m.check_draw_text(|_, _, _, pos, text| match text {
+            "Title" => assert!(pos.1 < 20),
+            "A longer subtitle" => assert!(pos.1 >= 20),
+            _ => {}
+        });
+        m.drop_check(|b| assert_eq!(b.num_draw_text_call, 2));
+    });
+    let font = ("sans-serif", 20).into_font();
+    let (_, h) = drawing_area
+        .estimate_text_size("Title\nA longer subtitle", &font)
+        .unwrap();
+    assert!(h >= 40);
+    let (_, area_h) = drawing_area
+        .titled("Title\nA longer subtitle", font)
+        .unwrap()
+        .dim_in_pixel();
+    assert!(area_h <= 200 - h);
+}
@@ -657,29 +657,50 @@ pub use palette;
 
 /// The module imports the most commonly used types and modules in Plotters
 pub mod prelude {
-    pub use crate::chart::{ChartBuilder, ChartContext, LabelAreaPosition, SeriesLabelPosition};
+    pub use crate::chart::{
+        AxisDescPosition, ChartBuilder, ChartContext, ChartState, ColorbarConfig,
+        ColorbarPosition, InsetConfig, InsetRect, LabelAreaPosition, SeriesLabelOrder,
+        SeriesLabelPosition, SubplotGrid, SubplotGridBuilder, TickMarkDirection,
+    };
     pub use crate::coord::{
-        CoordTranslate, GroupBy, IntoCentric, IntoPartialAxis, LogCoord, LogRange, LogScalable,
-        Ranged, RangedCoord, RangedCoordf32, RangedCoordf64, RangedCoordi32, RangedCoordi64,
-        RangedCoordu32, RangedCoordu64, ToGroupByRange,
+        BrokenRange, Cartesian3d, Categories, CoordTranslate, GroupBy, IntoCentric, IntoExactTicks,
+        IntoLogRange, IntoPartialAxis, IntoReversedAxis, IntoSymlogRange, IntoWithKeyPoints,
+        IntoWithLabelFormatter, LogCoord, LogRange, LogScalable, NestedCategories, Ranged,
+        RangedCoord, RangedCoordf32, RangedCoordf64, RangedCoordi32, RangedCoordi64,
+        RangedCoordu32, RangedCoordu64, RangedStdDuration, ToGroupByRange,
     };
 
     #[cfg(feature = "chrono")]
-    pub use crate::coord::{make_partial_axis, RangedDate, RangedDateTime, RangedDuration};
+    pub use crate::coord::{
+        make_partial_axis, RangedDate, RangedDateTime, RangedDuration, RangedNaiveDate,
+        RangedNaiveDateTime,
+    };
 
+    pub use crate::data::{PaddedRange, Quartiles};
     pub use crate::drawing::*;
-    pub use crate::series::{AreaSeries, Histogram, LineSeries, PointSeries};
+    pub use crate::series::{
+        AreaSeries, BandSeries, BarLabelPosition, BarOrdering, BinnedHistogram, Bins,
+        BoxplotSeries, CandleSeries, ContourSeries, CumulativeSeries, DumbbellSeries,
+        ErrorBarSeries, EventSeries, GappedLineSeries, HeatmapSeries, Hist2DSeries, Histogram,
+        Interpolation, IntervalSeries, LineSeries, LineSeries3d, ParetoSeries, PieSeries,
+        PointLabelPosition, PointSeries, QuiverScale, QuiverSeries, RadarSeries, Rolling,
+        ScatterSeries3d, SizeScale, SmoothLineSeries, StackedAreaSeries, StemSeries,
+        StepLineSeries, StepPosition, SurfaceSeries, TrendLine,
+    };
     pub use crate::style::{
-        AsRelative, Color, FontDesc, FontFamily, FontStyle, FontTransform, HSLColor, IntoFont,
-        Palette, Palette100, Palette99, Palette9999, PaletteColor, RGBColor, ShapeStyle,
-        SimpleColor, TextStyle,
+        AsRelative, Color, ColorMap, FontDesc, FontFamily, FontStyle, FontTransform, HPos,
+        HSLColor, HSVColor, IntoFont, LinearGradient, Palette, Palette100, Palette99, Palette9999,
+        PaletteColor, PaletteOkabeIto, PaletteTab10, Pattern, PatternFill, Pos, RGBColor,
+        RadialGradient, ShapeStyle, SimpleColor, TextStyle, VPos,
     };
     pub use crate::style::{BLACK, BLUE, CYAN, GREEN, MAGENTA, RED, TRANSPARENT, WHITE, YELLOW};
+    pub use crate::style::{COOLWARM, INFERNO, MAGMA, PLASMA, VIRIDIS};
 
     pub use crate::element::{
-        BitMapElement, CandleStick, Circle, Cross, DynElement, EmptyElement, ErrorBar,
-        IntoDynElement, MultiLineText, PathElement, Pixel, Polygon, Rectangle, Text,
-        TriangleMarker,
+        AngleConvention, Arc, Arrow, BezierPath, BitMapElement, BoxedText, Boxplot, CandleStick,
+        Circle, Cross, CubicBezier, DynElement, Ellipse, EmptyElement, ErrorBar, GradientPolygon,
+        IntoDynElement, Marker, MarkerShape, MultiComposed, MultiLineText, PathElement, Pie, Pixel,
+        Polygon, Rectangle, Sector, Shadowed, Text, TriangleMarker, WithShadow,
     };
 
     // TODO: This should be deprecated and completely removed
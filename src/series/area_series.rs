@@ -1,7 +1,7 @@
 use crate::drawing::DrawingBackend;
-use crate::element::{DynElement, IntoDynElement, PathElement, Polygon};
+use crate::element::{DynElement, GradientPolygon, IntoDynElement, PathElement, Polygon};
 use crate::style::colors::TRANSPARENT;
-use crate::style::ShapeStyle;
+use crate::style::{Color, RGBAColor, ShapeStyle};
 
 /// An area series is similar to a line series but use a filled polygon
 pub struct AreaSeries<DB: DrawingBackend, X: Clone, Y: Clone> {
@@ -9,6 +9,7 @@ pub struct AreaSeries<DB: DrawingBackend, X: Clone, Y: Clone> {
     border_style: ShapeStyle,
     baseline: Y,
     data: Vec<(X, Y)>,
+    gradient: Option<((Y, RGBAColor), (Y, RGBAColor))>,
     state: u32,
     _p: std::marker::PhantomData<DB>,
 }
@@ -23,6 +24,7 @@ impl<DB: DrawingBackend, X: Clone, Y: Clone> AreaSeries<DB, X, Y> {
             area_style: area_style.into(),
             baseline,
             data: iter.into_iter().collect(),
+            gradient: None,
             state: 0,
             border_style: (&TRANSPARENT).into(),
             _p: std::marker::PhantomData,
@@ -35,6 +37,25 @@ impl<DB: DrawingBackend, X: Clone, Y: Clone> AreaSeries<DB, X, Y> {
     }
 }
 
+impl<DB: DrawingBackend, X: Clone, Y: Clone + PartialOrd> AreaSeries<DB, X, Y> {
+    /// Fill the area with a vertical linear gradient instead of the area style. The gradient
+    /// goes from `top_color` at the maximum Y value of the series down to `bottom_color` at
+    /// the baseline, so it looks the same whatever the size of the chart is.
+    pub fn gradient_fill<T: Color, B: Color>(mut self, top_color: &T, bottom_color: &B) -> Self {
+        let top = self
+            .data
+            .iter()
+            .map(|(_, y)| y)
+            .fold(&self.baseline, |top, y| if y > top { y } else { top })
+            .clone();
+        self.gradient = Some((
+            (top, top_color.to_rgba()),
+            (self.baseline.clone(), bottom_color.to_rgba()),
+        ));
+        self
+    }
+}
+
 impl<DB: DrawingBackend, X: Clone + 'static, Y: Clone + 'static> Iterator for AreaSeries<DB, X, Y> {
     type Item = DynElement<'static, DB, (X, Y)>;
     fn next(&mut self) -> Option<Self::Item> {
@@ -48,7 +69,16 @@ impl<DB: DrawingBackend, X: Clone + 'static, Y: Clone + 'static> Iterator for Ar
 
             self.state = 1;
 
-            Some(Polygon::new(data, self.area_style.clone()).into_dyn())
+            match (&self.gradient, data.first()) {
+                (Some(((top, top_color), (bottom, bottom_color))), Some((x, _))) => {
+                    let (top, bottom) = ((x.clone(), top.clone()), (x.clone(), bottom.clone()));
+                    Some(
+                        GradientPolygon::new(data, (top, top_color), (bottom, bottom_color))
+                            .into_dyn(),
+                    )
+                }
+                _ => Some(Polygon::new(data, self.area_style.clone()).into_dyn()),
+            }
         } else if self.state == 1 {
             let data: Vec<_> = self.data.clone();
 
@@ -60,3 +90,56 @@ impl<DB: DrawingBackend, X: Clone + 'static, Y: Clone + 'static> Iterator for Ar
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_area_series_gradient_fill() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            // The gradient starts at the top of the series, which is halfway up the chart
+            m.check_draw_line(|c, _, from, _| {
+                assert_eq!(from.1, 100);
+                assert_eq!(c, RED.to_rgba());
+            });
+            m.check_draw_line(|c, _, from, _| {
+                assert!(from.1 > 100 && from.1 < 200);
+                assert!(c.rgb().0 < 255 && c.rgb().2 > 0);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_fill_polygon_call, 0);
+                assert_eq!(b.num_draw_line_call, 100);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0..2, 0..2)
+            .expect("Build chart error");
+
+        let data = vec![(0, 1), (1, 1), (2, 1)];
+        chart
+            .draw_series(AreaSeries::new(data, 0, &RED).gradient_fill(&RED, &BLUE))
+            .expect("Drawing Error");
+    }
+
+    #[test]
+    fn test_area_series_reversed_axis() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            // The baseline is at the top of the chart when the Y axis is reversed
+            m.check_fill_polygon(|_, path| {
+                assert_eq!(path, [(0, 50), (100, 150), (200, 50), (200, 0), (0, 0)]);
+            });
+            m.drop_check(|b| assert_eq!(b.num_fill_polygon_call, 1));
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0..2, (0..4).reversed())
+            .expect("Build chart error");
+
+        let data = vec![(0, 1), (1, 3), (2, 1)];
+        chart
+            .draw_series(AreaSeries::new(data, 0, &RED.mix(0.5)))
+            .expect("Drawing Error");
+    }
+}
@@ -0,0 +1,130 @@
+use crate::drawing::DrawingBackend;
+use crate::element::{DynElement, IntoDynElement, PathElement, Polygon};
+use crate::style::ShapeStyle;
+
+/// A band series fills the area between a lower and an upper curve, which is typically used
+/// to show the confidence interval around a line series
+pub struct BandSeries<DB: DrawingBackend, X: Clone, Y: Clone> {
+    area_style: ShapeStyle,
+    lower_style: Option<ShapeStyle>,
+    upper_style: Option<ShapeStyle>,
+    data: Vec<(X, Y, Y)>,
+    state: u32,
+    _p: std::marker::PhantomData<DB>,
+}
+
+impl<DB: DrawingBackend, X: Clone, Y: Clone + PartialOrd> BandSeries<DB, X, Y> {
+    /// Create a new band series
+    /// - `iter`: The iterator of `(x, y_low, y_high)` tuples. Where the curves cross, the
+    ///   area between them is still filled.
+    /// - `area_style`: The style of the filled area
+    pub fn new<S: Into<ShapeStyle>, I: IntoIterator<Item = (X, Y, Y)>>(
+        iter: I,
+        area_style: S,
+    ) -> Self {
+        Self {
+            area_style: area_style.into(),
+            lower_style: None,
+            upper_style: None,
+            data: iter.into_iter().collect(),
+            state: 0,
+            _p: std::marker::PhantomData,
+        }
+    }
+
+    /// Stroke the lower curve with the given style
+    pub fn lower_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.lower_style = Some(style.into());
+        self
+    }
+
+    /// Stroke the upper curve with the given style
+    pub fn upper_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.upper_style = Some(style.into());
+        self
+    }
+
+    fn boundary(&self, upper: bool) -> Vec<(X, Y)> {
+        self.data
+            .iter()
+            .map(|(x, lo, hi)| (x.clone(), if upper { hi } else { lo }.clone()))
+            .collect()
+    }
+}
+
+impl<DB: DrawingBackend, X: Clone + 'static, Y: Clone + PartialOrd + 'static> Iterator
+    for BandSeries<DB, X, Y>
+{
+    type Item = DynElement<'static, DB, (X, Y)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.data.is_empty() {
+            return None;
+        }
+        loop {
+            self.state += 1;
+            match self.state {
+                1 => {
+                    // The curves are swapped where they cross, so the polygon never
+                    // intersects itself
+                    let (mut lower, mut upper): (Vec<_>, Vec<_>) = self
+                        .data
+                        .iter()
+                        .map(|(x, lo, hi)| {
+                            let (lo, hi) = if lo <= hi { (lo, hi) } else { (hi, lo) };
+                            ((x.clone(), lo.clone()), (x.clone(), hi.clone()))
+                        })
+                        .unzip();
+                    upper.reverse();
+                    lower.extend(upper);
+                    return Some(Polygon::new(lower, self.area_style.clone()).into_dyn());
+                }
+                2 => {
+                    if let Some(style) = self.lower_style.clone() {
+                        return Some(PathElement::new(self.boundary(false), style).into_dyn());
+                    }
+                }
+                3 => {
+                    if let Some(style) = self.upper_style.clone() {
+                        return Some(PathElement::new(self.boundary(true), style).into_dyn());
+                    }
+                }
+                _ => return None,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_band_series() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_fill_polygon(|c, path| {
+                assert_eq!(c, BLUE.to_rgba());
+                assert_eq!(path, [(0, 150), (100, 150), (100, 100), (0, 50)]);
+            });
+            m.check_draw_path(|c, _, path| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!(path, [(0, 150), (100, 100)]);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_fill_polygon_call, 1);
+                assert_eq!(b.num_draw_path_call, 1);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0..2, 0..4)
+            .expect("Build chart error");
+
+        // The curves cross between the two points
+        chart
+            .draw_series(BandSeries::new(vec![(0, 3, 1), (1, 1, 2)], &BLUE).upper_style(&RED))
+            .expect("Drawing Error");
+        chart
+            .draw_series(BandSeries::new(Vec::<(i32, i32, i32)>::new(), &BLUE))
+            .expect("Drawing Error");
+    }
+}
@@ -0,0 +1,358 @@
+use std::ops::AddAssign;
+
+use super::histogram::HistogramBar;
+use crate::data::Quartiles;
+use crate::element::Rectangle;
+use crate::style::{Color, ShapeStyle, GREEN};
+
+/// Describes how the raw samples of a binned histogram are divided into bins. There are never
+/// more than `Bins::MAX_COUNT` bins.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Bins {
+    /// Divide the sample range into the given number of bins
+    Count(usize),
+    /// Use bins of the given width, starting from the smallest sample. The bins are widened if
+    /// there would be more than `Bins::MAX_COUNT`, and a width which isn't finite and positive gives
+    /// a single bin.
+    Width(f64),
+    /// Pick the bin width with the Freedman–Diaconis rule, falling back to Sturges' formula
+    /// when the interquartile range is zero or the rule gives more than `Bins::MAX_COUNT` bins
+    Auto,
+}
+
+impl Bins {
+    /// The most bins the samples are divided into, so an outlier or a tiny bin width doesn't
+    /// make a bin for each step of the width
+    pub const MAX_COUNT: usize = 10_000;
+}
+
+type StyleFunc<'a, A, W> = Box<dyn Fn(&A, &W) -> ShapeStyle + 'a>;
+
+/// The series that counts raw samples into bins on a continuous axis.
+///
+/// Each bar spans from the lower edge of its bin to the upper edge, so the series can be
+/// drawn on a regular numeric coordinate. The bins are half-open intervals, except for the last
+/// one, which also includes its upper edge.
+pub struct BinnedHistogram<'a, A, W = u32> {
+    style: StyleFunc<'a, A, W>,
+    margin: u32,
+    edges: Vec<A>,
+    samples: Vec<A>,
+    weight: Box<dyn Fn(&A) -> W + 'a>,
+    clamp: bool,
+    iter: std::vec::IntoIter<(A, A, W)>,
+    non_finite: usize,
+    skipped: usize,
+}
+
+impl<'a> BinnedHistogram<'a, f64, u32> {
+    /// Create a histogram series from raw samples.
+    ///
+    /// - `samples`: The raw samples, NaN and infinite values are skipped
+    /// - `bins`: How the sample range is divided into bins
+    ///
+    /// The largest sample is always counted in the last bin. An empty input produces no bars.
+    pub fn from_samples<I: IntoIterator<Item = f64>>(samples: I, bins: Bins) -> Self {
+        let mut non_finite = 0;
+        let mut samples: Vec<f64> = samples
+            .into_iter()
+            .filter(|x| {
+                if x.is_finite() {
+                    return true;
+                }
+                non_finite += 1;
+                false
+            })
+            .collect();
+        samples.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        let mut edges = vec![];
+        if let (Some(&min), Some(&max)) = (samples.first(), samples.last()) {
+            let range = max - min;
+            let n = samples.len() as f64;
+            let sturges = || {
+                let count = n.log2().ceil() as usize + 1;
+                (count, range / count as f64)
+            };
+            let (count, width) = match bins {
+                Bins::Count(n) => {
+                    let n = n.clamp(1, Bins::MAX_COUNT);
+                    (n, range / n as f64)
+                }
+                Bins::Width(w) if w.is_finite() && w > 0.0 => {
+                    let count = (range / w).ceil();
+                    if count > Bins::MAX_COUNT as f64 {
+                        (Bins::MAX_COUNT, range / Bins::MAX_COUNT as f64)
+                    } else {
+                        ((count as usize).max(1), w)
+                    }
+                }
+                Bins::Width(_) => (1, range),
+                Bins::Auto => {
+                    let quartiles = Quartiles::new(&samples).values();
+                    let iqr = quartiles[3] - quartiles[1];
+                    let width = 2.0 * iqr / n.cbrt();
+                    let count = (range / width).ceil();
+                    if iqr > 0.0 && count <= Bins::MAX_COUNT as f64 {
+                        ((count as usize).max(1), width)
+                    } else {
+                        sturges()
+                    }
+                }
+            };
+            // All the samples are the same, so we just use a unit bin
+            let width = if width > 0.0 { width } else { 1.0 };
+
+            edges = (0..count).map(|idx| min + width * idx as f64).collect();
+            // Make sure the rounding error doesn't push the largest sample out of the last bin
+            edges.push((min + width * count as f64).max(max));
+        }
+
+        let mut ret = Self::with_edges(edges, samples);
+        ret.non_finite = non_finite;
+        ret.skipped += non_finite;
+        ret
+    }
+}
+
+impl<'a, A: PartialOrd + Clone + 'a> BinnedHistogram<'a, A, u32> {
+    /// Create a histogram series with explicit bin edges.
+    ///
+    /// - `edges`: The edges of the bins in ascending order, consecutive edges define a bin
+    /// - `samples`: The raw samples
+    ///
+    /// By default, samples outside of the edges are dropped. Use `clamp_out_of_range` to count
+    /// them into the first or last bin instead.
+    pub fn with_edges<I: IntoIterator<Item = A>>(edges: Vec<A>, samples: I) -> Self {
+        let mut ret = Self {
+            style: Box::new(|_, _| GREEN.filled()),
+            margin: 0,
+            edges,
+            samples: samples.into_iter().collect(),
+            weight: Box::new(|_| 1),
+            clamp: false,
+            iter: vec![].into_iter(),
+            non_finite: 0,
+            skipped: 0,
+        };
+        ret.rebin();
+        ret
+    }
+}
+
+impl<'a, A: PartialOrd + Clone + 'a, W: AddAssign<W> + Default + 'a> BinnedHistogram<'a, A, W> {
+    /// Set the function that gives the weight of each sample, which makes the height of each
+    /// bar the sum of the weights in the bin instead of the number of samples.
+    ///
+    /// This resets the style of the histogram, since the style function depends on the weight
+    /// type.
+    pub fn weights<W2: AddAssign<W2> + Default + 'a>(
+        self,
+        weight: impl Fn(&A) -> W2 + 'a,
+    ) -> BinnedHistogram<'a, A, W2> {
+        let mut ret = BinnedHistogram {
+            style: Box::new(|_, _| GREEN.filled()),
+            margin: self.margin,
+            edges: self.edges,
+            samples: self.samples,
+            weight: Box::new(weight),
+            clamp: self.clamp,
+            iter: vec![].into_iter(),
+            non_finite: self.non_finite,
+            skipped: 0,
+        };
+        ret.rebin();
+        ret
+    }
+
+    /// Set if the samples outside of the edges are counted into the first or last bin instead
+    /// of being dropped
+    pub fn clamp_out_of_range(mut self, clamp: bool) -> Self {
+        self.clamp = clamp;
+        self.rebin();
+        self
+    }
+
+    fn rebin(&mut self) {
+        self.skipped = self.non_finite;
+        let count = self.edges.len().saturating_sub(1);
+        if count == 0 {
+            self.skipped += self.samples.len();
+            self.iter = vec![].into_iter();
+            return;
+        }
+
+        let mut values: Vec<W> = (0..count).map(|_| W::default()).collect();
+        let last = &self.edges[count];
+        for x in self.samples.iter() {
+            if x.partial_cmp(&self.edges[0]).is_none() {
+                self.skipped += 1;
+                continue;
+            }
+            // The number of edges that are less than or equal to the sample
+            let idx = self.edges.partition_point(|e| e <= x);
+            let bin = if idx == 0 {
+                if !self.clamp {
+                    self.skipped += 1;
+                    continue;
+                }
+                0
+            } else if idx > count {
+                if x != last && !self.clamp {
+                    self.skipped += 1;
+                    continue;
+                }
+                count - 1
+            } else {
+                idx - 1
+            };
+            values[bin] += (self.weight)(x);
+        }
+
+        self.iter = values
+            .into_iter()
+            .enumerate()
+            .map(|(idx, w)| (self.edges[idx].clone(), self.edges[idx + 1].clone(), w))
+            .collect::<Vec<_>>()
+            .into_iter();
+    }
+}
+
+impl<'a, A, W> BinnedHistogram<'a, A, W> {
+    /// Set the style of the histogram
+    pub fn style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        let style = style.into();
+        self.style = Box::new(move |_, _| style.clone());
+        self
+    }
+
+    /// Set the style of histogram using a lambda function, which takes the lower edge of the
+    /// bin and its count
+    pub fn style_func(mut self, style_func: impl Fn(&A, &W) -> ShapeStyle + 'a) -> Self {
+        self.style = Box::new(style_func);
+        self
+    }
+
+    /// Set the margin for each bar
+    pub fn margin(mut self, value: u32) -> Self {
+        self.margin = value;
+        self
+    }
+
+    /// Get the number of samples that were skipped, either because they were NaN or infinite, or
+    /// because they were out of the range of the bins
+    pub fn skipped(&self) -> usize {
+        self.skipped
+    }
+}
+
+impl<'a, A, W: Default> Iterator for BinnedHistogram<'a, A, W> {
+    type Item = HistogramBar<'a, (A, W)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some((lower, upper, count)) = self.iter.next() {
+            let style = (self.style)(&lower, &count);
+            let mut rect = Rectangle::new([(lower, count), (upper, W::default())], style);
+            rect.set_margin(0, 0, self.margin, self.margin);
+            return Some(HistogramBar::new(rect, false));
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::element::PointCollection;
+
+    fn collect(hist: BinnedHistogram<f64, u32>) -> Vec<(f64, f64, u32)> {
+        hist.map(|bar| {
+            let points = bar.point_iter();
+            (points[0].0, points[1].0, points[0].1)
+        })
+        .collect()
+    }
+
+    #[test]
+    fn test_binned_histogram() {
+        let samples = vec![0.0, 1.0, 2.0, 3.0, 4.0, f64::NAN];
+
+        let hist = BinnedHistogram::from_samples(samples.clone(), Bins::Count(2));
+        assert_eq!(hist.skipped(), 1);
+        assert_eq!(collect(hist), [(0.0, 2.0, 2), (2.0, 4.0, 3)]);
+
+        let hist = BinnedHistogram::from_samples(samples, Bins::Width(1.0));
+        assert_eq!(
+            collect(hist).into_iter().map(|b| b.2).collect::<Vec<_>>(),
+            [1, 1, 1, 2]
+        );
+
+        let hist = BinnedHistogram::from_samples(vec![], Bins::Auto);
+        assert_eq!(collect(hist), []);
+
+        let samples: Vec<_> = (0..100).map(|x| f64::from(x % 17)).collect();
+        let hist = BinnedHistogram::from_samples(samples, Bins::Auto);
+        assert_eq!(collect(hist).into_iter().map(|b| b.2).sum::<u32>(), 100);
+
+        let hist = BinnedHistogram::from_samples(vec![5.0, 5.0], Bins::Count(3));
+        assert_eq!(collect(hist)[0], (5.0, 6.0, 2));
+    }
+
+    #[test]
+    fn test_binned_histogram_bin_limit() {
+        // The outlier makes the Freedman–Diaconis width tiny next to the range, so Sturges'
+        // formula is used instead
+        let samples: Vec<_> = [0.0; 10]
+            .iter()
+            .chain([1.0; 10].iter())
+            .chain([1e12].iter())
+            .copied()
+            .collect();
+        let bars = collect(BinnedHistogram::from_samples(samples, Bins::Auto));
+        assert_eq!(bars.len(), 6);
+        assert_eq!(bars.iter().map(|b| b.2).sum::<u32>(), 21);
+
+        let samples = vec![0.0, 1.0, 1e-12];
+        let bars = collect(BinnedHistogram::from_samples(
+            samples.clone(),
+            Bins::Width(1e-30),
+        ));
+        assert_eq!(bars.len(), Bins::MAX_COUNT);
+        assert_eq!(bars.iter().map(|b| b.2).sum::<u32>(), 3);
+        let bars = collect(BinnedHistogram::from_samples(
+            samples.clone(),
+            Bins::Count(1 << 40),
+        ));
+        assert_eq!(bars.len(), Bins::MAX_COUNT);
+
+        for &width in [f64::NAN, f64::INFINITY, -1.0].iter() {
+            let bars = collect(BinnedHistogram::from_samples(
+                samples.clone(),
+                Bins::Width(width),
+            ));
+            assert_eq!(bars, [(0.0, 1.0, 3)]);
+        }
+    }
+
+    #[test]
+    fn test_histogram_with_edges() {
+        let edges = vec![0, 1, 2, 5, 10];
+        let samples = vec![-1, 0, 1, 3, 4, 5, 10, 20];
+
+        let hist = BinnedHistogram::with_edges(edges.clone(), samples.clone());
+        assert_eq!(hist.skipped(), 2);
+        let bars: Vec<_> = hist
+            .map(|bar| {
+                let points = bar.point_iter();
+                (points[0].0, points[1].0, points[0].1)
+            })
+            .collect();
+        assert_eq!(bars, [(0, 1, 1), (1, 2, 1), (2, 5, 2), (5, 10, 2)]);
+
+        let hist = BinnedHistogram::with_edges(edges, samples)
+            .clamp_out_of_range(true)
+            .weights(|x| f64::from(*x) / 2.0);
+        assert_eq!(hist.skipped(), 0);
+        let heights: Vec<_> = hist.map(|bar| bar.point_iter()[0].1).collect();
+        assert_eq!(heights, [-0.5, 0.5, 3.5, 17.5]);
+    }
+}
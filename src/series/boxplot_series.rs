@@ -0,0 +1,182 @@
+use std::marker::PhantomData;
+
+use super::histogram::{HistogramType, Horizontal, Vertical};
+use crate::chart::ChartContext;
+use crate::coord::{DiscreteRanged, Ranged, RangedCoord};
+use crate::data::Quartiles;
+use crate::drawing::DrawingBackend;
+use crate::element::{Boxplot, BoxplotOrientH, BoxplotOrientV};
+use crate::style::{ShapeStyle, BLACK};
+
+/// The series that draws a boxplot for each category of a discrete axis
+pub struct BoxplotSeries<BR, Tag = Vertical>
+where
+    BR: DiscreteRanged,
+    BR::ValueType: Eq,
+    Tag: HistogramType,
+{
+    data: std::vec::IntoIter<(BR::ValueType, Quartiles)>,
+    width_fraction: f64,
+    box_style: ShapeStyle,
+    whisker_style: ShapeStyle,
+    outlier_style: ShapeStyle,
+    _p: PhantomData<(BR, Tag)>,
+}
+
+impl<BR, Tag> BoxplotSeries<BR, Tag>
+where
+    BR: DiscreteRanged,
+    BR::ValueType: Eq,
+    Tag: HistogramType,
+{
+    fn empty() -> Self {
+        Self {
+            data: vec![].into_iter(),
+            width_fraction: 0.5,
+            box_style: (&BLACK).into(),
+            whisker_style: (&BLACK).into(),
+            outlier_style: (&BLACK).into(),
+            _p: PhantomData,
+        }
+    }
+
+    /// Set the width of each box as a fraction of the slot of its category
+    pub fn width_fraction(mut self, fraction: f64) -> Self {
+        self.width_fraction = fraction.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Set the style of the boxes
+    pub fn box_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.box_style = style.into();
+        self
+    }
+
+    /// Set the style of the whiskers and the median lines
+    pub fn whisker_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.whisker_style = style.into();
+        self
+    }
+
+    /// Set the style of the outliers
+    pub fn outlier_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.outlier_style = style.into();
+        self
+    }
+
+    /// Set the data iterator, which yields the quartiles of each category
+    pub fn data<I: IntoIterator<Item = (BR::ValueType, Quartiles)>>(mut self, iter: I) -> Self {
+        self.data = iter.into_iter().collect::<Vec<_>>().into_iter();
+        self
+    }
+}
+
+impl<BR> BoxplotSeries<BR, Vertical>
+where
+    BR: DiscreteRanged,
+    BR::ValueType: Eq,
+{
+    /// Create a series of vertical boxplots, which have the categories on the X axis
+    pub fn vertical<ACoord, DB: DrawingBackend>(
+        _: &ChartContext<DB, RangedCoord<BR, ACoord>>,
+    ) -> Self
+    where
+        ACoord: Ranged<ValueType = f64>,
+    {
+        Self::empty()
+    }
+}
+
+impl<BR> BoxplotSeries<BR, Horizontal>
+where
+    BR: DiscreteRanged,
+    BR::ValueType: Eq,
+{
+    /// Create a series of horizontal boxplots, which have the categories on the Y axis
+    pub fn horizontal<ACoord, DB: DrawingBackend>(
+        _: &ChartContext<DB, RangedCoord<ACoord, BR>>,
+    ) -> Self
+    where
+        ACoord: Ranged<ValueType = f64>,
+    {
+        Self::empty()
+    }
+}
+
+impl<BR> Iterator for BoxplotSeries<BR, Vertical>
+where
+    BR: DiscreteRanged,
+    BR::ValueType: Eq,
+{
+    type Item = Boxplot<BR::ValueType, BoxplotOrientV<BR::ValueType>>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let (key, quartiles) = self.data.next()?;
+        let (prev_key, next_key) = (BR::previous_value(&key), BR::next_value(&key));
+        Some(
+            Boxplot::new_vertical(key, &quartiles)
+                .width_fraction(prev_key, next_key, self.width_fraction)
+                .box_style(self.box_style.clone())
+                .whisker_style(self.whisker_style.clone())
+                .outlier_style(self.outlier_style.clone()),
+        )
+    }
+}
+
+impl<BR> Iterator for BoxplotSeries<BR, Horizontal>
+where
+    BR: DiscreteRanged,
+    BR::ValueType: Eq,
+{
+    type Item = Boxplot<BR::ValueType, BoxplotOrientH<BR::ValueType>>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let (key, quartiles) = self.data.next()?;
+        let (prev_key, next_key) = (BR::previous_value(&key), BR::next_value(&key));
+        Some(
+            Boxplot::new_horizontal(key, &quartiles)
+                .width_fraction(prev_key, next_key, self.width_fraction)
+                .box_style(self.box_style.clone())
+                .whisker_style(self.whisker_style.clone())
+                .outlier_style(self.outlier_style.clone()),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_boxplot_series() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            for &x in [50, 150].iter() {
+                m.check_draw_rect(move |c, _, f, u, d| {
+                    assert_eq!(c, RED.to_rgba());
+                    assert!(f);
+                    assert_eq!(u.0 + d.0, 2 * x);
+                    assert_eq!(d.0 - u.0, 50);
+                });
+            }
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 2);
+                assert_eq!(b.num_draw_line_call, 10);
+                assert_eq!(b.num_draw_circle_call, 1);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged((0..2).into_centric(), 0.0..10.0)
+            .expect("Build chart error");
+
+        let data = vec![
+            (1, Quartiles::new(&[1.0, 2.0, 3.0, 4.0, 5.0])),
+            (2, Quartiles::with_fences(&[1.0, 2.0, 3.0, 4.0, 9.5], 1.5)),
+        ];
+        chart
+            .draw_series(
+                BoxplotSeries::vertical(&chart)
+                    .box_style(RED.filled())
+                    .data(data),
+            )
+            .expect("Drawing Error");
+    }
+}
@@ -0,0 +1,165 @@
+use crate::chart::ChartContext;
+use crate::coord::{Ranged, RangedCoord};
+use crate::drawing::DrawingBackend;
+use crate::element::CandleStick;
+use crate::style::ShapeStyle;
+
+/// The series that renders OHLC data as candlesticks
+pub struct CandleSeries<X, Y: PartialOrd> {
+    data: Vec<(X, Y, Y, Y, Y)>,
+    idx: usize,
+    gain_style: ShapeStyle,
+    loss_style: ShapeStyle,
+    width: u32,
+    widths: Vec<Option<u32>>,
+}
+
+impl<X: Clone, Y: PartialOrd + Clone> CandleSeries<X, Y> {
+    /// Create a new candlestick series
+    /// - `iter`: The iterator of `(x, open, high, low, close)` tuples
+    /// - `gain_style`: The style of the candlesticks that close higher than they open
+    /// - `loss_style`: The style of the other candlesticks
+    pub fn new<
+        I: IntoIterator<Item = (X, Y, Y, Y, Y)>,
+        GS: Into<ShapeStyle>,
+        LS: Into<ShapeStyle>,
+    >(
+        iter: I,
+        gain_style: GS,
+        loss_style: LS,
+    ) -> Self {
+        Self {
+            data: iter.into_iter().collect(),
+            idx: 0,
+            gain_style: gain_style.into(),
+            loss_style: loss_style.into(),
+            width: 5,
+            widths: vec![],
+        }
+    }
+
+    /// Set the width of the bodies in pixels
+    pub fn width(mut self, width: u32) -> Self {
+        self.width = width;
+        self.widths.clear();
+        self
+    }
+
+    /// Set the width of the bodies as a fraction of the distance between adjacent candlesticks
+    /// on the chart. The distances are measured in pixels on the chart, so the candlesticks at
+    /// its edges get the same width as the others. A single candlestick keeps the width in pixels.
+    /// - `chart`: The chart the series is drawn on
+    /// - `fraction`: The fraction of the distance, in `[0, 1]`
+    pub fn width_fraction<XR, YR, DB: DrawingBackend>(
+        mut self,
+        chart: &ChartContext<DB, RangedCoord<XR, YR>>,
+        fraction: f64,
+    ) -> Self
+    where
+        XR: Ranged<ValueType = X>,
+        YR: Ranged<ValueType = Y>,
+    {
+        let fraction = fraction.clamp(0.0, 1.0);
+        let pixels: Vec<_> = self
+            .data
+            .iter()
+            .map(|d| chart.backend_coord(&(d.0.clone(), d.1.clone())).0)
+            .collect();
+        self.widths = (0..pixels.len())
+            .map(|idx| {
+                let neighbor = pixels
+                    .get(idx + 1)
+                    .or_else(|| pixels.get(idx.wrapping_sub(1)))?;
+                Some((f64::from((neighbor - pixels[idx]).abs()) * fraction).round() as u32)
+            })
+            .collect();
+        self
+    }
+}
+
+impl<X: Clone, Y: PartialOrd + Clone> Iterator for CandleSeries<X, Y> {
+    type Item = CandleStick<X, Y>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let (x, open, high, low, close) = self.data.get(self.idx)?.clone();
+        let candle = CandleStick::new(
+            x,
+            open,
+            high,
+            low,
+            close,
+            self.gain_style.clone(),
+            self.loss_style.clone(),
+            self.widths
+                .get(self.idx)
+                .copied()
+                .flatten()
+                .unwrap_or(self.width),
+        );
+        self.idx += 1;
+        Some(candle)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_candle_series() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            for &(color, x) in [(0, 50), (1, 100), (1, 150)].iter() {
+                m.check_draw_rect(move |c, _, _, u, d| {
+                    let expected = if color == 0 { GREEN } else { RED };
+                    assert_eq!(c, expected.to_rgba());
+                    assert_eq!(d.0 - u.0, 25);
+                    assert_eq!((u.0 + d.0) / 2, x);
+                });
+            }
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 3);
+                assert_eq!(b.num_draw_line_call, 6);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0.0..4.0, 0.0..10.0)
+            .expect("Build chart error");
+
+        let series = CandleSeries::new(
+            vec![
+                (1.0, 2.0, 8.0, 1.0, 5.0),
+                (2.0, 5.0, 6.0, 3.0, 4.0),
+                (3.0, 4.0, 9.0, 4.0, 4.0),
+            ],
+            GREEN.filled(),
+            RED.filled(),
+        )
+        .width_fraction(&chart, 0.5);
+        chart.draw_series(series).expect("Drawing Error");
+    }
+
+    #[test]
+    fn test_candle_series_at_the_edges() {
+        // The candlesticks at the edges have their neighbors outside of the chart, which are
+        // either clamped or left out
+        for &(policy, drawn) in [(OutOfRange::Clamp, 5), (OutOfRange::Clip, 3)].iter() {
+            let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+                m.check_draw_rect(|_, _, _, u, d| assert_eq!(d.0 - u.0, 50));
+                m.drop_check(move |b| assert_eq!(b.num_draw_rect_call, drawn));
+            });
+
+            let mut chart = ChartBuilder::on(&drawing_area)
+                .build_ranged(1.0..3.0, 0.0..10.0)
+                .expect("Build chart error");
+            chart.set_out_of_range(policy);
+
+            let series = CandleSeries::new(
+                (0..5).map(|x| (f64::from(x), 2.0, 8.0, 1.0, 5.0)),
+                GREEN.filled(),
+                RED.filled(),
+            )
+            .width_fraction(&chart, 0.5);
+            chart.draw_series(series).expect("Drawing Error");
+        }
+    }
+}
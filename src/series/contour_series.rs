@@ -0,0 +1,389 @@
+use std::collections::HashMap;
+use std::ops::Range;
+
+use crate::chart::ChartContext;
+use crate::coord::{Ranged, RangedCoord};
+use crate::drawing::DrawingBackend;
+use crate::element::{DynElement, IntoDynElement, PathElement, Polygon};
+use crate::style::{ShapeStyle, BLACK};
+
+type FieldFunc<'a> = Box<dyn Fn(f64, f64) -> f64 + 'a>;
+type LevelStyleFunc<'a> = Box<dyn Fn(f64) -> ShapeStyle + 'a>;
+type BandStyleFunc<'a> = Box<dyn Fn(f64, f64) -> ShapeStyle + 'a>;
+
+/// Identifies an edge of the sampling grid, which is either the horizontal edge to the right
+/// of the sample `(i, j)` or the vertical edge above it
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum Edge {
+    Horizontal(usize, usize),
+    Vertical(usize, usize),
+}
+
+/// The scalar field sampled on a regular grid
+struct Grid {
+    x: Range<f64>,
+    y: Range<f64>,
+    size: (usize, usize),
+    values: Vec<f64>,
+}
+
+impl Grid {
+    fn value(&self, i: usize, j: usize) -> f64 {
+        self.values[j * (self.size.0 + 1) + i]
+    }
+
+    fn position(&self, i: f64, j: f64) -> (f64, f64) {
+        (
+            self.x.start + (self.x.end - self.x.start) * i / self.size.0 as f64,
+            self.y.start + (self.y.end - self.y.start) * j / self.size.1 as f64,
+        )
+    }
+
+    /// Get the point where the level crosses the edge, if it does
+    fn crossing(&self, edge: Edge, level: f64) -> Option<(f64, f64)> {
+        let ((i0, j0), (i1, j1)) = match edge {
+            Edge::Horizontal(i, j) => ((i, j), (i + 1, j)),
+            Edge::Vertical(i, j) => ((i, j), (i, j + 1)),
+        };
+        let (a, b) = (self.value(i0, j0), self.value(i1, j1));
+        if (a >= level) == (b >= level) {
+            return None;
+        }
+        let t = (level - a) / (b - a);
+        Some(self.position(
+            i0 as f64 + (i1 - i0) as f64 * t,
+            j0 as f64 + (j1 - j0) as f64 * t,
+        ))
+    }
+
+    /// Get the segments of the contour line of the level in the cell with the lower left
+    /// sample `(i, j)`, using marching squares
+    fn cell_segments(&self, i: usize, j: usize, level: f64) -> Vec<(Edge, Edge)> {
+        let corners = [
+            self.value(i, j),
+            self.value(i + 1, j),
+            self.value(i + 1, j + 1),
+            self.value(i, j + 1),
+        ];
+        if corners.iter().any(|v| v.is_nan()) {
+            return vec![];
+        }
+        // The edges adjacent to each corner, counterclockwise from the lower left one
+        let (bottom, right, top, left) = (
+            Edge::Horizontal(i, j),
+            Edge::Vertical(i + 1, j),
+            Edge::Horizontal(i, j + 1),
+            Edge::Vertical(i, j),
+        );
+        let adjacent = [(bottom, left), (bottom, right), (right, top), (left, top)];
+        let above: Vec<bool> = corners.iter().map(|&v| v >= level).collect();
+
+        let crossed: Vec<Edge> = [bottom, right, top, left]
+            .iter()
+            .cloned()
+            .filter(|&e| self.crossing(e, level).is_some())
+            .collect();
+        match crossed.len() {
+            2 => vec![(crossed[0], crossed[1])],
+            4 => {
+                // The saddle is resolved by the average value at the center of the cell
+                let center = corners.iter().sum::<f64>() / 4.0 >= level;
+                (0..4)
+                    .filter(|&k| above[k] != center)
+                    .map(|k| adjacent[k])
+                    .collect()
+            }
+            _ => vec![],
+        }
+    }
+
+    /// Trace the contour lines of the level and join the segments into polylines
+    fn contour_lines(&self, level: f64) -> Vec<Vec<(f64, f64)>> {
+        let mut segments = vec![];
+        for j in 0..self.size.1 {
+            for i in 0..self.size.0 {
+                segments.extend(self.cell_segments(i, j, level));
+            }
+        }
+
+        let mut at_edge: HashMap<Edge, Vec<usize>> = HashMap::new();
+        for (idx, (a, b)) in segments.iter().enumerate() {
+            at_edge.entry(*a).or_default().push(idx);
+            at_edge.entry(*b).or_default().push(idx);
+        }
+
+        let mut used = vec![false; segments.len()];
+        let mut lines = vec![];
+        for start in 0..segments.len() {
+            if used[start] {
+                continue;
+            }
+            used[start] = true;
+            let (a, b) = segments[start];
+            let mut edges = vec![a, b];
+            // Extend the line forward from its last edge, then backward from its first one
+            for &forward in [true, false].iter() {
+                loop {
+                    let end = if forward {
+                        edges[edges.len() - 1]
+                    } else {
+                        edges[0]
+                    };
+                    let next = at_edge[&end].iter().cloned().find(|&idx| !used[idx]);
+                    let idx = match next {
+                        Some(idx) => idx,
+                        None => break,
+                    };
+                    used[idx] = true;
+                    let (a, b) = segments[idx];
+                    let other = if a == end { b } else { a };
+                    if forward {
+                        edges.push(other);
+                    } else {
+                        edges.insert(0, other);
+                    }
+                }
+            }
+            lines.push(
+                edges
+                    .into_iter()
+                    .filter_map(|e| self.crossing(e, level))
+                    .collect(),
+            );
+        }
+        lines
+    }
+
+    /// Get the polygons of the region where the value is between `lo` and `hi`, one per cell
+    fn band_polygons(&self, lo: f64, hi: f64) -> Vec<Vec<(f64, f64)>> {
+        let mut ret = vec![];
+        for j in 0..self.size.1 {
+            for i in 0..self.size.0 {
+                let corners: Vec<_> = [(i, j), (i + 1, j), (i + 1, j + 1), (i, j + 1)]
+                    .iter()
+                    .map(|&(ci, cj)| (ci as f64, cj as f64, self.value(ci, cj)))
+                    .collect();
+                if corners.iter().any(|c| c.2.is_nan()) {
+                    continue;
+                }
+                let clipped = clip(&clip(&corners, lo, true), hi, false);
+                if clipped.len() >= 3 {
+                    ret.push(
+                        clipped
+                            .into_iter()
+                            .map(|(ci, cj, _)| self.position(ci, cj))
+                            .collect(),
+                    );
+                }
+            }
+        }
+        ret
+    }
+}
+
+/// Clip the polygon of `(i, j, value)` vertices to the part where the value is above the
+/// limit, or below it, interpolating the value linearly along the edges
+fn clip(polygon: &[(f64, f64, f64)], limit: f64, above: bool) -> Vec<(f64, f64, f64)> {
+    let inside = |v: f64| if above { v >= limit } else { v <= limit };
+    let mut ret = vec![];
+    for (k, &p) in polygon.iter().enumerate() {
+        let q = polygon[(k + 1) % polygon.len()];
+        if inside(p.2) {
+            ret.push(p);
+        }
+        if inside(p.2) != inside(q.2) {
+            let t = (limit - p.2) / (q.2 - p.2);
+            ret.push((p.0 + (q.0 - p.0) * t, p.1 + (q.1 - p.1) * t, limit));
+        }
+    }
+    ret
+}
+
+/// The series that draws the contour lines of a scalar field over a rectangular range,
+/// optionally with filled bands between consecutive levels
+pub struct ContourSeries<'a, DB: DrawingBackend> {
+    x: Range<f64>,
+    y: Range<f64>,
+    resolution: (usize, usize),
+    func: FieldFunc<'a>,
+    levels: Vec<f64>,
+    style: LevelStyleFunc<'a>,
+    band_style: Option<BandStyleFunc<'a>>,
+    elements: Option<std::vec::IntoIter<DynElement<'static, DB, (f64, f64)>>>,
+}
+
+impl<'a, DB: DrawingBackend> ContourSeries<'a, DB> {
+    /// Create a new contour series
+    /// - `x`: The range of X values the field is sampled on
+    /// - `y`: The range of Y values the field is sampled on
+    /// - `resolution`: The number of grid cells in the X and the Y direction
+    /// - `func`: The scalar field
+    pub fn new<F: Fn(f64, f64) -> f64 + 'a>(
+        x: Range<f64>,
+        y: Range<f64>,
+        resolution: (usize, usize),
+        func: F,
+    ) -> Self {
+        Self {
+            x,
+            y,
+            resolution: (resolution.0.max(1), resolution.1.max(1)),
+            func: Box::new(func),
+            levels: vec![],
+            style: Box::new(|_| (&BLACK).into()),
+            band_style: None,
+            elements: None,
+        }
+    }
+
+    /// Create a new contour series that samples the field over the whole plotting range of
+    /// the chart
+    pub fn from_chart<X, Y, F>(
+        chart: &ChartContext<DB, RangedCoord<X, Y>>,
+        resolution: (usize, usize),
+        func: F,
+    ) -> Self
+    where
+        X: Ranged<ValueType = f64>,
+        Y: Ranged<ValueType = f64>,
+        F: Fn(f64, f64) -> f64 + 'a,
+    {
+        Self::new(chart.x_range(), chart.y_range(), resolution, func)
+    }
+
+    /// Set the levels of the contour lines
+    pub fn levels<I: IntoIterator<Item = f64>>(mut self, levels: I) -> Self {
+        self.levels = levels.into_iter().filter(|l| !l.is_nan()).collect();
+        self.levels
+            .sort_by(|a, b| a.partial_cmp(b).expect("levels aren't NaN"));
+        self
+    }
+
+    /// Set the function that computes the style of the contour line of each level
+    pub fn style_func<F: Fn(f64) -> ShapeStyle + 'a>(mut self, func: F) -> Self {
+        self.style = Box::new(func);
+        self
+    }
+
+    /// Fill the bands between consecutive levels, with the style computed from the lower
+    /// and the upper level of each band
+    pub fn filled<F: Fn(f64, f64) -> ShapeStyle + 'a>(mut self, func: F) -> Self {
+        self.band_style = Some(Box::new(func));
+        self
+    }
+
+    fn sample(&self) -> Grid {
+        let (nx, ny) = self.resolution;
+        let mut grid = Grid {
+            x: self.x.clone(),
+            y: self.y.clone(),
+            size: (nx, ny),
+            values: Vec::with_capacity((nx + 1) * (ny + 1)),
+        };
+        for j in 0..=ny {
+            for i in 0..=nx {
+                let (x, y) = grid.position(i as f64, j as f64);
+                grid.values.push((self.func)(x, y));
+            }
+        }
+        grid
+    }
+}
+
+impl<'a, DB: DrawingBackend> Iterator for ContourSeries<'a, DB> {
+    type Item = DynElement<'static, DB, (f64, f64)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.elements.is_none() {
+            let grid = self.sample();
+            let mut elements = vec![];
+            if let Some(band_style) = &self.band_style {
+                for w in self.levels.windows(2) {
+                    let style = band_style(w[0], w[1]);
+                    for polygon in grid.band_polygons(w[0], w[1]) {
+                        elements.push(Polygon::new(polygon, style.clone()).into_dyn());
+                    }
+                }
+            }
+            for &level in self.levels.iter() {
+                let style = (self.style)(level);
+                for line in grid.contour_lines(level) {
+                    elements.push(PathElement::new(line, style.clone()).into_dyn());
+                }
+            }
+            self.elements = Some(elements.into_iter());
+        }
+        self.elements.as_mut()?.next()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::element::PointCollection;
+    use crate::prelude::*;
+
+    fn cone() -> Grid {
+        let series: ContourSeries<MockedBackend> =
+            ContourSeries::new(-2.0..2.0, -2.0..2.0, (8, 8), |x, y| (x * x + y * y).sqrt());
+        series.sample()
+    }
+
+    #[test]
+    fn test_contour_lines() {
+        let grid = cone();
+
+        let lines = grid.contour_lines(1.0);
+        assert_eq!(lines.len(), 1);
+        let line = &lines[0];
+        // The circle is closed and all the points are on it
+        assert_eq!(line.first(), line.last());
+        assert!(line.len() > 8);
+        for &(x, y) in line {
+            assert!(((x * x + y * y).sqrt() - 1.0).abs() < 0.1);
+        }
+
+        assert!(grid.contour_lines(5.0).is_empty());
+        assert!(grid.contour_lines(-1.0).is_empty());
+    }
+
+    #[test]
+    fn test_contour_bands() {
+        let grid = cone();
+        let area: f64 = grid
+            .band_polygons(0.0, 1.0)
+            .iter()
+            .map(|p| {
+                (0..p.len())
+                    .map(|k| {
+                        let (a, b) = (p[k], p[(k + 1) % p.len()]);
+                        a.0 * b.1 - b.0 * a.1
+                    })
+                    .sum::<f64>()
+                    .abs()
+                    / 2.0
+            })
+            .sum();
+        assert!((area - std::f64::consts::PI).abs() < 0.2);
+    }
+
+    #[test]
+    fn test_contour_series() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_path_call, 2);
+                assert!(b.num_fill_polygon_call > 0);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(-2.0..2.0, -2.0..2.0)
+            .expect("Build chart error");
+
+        let series = ContourSeries::from_chart(&chart, (16, 16), |x, y| x * x + y * y)
+            .levels(vec![1.0, 2.0, 100.0])
+            .filled(|_, _| BLUE.filled());
+        let elements: Vec<_> = series.collect();
+        assert!(elements.iter().all(|e| e.point_iter().len() >= 2));
+        chart.draw_series(elements).expect("Drawing Error");
+    }
+}
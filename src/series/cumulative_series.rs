@@ -0,0 +1,146 @@
+use std::cmp::Ordering;
+
+use super::{StepLineSeries, StepPath, StepPosition};
+use crate::style::ShapeStyle;
+
+/// The series that renders the empirical cumulative distribution of the data as a step line,
+/// which steps at each value as a `StepLineSeries` with `StepPosition::Post` does
+pub struct CumulativeSeries<X> {
+    style: ShapeStyle,
+    data: Option<Vec<(X, f64)>>,
+    normalized: bool,
+}
+
+impl<X: PartialOrd> CumulativeSeries<X> {
+    /// Create a new cumulative series from the number of occurrences of each value.
+    ///
+    /// - `iter`: The iterator of `(value, count)` pairs, duplicated values are merged, and the
+    ///   values which can't be ordered, such as NaN, are dropped
+    /// - `style`: The style of the step line
+    pub fn new<C: Into<f64>, I: IntoIterator<Item = (X, C)>, S: Into<ShapeStyle>>(
+        iter: I,
+        style: S,
+    ) -> Self {
+        // A value isn't comparable to itself only if it can't be ordered, so the sort is a
+        // total order of the values kept
+        let mut data: Vec<(X, f64)> = iter
+            .into_iter()
+            .filter(|(x, _)| x.partial_cmp(x).is_some())
+            .map(|(x, c)| (x, c.into()))
+            .collect();
+        data.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+
+        let mut merged: Vec<(X, f64)> = Vec::with_capacity(data.len());
+        for (x, c) in data {
+            match merged.last_mut() {
+                Some(last) if last.0 == x => last.1 += c,
+                _ => merged.push((x, c)),
+            }
+        }
+
+        Self {
+            style: style.into(),
+            data: Some(merged),
+            normalized: false,
+        }
+    }
+
+    /// Create a new cumulative series from raw samples, each of them counts once.
+    ///
+    /// - `iter`: The iterator of samples
+    /// - `style`: The style of the step line
+    pub fn from_samples<I: IntoIterator<Item = X>, S: Into<ShapeStyle>>(iter: I, style: S) -> Self {
+        Self::new(iter.into_iter().map(|x| (x, 1.0)), style)
+    }
+
+    /// Set if the cumulative counts are divided by the total count, so the line ends at 1.0
+    pub fn normalized(mut self, normalized: bool) -> Self {
+        self.normalized = normalized;
+        self
+    }
+}
+
+impl<X: Clone> Iterator for CumulativeSeries<X> {
+    type Item = StepPath<(X, f64)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let data = self.data.take()?;
+
+        let total: f64 = data.iter().map(|(_, c)| c).sum();
+        let scale = if self.normalized && total != 0.0 {
+            1.0 / total
+        } else {
+            1.0
+        };
+
+        // The line starts from zero at the first value, and then steps to the cumulative count
+        // at each value
+        let mut points = Vec::with_capacity(data.len() + 1);
+        if let Some((x, _)) = data.first() {
+            points.push((x.clone(), 0.0));
+        }
+        let mut acc = 0.0;
+        for (x, c) in data {
+            acc += c;
+            points.push((x, acc * scale));
+        }
+
+        StepLineSeries::new(points, self.style.clone())
+            .position(StepPosition::Post)
+            .next()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::element::PointCollection;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_cumulative_series() {
+        let points = |series: CumulativeSeries<i32>| -> Vec<(i32, f64)> {
+            let mut series = series;
+            let path = series.next().unwrap();
+            assert!(series.next().is_none());
+            path.point_iter().to_vec()
+        };
+
+        let series = CumulativeSeries::new(vec![(3, 2), (1, 1), (3, 3), (2, 4)], &RED);
+        assert_eq!(points(series), [(1, 0.0), (1, 1.0), (2, 5.0), (3, 10.0)]);
+
+        let series = CumulativeSeries::from_samples(vec![5, 1, 3, 1], &RED).normalized(true);
+        assert_eq!(points(series).last(), Some(&(5, 1.0)));
+
+        let series = CumulativeSeries::from_samples(vec![], &RED).normalized(true);
+        assert_eq!(points(series), []);
+    }
+
+    #[test]
+    fn test_cumulative_series_nan() {
+        let samples: Vec<_> = (0..50)
+            .map(|x| if x == 25 { f64::NAN } else { f64::from(x % 7) })
+            .collect();
+        let mut series = CumulativeSeries::from_samples(samples, &RED);
+        let path = series.next().unwrap();
+        let points = path.point_iter();
+        assert_eq!(points.len(), 8);
+        assert!(points.windows(2).all(|w| w[0].0 < w[1].0 || w[0].1 == 0.0));
+        assert_eq!(points.last(), Some(&(6.0, 49.0)));
+    }
+
+    #[test]
+    fn test_cumulative_series_steps() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_path(|_, _, path| {
+                assert_eq!(path, [(0, 200), (0, 100), (100, 100), (100, 0)]);
+            });
+            m.drop_check(|b| assert_eq!(b.num_draw_path_call, 1));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0..2, 0.0..1.0)
+            .expect("Build chart error");
+        chart
+            .draw_series(CumulativeSeries::from_samples(vec![1, 0], &RED).normalized(true))
+            .expect("Drawing Error");
+    }
+}
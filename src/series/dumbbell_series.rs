@@ -0,0 +1,323 @@
+use std::marker::PhantomData;
+use std::ops::Range;
+
+use super::histogram::{HistogramType, Horizontal, Vertical};
+use crate::chart::ChartContext;
+use crate::coord::{DiscreteRanged, Ranged, RangedCoord};
+use crate::drawing::backend::{BackendCoord, DrawingErrorKind};
+use crate::drawing::DrawingBackend;
+use crate::element::{Drawable, PointCollection};
+use crate::style::{Color, ShapeStyle, BLACK};
+
+/// The radius and the style of a marker, if it's drawn
+type Marker = Option<(u32, ShapeStyle)>;
+
+/// A connector line in the middle of the slot of a category, with an optional circle marker
+/// at each end
+pub struct Dumbbell<Coord> {
+    /// The start and the end of the connector, then the far edge of the slot at the start
+    points: [Coord; 3],
+    line_style: ShapeStyle,
+    markers: [Marker; 2],
+}
+
+impl<'a, Coord> PointCollection<'a, Coord> for &'a Dumbbell<Coord> {
+    type Borrow = &'a Coord;
+    type IntoIter = &'a [Coord];
+    fn point_iter(self) -> &'a [Coord] {
+        &self.points
+    }
+}
+
+impl<Coord, DB: DrawingBackend> Drawable<DB> for Dumbbell<Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let points: Vec<_> = points.take(3).collect();
+        if let [start, end, slot_end] = points[..] {
+            // The slot extends either along the X or the Y axis, so this is the offset to its
+            // center in both cases
+            let offset = ((slot_end.0 - start.0) / 2, (slot_end.1 - start.1) / 2);
+            let start = (start.0 + offset.0, start.1 + offset.1);
+            let end = (end.0 + offset.0, end.1 + offset.1);
+            if start != end {
+                backend.draw_line(start, end, &self.line_style)?;
+            }
+            for (pos, marker) in [start, end].iter().zip(self.markers.iter()) {
+                if let Some((size, style)) = marker {
+                    backend.draw_circle(*pos, *size, &style.color, style.filled)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The series that draws a dumbbell chart, which connects two values of each category with a
+/// line that has a marker at both ends. It can also draw a lollipop chart, which connects the
+/// baseline to a single value with a marker at the value only. The connectors are clipped to
+/// the value range of the chart and the markers of the clipped ends are left out.
+pub struct DumbbellSeries<BR, A, Tag = Vertical>
+where
+    BR: DiscreteRanged,
+    BR::ValueType: Eq,
+    Tag: HistogramType,
+{
+    data: std::vec::IntoIter<(BR::ValueType, A, A, bool)>,
+    range: Range<A>,
+    baseline: A,
+    line_style: ShapeStyle,
+    start_style: ShapeStyle,
+    end_style: ShapeStyle,
+    marker_size: u32,
+    _p: PhantomData<(BR, Tag)>,
+}
+
+impl<BR, A: Default, Tag> DumbbellSeries<BR, A, Tag>
+where
+    BR: DiscreteRanged,
+    BR::ValueType: Eq,
+    Tag: HistogramType,
+{
+    fn empty(range: Range<A>) -> Self {
+        Self {
+            data: vec![].into_iter(),
+            range,
+            baseline: A::default(),
+            line_style: (&BLACK).into(),
+            start_style: BLACK.filled(),
+            end_style: BLACK.filled(),
+            marker_size: 4,
+            _p: PhantomData,
+        }
+    }
+}
+
+impl<BR, A, Tag> DumbbellSeries<BR, A, Tag>
+where
+    BR: DiscreteRanged,
+    BR::ValueType: Eq,
+    Tag: HistogramType,
+{
+    /// Set the style of the connector lines
+    pub fn line_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.line_style = style.into();
+        self
+    }
+
+    /// Set the style of the markers at the first value
+    pub fn start_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.start_style = style.into();
+        self
+    }
+
+    /// Set the style of the markers at the second value, which is the only marker of a
+    /// lollipop
+    pub fn end_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.end_style = style.into();
+        self
+    }
+
+    /// Set the radius of the markers in pixels
+    pub fn marker_size(mut self, size: u32) -> Self {
+        self.marker_size = size;
+        self
+    }
+
+    /// Set the baseline the lollipops start from
+    pub fn baseline(mut self, baseline: A) -> Self {
+        self.baseline = baseline;
+        self
+    }
+
+    /// Set the data of a dumbbell chart, which is an iterator of `(category, a, b)` triples
+    pub fn data<I: IntoIterator<Item = (BR::ValueType, A, A)>>(mut self, iter: I) -> Self {
+        self.data = iter
+            .into_iter()
+            .map(|(key, a, b)| (key, a, b, true))
+            .collect::<Vec<_>>()
+            .into_iter();
+        self
+    }
+
+    /// Set the data of a lollipop chart, which is an iterator of `(category, value)` pairs
+    pub fn lollipop_data<I: IntoIterator<Item = (BR::ValueType, A)>>(mut self, iter: I) -> Self
+    where
+        A: Clone,
+    {
+        let baseline = self.baseline.clone();
+        self.data = iter
+            .into_iter()
+            .map(|(key, value)| (key, baseline.clone(), value, false))
+            .collect::<Vec<_>>()
+            .into_iter();
+        self
+    }
+}
+
+impl<BR, A: Default> DumbbellSeries<BR, A, Vertical>
+where
+    BR: DiscreteRanged,
+    BR::ValueType: Eq,
+{
+    /// Create a series of vertical connectors, which have the categories on the X axis
+    pub fn vertical<ACoord, DB: DrawingBackend>(
+        chart: &ChartContext<DB, RangedCoord<BR, ACoord>>,
+    ) -> Self
+    where
+        ACoord: Ranged<ValueType = A>,
+    {
+        Self::empty(chart.y_range())
+    }
+}
+
+impl<BR, A: Default> DumbbellSeries<BR, A, Horizontal>
+where
+    BR: DiscreteRanged,
+    BR::ValueType: Eq,
+{
+    /// Create a series of horizontal connectors, which have the categories on the Y axis
+    pub fn horizontal<ACoord, DB: DrawingBackend>(
+        chart: &ChartContext<DB, RangedCoord<ACoord, BR>>,
+    ) -> Self
+    where
+        ACoord: Ranged<ValueType = A>,
+    {
+        Self::empty(chart.x_range())
+    }
+}
+
+impl<BR, A: PartialOrd + Clone, Tag> DumbbellSeries<BR, A, Tag>
+where
+    BR: DiscreteRanged,
+    BR::ValueType: Eq,
+    Tag: HistogramType,
+{
+    /// Get the next category with both of its values clipped to the value range of the chart,
+    /// and the markers of the values that haven't been clipped
+    fn next_clipped(&mut self) -> Option<(BR::ValueType, A, A, [Marker; 2])> {
+        let (key, a, b, start_marker) = self.data.next()?;
+        let (lo, hi) = if self.range.start <= self.range.end {
+            (&self.range.start, &self.range.end)
+        } else {
+            (&self.range.end, &self.range.start)
+        };
+        let clip = |v: A| {
+            if v < *lo {
+                (lo.clone(), false)
+            } else if v > *hi {
+                (hi.clone(), false)
+            } else {
+                (v, true)
+            }
+        };
+        let ((a, a_kept), (b, b_kept)) = (clip(a), clip(b));
+        let markers = [
+            Some((self.marker_size, self.start_style.clone())).filter(|_| start_marker && a_kept),
+            Some((self.marker_size, self.end_style.clone())).filter(|_| b_kept),
+        ];
+        Some((key, a, b, markers))
+    }
+}
+
+impl<BR, A: PartialOrd + Clone> Iterator for DumbbellSeries<BR, A, Vertical>
+where
+    BR: DiscreteRanged,
+    BR::ValueType: Eq,
+{
+    type Item = Dumbbell<(BR::ValueType, A)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let (x, a, b, markers) = self.next_clipped()?;
+        let nx = BR::next_value(&x);
+        Some(Dumbbell {
+            points: [(x, a.clone()), (BR::previous_value(&nx), b), (nx, a)],
+            line_style: self.line_style.clone(),
+            markers,
+        })
+    }
+}
+
+impl<BR, A: PartialOrd + Clone> Iterator for DumbbellSeries<BR, A, Horizontal>
+where
+    BR: DiscreteRanged,
+    BR::ValueType: Eq,
+{
+    type Item = Dumbbell<(A, BR::ValueType)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let (y, a, b, markers) = self.next_clipped()?;
+        let ny = BR::next_value(&y);
+        Some(Dumbbell {
+            points: [(a.clone(), y), (b, BR::previous_value(&ny)), (a, ny)],
+            line_style: self.line_style.clone(),
+            markers,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_dumbbell_series() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_line(|c, _, from, to| {
+                assert_eq!(c, BLACK.to_rgba());
+                assert_eq!([from, to], [(50, 150), (50, 50)]);
+            });
+            // The second value is clipped to the top of the chart
+            m.check_draw_line(|_, _, from, to| assert_eq!([from, to], [(150, 100), (150, 0)]));
+            m.check_draw_circle(|c, _, _, center, radius| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!(center, (50, 150));
+                assert_eq!(radius, 3);
+            });
+            m.check_draw_circle(|c, _, _, center, _| {
+                assert_eq!(c, BLUE.to_rgba());
+                assert_eq!(center, (50, 50));
+            });
+            m.check_draw_circle(|_, _, _, center, _| assert_eq!(center, (150, 100)));
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_line_call, 2);
+                assert_eq!(b.num_draw_circle_call, 3);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0..2, 0.0..4.0)
+            .expect("Build chart error");
+
+        chart
+            .draw_series(
+                DumbbellSeries::vertical(&chart)
+                    .marker_size(3)
+                    .start_style(RED.filled())
+                    .end_style(BLUE.filled())
+                    .data(vec![(0, 1.0, 3.0), (1, 2.0, 6.0)]),
+            )
+            .expect("Drawing Error");
+    }
+
+    #[test]
+    fn test_lollipop_series() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_line(|_, _, from, to| assert_eq!([from, to], [(0, 150), (150, 150)]));
+            m.check_draw_circle(|_, _, _, center, _| assert_eq!(center, (150, 150)));
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_line_call, 1);
+                assert_eq!(b.num_draw_circle_call, 1);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0.0..4.0, 0..2)
+            .expect("Build chart error");
+
+        chart
+            .draw_series(DumbbellSeries::horizontal(&chart).lollipop_data(vec![(0, 3.0)]))
+            .expect("Drawing Error");
+    }
+}
@@ -0,0 +1,235 @@
+use std::marker::PhantomData;
+use std::ops::{Add, Range, Sub};
+
+use crate::chart::ChartContext;
+use crate::coord::{Ranged, RangedCoord};
+use crate::drawing::DrawingBackend;
+use crate::element::{ErrorBar, ErrorBarOrientH, ErrorBarOrientV};
+use crate::style::{ShapeStyle, BLACK};
+
+/// The data of a single error bar, which can be either `(key, value, error)` for a symmetric
+/// uncertainty or `(key, value, lower_error, upper_error)` for an asymmetric one.
+pub trait ErrorBarData<K, V> {
+    /// Get the key, the minimum, the value and the maximum of the error bar
+    fn into_error_range(self) -> (K, V, V, V);
+}
+
+impl<K, V: Add<Output = V> + Sub<Output = V> + Clone> ErrorBarData<K, V> for (K, V, V) {
+    fn into_error_range(self) -> (K, V, V, V) {
+        let (key, value, err) = self;
+        (key, value.clone() - err.clone(), value.clone(), value + err)
+    }
+}
+
+impl<K, V: Add<Output = V> + Sub<Output = V> + Clone> ErrorBarData<K, V> for (K, V, V, V) {
+    fn into_error_range(self) -> (K, V, V, V) {
+        let (key, value, err_low, err_high) = self;
+        (
+            key,
+            value.clone() - err_low,
+            value.clone(),
+            value + err_high,
+        )
+    }
+}
+
+/// The series of error bars. The error bars are clipped to the value range of the chart, and
+/// an end cap is left out if its end has been clipped.
+pub struct ErrorBarSeries<K, V, O> {
+    data: std::vec::IntoIter<(K, V, V, V)>,
+    range: Range<V>,
+    style: ShapeStyle,
+    cap_width: u32,
+    marker: bool,
+    _p: PhantomData<O>,
+}
+
+impl<K, V, O> ErrorBarSeries<K, V, O> {
+    fn empty(range: Range<V>) -> Self {
+        Self {
+            data: vec![].into_iter(),
+            range,
+            style: (&BLACK).into(),
+            cap_width: 10,
+            marker: true,
+            _p: PhantomData,
+        }
+    }
+
+    /// Set the style of the error bars
+    pub fn style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Set the width of the end caps in pixels
+    pub fn cap_width(mut self, width: u32) -> Self {
+        self.cap_width = width;
+        self
+    }
+
+    /// Set if the marker at the value is drawn. Disabling it is useful when the error bars are
+    /// layered over an existing point series.
+    pub fn marker(mut self, marker: bool) -> Self {
+        self.marker = marker;
+        self
+    }
+
+    /// Set the data iterator
+    pub fn data<D: ErrorBarData<K, V>, I: IntoIterator<Item = D>>(mut self, iter: I) -> Self {
+        self.data = iter
+            .into_iter()
+            .map(ErrorBarData::into_error_range)
+            .collect::<Vec<_>>()
+            .into_iter();
+        self
+    }
+}
+
+impl<K, V> ErrorBarSeries<K, V, ErrorBarOrientV<K, V>> {
+    /// Create a series of vertical error bars, which show the uncertainty on the Y axis
+    pub fn vertical<X: Ranged<ValueType = K>, Y: Ranged<ValueType = V>, DB: DrawingBackend>(
+        chart: &ChartContext<DB, RangedCoord<X, Y>>,
+    ) -> Self {
+        Self::empty(chart.y_range())
+    }
+}
+
+impl<K, V> ErrorBarSeries<K, V, ErrorBarOrientH<K, V>> {
+    /// Create a series of horizontal error bars, which show the uncertainty on the X axis
+    pub fn horizontal<X: Ranged<ValueType = V>, Y: Ranged<ValueType = K>, DB: DrawingBackend>(
+        chart: &ChartContext<DB, RangedCoord<X, Y>>,
+    ) -> Self {
+        Self::empty(chart.x_range())
+    }
+}
+
+impl<K, V: PartialOrd + Clone, O> ErrorBarSeries<K, V, O> {
+    /// Clip the error range to the value range of the chart, returning if each end is kept, or
+    /// `None` if the error range is entirely outside of the value range
+    #[allow(clippy::type_complexity)]
+    fn clip(&self, min: V, avg: &V, max: V) -> Option<((V, V), (bool, bool, bool))> {
+        let (lo, hi) = if self.range.start <= self.range.end {
+            (&self.range.start, &self.range.end)
+        } else {
+            (&self.range.end, &self.range.start)
+        };
+        if max < *lo || min > *hi {
+            return None;
+        }
+        let min_kept = min >= *lo;
+        let max_kept = max <= *hi;
+        let avg_kept = avg >= lo && avg <= hi;
+        Some((
+            (
+                if min_kept { min } else { lo.clone() },
+                if max_kept { max } else { hi.clone() },
+            ),
+            (min_kept, avg_kept, max_kept),
+        ))
+    }
+}
+
+impl<K, V: PartialOrd + Clone> Iterator for ErrorBarSeries<K, V, ErrorBarOrientV<K, V>> {
+    type Item = ErrorBar<K, V, ErrorBarOrientV<K, V>>;
+    fn next(&mut self) -> Option<Self::Item> {
+        // The error bars entirely outside of the value range are left out
+        loop {
+            let (key, min, avg, max) = self.data.next()?;
+            if let Some(((min, max), (min_kept, avg_kept, max_kept))) = self.clip(min, &avg, max) {
+                return Some(
+                    ErrorBar::new_vertical(key, min, avg, max, self.style.clone(), self.cap_width)
+                        .marker(self.marker && avg_kept)
+                        .caps(min_kept, max_kept),
+                );
+            }
+        }
+    }
+}
+
+impl<K, V: PartialOrd + Clone> Iterator for ErrorBarSeries<K, V, ErrorBarOrientH<K, V>> {
+    type Item = ErrorBar<K, V, ErrorBarOrientH<K, V>>;
+    fn next(&mut self) -> Option<Self::Item> {
+        // The error bars entirely outside of the value range are left out
+        loop {
+            let (key, min, avg, max) = self.data.next()?;
+            if let Some(((min, max), (min_kept, avg_kept, max_kept))) = self.clip(min, &avg, max) {
+                return Some(
+                    ErrorBar::new_horizontal(
+                        key,
+                        min,
+                        avg,
+                        max,
+                        self.style.clone(),
+                        self.cap_width,
+                    )
+                    .marker(self.marker && avg_kept)
+                    .caps(min_kept, max_kept),
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_errorbar_series() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            // The first error bar is within the range and has both caps
+            m.check_draw_line(|_, _, f, t| assert_eq!([f, t], [(45, 150), (55, 150)]));
+            m.check_draw_line(|_, _, f, t| assert_eq!([f, t], [(45, 50), (55, 50)]));
+            m.check_draw_line(|_, _, f, t| assert_eq!([f, t], [(50, 150), (50, 50)]));
+            // The second one is clipped at the top, so it only has the bottom cap
+            m.check_draw_line(|_, _, f, t| assert_eq!([f, t], [(145, 100), (155, 100)]));
+            m.check_draw_line(|_, _, f, t| assert_eq!([f, t], [(150, 100), (150, 0)]));
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_line_call, 5);
+                assert_eq!(b.num_draw_circle_call, 1);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0.0..2.0, 0.0..4.0)
+            .expect("Build chart error");
+
+        chart
+            .draw_series(
+                ErrorBarSeries::vertical(&chart)
+                    .marker(false)
+                    .data(vec![(0.5, 2.0, 1.0)]),
+            )
+            .expect("Drawing Error");
+        chart
+            .draw_series(ErrorBarSeries::vertical(&chart).data(vec![(1.5, 3.0, 1.0, 5.0)]))
+            .expect("Drawing Error");
+    }
+
+    #[test]
+    fn test_errorbar_series_outside_of_the_range() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_line_call, 3);
+                assert_eq!(b.num_draw_circle_call, 1);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0.0..10.0, 0.0..10.0)
+            .expect("Build chart error");
+
+        // Only the error bar within the range is drawn, the ones above and below it are left out
+        chart
+            .draw_series(ErrorBarSeries::vertical(&chart).data(vec![
+                (2.0, 13.5, 1.5),
+                (5.0, 5.0, 1.0),
+                (8.0, -4.0, 2.0),
+            ]))
+            .expect("Drawing Error");
+        chart
+            .draw_series(ErrorBarSeries::horizontal(&chart).data(vec![(5.0, 12.0, 1.0)]))
+            .expect("Drawing Error");
+    }
+}
@@ -0,0 +1,232 @@
+use crate::chart::ChartContext;
+use crate::coord::{Ranged, RangedCoord};
+use crate::drawing::backend::{BackendCoord, DrawingErrorKind};
+use crate::drawing::DrawingBackend;
+use crate::element::{Drawable, PointCollection};
+use crate::style::{FontTransform, IntoFont, ShapeStyle, TextStyle, BLACK};
+
+/// The gap in pixels between a tick and its label, and between two lanes of labels
+const LABEL_GAP: i32 = 3;
+
+/// All the events of an event series, which are laid out together so that the overlapping
+/// labels can be moved into separate lanes
+pub struct Events<'a, Coord> {
+    /// The bottom and the top of the plot at each event
+    points: Vec<Coord>,
+    labels: Vec<Option<String>>,
+    style: ShapeStyle,
+    label_style: TextStyle<'a>,
+    tick_size: Option<u32>,
+}
+
+impl<'a, 'b, Coord> PointCollection<'b, Coord> for &'b Events<'a, Coord> {
+    type Borrow = &'b Coord;
+    type IntoIter = &'b [Coord];
+    fn point_iter(self) -> &'b [Coord] {
+        &self.points
+    }
+}
+
+impl<'a, Coord, DB: DrawingBackend> Drawable<DB> for Events<'a, Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let points: Vec<_> = points.collect();
+        let rotated = matches!(
+            self.label_style.font.get_transform(),
+            FontTransform::Rotate90 | FontTransform::Rotate270
+        );
+
+        let mut events = vec![];
+        for (ends, label) in points.chunks(2).zip(self.labels.iter()) {
+            if let [a, b] = ends {
+                let (x, top, bottom) = (a.0, a.1.min(b.1), a.1.max(b.1));
+                let from = match self.tick_size {
+                    Some(size) => bottom - size as i32,
+                    None => top,
+                };
+                backend.draw_line((x, from), (x, bottom), &self.style)?;
+                if let Some(text) = label {
+                    let (w, h) = backend.estimate_text_size(text, &self.label_style.font)?;
+                    let size = if rotated { (h, w) } else { (w, h) };
+                    events.push((x, top, text, (size.0 as i32, size.1 as i32)));
+                }
+            }
+        }
+
+        // Each label goes to the first lane where it doesn't overlap the labels before it
+        events.sort_by_key(|e| e.0);
+        let lane_height = events.iter().map(|e| (e.3).1).max().unwrap_or(0) + LABEL_GAP;
+        let mut lane_ends: Vec<i32> = vec![];
+        for (x, top, text, (w, _)) in events {
+            let left = x + LABEL_GAP;
+            let lane = match lane_ends.iter().position(|&end| end + LABEL_GAP <= left) {
+                Some(lane) => lane,
+                None => {
+                    lane_ends.push(0);
+                    lane_ends.len() - 1
+                }
+            };
+            lane_ends[lane] = left + w;
+            let pos = (left, top + LABEL_GAP + lane as i32 * lane_height);
+            backend.draw_text(text, &self.label_style.font, pos, &self.label_style.color)?;
+        }
+        Ok(())
+    }
+}
+
+/// The series that marks discrete events on the X axis, such as deploys or alerts over a
+/// time series. Each event is a short tick rising from the bottom of the plot, or a rule
+/// spanning its full height, with an optional label at the top. The labels that would
+/// overlap are staggered into lanes. A rotated font can be used for the labels, which makes
+/// them run along the rules.
+pub struct EventSeries<'a, X, Y> {
+    events: Vec<(X, Option<String>)>,
+    range: (Y, Y),
+    style: ShapeStyle,
+    label_style: TextStyle<'a>,
+    tick_size: Option<u32>,
+}
+
+impl<'a, X, Y> EventSeries<'a, X, Y> {
+    /// Create a new event series
+    /// - `chart`: The chart the series is drawn on, which gives the vertical extent
+    /// - `iter`: The iterator of the `(x, label)` events, the empty labels aren't drawn
+    pub fn new<DB, XR, YR, L, I>(chart: &ChartContext<DB, RangedCoord<XR, YR>>, iter: I) -> Self
+    where
+        DB: DrawingBackend,
+        XR: Ranged<ValueType = X>,
+        YR: Ranged<ValueType = Y>,
+        L: Into<String>,
+        I: IntoIterator<Item = (X, L)>,
+    {
+        let range = chart.y_range();
+        Self {
+            events: iter
+                .into_iter()
+                .map(|(x, label)| {
+                    let label = label.into();
+                    (x, if label.is_empty() { None } else { Some(label) })
+                })
+                .collect(),
+            range: (range.start, range.end),
+            style: (&BLACK).into(),
+            label_style: ("sans-serif", 12).into_font().into(),
+            tick_size: Some(10),
+        }
+    }
+
+    /// Set the style of the ticks
+    pub fn style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Set the text style of the labels
+    pub fn label_style<S: Into<TextStyle<'a>>>(mut self, style: S) -> Self {
+        self.label_style = style.into();
+        self
+    }
+
+    /// Set the length of the ticks in pixels
+    pub fn tick_size(mut self, size: u32) -> Self {
+        self.tick_size = Some(size);
+        self
+    }
+
+    /// Set if the events are drawn as rules spanning the full height of the plot instead of
+    /// ticks
+    pub fn full_height(mut self, full_height: bool) -> Self {
+        self.tick_size = match (full_height, self.tick_size) {
+            (true, _) => None,
+            (false, Some(size)) => Some(size),
+            (false, None) => Some(10),
+        };
+        self
+    }
+}
+
+impl<'a, X: Clone, Y: Clone> IntoIterator for EventSeries<'a, X, Y> {
+    type Item = Events<'a, (X, Y)>;
+    type IntoIter = std::iter::Once<Self::Item>;
+    fn into_iter(self) -> Self::IntoIter {
+        let (bottom, top) = self.range;
+        let mut points = vec![];
+        let mut labels = vec![];
+        for (x, label) in self.events {
+            points.push((x.clone(), bottom.clone()));
+            points.push((x, top.clone()));
+            labels.push(label);
+        }
+        std::iter::once(Events {
+            points,
+            labels,
+            style: self.style,
+            label_style: self.label_style,
+            tick_size: self.tick_size,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_event_series() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_line(|_, _, from, to| assert_eq!([from, to], [(150, 190), (150, 200)]));
+            m.check_draw_line(|_, _, from, to| assert_eq!([from, to], [(20, 190), (20, 200)]));
+            m.check_draw_line(|_, _, from, to| assert_eq!([from, to], [(30, 190), (30, 200)]));
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_eq!(text, "deploy");
+                assert_eq!(pos, (23, 3));
+            });
+            // The second label overlaps the first one, so it's moved to the next lane
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_eq!(text, "alert");
+                assert!(pos.0 == 33 && pos.1 > 3);
+            });
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_eq!(text, "rollback");
+                assert_eq!(pos, (153, 3));
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_line_call, 3);
+                assert_eq!(b.num_draw_text_call, 3);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0.0..20.0, 0.0..1.0)
+            .expect("Build chart error");
+
+        // The labels are laid out from left to right, whatever the order of the events
+        let events = vec![(15.0, "rollback"), (2.0, "deploy"), (3.0, "alert")];
+        chart
+            .draw_series(EventSeries::new(&chart, events))
+            .expect("Drawing Error");
+    }
+
+    #[test]
+    fn test_event_series_rules() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_line(|_, _, from, to| assert_eq!([from, to], [(100, 0), (100, 200)]));
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_line_call, 1);
+                assert_eq!(b.num_draw_text_call, 0);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0..2, 0..1)
+            .expect("Build chart error");
+
+        chart
+            .draw_series(EventSeries::new(&chart, vec![(1, "")]).full_height(true))
+            .expect("Drawing Error");
+    }
+}
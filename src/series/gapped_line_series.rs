@@ -0,0 +1,108 @@
+use crate::element::PathElement;
+use crate::style::ShapeStyle;
+
+/// The line series for data with missing samples, which takes an iterator of optional points.
+/// By default, the line is broken at every missing point, so each run of consecutive points
+/// becomes a separate path.
+pub struct GappedLineSeries<Coord, I: IntoIterator<Item = Option<Coord>>> {
+    style: ShapeStyle,
+    data_iter: I::IntoIter,
+    bridge: bool,
+}
+
+impl<Coord, I: IntoIterator<Item = Option<Coord>>> GappedLineSeries<Coord, I> {
+    /// Create a new gapped line series. A gap is a `None` in the data, so a missing sample
+    /// should be given as `None` rather than left out, and the values themselves, such as a NaN,
+    /// don't make a gap.
+    /// - `iter`: The optional points of the line, in the order they're connected
+    /// - `style`: The style of the line
+    pub fn new<S: Into<ShapeStyle>>(iter: I, style: S) -> Self {
+        Self {
+            style: style.into(),
+            data_iter: iter.into_iter(),
+            bridge: false,
+        }
+    }
+
+    /// Set if the line bridges the gaps by connecting the points around them, instead of
+    /// breaking at them
+    pub fn bridge(mut self, bridge: bool) -> Self {
+        self.bridge = bridge;
+        self
+    }
+}
+
+impl<Coord, I: IntoIterator<Item = Option<Coord>>> Iterator for GappedLineSeries<Coord, I> {
+    type Item = PathElement<Coord>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut points = vec![];
+        loop {
+            match self.data_iter.next() {
+                Some(Some(point)) => points.push(point),
+                Some(None) if self.bridge || points.is_empty() => continue,
+                Some(None) | None if !points.is_empty() => {
+                    return Some(PathElement::new(points, self.style.clone()))
+                }
+                _ => return None,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::element::PointCollection;
+    use crate::prelude::*;
+
+    fn data() -> Vec<Option<(i32, i32)>> {
+        vec![
+            None,
+            Some((0, 0)),
+            Some((1, 1)),
+            None,
+            None,
+            Some((2, 0)),
+            Some((3, 1)),
+            None,
+        ]
+    }
+
+    #[test]
+    fn test_gapped_line_series() {
+        let paths: Vec<_> = GappedLineSeries::new(data(), &RED)
+            .map(|path| (&path).point_iter().to_vec())
+            .collect();
+        assert_eq!(paths, [vec![(0, 0), (1, 1)], vec![(2, 0), (3, 1)]]);
+
+        let paths: Vec<_> = GappedLineSeries::new(data(), &RED)
+            .bridge(true)
+            .map(|path| (&path).point_iter().to_vec())
+            .collect();
+        assert_eq!(paths, [vec![(0, 0), (1, 1), (2, 0), (3, 1)]]);
+
+        assert_eq!(
+            GappedLineSeries::new(vec![None::<(i32, i32)>; 3], &RED).count(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_gapped_line_series_drawing() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_path(|_, _, path| assert_eq!(path, [(0, 200), (100, 100)]));
+            m.check_draw_path(|_, _, path| assert_eq!(path, [(100, 200), (200, 100)]));
+            m.drop_check(|b| assert_eq!(b.num_draw_path_call, 2));
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0..2, 0..2)
+            .expect("Build chart error");
+
+        chart
+            .draw_series(GappedLineSeries::new(
+                vec![Some((0, 0)), Some((1, 1)), None, Some((1, 0)), Some((2, 1))],
+                &RED,
+            ))
+            .expect("Drawing Error");
+    }
+}
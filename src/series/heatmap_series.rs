@@ -0,0 +1,193 @@
+use std::marker::PhantomData;
+
+use crate::chart::ChartContext;
+use crate::coord::{DiscreteRanged, RangedCoord};
+use crate::drawing::DrawingBackend;
+use crate::element::Rectangle;
+use crate::style::{Color, ColorMap, RGBColor, ShapeStyle};
+
+type ValueFunc<'a, X, Y> = Box<dyn Fn(&X, &Y) -> f64 + 'a>;
+type ColorMapFunc<'a> = Box<dyn Fn(f64) -> RGBColor + 'a>;
+
+/// The series that renders a matrix of values as colored cells. Each cell spans from its key
+/// to the next value on both discrete axes.
+pub struct HeatmapSeries<'a, XR, YR>
+where
+    XR: DiscreteRanged,
+    XR::ValueType: Eq,
+    YR: DiscreteRanged,
+    YR::ValueType: Eq,
+{
+    xs: Vec<XR::ValueType>,
+    ys: Vec<YR::ValueType>,
+    idx: usize,
+    value_func: ValueFunc<'a, XR::ValueType, YR::ValueType>,
+    colormap: ColorMapFunc<'a>,
+    value_range: (f64, f64),
+    missing_style: Option<ShapeStyle>,
+    _p: PhantomData<(XR, YR)>,
+}
+
+impl<'a, XR, YR> HeatmapSeries<'a, XR, YR>
+where
+    XR: DiscreteRanged,
+    XR::ValueType: Eq + Clone,
+    YR: DiscreteRanged,
+    YR::ValueType: Eq + Clone,
+{
+    /// Create a new heatmap series
+    /// - `chart`: The chart the heatmap is drawn on, which determines the cell extents
+    /// - `xs`: The keys of the columns
+    /// - `ys`: The keys of the rows
+    /// - `value_func`: The function that computes the value of each cell
+    /// - `colormap`: The color map of the normalized values in `[0, 1]`, like `VIRIDIS`
+    ///
+    /// The values are normalized from `0.0..1.0` by default, see `value_range` and
+    /// `auto_value_range` for other domains.
+    pub fn new<DB: DrawingBackend, XI, YI, VF, CM>(
+        _: &ChartContext<DB, RangedCoord<XR, YR>>,
+        xs: XI,
+        ys: YI,
+        value_func: VF,
+        colormap: CM,
+    ) -> Self
+    where
+        XI: IntoIterator<Item = XR::ValueType>,
+        YI: IntoIterator<Item = YR::ValueType>,
+        VF: Fn(&XR::ValueType, &YR::ValueType) -> f64 + 'a,
+        CM: ColorMap + 'a,
+    {
+        Self {
+            xs: xs.into_iter().collect(),
+            ys: ys.into_iter().collect(),
+            idx: 0,
+            value_func: Box::new(value_func),
+            colormap: Box::new(move |value| colormap.eval(value)),
+            value_range: (0.0, 1.0),
+            missing_style: None,
+            _p: PhantomData,
+        }
+    }
+
+    /// Set the values that are mapped to the both ends of the colormap. Values outside of the
+    /// range use the color of the nearest end.
+    pub fn value_range(mut self, lo: f64, hi: f64) -> Self {
+        self.value_range = (lo, hi);
+        self
+    }
+
+    /// Use the smallest and the largest finite value of the cells as the value range. This
+    /// evaluates the value function for every cell once in advance.
+    pub fn auto_value_range(mut self) -> Self {
+        let mut range: Option<(f64, f64)> = None;
+        for x in self.xs.iter() {
+            for y in self.ys.iter() {
+                let value = (self.value_func)(x, y);
+                if !value.is_finite() {
+                    continue;
+                }
+                range = Some(match range {
+                    Some((lo, hi)) => (lo.min(value), hi.max(value)),
+                    None => (value, value),
+                });
+            }
+        }
+        if let Some(range) = range {
+            self.value_range = range;
+        }
+        self
+    }
+
+    /// Set the style of the cells with a NaN value. By default, those cells are not drawn.
+    pub fn missing_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.missing_style = Some(style.into());
+        self
+    }
+
+    fn normalize(&self, value: f64) -> f64 {
+        let (lo, hi) = self.value_range;
+        if hi == lo {
+            return 0.5;
+        }
+        ((value - lo) / (hi - lo)).clamp(0.0, 1.0)
+    }
+}
+
+impl<'a, XR, YR> Iterator for HeatmapSeries<'a, XR, YR>
+where
+    XR: DiscreteRanged,
+    XR::ValueType: Eq + Clone,
+    YR: DiscreteRanged,
+    YR::ValueType: Eq + Clone,
+{
+    type Item = Rectangle<(XR::ValueType, YR::ValueType)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.idx < self.xs.len() * self.ys.len() {
+            let x = &self.xs[self.idx / self.ys.len()];
+            let y = &self.ys[self.idx % self.ys.len()];
+            self.idx += 1;
+
+            let value = (self.value_func)(x, y);
+            let style = if value.is_nan() {
+                match &self.missing_style {
+                    Some(style) => style.clone(),
+                    None => continue,
+                }
+            } else {
+                (self.colormap)(self.normalize(value)).filled()
+            };
+
+            return Some(Rectangle::new(
+                [
+                    (x.clone(), y.clone()),
+                    (XR::next_value(x), YR::next_value(y)),
+                ],
+                style,
+            ));
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_heatmap_series() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            let cells = [(0, 0, 100), (2, 0, 0), (1, 100, 100), (1, 100, 0)];
+            // The second series skips the NaN cell
+            for &(c, x, y) in cells.iter().chain(cells.iter().filter(|c| c.0 != 2)) {
+                m.check_draw_rect(move |color, _, filled, u, d| {
+                    let expected = [&BLUE, &RED, &BLACK][c];
+                    assert_eq!(color, expected.to_rgba());
+                    assert!(filled);
+                    assert_eq!(u.0.min(d.0), x);
+                    assert_eq!(u.1.min(d.1), y);
+                });
+            }
+            m.drop_check(|b| assert_eq!(b.num_draw_rect_call, 7));
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0..2, 0..2)
+            .expect("Build chart error");
+
+        let values = [[1.0, f64::NAN], [3.0, 2.0]];
+        let series = || {
+            HeatmapSeries::new(
+                &chart,
+                0..2,
+                0..2,
+                move |&x, &y| values[x as usize][y as usize],
+                |v| if v < 0.5 { BLUE } else { RED },
+            )
+            .auto_value_range()
+        };
+        let first = series().missing_style(BLACK.filled());
+        let second = series();
+        chart.draw_series(first).expect("Drawing Error");
+        chart.draw_series(second).expect("Drawing Error");
+    }
+}
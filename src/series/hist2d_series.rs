@@ -0,0 +1,283 @@
+use std::collections::BTreeMap;
+use std::ops::Range;
+
+use crate::drawing::DrawingBackend;
+use crate::element::{DynElement, IntoDynElement, Polygon, Rectangle};
+use crate::style::{Color, ColorMap, RGBColor};
+
+type ColorMapFunc<'a> = Box<dyn Fn(f64) -> RGBColor + 'a>;
+
+/// A bin of the 2D histogram, which is a grid cell or a hexagon on one of the two lattices
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Bin {
+    Cell(i64, i64),
+    Hexagon(bool, i64, i64),
+}
+
+/// The series that bins scattered points into a 2D histogram, drawing each bin as a cell
+/// colored by the number of points in it. The bins are either rectangular or hexagonal.
+pub struct Hist2DSeries<'a, DB: DrawingBackend, I: Iterator<Item = (f64, f64)>> {
+    iter: Option<I>,
+    bins: (usize, usize),
+    range: Option<(Range<f64>, Range<f64>)>,
+    colormap: ColorMapFunc<'a>,
+    log_scale: bool,
+    min_count: u32,
+    hexagons: bool,
+    elements: Option<std::vec::IntoIter<DynElement<'a, DB, (f64, f64)>>>,
+}
+
+impl<'a, DB: DrawingBackend + 'a, I: Iterator<Item = (f64, f64)>> Hist2DSeries<'a, DB, I> {
+    /// Create a new 2D histogram
+    /// - `iter`: The iterator of the points
+    /// - `colormap`: The color map of the counts normalized by the largest one, which are in
+    ///   `[0, 1]`
+    pub fn new<II: IntoIterator<IntoIter = I>, CM: ColorMap + 'a>(iter: II, colormap: CM) -> Self {
+        Self {
+            iter: Some(iter.into_iter()),
+            bins: (10, 10),
+            range: None,
+            colormap: Box::new(move |value| colormap.eval(value)),
+            log_scale: false,
+            min_count: 1,
+            hexagons: false,
+            elements: None,
+        }
+    }
+
+    /// Set the number of bins on the X and the Y axis. For hexagons, the number of rows of a
+    /// lattice is given on the Y axis, and the other lattice fills the gaps between them.
+    pub fn bins(mut self, nx: usize, ny: usize) -> Self {
+        self.bins = (nx.max(1), ny.max(1));
+        self
+    }
+
+    /// Set the range that is binned, the points outside of it are dropped. By default, it's
+    /// the bounding box of the points, which requires buffering them.
+    pub fn range(mut self, x: Range<f64>, y: Range<f64>) -> Self {
+        self.range = Some((x, y));
+        self
+    }
+
+    /// Set if the counts are mapped to the colormap on a logarithmic scale
+    pub fn log_scale_counts(mut self, log_scale: bool) -> Self {
+        self.log_scale = log_scale;
+        self
+    }
+
+    /// Set the smallest count of a bin that is drawn
+    pub fn min_count(mut self, count: u32) -> Self {
+        self.min_count = count;
+        self
+    }
+
+    /// Set if the points are binned into hexagons instead of rectangles
+    pub fn hexagons(mut self, hexagons: bool) -> Self {
+        self.hexagons = hexagons;
+        self
+    }
+
+    /// Get the bin of the point in the coordinates where each bin has the unit size
+    fn bin_of(&self, x: f64, y: f64) -> Bin {
+        let (nx, ny) = (self.bins.0 as i64, self.bins.1 as i64);
+        if !self.hexagons {
+            return Bin::Cell(
+                (x.floor() as i64).clamp(0, nx - 1),
+                (y.floor() as i64).clamp(0, ny - 1),
+            );
+        }
+        // The centers of the first lattice are on the grid points, the centers of the second
+        // one are in the middle of the grid cells
+        let (i1, j1) = (x.round(), y.round());
+        let (i2, j2) = (x.floor(), y.floor());
+        let d1 = (x - i1).powi(2) + 3.0 * (y - j1).powi(2);
+        let d2 = (x - i2 - 0.5).powi(2) + 3.0 * (y - j2 - 0.5).powi(2);
+        if d1 <= d2 {
+            Bin::Hexagon(false, i1 as i64, j1 as i64)
+        } else {
+            Bin::Hexagon(true, i2 as i64, j2 as i64)
+        }
+    }
+
+    /// Count the points in each bin of the range, the points outside of it are dropped
+    fn count_bins<P: Iterator<Item = (f64, f64)>>(
+        &self,
+        points: P,
+        (x, y): &(Range<f64>, Range<f64>),
+    ) -> BTreeMap<Bin, u32> {
+        let (w, h) = (
+            (x.end - x.start) / self.bins.0 as f64,
+            (y.end - y.start) / self.bins.1 as f64,
+        );
+        let (lo_x, hi_x) = (x.start.min(x.end), x.start.max(x.end));
+        let (lo_y, hi_y) = (y.start.min(y.end), y.start.max(y.end));
+        let mut counts = BTreeMap::new();
+        for (px, py) in points {
+            if px < lo_x || px > hi_x || py < lo_y || py > hi_y {
+                continue;
+            }
+            let bin = self.bin_of((px - x.start) / w, (py - y.start) / h);
+            *counts.entry(bin).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    fn build(&mut self) -> Vec<DynElement<'a, DB, (f64, f64)>> {
+        let iter = match self.iter.take() {
+            Some(iter) => iter.filter(|(x, y)| x.is_finite() && y.is_finite()),
+            None => return vec![],
+        };
+        let (counts, (x, y)) = match self.range.clone() {
+            Some(range) => (self.count_bins(iter, &range), range),
+            None => {
+                let buffer: Vec<_> = iter.collect();
+                if buffer.is_empty() {
+                    return vec![];
+                }
+                let bounds = |f: fn(&(f64, f64)) -> f64| {
+                    let (lo, hi) = buffer
+                        .iter()
+                        .map(f)
+                        .fold((f64::MAX, f64::MIN), |(lo, hi), v| (lo.min(v), hi.max(v)));
+                    if lo == hi {
+                        lo - 0.5..hi + 0.5
+                    } else {
+                        lo..hi
+                    }
+                };
+                let range = (bounds(|p| p.0), bounds(|p| p.1));
+                (self.count_bins(buffer.into_iter(), &range), range)
+            }
+        };
+        let (w, h) = (
+            (x.end - x.start) / self.bins.0 as f64,
+            (y.end - y.start) / self.bins.1 as f64,
+        );
+
+        let max = counts.values().cloned().max().unwrap_or(0);
+        let normalize = |count: u32| {
+            if max <= 1 {
+                1.0
+            } else if self.log_scale {
+                f64::from(count).ln_1p() / f64::from(max).ln_1p()
+            } else {
+                f64::from(count) / f64::from(max)
+            }
+        };
+
+        counts
+            .into_iter()
+            .filter(|&(_, count)| count >= self.min_count)
+            .map(|(bin, count)| {
+                let style = (self.colormap)(normalize(count)).filled();
+                match bin {
+                    Bin::Cell(i, j) => {
+                        let corner =
+                            |i: i64, j: i64| (x.start + w * i as f64, y.start + h * j as f64);
+                        Rectangle::new([corner(i, j), corner(i + 1, j + 1)], style).into_dyn()
+                    }
+                    Bin::Hexagon(shifted, i, j) => {
+                        let offset = if shifted { 0.5 } else { 0.0 };
+                        let (cx, cy) = (i as f64 + offset, j as f64 + offset);
+                        let vertices = [
+                            (0.5, -1.0 / 6.0),
+                            (0.5, 1.0 / 6.0),
+                            (0.0, 1.0 / 3.0),
+                            (-0.5, 1.0 / 6.0),
+                            (-0.5, -1.0 / 6.0),
+                            (0.0, -1.0 / 3.0),
+                        ]
+                        .iter()
+                        .map(|(dx, dy)| (x.start + w * (cx + dx), y.start + h * (cy + dy)))
+                        .collect::<Vec<_>>();
+                        Polygon::new(vertices, style).into_dyn()
+                    }
+                }
+            })
+            .collect()
+    }
+}
+
+impl<'a, DB: DrawingBackend + 'a, I: Iterator<Item = (f64, f64)>> Iterator
+    for Hist2DSeries<'a, DB, I>
+{
+    type Item = DynElement<'a, DB, (f64, f64)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.elements.is_none() {
+            self.elements = Some(self.build().into_iter());
+        }
+        self.elements.as_mut()?.next()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_hist2d_bins() {
+        let series: Hist2DSeries<MockedBackend, _> =
+            Hist2DSeries::new(vec![], |_| BLACK).hexagons(true);
+        assert!(series.bin_of(0.1, 0.1) == Bin::Hexagon(false, 0, 0));
+        assert!(series.bin_of(0.4, 0.4) == Bin::Hexagon(true, 0, 0));
+        assert!(series.bin_of(0.9, 1.1) == Bin::Hexagon(false, 1, 1));
+
+        let series: Hist2DSeries<MockedBackend, _> =
+            Hist2DSeries::new(vec![], |_| BLACK).bins(2, 2);
+        assert!(series.bin_of(2.0, 0.5) == Bin::Cell(1, 0));
+    }
+
+    #[test]
+    fn test_hist2d_series() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            let cells = [
+                (&RED, (0, 100), (100, 200)),
+                (&BLUE, (100, 100), (200, 200)),
+                (&BLUE, (100, 0), (200, 100)),
+                (&RED, (20, 50), (150, 150)),
+            ];
+            for &(color, min, max) in cells.iter() {
+                let color = color.to_rgba();
+                m.check_draw_rect(move |c, _, _, u, d| {
+                    assert_eq!(c, color);
+                    assert_eq!((u.0.min(d.0), u.1.min(d.1)), min);
+                    assert_eq!((u.0.max(d.0), u.1.max(d.1)), max);
+                });
+            }
+            m.drop_check(|b| assert_eq!(b.num_draw_rect_call, 4));
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0.0..2.0, 0.0..2.0)
+            .expect("Build chart error");
+
+        let points = vec![(0.5, 0.5), (0.2, 0.7), (1.5, 1.5), (1.5, 0.5), (5.0, 5.0)];
+        chart
+            .draw_series(
+                Hist2DSeries::new(points.clone(), |v| if v < 1.0 { BLUE } else { RED })
+                    .bins(2, 2)
+                    .range(0.0..2.0, 0.0..2.0)
+                    .log_scale_counts(true),
+            )
+            .expect("Drawing Error");
+
+        // The bounding box is used when the range isn't given
+        chart
+            .draw_series(
+                Hist2DSeries::new(
+                    points.into_iter().take(4),
+                    |v| {
+                        if v < 1.0 {
+                            BLUE
+                        } else {
+                            RED
+                        }
+                    },
+                )
+                .bins(1, 1)
+                .min_count(2),
+            )
+            .expect("Drawing Error");
+    }
+}
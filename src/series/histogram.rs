@@ -1,13 +1,15 @@
-use std::collections::{hash_map::IntoIter as HashMapIter, HashMap};
+use std::cmp::Ordering;
+use std::collections::HashMap;
 use std::hash::Hash;
 use std::marker::PhantomData;
 use std::ops::AddAssign;
 
 use crate::chart::ChartContext;
 use crate::coord::{DiscreteRanged, Ranged, RangedCoord};
+use crate::drawing::backend::{BackendCoord, DrawingErrorKind};
 use crate::drawing::DrawingBackend;
-use crate::element::Rectangle;
-use crate::style::{Color, ShapeStyle, GREEN};
+use crate::element::{Drawable, PointCollection, Rectangle};
+use crate::style::{Color, IntoFont, ShapeStyle, TextStyle, GREEN};
 
 pub trait HistogramType {}
 pub struct Vertical;
@@ -16,7 +18,170 @@ pub struct Horizontal;
 impl HistogramType for Vertical {}
 impl HistogramType for Horizontal {}
 
-/// The series that aggregate data into a histogram
+/// Describes the order in which the bars of a histogram are emitted
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BarOrdering {
+    /// Emit the bars in the order the categories first appear in the data
+    Insertion,
+    /// Emit the bars with the smallest category first
+    Ascending,
+    /// Emit the bars with the largest category first
+    Descending,
+}
+
+/// Describes where the value label of a bar is placed
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BarLabelPosition {
+    /// Inside the bar, next to its far end
+    Inside,
+    /// Outside the bar, just beyond its far end
+    OutsideEnd,
+}
+
+/// The gap in pixels between the far end of a bar and its label
+const LABEL_GAP: i32 = 3;
+
+/// A single bar of a histogram, optionally labelled with its value
+pub struct HistogramBar<'a, Coord> {
+    rect: Rectangle<Coord>,
+    label: Option<(String, TextStyle<'a>, BarLabelPosition)>,
+    horizontal: bool,
+    width_fraction: f64,
+    /// The width in pixels of a category slot on the chart, which the width of the bar is the
+    /// fraction of, so that all the bars get the same width
+    slot_width: Option<i32>,
+    corner_radius: u32,
+}
+
+impl<'a, Coord> HistogramBar<'a, Coord> {
+    pub(super) fn new(rect: Rectangle<Coord>, horizontal: bool) -> Self {
+        Self {
+            rect,
+            label: None,
+            horizontal,
+            width_fraction: 1.0,
+            slot_width: None,
+            corner_radius: 0,
+        }
+    }
+
+    pub(super) fn with_width_fraction(mut self, fraction: f64) -> Self {
+        self.width_fraction = fraction;
+        self
+    }
+}
+
+/// Shrink the interval between `a` and `b` around its center to the given fraction, of the
+/// interval itself or of the slot width when it's given
+fn shrink_slot(a: i32, b: i32, fraction: f64, slot_width: Option<i32>) -> (i32, i32) {
+    match slot_width {
+        _ if fraction >= 1.0 => (a, b),
+        Some(slot_width) => {
+            let width = (f64::from(slot_width) * fraction).round() as i32;
+            let start = a.min(b) + ((a - b).abs() - width) / 2;
+            if a <= b {
+                (start, start + width)
+            } else {
+                (start + width, start)
+            }
+        }
+        None => {
+            let gap = (f64::from(b - a) * (1.0 - fraction) / 2.0).round() as i32;
+            (a + gap, b - gap)
+        }
+    }
+}
+
+impl<'b, 'a, Coord> PointCollection<'a, Coord> for &'a HistogramBar<'b, Coord> {
+    type Borrow = &'a Coord;
+    type IntoIter = &'a [Coord];
+    fn point_iter(self) -> &'a [Coord] {
+        self.rect.point_iter()
+    }
+}
+
+impl<'a, Coord, DB: DrawingBackend> Drawable<DB> for HistogramBar<'a, Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let mut points: Vec<_> = points.take(2).collect();
+        if let [end, base] = &mut points[..] {
+            if self.horizontal {
+                let (a, b) = shrink_slot(end.1, base.1, self.width_fraction, self.slot_width);
+                end.1 = a;
+                base.1 = b;
+            } else {
+                let (a, b) = shrink_slot(end.0, base.0, self.width_fraction, self.slot_width);
+                end.0 = a;
+                base.0 = b;
+            }
+        }
+        // Only the far end of the bar is rounded, so that the stacked bars join flush
+        let r = self.corner_radius;
+        let radii = match &points[..] {
+            [end, base] if r > 0 => match self.horizontal {
+                false if end.1 <= base.1 => [r, r, 0, 0],
+                false => [0, 0, r, r],
+                true if end.0 >= base.0 => [0, r, r, 0],
+                true => [r, 0, 0, r],
+            },
+            _ => [0; 4],
+        };
+        self.rect
+            .draw_rounded(points.iter().cloned(), radii, backend)?;
+
+        // The first corner of the bar is always at the far end, the second one at the baseline
+        if let (Some((text, style, position)), [end, base]) = (&self.label, &points[..]) {
+            if (self.horizontal && end.0 == base.0) || (!self.horizontal && end.1 == base.1) {
+                return Ok(());
+            }
+            let (w, h) = backend.estimate_text_size(text, &style.font)?;
+            let (w, h) = (w as i32, h as i32);
+            // Whether the label should be placed after the far end in backend coordinates
+            let after_end = match position {
+                BarLabelPosition::OutsideEnd => {
+                    (self.horizontal && end.0 > base.0) || (!self.horizontal && end.1 > base.1)
+                }
+                BarLabelPosition::Inside => {
+                    (self.horizontal && end.0 < base.0) || (!self.horizontal && end.1 < base.1)
+                }
+            };
+            let pos = match (self.horizontal, after_end) {
+                (true, true) => (end.0 + LABEL_GAP, (end.1 + base.1 - h) / 2),
+                (true, false) => (end.0 - LABEL_GAP - w, (end.1 + base.1 - h) / 2),
+                (false, true) => ((end.0 + base.0 - w) / 2, end.1 + LABEL_GAP),
+                (false, false) => ((end.0 + base.0 - w) / 2, end.1 - LABEL_GAP - h),
+            };
+            backend.draw_text(text, &style.font, pos, &style.color)?;
+        }
+        Ok(())
+    }
+}
+
+type Comparator<V> = fn(&V, &V) -> Ordering;
+type LabelFunc<'a, V, A> = Box<dyn Fn(&V, &A) -> Option<String> + 'a>;
+
+/// Aggregate the data by category, preserving the order of first occurrence
+fn aggregate<V: Eq + Hash, A: AddAssign<A> + Default, I: IntoIterator<Item = (V, A)>>(
+    iter: I,
+) -> Vec<(V, A)> {
+    let mut buffer = HashMap::<V, (usize, A)>::new();
+    for (x, y) in iter.into_iter() {
+        let idx = buffer.len();
+        buffer.entry(x).or_insert_with(|| (idx, A::default())).1 += y;
+    }
+    let mut result: Vec<_> = buffer.into_iter().collect();
+    result.sort_by_key(|(_, (idx, _))| *idx);
+    result.into_iter().map(|(x, (_, y))| (x, y)).collect()
+}
+
+/// The series that aggregate data into a histogram.
+///
+/// A value below the baseline produces a bar extending downwards (or to the left for a
+/// horizontal histogram), and the style function still receives the signed value.
 pub struct Histogram<'a, BR, A, Tag = Vertical>
 where
     BR: DiscreteRanged,
@@ -26,8 +191,15 @@ where
 {
     style: Box<dyn Fn(&BR::ValueType, &A) -> ShapeStyle + 'a>,
     margin: u32,
-    iter: HashMapIter<BR::ValueType, A>,
+    iter: std::vec::IntoIter<(BR::ValueType, A)>,
     baseline: Box<dyn Fn(BR::ValueType) -> A + 'a>,
+    ordering: Option<Comparator<BR::ValueType>>,
+    label_func: Option<LabelFunc<'a, BR::ValueType, A>>,
+    label_style: TextStyle<'a>,
+    label_position: BarLabelPosition,
+    width_fraction: f64,
+    slot_width: Option<i32>,
+    corner_radius: u32,
     _p: PhantomData<(BR, Tag)>,
 }
 
@@ -42,8 +214,15 @@ where
         Self {
             style: Box::new(|_, _| GREEN.filled()),
             margin: 5,
-            iter: HashMap::new().into_iter(),
+            iter: Vec::new().into_iter(),
             baseline: Box::new(|_| A::default()),
+            ordering: None,
+            label_func: None,
+            label_style: ("sans-serif", 12).into_font().into(),
+            label_position: BarLabelPosition::OutsideEnd,
+            width_fraction: 1.0,
+            slot_width: None,
+            corner_radius: 0,
             _p: PhantomData,
         }
     }
@@ -78,21 +257,109 @@ where
         self
     }
 
+    /// Set the width of each bar as a fraction of the slot of its category.
+    ///
+    /// Unlike the margin, this doesn't depend on the size of the plotting area. When both are
+    /// set, the fraction is applied first and then the margin is removed from each side.
+    /// The slots are rounded to whole pixels, so they may differ by a pixel. With the histogram
+    /// made by `vertical` or `horizontal`, the bars are the fraction of the first slot on the
+    /// chart, rounded to a pixel, so that they all get the same width. Without the chart, as
+    /// with `Histogram::new`, each bar is the fraction of its own slot.
+    pub fn width_fraction(mut self, fraction: f64) -> Self {
+        self.width_fraction = fraction.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Round the far end of each bar, which is the top of a vertical bar above the baseline
+    /// or the right end of a horizontal one, while the end at the baseline stays square
+    pub fn rounded(mut self, radius: u32) -> Self {
+        self.corner_radius = radius;
+        self
+    }
+
     /// Set the margin for each bar
     pub fn margin(mut self, value: u32) -> Self {
         self.margin = value;
         self
     }
 
+    /// Set a function that produces the value label of each bar.
+    ///
+    /// If the function returns `None`, the bar is not labelled. Bars with zero length never
+    /// get a label.
+    pub fn label_func(mut self, func: impl Fn(&BR::ValueType, &A) -> Option<String> + 'a) -> Self {
+        self.label_func = Some(Box::new(func));
+        self
+    }
+
+    /// Set the text style of the value labels
+    pub fn label_style<S: Into<TextStyle<'a>>>(mut self, style: S) -> Self {
+        self.label_style = style.into();
+        self
+    }
+
+    /// Set where the value labels are placed relative to the far end of each bar
+    pub fn label_position(mut self, position: BarLabelPosition) -> Self {
+        self.label_position = position;
+        self
+    }
+
+    fn make_label(
+        &self,
+        x: &BR::ValueType,
+        y: &A,
+    ) -> Option<(String, TextStyle<'a>, BarLabelPosition)> {
+        let text = self.label_func.as_ref().and_then(|func| func(x, y))?;
+        Some((text, self.label_style.clone(), self.label_position))
+    }
+
+    /// Set the order in which the bars are emitted.
+    ///
+    /// By default the bars are emitted in the order their categories first appear in the data.
+    /// This can be set either before or after the data is given. Note that once the data has
+    /// been sorted, switching back to `BarOrdering::Insertion` only affects data given later.
+    pub fn ordering(mut self, ordering: BarOrdering) -> Self
+    where
+        BR::ValueType: Ord,
+    {
+        self.ordering = match ordering {
+            BarOrdering::Insertion => None,
+            BarOrdering::Ascending => Some(|a, b| a.cmp(b)),
+            BarOrdering::Descending => Some(|a, b| b.cmp(a)),
+        };
+        if let Some(cmp) = self.ordering {
+            let mut buffer: Vec<_> = self.iter.collect();
+            buffer.sort_by(|a, b| cmp(&a.0, &b.0));
+            self.iter = buffer.into_iter();
+        }
+        self
+    }
+
     /// Set the data iterator
     pub fn data<I: IntoIterator<Item = (BR::ValueType, A)>>(mut self, iter: I) -> Self {
-        let mut buffer = HashMap::<BR::ValueType, A>::new();
-        for (x, y) in iter.into_iter() {
-            *buffer.entry(x).or_insert_with(Default::default) += y;
+        let mut buffer = aggregate(iter);
+        if let Some(cmp) = self.ordering {
+            buffer.sort_by(|a, b| cmp(&a.0, &b.0));
         }
         self.iter = buffer.into_iter();
         self
     }
+
+    /// Set the data iterator with the samples alone, each of them contributing the weight
+    /// given by `weight_func` to the total of its bar.
+    ///
+    /// This is handy for the revenue per bucket or the probability mass, and it's the same as
+    /// passing the `(sample, weight)` pairs to `data`.
+    pub fn weighted_data<I: IntoIterator<Item = BR::ValueType>>(
+        self,
+        iter: I,
+        weight_func: impl Fn(&BR::ValueType) -> A,
+    ) -> Self {
+        self.data(iter.into_iter().map(|x| {
+            let weight = weight_func(&x);
+            (x, weight)
+        }))
+    }
 }
 
 impl<'a, BR, A> Histogram<'a, BR, A, Vertical>
@@ -114,27 +381,37 @@ where
         margin: u32,
         style: S,
     ) -> Self {
-        let mut buffer = HashMap::<BR::ValueType, A>::new();
-        for (x, y) in iter.into_iter() {
-            *buffer.entry(x).or_insert_with(Default::default) += y;
-        }
         let style = style.into();
         Self {
             style: Box::new(move |_, _| style.clone()),
             margin,
-            iter: buffer.into_iter(),
+            iter: aggregate(iter).into_iter(),
             baseline: Box::new(|_| A::default()),
+            ordering: None,
+            label_func: None,
+            label_style: ("sans-serif", 12).into_font().into(),
+            label_position: BarLabelPosition::OutsideEnd,
+            width_fraction: 1.0,
+            slot_width: None,
+            corner_radius: 0,
             _p: PhantomData,
         }
     }
 
     pub fn vertical<ACoord, DB: DrawingBackend>(
-        _: &ChartContext<DB, RangedCoord<BR, ACoord>>,
+        chart: &ChartContext<DB, RangedCoord<BR, ACoord>>,
     ) -> Self
     where
         ACoord: Ranged<ValueType = A>,
     {
-        Self::empty()
+        let start = chart.x_range().start;
+        let next = BR::next_value(&start);
+        let a = chart.backend_coord(&(start, A::default())).0;
+        let b = chart.backend_coord(&(next, A::default())).0;
+        Self {
+            slot_width: Some((b - a).abs()),
+            ..Self::empty()
+        }
     }
 }
 
@@ -145,12 +422,19 @@ where
     A: AddAssign<A> + Default + 'a,
 {
     pub fn horizontal<ACoord, DB: DrawingBackend>(
-        _: &ChartContext<DB, RangedCoord<ACoord, BR>>,
+        chart: &ChartContext<DB, RangedCoord<ACoord, BR>>,
     ) -> Self
     where
         ACoord: Ranged<ValueType = A>,
     {
-        Self::empty()
+        let start = chart.y_range().start;
+        let next = BR::next_value(&start);
+        let a = chart.backend_coord(&(A::default(), start)).1;
+        let b = chart.backend_coord(&(A::default(), next)).1;
+        Self {
+            slot_width: Some((b - a).abs()),
+            ..Self::empty()
+        }
     }
 }
 
@@ -158,17 +442,25 @@ impl<'a, BR, A> Iterator for Histogram<'a, BR, A, Vertical>
 where
     BR: DiscreteRanged,
     BR::ValueType: Eq + Hash,
-    A: AddAssign<A> + Default,
+    A: AddAssign<A> + Default + 'a,
 {
-    type Item = Rectangle<(BR::ValueType, A)>;
+    type Item = HistogramBar<'a, (BR::ValueType, A)>;
     fn next(&mut self) -> Option<Self::Item> {
         if let Some((x, y)) = self.iter.next() {
             let nx = BR::next_value(&x);
             let base = (self.baseline)(BR::previous_value(&nx));
             let style = (self.style)(&x, &y);
+            let label = self.make_label(&x, &y);
             let mut rect = Rectangle::new([(x, y), (nx, base)], style);
             rect.set_margin(0, 0, self.margin, self.margin);
-            return Some(rect);
+            return Some(HistogramBar {
+                rect,
+                label,
+                horizontal: false,
+                width_fraction: self.width_fraction,
+                slot_width: self.slot_width,
+                corner_radius: self.corner_radius,
+            });
         }
         None
     }
@@ -178,19 +470,334 @@ impl<'a, BR, A> Iterator for Histogram<'a, BR, A, Horizontal>
 where
     BR: DiscreteRanged,
     BR::ValueType: Eq + Hash,
-    A: AddAssign<A> + Default,
+    A: AddAssign<A> + Default + 'a,
 {
-    type Item = Rectangle<(A, BR::ValueType)>;
+    type Item = HistogramBar<'a, (A, BR::ValueType)>;
     fn next(&mut self) -> Option<Self::Item> {
         if let Some((y, x)) = self.iter.next() {
             let ny = BR::next_value(&y);
             // With this trick we can avoid the clone trait bound
             let base = (self.baseline)(BR::previous_value(&ny));
             let style = (self.style)(&y, &x);
+            let label = self.make_label(&y, &x);
             let mut rect = Rectangle::new([(x, y), (base, ny)], style);
             rect.set_margin(self.margin, self.margin, 0, 0);
-            return Some(rect);
+            return Some(HistogramBar {
+                rect,
+                label,
+                horizontal: true,
+                width_fraction: self.width_fraction,
+                slot_width: self.slot_width,
+                corner_radius: self.corner_radius,
+            });
         }
         None
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::element::PointCollection;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_histogram_ordering() {
+        let data = [(3, 1), (1, 2), (2, 3), (1, 4)];
+
+        let collect_x = |hist: Histogram<RangedCoordi32, i32>| -> Vec<i32> {
+            hist.map(|rect| rect.point_iter()[0].0).collect()
+        };
+        let collect_y = |hist: Histogram<RangedCoordi32, i32, Horizontal>| -> Vec<i32> {
+            hist.map(|rect| rect.point_iter()[0].1).collect()
+        };
+
+        let vertical = || {
+            let hist: Histogram<RangedCoordi32, i32> = Histogram::empty();
+            hist
+        };
+        let horizontal = || {
+            let hist: Histogram<RangedCoordi32, i32, Horizontal> = Histogram::empty();
+            hist
+        };
+
+        assert_eq!(collect_x(vertical().data(data.iter().cloned())), [3, 1, 2]);
+        assert_eq!(
+            collect_y(horizontal().data(data.iter().cloned())),
+            [3, 1, 2]
+        );
+
+        assert_eq!(
+            collect_x(
+                vertical()
+                    .ordering(BarOrdering::Ascending)
+                    .data(data.iter().cloned())
+            ),
+            [1, 2, 3]
+        );
+        assert_eq!(
+            collect_y(
+                horizontal()
+                    .data(data.iter().cloned())
+                    .ordering(BarOrdering::Descending)
+            ),
+            [3, 2, 1]
+        );
+
+        let sums: Vec<_> = vertical()
+            .data(data.iter().cloned())
+            .map(|rect| rect.point_iter()[0].1)
+            .collect();
+        assert_eq!(sums, [1, 6, 3]);
+    }
+
+    #[test]
+    fn test_histogram_weighted_data() {
+        let prices = [(1, 2.5), (2, 1.0), (3, 4.0)];
+        let price = |x: &i32| prices.iter().find(|p| p.0 == *x).map_or(0.0, |p| p.1);
+
+        let hist: Histogram<RangedCoordi32, f64> = Histogram::empty();
+        let totals: Vec<_> = hist
+            .weighted_data(vec![1, 3, 1, 2, 1], price)
+            .map(|rect| rect.point_iter()[0])
+            .collect();
+        assert_eq!(totals, [(1, 7.5), (3, 4.0), (2, 1.0)]);
+
+        let hist: Histogram<RangedCoordi32, u32> = Histogram::empty();
+        let totals: Vec<_> = hist
+            .ordering(BarOrdering::Ascending)
+            .weighted_data(vec![2, 1, 2], |x| *x as u32 * 10)
+            .map(|rect| rect.point_iter()[0])
+            .collect();
+        assert_eq!(totals, [(1, 10), (2, 40)]);
+    }
+
+    #[test]
+    fn test_histogram_mixed_signs() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            let expected = vec![
+                (GREEN, (0, 50), (50, 100)),
+                (RED, (50, 100), (100, 150)),
+                (GREEN, (100, 0), (150, 100)),
+                (RED, (150, 100), (200, 200)),
+            ];
+            for (color, ul, br) in expected {
+                m.check_draw_rect(move |c, _, f, u, d| {
+                    assert_eq!(c, color.to_rgba());
+                    assert!(f);
+                    assert_eq!([u, d], [ul, br]);
+                });
+            }
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 4);
+                assert_eq!(b.draw_count, 4);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0..4, -10..10)
+            .expect("Build chart error");
+
+        chart
+            .draw_series(
+                Histogram::vertical(&chart)
+                    .margin(0)
+                    .style_func(|_, v| if *v < 0 { RED.filled() } else { GREEN.filled() })
+                    .data(vec![(0, 5), (1, -5), (2, 10), (3, -10)]),
+            )
+            .expect("Drawing Error");
+    }
+
+    #[test]
+    fn test_histogram_rounded() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            // The bar above the baseline is rounded at the top and the one below at the bottom
+            m.check_draw_rounded_rect(|_, _, _, u, _, radii| {
+                assert_eq!(u, (0, 50));
+                assert_eq!(radii, [4, 4, 0, 0]);
+            });
+            m.check_draw_rounded_rect(|_, _, _, u, _, radii| {
+                assert_eq!(u, (50, 100));
+                assert_eq!(radii, [0, 0, 4, 4]);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rounded_rect_call, 2);
+                assert_eq!(b.num_draw_rect_call, 0);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0..4, -10..10)
+            .expect("Build chart error");
+
+        chart
+            .draw_series(
+                Histogram::vertical(&chart)
+                    .margin(0)
+                    .rounded(4)
+                    .data(vec![(0, 5), (1, -5)]),
+            )
+            .expect("Drawing Error");
+    }
+
+    #[test]
+    fn test_histogram_reversed_axes() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            // The first bar is at the right and it hangs from the top of the chart
+            m.check_draw_rect(|_, _, _, u, d| assert_eq!([u, d], [(150, 0), (200, 100)]));
+            m.drop_check(|b| assert_eq!(b.num_draw_rect_call, 1));
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged((0..4).reversed(), (0..10).reversed())
+            .expect("Build chart error");
+
+        chart
+            .draw_series(Histogram::vertical(&chart).margin(0).data(vec![(0, 5)]))
+            .expect("Drawing Error");
+    }
+
+    #[test]
+    fn test_histogram_labels() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_eq!(text, "5");
+                assert!(pos.1 < 50);
+            });
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_eq!(text, "-5");
+                assert!(pos.1 > 150);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 3);
+                assert_eq!(b.num_draw_text_call, 2);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0..3, -10..10)
+            .expect("Build chart error");
+
+        chart
+            .draw_series(
+                Histogram::vertical(&chart)
+                    .label_func(|_, v| Some(format!("{}", v)))
+                    .data(vec![(0, 5), (1, -5), (2, 0)]),
+            )
+            .expect("Drawing Error");
+    }
+
+    #[test]
+    fn test_horizontal_histogram_labels() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_eq!(text, "8");
+                assert!(pos.0 < 180 && pos.0 > 100);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_text_call, 1);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0..10, 0..2)
+            .expect("Build chart error");
+
+        chart
+            .draw_series(
+                Histogram::horizontal(&chart)
+                    .label_func(|_, v| Some(format!("{}", v)))
+                    .label_position(BarLabelPosition::Inside)
+                    .data(vec![(0, 8)]),
+            )
+            .expect("Drawing Error");
+    }
+
+    #[test]
+    fn test_histogram_width_fraction() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let bar_widths = |size: u32| {
+            let widths = Rc::new(RefCell::new(vec![]));
+            {
+                let drawing_area = create_mocked_drawing_area(size, size, |m| {
+                    for _ in 0..4 {
+                        let widths = widths.clone();
+                        m.check_draw_rect(move |_, _, _, u, d| {
+                            widths.borrow_mut().push((u.0, d.0));
+                        });
+                    }
+                });
+
+                let mut chart = ChartBuilder::on(&drawing_area)
+                    .build_ranged(0..4, 0..10)
+                    .expect("Build chart error");
+
+                chart
+                    .draw_series(
+                        Histogram::vertical(&chart)
+                            .margin(0)
+                            .width_fraction(0.8)
+                            .data((0..4).map(|x| (x, 5))),
+                    )
+                    .expect("Drawing Error");
+            }
+            let widths = widths.borrow();
+            let bar = widths[0].1 - widths[0].0;
+            let gap = widths[1].0 - widths[0].1;
+            f64::from(bar) / f64::from(gap)
+        };
+
+        assert_eq!(bar_widths(200), 4.0);
+        assert_eq!(bar_widths(2000), 4.0);
+    }
+
+    #[test]
+    fn test_histogram_width_fraction_uniform() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let widths = Rc::new(RefCell::new(vec![]));
+        {
+            // The slots of the three categories are 33, 33 and 34 pixels wide on both axes
+            let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+                for _ in 0..6 {
+                    let widths = widths.clone();
+                    m.check_draw_rect(move |_, _, _, u, d| {
+                        widths
+                            .borrow_mut()
+                            .push(((d.0 - u.0).abs(), (d.1 - u.1).abs()))
+                    });
+                }
+            });
+
+            let mut chart = ChartBuilder::on(&drawing_area)
+                .build_ranged(0..3, 0..3)
+                .expect("Build chart error");
+
+            let data = || (0..3).map(|x| (x, 2));
+            chart
+                .draw_series(
+                    Histogram::vertical(&chart)
+                        .margin(0)
+                        .width_fraction(0.8)
+                        .data(data()),
+                )
+                .expect("Drawing Error");
+            chart
+                .draw_series(
+                    Histogram::horizontal(&chart)
+                        .margin(0)
+                        .width_fraction(0.8)
+                        .data(data()),
+                )
+                .expect("Drawing Error");
+        }
+        let widths = widths.borrow();
+        assert_eq!(widths.len(), 6);
+        // The first slot is 33 pixels wide on the X axis, and 34 pixels on the flipped Y axis
+        assert!(widths[..3].iter().all(|&(w, _)| w == 26));
+        assert!(widths[3..].iter().all(|&(_, h)| h == 27));
+    }
+}
@@ -0,0 +1,163 @@
+use std::marker::PhantomData;
+
+use super::histogram::{HistogramType, Horizontal, Vertical};
+use super::HistogramBar;
+use crate::chart::ChartContext;
+use crate::coord::{DiscreteRanged, Ranged, RangedCoord};
+use crate::drawing::DrawingBackend;
+use crate::element::Rectangle;
+use crate::style::ShapeStyle;
+
+/// The series that draws a bar for each interval at the slot of its category, such as the
+/// tasks of a Gantt chart. The intervals of the same category share the row, they're never
+/// stacked.
+pub struct IntervalSeries<BR, A, Tag = Horizontal>
+where
+    BR: DiscreteRanged,
+    BR::ValueType: Eq,
+    Tag: HistogramType,
+{
+    data: std::vec::IntoIter<(BR::ValueType, A, A, ShapeStyle)>,
+    margin: u32,
+    fraction: f64,
+    _p: PhantomData<(BR, Tag)>,
+}
+
+impl<BR, A, Tag> IntervalSeries<BR, A, Tag>
+where
+    BR: DiscreteRanged,
+    BR::ValueType: Eq,
+    Tag: HistogramType,
+{
+    fn empty() -> Self {
+        Self {
+            data: vec![].into_iter(),
+            margin: 0,
+            fraction: 1.0,
+            _p: PhantomData,
+        }
+    }
+
+    /// Set the margin in pixels between the bars and the edges of their row
+    pub fn margin(mut self, margin: u32) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Set the thickness of the bars as a fraction of their row
+    pub fn width_fraction(mut self, fraction: f64) -> Self {
+        self.fraction = fraction.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Set the data iterator, which yields `(category, start, end, style)` tuples
+    pub fn data<S: Into<ShapeStyle>, I: IntoIterator<Item = (BR::ValueType, A, A, S)>>(
+        mut self,
+        iter: I,
+    ) -> Self {
+        self.data = iter
+            .into_iter()
+            .map(|(key, start, end, style)| (key, start, end, style.into()))
+            .collect::<Vec<_>>()
+            .into_iter();
+        self
+    }
+}
+
+impl<BR, A> IntervalSeries<BR, A, Horizontal>
+where
+    BR: DiscreteRanged,
+    BR::ValueType: Eq,
+{
+    /// Create a series of horizontal intervals, which have the categories on the Y axis
+    pub fn horizontal<ACoord, DB: DrawingBackend>(
+        _: &ChartContext<DB, RangedCoord<ACoord, BR>>,
+    ) -> Self
+    where
+        ACoord: Ranged<ValueType = A>,
+    {
+        Self::empty()
+    }
+}
+
+impl<BR, A> IntervalSeries<BR, A, Vertical>
+where
+    BR: DiscreteRanged,
+    BR::ValueType: Eq,
+{
+    /// Create a series of vertical intervals, which have the categories on the X axis
+    pub fn vertical<ACoord, DB: DrawingBackend>(
+        _: &ChartContext<DB, RangedCoord<BR, ACoord>>,
+    ) -> Self
+    where
+        ACoord: Ranged<ValueType = A>,
+    {
+        Self::empty()
+    }
+}
+
+impl<BR, A> Iterator for IntervalSeries<BR, A, Horizontal>
+where
+    BR: DiscreteRanged,
+    BR::ValueType: Eq,
+{
+    type Item = HistogramBar<'static, (A, BR::ValueType)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let (y, start, end, style) = self.data.next()?;
+        let ny = BR::next_value(&y);
+        let mut rect = Rectangle::new([(end, y), (start, ny)], style);
+        rect.set_margin(self.margin, self.margin, 0, 0);
+        Some(HistogramBar::new(rect, true).with_width_fraction(self.fraction))
+    }
+}
+
+impl<BR, A> Iterator for IntervalSeries<BR, A, Vertical>
+where
+    BR: DiscreteRanged,
+    BR::ValueType: Eq,
+{
+    type Item = HistogramBar<'static, (BR::ValueType, A)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let (x, start, end, style) = self.data.next()?;
+        let nx = BR::next_value(&x);
+        let mut rect = Rectangle::new([(x, end), (nx, start)], style);
+        rect.set_margin(0, 0, self.margin, self.margin);
+        Some(HistogramBar::new(rect, false).with_width_fraction(self.fraction))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_interval_series() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            for &(x0, x1, y0, y1) in
+                [(20, 60, 25, 75), (100, 180, 25, 75), (40, 120, 125, 175)].iter()
+            {
+                m.check_draw_rect(move |_, _, _, u, d| {
+                    assert_eq!((u.0.min(d.0), u.0.max(d.0)), (x0, x1));
+                    assert_eq!((u.1.min(d.1), u.1.max(d.1)), (y0, y1));
+                });
+            }
+            m.drop_check(|b| assert_eq!(b.num_draw_rect_call, 3));
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0.0..10.0, 0..2)
+            .expect("Build chart error");
+
+        chart
+            .draw_series(
+                IntervalSeries::horizontal(&chart)
+                    .width_fraction(0.5)
+                    .data(vec![
+                        (1, 1.0, 3.0, RED.filled()),
+                        (1, 5.0, 9.0, RED.filled()),
+                        (0, 2.0, 6.0, BLUE.filled()),
+                    ]),
+            )
+            .expect("Drawing Error");
+    }
+}
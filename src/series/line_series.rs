@@ -1,6 +1,10 @@
-use crate::element::PathElement;
+use crate::drawing::DrawingBackend;
+use crate::element::{Circle, Drawable, DynElement, IntoDynElement, PathElement, PointCollection};
 use crate::style::ShapeStyle;
 
+type MakeMarker<'a, DB, Coord> =
+    Box<dyn Fn(Coord, u32, ShapeStyle) -> DynElement<'a, DB, Coord> + 'a>;
+
 /// The line series object, which takes an iterator of points in guest coordinate system
 /// and creates the element rendering the line plot
 pub struct LineSeries<Coord, I: IntoIterator<Item = Coord>> {
@@ -31,6 +35,97 @@ impl<Coord, I: IntoIterator<Item = Coord>> LineSeries<Coord, I> {
             data_iter: Some(iter.into_iter()),
         }
     }
+
+    /// Draw a circle marker of the given size at each point, filled with the color of the line
+    pub fn point_size<'a, DB: DrawingBackend + 'a>(
+        self,
+        size: u32,
+    ) -> MarkedLineSeries<'a, DB, Coord>
+    where
+        Coord: Clone + 'a,
+    {
+        MarkedLineSeries::new(self).point_size(size)
+    }
+
+    /// Draw a circle marker with the given style at each point
+    pub fn point_style<'a, DB: DrawingBackend + 'a, S: Into<ShapeStyle>>(
+        self,
+        style: S,
+    ) -> MarkedLineSeries<'a, DB, Coord>
+    where
+        Coord: Clone + 'a,
+    {
+        MarkedLineSeries::new(self).point_style(style)
+    }
+}
+
+/// The line series that also draws a marker at each point, which is created by `point_size`
+/// or `point_style` of a line series. The data is only iterated once, the markers are made
+/// first and the path takes the data, but the path is emitted first, so the markers are drawn
+/// over the line.
+pub struct MarkedLineSeries<'a, DB: DrawingBackend, Coord: Clone> {
+    style: ShapeStyle,
+    data: Option<Vec<Coord>>,
+    markers: std::vec::IntoIter<DynElement<'a, DB, Coord>>,
+    point_size: u32,
+    point_style: ShapeStyle,
+    make_point: MakeMarker<'a, DB, Coord>,
+}
+
+impl<'a, DB: DrawingBackend + 'a, Coord: Clone + 'a> MarkedLineSeries<'a, DB, Coord> {
+    fn new<I: IntoIterator<Item = Coord>>(line: LineSeries<Coord, I>) -> Self {
+        Self {
+            point_style: line.style.filled(),
+            style: line.style,
+            data: line.data_iter.map(Iterator::collect),
+            markers: vec![].into_iter(),
+            point_size: 3,
+            make_point: Box::new(|coord, size, style| Circle::new(coord, size, style).into_dyn()),
+        }
+    }
+
+    /// Set the size of the markers
+    pub fn point_size(mut self, size: u32) -> Self {
+        self.point_size = size;
+        self
+    }
+
+    /// Set the style of the markers
+    pub fn point_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.point_style = style.into();
+        self
+    }
+
+    /// Set the function that creates the marker of each point from its position, size and
+    /// style, such as `Cross::new` or a composed element
+    pub fn point_element<E, F>(mut self, make_point: F) -> Self
+    where
+        E: Drawable<DB> + 'a,
+        for<'b> &'b E: PointCollection<'b, Coord>,
+        F: Fn(Coord, u32, ShapeStyle) -> E + 'a,
+    {
+        self.make_point =
+            Box::new(move |coord, size, style| make_point(coord, size, style).into_dyn());
+        self
+    }
+}
+
+impl<'a, DB: DrawingBackend + 'a, Coord: Clone + 'a> Iterator for MarkedLineSeries<'a, DB, Coord> {
+    type Item = DynElement<'a, DB, Coord>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(data) = &self.data {
+            let markers: Vec<_> = data
+                .iter()
+                .map(|coord| {
+                    (self.make_point)(coord.clone(), self.point_size, self.point_style.clone())
+                })
+                .collect();
+            self.markers = markers.into_iter();
+            let data = std::mem::take(&mut self.data)?;
+            return Some(PathElement::new(data, self.style.clone()).into_dyn());
+        }
+        self.markers.next()
+    }
 }
 
 #[cfg(test)]
@@ -65,4 +160,45 @@ mod test {
             ))
             .expect("Drawing Error");
     }
+
+    #[test]
+    fn test_marked_line_series() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_path(|c, _, path| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!(path, [(0, 200), (100, 100), (200, 200)]);
+            });
+            m.check_draw_circle(|c, _, filled, center, size| {
+                assert_eq!(c, RED.to_rgba());
+                assert!(filled);
+                assert_eq!(center, (0, 200));
+                assert_eq!(size, 4);
+            });
+            m.check_draw_circle(|_, _, _, center, _| assert_eq!(center, (100, 100)));
+            m.check_draw_circle(|_, _, _, center, _| assert_eq!(center, (200, 200)));
+            m.check_draw_line(|c, _, _, _| assert_eq!(c, BLUE.to_rgba()));
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_path_call, 2);
+                assert_eq!(b.num_draw_circle_call, 3);
+                // Each cross is made of two lines
+                assert_eq!(b.num_draw_line_call, 6);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0..2, 0..2)
+            .expect("Build chart error");
+
+        let data = vec![(0, 0), (1, 1), (2, 0)];
+        chart
+            .draw_series(LineSeries::new(data.clone(), &RED).point_size(4))
+            .expect("Drawing Error");
+        chart
+            .draw_series(
+                LineSeries::new(data, &RED)
+                    .point_style(&BLUE)
+                    .point_element(Cross::new),
+            )
+            .expect("Drawing Error");
+    }
 }
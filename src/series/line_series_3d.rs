@@ -0,0 +1,183 @@
+use super::scatter_series_3d::{attenuate_alpha, attenuate_size};
+use crate::chart::ChartContext;
+use crate::coord::{Cartesian3d, Ranged};
+use crate::drawing::DrawingBackend;
+use crate::element::PathElement;
+use crate::style::ShapeStyle;
+
+/// The series that draws a polyline through `(x, y, z)` points on a 3D chart. The line can be
+/// made wider or more opaque the nearer it is to the viewer, in which case each segment is
+/// drawn separately. The line is broken at the points behind the camera of a perspective
+/// projection, instead of connecting them.
+pub struct LineSeries3d<Coord> {
+    runs: Vec<Vec<(Coord, f64)>>,
+    style: ShapeStyle,
+    size_attenuation: bool,
+    alpha_attenuation: bool,
+}
+
+impl<X, Y, Z> LineSeries3d<(X, Y, Z)> {
+    /// Create a new 3D line series
+    /// - `chart`: The 3D chart the series is drawn on, which gives the projection
+    /// - `iter`: The iterator of the points
+    /// - `style`: The style of the line
+    pub fn new<DB, XR, YR, ZR, I, S>(
+        chart: &ChartContext<DB, Cartesian3d<XR, YR, ZR>>,
+        iter: I,
+        style: S,
+    ) -> Self
+    where
+        DB: DrawingBackend,
+        XR: Ranged<ValueType = X>,
+        YR: Ranged<ValueType = Y>,
+        ZR: Ranged<ValueType = Z>,
+        I: IntoIterator<Item = (X, Y, Z)>,
+        S: Into<ShapeStyle>,
+    {
+        let mut runs = vec![vec![]];
+        for point in iter {
+            if chart.is_visible(&point) {
+                let nearness = chart.projected_nearness(&point);
+                runs.last_mut().unwrap().push((point, nearness));
+            } else if !runs.last().unwrap().is_empty() {
+                runs.push(vec![]);
+            }
+        }
+        runs.retain(|run| !run.is_empty());
+        Self {
+            runs,
+            style: style.into(),
+            size_attenuation: false,
+            alpha_attenuation: false,
+        }
+    }
+
+    /// Set if the nearer segments are wider, from half of the stroke width for the farthest
+    /// points of the chart to one and a half of it for the nearest ones
+    pub fn size_attenuation(mut self, enabled: bool) -> Self {
+        self.size_attenuation = enabled;
+        self
+    }
+
+    /// Set if the nearer segments are more opaque, from a quarter of the opacity for the
+    /// farthest points of the chart to the full opacity for the nearest ones
+    pub fn alpha_attenuation(mut self, enabled: bool) -> Self {
+        self.alpha_attenuation = enabled;
+        self
+    }
+}
+
+impl<Coord: Clone> IntoIterator for LineSeries3d<Coord> {
+    type Item = PathElement<Coord>;
+    type IntoIter = std::vec::IntoIter<Self::Item>;
+    fn into_iter(self) -> Self::IntoIter {
+        let style = self.style;
+        if !self.size_attenuation && !self.alpha_attenuation {
+            return self
+                .runs
+                .into_iter()
+                .map(|run| {
+                    PathElement::new(
+                        run.into_iter().map(|p| p.0).collect::<Vec<_>>(),
+                        style.clone(),
+                    )
+                })
+                .collect::<Vec<_>>()
+                .into_iter();
+        }
+
+        let mut segments = vec![];
+        for run in self.runs {
+            for pair in run.windows(2) {
+                let nearness = (pair[0].1 + pair[1].1) / 2.0;
+                let mut segment_style = if self.alpha_attenuation {
+                    attenuate_alpha(&style, nearness)
+                } else {
+                    style.clone()
+                };
+                if self.size_attenuation {
+                    segment_style.stroke_width = attenuate_size(style.stroke_width, nearness);
+                }
+                segments.push(PathElement::new(
+                    vec![pair[0].0.clone(), pair[1].0.clone()],
+                    segment_style,
+                ));
+            }
+        }
+        segments.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_line_series_3d() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_path(|_, _, path| assert_eq!(path.len(), 2));
+            m.check_draw_path(|_, _, path| assert_eq!(path.len(), 3));
+            m.drop_check(|b| assert_eq!(b.num_draw_path_call, 2));
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_3d(0.0..1.0, 0.0..1.0, 0.0..1.0)
+            .expect("Build chart error");
+        chart.with_projection(|mut p| {
+            p.perspective = Some(2.0);
+            p
+        });
+
+        // The point behind the camera breaks the line into two parts
+        let points = vec![
+            (0.0, 0.0, 0.0),
+            (1.0, 1.0, 1.0),
+            (0.5, 0.5, -5.0),
+            (0.0, 1.0, 0.0),
+            (1.0, 0.0, 1.0),
+            (0.5, 0.5, 0.5),
+        ];
+        chart
+            .draw_series(LineSeries3d::new(&chart, points, &RED))
+            .expect("Drawing Error");
+    }
+
+    #[test]
+    fn test_line_series_3d_attenuation() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_path(|c, w, _| {
+                assert_eq!(w, 5);
+                assert!(c.alpha() > 0.8);
+            });
+            m.check_draw_path(|_, w, _| assert_eq!(w, 4));
+            m.check_draw_path(|c, w, _| {
+                assert_eq!(w, 3);
+                assert!(c.alpha() < 0.5);
+            });
+            m.drop_check(|b| assert_eq!(b.num_draw_path_call, 3));
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_3d(0.0..1.0, 0.0..1.0, 0.0..1.0)
+            .expect("Build chart error");
+        chart.with_projection(|mut p| {
+            p.yaw = 0.0;
+            p.pitch = 0.0;
+            p
+        });
+
+        let points = vec![
+            (0.0, 0.5, 0.0),
+            (1.0, 0.5, 0.0),
+            (1.0, 0.5, 1.0),
+            (0.0, 0.5, 1.0),
+        ];
+        chart
+            .draw_series(
+                LineSeries3d::new(&chart, points, RED.stroke_width(4))
+                    .size_attenuation(true)
+                    .alpha_attenuation(true),
+            )
+            .expect("Drawing Error");
+    }
+}
@@ -11,11 +11,65 @@
 */
 
 mod area_series;
+mod band_series;
+mod binned_histogram;
+mod boxplot_series;
+mod candle_series;
+mod contour_series;
+mod cumulative_series;
+mod dumbbell_series;
+mod errorbar_series;
+mod event_series;
+mod gapped_line_series;
+mod heatmap_series;
+mod hist2d_series;
 mod histogram;
+mod interval_series;
 mod line_series;
+mod line_series_3d;
+mod pareto_series;
+mod pie_series;
 mod point_series;
+mod quiver_series;
+mod radar_series;
+mod rolling;
+mod scatter_series_3d;
+mod smooth_line_series;
+mod stacked_area_series;
+mod stem_series;
+mod step_line_series;
+mod surface_series;
+mod trend_line;
 
 pub use area_series::AreaSeries;
-pub use histogram::Histogram;
-pub use line_series::LineSeries;
-pub use point_series::PointSeries;
+pub use band_series::BandSeries;
+pub use binned_histogram::{BinnedHistogram, Bins};
+pub use boxplot_series::BoxplotSeries;
+pub use candle_series::CandleSeries;
+pub use contour_series::ContourSeries;
+pub use cumulative_series::CumulativeSeries;
+pub use dumbbell_series::{Dumbbell, DumbbellSeries};
+pub use errorbar_series::{ErrorBarData, ErrorBarSeries};
+pub use event_series::{EventSeries, Events};
+pub use gapped_line_series::GappedLineSeries;
+pub use heatmap_series::HeatmapSeries;
+pub use hist2d_series::Hist2DSeries;
+pub use histogram::{BarLabelPosition, BarOrdering, Histogram, HistogramBar};
+pub use interval_series::IntervalSeries;
+pub use line_series::{LineSeries, MarkedLineSeries};
+pub use line_series_3d::LineSeries3d;
+pub use pareto_series::ParetoSeries;
+pub use pie_series::PieSeries;
+pub use point_series::{
+    LabeledPoint, LabeledPointSeries, OffsetPoint, PointLabelPosition, PointSeries, SizeScale,
+};
+pub use quiver_series::{QuiverScale, QuiverSeries};
+pub use radar_series::RadarSeries;
+pub use rolling::{Rolling, RollingIter};
+pub use scatter_series_3d::ScatterSeries3d;
+pub use smooth_line_series::{Interpolation, SmoothLineSeries, SmoothPath};
+pub use stacked_area_series::{StackError, StackedAreaSeries};
+pub use stem_series::StemSeries;
+pub use step_line_series::{StepLineSeries, StepPath, StepPosition};
+pub use surface_series::SurfaceSeries;
+pub use trend_line::{TrendError, TrendLine};
@@ -0,0 +1,164 @@
+use super::LineSeries;
+use crate::element::Rectangle;
+use crate::style::ShapeStyle;
+
+/// The helper that builds a Pareto chart, which is made of the bars of the categories sorted
+/// by descending value and the line of their cumulative percentage.
+///
+/// The bars are placed on a discrete `0..n` X axis in the order of `categories`, which can be
+/// used for the axis labels. The line is meant for a secondary coordinate with the X range
+/// `0.0..n` and the Y range `0.0..100.0`, so that its points are at the centers of the bars.
+pub struct ParetoSeries<L> {
+    categories: Vec<L>,
+    values: Vec<f64>,
+    margin: u32,
+}
+
+impl<L> ParetoSeries<L> {
+    /// Create a new Pareto chart from the `(category, value)` pairs. The categories with the
+    /// same value keep their order, and the ones whose value is NaN or infinite are dropped.
+    pub fn new<I: IntoIterator<Item = (L, f64)>>(iter: I) -> Self {
+        let mut data: Vec<_> = iter
+            .into_iter()
+            .filter(|(_, value)| value.is_finite())
+            .collect();
+        data.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        let (categories, values) = data.into_iter().unzip();
+        Self {
+            categories,
+            values,
+            margin: 5,
+        }
+    }
+
+    /// Set the margin between the bars in pixels
+    pub fn margin(mut self, margin: u32) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Get the categories in the order of the bars
+    pub fn categories(&self) -> &[L] {
+        &self.categories
+    }
+
+    /// Get the values in the order of the bars
+    pub fn values(&self) -> &[f64] {
+        &self.values
+    }
+
+    /// Get the cumulative percentage after each bar, the last one is always exactly 100
+    pub fn cumulative_percents(&self) -> Vec<f64> {
+        let total: f64 = self.values.iter().sum();
+        let mut sum = 0.0;
+        let mut ret: Vec<_> = self
+            .values
+            .iter()
+            .map(|value| {
+                sum += value;
+                if total == 0.0 {
+                    0.0
+                } else {
+                    sum / total * 100.0
+                }
+            })
+            .collect();
+        if let Some(last) = ret.last_mut() {
+            *last = 100.0;
+        }
+        ret
+    }
+
+    /// Get the series of the bars for the primary coordinate
+    pub fn bars<S: Into<ShapeStyle>>(&self, style: S) -> std::vec::IntoIter<Rectangle<(u32, f64)>> {
+        let style = style.into();
+        self.values
+            .iter()
+            .enumerate()
+            .map(|(idx, &value)| {
+                let idx = idx as u32;
+                let mut rect = Rectangle::new([(idx, value), (idx + 1, 0.0)], style.clone());
+                rect.set_margin(0, 0, self.margin, self.margin);
+                rect
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+
+    /// Get the series of the cumulative percentage line for the secondary coordinate
+    pub fn line<S: Into<ShapeStyle>>(
+        &self,
+        style: S,
+    ) -> LineSeries<(f64, f64), std::vec::IntoIter<(f64, f64)>> {
+        let points: Vec<_> = self
+            .cumulative_percents()
+            .into_iter()
+            .enumerate()
+            .map(|(idx, percent)| (idx as f64 + 0.5, percent))
+            .collect();
+        LineSeries::new(points.into_iter(), style)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_pareto_order() {
+        let pareto = ParetoSeries::new(vec![("a", 1.0), ("b", 3.0), ("c", 1.0), ("d", 2.0)]);
+        assert_eq!(pareto.categories(), ["b", "d", "a", "c"]);
+        assert_eq!(pareto.values(), [3.0, 2.0, 1.0, 1.0]);
+        assert_eq!(
+            pareto.cumulative_percents(),
+            [
+                3.0 / 7.0 * 100.0,
+                5.0 / 7.0 * 100.0,
+                6.0 / 7.0 * 100.0,
+                100.0
+            ]
+        );
+    }
+
+    #[test]
+    fn test_pareto_non_finite() {
+        let data: Vec<_> = (0..50)
+            .map(|x| match x {
+                10 => (x, f64::NAN),
+                20 => (x, f64::INFINITY),
+                _ => (x, f64::from(x % 9)),
+            })
+            .collect();
+        let pareto = ParetoSeries::new(data);
+        assert_eq!(pareto.values().len(), 48);
+        assert!(pareto.values().windows(2).all(|w| w[0] >= w[1]));
+        assert!(!pareto.categories().contains(&10) && !pareto.categories().contains(&20));
+        assert_eq!(pareto.cumulative_percents().last(), Some(&100.0));
+    }
+
+    #[test]
+    fn test_pareto_series() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_rect(|_, _, _, u, d| assert_eq!([u, d], [(5, 0), (95, 200)]));
+            m.check_draw_rect(|_, _, _, u, d| assert_eq!([u, d], [(105, 100), (195, 200)]));
+            m.check_draw_path(|_, _, path| assert_eq!(path, [(50, 66), (150, 0)]));
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 2);
+                assert_eq!(b.num_draw_path_call, 1);
+            });
+        });
+
+        let pareto = ParetoSeries::new(vec![("a", 1.0), ("b", 2.0)]);
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0u32..2, 0.0..2.0)
+            .expect("Build chart error")
+            .set_secondary_coord(0.0..2.0, 0.0..100.0);
+
+        chart
+            .draw_series(pareto.bars(RED.filled()))
+            .expect("Drawing Error");
+        chart
+            .draw_secondary_series(pareto.line(&BLUE))
+            .expect("Drawing Error");
+    }
+}
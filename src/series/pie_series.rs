@@ -0,0 +1,155 @@
+use std::f64::consts::PI;
+
+use crate::element::Pie;
+use crate::style::{IntoFont, ShapeStyle, TextStyle};
+
+type PieLabelFunc<'a, L> = Box<dyn Fn(&L, f64) -> Option<String> + 'a>;
+
+/// The series that draws a pie chart, where the angle of each sector is proportional to its
+/// value. Since the radius is in pixels, the pie can be drawn on a chart as well as on a
+/// drawing area directly.
+pub struct PieSeries<'a, Coord, L> {
+    center: Coord,
+    radius: u32,
+    inner_radius: u32,
+    data: Vec<(L, f64, ShapeStyle)>,
+    start_angle: f64,
+    clockwise: bool,
+    label_func: Option<PieLabelFunc<'a, L>>,
+    label_style: TextStyle<'a>,
+    idx: usize,
+    offset: f64,
+}
+
+impl<'a, Coord, L> PieSeries<'a, Coord, L> {
+    /// Create a new pie series, which starts at the 12 o'clock position and goes clockwise
+    /// by default
+    /// - `center`: The center of the pie
+    /// - `radius`: The radius in pixels
+    /// - `iter`: The iterator of `(label, value, style)` triples, the values that aren't
+    ///   positive are skipped
+    pub fn new<S: Into<ShapeStyle>, I: IntoIterator<Item = (L, f64, S)>>(
+        center: Coord,
+        radius: u32,
+        iter: I,
+    ) -> Self {
+        Self {
+            center,
+            radius,
+            inner_radius: 0,
+            data: iter
+                .into_iter()
+                .filter(|(_, value, _)| *value > 0.0)
+                .map(|(label, value, style)| (label, value, style.into()))
+                .collect(),
+            start_angle: PI / 2.0,
+            clockwise: true,
+            label_func: None,
+            label_style: ("sans-serif", 12).into_font().into(),
+            idx: 0,
+            offset: 0.0,
+        }
+    }
+
+    /// Cut out the middle of the pie with the given radius in pixels, which makes a donut chart
+    pub fn donut(mut self, inner_radius: u32) -> Self {
+        self.inner_radius = inner_radius;
+        self
+    }
+
+    /// Set the angle the first sector starts at, in radians counterclockwise from the
+    /// 3 o'clock position
+    pub fn start_angle(mut self, angle: f64) -> Self {
+        self.start_angle = angle;
+        self
+    }
+
+    /// Set if the sectors follow each other clockwise or counterclockwise
+    pub fn clockwise(mut self, clockwise: bool) -> Self {
+        self.clockwise = clockwise;
+        self
+    }
+
+    /// Set the function that computes the label of each sector from its label and the
+    /// fraction of the total it covers. Returning `None` leaves the sector unlabelled.
+    pub fn label_func<F: Fn(&L, f64) -> Option<String> + 'a>(mut self, func: F) -> Self {
+        self.label_func = Some(Box::new(func));
+        self
+    }
+
+    /// Set the style of the labels
+    pub fn label_style<S: Into<TextStyle<'a>>>(mut self, style: S) -> Self {
+        self.label_style = style.into();
+        self
+    }
+}
+
+impl<'a, Coord: Clone, L> Iterator for PieSeries<'a, Coord, L> {
+    type Item = Pie<'a, Coord>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let (label, value, style) = self.data.get(self.idx)?;
+        let total: f64 = self.data.iter().map(|(_, value, _)| value).sum();
+        let fraction = value / total;
+        let direction = if self.clockwise { -1.0 } else { 1.0 };
+
+        let start = self.start_angle + direction * self.offset * 2.0 * PI;
+        self.offset += fraction;
+        let end = self.start_angle + direction * self.offset * 2.0 * PI;
+        self.idx += 1;
+
+        let mut pie = Pie::new(self.center.clone(), self.radius, start, end, style.clone())
+            .inner_radius(self.inner_radius);
+        if let Some(text) = self.label_func.as_ref().and_then(|f| f(label, fraction)) {
+            pie = pie.label(text, self.label_style.clone());
+        }
+        Some(pie)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_pie_series() {
+        let outlines = Rc::new(RefCell::new(vec![]));
+        let checked = outlines.clone();
+        let drawing_area = create_mocked_drawing_area(200, 200, move |m| {
+            m.check_fill_polygon(move |_, path| checked.borrow_mut().push(path));
+            m.check_draw_text(|_, _, _, _, text| assert_eq!(text, "75%"));
+            m.drop_check(|b| {
+                assert_eq!(b.num_fill_polygon_call, 2);
+                assert_eq!(b.num_draw_text_call, 1);
+            });
+        });
+
+        let series = PieSeries::new(
+            (100, 100),
+            50,
+            vec![("a", 3.0, RED.filled()), ("b", 1.0, BLUE.filled())],
+        )
+        .donut(20)
+        .label_func(|&label, fraction| {
+            if label == "a" {
+                Some(format!("{}%", fraction * 100.0))
+            } else {
+                None
+            }
+        });
+        for pie in series {
+            drawing_area.draw(&pie).expect("Drawing Error");
+        }
+        drop(drawing_area);
+
+        let outlines = outlines.borrow();
+        // The first sector goes clockwise from 12 o'clock to 9 o'clock
+        assert_eq!(outlines[0][0], (100, 50));
+        assert!(outlines[0].contains(&(150, 100)));
+        assert!(outlines[0].contains(&(100, 150)));
+        // The second one closes the circle
+        assert_eq!(outlines[1][0], (50, 100));
+        assert!(outlines[1].contains(&(100, 50)));
+    }
+}
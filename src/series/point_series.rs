@@ -1,5 +1,36 @@
-use crate::element::PointElement;
-use crate::style::{ShapeStyle, SizeDesc};
+use std::borrow::Borrow;
+
+use crate::chart::ChartContext;
+use crate::coord::{DiscreteRanged, Ranged, RangedCoord};
+use crate::drawing::backend::{BackendCoord, DrawingErrorKind};
+use crate::drawing::DrawingBackend;
+use crate::element::{Drawable, Marker, MarkerShape, PointCollection, PointElement};
+use crate::style::{ShapeStyle, SizeDesc, TextStyle};
+
+/// Describes how the value computed by the size function of a point series maps to the
+/// marker size
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SizeScale {
+    /// The value is the size of the marker in pixels
+    Radius,
+    /// The value is proportional to the area of the marker, so the size is its square root.
+    /// This is how bubble charts are usually scaled perceptually.
+    Area,
+}
+
+impl SizeScale {
+    fn apply(self, value: u32) -> u32 {
+        match self {
+            SizeScale::Radius => value,
+            SizeScale::Area => f64::from(value).sqrt().round() as u32,
+        }
+    }
+}
+
+type MakePointFunc<'a, Coord, Size, E> = Box<dyn Fn(Coord, Size, ShapeStyle) -> E + 'a>;
+type SizeFunc<'a, Coord, Size> = Box<dyn Fn(&Coord, SizeScale) -> Option<Size> + 'a>;
+type StyleFunc<'a, Coord> = Box<dyn Fn(&Coord) -> ShapeStyle + 'a>;
+type LabelFunc<'a, Coord> = Box<dyn Fn(&Coord) -> Option<String> + 'a>;
 
 /// The point plot object, which takes an iterator of points in guest coordinate system
 /// and create an element for each point
@@ -7,7 +38,10 @@ pub struct PointSeries<'a, Coord, I: IntoIterator<Item = Coord>, E, Size: SizeDe
     style: ShapeStyle,
     size: Size,
     data_iter: I::IntoIter,
-    make_point: &'a dyn Fn(Coord, Size, ShapeStyle) -> E,
+    make_point: MakePointFunc<'a, Coord, Size, E>,
+    size_func: Option<SizeFunc<'a, Coord, Size>>,
+    size_scale: SizeScale,
+    style_func: Option<StyleFunc<'a, Coord>>,
 }
 
 impl<'a, Coord, I: IntoIterator<Item = Coord>, E, Size: SizeDesc + Clone> Iterator
@@ -15,9 +49,31 @@ impl<'a, Coord, I: IntoIterator<Item = Coord>, E, Size: SizeDesc + Clone> Iterat
 {
     type Item = E;
     fn next(&mut self) -> Option<Self::Item> {
-        self.data_iter
-            .next()
-            .map(|x| (self.make_point)(x, self.size.clone(), self.style.clone()))
+        self.next_with(|_| ()).map(|(_, e)| e)
+    }
+}
+
+impl<'a, Coord, I: IntoIterator<Item = Coord>, E, Size: SizeDesc + Clone>
+    PointSeries<'a, Coord, I, E, Size>
+{
+    /// Get the next marker, along with the value the function computes from its point
+    fn next_with<T, F: FnMut(&Coord) -> T>(&mut self, mut func: F) -> Option<(T, E)> {
+        loop {
+            let coord = self.data_iter.next()?;
+            let size = match &self.size_func {
+                Some(func) => match func(&coord, self.size_scale) {
+                    Some(size) => size,
+                    None => continue,
+                },
+                None => self.size.clone(),
+            };
+            let style = match &self.style_func {
+                Some(func) => func(&coord),
+                None => self.style.clone(),
+            };
+            let extra = func(&coord);
+            return Some((extra, (self.make_point)(coord, size, style)));
+        }
     }
 }
 
@@ -34,11 +90,58 @@ where
             data_iter: iter.into_iter(),
             size,
             style: style.into(),
-            make_point: &|a, b, c| E::make_point(a, b, c),
+            make_point: Box::new(|a, b, c| E::make_point(a, b, c)),
+            size_func: None,
+            size_scale: SizeScale::Radius,
+            style_func: None,
+        }
+    }
+}
+
+impl<'a, Coord, I: IntoIterator<Item = Coord>, Size: SizeDesc + Clone>
+    PointSeries<'a, Coord, I, Marker<Coord, Size>, Size>
+{
+    /// Create a new point series of the markers of the given shape. Together with
+    /// `MarkerShape::cycle` and `Palette::pick`, this gives each series of a scatter plot a
+    /// distinct shape and color.
+    pub fn of_shape<S: Into<ShapeStyle>>(
+        iter: I,
+        size: Size,
+        shape: MarkerShape,
+        style: S,
+    ) -> Self {
+        Self {
+            data_iter: iter.into_iter(),
+            size,
+            style: style.into(),
+            make_point: Box::new(move |a, b, c| Marker::new(a, b, shape, c)),
+            size_func: None,
+            size_scale: SizeScale::Radius,
+            style_func: None,
         }
     }
 }
 
+impl<'a, Coord, I: IntoIterator<Item = Coord>, E> PointSeries<'a, Coord, I, E, u32>
+where
+    E: PointElement<Coord, u32>,
+{
+    /// Create a new point series where the size of each marker is computed from its point,
+    /// which makes a bubble chart. The points with a size of zero are skipped.
+    pub fn with_size_fn<S: Into<ShapeStyle>, F: Fn(&Coord) -> u32 + 'a>(
+        iter: I,
+        size_func: F,
+        style: S,
+    ) -> Self {
+        let mut ret = Self::new(iter, 0, style);
+        ret.size_func = Some(Box::new(move |coord, scale| match size_func(coord) {
+            0 => None,
+            value => Some(scale.apply(value)),
+        }));
+        ret
+    }
+}
+
 impl<'a, Coord, I: IntoIterator<Item = Coord>, E, Size: SizeDesc + Clone>
     PointSeries<'a, Coord, I, E, Size>
 {
@@ -55,7 +158,443 @@ impl<'a, Coord, I: IntoIterator<Item = Coord>, E, Size: SizeDesc + Clone>
             data_iter: iter.into_iter(),
             size,
             style: style.into(),
-            make_point: cons,
+            make_point: Box::new(cons),
+            size_func: None,
+            size_scale: SizeScale::Radius,
+            style_func: None,
+        }
+    }
+
+    /// Set how the values of the size function map to the marker sizes
+    pub fn size_scale(mut self, scale: SizeScale) -> Self {
+        self.size_scale = scale;
+        self
+    }
+
+    /// Set the function that computes the style of each marker from its point
+    pub fn style_func<F: Fn(&Coord) -> ShapeStyle + 'a>(mut self, func: F) -> Self {
+        self.style_func = Some(Box::new(func));
+        self
+    }
+
+    /// Attach a text label to each marker, which is placed above it by default
+    /// - `label_fn`: The function that produces the label of a point, the point isn't labelled
+    ///   if it returns `None`
+    /// - `style`: The text style of the labels
+    /// - `offset`: The offset in pixels of the labels from the markers
+    pub fn with_labels<F: Fn(&Coord) -> Option<String> + 'a, S: Into<TextStyle<'a>>>(
+        self,
+        label_fn: F,
+        style: S,
+        offset: (i32, i32),
+    ) -> LabeledPointSeries<'a, Coord, I, E, Size> {
+        LabeledPointSeries {
+            series: self,
+            label_func: Box::new(label_fn),
+            style: style.into(),
+            offset,
+            position: PointLabelPosition::Above,
         }
     }
 }
+
+/// Describes which side of a marker its label is placed on. The label is placed against the
+/// point moved by the label offset.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PointLabelPosition {
+    /// Centered above the marker, with the bottom of the label at the point
+    Above,
+    /// Centered below the marker, with the top of the label at the point
+    Below,
+    /// On the left of the marker, with the right end of the label at the point
+    Left,
+    /// On the right of the marker, with the left end of the label at the point
+    Right,
+}
+
+/// A point marker with an optional text label next to it, which is produced by
+/// `PointSeries::with_labels`
+pub struct LabeledPoint<'a, Coord, E> {
+    point: Coord,
+    inner: E,
+    label: Option<(String, TextStyle<'a>)>,
+    offset: BackendCoord,
+    position: PointLabelPosition,
+}
+
+impl<'a, 'b, Coord, E> PointCollection<'b, Coord> for &'b LabeledPoint<'a, Coord, E> {
+    type Borrow = &'b Coord;
+    type IntoIter = std::iter::Once<&'b Coord>;
+    fn point_iter(self) -> Self::IntoIter {
+        std::iter::once(&self.point)
+    }
+}
+
+impl<'a, Coord, E: Drawable<DB>, DB: DrawingBackend> Drawable<DB> for LabeledPoint<'a, Coord, E> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        parent_dim: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (x, y) = match points.next() {
+            Some(point) => point,
+            None => return Ok(()),
+        };
+        self.inner
+            .draw(std::iter::once((x, y)), backend, parent_dim)?;
+
+        // The label is drawn separately, so it's kept even if the marker is out of the area
+        if let Some((text, style)) = &self.label {
+            let (w, h) = backend.estimate_text_size(text, &style.font)?;
+            let (w, h) = (w as i32, h as i32);
+            let (x, y) = (x + self.offset.0, y + self.offset.1);
+            let pos = match self.position {
+                PointLabelPosition::Above => (x - w / 2, y - h),
+                PointLabelPosition::Below => (x - w / 2, y),
+                PointLabelPosition::Left => (x - w, y - h / 2),
+                PointLabelPosition::Right => (x, y - h / 2),
+            };
+            backend.draw_text(text, &style.font, pos, &style.color)?;
+        }
+        Ok(())
+    }
+}
+
+/// The point series with a text label attached to each marker, see `PointSeries::with_labels`
+pub struct LabeledPointSeries<'a, Coord, I: IntoIterator<Item = Coord>, E, Size: SizeDesc + Clone> {
+    series: PointSeries<'a, Coord, I, E, Size>,
+    label_func: LabelFunc<'a, Coord>,
+    style: TextStyle<'a>,
+    offset: BackendCoord,
+    position: PointLabelPosition,
+}
+
+impl<'a, Coord, I: IntoIterator<Item = Coord>, E, Size: SizeDesc + Clone>
+    LabeledPointSeries<'a, Coord, I, E, Size>
+{
+    /// Set which side of the markers the labels are placed on. The offset moves the labels from
+    /// the marker in all cases, so it usually points to the same side.
+    pub fn position(mut self, position: PointLabelPosition) -> Self {
+        self.position = position;
+        self
+    }
+}
+
+impl<'a, Coord: Clone, I: IntoIterator<Item = Coord>, E, Size: SizeDesc + Clone> Iterator
+    for LabeledPointSeries<'a, Coord, I, E, Size>
+{
+    type Item = LabeledPoint<'a, Coord, E>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let label_func = &self.label_func;
+        let ((point, label), inner) = self
+            .series
+            .next_with(|coord| (coord.clone(), label_func(coord)))?;
+        Some(LabeledPoint {
+            point,
+            inner,
+            label: label.map(|text| (text, self.style.clone())),
+            offset: self.offset,
+            position: self.position,
+        })
+    }
+}
+
+/// A point marker shifted by a fixed offset in pixels, which is produced by the `jitter` and
+/// the `beeswarm` modes of a point series
+pub struct OffsetPoint<Coord, E> {
+    point: Coord,
+    inner: E,
+    offset: BackendCoord,
+}
+
+impl<'b, Coord, E> PointCollection<'b, Coord> for &'b OffsetPoint<Coord, E> {
+    type Borrow = &'b Coord;
+    type IntoIter = std::iter::Once<&'b Coord>;
+    fn point_iter(self) -> Self::IntoIter {
+        std::iter::once(&self.point)
+    }
+}
+
+impl<Coord, E: Drawable<DB>, DB: DrawingBackend> Drawable<DB> for OffsetPoint<Coord, E> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        parent_dim: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (dx, dy) = self.offset;
+        self.inner
+            .draw(points.map(|(x, y)| (x + dx, y + dy)), backend, parent_dim)
+    }
+}
+
+/// A deterministic pseudo-random number in `[0, 1)` for the given seed and index
+fn jitter_random(seed: u64, idx: u64) -> f64 {
+    // The SplitMix64 finalizer, which is enough to decorrelate consecutive indices
+    let mut z = seed.wrapping_add(idx.wrapping_add(1).wrapping_mul(0x9E37_79B9_7F4A_7C15));
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^= z >> 31;
+    (z >> 11) as f64 / (1u64 << 53) as f64
+}
+
+impl<'a, X, Y, I, E, Size> PointSeries<'a, (X, Y), I, E, Size>
+where
+    X: Clone,
+    Y: Clone,
+    I: IntoIterator<Item = (X, Y)>,
+    Size: SizeDesc + Clone,
+    for<'b> &'b E: PointCollection<'b, (X, Y)>,
+{
+    /// Get the markers with the pixel position of their points and the pixel width of the
+    /// slot of their category on the discrete X axis
+    fn with_slots<DB: DrawingBackend, XR, YR>(
+        self,
+        chart: &ChartContext<DB, RangedCoord<XR, YR>>,
+    ) -> Vec<((X, Y), E, BackendCoord, i32)>
+    where
+        XR: DiscreteRanged<ValueType = X>,
+        X: Eq,
+        YR: Ranged<ValueType = Y>,
+    {
+        self.filter_map(|e| {
+            let (x, y) = (&e).point_iter().into_iter().next()?.borrow().clone();
+            let pos = chart.backend_coord(&(x.clone(), y.clone()));
+            let next = chart.backend_coord(&(XR::next_value(&x), y.clone()));
+            Some(((x, y), e, pos, (next.0 - pos.0).abs()))
+        })
+        .collect()
+    }
+
+    /// Spread the markers along the discrete X axis by a deterministic pseudo-random offset,
+    /// so the points of the same category don't overlap into a blob. This is meant for centric
+    /// discrete axes, where each category is drawn in the middle of its slot.
+    /// - `chart`: The chart the series is drawn on, which determines the slot widths
+    /// - `amount`: The range of the offsets as a fraction of the slot width, which is at most 1
+    ///   so the markers stay in the slot of their category
+    /// - `seed`: The seed of the offsets, the same seed always produces the same image
+    pub fn jitter<DB: DrawingBackend, XR, YR>(
+        self,
+        chart: &ChartContext<DB, RangedCoord<XR, YR>>,
+        amount: f64,
+        seed: u64,
+    ) -> std::vec::IntoIter<OffsetPoint<(X, Y), E>>
+    where
+        XR: DiscreteRanged<ValueType = X>,
+        X: Eq,
+        YR: Ranged<ValueType = Y>,
+    {
+        let amount = amount.clamp(0.0, 1.0);
+        self.with_slots(chart)
+            .into_iter()
+            .enumerate()
+            .map(|(idx, (point, inner, _, width))| {
+                let dx = (jitter_random(seed, idx as u64) - 0.5) * amount * f64::from(width);
+                OffsetPoint {
+                    point,
+                    inner,
+                    offset: (dx.round() as i32, 0),
+                }
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+
+    /// Spread the overlapping markers of each category symmetrically along the discrete X
+    /// axis, which is known as a beeswarm plot. Each marker takes the position closest to the
+    /// category that keeps it at least `spacing` pixels away from the markers placed before
+    /// it, as long as it stays in the slot of its category.
+    pub fn beeswarm<DB: DrawingBackend, XR, YR>(
+        self,
+        chart: &ChartContext<DB, RangedCoord<XR, YR>>,
+        spacing: u32,
+    ) -> std::vec::IntoIter<OffsetPoint<(X, Y), E>>
+    where
+        XR: DiscreteRanged<ValueType = X>,
+        X: Eq,
+        YR: Ranged<ValueType = Y>,
+    {
+        let spacing = spacing.max(1) as i32;
+        let mut placed: Vec<BackendCoord> = vec![];
+        self.with_slots(chart)
+            .into_iter()
+            .map(|(point, inner, (x, y), width)| {
+                let fits = |dx: i32| {
+                    placed.iter().all(|&(px, py)| {
+                        let (ox, oy) = (x + dx - px, y - py);
+                        ox * ox + oy * oy >= spacing * spacing
+                    })
+                };
+                let dx = (0..)
+                    .map(|k: i32| if k % 2 == 1 { (k + 1) / 2 } else { -k / 2 } * spacing)
+                    .take_while(|dx| dx.abs() * 2 <= width)
+                    .find(|&dx| fits(dx))
+                    .unwrap_or(0);
+                placed.push((x + dx, y));
+                OffsetPoint {
+                    point,
+                    inner,
+                    offset: (dx, 0),
+                }
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_bubble_series() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            for &(r, color) in [(3, 0), (2, 1)].iter() {
+                m.check_draw_circle(move |c, _, _, _, radius| {
+                    assert_eq!(radius, r);
+                    assert_eq!(c, [&RED, &BLUE][color].to_rgba());
+                });
+            }
+            m.drop_check(|b| assert_eq!(b.num_draw_circle_call, 2));
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0.0..10.0, 0.0..10.0)
+            .expect("Build chart error");
+
+        chart
+            .draw_series(
+                PointSeries::<_, _, Circle<_, _>, _>::with_size_fn(
+                    vec![(1.0, 9.0), (2.0, 0.0), (3.0, 4.0)],
+                    |&(_, y)| y as u32,
+                    &BLACK,
+                )
+                .size_scale(SizeScale::Area)
+                .style_func(|&(x, _)| if x < 2.0 { RED.filled() } else { BLUE.filled() }),
+            )
+            .expect("Drawing Error");
+    }
+
+    #[test]
+    fn test_shape_point_series() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_circle(|c, _, _, _, r| {
+                assert_eq!((c, r), (Palette99::pick(0).to_rgba(), 4))
+            });
+            m.check_fill_polygon(|c, path| {
+                assert_eq!(c, Palette99::pick(1).to_rgba());
+                assert_eq!(path.len(), 4);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_circle_call, 1);
+                assert_eq!(b.num_fill_polygon_call, 1);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0.0..10.0, 0.0..10.0)
+            .expect("Build chart error");
+
+        // Each series gets the shape and the color of its index
+        for (idx, point) in [(1.0, 1.0), (2.0, 2.0)].iter().enumerate() {
+            chart
+                .draw_series(PointSeries::of_shape(
+                    vec![*point],
+                    4,
+                    MarkerShape::cycle(idx),
+                    Palette99::pick(idx).filled(),
+                ))
+                .expect("Drawing Error");
+        }
+    }
+
+    #[test]
+    fn test_labeled_point_series() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_eq!(text, "a");
+                assert!(pos.0 > 100 && pos.1 < 100);
+            });
+            // The marker is out of the plot, but its label is still drawn at the edge
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_eq!(text, "c");
+                assert!(pos.0 > 200);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_circle_call, 3);
+                assert_eq!(b.num_draw_text_call, 2);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0..2, 0..2)
+            .expect("Build chart error");
+
+        chart
+            .draw_series(
+                PointSeries::<_, _, Circle<_, _>, _>::new(vec![(1, 1), (1, 0), (3, 1)], 2, &RED)
+                    .with_labels(
+                        |&(x, y)| match (x, y) {
+                            (1, 0) => None,
+                            (1, _) => Some("a".to_string()),
+                            _ => Some("c".to_string()),
+                        },
+                        ("sans-serif", 10),
+                        (5, 0),
+                    )
+                    .position(PointLabelPosition::Right),
+            )
+            .expect("Drawing Error");
+    }
+
+    #[test]
+    fn test_jitter_series() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_circle(|_, _, _, center, _| {
+                // The slot of the category is from 0 to 100, centered at 50
+                assert!(center.0 >= 25 && center.0 <= 75);
+            });
+            m.drop_check(|b| assert_eq!(b.num_draw_circle_call, 20));
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged((0..2).into_centric(), 0..10)
+            .expect("Build chart error");
+
+        let series = || {
+            PointSeries::<_, _, Circle<_, _>, _>::new((0..10).map(|y| (1, y)), 2, &RED)
+                .jitter(&chart, 0.5, 42)
+                .map(|p| p.offset)
+                .collect::<Vec<_>>()
+        };
+        let offsets = series();
+        assert_eq!(offsets, series());
+        assert!(offsets.iter().any(|&(dx, _)| dx != offsets[0].0));
+
+        let series = PointSeries::<_, _, Circle<_, _>, _>::new((0..10).map(|y| (1, y)), 2, &RED)
+            .jitter(&chart, 0.5, 42);
+        chart.draw_series(series).expect("Drawing Error");
+        let series = PointSeries::<_, _, Circle<_, _>, _>::new((0..10).map(|y| (1, y)), 2, &RED)
+            .beeswarm(&chart, 10);
+        chart.draw_series(series).expect("Drawing Error");
+    }
+
+    #[test]
+    fn test_beeswarm_series() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |_| {});
+        let chart = ChartBuilder::on(&drawing_area)
+            .build_ranged((0..2).into_centric(), 0..10)
+            .expect("Build chart error");
+
+        let offsets: Vec<_> = PointSeries::<_, _, Circle<_, _>, _>::new(
+            vec![(1, 5), (1, 5), (1, 5), (1, 0)],
+            2,
+            &RED,
+        )
+        .beeswarm(&chart, 10)
+        .map(|p| p.offset.0)
+        .collect();
+        assert_eq!(offsets, [0, 10, -10, 0]);
+    }
+}
@@ -0,0 +1,188 @@
+use crate::drawing::DrawingBackend;
+use crate::element::{Arrow, Circle, DynElement, EmptyElement, IntoDynElement};
+use crate::style::{Color, ColorMap, RGBColor, ShapeStyle};
+
+type MagnitudeColorMap<'a> = Box<dyn Fn(f64) -> RGBColor + 'a>;
+
+/// How the vectors of a quiver series are mapped to the length of their arrows
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum QuiverScale {
+    /// The arrow covers the vector multiplied by the factor in pixels, so the arrows have the
+    /// same length regardless of the aspect ratio of the chart
+    Pixels(f64),
+    /// The arrow covers the vector multiplied by the factor in data units
+    Data(f64),
+}
+
+/// The series that draws a vector field as an arrow at each sample point
+pub struct QuiverSeries<'a, DB: DrawingBackend> {
+    data: Vec<(f64, f64, f64, f64)>,
+    style: ShapeStyle,
+    head_size: u32,
+    scale: QuiverScale,
+    color_map: Option<MagnitudeColorMap<'a>>,
+    dot_size: Option<u32>,
+    idx: usize,
+    _p: std::marker::PhantomData<DB>,
+}
+
+impl<'a, DB: DrawingBackend> QuiverSeries<'a, DB> {
+    /// Create a new quiver series
+    /// - `iter`: The iterator of `(x, y, dx, dy)` samples
+    /// - `style`: The style of the arrows
+    pub fn new<S: Into<ShapeStyle>, I: IntoIterator<Item = (f64, f64, f64, f64)>>(
+        iter: I,
+        style: S,
+    ) -> Self {
+        Self {
+            data: iter.into_iter().collect(),
+            style: style.into(),
+            head_size: 8,
+            scale: QuiverScale::Data(1.0),
+            color_map: None,
+            dot_size: None,
+            idx: 0,
+            _p: std::marker::PhantomData,
+        }
+    }
+
+    /// Set the length of the arrow heads in pixels
+    pub fn head_size(mut self, size: u32) -> Self {
+        self.head_size = size;
+        self
+    }
+
+    /// Set how the vectors are mapped to the length of the arrows, which is the vector itself
+    /// in data units by default
+    pub fn scale(mut self, scale: QuiverScale) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Color the arrows by the magnitude of their vectors. The color map receives the magnitude
+    /// normalized by the largest one, which is in `[0, 1]`.
+    pub fn color_map<CM: ColorMap + 'a>(mut self, colormap: CM) -> Self {
+        self.color_map = Some(Box::new(move |value| colormap.eval(value)));
+        self
+    }
+
+    /// Draw the samples which have a zero vector as dots of the given radius, instead of
+    /// skipping them
+    pub fn zero_dot_size(mut self, size: u32) -> Self {
+        self.dot_size = Some(size);
+        self
+    }
+
+    fn style_of(&self, magnitude: f64) -> ShapeStyle {
+        let color_map = match &self.color_map {
+            Some(color_map) => color_map,
+            None => return self.style.clone(),
+        };
+        let max = self
+            .data
+            .iter()
+            .map(|&(_, _, dx, dy)| dx.hypot(dy))
+            .fold(0.0, f64::max);
+        let value = if max > 0.0 { magnitude / max } else { 0.0 };
+        ShapeStyle {
+            color: color_map(value).to_rgba(),
+            ..self.style.clone()
+        }
+    }
+}
+
+impl<'a, DB: DrawingBackend + 'a> Iterator for QuiverSeries<'a, DB> {
+    type Item = DynElement<'a, DB, (f64, f64)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let &(x, y, dx, dy) = self.data.get(self.idx)?;
+            self.idx += 1;
+
+            let magnitude = dx.hypot(dy);
+            let style = self.style_of(magnitude);
+            if magnitude == 0.0 {
+                match self.dot_size {
+                    Some(size) => {
+                        return Some(Circle::new((x, y), size, style.filled()).into_dyn())
+                    }
+                    None => continue,
+                }
+            }
+
+            return Some(match self.scale {
+                QuiverScale::Pixels(factor) => {
+                    let tip = ((dx * factor).round() as i32, -(dy * factor).round() as i32);
+                    (EmptyElement::at((x, y))
+                        + Arrow::new((0, 0), tip, style).head_size(self.head_size))
+                    .into_dyn()
+                }
+                QuiverScale::Data(factor) => {
+                    Arrow::new((x, y), (x + dx * factor, y + dy * factor), style)
+                        .head_size(self.head_size)
+                        .into_dyn()
+                }
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_quiver_series() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_line(|c, _, from, to| {
+                assert_eq!(c, BLUE.to_rgba());
+                assert_eq!(from, (0, 200));
+                assert_eq!(to, (95, 200));
+            });
+            m.check_draw_line(|c, _, from, to| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!(from, (100, 100));
+                assert_eq!(to, (100, 55));
+            });
+            m.check_draw_line(|c, _, from, to| {
+                assert_eq!(c, BLACK.to_rgba());
+                assert_eq!(from, (100, 100));
+                assert_eq!(to, (100, 50));
+            });
+            m.check_fill_polygon(|_, path| assert_eq!(path[0], (100, 200)));
+            m.check_fill_polygon(|_, path| assert_eq!(path[0], (100, 50)));
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_line_call, 3);
+                assert_eq!(b.num_fill_polygon_call, 2);
+                assert_eq!(b.num_draw_circle_call, 1);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0.0..2.0, 0.0..2.0)
+            .expect("Build chart error");
+
+        chart
+            .draw_series(
+                QuiverSeries::new(
+                    vec![
+                        (0.0, 0.0, 1.0, 0.0),
+                        (1.0, 1.0, 0.0, 0.5),
+                        (2.0, 2.0, 0.0, 0.0),
+                    ],
+                    &BLACK,
+                )
+                .head_size(5)
+                .zero_dot_size(2)
+                .color_map(|v| if v > 0.9 { BLUE } else { RED }),
+            )
+            .expect("Drawing Error");
+
+        chart
+            .draw_series(
+                QuiverSeries::new(vec![(1.0, 1.0, 0.0, 0.5)], &BLACK)
+                    .scale(QuiverScale::Pixels(100.0))
+                    .head_size(0),
+            )
+            .expect("Drawing Error");
+    }
+}
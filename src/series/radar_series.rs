@@ -0,0 +1,307 @@
+use std::f64::consts::PI;
+
+use crate::drawing::backend::{BackendCoord, DrawingErrorKind};
+use crate::drawing::DrawingBackend;
+use crate::element::{Drawable, DynElement, IntoDynElement, PathElement, PointCollection, Polygon};
+use crate::style::{Color, IntoFont, ShapeStyle, TextStyle, BLACK};
+
+/// The gap in pixels between the tip of a spoke and its label
+const LABEL_GAP: i32 = 5;
+
+/// The label at the tip of a spoke, which is anchored so that it extends away from the center
+struct SpokeLabel<'a> {
+    tip: BackendCoord,
+    text: String,
+    style: TextStyle<'a>,
+    angle: f64,
+}
+
+impl<'b, 'a> PointCollection<'a, BackendCoord> for &'a SpokeLabel<'b> {
+    type Borrow = &'a BackendCoord;
+    type IntoIter = std::iter::Once<&'a BackendCoord>;
+    fn point_iter(self) -> Self::IntoIter {
+        std::iter::once(&self.tip)
+    }
+}
+
+impl<'a, DB: DrawingBackend> Drawable<DB> for SpokeLabel<'a> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let tip = match points.next() {
+            Some(tip) => tip,
+            None => return Ok(()),
+        };
+        let (w, h) = backend.estimate_text_size(&self.text, &self.style.font)?;
+        let (w, h) = (w as i32, h as i32);
+        let (cos, sin) = (self.angle.cos(), self.angle.sin());
+        let x = if cos > 0.1 {
+            tip.0 + LABEL_GAP
+        } else if cos < -0.1 {
+            tip.0 - LABEL_GAP - w
+        } else {
+            tip.0 - w / 2
+        };
+        let y = if sin > 0.1 {
+            tip.1 - LABEL_GAP - h
+        } else if sin < -0.1 {
+            tip.1 + LABEL_GAP
+        } else {
+            tip.1 - h / 2
+        };
+        backend.draw_text(&self.text, &self.style.font, (x, y), &self.style.color)
+    }
+}
+
+/// The elements of a radar chart, in the order they're drawn
+enum RadarPart {
+    Ring(usize),
+    Spoke(usize),
+    Label(usize),
+    Fill(usize),
+    Stroke(usize),
+}
+
+/// The series that draws a radar chart in pixel coordinates, which is made of the spokes of
+/// the axes, the concentric grid polygons and a polygon for each data series. The first axis
+/// points to 12 o'clock and the others follow clockwise.
+pub struct RadarSeries<'a, DB: DrawingBackend> {
+    center: BackendCoord,
+    radius: u32,
+    labels: Vec<String>,
+    rings: usize,
+    max_values: Option<Vec<f64>>,
+    grid_style: ShapeStyle,
+    label_style: TextStyle<'a>,
+    data: Vec<(Vec<f64>, ShapeStyle, ShapeStyle)>,
+    parts: Option<std::vec::IntoIter<RadarPart>>,
+    _p: std::marker::PhantomData<DB>,
+}
+
+impl<'a, DB: DrawingBackend> RadarSeries<'a, DB> {
+    /// Create a new radar series
+    /// - `center`: The center of the chart in pixels
+    /// - `radius`: The length of the spokes in pixels
+    /// - `labels`: The labels of the axes
+    pub fn new<T: Into<String>, I: IntoIterator<Item = T>>(
+        center: BackendCoord,
+        radius: u32,
+        labels: I,
+    ) -> Self {
+        Self {
+            center,
+            radius,
+            labels: labels.into_iter().map(Into::into).collect(),
+            rings: 5,
+            max_values: None,
+            grid_style: (&BLACK.mix(0.3)).into(),
+            label_style: ("sans-serif", 12).into_font().into(),
+            data: vec![],
+            parts: None,
+            _p: std::marker::PhantomData,
+        }
+    }
+
+    /// Set the number of concentric grid polygons
+    pub fn rings(mut self, rings: usize) -> Self {
+        self.rings = rings;
+        self
+    }
+
+    /// Set the value at the tip of every spoke. By default, it's the largest value of all
+    /// the data series.
+    pub fn max_value(mut self, max: f64) -> Self {
+        self.max_values = Some(vec![max; self.labels.len()]);
+        self
+    }
+
+    /// Set the value at the tip of each spoke separately
+    pub fn axis_max_values<I: IntoIterator<Item = f64>>(mut self, max: I) -> Self {
+        self.max_values = Some(max.into_iter().collect());
+        self
+    }
+
+    /// Set the style of the spokes and the grid polygons
+    pub fn grid_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.grid_style = style.into();
+        self
+    }
+
+    /// Set the style of the axis labels
+    pub fn label_style<S: Into<TextStyle<'a>>>(mut self, style: S) -> Self {
+        self.label_style = style.into();
+        self
+    }
+
+    /// Add a data series, which has a value for each axis
+    /// - `values`: The values, the missing ones are treated as zero
+    /// - `stroke`: The style of the outline
+    /// - `fill`: The style of the area, which is usually translucent
+    pub fn series<I: IntoIterator<Item = f64>, S: Into<ShapeStyle>, F: Into<ShapeStyle>>(
+        mut self,
+        values: I,
+        stroke: S,
+        fill: F,
+    ) -> Self {
+        self.data
+            .push((values.into_iter().collect(), stroke.into(), fill.into()));
+        self
+    }
+
+    fn angle(&self, axis: usize) -> f64 {
+        PI / 2.0 - 2.0 * PI * axis as f64 / self.labels.len() as f64
+    }
+
+    /// Get the pixel position on the axis at the given fraction of the spoke length
+    fn spoke_point(&self, axis: usize, fraction: f64) -> BackendCoord {
+        let angle = self.angle(axis);
+        let length = f64::from(self.radius) * fraction;
+        (
+            self.center.0 + (length * angle.cos()).round() as i32,
+            self.center.1 - (length * angle.sin()).round() as i32,
+        )
+    }
+
+    fn max_value_of(&self, axis: usize) -> f64 {
+        match &self.max_values {
+            Some(max) => max.get(axis).cloned().unwrap_or(1.0),
+            None => self
+                .data
+                .iter()
+                .flat_map(|(values, _, _)| values.iter().cloned())
+                .fold(0.0, f64::max),
+        }
+    }
+
+    fn ring(&self, fraction: f64) -> Vec<BackendCoord> {
+        let mut points: Vec<_> = (0..self.labels.len())
+            .map(|axis| self.spoke_point(axis, fraction))
+            .collect();
+        points.push(points[0]);
+        points
+    }
+
+    fn data_points(&self, values: &[f64]) -> Vec<BackendCoord> {
+        (0..self.labels.len())
+            .map(|axis| {
+                let max = self.max_value_of(axis);
+                let value = values.get(axis).cloned().unwrap_or(0.0);
+                let fraction = if max > 0.0 { value / max } else { 0.0 };
+                self.spoke_point(axis, fraction.max(0.0))
+            })
+            .collect()
+    }
+}
+
+impl<'a, DB: DrawingBackend + 'a> Iterator for RadarSeries<'a, DB> {
+    type Item = DynElement<'a, DB, BackendCoord>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.labels.is_empty() {
+            return None;
+        }
+        if self.parts.is_none() {
+            let (rings, axes, series) = (self.rings, self.labels.len(), self.data.len());
+            let mut parts = vec![];
+            parts.extend((1..=rings).map(RadarPart::Ring));
+            parts.extend((0..axes).map(RadarPart::Spoke));
+            parts.extend((0..axes).map(RadarPart::Label));
+            parts.extend((0..series).map(RadarPart::Fill));
+            parts.extend((0..series).map(RadarPart::Stroke));
+            self.parts = Some(parts.into_iter());
+        }
+
+        Some(match self.parts.as_mut()?.next()? {
+            RadarPart::Ring(idx) => PathElement::new(
+                self.ring(idx as f64 / self.rings as f64),
+                self.grid_style.clone(),
+            )
+            .into_dyn(),
+            RadarPart::Spoke(idx) => PathElement::new(
+                vec![self.center, self.spoke_point(idx, 1.0)],
+                self.grid_style.clone(),
+            )
+            .into_dyn(),
+            RadarPart::Label(idx) => SpokeLabel {
+                tip: self.spoke_point(idx, 1.0),
+                text: self.labels[idx].clone(),
+                style: self.label_style.clone(),
+                angle: self.angle(idx),
+            }
+            .into_dyn(),
+            RadarPart::Fill(idx) => Polygon::new(
+                self.data_points(&self.data[idx].0),
+                self.data[idx].2.clone(),
+            )
+            .into_dyn(),
+            RadarPart::Stroke(idx) => {
+                let mut points = self.data_points(&self.data[idx].0);
+                points.push(points[0]);
+                PathElement::new(points, self.data[idx].1.clone()).into_dyn()
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_radar_series() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            // The two grid rings
+            m.check_draw_path(|_, _, path| {
+                assert_eq!(
+                    path,
+                    [(100, 75), (125, 100), (100, 125), (75, 100), (100, 75)]
+                );
+            });
+            m.check_draw_path(|_, _, path| {
+                assert_eq!(
+                    path,
+                    [(100, 50), (150, 100), (100, 150), (50, 100), (100, 50)]
+                );
+            });
+            for &tip in [(100, 50), (150, 100), (100, 150), (50, 100)].iter() {
+                m.check_draw_path(move |_, _, path| assert_eq!(path, [(100, 100), tip]));
+            }
+            m.check_draw_path(|c, _, path| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!(
+                    path,
+                    [(100, 50), (125, 100), (100, 100), (75, 100), (100, 50)]
+                );
+            });
+            m.check_fill_polygon(|c, path| {
+                assert_eq!(c, RED.mix(0.2));
+                assert_eq!(path, [(100, 50), (125, 100), (100, 100), (75, 100)]);
+            });
+            m.check_draw_text(|_, _, _, pos, text| {
+                if text == "b" {
+                    assert!(pos.0 > 150);
+                }
+                if text == "d" {
+                    assert!(pos.0 < 50);
+                }
+                if text == "a" {
+                    assert!(pos.1 < 50);
+                }
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_path_call, 7);
+                assert_eq!(b.num_fill_polygon_call, 1);
+                assert_eq!(b.num_draw_text_call, 4);
+            });
+        });
+
+        let series = RadarSeries::new((100, 100), 50, vec!["a", "b", "c", "d"])
+            .rings(2)
+            .series(vec![4.0, 2.0, 0.0, 2.0], &RED, &RED.mix(0.2));
+        for element in series {
+            drawing_area.draw(&element).expect("Drawing Error");
+        }
+    }
+}
@@ -0,0 +1,178 @@
+/// The aggregation a rolling window computes
+#[derive(Clone, Copy)]
+enum RollingOp {
+    Sum,
+    Mean,
+    Min,
+    Max,
+}
+
+impl RollingOp {
+    fn apply(self, values: &[f64]) -> f64 {
+        match self {
+            RollingOp::Sum => values.iter().sum(),
+            RollingOp::Mean => values.iter().sum::<f64>() / values.len() as f64,
+            RollingOp::Min => values.iter().cloned().fold(f64::NAN, f64::min),
+            RollingOp::Max => values.iter().cloned().fold(f64::NAN, f64::max),
+        }
+    }
+}
+
+/// The transformer that turns an iterator of `(x, y)` points into the points of a rolling
+/// window aggregation, such as a moving average. The window spans a number of points, not a
+/// distance on the X axis, and each result keeps the X value of the point it's computed for.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let data = vec![(0, 1.0), (1, 2.0), (2, 3.0), (3, 4.0)];
+/// let smoothed: Vec<_> = Rolling::new(data, 2).mean().collect();
+/// assert_eq!(smoothed, [(1, 1.5), (2, 2.5), (3, 3.5)]);
+/// ```
+pub struct Rolling<X> {
+    data: Vec<(X, f64)>,
+    window: usize,
+    centered: bool,
+    partial: bool,
+}
+
+impl<X> Rolling<X> {
+    /// Create a new rolling window transformer
+    /// - `iter`: The iterator of the points
+    /// - `window`: The number of points in the window
+    pub fn new<I: IntoIterator<Item = (X, f64)>>(iter: I, window: usize) -> Self {
+        Self {
+            data: iter.into_iter().collect(),
+            window: window.max(1),
+            centered: false,
+            partial: false,
+        }
+    }
+
+    /// Set if the window is centered on each point instead of ending at it. For an even
+    /// window, the center is the latter of the two middle points.
+    pub fn centered(mut self, centered: bool) -> Self {
+        self.centered = centered;
+        self
+    }
+
+    /// Set if the points near the ends that don't have a full window are emitted with the
+    /// aggregation of the partial window, instead of being skipped
+    pub fn partial(mut self, partial: bool) -> Self {
+        self.partial = partial;
+        self
+    }
+
+    /// Compute the sum of each window
+    pub fn sum(self) -> RollingIter<X> {
+        self.aggregate(RollingOp::Sum)
+    }
+
+    /// Compute the mean of each window
+    pub fn mean(self) -> RollingIter<X> {
+        self.aggregate(RollingOp::Mean)
+    }
+
+    /// Compute the minimum of each window
+    pub fn min(self) -> RollingIter<X> {
+        self.aggregate(RollingOp::Min)
+    }
+
+    /// Compute the maximum of each window
+    pub fn max(self) -> RollingIter<X> {
+        self.aggregate(RollingOp::Max)
+    }
+
+    fn aggregate(self, op: RollingOp) -> RollingIter<X> {
+        let (before, after) = if self.centered {
+            (self.window / 2, (self.window - 1) / 2)
+        } else {
+            (self.window - 1, 0)
+        };
+        let ys: Vec<_> = self.data.iter().map(|(_, y)| *y).collect();
+        RollingIter {
+            data: self.data.into_iter().enumerate(),
+            ys,
+            before,
+            after,
+            partial: self.partial,
+            op,
+        }
+    }
+}
+
+/// The iterator of the points computed by a `Rolling` transformer
+pub struct RollingIter<X> {
+    data: std::iter::Enumerate<std::vec::IntoIter<(X, f64)>>,
+    ys: Vec<f64>,
+    before: usize,
+    after: usize,
+    partial: bool,
+    op: RollingOp,
+}
+
+impl<X> Iterator for RollingIter<X> {
+    type Item = (X, f64);
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (idx, (x, _)) = self.data.next()?;
+            let full = idx >= self.before && idx + self.after < self.ys.len();
+            if !full && !self.partial {
+                continue;
+            }
+            let start = idx.saturating_sub(self.before);
+            let end = (idx + self.after + 1).min(self.ys.len());
+            return Some((x, self.op.apply(&self.ys[start..end])));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn data() -> Vec<(i32, f64)> {
+        vec![(0, 1.0), (1, 3.0), (2, 2.0), (3, 6.0), (4, 4.0)]
+    }
+
+    #[test]
+    fn test_rolling_trailing() {
+        let mean: Vec<_> = Rolling::new(data(), 3).mean().collect();
+        assert_eq!(mean, [(2, 2.0), (3, 11.0 / 3.0), (4, 4.0)]);
+
+        let sum: Vec<_> = Rolling::new(data(), 3).partial(true).sum().collect();
+        assert_eq!(sum, [(0, 1.0), (1, 4.0), (2, 6.0), (3, 11.0), (4, 12.0)]);
+
+        let min: Vec<_> = Rolling::new(data(), 2).min().collect();
+        assert_eq!(min, [(1, 1.0), (2, 2.0), (3, 2.0), (4, 4.0)]);
+    }
+
+    #[test]
+    fn test_rolling_centered() {
+        let max: Vec<_> = Rolling::new(data(), 3).centered(true).max().collect();
+        assert_eq!(max, [(1, 3.0), (2, 6.0), (3, 6.0)]);
+
+        let mean: Vec<_> = Rolling::new(data(), 3)
+            .centered(true)
+            .partial(true)
+            .mean()
+            .collect();
+        assert_eq!(
+            mean,
+            [(0, 2.0), (1, 2.0), (2, 11.0 / 3.0), (3, 4.0), (4, 5.0)]
+        );
+
+        // The even window has one more point before the center than after it
+        let sum: Vec<_> = Rolling::new(data(), 4).centered(true).sum().collect();
+        assert_eq!(sum, [(2, 12.0), (3, 15.0)]);
+    }
+
+    #[test]
+    fn test_rolling_short_input() {
+        assert_eq!(Rolling::new(data(), 10).mean().count(), 0);
+        assert_eq!(
+            Rolling::new(vec![(0, 1.0)], 0).sum().collect::<Vec<_>>(),
+            [(0, 1.0)]
+        );
+    }
+}
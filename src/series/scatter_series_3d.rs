@@ -0,0 +1,151 @@
+use crate::chart::ChartContext;
+use crate::coord::{Cartesian3d, Ranged};
+use crate::drawing::DrawingBackend;
+use crate::element::Circle;
+use crate::style::{Color, ShapeStyle};
+
+/// Scale the marker size by how near the point is, from half of the size for the farthest
+/// points to one and a half of it for the nearest ones
+pub(super) fn attenuate_size(size: u32, nearness: f64) -> u32 {
+    (f64::from(size) * (0.5 + nearness)).round() as u32
+}
+
+/// Scale the opacity of the style by how near the point is, from a quarter of the opacity for
+/// the farthest points to the full opacity for the nearest ones
+pub(super) fn attenuate_alpha(style: &ShapeStyle, nearness: f64) -> ShapeStyle {
+    ShapeStyle {
+        color: style.color.mix(0.25 + 0.75 * nearness),
+        ..style.clone()
+    }
+}
+
+/// The series that draws a circle marker for each `(x, y, z)` point on a 3D chart. The markers
+/// are drawn back to front, and they can be made larger or more opaque the nearer they are to
+/// the viewer, which helps perceiving the depth. The points behind the camera of a perspective
+/// projection are left out.
+pub struct ScatterSeries3d<Coord> {
+    points: Vec<(Coord, f64)>,
+    size: u32,
+    style: ShapeStyle,
+    size_attenuation: bool,
+    alpha_attenuation: bool,
+}
+
+impl<X, Y, Z> ScatterSeries3d<(X, Y, Z)> {
+    /// Create a new 3D scatter series
+    /// - `chart`: The 3D chart the series is drawn on, which gives the projection
+    /// - `iter`: The iterator of the points
+    /// - `size`: The radius of the markers
+    /// - `style`: The style of the markers
+    pub fn new<DB, XR, YR, ZR, I, S>(
+        chart: &ChartContext<DB, Cartesian3d<XR, YR, ZR>>,
+        iter: I,
+        size: u32,
+        style: S,
+    ) -> Self
+    where
+        DB: DrawingBackend,
+        XR: Ranged<ValueType = X>,
+        YR: Ranged<ValueType = Y>,
+        ZR: Ranged<ValueType = Z>,
+        I: IntoIterator<Item = (X, Y, Z)>,
+        S: Into<ShapeStyle>,
+    {
+        let mut points: Vec<_> = iter
+            .into_iter()
+            .filter(|p| chart.is_visible(p))
+            .map(|p| {
+                let nearness = chart.projected_nearness(&p);
+                (p, nearness)
+            })
+            .collect();
+        points.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
+        Self {
+            points,
+            size,
+            style: style.into(),
+            size_attenuation: false,
+            alpha_attenuation: false,
+        }
+    }
+
+    /// Set if the nearer markers are larger, from half of the size for the farthest points
+    /// of the chart to one and a half of it for the nearest ones
+    pub fn size_attenuation(mut self, enabled: bool) -> Self {
+        self.size_attenuation = enabled;
+        self
+    }
+
+    /// Set if the nearer markers are more opaque, from a quarter of the opacity for the
+    /// farthest points of the chart to the full opacity for the nearest ones
+    pub fn alpha_attenuation(mut self, enabled: bool) -> Self {
+        self.alpha_attenuation = enabled;
+        self
+    }
+}
+
+impl<Coord> IntoIterator for ScatterSeries3d<Coord> {
+    type Item = Circle<Coord, u32>;
+    type IntoIter = std::vec::IntoIter<Self::Item>;
+    fn into_iter(self) -> Self::IntoIter {
+        let (size, style) = (self.size, self.style);
+        let (size_attenuation, alpha_attenuation) = (self.size_attenuation, self.alpha_attenuation);
+        self.points
+            .into_iter()
+            .map(|(point, nearness)| {
+                let size = if size_attenuation {
+                    attenuate_size(size, nearness)
+                } else {
+                    size
+                };
+                let style = if alpha_attenuation {
+                    attenuate_alpha(&style, nearness)
+                } else {
+                    style.clone()
+                };
+                Circle::new(point, size, style)
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_scatter_series_3d() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            // The far point is drawn first, it's smaller and more transparent
+            m.check_draw_circle(|c, _, _, _, r| {
+                assert_eq!(r, 3);
+                assert!(c.alpha() < 0.5);
+            });
+            m.check_draw_circle(|c, _, _, _, r| {
+                assert_eq!(r, 5);
+                assert!(c.alpha() > 0.8);
+            });
+            m.drop_check(|b| assert_eq!(b.num_draw_circle_call, 2));
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_3d(0.0..1.0, 0.0..1.0, 0.0..1.0)
+            .expect("Build chart error");
+        chart.with_projection(|mut p| {
+            p.yaw = 0.0;
+            p.pitch = 0.0;
+            p.perspective = Some(2.0);
+            p
+        });
+
+        let points = vec![(0.5, 0.5, 0.0), (0.5, 0.5, -5.0), (0.5, 0.5, 1.0)];
+        chart
+            .draw_series(
+                ScatterSeries3d::new(&chart, points, 4, &RED)
+                    .size_attenuation(true)
+                    .alpha_attenuation(true),
+            )
+            .expect("Drawing Error");
+    }
+}
@@ -0,0 +1,320 @@
+use crate::drawing::backend::{BackendCoord, DrawingErrorKind};
+use crate::drawing::DrawingBackend;
+use crate::element::{Drawable, PointCollection};
+use crate::style::ShapeStyle;
+
+/// Describes the curve a smooth line draws through its points
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Interpolation {
+    /// The Catmull-Rom spline, whose tangents are controlled by the tension
+    CatmullRom,
+    /// The monotone cubic interpolation, which never overshoots between two points, so
+    /// monotone data stays monotone. This requires the X coordinates to be sorted.
+    MonotoneCubic,
+}
+
+fn to_f64(points: &[BackendCoord]) -> Vec<(f64, f64)> {
+    points
+        .iter()
+        .map(|&(x, y)| (f64::from(x), f64::from(y)))
+        .collect()
+}
+
+/// The tangents of the Catmull-Rom spline. A tension of 0 gives the plain Catmull-Rom
+/// spline and a tension of 1 gives straight lines.
+fn catmull_rom_tangents(points: &[(f64, f64)], tension: f64) -> Vec<(f64, f64)> {
+    let scale = (1.0 - tension) / 2.0;
+    (0..points.len())
+        .map(|i| {
+            let prev = points[i.saturating_sub(1)];
+            let next = points[(i + 1).min(points.len() - 1)];
+            ((next.0 - prev.0) * scale, (next.1 - prev.1) * scale)
+        })
+        .collect()
+}
+
+/// The slopes of the monotone cubic interpolation by Fritsch and Carlson
+fn monotone_slopes(points: &[(f64, f64)]) -> Vec<f64> {
+    let n = points.len();
+    let secants: Vec<f64> = points
+        .windows(2)
+        .map(|w| {
+            let dx = w[1].0 - w[0].0;
+            if dx == 0.0 {
+                0.0
+            } else {
+                (w[1].1 - w[0].1) / dx
+            }
+        })
+        .collect();
+
+    let mut slopes = vec![0.0; n];
+    slopes[0] = secants[0];
+    slopes[n - 1] = secants[n - 2];
+    for i in 1..n - 1 {
+        if secants[i - 1] * secants[i] > 0.0 {
+            slopes[i] = (secants[i - 1] + secants[i]) / 2.0;
+        }
+    }
+
+    for (i, &d) in secants.iter().enumerate() {
+        if d == 0.0 {
+            slopes[i] = 0.0;
+            slopes[i + 1] = 0.0;
+            continue;
+        }
+        let (a, b) = (slopes[i] / d, slopes[i + 1] / d);
+        let norm = a * a + b * b;
+        if norm > 9.0 {
+            let tau = 3.0 / norm.sqrt();
+            slopes[i] = tau * a * d;
+            slopes[i + 1] = tau * b * d;
+        }
+    }
+
+    slopes
+}
+
+/// Convert the smooth curve through the points into the cubic Bézier curves, which are the two
+/// control points and the end point of each span. The Hermite tangents of a span are a third
+/// of the way from its ends to the control points.
+fn interpolate(
+    points: &[BackendCoord],
+    interpolation: Interpolation,
+    tension: f64,
+) -> Vec<[BackendCoord; 3]> {
+    if points.len() < 3 {
+        return points.windows(2).map(|w| [w[0], w[1], w[1]]).collect();
+    }
+
+    let points = to_f64(points);
+    let (tangents, slopes) = match interpolation {
+        Interpolation::CatmullRom => (catmull_rom_tangents(&points, tension), vec![]),
+        Interpolation::MonotoneCubic => (vec![], monotone_slopes(&points)),
+    };
+
+    let round = |(x, y): (f64, f64)| (x.round() as i32, y.round() as i32);
+    (0..points.len() - 1)
+        .map(|i| {
+            let (p0, p1) = (points[i], points[i + 1]);
+            let (m0, m1) = match interpolation {
+                Interpolation::CatmullRom => (tangents[i], tangents[i + 1]),
+                // The X coordinate is linear, so the slopes are scaled to the segment width
+                Interpolation::MonotoneCubic => {
+                    let h = p1.0 - p0.0;
+                    ((h, slopes[i] * h), (h, slopes[i + 1] * h))
+                }
+            };
+            [
+                round((p0.0 + m0.0 / 3.0, p0.1 + m0.1 / 3.0)),
+                round((p1.0 - m1.0 / 3.0, p1.1 - m1.1 / 3.0)),
+                round(p1),
+            ]
+        })
+        .collect()
+}
+
+/// Sample each cubic Bézier curve at `samples` evenly spaced parameters
+fn sample_bezier(
+    start: BackendCoord,
+    segments: &[[BackendCoord; 3]],
+    samples: usize,
+) -> Vec<BackendCoord> {
+    let to_f64 = |(x, y): BackendCoord| (f64::from(x), f64::from(y));
+    let mut from = to_f64(start);
+    let mut ret = vec![start];
+    for segment in segments {
+        let (c0, c1, end) = (to_f64(segment[0]), to_f64(segment[1]), to_f64(segment[2]));
+        for k in 1..=samples {
+            let t = k as f64 / samples as f64;
+            let s = 1.0 - t;
+            let (a, b, c, d) = (s * s * s, 3.0 * s * s * t, 3.0 * s * t * t, t * t * t);
+            let x = a * from.0 + b * c0.0 + c * c1.0 + d * end.0;
+            let y = a * from.1 + b * c0.1 + c * c1.1 + d * end.1;
+            ret.push((x.round() as i32, y.round() as i32));
+        }
+        from = end;
+    }
+    ret
+}
+
+/// The path of a smooth line, which is drawn as a path of cubic Bézier curves
+pub struct SmoothPath<Coord> {
+    points: Vec<Coord>,
+    style: ShapeStyle,
+    interpolation: Interpolation,
+    tension: f64,
+    samples: Option<usize>,
+}
+
+impl<'a, Coord> PointCollection<'a, Coord> for &'a SmoothPath<Coord> {
+    type Borrow = &'a Coord;
+    type IntoIter = &'a [Coord];
+    fn point_iter(self) -> &'a [Coord] {
+        &self.points
+    }
+}
+
+impl<Coord, DB: DrawingBackend> Drawable<DB> for SmoothPath<Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let points: Vec<_> = points.collect();
+        if let Some(&start) = points.first() {
+            let segments = interpolate(&points, self.interpolation, self.tension);
+            match self.samples {
+                Some(samples) => {
+                    backend.draw_path(sample_bezier(start, &segments, samples), &self.style)?
+                }
+                None => backend.draw_bezier(start, &segments, &self.style)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The smooth line series object, which draws a spline through the points instead of
+/// straight segments. With fewer than 3 points, it draws a plain line.
+pub struct SmoothLineSeries<Coord> {
+    style: ShapeStyle,
+    data: Option<Vec<Coord>>,
+    interpolation: Interpolation,
+    tension: f64,
+    samples: Option<usize>,
+}
+
+impl<Coord> SmoothLineSeries<Coord> {
+    /// Create a new smooth line series, which uses the Catmull-Rom spline by default
+    pub fn new<I: IntoIterator<Item = Coord>, S: Into<ShapeStyle>>(iter: I, style: S) -> Self {
+        Self {
+            style: style.into(),
+            data: Some(iter.into_iter().collect()),
+            interpolation: Interpolation::CatmullRom,
+            tension: 0.0,
+            samples: None,
+        }
+    }
+
+    /// Set the curve that is drawn through the points
+    pub fn interpolation(mut self, interpolation: Interpolation) -> Self {
+        self.interpolation = interpolation;
+        self
+    }
+
+    /// Set the tension of the Catmull-Rom spline, from 0 for the plain spline to 1 for
+    /// straight lines
+    pub fn tension(mut self, tension: f64) -> Self {
+        self.tension = tension.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Set the number of line segments each span between two points is drawn with.
+    /// By default, the spans are drawn as the Bézier curves, which the backends without
+    /// curve support flatten within a quarter of a pixel.
+    pub fn samples_per_segment(mut self, samples: usize) -> Self {
+        self.samples = Some(samples.max(1));
+        self
+    }
+}
+
+impl<Coord> Iterator for SmoothLineSeries<Coord> {
+    type Item = SmoothPath<Coord>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let data = self.data.take()?;
+        Some(SmoothPath {
+            points: data,
+            style: self.style.clone(),
+            interpolation: self.interpolation,
+            tension: self.tension,
+            samples: self.samples,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_interpolation() {
+        use crate::drawing::rasterizer::flatten_bezier;
+        let points = [(0, 0), (50, 100), (100, 100), (150, 0)];
+
+        let curves = interpolate(&points, Interpolation::CatmullRom, 0.0);
+        assert_eq!(curves.len(), 3);
+        assert_eq!(curves[0], [(8, 17), (33, 83), (50, 100)]);
+        let curve = flatten_bezier(points[0], &curves, 0.25);
+        assert_eq!(curve.last(), Some(&(150, 0)));
+        assert!(curve.contains(&(50, 100)) && curve.contains(&(100, 100)));
+        // The flat span overshoots with the Catmull-Rom spline
+        assert!(curve.iter().any(|&(_, y)| y > 100));
+
+        let curves = interpolate(&points, Interpolation::MonotoneCubic, 0.0);
+        let curve = flatten_bezier(points[0], &curves, 0.25);
+        assert!(curve.iter().all(|&(_, y)| (0..=100).contains(&y)));
+        assert!(curve.windows(2).all(|w| w[0].0 <= w[1].0));
+
+        // The control points of the straight lines are at the ends of the spans
+        let lines = interpolate(&points, Interpolation::CatmullRom, 1.0);
+        assert_eq!(lines[1], [(50, 100), (100, 100), (100, 100)]);
+        assert_eq!(flatten_bezier(points[0], &lines, 0.25), points);
+
+        assert_eq!(
+            interpolate(&points[..2], Interpolation::CatmullRom, 0.0),
+            [[(0, 0), (50, 100), (50, 100)]]
+        );
+        assert!(interpolate(&points[..1], Interpolation::MonotoneCubic, 0.0).is_empty());
+    }
+
+    #[test]
+    fn test_samples_per_segment() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_path(|_, _, path| {
+                assert_eq!(path.len(), 9);
+                assert_eq!(path[0], (0, 200));
+                assert_eq!(path[4], (100, 100));
+                assert_eq!(path[8], (200, 200));
+            });
+            m.drop_check(|b| assert_eq!(b.num_draw_path_call, 1));
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0..2, 0..2)
+            .expect("Build chart error");
+
+        chart
+            .draw_series(
+                SmoothLineSeries::new(vec![(0, 0), (1, 1), (2, 0)], &RED).samples_per_segment(4),
+            )
+            .expect("Drawing Error");
+    }
+
+    #[test]
+    fn test_smooth_line_series() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_path(|c, _, path| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!(path.first(), Some(&(0, 200)));
+                assert!(path.contains(&(100, 100)));
+                assert_eq!(path.last(), Some(&(200, 200)));
+                assert!(path.iter().all(|&(_, y)| y >= 100));
+            });
+            m.drop_check(|b| assert_eq!(b.num_draw_path_call, 1));
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0..2, 0..2)
+            .expect("Build chart error");
+
+        chart
+            .draw_series(
+                SmoothLineSeries::new(vec![(0, 0), (1, 1), (2, 0)], &RED)
+                    .interpolation(Interpolation::MonotoneCubic),
+            )
+            .expect("Drawing Error");
+    }
+}
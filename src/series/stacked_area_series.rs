@@ -0,0 +1,231 @@
+use crate::element::Polygon;
+use crate::style::{Color, Palette, Palette99, ShapeStyle};
+
+/// The error when the layers of a stacked area series don't have the same number of values, or
+/// not the same X values
+#[derive(Debug, PartialEq, Eq)]
+pub enum StackError {
+    /// The data at `index` has `found` values, but `expected` values were expected
+    LengthMismatch {
+        index: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// The X value at `position` of the layer at `index` isn't the one of the first layer
+    XMismatch { index: usize, position: usize },
+}
+
+impl std::fmt::Display for StackError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl std::error::Error for StackError {}
+
+type LayerStyleFunc<'a> = Box<dyn Fn(usize) -> ShapeStyle + 'a>;
+
+/// The series that stacks multiple area layers over the same X values on top of each other.
+/// The layers are emitted bottom-up, so each of them is drawn above the one below it.
+pub struct StackedAreaSeries<'a, X: Clone> {
+    xs: Vec<X>,
+    layers: Vec<Vec<f64>>,
+    style: LayerStyleFunc<'a>,
+    percent: bool,
+    idx: usize,
+    baseline: Vec<f64>,
+}
+
+impl<'a, X: Clone> StackedAreaSeries<'a, X> {
+    /// Create a new stacked area series from the columns of values of each X value.
+    ///
+    /// - `iter`: The iterator of `(x, [y0, y1, ..., yk])` columns, `y0` is the bottom layer
+    ///
+    /// Returns an error if the columns have different numbers of values
+    pub fn new<R: AsRef<[f64]>, I: IntoIterator<Item = (X, R)>>(
+        iter: I,
+    ) -> Result<Self, StackError> {
+        let mut xs = vec![];
+        let mut layers: Vec<Vec<f64>> = vec![];
+        for (index, (x, column)) in iter.into_iter().enumerate() {
+            let column = column.as_ref();
+            if index == 0 {
+                layers.resize(column.len(), vec![]);
+            } else if column.len() != layers.len() {
+                return Err(StackError::LengthMismatch {
+                    index,
+                    expected: layers.len(),
+                    found: column.len(),
+                });
+            }
+            xs.push(x);
+            for (layer, &y) in layers.iter_mut().zip(column) {
+                layer.push(y);
+            }
+        }
+        Ok(Self::from_parts(xs, layers))
+    }
+
+    /// Create a new stacked area series from the data of each layer, which must all have the
+    /// same X values as the first layer, the bottom one.
+    ///
+    /// Returns an error if the layers have different lengths or different X values
+    pub fn from_layers<L: IntoIterator<Item = (X, f64)>, I: IntoIterator<Item = L>>(
+        iter: I,
+    ) -> Result<Self, StackError>
+    where
+        X: PartialEq,
+    {
+        let mut xs = vec![];
+        let mut layers = vec![];
+        for (index, layer) in iter.into_iter().enumerate() {
+            let (layer_xs, layer): (Vec<_>, Vec<_>) = layer.into_iter().unzip();
+            if index == 0 {
+                xs = layer_xs;
+            } else if layer.len() != xs.len() {
+                return Err(StackError::LengthMismatch {
+                    index,
+                    expected: xs.len(),
+                    found: layer.len(),
+                });
+            } else if let Some(position) = (0..xs.len()).find(|&i| layer_xs[i] != xs[i]) {
+                return Err(StackError::XMismatch { index, position });
+            }
+            layers.push(layer);
+        }
+        Ok(Self::from_parts(xs, layers))
+    }
+
+    fn from_parts(xs: Vec<X>, layers: Vec<Vec<f64>>) -> Self {
+        let baseline = vec![0.0; xs.len()];
+        Self {
+            xs,
+            layers,
+            style: Box::new(|idx| Palette99::pick(idx).filled()),
+            percent: false,
+            idx: 0,
+            baseline,
+        }
+    }
+
+    /// Set the style of each layer by its index, the bottom layer has the index 0. By
+    /// default, the colors are picked from `Palette99`.
+    pub fn style_func<F: Fn(usize) -> ShapeStyle + 'a>(mut self, func: F) -> Self {
+        self.style = Box::new(func);
+        self
+    }
+
+    /// Set if each column is normalized so the stack sums up to 100
+    pub fn percent(mut self, percent: bool) -> Self {
+        self.percent = percent;
+        self
+    }
+
+    /// Get the index and the style of each layer, from the bottom one up, which can be used
+    /// to build the legend
+    pub fn layers(&self) -> Vec<(usize, ShapeStyle)> {
+        (0..self.layers.len())
+            .map(|idx| (idx, (self.style)(idx)))
+            .collect()
+    }
+
+    fn scale(&self, column: usize) -> f64 {
+        if !self.percent {
+            return 1.0;
+        }
+        let total: f64 = self.layers.iter().map(|layer| layer[column]).sum();
+        if total == 0.0 {
+            0.0
+        } else {
+            100.0 / total
+        }
+    }
+}
+
+impl<'a, X: Clone> Iterator for StackedAreaSeries<'a, X> {
+    type Item = Polygon<(X, f64)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let layer = self.layers.get(self.idx)?;
+        let top: Vec<f64> = (0..self.xs.len())
+            .map(|i| self.baseline[i] + layer[i] * self.scale(i))
+            .collect();
+
+        let mut points: Vec<_> = self.xs.iter().cloned().zip(top.iter().cloned()).collect();
+        points.extend(
+            self.xs
+                .iter()
+                .cloned()
+                .zip(self.baseline.iter().cloned())
+                .rev(),
+        );
+
+        let style = (self.style)(self.idx);
+        self.idx += 1;
+        self.baseline = top;
+        Some(Polygon::new(points, style))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::element::PointCollection;
+
+    #[test]
+    fn test_stacked_area_series() {
+        let mut series =
+            StackedAreaSeries::new(vec![(0, [1.0, 3.0]), (1, [2.0, 2.0])]).expect("Invalid data");
+        assert_eq!(series.layers().len(), 2);
+
+        let bottom = series.next().unwrap();
+        assert_eq!(
+            bottom.point_iter(),
+            [(0, 1.0), (1, 2.0), (1, 0.0), (0, 0.0)]
+        );
+        let top = series.next().unwrap();
+        assert_eq!(top.point_iter(), [(0, 4.0), (1, 4.0), (1, 2.0), (0, 1.0)]);
+        assert!(series.next().is_none());
+
+        let mut series = StackedAreaSeries::from_layers(vec![
+            vec![(0, 1.0), (1, 0.0)],
+            vec![(0, 3.0), (1, 0.0)],
+        ])
+        .expect("Invalid data")
+        .percent(true);
+        let bottom = series.next().unwrap();
+        assert_eq!(bottom.point_iter()[..2], [(0, 25.0), (1, 0.0)]);
+        let top = series.next().unwrap();
+        assert_eq!(top.point_iter()[..2], [(0, 100.0), (1, 0.0)]);
+
+        let err = StackedAreaSeries::new(vec![(0, vec![1.0, 2.0]), (1, vec![1.0])]);
+        assert_eq!(
+            err.err(),
+            Some(StackError::LengthMismatch {
+                index: 1,
+                expected: 2,
+                found: 1
+            })
+        );
+
+        let err = StackedAreaSeries::from_layers(vec![
+            vec![(0, 1.0), (1, 2.0)],
+            vec![(0, 3.0), (2, 2.0)],
+        ]);
+        assert_eq!(
+            err.err(),
+            Some(StackError::XMismatch {
+                index: 1,
+                position: 1
+            })
+        );
+        let err = StackedAreaSeries::from_layers(vec![vec![(0, 1.0)], vec![(0, 3.0), (1, 2.0)]]);
+        assert_eq!(
+            err.err(),
+            Some(StackError::LengthMismatch {
+                index: 1,
+                expected: 1,
+                found: 2
+            })
+        );
+    }
+}
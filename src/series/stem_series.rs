@@ -0,0 +1,138 @@
+use crate::drawing::DrawingBackend;
+use crate::element::{Circle, DynElement, EmptyElement, IntoDynElement, PathElement};
+use crate::style::ShapeStyle;
+
+/// The series that draws a vertical stem from the baseline to each point, with a circle
+/// marker at its tip. This is commonly used for discrete signals and impulse responses.
+pub struct StemSeries<'a, DB: DrawingBackend, X, Y> {
+    data: std::vec::IntoIter<(X, Y)>,
+    baseline: Box<dyn Fn(&X) -> Y + 'a>,
+    stem_style: ShapeStyle,
+    marker_style: ShapeStyle,
+    marker_size: Option<u32>,
+    pending_marker: Option<(X, Y)>,
+    _p: std::marker::PhantomData<DB>,
+}
+
+impl<'a, DB: DrawingBackend, X, Y: Default + 'a> StemSeries<'a, DB, X, Y> {
+    /// Create a new stem series, which has its baseline at the default value of `Y` and
+    /// draws the markers filled with the stem color
+    /// - `iter`: The iterator of the points
+    /// - `style`: The style of the stems
+    pub fn new<S: Into<ShapeStyle>, I: IntoIterator<Item = (X, Y)>>(iter: I, style: S) -> Self {
+        let stem_style = style.into();
+        Self {
+            data: iter.into_iter().collect::<Vec<_>>().into_iter(),
+            baseline: Box::new(|_| Y::default()),
+            marker_style: stem_style.filled(),
+            stem_style,
+            marker_size: Some(3),
+            pending_marker: None,
+            _p: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, DB: DrawingBackend, X, Y> StemSeries<'a, DB, X, Y> {
+    /// Set the baseline the stems start from
+    pub fn baseline(mut self, baseline: Y) -> Self
+    where
+        Y: Clone + 'a,
+    {
+        self.baseline = Box::new(move |_| baseline.clone());
+        self
+    }
+
+    /// Set a function that defines variant baseline
+    pub fn baseline_func(mut self, func: impl Fn(&X) -> Y + 'a) -> Self {
+        self.baseline = Box::new(func);
+        self
+    }
+
+    /// Set the radius of the markers in pixels
+    pub fn marker_size(mut self, size: u32) -> Self {
+        self.marker_size = Some(size);
+        self
+    }
+
+    /// Set the style of the markers
+    pub fn marker_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.marker_style = style.into();
+        self
+    }
+
+    /// Draw the stems only, without the markers at their tips
+    pub fn no_marker(mut self) -> Self {
+        self.marker_size = None;
+        self
+    }
+}
+
+impl<'a, DB: DrawingBackend + 'a, X: Clone + 'a, Y: Clone + 'a> Iterator
+    for StemSeries<'a, DB, X, Y>
+{
+    type Item = DynElement<'a, DB, (X, Y)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(tip) = self.pending_marker.take() {
+            let size = self.marker_size.unwrap_or(0);
+            return Some(
+                (EmptyElement::at(tip) + Circle::new((0, 0), size, self.marker_style.clone()))
+                    .into_dyn(),
+            );
+        }
+
+        let (x, y) = self.data.next()?;
+        let base = (self.baseline)(&x);
+        if self.marker_size.is_some() {
+            self.pending_marker = Some((x.clone(), y.clone()));
+        }
+        Some(PathElement::new(vec![(x.clone(), base), (x, y)], self.stem_style.clone()).into_dyn())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_stem_series() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_path(|c, _, path| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!(path, [(50, 100), (50, 50)]);
+            });
+            m.check_draw_path(|_, _, path| assert_eq!(path, [(150, 100), (150, 150)]));
+            m.check_draw_path(|_, _, path| assert_eq!(path, [(50, 150), (50, 50)]));
+            m.check_draw_circle(|c, _, _, center, radius| {
+                assert_eq!(c, BLUE.to_rgba());
+                assert_eq!(center, (50, 50));
+                assert_eq!(radius, 4);
+            });
+            m.check_draw_circle(|_, _, _, center, _| assert_eq!(center, (150, 150)));
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_path_call, 3);
+                assert_eq!(b.num_draw_circle_call, 2);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0.0..4.0, -2.0..2.0)
+            .expect("Build chart error");
+
+        chart
+            .draw_series(
+                StemSeries::new(vec![(1.0, 1.0), (3.0, -1.0)], &RED)
+                    .marker_size(4)
+                    .marker_style(BLUE.filled()),
+            )
+            .expect("Drawing Error");
+
+        chart
+            .draw_series(
+                StemSeries::new(vec![(1.0, 1.0)], &RED)
+                    .baseline_func(|_| -1.0)
+                    .no_marker(),
+            )
+            .expect("Drawing Error");
+    }
+}
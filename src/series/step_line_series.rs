@@ -0,0 +1,192 @@
+use crate::drawing::backend::{BackendCoord, DrawingErrorKind};
+use crate::drawing::DrawingBackend;
+use crate::element::{Drawable, PathElement, PointCollection};
+use crate::style::ShapeStyle;
+
+/// Describes where the step between two consecutive points of a step line happens
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StepPosition {
+    /// The line moves vertically at the first point, and then horizontally to the second
+    Pre,
+    /// The line moves horizontally to the second point, and then vertically at it
+    Post,
+    /// The line moves vertically halfway between the two points
+    Mid,
+}
+
+impl StepPosition {
+    /// Turn the points of a line into the points of the step line in backend coordinates
+    pub fn make_steps(self, points: &[BackendCoord]) -> Vec<BackendCoord> {
+        let mut ret: Vec<BackendCoord> = Vec::with_capacity(points.len() * 3);
+        // The corners of flat steps coincide with the points, so duplicates are dropped
+        let mut push = |p: BackendCoord| {
+            if ret.last() != Some(&p) {
+                ret.push(p);
+            }
+        };
+        for (i, &(x, y)) in points.iter().enumerate() {
+            if let Some(&(px, py)) = i.checked_sub(1).and_then(|i| points.get(i)) {
+                match self {
+                    StepPosition::Pre => push((px, y)),
+                    StepPosition::Post => push((x, py)),
+                    StepPosition::Mid => {
+                        let mx = (px + x) / 2;
+                        push((mx, py));
+                        push((mx, y));
+                    }
+                }
+            }
+            push((x, y));
+        }
+        ret
+    }
+}
+
+/// The path of a step line, which is drawn as a path element after the steps are inserted
+pub struct StepPath<Coord> {
+    path: PathElement<Coord>,
+    position: StepPosition,
+    single_point_size: Option<u32>,
+    style: ShapeStyle,
+}
+
+impl<'a, Coord> PointCollection<'a, Coord> for &'a StepPath<Coord> {
+    type Borrow = &'a Coord;
+    type IntoIter = &'a [Coord];
+    fn point_iter(self) -> &'a [Coord] {
+        self.path.point_iter()
+    }
+}
+
+impl<Coord, DB: DrawingBackend> Drawable<DB> for StepPath<Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        parent_dim: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let points: Vec<_> = points.collect();
+        if points.len() == 1 {
+            if let Some(size) = self.single_point_size {
+                backend.draw_circle(points[0], size, &self.style.color, true)?;
+            }
+            return Ok(());
+        }
+        self.path.draw(
+            self.position.make_steps(&points).into_iter(),
+            backend,
+            parent_dim,
+        )
+    }
+}
+
+/// The step line series object, which connects consecutive points with a horizontal and a
+/// vertical segment instead of a direct line
+pub struct StepLineSeries<Coord> {
+    style: ShapeStyle,
+    data: Option<Vec<Coord>>,
+    position: StepPosition,
+    single_point_size: Option<u32>,
+}
+
+impl<Coord> StepLineSeries<Coord> {
+    /// Create a new step line series, which steps at the next point by default
+    pub fn new<I: IntoIterator<Item = Coord>, S: Into<ShapeStyle>>(iter: I, style: S) -> Self {
+        Self {
+            style: style.into(),
+            data: Some(iter.into_iter().collect()),
+            position: StepPosition::Post,
+            single_point_size: None,
+        }
+    }
+
+    /// Set where the steps between consecutive points happen
+    pub fn position(mut self, position: StepPosition) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// Set the size of the dot drawn when there is only a single point. By default, nothing
+    /// is drawn in that case.
+    pub fn single_point_size(mut self, size: Option<u32>) -> Self {
+        self.single_point_size = size;
+        self
+    }
+}
+
+impl<Coord> Iterator for StepLineSeries<Coord> {
+    type Item = StepPath<Coord>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let data = self.data.take()?;
+        Some(StepPath {
+            path: PathElement::new(data, self.style.clone()),
+            position: self.position,
+            single_point_size: self.single_point_size,
+            style: self.style.clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_step_positions() {
+        let points = [(0, 0), (10, 10), (30, 20)];
+        assert_eq!(
+            StepPosition::Pre.make_steps(&points),
+            [(0, 0), (0, 10), (10, 10), (10, 20), (30, 20)]
+        );
+        assert_eq!(
+            StepPosition::Post.make_steps(&points),
+            [(0, 0), (10, 0), (10, 10), (30, 10), (30, 20)]
+        );
+        assert_eq!(
+            StepPosition::Mid.make_steps(&points),
+            [
+                (0, 0),
+                (5, 0),
+                (5, 10),
+                (10, 10),
+                (20, 10),
+                (20, 20),
+                (30, 20)
+            ]
+        );
+        assert_eq!(StepPosition::Mid.make_steps(&points[..1]), [(0, 0)]);
+    }
+
+    #[test]
+    fn test_step_line_series() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_path(|c, _, path| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!(path, [(0, 200), (100, 200), (100, 100), (200, 100)]);
+            });
+            m.check_draw_circle(|_, _, filled, center, size| {
+                assert!(filled);
+                assert_eq!(center, (100, 100));
+                assert_eq!(size, 3);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_path_call, 1);
+                assert_eq!(b.num_draw_circle_call, 1);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0..2, 0..2)
+            .expect("Build chart error");
+
+        chart
+            .draw_series(StepLineSeries::new(vec![(0, 0), (1, 1), (2, 1)], &RED))
+            .expect("Drawing Error");
+        chart
+            .draw_series(StepLineSeries::new(vec![(1, 1)], &RED).single_point_size(Some(3)))
+            .expect("Drawing Error");
+        chart
+            .draw_series(StepLineSeries::new(vec![(1, 1)], &RED))
+            .expect("Drawing Error");
+    }
+}
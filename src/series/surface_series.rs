@@ -0,0 +1,148 @@
+use crate::chart::ChartContext;
+use crate::coord::{Cartesian3d, Ranged};
+use crate::drawing::DrawingBackend;
+use crate::element::Polygon;
+use crate::style::{Color, HSLColor, ShapeStyle};
+
+type Quad = [(f64, f64, f64); 4];
+type ValueStyleFunc<'a> = Box<dyn Fn(f64) -> ShapeStyle + 'a>;
+
+/// The series that draws the surface `y = f(x, z)` on a 3D chart. The function is sampled on
+/// a regular grid over the X and Z ranges of the chart, and each cell of the grid is drawn as
+/// a filled quad. The quads are sorted by their projected depth and drawn back to front, so
+/// the nearer parts of the surface cover the farther ones.
+pub struct SurfaceSeries<'a> {
+    quads: std::vec::IntoIter<(Quad, f64)>,
+    style_func: ValueStyleFunc<'a>,
+}
+
+impl<'a> SurfaceSeries<'a> {
+    /// Create a new surface series
+    /// - `chart`: The 3D chart the surface is drawn on, which gives the sampled ranges and the
+    ///   projection
+    /// - `resolution`: The number of cells along the X and the Z axis
+    /// - `func`: The function that computes the height of the surface, the cells with a
+    ///   non-finite corner are left out
+    pub fn new<DB, X, Y, Z, F>(
+        chart: &ChartContext<DB, Cartesian3d<X, Y, Z>>,
+        resolution: (usize, usize),
+        func: F,
+    ) -> Self
+    where
+        DB: DrawingBackend,
+        X: Ranged<ValueType = f64>,
+        Y: Ranged<ValueType = f64>,
+        Z: Ranged<ValueType = f64>,
+        F: Fn(f64, f64) -> f64,
+    {
+        let (nx, nz) = (resolution.0.max(1), resolution.1.max(1));
+        let (x, z) = (chart.x_range(), chart.z_range());
+        let samples: Vec<Vec<(f64, f64, f64)>> = (0..=nz)
+            .map(|j| {
+                let z = z.start + (z.end - z.start) * j as f64 / nz as f64;
+                (0..=nx)
+                    .map(|i| {
+                        let x = x.start + (x.end - x.start) * i as f64 / nx as f64;
+                        (x, func(x, z), z)
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let (min, max) = samples
+            .iter()
+            .flatten()
+            .map(|p| p.1)
+            .filter(|y| y.is_finite())
+            .fold((f64::MAX, f64::MIN), |(lo, hi), y| (lo.min(y), hi.max(y)));
+
+        let mut quads = vec![];
+        for (row, next_row) in samples.iter().zip(samples.iter().skip(1)) {
+            for i in 0..nx {
+                let quad = [row[i], row[i + 1], next_row[i + 1], next_row[i]];
+                if quad.iter().any(|p| !p.1.is_finite()) {
+                    continue;
+                }
+                let value = quad.iter().map(|p| p.1).sum::<f64>() / 4.0;
+                let value = if max > min {
+                    (value - min) / (max - min)
+                } else {
+                    0.5
+                };
+                let depth = quad.iter().map(|p| chart.projected_depth(p)).sum::<f64>();
+                quads.push((depth, quad, value));
+            }
+        }
+        quads.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap_or(std::cmp::Ordering::Equal));
+
+        Self {
+            quads: quads
+                .into_iter()
+                .map(|(_, quad, value)| (quad, value))
+                .collect::<Vec<_>>()
+                .into_iter(),
+            style_func: Box::new(|value| HSLColor(0.66 * (1.0 - value), 0.7, 0.5).filled()),
+        }
+    }
+
+    /// Set the function that computes the style of a quad from its height, which is the average
+    /// of its corners normalized to `[0, 1]` over the whole surface. By default, the low parts
+    /// are blue and the high parts are red.
+    pub fn style_func<F: Fn(f64) -> ShapeStyle + 'a>(mut self, func: F) -> Self {
+        self.style_func = Box::new(func);
+        self
+    }
+}
+
+impl<'a> Iterator for SurfaceSeries<'a> {
+    type Item = Polygon<(f64, f64, f64)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let (quad, value) = self.quads.next()?;
+        Some(Polygon::new(quad.to_vec(), (self.style_func)(value)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::element::PointCollection;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_surface_series() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.drop_check(|b| assert_eq!(b.num_fill_polygon_call, 3));
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_3d(0.0..1.0, 0.0..1.0, 0.0..1.0)
+            .expect("Build chart error");
+        chart.with_projection(|mut p| {
+            p.yaw = 0.3;
+            p.pitch = 0.4;
+            p
+        });
+
+        let func = |x: f64, z: f64| if x > 0.6 && z > 0.6 { f64::NAN } else { x * z };
+        let depths: Vec<_> = SurfaceSeries::new(&chart, (2, 2), func)
+            .map(|quad| {
+                (&quad)
+                    .point_iter()
+                    .iter()
+                    .map(|p| chart.projected_depth(p))
+                    .sum::<f64>()
+            })
+            .collect();
+        assert_eq!(depths.len(), 3);
+        assert!(depths.windows(2).all(|w| w[0] >= w[1]));
+
+        let values = std::cell::RefCell::new(vec![]);
+        let series = SurfaceSeries::new(&chart, (2, 2), func).style_func(|v| {
+            values.borrow_mut().push(v);
+            RED.filled()
+        });
+        chart.draw_series(series).expect("Drawing Error");
+        let mut values = values.into_inner();
+        values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(values, [0.125, 0.375, 0.375]);
+    }
+}
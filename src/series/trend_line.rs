@@ -0,0 +1,312 @@
+use std::ops::Range;
+
+/// The error when a trend line can't be fitted to the data
+#[derive(Debug, PartialEq, Eq)]
+pub enum TrendError {
+    /// There are fewer finite points than the model has coefficients
+    NotEnoughPoints { required: usize, found: usize },
+    /// There aren't enough distinct X values to determine the coefficients
+    DegenerateX,
+    /// An exponential trend requires all the Y values to be positive
+    NonPositiveY,
+}
+
+impl std::fmt::Display for TrendError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl std::error::Error for TrendError {}
+
+/// The number of points the curved trend lines are sampled with by `points`
+const CURVE_SAMPLES: usize = 50;
+
+/// Solve the linear system in place with Gaussian elimination, returning `None` if it's
+/// singular compared to the largest entry of the matrix
+fn solve(mut matrix: Vec<Vec<f64>>, mut rhs: Vec<f64>) -> Option<Vec<f64>> {
+    let n = rhs.len();
+    let norm = matrix
+        .iter()
+        .flat_map(|row| row.iter())
+        .fold(0.0f64, |norm, cell| norm.max(cell.abs()));
+    for col in 0..n {
+        let pivot = (col..n).max_by(|&a, &b| {
+            matrix[a][col]
+                .abs()
+                .partial_cmp(&matrix[b][col].abs())
+                .unwrap_or(std::cmp::Ordering::Equal)
+        })?;
+        if matrix[pivot][col].abs() <= 1e-12 * norm {
+            return None;
+        }
+        matrix.swap(col, pivot);
+        rhs.swap(col, pivot);
+        let pivot_row = matrix[col].clone();
+        for row in col + 1..n {
+            let factor = matrix[row][col] / pivot_row[col];
+            for (cell, pivot) in matrix[row].iter_mut().zip(pivot_row.iter()).skip(col) {
+                *cell -= factor * pivot;
+            }
+            rhs[row] -= factor * rhs[col];
+        }
+    }
+    let mut ret = vec![0.0; n];
+    for row in (0..n).rev() {
+        let sum: f64 = (row + 1..n).map(|k| matrix[row][k] * ret[k]).sum();
+        ret[row] = (rhs[row] - sum) / matrix[row][row];
+    }
+    Some(ret)
+}
+
+/// The least-squares polynomial fit of the points, with the coefficients in ascending order
+fn fit_polynomial(points: &[(f64, f64)], order: usize) -> Result<Vec<f64>, TrendError> {
+    let n = order + 1;
+    if points.len() < n {
+        return Err(TrendError::NotEnoughPoints {
+            required: n,
+            found: points.len(),
+        });
+    }
+    // The X values are centered and scaled into `-1.0..=1.0` for the numerical stability of
+    // the normal equations, whatever the scale of the data is
+    let mean = points.iter().map(|p| p.0).sum::<f64>() / points.len() as f64;
+    let scale = points
+        .iter()
+        .fold(0.0f64, |scale, p| scale.max((p.0 - mean).abs()));
+    let scale = if scale > 0.0 { scale } else { 1.0 };
+    let mut matrix = vec![vec![0.0; n]; n];
+    let mut rhs = vec![0.0; n];
+    for &(x, y) in points {
+        let x = (x - mean) / scale;
+        for (row, rhs) in rhs.iter_mut().enumerate() {
+            *rhs += y * x.powi(row as i32);
+            for (col, cell) in matrix[row].iter_mut().enumerate() {
+                *cell += x.powi((row + col) as i32);
+            }
+        }
+    }
+    let centered = solve(matrix, rhs).ok_or(TrendError::DegenerateX)?;
+
+    // Expand the polynomial of `(x - mean) / scale` back into the powers of `x`
+    let mut coefficients = vec![0.0; n];
+    for (power, c) in centered.iter().enumerate() {
+        let c = c / scale.powi(power as i32);
+        let mut binomial = 1.0;
+        for (k, coefficient) in coefficients.iter_mut().enumerate().take(power + 1) {
+            *coefficient += c * binomial * (-mean).powi((power - k) as i32);
+            binomial = binomial * (power - k) as f64 / (k + 1) as f64;
+        }
+    }
+    Ok(coefficients)
+}
+
+#[derive(Clone, Debug)]
+enum TrendModel {
+    Polynomial(Vec<f64>),
+    /// The exponential `a * exp(b * x)`, which is stored as the linear fit of `ln(y)`
+    Exponential(Vec<f64>),
+}
+
+/// A trend line fitted to `(x, y)` data with the least squares method. The points of the
+/// line can be drawn with a `LineSeries`.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let trend = TrendLine::linear(vec![(0.0, 1.0), (1.0, 3.0), (2.0, 5.0)]).unwrap();
+/// assert_eq!(trend.points(0.0..4.0).collect::<Vec<_>>(), [(0.0, 1.0), (4.0, 9.0)]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct TrendLine {
+    model: TrendModel,
+    r_squared: f64,
+}
+
+impl TrendLine {
+    fn finite_points<I: IntoIterator<Item = (f64, f64)>>(iter: I) -> Vec<(f64, f64)> {
+        iter.into_iter()
+            .filter(|(x, y)| x.is_finite() && y.is_finite())
+            .collect()
+    }
+
+    fn new(model: TrendModel, points: &[(f64, f64)]) -> Self {
+        let mut ret = Self {
+            model,
+            r_squared: 0.0,
+        };
+        let mean = points.iter().map(|p| p.1).sum::<f64>() / points.len() as f64;
+        let total: f64 = points.iter().map(|p| (p.1 - mean).powi(2)).sum();
+        let residual: f64 = points.iter().map(|p| (p.1 - ret.value(p.0)).powi(2)).sum();
+        ret.r_squared = if total > 0.0 {
+            1.0 - residual / total
+        } else {
+            1.0
+        };
+        ret
+    }
+
+    /// Fit a straight line to the points, the non-finite ones are ignored
+    pub fn linear<I: IntoIterator<Item = (f64, f64)>>(iter: I) -> Result<Self, TrendError> {
+        Self::polynomial(iter, 1)
+    }
+
+    /// Fit a polynomial of the given order to the points, the non-finite ones are ignored
+    pub fn polynomial<I: IntoIterator<Item = (f64, f64)>>(
+        iter: I,
+        order: usize,
+    ) -> Result<Self, TrendError> {
+        let points = Self::finite_points(iter);
+        let coefficients = fit_polynomial(&points, order)?;
+        Ok(Self::new(TrendModel::Polynomial(coefficients), &points))
+    }
+
+    /// Fit the exponential `a * exp(b * x)` to the points by fitting a straight line to the
+    /// logarithm of the Y values, the non-finite points are ignored
+    pub fn exponential<I: IntoIterator<Item = (f64, f64)>>(iter: I) -> Result<Self, TrendError> {
+        let points = Self::finite_points(iter);
+        if points.iter().any(|p| p.1 <= 0.0) {
+            return Err(TrendError::NonPositiveY);
+        }
+        let log_points: Vec<_> = points.iter().map(|&(x, y)| (x, y.ln())).collect();
+        let coefficients = fit_polynomial(&log_points, 1)?;
+        Ok(Self::new(TrendModel::Exponential(coefficients), &points))
+    }
+
+    /// Get the coefficients of the polynomial in ascending order of the powers. For an
+    /// exponential trend, these are the coefficients of the straight line fitted to `ln(y)`.
+    pub fn coefficients(&self) -> &[f64] {
+        match &self.model {
+            TrendModel::Polynomial(c) | TrendModel::Exponential(c) => c,
+        }
+    }
+
+    /// Get the slope of a linear trend, which is the `b` of an exponential one
+    pub fn slope(&self) -> f64 {
+        self.coefficients().get(1).cloned().unwrap_or(0.0)
+    }
+
+    /// Get the intercept of a linear trend, which is the `ln(a)` of an exponential one
+    pub fn intercept(&self) -> f64 {
+        self.coefficients()[0]
+    }
+
+    /// Get the coefficient of determination of the fit
+    pub fn r_squared(&self) -> f64 {
+        self.r_squared
+    }
+
+    /// Evaluate the trend at the given X value
+    pub fn value(&self, x: f64) -> f64 {
+        let polynomial = self
+            .coefficients()
+            .iter()
+            .rev()
+            .fold(0.0, |acc, c| acc * x + c);
+        match self.model {
+            TrendModel::Polynomial(_) => polynomial,
+            TrendModel::Exponential(_) => polynomial.exp(),
+        }
+    }
+
+    /// Get the points of the trend line over the X range, which are the two ends of a straight
+    /// line or evenly spaced samples of a curve
+    pub fn points(&self, x: Range<f64>) -> std::vec::IntoIter<(f64, f64)> {
+        match &self.model {
+            TrendModel::Polynomial(c) if c.len() <= 2 => self.sampled_points(x, 2),
+            _ => self.sampled_points(x, CURVE_SAMPLES),
+        }
+    }
+
+    /// Get the given number of evenly spaced points of the trend line over the X range
+    pub fn sampled_points(&self, x: Range<f64>, count: usize) -> std::vec::IntoIter<(f64, f64)> {
+        let count = count.max(2);
+        (0..count)
+            .map(|i| {
+                let x = x.start + (x.end - x.start) * i as f64 / (count - 1) as f64;
+                (x, self.value(x))
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-9, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn test_linear_trend() {
+        let trend = TrendLine::linear(vec![(1.0, 2.0), (2.0, 3.0), (3.0, 5.0), (4.0, 6.0)])
+            .expect("Fitting error");
+        assert_close(trend.slope(), 1.4);
+        assert_close(trend.intercept(), 0.5);
+        assert_close(trend.r_squared(), 0.98);
+
+        let points: Vec<_> = trend.points(0.0..10.0).collect();
+        assert_eq!(points.len(), 2);
+        assert_close(points[1].1, 14.5);
+    }
+
+    #[test]
+    fn test_curved_trends() {
+        let trend = TrendLine::polynomial((0..5).map(|x| (x as f64, (x * x) as f64 - 1.0)), 2)
+            .expect("Fitting error");
+        let c = trend.coefficients();
+        assert_close(c[0], -1.0);
+        assert_close(c[1], 0.0);
+        assert_close(c[2], 1.0);
+        assert_close(trend.r_squared(), 1.0);
+        assert_eq!(trend.points(0.0..1.0).count(), CURVE_SAMPLES);
+
+        let trend =
+            TrendLine::exponential((0..5).map(|x| (x as f64, 3.0 * (0.5 * x as f64).exp())))
+                .expect("Fitting error");
+        assert_close(trend.slope(), 0.5);
+        assert_close(trend.value(0.0), 3.0);
+    }
+
+    #[test]
+    fn test_trend_scales() {
+        let assert_relative =
+            |a: f64, b: f64| assert!((a - b).abs() <= 1e-6 * b.abs(), "{} != {}", a, b);
+
+        let trend =
+            TrendLine::linear(vec![(0.0, 0.0), (1e-7, 1.0), (2e-7, 2.0)]).expect("Fitting error");
+        assert_relative(trend.slope(), 1e7);
+        assert_close(trend.intercept(), 0.0);
+
+        let trend = TrendLine::linear(vec![(1e7, 0.0), (1e7 + 1.0, 1.0), (1e7 + 2.0, 2.0)])
+            .expect("Fitting error");
+        assert_relative(trend.slope(), 1.0);
+        assert_relative(trend.intercept(), -1e7);
+
+        let trend = TrendLine::polynomial((0..5).map(|x| (x as f64 * 1e-7, (x * x) as f64)), 2)
+            .expect("Fitting error");
+        assert_relative(trend.coefficients()[2], 1e14);
+        assert_close(trend.r_squared(), 1.0);
+    }
+
+    #[test]
+    fn test_degenerate_trends() {
+        assert_eq!(
+            TrendLine::linear(vec![(1.0, 1.0), (f64::NAN, 2.0)]).unwrap_err(),
+            TrendError::NotEnoughPoints {
+                required: 2,
+                found: 1
+            }
+        );
+        assert_eq!(
+            TrendLine::linear(vec![(1.0, 1.0), (1.0, 2.0), (1.0, 3.0)]).unwrap_err(),
+            TrendError::DegenerateX
+        );
+        assert_eq!(
+            TrendLine::exponential(vec![(1.0, 1.0), (2.0, 0.0)]).unwrap_err(),
+            TrendError::NonPositiveY
+        );
+    }
+}
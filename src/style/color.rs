@@ -25,6 +25,50 @@ pub trait Color {
         RGBAColor(r, g, b, a)
     }
 
+    /// Interpolate linearly between the color and another one in the RGB space, including the
+    /// alpha channel
+    /// - `other`: The color at the end of the interpolation
+    /// - `t`: The position between the colors, 0 is this color and 1 is the other one
+    fn lerp<C: Color>(&self, other: &C, t: f64) -> RGBAColor
+    where
+        Self: Sized,
+    {
+        let (from, to) = (self.rgb(), other.rgb());
+        let t = t.clamp(0.0, 1.0);
+        let lerp = |a: u8, b: u8| (f64::from(a) + (f64::from(b) - f64::from(a)) * t).round() as u8;
+        RGBAColor(
+            lerp(from.0, to.0),
+            lerp(from.1, to.1),
+            lerp(from.2, to.2),
+            self.alpha() + (other.alpha() - self.alpha()) * t,
+        )
+    }
+
+    /// Interpolate linearly between the color and another one in the HSL space, including the
+    /// alpha channel. The hue goes the shorter way around the color wheel, so the colors in
+    /// between stay saturated, unlike the interpolation in the RGB space.
+    /// - `other`: The color at the end of the interpolation
+    /// - `t`: The position between the colors, 0 is this color and 1 is the other one
+    fn lerp_hsl<C: Color>(&self, other: &C, t: f64) -> RGBAColor
+    where
+        Self: Sized,
+    {
+        let (from, to) = (rgb_to_hsl(self.rgb()), rgb_to_hsl(other.rgb()));
+        let t = t.clamp(0.0, 1.0);
+        let mut hue_delta = to.0 - from.0;
+        if hue_delta > 0.5 {
+            hue_delta -= 1.0;
+        } else if hue_delta < -0.5 {
+            hue_delta += 1.0;
+        }
+        let (r, g, b) = SimpleColor::rgb(&HSLColor(
+            (from.0 + hue_delta * t).rem_euclid(1.0),
+            from.1 + (to.1 - from.1) * t,
+            from.2 + (to.2 - from.2) * t,
+        ));
+        RGBAColor(r, g, b, self.alpha() + (other.alpha() - self.alpha()) * t)
+    }
+
     /// Make a filled style form the color
     fn filled(&self) -> ShapeStyle
     where
@@ -45,6 +89,7 @@ pub trait Color {
 /// The RGBA representation of the color, Plotters use RGBA as the internal representation
 /// of color
 #[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RGBAColor(pub(super) u8, pub(super) u8, pub(super) u8, pub(super) f64);
 
 impl Color for RGBAColor {
@@ -80,12 +125,21 @@ impl<T: SimpleColor> Color for T {
 pub struct PaletteColor<P: Palette>(usize, PhantomData<P>);
 
 impl<P: Palette> PaletteColor<P> {
-    /// Pick a color from the palette
-    pub fn pick(idx: usize) -> PaletteColor<P> {
+    /// Pick a color from the palette, the index wraps around after the last color of the
+    /// palette. It can be used in the constants.
+    pub const fn pick(idx: usize) -> PaletteColor<P> {
         PaletteColor(idx % P::COLORS.len(), PhantomData)
     }
 }
 
+impl<P: Palette> Clone for PaletteColor<P> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<P: Palette> Copy for PaletteColor<P> {}
+
 impl<P: Palette> SimpleColor for PaletteColor<P> {
     fn rgb(&self) -> (u8, u8, u8) {
         P::COLORS[self.0]
@@ -147,3 +201,100 @@ impl SimpleColor for HSLColor {
         (cvt(h + 1.0 / 3.0), cvt(h), cvt(h - 1.0 / 3.0))
     }
 }
+
+/// Convert the RGB value to the hue, the saturation and the lightness, all of them in `[0, 1]`
+#[allow(clippy::many_single_char_names)]
+fn rgb_to_hsl((r, g, b): (u8, u8, u8)) -> (f64, f64, f64) {
+    let (r, g, b) = (
+        f64::from(r) / 255.0,
+        f64::from(g) / 255.0,
+        f64::from(b) / 255.0,
+    );
+    let (max, min) = (r.max(g).max(b), r.min(g).min(b));
+    let l = (max + min) / 2.0;
+    if max == min {
+        return (0.0, 0.0, l);
+    }
+    let d = max - min;
+    let s = if l > 0.5 {
+        d / (2.0 - max - min)
+    } else {
+        d / (max + min)
+    };
+    let h = if max == r {
+        (g - b) / d + if g < b { 6.0 } else { 0.0 }
+    } else if max == g {
+        (b - r) / d + 2.0
+    } else {
+        (r - g) / d + 4.0
+    };
+    (h / 6.0, s, l)
+}
+
+/// The color described by HSV color space, the hue, the saturation and the value are in
+/// `[0, 1]`
+pub struct HSVColor(pub f64, pub f64, pub f64);
+
+impl SimpleColor for HSVColor {
+    #[allow(clippy::many_single_char_names)]
+    fn rgb(&self) -> (u8, u8, u8) {
+        let (h, s, v) = (
+            self.0.clamp(0.0, 1.0),
+            self.1.clamp(0.0, 1.0),
+            self.2.clamp(0.0, 1.0),
+        );
+        let sector = (h * 6.0).rem_euclid(6.0);
+        let f = sector - sector.floor();
+        let (p, q, t) = (v * (1.0 - s), v * (1.0 - s * f), v * (1.0 - s * (1.0 - f)));
+        let (r, g, b) = match sector as u32 {
+            0 => (v, t, p),
+            1 => (q, v, p),
+            2 => (p, v, t),
+            3 => (p, q, v),
+            4 => (t, p, v),
+            _ => (v, p, q),
+        };
+        let cvt = |value: f64| (value * 255.0).round() as u8;
+        (cvt(r), cvt(g), cvt(b))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{rgb_to_hsl, Color, HSLColor, HSVColor, RGBAColor, RGBColor};
+
+    #[test]
+    fn test_hsl_and_hsv_color() {
+        assert_eq!(HSLColor(0.0, 1.0, 0.5).rgb(), (255, 0, 0));
+        assert_eq!(HSLColor(1.0 / 3.0, 1.0, 0.5).rgb(), (0, 255, 0));
+        assert_eq!(HSLColor(0.5, 0.5, 0.25).rgb(), (32, 96, 96));
+        assert_eq!(HSVColor(0.0, 1.0, 1.0).rgb(), (255, 0, 0));
+        assert_eq!(HSVColor(2.0 / 3.0, 1.0, 1.0).rgb(), (0, 0, 255));
+        assert_eq!(HSVColor(1.0, 1.0, 1.0).rgb(), (255, 0, 0));
+        assert_eq!(HSVColor(0.5, 0.5, 0.5).rgb(), (64, 128, 128));
+        assert_eq!(HSVColor(0.3, 0.0, 0.2).rgb(), (51, 51, 51));
+
+        for &(r, g, b) in &[(255, 0, 0), (32, 96, 96), (200, 150, 10), (7, 7, 7)] {
+            let (h, s, l) = rgb_to_hsl((r, g, b));
+            assert_eq!(HSLColor(h, s, l).rgb(), (r, g, b));
+        }
+    }
+
+    #[test]
+    fn test_color_lerp() {
+        let (red, blue) = (RGBColor(255, 0, 0), RGBColor(0, 0, 255));
+        assert_eq!(red.lerp(&blue, 0.5), RGBAColor(128, 0, 128, 1.0));
+        assert_eq!(red.lerp(&blue, 2.0), RGBAColor(0, 0, 255, 1.0));
+        assert_eq!(
+            red.lerp(&RGBAColor(0, 0, 0, 0.0), 0.25),
+            RGBAColor(191, 0, 0, 0.75)
+        );
+        // The hue goes from red to blue through magenta, which is the shorter way
+        assert_eq!(red.lerp_hsl(&blue, 0.5), RGBAColor(255, 0, 255, 1.0));
+        assert_eq!(
+            red.lerp_hsl(&RGBColor(0, 255, 0), 0.5),
+            RGBAColor(255, 255, 0, 1.0)
+        );
+        assert_eq!(red.lerp_hsl(&blue, 0.0), RGBAColor(255, 0, 0, 1.0));
+    }
+}
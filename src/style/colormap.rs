@@ -0,0 +1,156 @@
+use super::color::RGBColor;
+
+/// The color map, which maps a normalized value in `[0, 1]` to a color. It's used to color the
+/// cells of a heatmap or a 2D histogram, or any element by a value, for example
+/// `VIRIDIS.eval(v).filled()`. Any function from `f64` to `RGBColor` is a color map as well.
+pub trait ColorMap {
+    /// Get the color of the normalized value
+    fn eval(&self, value: f64) -> RGBColor;
+}
+
+impl<F: Fn(f64) -> RGBColor> ColorMap for F {
+    fn eval(&self, value: f64) -> RGBColor {
+        self(value)
+    }
+}
+
+/// The color map made of a table of evenly spaced colors, which are linearly interpolated
+/// between. The values out of `[0, 1]` are clamped, and NaN is mapped to a separate color.
+#[derive(Clone, Copy, Debug)]
+pub struct LinearColorMap {
+    colors: &'static [(u8, u8, u8)],
+    nan_color: (u8, u8, u8),
+}
+
+impl LinearColorMap {
+    /// Create a new color map, whose NaN color is gray
+    /// - `colors`: The colors from 0 to 1, there should be at least one
+    pub const fn new(colors: &'static [(u8, u8, u8)]) -> Self {
+        Self {
+            colors,
+            nan_color: (128, 128, 128),
+        }
+    }
+
+    /// Set the color of NaN
+    pub const fn nan_color(mut self, color: RGBColor) -> Self {
+        self.nan_color = (color.0, color.1, color.2);
+        self
+    }
+}
+
+impl ColorMap for LinearColorMap {
+    fn eval(&self, value: f64) -> RGBColor {
+        if value.is_nan() || self.colors.is_empty() {
+            let (r, g, b) = self.nan_color;
+            return RGBColor(r, g, b);
+        }
+        let last = self.colors.len() - 1;
+        let pos = value.clamp(0.0, 1.0) * last as f64;
+        let idx = (pos.floor() as usize).min(last);
+        let (from, to) = (self.colors[idx], self.colors[(idx + 1).min(last)]);
+        let t = pos - idx as f64;
+        let lerp = |a: u8, b: u8| (f64::from(a) + (f64::from(b) - f64::from(a)) * t).round() as u8;
+        RGBColor(lerp(from.0, to.0), lerp(from.1, to.1), lerp(from.2, to.2))
+    }
+}
+
+/// The perceptually uniform color map from dark blue through green to yellow
+pub const VIRIDIS: LinearColorMap = LinearColorMap::new(&[
+    (68, 1, 84),
+    (72, 37, 118),
+    (65, 68, 135),
+    (53, 96, 141),
+    (42, 120, 142),
+    (33, 144, 140),
+    (34, 168, 132),
+    (67, 191, 113),
+    (122, 209, 81),
+    (187, 223, 39),
+    (253, 231, 37),
+]);
+
+/// The perceptually uniform color map from dark blue through magenta to yellow
+pub const PLASMA: LinearColorMap = LinearColorMap::new(&[
+    (13, 8, 135),
+    (65, 4, 157),
+    (106, 0, 168),
+    (143, 13, 164),
+    (177, 42, 144),
+    (204, 71, 120),
+    (225, 100, 98),
+    (242, 132, 75),
+    (252, 166, 54),
+    (252, 206, 37),
+    (240, 249, 33),
+]);
+
+/// The perceptually uniform color map from black through purple and orange to light yellow
+pub const MAGMA: LinearColorMap = LinearColorMap::new(&[
+    (0, 0, 4),
+    (20, 14, 54),
+    (59, 15, 112),
+    (100, 26, 128),
+    (140, 41, 129),
+    (183, 55, 121),
+    (222, 73, 104),
+    (247, 112, 92),
+    (254, 159, 109),
+    (254, 207, 146),
+    (252, 253, 191),
+]);
+
+/// The perceptually uniform color map from black through red and orange to yellow
+pub const INFERNO: LinearColorMap = LinearColorMap::new(&[
+    (0, 0, 4),
+    (22, 11, 57),
+    (66, 10, 104),
+    (106, 23, 110),
+    (147, 38, 103),
+    (188, 55, 84),
+    (221, 81, 58),
+    (243, 120, 25),
+    (252, 165, 10),
+    (246, 215, 70),
+    (252, 255, 164),
+]);
+
+/// The diverging color map from blue through light gray to red, for the values that are
+/// either side of a center at 0.5
+pub const COOLWARM: LinearColorMap = LinearColorMap::new(&[
+    (59, 76, 192),
+    (98, 130, 234),
+    (141, 176, 254),
+    (184, 208, 249),
+    (221, 221, 221),
+    (245, 196, 173),
+    (244, 154, 123),
+    (222, 96, 77),
+    (180, 4, 38),
+]);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::style::Color;
+
+    #[test]
+    fn test_linear_color_map() {
+        assert_eq!(VIRIDIS.eval(0.0).rgb(), (68, 1, 84));
+        assert_eq!(VIRIDIS.eval(1.0).rgb(), (253, 231, 37));
+        // The values between two colors of the table are interpolated
+        assert_eq!(VIRIDIS.eval(0.05).rgb(), (70, 19, 101));
+        assert_eq!(COOLWARM.eval(0.5).rgb(), (221, 221, 221));
+        // The values out of the range are clamped
+        assert_eq!(PLASMA.eval(-1.0).rgb(), PLASMA.eval(0.0).rgb());
+        assert_eq!(MAGMA.eval(2.0).rgb(), (252, 253, 191));
+        assert_eq!(INFERNO.eval(f64::INFINITY).rgb(), (252, 255, 164));
+
+        assert_eq!(VIRIDIS.eval(f64::NAN).rgb(), (128, 128, 128));
+        const MAP: LinearColorMap = VIRIDIS.nan_color(RGBColor(255, 0, 0));
+        assert_eq!(MAP.eval(f64::NAN).rgb(), (255, 0, 0));
+
+        let func = |v: f64| RGBColor((v * 255.0) as u8, 0, 0);
+        assert_eq!(func.eval(1.0).rgb(), (255, 0, 0));
+    }
+}
@@ -10,7 +10,7 @@ macro_rules! predefined_color {
     ($name:ident, $r:expr, $g:expr, $b:expr, $a: expr, $doc:expr) => {
         #[doc = $doc]
         pub const $name: RGBAColor = RGBAColor($r, $g, $b, $a);
-    }
+    };
 }
 
 predefined_color!(WHITE, 255, 255, 255, "The predefined white color");
@@ -34,14 +34,22 @@ pub mod palette_ext {
     macro_rules! predefined_color_pal {
         ($name:ident, $r:expr, $g:expr, $b:expr, $doc:expr) => {
             #[doc = $doc]
-            pub const $name: Srgb<u8> = predefined_color_pal!(@gen_c $r, $g, $b);
+pub const $name: Srgb<u8> = predefined_color_pal!(@gen_c $r, $g, $b);
         };
         ($name:ident, $r:expr, $g:expr, $b:expr, $a:expr, $doc:expr) => {
             #[doc = $doc]
-            pub const $name: Alpha<Srgb<u8>, f64> = Alpha{ alpha: $a, color: predefined_color_pal!(@gen_c $r, $g, $b) };
+            pub const $name: Alpha<Srgb<u8>, f64> = Alpha {
+                alpha: $a,
+    color: predefined_color_pal!(@gen_c $r, $g, $b),
+            };
         };
         (@gen_c $r:expr, $g:expr, $b:expr) => {
-            Srgb { red: $r, green: $g, blue: $b, standard: PhantomData }
+            Srgb {
+                red: $r,
+                green: $g,
+                blue: $b,
+                standard: PhantomData,
+            }
         };
     }
 
@@ -10,7 +10,8 @@ pub type FontError = <FontDataInternal as FontData>::ErrorType;
 pub type FontResult<T> = Result<T, FontError>;
 
 /// Specifying text transformations
-#[derive(Clone)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FontTransform {
     /// Nothing to transform
     None,
@@ -20,6 +21,8 @@ pub enum FontTransform {
     Rotate180,
     /// Rotating the text 270 degree clockwise
     Rotate270,
+    /// Rotating the text clockwise by the angle in degrees
+    RotateAngle(f64),
 }
 
 impl FontTransform {
@@ -34,7 +37,62 @@ impl FontTransform {
             FontTransform::Rotate90 => ((layout.1).1 - (layout.0).1, 0),
             FontTransform::Rotate180 => ((layout.1).0 - (layout.0).0, (layout.1).1 - (layout.0).1),
             FontTransform::Rotate270 => (0, (layout.1).0 - (layout.0).0),
+            FontTransform::RotateAngle(_) => {
+                let (ul, _) = self.rotated_box(layout);
+                (-ul.0, -ul.1)
+            }
+        }
+    }
+
+    /// Get the angle of the rotation in degrees clockwise
+    pub fn angle(&self) -> f64 {
+        match self {
+            FontTransform::None => 0.0,
+            FontTransform::Rotate90 => 90.0,
+            FontTransform::Rotate180 => 180.0,
+            FontTransform::Rotate270 => 270.0,
+            FontTransform::RotateAngle(angle) => *angle,
+        }
+    }
+
+    /// Make the transformation that rotates the text by the angle in degrees clockwise, the
+    /// multiples of the right angle are turned into the exact rotations
+    pub fn from_angle(angle: f64) -> Self {
+        let normalized = angle.rem_euclid(360.0);
+        let right_angles = [
+            FontTransform::None,
+            FontTransform::Rotate90,
+            FontTransform::Rotate180,
+            FontTransform::Rotate270,
+        ];
+        right_angles
+            .iter()
+            .find(|trans| trans.angle() == normalized)
+            .cloned()
+            .unwrap_or(FontTransform::RotateAngle(normalized))
+    }
+
+    /// Get the bounding box of the text box after the rotation, relative to the rotation
+    /// center at the upper left corner of the text box
+    fn rotated_box(&self, layout: LayoutBox) -> LayoutBox {
+        let (w, h) = (
+            f64::from((layout.1).0 - (layout.0).0),
+            f64::from((layout.1).1 - (layout.0).1),
+        );
+        let (sin, cos) = self.angle().to_radians().sin_cos();
+        let corners = [(0.0, 0.0), (w, 0.0), (0.0, h), (w, h)];
+        let rotated = corners
+            .iter()
+            .map(|&(x, y)| (x * cos - y * sin, x * sin + y * cos));
+        let (mut ul, mut br) = ((0.0f64, 0.0f64), (0.0f64, 0.0f64));
+        for (x, y) in rotated {
+            ul = (ul.0.min(x), ul.1.min(y));
+            br = (br.0.max(x), br.1.max(y));
         }
+        (
+            (ul.0.floor() as i32, ul.1.floor() as i32),
+            (br.0.ceil() as i32, br.1.ceil() as i32),
+        )
     }
 
     /// Transform the coordinate to perform the rotation
@@ -48,6 +106,14 @@ impl FontTransform {
             FontTransform::Rotate90 => (-y, x),
             FontTransform::Rotate180 => (-x, -y),
             FontTransform::Rotate270 => (y, -x),
+            FontTransform::RotateAngle(angle) => {
+                let (sin, cos) = angle.to_radians().sin_cos();
+                let (x, y) = (f64::from(x), f64::from(y));
+                (
+                    (x * cos - y * sin).round() as i32,
+                    (x * sin + y * cos).round() as i32,
+                )
+            }
         }
     }
 }
@@ -102,7 +168,8 @@ impl<'a> From<&'a str> for FontFamily<'a> {
 }
 
 /// Describes the font style. Such as Italic, Oblique, etc.
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FontStyle {
     /// The normal style
     Normal,
@@ -112,6 +179,8 @@ pub enum FontStyle {
     Italic,
     /// The bold style
     Bold,
+    /// The bold and italic style
+    BoldItalic,
 }
 
 impl FontStyle {
@@ -122,6 +191,22 @@ impl FontStyle {
             FontStyle::Italic => "italic",
             FontStyle::Oblique => "oblique",
             FontStyle::Bold => "bold",
+            FontStyle::BoldItalic => "bold italic",
+        }
+    }
+
+    /// Check if the style has the bold weight
+    pub fn is_bold(&self) -> bool {
+        matches!(self, FontStyle::Bold | FontStyle::BoldItalic)
+    }
+
+    /// Get the slant of the style, which is a CSS compatible string for the `font-style`
+    /// attribute, or `None` if the text is upright
+    pub fn slant(&self) -> Option<&str> {
+        match self {
+            FontStyle::Italic | FontStyle::BoldItalic => Some("italic"),
+            FontStyle::Oblique => Some("oblique"),
+            _ => None,
         }
     }
 }
@@ -133,6 +218,7 @@ impl<'a> From<&'a str> for FontStyle {
             "italic" => FontStyle::Italic,
             "oblique" => FontStyle::Oblique,
             "bold" => FontStyle::Bold,
+            "bold italic" | "bold-italic" | "bolditalic" => FontStyle::BoldItalic,
             _ => FontStyle::Normal,
         }
     }
@@ -225,12 +311,33 @@ impl<'a> FontDesc<'a> {
         Self {
             size: self.size,
             family: self.family,
-            data: self.data.clone(),
+            data: FontDataInternal::new(self.family, style),
             transform: self.transform.clone(),
             style,
         }
     }
 
+    /// Make the font bold, an italic font becomes bold and italic
+    ///
+    /// - **returns** The new font description with the bold weight
+    pub fn bold(&self) -> Self {
+        match self.style {
+            FontStyle::Italic | FontStyle::BoldItalic => self.style(FontStyle::BoldItalic),
+            _ => self.style(FontStyle::Bold),
+        }
+    }
+
+    /// Make the font italic, a bold font becomes bold and italic
+    ///
+    /// - **returns** The new font description with the italic slant
+    pub fn italic(&self) -> Self {
+        if self.style.is_bold() {
+            self.style(FontStyle::BoldItalic)
+        } else {
+            self.style(FontStyle::Italic)
+        }
+    }
+
     /// Set the font transformation
     ///
     /// - `trans`: The new transformation
@@ -255,6 +362,7 @@ impl<'a> FontDesc<'a> {
         TextStyle {
             font: self.clone(),
             color: color.to_rgba(),
+            pos: Default::default(),
         }
     }
 
@@ -288,7 +396,12 @@ impl<'a> FontDesc<'a> {
     /// This is similar to `layout_box` function, but it apply the font transformation
     /// and estimate the overall size of the font
     pub fn box_size(&self, text: &str) -> FontResult<(u32, u32)> {
-        let ((min_x, min_y), (max_x, max_y)) = self.layout_box(text)?;
+        let layout = self.layout_box(text)?;
+        if let FontTransform::RotateAngle(_) = self.transform {
+            let (ul, br) = self.transform.rotated_box(layout);
+            return Ok(((br.0 - ul.0) as u32, (br.1 - ul.1) as u32));
+        }
+        let ((min_x, min_y), (max_x, max_y)) = layout;
         let (w, h) = self.get_transform().transform(max_x - min_x, max_y - min_y);
         Ok((w.abs() as u32, h.abs() as u32))
     }
@@ -301,8 +414,64 @@ impl<'a> FontDesc<'a> {
         draw: DrawFunc,
     ) -> FontResult<Result<(), E>> {
         match &self.data {
-            Ok(ref font) => font.draw((x, y), self.size, text, self.get_transform(), draw),
+            Ok(ref font) => match self.transform {
+                FontTransform::RotateAngle(angle) => {
+                    self.draw_rotated(font, text, (x, y), angle, draw)
+                }
+                _ => font.draw((x, y), self.size, text, self.get_transform(), draw),
+            },
             Err(e) => Err(e.clone()),
         }
     }
+
+    /// Draw the text rotated by an arbitrary angle. The text is rendered upright first, then
+    /// each pixel of the rotated box is sampled from it, so the rotated glyphs have no holes.
+    fn draw_rotated<E, DrawFunc: FnMut(i32, i32, f32) -> Result<(), E>>(
+        &self,
+        font: &FontDataInternal,
+        text: &str,
+        (x, y): (i32, i32),
+        angle: f64,
+        mut draw: DrawFunc,
+    ) -> FontResult<Result<(), E>> {
+        let layout = font.estimate_layout(self.size, text)?;
+        let (w, h) = (
+            (layout.1).0.max(0) as usize + 1,
+            ((layout.1).1 - (layout.0).1).max(0) as usize + 1,
+        );
+        let mut buffer = vec![0f32; w * h];
+        let upright = font.draw((0, 0), self.size, text, FontTransform::None, |x, y, v| {
+            if (x as usize) < w {
+                if let Some(pixel) = buffer.get_mut(y as usize * w + x as usize) {
+                    *pixel = pixel.max(v);
+                }
+            }
+            Ok(())
+        })?;
+        if let Err(e) = upright {
+            return Ok(Err(e));
+        }
+
+        let (ul, br) = self.transform.rotated_box(layout);
+        let (sin, cos) = angle.to_radians().sin_cos();
+        for dy in ul.1..=br.1 {
+            for dx in ul.0..=br.0 {
+                // Rotate the pixel back to find where it's in the upright text
+                let (fx, fy) = (f64::from(dx), f64::from(dy));
+                let sx = (fx * cos + fy * sin).round();
+                let sy = (fy * cos - fx * sin).round();
+                if sx < 0.0 || sy < 0.0 || sx as usize >= w || sy as usize >= h {
+                    continue;
+                }
+                let v = buffer[sy as usize * w + sx as usize];
+                let (px, py) = (x - ul.0 + dx, y - ul.1 + dy);
+                if v > 0.0 && px >= 0 && py >= 0 {
+                    if let Err(e) = draw(px, py, v) {
+                        return Ok(Err(e));
+                    }
+                }
+            }
+        }
+        Ok(Ok(()))
+    }
 }
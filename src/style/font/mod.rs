@@ -13,6 +13,9 @@ mod ttf;
 #[allow(unused_imports, dead_code)]
 use ttf::FontDataInternal;
 
+#[cfg(not(target_arch = "wasm32"))]
+pub use ttf::{register_font, register_font_data};
+
 #[cfg(target_arch = "wasm32")]
 mod web;
 #[cfg(target_arch = "wasm32")]
@@ -21,6 +21,9 @@ pub enum FontError {
     LockError,
     NoSuchFont(String, String),
     FontLoadError(Arc<Error>),
+    /// None of the sources has the font, the fields are the family, the style and the sources
+    /// that have been tried, in order
+    FontNotFound(String, String, Vec<String>),
 }
 
 impl std::fmt::Display for FontError {
@@ -31,15 +34,74 @@ impl std::fmt::Display for FontError {
                 write!(fmt, "No such font: {} {}", family, style)
             }
             FontError::FontLoadError(e) => write!(fmt, "Font loading error: {}", e),
+            FontError::FontNotFound(family, style, sources) => write!(
+                fmt,
+                "No such font: {} {} (tried {})",
+                family,
+                style,
+                sources.join(", ")
+            ),
         }
     }
 }
 
 impl std::error::Error for FontError {}
 
+/// The font data and whether the bold weight has to be synthesized, because the font of the
+/// bold style isn't available
+type LoadedFont = (Font<'static>, bool);
+
 lazy_static! {
-    static ref CACHE: RwLock<HashMap<String, FontResult<Font<'static>>>> =
-        RwLock::new(HashMap::new());
+    static ref CACHE: RwLock<HashMap<String, FontResult<LoadedFont>>> = RwLock::new(HashMap::new());
+    static ref REGISTERED: RwLock<HashMap<String, Font<'static>>> = RwLock::new(HashMap::new());
+}
+
+fn parse_font(bytes: SharedBytes<'static>) -> FontResult<Font<'static>> {
+    // The font parser reads the 4 bytes of the tag without checking the length
+    if bytes.len() < 4 {
+        return Err(FontError::FontLoadError(Arc::new(
+            Error::UnrecognizedFormat,
+        )));
+    }
+    Font::from_bytes(bytes).map_err(|err| FontError::FontLoadError(Arc::new(err)))
+}
+
+fn insert_registered(name: &str, font: Font<'static>) -> FontResult<()> {
+    REGISTERED
+        .write()
+        .map_err(|_| FontError::LockError)?
+        .insert(name.to_owned(), font);
+    Ok(())
+}
+
+/// Register the TTF or OTF font data under the name, so that the text styles with the font
+/// family of the name use it, for example the font embedded with `include_bytes!`. The
+/// registered fonts are preferred over the system ones, so the text looks the same on every
+/// machine the chart is rendered on.
+///
+/// A family is registered for all the styles, a single style of it can be registered with a
+/// name like `"Roboto, bold"` or `"Roboto, bold italic"`, which is preferred for the text of
+/// the style in the family `"Roboto"`.
+///
+/// - `name`: The name of the font family, `"sans-serif"` replaces the default font
+/// - `bytes`: The font data
+/// - **returns**: The error if the data isn't a valid font
+pub fn register_font(name: &str, bytes: &'static [u8]) -> FontResult<()> {
+    insert_registered(name, parse_font(bytes.into())?)
+}
+
+/// Register the font data under the name, like `register_font`, for the data loaded at runtime
+pub fn register_font_data(name: &str, bytes: Vec<u8>) -> FontResult<()> {
+    insert_registered(name, parse_font(bytes.into())?)
+}
+
+/// Find the registered font of the style, or of the family if there isn't one for the style
+fn find_registered(key: &str, family: &str, bold: bool) -> FontResult<Option<LoadedFont>> {
+    let registered = REGISTERED.read().map_err(|_| FontError::LockError)?;
+    if let Some(font) = registered.get(key) {
+        return Ok(Some((font.clone(), false)));
+    }
+    Ok(registered.get(family).map(|font| (font.clone(), bold)))
 }
 
 thread_local! {
@@ -49,11 +111,15 @@ thread_local! {
 #[allow(dead_code)]
 /// Lazily load font data. Font type doesn't own actual data, which
 /// lives in the cache.
-fn load_font_data(face: FontFamily, style: FontStyle) -> FontResult<Font<'static>> {
+fn load_font_data(face: FontFamily, style: FontStyle) -> FontResult<LoadedFont> {
     let key = match style {
         FontStyle::Normal => Cow::Borrowed(face.as_str()),
         _ => Cow::Owned(format!("{}, {}", face.as_str(), style.as_str())),
     };
+    if let Some(font) = find_registered(&key, face.as_str(), style.is_bold())? {
+        return Ok(font);
+    }
+
     let cache = CACHE.read().unwrap();
     if let Some(cached) = cache.get(Borrow::<str>::borrow(&key)) {
         return cached.clone();
@@ -66,6 +132,7 @@ fn load_font_data(face: FontFamily, style: FontStyle) -> FontResult<Font<'static
         FontStyle::Italic => properties.style(Style::Italic),
         FontStyle::Oblique => properties.style(Style::Oblique),
         FontStyle::Bold => properties.weight(Weight::BOLD),
+        FontStyle::BoldItalic => properties.style(Style::Italic).weight(Weight::BOLD),
     };
 
     let family = match face {
@@ -78,9 +145,24 @@ fn load_font_data(face: FontFamily, style: FontStyle) -> FontResult<Font<'static
     let make_not_found_error =
         || FontError::NoSuchFont(face.as_str().to_owned(), style.as_str().to_owned());
 
+    let mut sources = vec![format!("registered font \"{}\"", face.as_str())];
+    if key != face.as_str() {
+        sources.insert(0, format!("registered font \"{}\"", key));
+    }
+    sources.push(format!("system font \"{}\"", face.as_str()));
+    if face.as_str() != "sans-serif" {
+        sources.push("system font \"sans-serif\"".to_owned());
+    }
+
     if let Ok(handle) = FONT_SOURCE
         .with(|source| source.select_best_match(&[family, FamilyName::SansSerif], &properties))
     {
+        // The best match may be a lighter face of the family, if it doesn't have a bold one
+        let synthetic_bold = style.is_bold()
+            && handle
+                .load()
+                .map(|font| font.properties().weight.0 < Weight::SEMIBOLD.0)
+                .unwrap_or(false);
         let (data, id) = match handle {
             Handle::Path {
                 path,
@@ -103,6 +185,7 @@ fn load_font_data(face: FontFamily, style: FontStyle) -> FontResult<Font<'static
         let result = FontCollection::from_bytes(Into::<SharedBytes>::into(data))
             .map_err(|err| FontError::FontLoadError(Arc::new(err)))?
             .font_at(id.max(0) as usize)
+            .map(|font| (font, synthetic_bold))
             .map_err(|err| FontError::FontLoadError(Arc::new(err)));
 
         CACHE
@@ -112,7 +195,11 @@ fn load_font_data(face: FontFamily, style: FontStyle) -> FontResult<Font<'static
 
         return result;
     }
-    Err(make_not_found_error())
+    Err(FontError::FontNotFound(
+        face.as_str().to_owned(),
+        style.as_str().to_owned(),
+        sources,
+    ))
 }
 
 /// Remove all cached fonts data.
@@ -124,13 +211,26 @@ pub fn clear_font_cache() -> FontResult<()> {
 }
 
 #[derive(Clone)]
-pub struct FontDataInternal(Font<'static>);
+pub struct FontDataInternal(Font<'static>, bool);
+
+impl FontDataInternal {
+    /// The offset in pixels the glyphs are drawn again at to synthesize the bold weight, which
+    /// is 0 if the font is already bold
+    fn bold_offset(&self, size: f64) -> i32 {
+        if self.1 {
+            (size / 24.0).round().max(1.0) as i32
+        } else {
+            0
+        }
+    }
+}
 
 impl FontData for FontDataInternal {
     type ErrorType = FontError;
 
     fn new(family: FontFamily, style: FontStyle) -> Result<Self, FontError> {
-        Ok(FontDataInternal(load_font_data(family, style)?))
+        let (font, synthetic_bold) = load_font_data(family, style)?;
+        Ok(FontDataInternal(font, synthetic_bold))
     }
 
     fn estimate_layout(&self, size: f64, text: &str) -> Result<LayoutBox, Self::ErrorType> {
@@ -154,7 +254,7 @@ impl FontData for FontDataInternal {
             return Ok(((0, 0), (0, 0)));
         }
 
-        Ok(((min_x, min_y), (max_x, max_y)))
+        Ok(((min_x, min_y), (max_x + self.bold_offset(size), max_y)))
     }
 
     fn draw<E, DrawFunc: FnMut(i32, i32, f32) -> Result<(), E>>(
@@ -173,15 +273,19 @@ impl FontData for FontDataInternal {
 
         let base_x = x + trans.offset(layout).0;
         let base_y = y + trans.offset(layout).1;
+        let bold_offset = self.bold_offset(size);
 
         for g in font.layout(text, scale, point(0.0, 0.0)) {
             if let Some(rect) = g.pixel_bounding_box() {
                 let x0 = rect.min.x;
                 let y0 = rect.min.y - (layout.0).1;
                 g.draw(|x, y, v| {
-                    let (x, y) = trans.transform(x as i32 + x0, y as i32 + y0);
-                    if x + base_x >= 0 && y + base_y >= 0 && result.is_ok() {
-                        result = draw(x + base_x, y + base_y, v);
+                    // The synthesized bold glyph is the glyph drawn along the offset
+                    for dx in 0..=bold_offset {
+                        let (x, y) = trans.transform(x as i32 + x0 + dx, y as i32 + y0);
+                        if x + base_x >= 0 && y + base_y >= 0 && result.is_ok() {
+                            result = draw(x + base_x, y + base_y, v);
+                        }
                     }
                 });
             }
@@ -212,4 +316,79 @@ mod test {
 
         return Ok(());
     }
+
+    #[test]
+    fn test_rotated_text() -> FontResult<()> {
+        use crate::style::{FontDesc, FontTransform};
+        assert!(matches!(
+            FontTransform::from_angle(-90.0),
+            FontTransform::Rotate270
+        ));
+        assert!(matches!(
+            FontTransform::from_angle(45.0),
+            FontTransform::RotateAngle(a) if a == 45.0
+        ));
+
+        let font = FontDesc::new(FontFamily::SansSerif, 20.0, FontStyle::Normal);
+        let (w, h) = font.box_size("Hello World")?;
+        let rotated = font.transform(FontTransform::RotateAngle(45.0));
+        let (rw, rh) = rotated.box_size("Hello World")?;
+        let diagonal = f64::from(w + h) / 2f64.sqrt();
+        assert!((f64::from(rw) - diagonal).abs() <= 2.0);
+        assert!((f64::from(rh) - diagonal).abs() <= 2.0);
+
+        // The rotated glyphs are drawn inside the rotated box, without losing their pixels
+        let count = |font: &FontDesc| -> FontResult<(usize, bool)> {
+            let (mut count, mut inside) = (0, true);
+            let (bw, bh) = font.box_size("Hello World")?;
+            font.draw("Hello World", (100, 100), |x, y, v| {
+                count += (v > 0.5) as usize;
+                inside &= x >= 99 && y >= 99 && x <= 101 + bw as i32 && y <= 101 + bh as i32;
+                Ok::<(), ()>(())
+            })?
+            .unwrap();
+            Ok((count, inside))
+        };
+        let (upright, _) = count(&font)?;
+        let (drawn, inside) = count(&rotated)?;
+        assert!(inside);
+        assert!(drawn * 10 >= upright * 8 && drawn * 10 <= upright * 12);
+        Ok(())
+    }
+
+    #[test]
+    fn test_register_font() -> FontResult<()> {
+        let handle = FONT_SOURCE
+            .with(|source| source.select_best_match(&[FamilyName::Serif], &Properties::new()))
+            .expect("No serif font on the system");
+        let data = match handle {
+            Handle::Path { path, .. } => std::fs::read(path).unwrap(),
+            Handle::Memory { bytes, .. } => bytes[..].to_owned(),
+        };
+        assert!(register_font_data("registered-test-font", vec![1, 2, 3]).is_err());
+        register_font_data("registered-test-font", data)?;
+
+        // The registered family is used for all the styles of it, the bold one is synthesized
+        let layout = |family, style| -> FontResult<LayoutBox> {
+            FontDataInternal::new(family, style)?.estimate_layout(20.0, "Hello")
+        };
+        let (ul, br) = layout(FontFamily::Serif, FontStyle::Normal)?;
+        let family = FontFamily::Name("registered-test-font");
+        assert_eq!(layout(family, FontStyle::Normal)?, (ul, br));
+        assert_eq!(layout(family, FontStyle::Bold)?, (ul, (br.0 + 1, br.1)));
+
+        let error = FontError::FontNotFound(
+            "x".to_owned(),
+            "bold".to_owned(),
+            vec![
+                "registered font \"x\"".to_owned(),
+                "system font \"x\"".to_owned(),
+            ],
+        );
+        assert_eq!(
+            error.to_string(),
+            "No such font: x bold (tried registered font \"x\", system font \"x\")"
+        );
+        Ok(())
+    }
 }
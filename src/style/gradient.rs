@@ -0,0 +1,194 @@
+use super::color::{Color, RGBAColor};
+
+/// Normalize the stops of a gradient: the offsets are clamped to `[0, 1]`, the stops are
+/// sorted by their offsets, keeping the order of the stops at the same offset, and the stops
+/// whose offset is NaN are left out
+fn normalize_stops<C: Color>(stops: Vec<(f64, C)>) -> Vec<(f64, RGBAColor)> {
+    let mut ret: Vec<_> = stops
+        .into_iter()
+        .filter(|(offset, _)| !offset.is_nan())
+        .map(|(offset, color)| (offset.clamp(0.0, 1.0), color.to_rgba()))
+        .collect();
+    ret.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+    ret
+}
+
+/// A gradient along a line through the bounding box of the filled shape. The line goes
+/// through the center of the box at the angle, and the offsets `0` and `1` are at the
+/// corners of the box the line goes from and to.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LinearGradient {
+    angle: f64,
+    stops: Vec<(f64, RGBAColor)>,
+}
+
+impl LinearGradient {
+    /// Create a new linear gradient
+    /// - `angle`: The direction of the gradient in degrees, from the x axis towards the y axis,
+    ///   so `0.0` goes from the left to the right and `90.0` from the top to the bottom
+    /// - `stops`: The offsets in `[0, 1]` along the gradient and the colors at them
+    pub fn new<C: Color>(angle: f64, stops: Vec<(f64, C)>) -> Self {
+        Self {
+            angle,
+            stops: normalize_stops(stops),
+        }
+    }
+
+    /// Get the direction of the gradient in degrees
+    pub fn angle(&self) -> f64 {
+        self.angle
+    }
+}
+
+/// A gradient from the center of the bounding box of the filled shape to the ellipse within
+/// the box, which is the circle itself for a circle
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RadialGradient {
+    stops: Vec<(f64, RGBAColor)>,
+}
+
+impl RadialGradient {
+    /// Create a new radial gradient
+    /// - `stops`: The offsets in `[0, 1]` from the center to the edge and the colors at them
+    pub fn new<C: Color>(stops: Vec<(f64, C)>) -> Self {
+        Self {
+            stops: normalize_stops(stops),
+        }
+    }
+}
+
+/// The gradient a shape is filled with, see `ShapeStyle::gradient`
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Gradient {
+    Linear(LinearGradient),
+    Radial(RadialGradient),
+}
+
+impl From<LinearGradient> for Gradient {
+    fn from(gradient: LinearGradient) -> Self {
+        Gradient::Linear(gradient)
+    }
+}
+
+impl From<RadialGradient> for Gradient {
+    fn from(gradient: RadialGradient) -> Self {
+        Gradient::Radial(gradient)
+    }
+}
+
+impl Gradient {
+    /// Get the stops of the gradient, which are sorted by their offsets in `[0, 1]`
+    pub fn stops(&self) -> &[(f64, RGBAColor)] {
+        match self {
+            Gradient::Linear(gradient) => &gradient.stops,
+            Gradient::Radial(gradient) => &gradient.stops,
+        }
+    }
+
+    /// Get the color at the offset, which is interpolated between the stops around it. The
+    /// offsets before the first stop and after the last one take the color of that stop.
+    pub fn color_at(&self, offset: f64) -> RGBAColor {
+        let stops = self.stops();
+        let next = stops.iter().position(|(at, _)| *at > offset);
+        let (from, to) = match next {
+            None => match stops.last() {
+                Some((_, color)) => return color.clone(),
+                None => return RGBAColor(0, 0, 0, 0.0),
+            },
+            Some(0) => return stops[0].1.clone(),
+            Some(idx) => (&stops[idx - 1], &stops[idx]),
+        };
+        let t = (offset - from.0) / (to.0 - from.0);
+        let ((r0, g0, b0), (r1, g1, b1)) = (from.1.rgb(), to.1.rgb());
+        let lerp = |a: u8, b: u8| (f64::from(a) + (f64::from(b) - f64::from(a)) * t).round() as u8;
+        RGBAColor(
+            lerp(r0, r1),
+            lerp(g0, g1),
+            lerp(b0, b1),
+            from.1.alpha() + (to.1.alpha() - from.1.alpha()) * t,
+        )
+    }
+
+    /// Get the average of the colors of the stops, which stands for the gradient where a
+    /// single color is needed
+    pub fn average_color(&self) -> RGBAColor {
+        let stops = self.stops();
+        if stops.is_empty() {
+            return RGBAColor(0, 0, 0, 0.0);
+        }
+        let mut sum = [0.0; 4];
+        for (_, color) in stops {
+            let (r, g, b) = color.rgb();
+            for (sum, value) in sum
+                .iter_mut()
+                .zip([f64::from(r), f64::from(g), f64::from(b), color.alpha()].iter())
+            {
+                *sum += value;
+            }
+        }
+        let n = stops.len() as f64;
+        RGBAColor(
+            (sum[0] / n).round() as u8,
+            (sum[1] / n).round() as u8,
+            (sum[2] / n).round() as u8,
+            sum[3] / n,
+        )
+    }
+
+    /// Get the offset of the gradient at a point of the bounding box of the filled shape, where
+    /// `(0, 0)` is the upper left corner and `(1, 1)` is the bottom right corner of the box. The
+    /// offset isn't clamped to `[0, 1]`.
+    pub fn offset_in_box(&self, (u, v): (f64, f64)) -> f64 {
+        let (du, dv) = (u - 0.5, v - 0.5);
+        match self {
+            Gradient::Linear(gradient) => {
+                let (sin, cos) = gradient.angle.to_radians().sin_cos();
+                // The distance along the direction from the center to the farthest corner
+                let extent = cos.abs() + sin.abs();
+                (du * cos + dv * sin) / extent + 0.5
+            }
+            Gradient::Radial(_) => 2.0 * du.hypot(dv),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::style::{BLUE, RED, WHITE};
+
+    #[test]
+    fn test_gradient_stops_normalized() {
+        let gradient: Gradient =
+            LinearGradient::new(0.0, vec![(1.5, BLUE), (-1.0, RED), (std::f64::NAN, WHITE)]).into();
+        assert_eq!(
+            gradient.stops(),
+            &[(0.0, RED.to_rgba()), (1.0, BLUE.to_rgba())]
+        );
+        assert_eq!(gradient.color_at(-0.5), RED.to_rgba());
+        assert_eq!(gradient.color_at(0.5), RGBAColor(128, 0, 128, 1.0));
+        assert_eq!(gradient.color_at(2.0), BLUE.to_rgba());
+        assert_eq!(gradient.average_color(), RGBAColor(128, 0, 128, 1.0));
+
+        let empty: Gradient = RadialGradient::new(Vec::<(f64, RGBAColor)>::new()).into();
+        assert_eq!(empty.color_at(0.5).alpha(), 0.0);
+    }
+
+    #[test]
+    fn test_gradient_offset_in_box() {
+        let horizontal: Gradient = LinearGradient::new(0.0, vec![(0.0, RED), (1.0, BLUE)]).into();
+        assert_eq!(horizontal.offset_in_box((0.0, 0.7)), 0.0);
+        assert_eq!(horizontal.offset_in_box((1.0, 0.2)), 1.0);
+        // The diagonal gradient goes from corner to corner
+        let diagonal: Gradient = LinearGradient::new(45.0, vec![(0.0, RED), (1.0, BLUE)]).into();
+        assert!(diagonal.offset_in_box((0.0, 0.0)).abs() < 1e-9);
+        assert!((diagonal.offset_in_box((1.0, 1.0)) - 1.0).abs() < 1e-9);
+        assert!((diagonal.offset_in_box((1.0, 0.0)) - 0.5).abs() < 1e-9);
+        let radial: Gradient = RadialGradient::new(vec![(0.0, RED), (1.0, BLUE)]).into();
+        assert_eq!(radial.offset_in_box((0.5, 0.5)), 0.0);
+        assert_eq!(radial.offset_in_box((0.5, 0.0)), 1.0);
+    }
+}
@@ -2,9 +2,12 @@
   The style for shapes and text, font, color, etc.
 */
 mod color;
+mod colormap;
 pub mod colors;
 mod font;
+mod gradient;
 mod palette;
+mod pattern;
 mod shape;
 mod size;
 mod text;
@@ -14,11 +17,16 @@ mod palette_ext;
 
 /// Definitions of palettes of accessibility
 pub use self::palette::*;
-pub use color::{Color, HSLColor, PaletteColor, RGBAColor, RGBColor, SimpleColor};
+pub use color::{Color, HSLColor, HSVColor, PaletteColor, RGBAColor, RGBColor, SimpleColor};
+pub use colormap::{ColorMap, LinearColorMap, COOLWARM, INFERNO, MAGMA, PLASMA, VIRIDIS};
 pub use colors::{BLACK, BLUE, CYAN, GREEN, MAGENTA, RED, TRANSPARENT, WHITE, YELLOW};
+#[cfg(not(target_arch = "wasm32"))]
+pub use font::{register_font, register_font_data};
 pub use font::{
     FontDesc, FontError, FontFamily, FontResult, FontStyle, FontTransform, IntoFont, LayoutBox,
 };
+pub use gradient::{Gradient, LinearGradient, RadialGradient};
+pub use pattern::{Pattern, PatternFill};
 pub use shape::ShapeStyle;
 pub use size::{AsRelative, RelativeSize, SizeDesc};
-pub use text::{IntoTextStyle, TextStyle};
+pub use text::{HPos, IntoTextStyle, Pos, TextStyle, VPos};
@@ -16,6 +16,12 @@ pub struct Palette99;
 pub struct Palette9999;
 /// The palette of 100% accessibility
 pub struct Palette100;
+/// The categorical palette of 10 colors of Tableau, which is also the default color cycle of
+/// matplotlib
+pub struct PaletteTab10;
+/// The palette of 8 colors by Okabe and Ito, which are distinguishable with any kind of color
+/// blindness
+pub struct PaletteOkabeIto;
 
 impl Palette for Palette99 {
     const COLORS: &'static [(u8, u8, u8)] = &[
@@ -61,3 +67,49 @@ impl Palette for Palette100 {
     const COLORS: &'static [(u8, u8, u8)] =
         &[(255, 225, 25), (0, 130, 200), (128, 128, 128), (0, 0, 0)];
 }
+
+impl Palette for PaletteTab10 {
+    const COLORS: &'static [(u8, u8, u8)] = &[
+        (31, 119, 180),
+        (255, 127, 14),
+        (44, 160, 44),
+        (214, 39, 40),
+        (148, 103, 189),
+        (140, 86, 75),
+        (227, 119, 194),
+        (127, 127, 127),
+        (188, 189, 34),
+        (23, 190, 207),
+    ];
+}
+
+impl Palette for PaletteOkabeIto {
+    const COLORS: &'static [(u8, u8, u8)] = &[
+        (230, 159, 0),
+        (86, 180, 233),
+        (0, 158, 115),
+        (240, 228, 66),
+        (0, 114, 178),
+        (213, 94, 0),
+        (204, 121, 167),
+        (0, 0, 0),
+    ];
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::style::Color;
+
+    #[test]
+    fn test_palette_cycle() {
+        // The colors can be picked in the constants
+        const SECOND: PaletteColor<PaletteTab10> = PaletteColor::pick(1);
+        assert_eq!(SECOND.rgb(), (255, 127, 14));
+        // The index wraps around after the last color
+        assert_eq!(PaletteTab10::pick(10).rgb(), PaletteTab10::pick(0).rgb());
+        assert_eq!(PaletteOkabeIto::pick(9).rgb(), (86, 180, 233));
+        let style = PaletteOkabeIto::pick(7).filled();
+        assert_eq!((style.color.rgb(), style.filled), ((0, 0, 0), true));
+    }
+}
@@ -0,0 +1,164 @@
+use super::color::{Color, RGBAColor};
+
+/// The kinds of the hatches a pattern is made of
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PatternFill {
+    /// The lines going up from the left to the right
+    DiagonalUp,
+    /// The lines going down from the left to the right
+    DiagonalDown,
+    /// The lines of both of the diagonals, which is the cross-hatch
+    Cross,
+    /// The dots on a grid
+    Dots,
+    /// The horizontal lines
+    Horizontal,
+    /// The vertical lines
+    Vertical,
+}
+
+/// A pattern a filled shape is filled with instead of a plain color, which suits the
+/// grayscale charts for the print. The hatches are drawn with the color of the style, and the
+/// pattern starts at the upper left corner of each shape, so the adjacent bars don't share
+/// the phase of their hatches.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Pattern {
+    fill: PatternFill,
+    spacing: u32,
+    line_width: u32,
+    background: Option<RGBAColor>,
+}
+
+impl From<PatternFill> for Pattern {
+    fn from(fill: PatternFill) -> Self {
+        Pattern::new(fill)
+    }
+}
+
+impl Pattern {
+    /// Create a new pattern, whose hatches are 8 pixels apart and 1 pixel wide
+    /// - `fill`: The kind of the hatches
+    pub fn new(fill: PatternFill) -> Self {
+        Self {
+            fill,
+            spacing: 8,
+            line_width: 1,
+            background: None,
+        }
+    }
+
+    /// Set the distance in pixels the hatches repeat at, which is at least 2
+    pub fn spacing(mut self, spacing: u32) -> Self {
+        self.spacing = spacing.max(2);
+        self
+    }
+
+    /// Set the width of the lines or the diameter of the dots in pixels, which is less than the
+    /// spacing
+    pub fn line_width(mut self, width: u32) -> Self {
+        self.line_width = width.max(1);
+        self
+    }
+
+    /// Fill the space between the hatches with a color, otherwise it's left as it is
+    pub fn background<C: Color>(mut self, color: &C) -> Self {
+        self.background = Some(color.to_rgba());
+        self
+    }
+
+    /// Get the kind of the hatches
+    pub fn fill(&self) -> PatternFill {
+        self.fill
+    }
+
+    /// Get the size of the tile the pattern repeats
+    pub fn tile_size(&self) -> u32 {
+        self.spacing
+    }
+
+    /// Get the width of the hatches, which is less than the size of the tile
+    pub fn hatch_width(&self) -> u32 {
+        self.line_width.min(self.spacing - 1)
+    }
+
+    /// Get the background color of the pattern, if there's one
+    pub fn background_color(&self) -> Option<&RGBAColor> {
+        self.background.as_ref()
+    }
+
+    /// Check if the hatches cover the pixel, which is relative to the upper left corner of the
+    /// shape
+    pub fn covers(&self, (x, y): (i32, i32)) -> bool {
+        let (spacing, width) = (self.spacing as i32, self.hatch_width() as i32);
+        let band = |value: i32| value.rem_euclid(spacing) < width;
+        match self.fill {
+            PatternFill::DiagonalUp => band(x + y),
+            PatternFill::DiagonalDown => band(x - y),
+            PatternFill::Cross => band(x + y) || band(x - y),
+            PatternFill::Horizontal => band(y),
+            PatternFill::Vertical => band(x),
+            PatternFill::Dots => {
+                let radius = f64::from(width) / 2.0;
+                let dx = f64::from(x.rem_euclid(spacing)) + 0.5 - radius;
+                let dy = f64::from(y.rem_euclid(spacing)) + 0.5 - radius;
+                dx.hypot(dy) <= radius
+            }
+        }
+    }
+
+    /// Get the color of the pixel, which is relative to the upper left corner of the shape, or
+    /// `None` if the pixel is left as it is
+    /// - `color`: The color of the hatches
+    pub fn color_at(&self, pos: (i32, i32), color: &RGBAColor) -> Option<RGBAColor> {
+        if self.covers(pos) {
+            Some(color.clone())
+        } else {
+            self.background.clone()
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_pattern_covers() {
+        let row = |pattern: &Pattern, y: i32| -> String {
+            (0..8)
+                .map(|x| if pattern.covers((x, y)) { '#' } else { '.' })
+                .collect()
+        };
+        let up = Pattern::new(PatternFill::DiagonalUp).spacing(4);
+        assert_eq!(row(&up, 0), "#...#...");
+        assert_eq!(row(&up, 1), "...#...#");
+        let down = Pattern::new(PatternFill::DiagonalDown).spacing(4);
+        assert_eq!(row(&down, 1), ".#...#..");
+        let cross = Pattern::new(PatternFill::Cross).spacing(4);
+        assert_eq!(row(&cross, 1), ".#.#.#.#");
+        let horizontal = Pattern::new(PatternFill::Horizontal)
+            .spacing(3)
+            .line_width(2);
+        assert_eq!(row(&horizontal, 1), "########");
+        assert_eq!(row(&horizontal, 2), "........");
+        let vertical = Pattern::new(PatternFill::Vertical).spacing(4).line_width(9);
+        assert_eq!(row(&vertical, 5), "###.###.");
+        let dots = Pattern::new(PatternFill::Dots).spacing(4).line_width(2);
+        assert_eq!(row(&dots, 0), "##..##..");
+        assert_eq!(row(&dots, 2), "........");
+
+        let background = up.background(&RGBAColor(255, 255, 255, 1.0));
+        let black = RGBAColor(0, 0, 0, 1.0);
+        assert_eq!(background.color_at((0, 0), &black), Some(black.clone()));
+        assert_eq!(
+            background.color_at((1, 0), &black),
+            Some(RGBAColor(255, 255, 255, 1.0))
+        );
+        assert_eq!(
+            Pattern::new(PatternFill::Dots).color_at((5, 5), &black),
+            None
+        );
+    }
+}
@@ -1,30 +1,123 @@
 use super::color::{Color, RGBAColor};
+use super::colors::BLACK;
+use super::gradient::Gradient;
+use super::pattern::Pattern;
 
 /// Style for any of shape
-#[derive(Clone)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ShapeStyle {
     pub color: RGBAColor,
     pub filled: bool,
     pub stroke_width: u32,
+    /// The lengths in pixels of the dashes and the gaps between them of the stroke, which is
+    /// solid if it's `None`. The pattern starts with a dash and repeats along the line.
+    pub dash_pattern: Option<Vec<u32>>,
+    /// The color of the outline of a filled shape, which is drawn over the fill with the stroke
+    /// width and the dash pattern. A filled shape doesn't have an outline if it's `None`, and
+    /// the stroke of an unfilled shape always uses `color`.
+    pub stroke_color: Option<RGBAColor>,
+    /// The gradient a filled shape is filled with instead of `color`, which spans the bounding
+    /// box of the shape. The strokes are always drawn with a single color.
+    pub gradient: Option<Gradient>,
+    /// The pattern of the hatches a filled shape is filled with instead of `color`, which draws
+    /// the hatches with `color`. A pattern takes the place of the gradient.
+    pub pattern: Option<Pattern>,
 }
 
 impl ShapeStyle {
+    /// Make a filled shape style with a gradient, for example
+    /// `ShapeStyle::gradient(LinearGradient::new(90.0, vec![(0.0, RED), (1.0, BLUE)]))`. The
+    /// color of the style is the average of the stops, which is used by the backends without
+    /// the gradient support.
+    /// - `gradient`: The linear or the radial gradient
+    pub fn gradient<G: Into<Gradient>>(gradient: G) -> Self {
+        let gradient = gradient.into();
+        let color = gradient.average_color();
+        Self {
+            filled: true,
+            gradient: Some(gradient),
+            ..Self::from(&color)
+        }
+    }
+
+    /// Make a filled shape style with a pattern of the hatches of the color of the style, for
+    /// example `BLACK.filled().pattern(PatternFill::Cross)`, or
+    /// `BLACK.filled().pattern(Pattern::new(PatternFill::Dots).spacing(6).line_width(2))`
+    /// - `pattern`: The pattern, or the kind of the hatches for the default pattern
+    pub fn pattern<P: Into<Pattern>>(&self, pattern: P) -> Self {
+        Self {
+            filled: true,
+            gradient: None,
+            pattern: Some(pattern.into()),
+            ..self.clone()
+        }
+    }
+
     /// Make a filled shape style
     pub fn filled(&self) -> Self {
         Self {
-            color: self.color.to_rgba(),
             filled: true,
-            stroke_width: self.stroke_width,
+            ..self.clone()
         }
     }
 
     pub fn stroke_width(&self, width: u32) -> Self {
         Self {
-            color: self.color.to_rgba(),
-            filled: self.filled,
             stroke_width: width,
+            ..self.clone()
+        }
+    }
+
+    /// Make a filled shape style with an outline of a different color, for example
+    /// `WHITE.filled().stroke_color(&RED).stroke_width(2)` for a white marker with a red border
+    /// - `color`: The color of the outline, the fill keeps the current color
+    pub fn stroke_color<C: Color>(&self, color: &C) -> Self {
+        Self {
+            filled: true,
+            stroke_color: Some(color.to_rgba()),
+            ..self.clone()
         }
     }
+
+    /// Get the style of the outline drawn over a filled shape, if there's one
+    pub(crate) fn outline(&self) -> Option<ShapeStyle> {
+        match self.stroke_color {
+            Some(ref color) if self.filled => Some(Self {
+                color: color.to_rgba(),
+                filled: false,
+                stroke_color: None,
+                gradient: None,
+                pattern: None,
+                ..self.clone()
+            }),
+            _ => None,
+        }
+    }
+
+    /// Make the stroke dashed with the given pattern
+    /// - `pattern`: The lengths in pixels of the dashes and the gaps, in turn. A pattern of an
+    ///   odd length is repeated twice, so `[5]` is the same as `[5, 5]`.
+    pub fn dash(&self, pattern: &[u32]) -> Self {
+        Self {
+            dash_pattern: Some(pattern.to_vec()),
+            ..self.clone()
+        }
+    }
+
+    /// Make the stroke dashed, the dashes and the gaps scale with the stroke width, so the
+    /// width should be set first
+    pub fn dashed(&self) -> Self {
+        let width = self.stroke_width.max(1);
+        self.dash(&[width * 6, width * 3])
+    }
+
+    /// Make the stroke dotted, the dots are as long as the stroke width, so the width should
+    /// be set first
+    pub fn dotted(&self) -> Self {
+        let width = self.stroke_width.max(1);
+        self.dash(&[width, width * 2])
+    }
 }
 
 impl<'a, T: Color> From<&'a T> for ShapeStyle {
@@ -33,6 +126,18 @@ impl<'a, T: Color> From<&'a T> for ShapeStyle {
             color: f.to_rgba(),
             filled: false,
             stroke_width: 1,
+            dash_pattern: None,
+            stroke_color: None,
+            gradient: None,
+            pattern: None,
         }
     }
 }
+
+/// The default style is a solid black stroke of one pixel, which the struct literals can
+/// extend as `ShapeStyle { stroke_width: 2, ..Default::default() }`
+impl Default for ShapeStyle {
+    fn default() -> Self {
+        Self::from(&BLACK)
+    }
+}
@@ -3,11 +3,73 @@ use super::font::{FontDesc, FontFamily, FontStyle, FontTransform};
 use super::size::{HasDimension, SizeDesc};
 use super::BLACK;
 
+/// The horizontal alignment of a text to its anchor point
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HPos {
+    /// The anchor is at the left edge of the text
+    Left,
+    /// The anchor is at the horizontal center of the text
+    Center,
+    /// The anchor is at the right edge of the text
+    Right,
+}
+
+/// The vertical alignment of a text to its anchor point
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VPos {
+    /// The anchor is at the top edge of the text
+    Top,
+    /// The anchor is at the vertical center of the text
+    Center,
+    /// The anchor is at the bottom edge of the text
+    Bottom,
+}
+
+/// The point of the text box that is placed at the position of a text, which is the upper
+/// left corner by default
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Pos {
+    pub h_pos: HPos,
+    pub v_pos: VPos,
+}
+
+impl Pos {
+    /// Create a new anchor point of the text
+    pub fn new(h_pos: HPos, v_pos: VPos) -> Self {
+        Self { h_pos, v_pos }
+    }
+
+    /// Get the position of the upper left corner of the text box of the size, relative to the
+    /// anchor point
+    pub fn upper_left_offset(&self, (w, h): (u32, u32)) -> (i32, i32) {
+        let (w, h) = (w as i32, h as i32);
+        let dx = match self.h_pos {
+            HPos::Left => 0,
+            HPos::Center => -w / 2,
+            HPos::Right => -w,
+        };
+        let dy = match self.v_pos {
+            VPos::Top => 0,
+            VPos::Center => -h / 2,
+            VPos::Bottom => -h,
+        };
+        (dx, dy)
+    }
+}
+
+impl Default for Pos {
+    fn default() -> Self {
+        Self::new(HPos::Left, VPos::Top)
+    }
+}
+
 /// Style of a text
 #[derive(Clone)]
 pub struct TextStyle<'a> {
     pub font: FontDesc<'a>,
     pub color: RGBAColor,
+    /// The point of the text anchored at the position of the text element
+    pub pos: Pos,
 }
 
 pub trait IntoTextStyle<'a> {
@@ -62,6 +124,7 @@ impl<'a> TextStyle<'a> {
         Self {
             font: self.font.clone(),
             color: color.to_rgba(),
+            pos: self.pos,
         }
     }
 
@@ -69,6 +132,17 @@ impl<'a> TextStyle<'a> {
         Self {
             font: self.font.clone().transform(trans),
             color: self.color.clone(),
+            pos: self.pos,
+        }
+    }
+
+    /// Set the point of the text that is anchored at the position of the text element, for
+    /// example `Pos::new(HPos::Center, VPos::Bottom)` to place the text above the position
+    pub fn pos(&self, pos: Pos) -> Self {
+        Self {
+            font: self.font.clone(),
+            color: self.color.clone(),
+            pos,
         }
     }
 }
@@ -85,6 +159,7 @@ impl<'a, T: Into<FontDesc<'a>>> From<T> for TextStyle<'a> {
         Self {
             font: font.into(),
             color: BLACK.to_rgba(),
+            pos: Pos::default(),
         }
     }
 }